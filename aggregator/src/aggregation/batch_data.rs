@@ -1,6 +1,8 @@
 use crate::{
-    aggregation::rlc::POWS_OF_256, blob_consistency::BLOB_WIDTH, constants::N_BYTES_U256,
-    BatchHash, ChunkInfo, RlcConfig,
+    aggregation::rlc::POWS_OF_256,
+    blob_consistency::{BlobConsistency, BLOB_WIDTH},
+    constants::N_BYTES_U256,
+    BatchHash, ChunkInfo, RlcConfig, MAX_BLOBS,
 };
 use eth_types::{H256, U256};
 use ethers_core::utils::keccak256;
@@ -26,8 +28,9 @@ pub const N_DATA_BYTES_PER_COEFFICIENT: usize = 31;
 /// we explicitly set the most-significant byte to 0, effectively utilising only 31 bytes.
 pub const N_BLOB_BYTES: usize = BLOB_WIDTH * N_DATA_BYTES_PER_COEFFICIENT;
 
-/// Allow up to 5x compression via zstd encoding of the batch data.
-const N_BATCH_BYTES: usize = N_BLOB_BYTES * 5;
+/// Allow up to 5x compression via zstd encoding of the batch data, that spans up to MAX_BLOBS
+/// blobs.
+const N_BATCH_BYTES: usize = N_BLOB_BYTES * MAX_BLOBS * 5;
 
 /// The number of rows to encode number of valid chunks (num_valid_snarks) in a batch, in the Blob
 /// Data config. Since num_valid_chunks is u16, we use 2 bytes/rows.
@@ -77,7 +80,7 @@ pub struct BatchDataConfig<const N_SNARKS: usize> {
 pub struct AssignedBatchDataExport {
     pub num_valid_chunks: AssignedCell<Fr, Fr>,
     pub batch_data_len: AssignedCell<Fr, Fr>,
-    pub versioned_hash: Vec<AssignedCell<Fr, Fr>>,
+    pub versioned_hashes: Vec<Vec<AssignedCell<Fr, Fr>>>,
     pub chunk_data_digests: Vec<Vec<AssignedCell<Fr, Fr>>>,
     pub bytes_rlc: AssignedCell<Fr, Fr>,
 }
@@ -349,7 +352,7 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
             },
         );
 
-        // lookup challenge digest in keccak table.
+        // lookup challenge digests in keccak table.
         meta.lookup_any(
             "BatchDataConfig (metadata/chunk_data/challenge digests in keccak table)",
             |meta| {
@@ -357,7 +360,7 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
                 let is_boundary = meta.query_advice(config.is_boundary, Rotation::cur());
 
                 // when is_boundary is set in the "digest RLC" section.
-                // this is also the last row of the "digest RLC" section.
+                // these are the last MAX_BLOBS rows of the "digest RLC" section.
                 let cond = is_hash * is_boundary;
 
                 // - metadata_digest: 32 bytes
@@ -400,14 +403,16 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         // `core.rs`. Since these are already constrained, we can just use them as is.
         chunks_are_padding: &[AssignedCell<Fr, Fr>],
         batch_data: &BatchData<N_SNARKS>,
-        versioned_hash: H256,
-        barycentric_assignments: &[CRTInteger<Fr>],
+        versioned_hashes: &[H256],
+        barycentric_assignments: &[&[CRTInteger<Fr>]],
     ) -> Result<AssignedBatchDataExport, Error> {
         self.load_range_tables(layouter)?;
 
         let assigned_rows = layouter.assign_region(
             || "BatchData rows",
-            |mut region| self.assign_rows(&mut region, challenge_value, batch_data, versioned_hash),
+            |mut region| {
+                self.assign_rows(&mut region, challenge_value, batch_data, versioned_hashes)
+            },
         )?;
 
         layouter.assign_region(
@@ -435,14 +440,14 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         region: &mut Region<Fr>,
         challenge_value: Challenges<Value<Fr>>,
         batch_data: &BatchData<N_SNARKS>,
-        versioned_hash: H256,
+        versioned_hashes: &[H256],
     ) -> Result<Vec<AssignedBatchDataConfig>, Error> {
         let n_rows_data = BatchData::<N_SNARKS>::n_rows_data();
         let n_rows_metadata = BatchData::<N_SNARKS>::n_rows_metadata();
         let n_rows_digest_rlc = BatchData::<N_SNARKS>::n_rows_digest_rlc();
         let n_rows_total = BatchData::<N_SNARKS>::n_rows();
 
-        let rows = batch_data.to_rows(versioned_hash, challenge_value);
+        let rows = batch_data.to_rows(versioned_hashes, challenge_value);
         assert_eq!(rows.len(), n_rows_total);
 
         // enable data selector
@@ -550,7 +555,7 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         // The chunks_are_padding assigned cells are exports from the conditional constraints in
         // `core.rs`. Since these are already constrained, we can just use them as is.
         chunks_are_padding: &[AssignedCell<Fr, Fr>],
-        barycentric_assignments: &[CRTInteger<Fr>],
+        barycentric_assignments: &[&[CRTInteger<Fr>]],
         assigned_rows: &[AssignedBatchDataConfig],
     ) -> Result<AssignedBatchDataExport, Error> {
         let n_rows_metadata = BatchData::<N_SNARKS>::n_rows_metadata();
@@ -586,7 +591,7 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
                     rlc_config.load_private(region, &Fr::from(i as u64), &mut rlc_config_offset)?;
                 // TODO: look into this....
                 let i_fixed_cell =
-                    rlc_config.fixed_up_to_max_agg_snarks_cell(i_cell.cell().region_index, i);
+                    rlc_config.fixed_up_to_max_batch_capacity_cell(i_cell.cell().region_index, i);
                 region.constrain_equal(i_cell.cell(), i_fixed_cell)?;
                 fixed_chunk_indices.push(i_cell);
             }
@@ -842,13 +847,23 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
             region.constrain_equal(row.chunk_idx.cell(), fixed_chunk_idx.cell())?;
         }
 
-        let challenge_digest_preimage_rlc_specified = &rows.last().unwrap().preimage_rlc;
-        let challenge_digest_rlc_specified = &rows.last().unwrap().digest_rlc;
-        let versioned_hash_rlc = &rows.get(n_rows_digest_rlc - 2).unwrap().digest_rlc;
+        let versioned_hash_rlcs = rows
+            .iter()
+            .skip(1 + N_SNARKS)
+            .take(MAX_BLOBS)
+            .map(|row| &row.digest_rlc)
+            .collect::<Vec<_>>();
+        let challenge_digest_rows = rows
+            .iter()
+            .skip(1 + N_SNARKS + MAX_BLOBS)
+            .take(MAX_BLOBS)
+            .collect::<Vec<_>>();
 
-        // ensure that on the last row of this section the is_boundary is turned on
-        // which would enable the keccak table lookup for challenge_digest
-        region.constrain_equal(rows.last().unwrap().is_boundary.cell(), one.cell())?;
+        // ensure that on the last MAX_BLOBS rows of this section the is_boundary is turned on
+        // which would enable the keccak table lookup for each blob's challenge_digest
+        for row in challenge_digest_rows.iter() {
+            region.constrain_equal(row.is_boundary.cell(), one.cell())?;
+        }
 
         let metadata_digest_rlc_computed =
             &assigned_rows.get(n_rows_metadata - 1).unwrap().digest_rlc;
@@ -909,7 +924,8 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         ///////////////////////////////// DIGEST BYTES /////////////////////////////////
         ////////////////////////////////////////////////////////////////////////////////
 
-        let mut challenge_digest_preimage_keccak_rlc = zero.clone();
+        let mut challenge_digest_preimage_prefix_keccak_rlc = zero.clone();
+        let mut versioned_hash_keccak_rlcs = Vec::with_capacity(MAX_BLOBS);
         let rows = assigned_rows
             .iter()
             .skip(n_rows_metadata + n_rows_data + n_rows_digest_rlc)
@@ -917,8 +933,8 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
             .collect::<Vec<_>>();
         for (i, digest_rlc_specified) in std::iter::once(metadata_digest_rlc_specified)
             .chain(chunk_digest_evm_rlcs)
-            .chain(std::iter::once(versioned_hash_rlc))
-            .chain(std::iter::once(challenge_digest_rlc_specified))
+            .chain(versioned_hash_rlcs)
+            .chain(challenge_digest_rows.iter().map(|row| &row.digest_rlc))
             .enumerate()
         {
             let digest_rows = rows
@@ -935,23 +951,44 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
             region.constrain_equal(digest_rlc_computed.cell(), digest_rlc_specified.cell())?;
 
             // compute the keccak input RLC:
-            // we do this only for the metadata and chunks, not for the blob row itself.
-            if i < N_SNARKS + 1 + 1 {
+            // - the metadata and chunks form the prefix shared by every blob's challenge digest.
+            // - each blob's versioned hash completes the preimage of that blob's challenge digest.
+            // we do not do this for the challenge digests themselves.
+            if i < N_SNARKS + 1 {
                 let digest_keccak_rlc =
                     rlc_config.rlc(region, &digest_bytes, &r_keccak, &mut rlc_config_offset)?;
-                challenge_digest_preimage_keccak_rlc = rlc_config.mul_add(
+                challenge_digest_preimage_prefix_keccak_rlc = rlc_config.mul_add(
                     region,
-                    &challenge_digest_preimage_keccak_rlc,
+                    &challenge_digest_preimage_prefix_keccak_rlc,
                     &r32,
                     &digest_keccak_rlc,
                     &mut rlc_config_offset,
                 )?;
+            } else if i < N_SNARKS + 1 + MAX_BLOBS {
+                versioned_hash_keccak_rlcs.push(rlc_config.rlc(
+                    region,
+                    &digest_bytes,
+                    &r_keccak,
+                    &mut rlc_config_offset,
+                )?);
             }
         }
-        region.constrain_equal(
-            challenge_digest_preimage_keccak_rlc.cell(),
-            challenge_digest_preimage_rlc_specified.cell(),
-        )?;
+        for (versioned_hash_keccak_rlc, challenge_digest_row) in versioned_hash_keccak_rlcs
+            .iter()
+            .zip_eq(challenge_digest_rows.iter())
+        {
+            let challenge_digest_preimage_keccak_rlc = rlc_config.mul_add(
+                region,
+                &challenge_digest_preimage_prefix_keccak_rlc,
+                &r32,
+                versioned_hash_keccak_rlc,
+                &mut rlc_config_offset,
+            )?;
+            region.constrain_equal(
+                challenge_digest_preimage_keccak_rlc.cell(),
+                challenge_digest_row.preimage_rlc.cell(),
+            )?;
+        }
 
         ////////////////////////////////////////////////////////////////////////////////
         //////////////////////////////////// EXPORT ////////////////////////////////////
@@ -967,22 +1004,19 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         for chunk in chunk_data_digests_bytes.chunks_exact(N_BYTES_U256) {
             chunk_data_digests.push(chunk.to_vec());
         }
-        let challenge_digest = assigned_rows
-            .iter()
-            .rev()
-            .take(N_BYTES_U256)
-            .map(|row| row.byte.clone())
-            .collect::<Vec<AssignedCell<Fr, Fr>>>();
-        let export = AssignedBatchDataExport {
-            num_valid_chunks,
-            batch_data_len,
-            versioned_hash: assigned_rows
+        let digest_bytes_at = |i: usize| -> Vec<AssignedCell<Fr, Fr>> {
+            assigned_rows
                 .iter()
-                .rev()
-                .skip(N_BYTES_U256)
+                .skip(n_rows_metadata + n_rows_data + n_rows_digest_rlc + N_BYTES_U256 * i)
                 .take(N_BYTES_U256)
                 .map(|row| row.byte.clone())
-                .rev()
+                .collect()
+        };
+        let export = AssignedBatchDataExport {
+            num_valid_chunks,
+            batch_data_len,
+            versioned_hashes: (0..MAX_BLOBS)
+                .map(|i| digest_bytes_at(1 + N_SNARKS + i))
                 .collect(),
             chunk_data_digests,
             // bytes rlc is from the last row of the "chunk data" section.
@@ -997,40 +1031,47 @@ impl<const N_SNARKS: usize> BatchDataConfig<N_SNARKS> {
         //////////////////////////// CHALLENGE DIGEST CHECK ////////////////////////////
         ////////////////////////////////////////////////////////////////////////////////
 
-        assert_eq!(barycentric_assignments.len(), BLOB_WIDTH + 1);
-        let challenge_digest_crt = barycentric_assignments
-            .get(BLOB_WIDTH)
-            .expect("challenge digest CRT");
-        let challenge_digest_limb1 = rlc_config.inner_product(
-            region,
-            &challenge_digest[0..11],
-            &pows_of_256,
-            &mut rlc_config_offset,
-        )?;
-        let challenge_digest_limb2 = rlc_config.inner_product(
-            region,
-            &challenge_digest[11..22],
-            &pows_of_256,
-            &mut rlc_config_offset,
-        )?;
-        let challenge_digest_limb3 = rlc_config.inner_product(
-            region,
-            &challenge_digest[22..32],
-            &pows_of_256[0..10],
-            &mut rlc_config_offset,
-        )?;
-        region.constrain_equal(
-            challenge_digest_limb1.cell(),
-            challenge_digest_crt.truncation.limbs[0].cell(),
-        )?;
-        region.constrain_equal(
-            challenge_digest_limb2.cell(),
-            challenge_digest_crt.truncation.limbs[1].cell(),
-        )?;
-        region.constrain_equal(
-            challenge_digest_limb3.cell(),
-            challenge_digest_crt.truncation.limbs[2].cell(),
-        )?;
+        assert_eq!(barycentric_assignments.len(), MAX_BLOBS);
+        for (i, barycentric_assignments) in barycentric_assignments.iter().enumerate() {
+            let challenge_digest = digest_bytes_at(1 + N_SNARKS + MAX_BLOBS + i);
+
+            assert_eq!(barycentric_assignments.len(), BLOB_WIDTH + 1);
+            let challenge_digest_crt = barycentric_assignments
+                .get(BLOB_WIDTH)
+                .expect("challenge digest CRT");
+            // the limbs are little-endian, whereas the digest bytes are big-endian.
+            let challenge_digest_le = challenge_digest.into_iter().rev().collect::<Vec<_>>();
+            let challenge_digest_limb1 = rlc_config.inner_product(
+                region,
+                &challenge_digest_le[0..11],
+                &pows_of_256,
+                &mut rlc_config_offset,
+            )?;
+            let challenge_digest_limb2 = rlc_config.inner_product(
+                region,
+                &challenge_digest_le[11..22],
+                &pows_of_256,
+                &mut rlc_config_offset,
+            )?;
+            let challenge_digest_limb3 = rlc_config.inner_product(
+                region,
+                &challenge_digest_le[22..32],
+                &pows_of_256[0..10],
+                &mut rlc_config_offset,
+            )?;
+            region.constrain_equal(
+                challenge_digest_limb1.cell(),
+                challenge_digest_crt.truncation.limbs[0].cell(),
+            )?;
+            region.constrain_equal(
+                challenge_digest_limb2.cell(),
+                challenge_digest_crt.truncation.limbs[1].cell(),
+            )?;
+            region.constrain_equal(
+                challenge_digest_limb3.cell(),
+                challenge_digest_crt.truncation.limbs[2].cell(),
+            )?;
+        }
 
         Ok(export)
     }
@@ -1115,8 +1156,10 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
     }
 }
 
-impl<const N_SNARKS: usize> From<&BatchHash<N_SNARKS>> for BatchData<N_SNARKS> {
-    fn from(batch_hash: &BatchHash<N_SNARKS>) -> Self {
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> From<&BatchHash<N_SNARKS, B>>
+    for BatchData<N_SNARKS>
+{
+    fn from(batch_hash: &BatchHash<N_SNARKS, B>) -> Self {
         Self::new(
             batch_hash.number_of_valid_chunks,
             &batch_hash.chunks_with_padding,
@@ -1171,10 +1214,10 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
     /// The number of rows in Blob Data config's layout to represent the "digest rlc" section.
     /// - metadata digest RLC (1 row)
     /// - chunk_digests RLC for each chunk (MAX_AGG_SNARKS rows)
-    /// - blob versioned hash RLC (1 row)
-    /// - challenge digest RLC (1 row)
+    /// - blob versioned hash RLC for each blob (MAX_BLOBS rows)
+    /// - challenge digest RLC for each blob (MAX_BLOBS rows)
     pub const fn n_rows_digest_rlc() -> usize {
-        1 + N_SNARKS + 1 + 1
+        1 + N_SNARKS + MAX_BLOBS + MAX_BLOBS
     }
 
     /// The number of rows in Blob Data config's layout to represent the "digest bytes" section.
@@ -1253,7 +1296,7 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
         }
     }
 
-    /// Get the preimage of the challenge digest.
+    /// Get the preimage of the challenge digest of the blob with the given versioned hash.
     pub(crate) fn get_challenge_digest_preimage(&self, versioned_hash: H256) -> Vec<u8> {
         let metadata_digest = keccak256(self.to_metadata_bytes());
         let chunk_digests = self.chunk_data.iter().map(keccak256);
@@ -1273,7 +1316,7 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
             .collect::<Vec<_>>()
     }
 
-    /// Compute the challenge digest from blob bytes.
+    /// Compute the challenge digest of the blob with the given versioned hash.
     pub(crate) fn get_challenge_digest(&self, versioned_hash: H256) -> U256 {
         let challenge_digest = keccak256(self.get_challenge_digest_preimage(versioned_hash));
         U256::from_big_endian(&challenge_digest)
@@ -1296,9 +1339,9 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
 
     /// Get the list of preimages that need to go through the keccak hashing function, and
     /// eventually required to be checked for the consistency of blob's metadata, its chunks' bytes
-    /// and the final blob preimage.
-    pub fn preimages(&self, versioned_hash: H256) -> Vec<Vec<u8>> {
-        let mut preimages = Vec::with_capacity(2 + N_SNARKS);
+    /// and the final blob preimages.
+    pub fn preimages(&self, versioned_hashes: &[H256]) -> Vec<Vec<u8>> {
+        let mut preimages = Vec::with_capacity(1 + N_SNARKS + MAX_BLOBS);
 
         // metadata
         preimages.push(self.to_metadata_bytes());
@@ -1308,8 +1351,10 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
             preimages.push(chunk.to_vec());
         }
 
-        // preimage for challenge digest
-        preimages.push(self.get_challenge_digest_preimage(versioned_hash));
+        // preimage for each blob's challenge digest
+        for &versioned_hash in versioned_hashes.iter() {
+            preimages.push(self.get_challenge_digest_preimage(versioned_hash));
+        }
 
         preimages
     }
@@ -1317,7 +1362,7 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
     /// Get the witness rows for assignment to the BlobDataConfig.
     fn to_rows(
         &self,
-        versioned_hash: H256,
+        versioned_hashes: &[H256],
         challenge: Challenges<Value<Fr>>,
     ) -> Vec<BatchDataRow<Fr>> {
         let metadata_rows = self.to_metadata_rows(challenge);
@@ -1326,7 +1371,7 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
         let data_rows = self.to_data_rows(challenge);
         assert_eq!(data_rows.len(), Self::n_rows_data());
 
-        let digest_rows = self.to_digest_rows(versioned_hash, challenge);
+        let digest_rows = self.to_digest_rows(versioned_hashes, challenge);
         assert_eq!(digest_rows.len(), Self::n_rows_digest());
 
         metadata_rows
@@ -1465,9 +1510,11 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
     /// Get the witness rows for both "digest rlc" and "digest bytes" sections of Blob data config.
     fn to_digest_rows(
         &self,
-        versioned_hash: H256,
+        versioned_hashes: &[H256],
         challenge: Challenges<Value<Fr>>,
     ) -> Vec<BatchDataRow<Fr>> {
+        assert_eq!(versioned_hashes.len(), MAX_BLOBS);
+
         let zero = Value::known(Fr::zero());
 
         // metadata
@@ -1493,30 +1540,56 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
             })
             .unzip();
 
-        // challenge digest
-        let challenge_digest_preimage = self.get_challenge_digest_preimage(versioned_hash);
-        let challenge_digest_preimage_rlc =
-            challenge_digest_preimage.iter().fold(zero, |acc, &byte| {
-                acc * challenge.keccak_input() + Value::known(Fr::from(byte as u64))
-            });
-        let challenge_digest = keccak256(&challenge_digest_preimage);
-        let challenge_digest_rlc = challenge_digest.iter().fold(zero, |acc, &byte| {
-            acc * challenge.evm_word() + Value::known(Fr::from(byte as u64))
+        // blob versioned hashes
+        let versioned_hash_rlcs = versioned_hashes.iter().map(|versioned_hash| {
+            versioned_hash.as_bytes().iter().fold(zero, |acc, &byte| {
+                acc * challenge.evm_word() + Value::known(Fr::from(byte as u64))
+            })
         });
 
-        // blob versioned hash
-        let versioned_hash_rlc = versioned_hash.as_bytes().iter().fold(zero, |acc, &byte| {
-            acc * challenge.evm_word() + Value::known(Fr::from(byte as u64))
-        });
+        // challenge digests
+        let (challenge_digests, challenge_digest_rows): (Vec<[u8; 32]>, Vec<BatchDataRow<Fr>>) =
+            versioned_hashes
+                .iter()
+                .map(|&versioned_hash| {
+                    let challenge_digest_preimage =
+                        self.get_challenge_digest_preimage(versioned_hash);
+                    let challenge_digest_preimage_rlc =
+                        challenge_digest_preimage.iter().fold(zero, |acc, &byte| {
+                            acc * challenge.keccak_input() + Value::known(Fr::from(byte as u64))
+                        });
+                    let challenge_digest = keccak256(&challenge_digest_preimage);
+                    let challenge_digest_rlc = challenge_digest.iter().fold(zero, |acc, &byte| {
+                        acc * challenge.evm_word() + Value::known(Fr::from(byte as u64))
+                    });
+                    (
+                        challenge_digest,
+                        BatchDataRow {
+                            preimage_rlc: challenge_digest_preimage_rlc,
+                            digest_rlc: challenge_digest_rlc,
+                            accumulator: 32 * (N_SNARKS + 1 + 1) as u64,
+                            is_boundary: true,
+                            ..Default::default()
+                        },
+                    )
+                })
+                .unzip();
+
+        let digest_byte_row = |byte: u8| BatchDataRow {
+            preimage_rlc: Value::known(Fr::zero()),
+            digest_rlc: Value::known(Fr::zero()),
+            byte,
+            ..Default::default()
+        };
 
         // - metadata digest rlc
         // - chunks[i].chunk_data_digest rlc for each chunk
-        // - versioned hash rlc
-        // - challenge digest rlc
+        // - versioned hash rlc for each blob
+        // - challenge digest rlc for each blob
         // - metadata digest bytes
         // - chunks[i].chunk_data_digest bytes for each chunk
-        // - versioned hash bytes
-        // - challenge digest bytes
+        // - versioned hash bytes for each blob
+        // - challenge digest bytes for each blob
         once(BatchDataRow {
             preimage_rlc: Value::known(Fr::zero()),
             digest_rlc: metadata_digest_rlc,
@@ -1539,46 +1612,32 @@ impl<const N_SNARKS: usize> BatchData<N_SNARKS> {
                     ..Default::default()
                 }),
         )
-        // versioned hash RLC
-        .chain(once(BatchDataRow {
+        // versioned hash RLCs
+        .chain(versioned_hash_rlcs.map(|digest_rlc| BatchDataRow {
             preimage_rlc: Value::known(Fr::zero()),
-            digest_rlc: versioned_hash_rlc,
-            ..Default::default()
-        }))
-        .chain(once(BatchDataRow {
-            preimage_rlc: challenge_digest_preimage_rlc,
-            digest_rlc: challenge_digest_rlc,
-            accumulator: 32 * (N_SNARKS + 1 + 1) as u64,
-            is_boundary: true,
-            ..Default::default()
-        }))
-        .chain(metadata_digest.iter().map(|&byte| BatchDataRow {
-            preimage_rlc: Value::known(Fr::zero()),
-            digest_rlc: Value::known(Fr::zero()),
-            byte,
-            ..Default::default()
-        }))
-        .chain(chunk_digests.iter().flat_map(|digest| {
-            digest.iter().map(|&byte| BatchDataRow {
-                preimage_rlc: Value::known(Fr::zero()),
-                digest_rlc: Value::known(Fr::zero()),
-                byte,
-                ..Default::default()
-            })
-        }))
-        // bytes of versioned hash
-        .chain(versioned_hash.as_bytes().iter().map(|&byte| BatchDataRow {
-            preimage_rlc: Value::known(Fr::zero()),
-            digest_rlc: Value::known(Fr::zero()),
-            byte,
-            ..Default::default()
-        }))
-        .chain(challenge_digest.iter().map(|&byte| BatchDataRow {
-            preimage_rlc: Value::known(Fr::zero()),
-            digest_rlc: Value::known(Fr::zero()),
-            byte,
+            digest_rlc,
             ..Default::default()
         }))
+        .chain(challenge_digest_rows)
+        .chain(metadata_digest.iter().map(|&byte| digest_byte_row(byte)))
+        .chain(
+            chunk_digests
+                .iter()
+                .flat_map(|digest| digest.iter().map(|&byte| digest_byte_row(byte))),
+        )
+        // bytes of versioned hashes
+        .chain(
+            versioned_hashes
+                .iter()
+                .flat_map(|versioned_hash| versioned_hash.as_bytes().iter())
+                .map(|&byte| digest_byte_row(byte)),
+        )
+        .chain(
+            challenge_digests
+                .iter()
+                .flatten()
+                .map(|&byte| digest_byte_row(byte)),
+        )
         .collect()
     }
 }
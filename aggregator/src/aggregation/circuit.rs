@@ -32,18 +32,24 @@ use zkevm_circuits::util::Challenges;
 use crate::{
     aggregation::{decoder::WORKED_EXAMPLE, witgen::process, BatchCircuitConfig, BatchData},
     batch::BatchHash,
-    blob_consistency::BlobConsistencyConfig,
+    blob_consistency::{segment_blob_bytes, BlobConsistency, DefaultBlobConsistency, BLOB_WIDTH},
     constants::{ACC_LEN, DIGEST_LEN},
     core::{assign_batch_hashes, extract_proof_and_instances_with_pairing_check},
     util::parse_hash_digest_cells,
     witgen::{zstd_encode, MultiBlockProcessResult},
-    ConfigParams, FixedProtocol, LOG_DEGREE, PI_CHAIN_ID, PI_CURRENT_BATCH_HASH,
+    ConfigParams, FixedProtocol, LOG_DEGREE, MAX_BLOBS, PI_CHAIN_ID, PI_CURRENT_BATCH_HASH,
     PI_CURRENT_STATE_ROOT, PI_CURRENT_WITHDRAW_ROOT, PI_PARENT_BATCH_HASH, PI_PARENT_STATE_ROOT,
 };
 
 /// Batch circuit, the chunk aggregation routine below recursion circuit
+///
+/// The circuit is generic over the [`BlobConsistency`] check, i.e. over how the blob used as advice
+/// is tied to the data availability layer.
 #[derive(Clone)]
-pub struct BatchCircuit<const N_SNARKS: usize> {
+pub struct BatchCircuit<
+    const N_SNARKS: usize,
+    B: BlobConsistency<N_SNARKS> = DefaultBlobConsistency,
+> {
     pub svk: KzgSuccinctVerifyingKey<G1Affine>,
     // the input snarks for the aggregation circuit
     // it is padded already so it will have a fixed length of N_SNARKS
@@ -61,7 +67,7 @@ pub struct BatchCircuit<const N_SNARKS: usize> {
     pub as_proof: Value<Vec<u8>>,
     // batch hash circuit for which the snarks are generated
     // the chunks in this batch are also padded already
-    pub batch_hash: BatchHash<N_SNARKS>,
+    pub batch_hash: BatchHash<N_SNARKS, B>,
 
     /// The SNARK protocol from the halo2-based inner circuit route.
     pub halo2_protocol: FixedProtocol,
@@ -69,12 +75,12 @@ pub struct BatchCircuit<const N_SNARKS: usize> {
     pub sp1_protocol: FixedProtocol,
 }
 
-impl<const N_SNARKS: usize> BatchCircuit<N_SNARKS> {
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> BatchCircuit<N_SNARKS, B> {
     pub fn new<P: Into<FixedProtocol>>(
         params: &ParamsKZG<Bn256>,
         snarks_with_padding: &[Snark],
         rng: impl Rng + Send,
-        batch_hash: BatchHash<N_SNARKS>,
+        batch_hash: BatchHash<N_SNARKS, B>,
         halo2_protocol: P,
         sp1_protocol: P,
     ) -> Result<Self, snark_verifier::Error> {
@@ -144,8 +150,10 @@ impl<const N_SNARKS: usize> BatchCircuit<N_SNARKS> {
     }
 }
 
-impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
-    type Config = (BatchCircuitConfig<N_SNARKS>, Challenges);
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> Circuit<Fr>
+    for BatchCircuit<N_SNARKS, B>
+{
+    type Config = (BatchCircuitConfig<N_SNARKS, B>, Challenges);
     type FloorPlanner = SimpleFloorPlanner;
     type Params = ();
 
@@ -360,11 +368,19 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
 
                     ctx.print_stats(&["protocol check"]);
 
-                    let barycentric = config.blob_consistency_config.assign_barycentric(
-                        &mut ctx,
-                        &self.batch_hash.blob_bytes,
-                        self.batch_hash.blob_consistency_witness.challenge_digest(),
-                    );
+                    // evaluate each blob's polynomial at its own challenge
+                    let barycentric = segment_blob_bytes(&self.batch_hash.blob_bytes)
+                        .iter()
+                        .zip_eq(self.batch_hash.blob_consistency_witnesses.iter())
+                        .map(|(blob_bytes, blob_consistency_witness)| {
+                            B::assign_barycentric(
+                                &config.blob_consistency_config,
+                                &mut ctx,
+                                blob_bytes,
+                                blob_consistency_witness.challenge_digest(),
+                            )
+                        })
+                        .collect::<Vec<_>>();
 
                     ctx.print_stats(&["barycentric"]);
 
@@ -399,11 +415,11 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
             // - batch_data_hash_preimage
             // - preimage for blob metadata
             // - preimage of chunk data digest (only for valid chunks)
-            // - preimage of challenge digest
+            // - preimage of challenge digest of each blob
             let preimages = self.batch_hash.extract_hash_preimages();
             assert_eq!(
                 preimages.len(),
-                4 + N_SNARKS + self.batch_hash.number_of_valid_chunks,
+                3 + MAX_BLOBS + N_SNARKS + self.batch_hash.number_of_valid_chunks,
                 "error extracting preimages"
             );
             end_timer!(timer);
@@ -509,24 +525,33 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
 
         // blob data config
         {
-            let barycentric_assignments = &barycentric.barycentric_assignments;
-            let challenge_le = &barycentric.z_le;
-            let evaluation_le = &barycentric.y_le;
+            let barycentric_assignments = barycentric
+                .iter()
+                .map(|barycentric| barycentric.barycentric_assignments.as_slice())
+                .collect::<Vec<_>>();
 
             let batch_data = BatchData::from(&self.batch_hash);
 
-            let blob_data_exports = config.blob_consistency_config.assign_blob_data(
+            let blob_data_exports = B::assign_blob_data(
+                &config.blob_consistency_config,
                 &mut layouter,
                 challenges,
                 &config.rlc_config,
                 &self.batch_hash.blob_bytes,
             )?;
 
-            BlobConsistencyConfig::<N_SNARKS>::link(
-                &mut layouter,
-                &blob_data_exports.blob_crts_limbs,
-                barycentric_assignments,
-            )?;
+            for (blob_crts_limbs, barycentric_assignments) in blob_data_exports
+                .blob_crts_limbs
+                .chunks(BLOB_WIDTH)
+                .zip_eq(barycentric_assignments.iter())
+            {
+                B::link(&mut layouter, blob_crts_limbs, barycentric_assignments)?;
+            }
+
+            let versioned_hashes = self
+                .batch_hash
+                .blob_consistency_witnesses
+                .map(|blob_consistency_witness| blob_consistency_witness.id());
 
             let batch_data_exports = config.batch_data_config.assign(
                 &mut layouter,
@@ -534,8 +559,8 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
                 &config.rlc_config,
                 &assigned_batch_hash.chunks_are_padding,
                 &batch_data,
-                self.batch_hash.blob_consistency_witness.id(),
-                barycentric_assignments,
+                &versioned_hashes,
+                &barycentric_assignments,
             )?;
 
             // conditionally encode those bytes. By default we use a worked example.
@@ -550,6 +575,7 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
                 witness_rows,
                 literal_bytes: decoded_literals,
                 fse_aux_tables,
+                huffman_codes,
                 block_info_arr,
                 sequence_info_arr,
                 address_table_rows: address_table_arr,
@@ -579,6 +605,7 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
                 witness_rows,
                 decoded_literals,
                 fse_aux_tables,
+                huffman_codes,
                 block_info_arr,
                 sequence_info_arr,
                 address_table_arr,
@@ -613,29 +640,72 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
                         }
                     }
 
-                    for (c, ec) in evaluation_le
+                    // a blob is committed to in the batch hash iff it carries data, i.e. iff
+                    // its first row is not padded.
+                    for (blob_is_valid, blob_is_padding) in assigned_batch_hash
+                        .blob
+                        .blobs_are_valid
                         .iter()
-                        .zip_eq(assigned_batch_hash.blob.y.iter().rev())
+                        .zip_eq(blob_data_exports.blobs_are_padding.iter())
                     {
-                        log::trace!("blob y: {:?} {:?}", c.value(), ec.value());
-                        region.constrain_equal(c.cell(), ec.cell())?;
+                        let blob_is_not_padding = config.rlc_config.not(
+                            &mut region,
+                            blob_is_padding,
+                            &mut rlc_config_offset,
+                        )?;
+                        region.constrain_equal(blob_is_valid.cell(), blob_is_not_padding.cell())?;
                     }
 
-                    for (c, ec) in challenge_le
+                    // the blobs that carry no data are left out of the batch hash, hence only
+                    // the valid blobs are checked against it.
+                    for (i, (blob_barycentric, blob_is_valid)) in barycentric
                         .iter()
-                        .zip_eq(assigned_batch_hash.blob.z.iter().rev())
+                        .zip_eq(assigned_batch_hash.blob.blobs_are_valid.iter())
+                        .enumerate()
                     {
-                        log::trace!("blob z: {:?} {:?}", c.value(), ec.value());
-                        region.constrain_equal(c.cell(), ec.cell())?;
-                    }
+                        for (c, ec) in blob_barycentric
+                            .y_le
+                            .iter()
+                            .zip_eq(assigned_batch_hash.blob.y[i].iter().rev())
+                        {
+                            log::trace!("blob[{i}] y: {:?} {:?}", c.value(), ec.value());
+                            config.rlc_config.conditional_enforce_equal(
+                                &mut region,
+                                c,
+                                ec,
+                                blob_is_valid,
+                                &mut rlc_config_offset,
+                            )?;
+                        }
 
-                    for (c, ec) in batch_data_exports
-                        .versioned_hash
-                        .iter()
-                        .zip_eq(assigned_batch_hash.blob.versioned_hash.iter())
-                    {
-                        log::trace!("blob version hash: {:?} {:?}", c.value(), ec.value());
-                        region.constrain_equal(c.cell(), ec.cell())?;
+                        for (c, ec) in blob_barycentric
+                            .z_le
+                            .iter()
+                            .zip_eq(assigned_batch_hash.blob.z[i].iter().rev())
+                        {
+                            log::trace!("blob[{i}] z: {:?} {:?}", c.value(), ec.value());
+                            config.rlc_config.conditional_enforce_equal(
+                                &mut region,
+                                c,
+                                ec,
+                                blob_is_valid,
+                                &mut rlc_config_offset,
+                            )?;
+                        }
+
+                        for (c, ec) in batch_data_exports.versioned_hashes[i]
+                            .iter()
+                            .zip_eq(assigned_batch_hash.blob.versioned_hashes[i].iter())
+                        {
+                            log::trace!("blob[{i}] version hash: {:?} {:?}", c.value(), ec.value());
+                            config.rlc_config.conditional_enforce_equal(
+                                &mut region,
+                                c,
+                                ec,
+                                blob_is_valid,
+                                &mut rlc_config_offset,
+                            )?;
+                        }
                     }
 
                     // do we encode batch data to blob? or not.
@@ -777,7 +847,9 @@ impl<const N_SNARKS: usize> Circuit<Fr> for BatchCircuit<N_SNARKS> {
     }
 }
 
-impl<const N_SNARKS: usize> CircuitExt<Fr> for BatchCircuit<N_SNARKS> {
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> CircuitExt<Fr>
+    for BatchCircuit<N_SNARKS, B>
+{
     fn num_instance(&self) -> Vec<usize> {
         // - 12 elements from accumulator
         // - parent_state_root (2 elements, split hi_lo)
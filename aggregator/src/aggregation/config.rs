@@ -17,7 +17,7 @@ use zkevm_circuits::{
 };
 
 use crate::{
-    blob_consistency::BlobConsistencyConfig,
+    blob_consistency::{BlobConsistency, DefaultBlobConsistency},
     constants::{BITS, LIMBS},
     param::ConfigParams,
     BatchDataConfig, DecoderConfig, DecoderConfigArgs, RlcConfig,
@@ -27,7 +27,7 @@ use crate::{
 #[rustfmt::skip]
 /// Configurations for batch circuit.
 /// This config is hard coded for BN256 curve.
-pub struct BatchCircuitConfig<const N_SNARKS: usize> {
+pub struct BatchCircuitConfig<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS> = DefaultBlobConsistency> {
     /// Non-native field chip configurations
     pub base_field_config: FpConfig<Fr, Fq>,
     /// Keccak circuit configurations
@@ -39,7 +39,7 @@ pub struct BatchCircuitConfig<const N_SNARKS: usize> {
     /// The zstd decoder's config.
     pub decoder_config: DecoderConfig<1024, 512>,
     /// Config check witness blob matches commitment to blob obtained from data availability provider.
-    pub blob_consistency_config: BlobConsistencyConfig<N_SNARKS>,
+    pub blob_consistency_config: B::Config,
     /// Instance for public input; stores
     /// - accumulator from aggregation (12 elements)
     /// - chain id (1 element)
@@ -51,7 +51,7 @@ pub struct BatchCircuitConfig<const N_SNARKS: usize> {
     pub instance: Column<Instance>,
 }
 
-impl<const N_SNARKS: usize> BatchCircuitConfig<N_SNARKS> {
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> BatchCircuitConfig<N_SNARKS, B> {
     /// Build a configuration from parameters.
     pub fn configure(
         meta: &mut ConstraintSystem<Fr>,
@@ -127,7 +127,7 @@ impl<const N_SNARKS: usize> BatchCircuitConfig<N_SNARKS> {
         let pow_rand_table = PowOfRandTable::construct(meta, &challenges_expr);
 
         // Blob consistency.
-        let blob_consistency_config = BlobConsistencyConfig::construct(
+        let blob_consistency_config = B::configure(
             meta,
             &challenges_expr,
             u8_table,
@@ -205,6 +205,12 @@ impl<const N_SNARKS: usize> BatchCircuitConfig<N_SNARKS> {
 
 #[test]
 fn batch_circuit_degree() {
+    batch_circuit_degree_for::<crate::blob_consistency::Eip4844>();
+    batch_circuit_degree_for::<crate::blob_consistency::Avail>();
+}
+
+#[cfg(test)]
+fn batch_circuit_degree_for<B: BlobConsistency<{ crate::constants::MAX_AGG_SNARKS }>>() {
     use halo2_ecc::fields::fp::FpStrategy;
     let mut cs = ConstraintSystem::<Fr>::default();
     let param = ConfigParams {
@@ -218,7 +224,7 @@ fn batch_circuit_degree() {
         num_limbs: 3,
     };
     let challenges = Challenges::construct_p1(&mut cs);
-    BatchCircuitConfig::<{ crate::constants::MAX_AGG_SNARKS }>::configure(
+    BatchCircuitConfig::<{ crate::constants::MAX_AGG_SNARKS }, B>::configure(
         &mut cs, &param, challenges,
     );
     cs = cs.chunk_lookups();
@@ -1,3 +1,4 @@
+mod circuit;
 mod seq_exec;
 mod tables;
 pub mod witgen;
@@ -27,17 +28,20 @@ use zkevm_circuits::{
 
 use self::{
     tables::{
-        BitstringTable, FixedLookupTag, FixedTable, FseTable, LiteralsHeaderTable,
-        SeqInstTable as SequenceInstructionTable,
+        BitstringTable, FixedLookupTag, FixedTable, FseTable, HuffmanCodesTable,
+        LiteralsHeaderTable, SeqInstTable as SequenceInstructionTable,
     },
     witgen::{
-        util::value_bits_le, AddressTableRow, BlockInfo, FseAuxiliaryTableData, FseTableKind,
-        SequenceExec, SequenceInfo, ZstdTag, ZstdWitnessRow, N_BITS_PER_BYTE, N_BITS_REPEAT_FLAG,
-        N_BITS_ZSTD_TAG, N_BLOCK_HEADER_BYTES, N_BLOCK_SIZE_TARGET,
+        util::value_bits_le, AddressTableRow, BlockInfo, CompressionMode, FseAuxiliaryTableData,
+        FseTableKind, HuffmanCodesData, SequenceExec, SequenceInfo, ZstdTag, ZstdWitnessRow,
+        N_BITS_PER_BYTE, N_BITS_REPEAT_FLAG, N_BITS_ZSTD_TAG, N_BLOCK_HEADER_BYTES,
+        N_BLOCK_SIZE_TARGET, N_MAX_LITERAL_HEADER_BYTES,
     },
 };
 use super::util::BooleanAdvice;
 
+pub use circuit::{ZstdDecoderCircuit, ZstdDecoderCircuitConfig};
+
 use seq_exec::{LiteralTable, SeqExecConfig as SequenceExecutionConfig, SequenceConfig};
 
 /// The raw text from < https://nigeltao.github.io/blog/2022/romeo.txt >
@@ -77,6 +81,8 @@ pub struct DecoderConfig<const L: usize, const R: usize> {
     fse_decoder: FseDecoder,
     /// Config required while applying the FSE tables on the Sequences data.
     sequences_data_decoder: SequencesDataDecoder,
+    /// Config required while decoding the literals, raw or Huffman-coded.
+    literals_decoder: LiteralsDecoder,
     /// Range Table for [0, 8).
     range8: RangeTable<8>,
     /// Range Table for [0, 16).
@@ -101,6 +107,8 @@ pub struct DecoderConfig<const L: usize, const R: usize> {
     bitstring_table_3: BitstringTable<3>,
     /// Helper table for decoding FSE tables.
     fse_table: FseTable<L, R>,
+    /// Helper table for the canonical Huffman codes.
+    huffman_codes_table: HuffmanCodesTable,
     /// Helper table for sequences as instructions.
     sequence_instruction_table: SequenceInstructionTable<Fr>,
     /// Helper table in the "output" region for accumulating the result of executing sequences.
@@ -164,8 +172,16 @@ struct TagConfig {
     is_literals_header: Column<Advice>,
     /// Degree reduction: SequencesHeader
     is_sequence_header: Column<Advice>,
-    /// Degree reduction: SequenceFseCode
+    /// Degree reduction: SequenceFseCode or HuffmanFseCode, except a SequenceFseCode in RLE_Mode
     is_fse_code: Column<Advice>,
+    /// Degree reduction: SequenceFseCode in RLE_Mode
+    is_rle_fse_code: Column<Advice>,
+    /// Degree reduction: HuffmanFseCode
+    is_huffman_fse_code: Column<Advice>,
+    /// Degree reduction: HuffmanCode
+    is_huffman_code: Column<Advice>,
+    /// Degree reduction: Lstream
+    is_lstream: Column<Advice>,
     /// Degree reduction: SequencesData
     is_sequence_data: Column<Advice>,
     /// Degree reduction: Null
@@ -204,12 +220,31 @@ impl TagConfig {
             is_literals_header: meta.advice_column(),
             is_sequence_header: meta.advice_column(),
             is_fse_code: meta.advice_column(),
+            is_rle_fse_code: meta.advice_column(),
+            is_huffman_fse_code: meta.advice_column(),
+            is_huffman_code: meta.advice_column(),
+            is_lstream: meta.advice_column(),
             is_sequence_data: meta.advice_column(),
             is_null: meta.advice_column(),
         }
     }
 }
 
+impl TagConfig {
+    /// Whether the current tag is a reversed bitstream in the literals section, i.e. the
+    /// FSE-compressed Huffman weights or a Huffman-coded literal stream.
+    fn is_huffman_bitstream(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        sum::expr([
+            meta.query_advice(self.is_huffman_code, rotation),
+            meta.query_advice(self.is_lstream, rotation),
+        ])
+    }
+}
+
 #[derive(Clone, Debug)]
 struct BlockConfig {
     /// The number of bytes in this block.
@@ -229,15 +264,24 @@ struct BlockConfig {
     /// Helper gadget to know if the number of sequences is 0.
     is_empty_sequences: IsEqualConfig<Fr>,
     /// For sequence decoding, the tag=ZstdBlockSequenceHeader bytes tell us the Compression_Mode
-    /// utilised for Literals Lengths, Match Offsets and Match Lengths. We expect only 2
-    /// possibilities:
+    /// utilised for Literals Lengths, Match Offsets and Match Lengths. There are 4 possibilities:
     /// 1. Predefined_Mode (value=0)
-    /// 2. Fse_Compressed_Mode (value=2)
+    /// 2. RLE_Mode (value=1)
+    /// 3. Fse_Compressed_Mode (value=2)
+    /// 4. Repeat_Mode (value=3)
+    ///
+    /// We take note of the compression mode for each of the above purposes with 3 boolean flags,
+    /// of which at most one is set. The Predefined_Mode is utilised if none of them are set.
     ///
-    /// Which means a single boolean flag is sufficient to take note of which compression mode is
-    /// utilised for each of the above purposes. The boolean flag will be set if we utilise the
-    /// Fse_Compressed_Mode.
+    /// The boolean flag will be set if we utilise the Fse_Compressed_Mode.
     compression_modes: [Column<Advice>; 3],
+    /// The boolean flag will be set if we utilise the RLE_Mode.
+    rle_modes: [Column<Advice>; 3],
+    /// The boolean flag will be set if we utilise the Repeat_Mode.
+    repeat_modes: [Column<Advice>; 3],
+    /// The most recent previous block that decoded sequences. The FSE tables in Repeat_Mode are
+    /// the ones utilised by this block.
+    repeat_block_idx: Column<Advice>,
 }
 
 impl BlockConfig {
@@ -261,32 +305,72 @@ impl BlockConfig {
                 meta.advice_column(),
                 meta.advice_column(),
             ],
+            rle_modes: [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ],
+            repeat_modes: [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ],
+            repeat_block_idx: meta.advice_column(),
         }
     }
 }
 
 impl BlockConfig {
+    /// Whether the table at the given index (LLT=0, MOT=1, MLT=2) utilises the Predefined_Mode.
+    fn is_predefined_mode(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        idx: usize,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        not::expr(sum::expr([
+            meta.query_advice(self.compression_modes[idx], rotation),
+            meta.query_advice(self.rle_modes[idx], rotation),
+            meta.query_advice(self.repeat_modes[idx], rotation),
+        ]))
+    }
+
+    /// Whether the table at the given index (LLT=0, MOT=1, MLT=2) is described in the sequences
+    /// section, i.e. it utilises the Fse_Compressed_Mode or the RLE_Mode.
+    fn is_described_mode(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        idx: usize,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        sum::expr([
+            meta.query_advice(self.compression_modes[idx], rotation),
+            meta.query_advice(self.rle_modes[idx], rotation),
+        ])
+    }
+
     fn is_predefined_llt(&self, meta: &mut VirtualCells<Fr>, rotation: Rotation) -> Expression<Fr> {
-        not::expr(meta.query_advice(self.compression_modes[0], rotation))
+        self.is_predefined_mode(meta, 0, rotation)
     }
 
     fn is_predefined_mot(&self, meta: &mut VirtualCells<Fr>, rotation: Rotation) -> Expression<Fr> {
-        not::expr(meta.query_advice(self.compression_modes[1], rotation))
+        self.is_predefined_mode(meta, 1, rotation)
     }
 
     fn is_predefined_mlt(&self, meta: &mut VirtualCells<Fr>, rotation: Rotation) -> Expression<Fr> {
-        not::expr(meta.query_advice(self.compression_modes[2], rotation))
+        self.is_predefined_mode(meta, 2, rotation)
     }
 
-    fn are_predefined_all(
+    /// None of the LLT, MOT and MLT tables are described in the sequences section.
+    fn are_described_none(
         &self,
         meta: &mut VirtualCells<Fr>,
         rotation: Rotation,
     ) -> Expression<Fr> {
         and::expr([
-            self.is_predefined_llt(meta, rotation),
-            self.is_predefined_mot(meta, rotation),
-            self.is_predefined_mlt(meta, rotation),
+            not::expr(self.is_described_mode(meta, 0, rotation)),
+            not::expr(self.is_described_mode(meta, 1, rotation)),
+            not::expr(self.is_described_mode(meta, 2, rotation)),
         ])
     }
 
@@ -312,6 +396,48 @@ impl BlockConfig {
         ])
     }
 
+    /// Whether the FSE table of the table_kind at this row utilises the given compression modes.
+    fn is_table_mode(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        fse_decoder: &FseDecoder,
+        modes: [Column<Advice>; 3],
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        sum::expr([
+            and::expr([
+                fse_decoder.is_llt(meta, rotation),
+                meta.query_advice(modes[0], rotation),
+            ]),
+            and::expr([
+                fse_decoder.is_mlt(meta, rotation),
+                meta.query_advice(modes[2], rotation),
+            ]),
+            and::expr([
+                fse_decoder.is_mot(meta, rotation),
+                meta.query_advice(modes[1], rotation),
+            ]),
+        ])
+    }
+
+    fn is_rle(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        fse_decoder: &FseDecoder,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        self.is_table_mode(meta, fse_decoder, self.rle_modes, rotation)
+    }
+
+    fn is_repeat(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        fse_decoder: &FseDecoder,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        self.is_table_mode(meta, fse_decoder, self.repeat_modes, rotation)
+    }
+
     fn is_empty_sequences(
         &self,
         meta: &mut VirtualCells<Fr>,
@@ -725,13 +851,15 @@ impl BitstreamDecoder {
 #[derive(Clone, Debug)]
 pub struct FseDecoder {
     /// The FSE table that is being decoded in this tag. Possible values are:
-    /// - LLT = 1, MOT = 2, MLT = 3
+    /// - LLT = 1, MOT = 2, MLT = 3, HUF = 4
     table_kind: Column<Advice>,
     /// The number of states in the FSE table. table_size == 1 << AL, where AL is the accuracy log
     /// of the FSE table.
     table_size: Column<Advice>,
     /// If the table_kind at this row is predefined table.
     is_predefined: Column<Advice>,
+    /// If the table_kind at this row is a table repeated from a previous block.
+    is_repeat: Column<Advice>,
     /// The incremental symbol for which probability is decoded.
     symbol: Column<Advice>,
     /// The value decoded as per variable bit-packing.
@@ -759,6 +887,7 @@ impl FseDecoder {
         meta: &mut ConstraintSystem<Fr>,
         block_config: &BlockConfig,
         is_fse_code: Column<Advice>,
+        is_huffman_fse_code: Column<Advice>,
         is_sequence_data: Column<Advice>,
         is_change: BooleanAdvice,
         q_enable: Column<Fixed>,
@@ -769,6 +898,7 @@ impl FseDecoder {
             table_kind: meta.advice_column(),
             table_size: meta.advice_column(),
             is_predefined: meta.advice_column(),
+            is_repeat: meta.advice_column(),
             symbol: meta.advice_column(),
             value_decoded,
             probability_acc: meta.advice_column(),
@@ -808,10 +938,24 @@ impl FseDecoder {
 
             let mut cb = BaseConstraintBuilder::default();
 
+            // The FSE table for Huffman weights is never predefined.
             cb.require_equal(
                 "is_predefined value",
                 meta.query_advice(fse_decoder.is_predefined, Rotation::cur()),
-                block_config.is_predefined(meta, &fse_decoder, Rotation::cur()),
+                and::expr([
+                    not::expr(meta.query_advice(is_huffman_fse_code, Rotation::cur())),
+                    block_config.is_predefined(meta, &fse_decoder, Rotation::cur()),
+                ]),
+            );
+
+            // The FSE table for Huffman weights is never repeated.
+            cb.require_equal(
+                "is_repeat value",
+                meta.query_advice(fse_decoder.is_repeat, Rotation::cur()),
+                and::expr([
+                    not::expr(meta.query_advice(is_huffman_fse_code, Rotation::cur())),
+                    block_config.is_repeat(meta, &fse_decoder, Rotation::cur()),
+                ]),
             );
 
             cb.gate(condition)
@@ -1050,6 +1194,93 @@ impl SequencesDataDecoder {
 }
 
 #[allow(dead_code)]
+/// Fields used while decoding the literals section, i.e. the raw bytes or the Huffman-coded
+/// literal streams, along with the Huffman weights that describe the Huffman tree.
+#[derive(Clone, Debug)]
+pub struct LiteralsDecoder {
+    /// Whether a literal is emitted at this row.
+    is_literal: BooleanAdvice,
+    /// The number of literals emitted so far in the block. At the row where a literal is emitted,
+    /// this is the 1-indexed position of the literal.
+    literal_idx: Column<Advice>,
+    /// The literal emitted at this row.
+    literal: Column<Advice>,
+    /// Whether the literal emitted at this row is the last literal in the block.
+    is_last_literal: BooleanAdvice,
+    /// Helper gadget to know when literal_idx == regen_size.
+    literal_idx_eq_regen_size: IsEqualConfig<Fr>,
+    /// The 0-indexed literal stream being decoded.
+    lstream_idx: Column<Advice>,
+    /// The block in which the Huffman tree used to decode the literal streams was described.
+    huffman_block_idx: Column<Advice>,
+    /// Whether a Huffman weight is decoded at this row.
+    is_weight: BooleanAdvice,
+    /// The FSE state reached after the read operation at this row, while decoding the
+    /// FSE-compressed Huffman weights.
+    weight_state: Column<Advice>,
+    /// The symbol whose Huffman weight is decoded at this row.
+    weight_idx: Column<Advice>,
+    /// The Huffman weight decoded at this row.
+    weight: Column<Advice>,
+    /// The baseline of the FSE state that emitted the weight.
+    weight_baseline: Column<Advice>,
+    /// The number of bits to be read at the FSE state that emitted the weight.
+    weight_nb: Column<Advice>,
+}
+
+impl LiteralsDecoder {
+    fn configure(
+        meta: &mut ConstraintSystem<Fr>,
+        q_enable: Column<Fixed>,
+        regen_size: Column<Advice>,
+    ) -> Self {
+        let literal_idx = meta.advice_column();
+        Self {
+            is_literal: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+            literal_idx,
+            literal: meta.advice_column(),
+            is_last_literal: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+            literal_idx_eq_regen_size: IsEqualChip::configure(
+                meta,
+                |meta| meta.query_fixed(q_enable, Rotation::cur()),
+                |meta| meta.query_advice(literal_idx, Rotation::cur()),
+                |meta| meta.query_advice(regen_size, Rotation::cur()),
+            ),
+            lstream_idx: meta.advice_column(),
+            huffman_block_idx: meta.advice_column(),
+            is_weight: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+            weight_state: meta.advice_column(),
+            weight_idx: meta.advice_column(),
+            weight: meta.advice_column(),
+            weight_baseline: meta.advice_column(),
+            weight_nb: meta.advice_column(),
+        }
+    }
+}
+
+impl LiteralsDecoder {
+    /// Whether the literal index has reached the regenerated size of the block.
+    fn is_literal_idx_eq_regen_size(
+        &self,
+        meta: &mut VirtualCells<Fr>,
+        block_config: &BlockConfig,
+        rotation: Rotation,
+    ) -> Expression<Fr> {
+        let (literal_idx, regen_size) = (
+            meta.query_advice(self.literal_idx, rotation),
+            meta.query_advice(block_config.regen_size, rotation),
+        );
+        self.literal_idx_eq_regen_size
+            .expr_at(meta, rotation, literal_idx, regen_size)
+    }
+}
+
 #[derive(Debug)]
 pub struct AssignedDecoderConfigExports {
     /// The RLC of the zstd encoded bytes, i.e. blob bytes.
@@ -1108,7 +1339,8 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
         let is_padding =
             BooleanAdvice::construct(meta, |meta| meta.query_fixed(q_enable, Rotation::cur()));
         // Helper tables
-        let literals_header_table = LiteralsHeaderTable::configure(meta, q_enable, range8, range16);
+        let literals_header_table =
+            LiteralsHeaderTable::configure(meta, q_enable, range8, range16, range512);
         let bitstring_table_1 = BitstringTable::configure(meta, q_enable, range_block_len);
         let bitstring_table_2 = BitstringTable::configure(meta, q_enable, range_block_len);
         let bitstring_table_3 = BitstringTable::configure(meta, q_enable, range_block_len);
@@ -1122,6 +1354,14 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             pow2_table,
             bitwise_op_table,
         );
+        let huffman_codes_table = HuffmanCodesTable::configure(
+            meta,
+            q_enable,
+            u8_table,
+            range16,
+            range_block_len,
+            pow2_table,
+        );
         let sequence_instruction_table = SequenceInstructionTable::configure(meta);
 
         debug_assert!(meta.degree() <= 9);
@@ -1136,21 +1376,23 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             meta,
             &block_config,
             tag_config.is_fse_code,
+            tag_config.is_huffman_fse_code,
             tag_config.is_sequence_data,
             tag_config.is_change,
             q_enable,
         );
         let sequences_data_decoder = SequencesDataDecoder::configure(meta, q_enable);
+        let literals_decoder = LiteralsDecoder::configure(meta, q_enable, block_config.regen_size);
         let sequence_execution_config = SequenceExecutionConfig::configure(
             meta,
             challenges.keccak_input(),
             &LiteralTable::construct([
                 q_enable.into(),
-                tag_config.tag.into(),
+                literals_decoder.is_literal.column.into(),
                 block_config.block_idx.into(),
-                tag_config.tag_idx.into(),
-                byte.into(),
-                tag_config.is_change.column.into(),
+                literals_decoder.literal_idx.into(),
+                literals_decoder.literal.into(),
+                literals_decoder.is_last_literal.column.into(),
                 is_padding.column.into(),
             ]),
             &sequence_instruction_table,
@@ -1200,6 +1442,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             bitstream_decoder,
             fse_decoder,
             sequences_data_decoder,
+            literals_decoder,
             range8,
             range16,
             range512,
@@ -1212,6 +1455,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             bitstring_table_2,
             bitstring_table_3,
             fse_table,
+            huffman_codes_table,
             lookups_enabled,
 
             sequence_instruction_table,
@@ -1249,13 +1493,22 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
         is_tag!(is_frame_header_descriptor, FrameHeaderDescriptor);
         is_tag!(is_frame_content_size, FrameContentSize);
         is_tag!(is_block_header, BlockHeader);
+        is_tag!(is_raw_block_bytes, RawBlockBytes);
+        is_tag!(is_rle_block_bytes, RleBlockBytes);
         is_tag!(is_zb_literals_header, ZstdBlockLiteralsHeader);
         is_tag!(is_zb_raw_block, ZstdBlockLiteralsRawBytes);
+        is_tag!(is_zb_huffman_header, ZstdBlockHuffmanHeader);
+        is_tag!(is_zb_huffman_fse, ZstdBlockHuffmanFseCode);
+        is_tag!(is_zb_huffman_code, ZstdBlockHuffmanCode);
+        is_tag!(is_zb_jump_table, ZstdBlockJumpTable);
+        is_tag!(is_zb_lstream, ZstdBlockLstream);
         is_tag!(is_zb_sequence_header, ZstdBlockSequenceHeader);
         is_tag!(is_zb_sequence_fse, ZstdBlockSequenceFseCode);
         is_tag!(is_zb_sequence_data, ZstdBlockSequenceData);
 
         is_prev_tag!(is_prev_frame_content_size, FrameContentSize);
+        is_prev_tag!(is_prev_raw_block_bytes, RawBlockBytes);
+        is_prev_tag!(is_prev_rle_block_bytes, RleBlockBytes);
         is_prev_tag!(is_prev_sequence_header, ZstdBlockSequenceHeader);
         is_prev_tag!(is_prev_sequence_data, ZstdBlockSequenceData);
 
@@ -1384,7 +1637,24 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 config.tag_config.is_sequence_header,
                 is_zb_sequence_header(meta)
             );
-            degree_reduction_check!(config.tag_config.is_fse_code, is_zb_sequence_fse(meta));
+            degree_reduction_check!(
+                config.tag_config.is_fse_code,
+                is_zb_sequence_fse(meta) + is_zb_huffman_fse(meta)
+                    - meta.query_advice(config.tag_config.is_rle_fse_code, Rotation::cur())
+            );
+            degree_reduction_check!(
+                config.tag_config.is_rle_fse_code,
+                is_zb_sequence_fse(meta)
+                    * config
+                        .block_config
+                        .is_rle(meta, &config.fse_decoder, Rotation::cur())
+            );
+            degree_reduction_check!(
+                config.tag_config.is_huffman_fse_code,
+                is_zb_huffman_fse(meta)
+            );
+            degree_reduction_check!(config.tag_config.is_huffman_code, is_zb_huffman_code(meta));
+            degree_reduction_check!(config.tag_config.is_lstream, is_zb_lstream(meta));
             degree_reduction_check!(
                 config.tag_config.is_sequence_data,
                 is_zb_sequence_data(meta)
@@ -1492,6 +1762,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config
+                            .tag_config
+                            .is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ])
             );
@@ -1533,7 +1806,10 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                         meta.query_advice(config.tag_config.is_frame_content_size, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_block_header, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_literals_header, Rotation::cur()),
+                        is_raw_block_bytes(meta),
                         is_zb_raw_block(meta),
+                        is_zb_huffman_header(meta),
+                        is_zb_jump_table(meta),
                         meta.query_advice(config.tag_config.is_sequence_header, Rotation::cur()),
                     ]),
                     |cb| {
@@ -1940,6 +2216,10 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 "block_idx == 0 to start",
                 meta.query_advice(config.block_config.block_idx, Rotation::cur()),
             );
+            cb.require_zero(
+                "repeat_block_idx == 0 to start",
+                meta.query_advice(config.block_config.repeat_block_idx, Rotation::cur()),
+            );
 
             cb.gate(condition)
         });
@@ -1975,16 +2255,23 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             let block_type_bit1 = config.bits[1].expr_at(meta, Rotation::cur());
             let block_type_bit2 = config.bits[2].expr_at(meta, Rotation::cur());
 
-            // We expect a Block_Type of Compressed_Block, i.e. Block_Type == 2.
-            cb.require_equal(
-                "Block_Type is Compressed_Block (bit 1)",
-                block_type_bit1,
-                0.expr(),
+            // We expect a Block_Type of Raw_Block (0), RLE_Block (1) or Compressed_Block (2), i.e.
+            // Block_Type != Reserved (3).
+            cb.require_zero(
+                "Block_Type is not Reserved",
+                block_type_bit1.expr() * block_type_bit2.expr(),
             );
+
+            // The block's contents are decoded depending on the Block_Type.
             cb.require_equal(
-                "Block_Type is Compressed_Block (bit 2)",
-                block_type_bit2,
-                1.expr(),
+                "tag_next depends on Block_Type",
+                meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
+                sum::expr([
+                    not::expr(block_type_bit1.expr() + block_type_bit2.expr())
+                        * ZstdTag::RawBlockBytes.expr(),
+                    block_type_bit1 * ZstdTag::RleBlockBytes.expr(),
+                    block_type_bit2 * ZstdTag::ZstdBlockLiteralsHeader.expr(),
+                ]),
             );
 
             // is_last_block is assigned correctly.
@@ -2010,6 +2297,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 config.block_config.block_len,
                 config.block_config.block_idx,
                 config.block_config.is_last_block,
+                config.block_config.repeat_block_idx,
             ] {
                 cb.require_equal(
                     "BlockHeader: block_idx/block_len/is_last_block",
@@ -2024,15 +2312,18 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             }
 
             // We now validate the end of the previous block.
-            // - tag=BlockHeader is preceded by tag in [FrameContentSize, SeqHeader, SeqData].
+            // - tag=BlockHeader is preceded by tag in [FrameContentSize, SeqHeader, SeqData,
+            //   RawBlockBytes, RleBlockBytes].
             // - if prev_tag=SequenceHeader: prev block had no sequences.
             // - if prev_tag=SequenceData: all sequences from prev block were decoded.
             cb.require_equal(
-                "tag::prev in [FCS, SH, SD]",
+                "tag::prev in [FCS, SH, SD, RAW, RLE]",
                 sum::expr([
                     is_prev_frame_content_size(meta),
                     is_prev_sequence_header(meta),
                     is_prev_sequence_data(meta),
+                    is_prev_raw_block_bytes(meta),
+                    is_prev_rle_block_bytes(meta),
                 ]),
                 1.expr(),
             );
@@ -2053,6 +2344,18 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 );
             });
 
+            // The FSE tables that may be repeated are the ones from the most recent previous
+            // block that decoded sequences.
+            cb.require_equal(
+                "BlockHeader: repeat_block_idx",
+                meta.query_advice(config.block_config.repeat_block_idx, Rotation::cur()),
+                select::expr(
+                    is_prev_sequence_data(meta),
+                    meta.query_advice(config.block_config.block_idx, Rotation::prev()),
+                    meta.query_advice(config.block_config.repeat_block_idx, Rotation::prev()),
+                ),
+            );
+
             cb.gate(condition)
         });
 
@@ -2117,8 +2420,21 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 meta.query_advice(config.block_config.regen_size, Rotation::prev()),
             );
 
+            // the block from which FSE tables may be repeated remains unchanged.
+            cb.require_equal(
+                "repeat_block_idx::cur == repeat_block_idx::prev",
+                meta.query_advice(config.block_config.repeat_block_idx, Rotation::cur()),
+                meta.query_advice(config.block_config.repeat_block_idx, Rotation::prev()),
+            );
+
             // the compression modes are remembered throughout the block's context.
-            for column in config.block_config.compression_modes {
+            for column in config
+                .block_config
+                .compression_modes
+                .into_iter()
+                .chain(config.block_config.rle_modes)
+                .chain(config.block_config.repeat_modes)
+            {
                 cb.require_equal(
                     "compression_modes::cur == compression_modes::prev (during block)",
                     meta.query_advice(column, Rotation::cur()),
@@ -2131,6 +2447,133 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
 
         debug_assert!(meta.degree() <= 9);
 
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        ///////////////////////////////// ZstdTag::RawBlockBytes //////////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag RawBlockBytes", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                is_raw_block_bytes(meta),
+                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // Raw_Block's contents are Block_Size bytes.
+            cb.require_equal(
+                "RawBlockBytes: tag_len == block_len",
+                meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
+                meta.query_advice(config.block_config.block_len, Rotation::cur()),
+            );
+
+            // The block's contents are the literals of a block with no sequences, i.e. they are
+            // copied as-is to the decoded data while executing the (empty) sequences section.
+            cb.require_equal(
+                "RawBlockBytes: regen_size == block_len",
+                meta.query_advice(config.block_config.regen_size, Rotation::cur()),
+                meta.query_advice(config.block_config.block_len, Rotation::cur()),
+            );
+            cb.require_zero(
+                "RawBlockBytes: num_sequences == 0",
+                meta.query_advice(config.block_config.num_sequences, Rotation::cur()),
+            );
+
+            // tag::next == is_last_block ? Null : BlockHeader.
+            cb.require_equal(
+                "tag::next",
+                meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
+                select::expr(
+                    meta.query_advice(config.block_config.is_last_block, Rotation::cur()),
+                    ZstdTag::Null.expr(),
+                    ZstdTag::BlockHeader.expr(),
+                ),
+            );
+
+            cb.gate(condition)
+        });
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        ///////////////////////////////// ZstdTag::RleBlockBytes //////////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag RleBlockBytes", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                is_rle_block_bytes(meta),
+                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // RLE_Block's contents are a single byte, that is repeated Block_Size times.
+            cb.require_equal(
+                "RleBlockBytes: tag_len == 1",
+                meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
+                1.expr(),
+            );
+
+            // The block's contents are the literals of a block with no sequences, i.e. they are
+            // copied as-is to the decoded data while executing the (empty) sequences section.
+            cb.require_equal(
+                "RleBlockBytes: regen_size == block_len",
+                meta.query_advice(config.block_config.regen_size, Rotation::cur()),
+                meta.query_advice(config.block_config.block_len, Rotation::cur()),
+            );
+            cb.require_zero(
+                "RleBlockBytes: num_sequences == 0",
+                meta.query_advice(config.block_config.num_sequences, Rotation::cur()),
+            );
+
+            // tag::next == is_last_block ? Null : BlockHeader.
+            cb.require_equal(
+                "tag::next",
+                meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
+                select::expr(
+                    meta.query_advice(config.block_config.is_last_block, Rotation::cur()),
+                    ZstdTag::Null.expr(),
+                    ZstdTag::BlockHeader.expr(),
+                ),
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.create_gate("DecoderConfig: tag RleBlockBytes (repeat)", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                is_rle_block_bytes(meta),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // The same byte is emitted as a literal at every row, i.e. byte_idx does not change.
+            cb.condition(
+                not::expr(config.tag_config.is_change.expr_at(meta, Rotation::cur())),
+                |cb| {
+                    cb.require_equal(
+                        "RleBlockBytes: byte_idx::cur == byte_idx::prev",
+                        meta.query_advice(config.byte_idx, Rotation::cur()),
+                        meta.query_advice(config.byte_idx, Rotation::prev()),
+                    );
+                },
+            );
+
+            // The tag ends at the last literal, i.e. once the byte was repeated Block_Size times.
+            cb.require_equal(
+                "RleBlockBytes: is_change::next == is_last_literal",
+                config.tag_config.is_change.expr_at(meta, Rotation::next()),
+                config
+                    .literals_decoder
+                    .is_last_literal
+                    .expr_at(meta, Rotation::cur()),
+            );
+
+            cb.gate(condition)
+        });
+
+        debug_assert!(meta.degree() <= 9);
+
         ///////////////////////////////////////////////////////////////////////////////////////////
         ///////////////////////////// ZstdTag::ZstdBlockLiteralsHeader ////////////////////////////
         ///////////////////////////////////////////////////////////////////////////////////////////
@@ -2146,20 +2589,38 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             let literals_block_type_bit0 = config.bits[0].expr_at(meta, Rotation::cur());
             let literals_block_type_bit1 = config.bits[1].expr_at(meta, Rotation::cur());
 
-            // We expect a Raw_Literals_Block, i.e. bit0 and bit1 are both 0.
-            cb.require_zero("Raw_Literals_Block: bit0", literals_block_type_bit0);
-            cb.require_zero("Raw_Literals_Block: bit1", literals_block_type_bit1);
+            // We expect a Raw_Literals_Block (00), Compressed_Literals_Block (10) or
+            // Treeless_Literals_Block (11), i.e. the RLE_Literals_Block (01) is not supported.
+            cb.require_zero(
+                "RLE_Literals_Block unsupported",
+                literals_block_type_bit0.expr() * not::expr(literals_block_type_bit1.expr()),
+            );
+            let is_compressed = literals_block_type_bit1;
 
             let size_format_bit0 = config.bits[2].expr_at(meta, Rotation::cur());
             let size_format_bit1 = config.bits[3].expr_at(meta, Rotation::cur());
 
+            // Raw_Literals_Block:
             // - Size_Format is 00 or 10: Size_Format uses 1 bit, literals header is 1 byte
             // - Size_Format is 01: Size_Format uses 2 bits, literals header is 2 bytes
-            // - Size_Format is 10: Size_Format uses 2 bits, literals header is 3 bytes
+            // - Size_Format is 11: Size_Format uses 2 bits, literals header is 3 bytes
+            //
+            // Compressed_Literals_Block or Treeless_Literals_Block:
+            // - Size_Format is 00 or 01: literals header is 3 bytes
+            // - Size_Format is 10: literals header is 4 bytes
+            // - Size_Format is 11: literals header is 5 bytes
             let expected_tag_len = select::expr(
-                not::expr(size_format_bit0.expr()),
-                1.expr(),
-                select::expr(size_format_bit1.expr(), 3.expr(), 2.expr()),
+                is_compressed.expr(),
+                select::expr(
+                    size_format_bit1.expr(),
+                    4.expr() + size_format_bit0.expr(),
+                    3.expr(),
+                ),
+                select::expr(
+                    not::expr(size_format_bit0.expr()),
+                    1.expr(),
+                    select::expr(size_format_bit1.expr(), 3.expr(), 2.expr()),
+                ),
             );
             cb.require_equal(
                 "ZstdBlockLiteralsHeader: tag_len == expected_tag_len",
@@ -2167,6 +2628,41 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 expected_tag_len,
             );
 
+            // The literals header is followed by:
+            // - Raw_Literals_Block: the raw bytes
+            // - Compressed_Literals_Block: the Huffman tree description
+            // - Treeless_Literals_Block: the jump table (4 streams) or the only literal stream
+            let expected_tag_next = select::expr(
+                is_compressed.expr(),
+                select::expr(
+                    literals_block_type_bit0,
+                    select::expr(
+                        sum::expr([size_format_bit0, size_format_bit1]),
+                        ZstdTag::ZstdBlockJumpTable.expr(),
+                        ZstdTag::ZstdBlockLstream.expr(),
+                    ),
+                    ZstdTag::ZstdBlockHuffmanHeader.expr(),
+                ),
+                ZstdTag::ZstdBlockLiteralsRawBytes.expr(),
+            );
+            cb.require_equal(
+                "ZstdBlockLiteralsHeader: tag_next == expected_tag_next",
+                meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
+                expected_tag_next,
+            );
+
+            // A Compressed_Literals_Block describes a new Huffman tree, whereas a
+            // Treeless_Literals_Block re-uses the Huffman tree from the previous block.
+            cb.require_equal(
+                "ZstdBlockLiteralsHeader: huffman_block_idx",
+                meta.query_advice(config.literals_decoder.huffman_block_idx, Rotation::cur()),
+                select::expr(
+                    is_compressed.expr() * not::expr(literals_block_type_bit0.expr()),
+                    meta.query_advice(config.block_config.block_idx, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.huffman_block_idx, Rotation::prev()),
+                ),
+            );
+
             cb.gate(condition)
         });
 
@@ -2178,70 +2674,756 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     config.tag_config.is_change.expr_at(meta, Rotation::cur()),
                 ]);
 
+                let is_compressed = config.bits[1].expr_at(meta, Rotation::cur());
                 let size_format_bit0 = config.bits[2].expr_at(meta, Rotation::cur());
                 let size_format_bit1 = config.bits[3].expr_at(meta, Rotation::cur());
 
                 // - byte0 is the first byte of the literals header
                 // - byte1 is either the second byte of the literals header or 0
                 // - byte2 is either the third byte of the literals header or 0
+                // - byte3 is either the fourth byte of the literals header or 0
+                // - byte4 is either the fifth byte of the literals header or 0
                 let byte0 = meta.query_advice(config.byte, Rotation(0));
                 let byte1 = select::expr(
-                    size_format_bit0.expr(),
+                    sum::expr([is_compressed.expr(), size_format_bit0.expr()]),
                     meta.query_advice(config.byte, Rotation(1)),
                     0.expr(),
                 );
                 let byte2 = select::expr(
-                    size_format_bit0.expr() * size_format_bit1.expr(),
+                    sum::expr([
+                        is_compressed.expr(),
+                        not::expr(is_compressed.expr())
+                            * size_format_bit0.expr()
+                            * size_format_bit1.expr(),
+                    ]),
                     meta.query_advice(config.byte, Rotation(2)),
                     0.expr(),
                 );
+                let byte3 = select::expr(
+                    is_compressed.expr() * size_format_bit1.expr(),
+                    meta.query_advice(config.byte, Rotation(3)),
+                    0.expr(),
+                );
+                let byte4 = select::expr(
+                    is_compressed.expr() * size_format_bit1.expr() * size_format_bit0.expr(),
+                    meta.query_advice(config.byte, Rotation(4)),
+                    0.expr(),
+                );
+
+                let (block_idx, regen_size) = (
+                    meta.query_advice(config.block_config.block_idx, Rotation::cur()),
+                    meta.query_advice(config.block_config.regen_size, Rotation::cur()),
+                );
+                [
+                    block_idx,
+                    byte0,
+                    byte1,
+                    byte2,
+                    byte3,
+                    byte4,
+                    is_compressed,
+                    size_format_bit0,
+                    size_format_bit1,
+                    regen_size,
+                    0.expr(), // not padding
+                ]
+                .into_iter()
+                .zip_eq(config.literals_header_table.table_exprs(meta))
+                .map(|(value, table)| (condition.expr() * value, table))
+                .collect()
+            },
+        );
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        /////////////////////////// ZstdTag::ZstdBlockLiteralsRawBytes ////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag ZstdBlockLiteralsRawBytes", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                is_zb_raw_block(meta),
+                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            cb.require_equal(
+                "block's regen size is raw literals' tag length",
+                meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
+                meta.query_advice(config.block_config.regen_size, Rotation::cur()),
+            );
+
+            cb.gate(condition)
+        });
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        ////////////////////////////////// Literals Decoding //////////////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: literals decoding", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                not::expr(meta.query_fixed(config.q_first, Rotation::cur())),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // The Huffman tree in use only changes at a literals header.
+            cb.condition(
+                not::expr(and::expr([
+                    meta.query_advice(config.tag_config.is_literals_header, Rotation::cur()),
+                    config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+                ])),
+                |cb| {
+                    cb.require_equal(
+                        "huffman_block_idx::cur == huffman_block_idx::prev",
+                        meta.query_advice(
+                            config.literals_decoder.huffman_block_idx,
+                            Rotation::cur(),
+                        ),
+                        meta.query_advice(
+                            config.literals_decoder.huffman_block_idx,
+                            Rotation::prev(),
+                        ),
+                    );
+                },
+            );
+
+            // A literal is emitted at every raw byte, including the bytes of Raw and RLE blocks, and
+            // at every prefix code read from a literal stream, i.e. excluding the sentinel row and
+            // the nil rows.
+            let is_lstream = meta.query_advice(config.tag_config.is_lstream, Rotation::cur());
+            let is_literal = config
+                .literals_decoder
+                .is_literal
+                .expr_at(meta, Rotation::cur());
+            cb.require_equal(
+                "is_literal == is_raw_bytes + (is_lstream && !is_change && !is_nil)",
+                is_literal.expr(),
+                sum::expr([
+                    is_raw_block_bytes(meta),
+                    is_rle_block_bytes(meta),
+                    is_zb_raw_block(meta),
+                    and::expr([
+                        is_lstream.expr(),
+                        not::expr(config.tag_config.is_change.expr_at(meta, Rotation::cur())),
+                        config.bitstream_decoder.is_not_nil(meta, Rotation::cur()),
+                    ]),
+                ]),
+            );
+
+            // The raw bytes are the literals themselves.
+            cb.condition(
+                sum::expr([
+                    is_raw_block_bytes(meta),
+                    is_rle_block_bytes(meta),
+                    is_zb_raw_block(meta),
+                ]),
+                |cb| {
+                    cb.require_equal(
+                        "raw bytes: literal == byte",
+                        meta.query_advice(config.literals_decoder.literal, Rotation::cur()),
+                        meta.query_advice(config.byte, Rotation::cur()),
+                    );
+                },
+            );
+
+            // The literal index increments at every literal emitted in the literals section, and
+            // is reset outside of it.
+            let is_literals_content = sum::expr([
+                is_raw_block_bytes(meta),
+                is_rle_block_bytes(meta),
+                is_zb_raw_block(meta),
+                is_zb_huffman_header(meta),
+                meta.query_advice(config.tag_config.is_huffman_fse_code, Rotation::cur()),
+                meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+                is_zb_jump_table(meta),
+                is_lstream,
+            ]);
+            let literal_idx =
+                meta.query_advice(config.literals_decoder.literal_idx, Rotation::cur());
+            cb.condition(is_literals_content.expr(), |cb| {
+                cb.require_equal(
+                    "literal_idx == literal_idx::prev + is_literal",
+                    literal_idx.expr(),
+                    meta.query_advice(config.literals_decoder.literal_idx, Rotation::prev())
+                        + is_literal.expr(),
+                );
+            });
+            cb.condition(not::expr(is_literals_content), |cb| {
+                cb.require_zero("literal_idx == 0 outside literals section", literal_idx);
+            });
+
+            // The last literal is the one at which we reach the regenerated size.
+            cb.require_equal(
+                "is_last_literal == is_literal && literal_idx == regen_size",
+                config
+                    .literals_decoder
+                    .is_last_literal
+                    .expr_at(meta, Rotation::cur()),
+                and::expr([
+                    is_literal,
+                    config.literals_decoder.is_literal_idx_eq_regen_size(
+                        meta,
+                        &config.block_config,
+                        Rotation::cur(),
+                    ),
+                ]),
+            );
+
+            // Huffman weights are only decoded in the Huffman tree description.
+            cb.condition(
+                not::expr(sum::expr([
+                    is_zb_huffman_header(meta),
+                    meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+                ])),
+                |cb| {
+                    cb.require_zero(
+                        "is_weight == 0 outside Huffman tree description",
+                        config
+                            .literals_decoder
+                            .is_weight
+                            .expr_at(meta, Rotation::cur()),
+                    );
+                },
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.lookup_any(
+            "DecoderConfig: Huffman weights (HuffmanCodesTable)",
+            |meta| {
+                // The Huffman weight decoded for a symbol is the one used to construct the
+                // canonical Huffman codes.
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                ]);
+
+                [
+                    meta.query_advice(config.literals_decoder.huffman_block_idx, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.weight_idx, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.weight, Rotation::cur()),
+                    0.expr(), // is_padding
+                ]
+                .into_iter()
+                .zip_eq(config.huffman_codes_table.table_exprs_by_weight(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        //////////////////////////// ZstdTag::ZstdBlockHuffmanHeader //////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag ZstdBlockHuffmanHeader", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                is_zb_huffman_header(meta),
+                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // - headerByte < 128: the weights are FSE-compressed, the header byte being the
+            //   number of bytes taken by the FSE table description and the FSE bitstream.
+            // - headerByte >= 128: the (headerByte - 127) weights are directly represented, with 2
+            //   weights per byte.
+            let is_direct = config.bits[7].expr_at(meta, Rotation::cur());
+            cb.condition(not::expr(is_direct.expr()), |cb| {
+                cb.require_equal(
+                    "FSE-compressed weights: tag_len == 1",
+                    meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
+                    1.expr(),
+                );
+                cb.require_equal(
+                    "FSE-compressed weights: tag_next == HuffmanFseCode",
+                    meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
+                    ZstdTag::ZstdBlockHuffmanFseCode.expr(),
+                );
+            });
+
+            // tag_len == 1 + ceil((headerByte - 127) / 2), i.e. the number of weights is odd iff
+            // the header byte is even.
+            cb.condition(is_direct, |cb| {
+                cb.require_equal(
+                    "direct weights: 2 * (tag_len - 1) == headerByte - 127 + (1 - bit0)",
+                    (meta.query_advice(config.tag_config.tag_len, Rotation::cur()) - 1.expr())
+                        * 2.expr(),
+                    meta.query_advice(config.byte, Rotation::cur())
+                        - 126.expr()
+                        - config.bits[0].expr_at(meta, Rotation::cur()),
+                );
+            });
+
+            cb.require_zero(
+                "header byte: is_weight == 0",
+                config
+                    .literals_decoder
+                    .is_weight
+                    .expr_at(meta, Rotation::cur()),
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.create_gate(
+            "DecoderConfig: tag ZstdBlockHuffmanHeader (direct weights)",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    is_zb_huffman_header(meta),
+                    not::expr(config.tag_config.is_change.expr_at(meta, Rotation::cur())),
+                ]);
+
+                let mut cb = BaseConstraintBuilder::default();
+
+                // Every byte represents the weights of 2 symbols: the high nibble for the symbol
+                // at weight_idx and the low nibble for the symbol at weight_idx + 1.
+                cb.require_equal(
+                    "direct weights: is_weight == 1",
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                    1.expr(),
+                );
+                cb.require_equal(
+                    "direct weights: weight_idx == 2 * (tag_idx - 2)",
+                    meta.query_advice(config.literals_decoder.weight_idx, Rotation::cur()),
+                    (meta.query_advice(config.tag_config.tag_idx, Rotation::cur()) - 2.expr())
+                        * 2.expr(),
+                );
+                cb.require_equal(
+                    "direct weights: weight == byte >> 4",
+                    meta.query_advice(config.literals_decoder.weight, Rotation::cur()),
+                    sum::expr([
+                        config.bits[4].expr_at(meta, Rotation::cur()),
+                        config.bits[5].expr_at(meta, Rotation::cur()) * 2.expr(),
+                        config.bits[6].expr_at(meta, Rotation::cur()) * 4.expr(),
+                        config.bits[7].expr_at(meta, Rotation::cur()) * 8.expr(),
+                    ]),
+                );
+
+                cb.gate(condition)
+            },
+        );
+
+        meta.lookup_any(
+            "DecoderConfig: tag ZstdBlockHuffmanHeader (direct weights low nibble)",
+            |meta| {
+                // The low nibble of the last byte is ignored, as the number of weights could be
+                // odd. The weights described directly are at the rows with is_weight=true in a tag
+                // other than HuffmanCode.
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                    not::expr(
+                        meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+                    ),
+                    not::expr(config.tag_config.is_change.expr_at(meta, Rotation::next())),
+                ]);
+
+                let low_nibble = meta.query_advice(config.byte, Rotation::cur())
+                    - meta.query_advice(config.literals_decoder.weight, Rotation::cur())
+                        * 16.expr();
+
+                [
+                    meta.query_advice(config.literals_decoder.huffman_block_idx, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.weight_idx, Rotation::cur())
+                        + 1.expr(),
+                    low_nibble,
+                    0.expr(), // is_padding
+                ]
+                .into_iter()
+                .zip_eq(config.huffman_codes_table.table_exprs_by_weight(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        //////////////////////////// ZstdTag::ZstdBlockHuffmanCode ////////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag ZstdBlockHuffmanCode", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // The weights are decoded using the FSE table described in tag=HuffmanFseCode.
+            for column in [config.fse_decoder.table_kind, config.fse_decoder.table_size] {
+                cb.require_equal(
+                    "HuffmanCode: table_kind and table_size unchanged",
+                    meta.query_advice(column, Rotation::cur()),
+                    meta.query_advice(column, Rotation::prev()),
+                );
+            }
+
+            // The accuracy log of the FSE table is at most 6, i.e. a read operation never covers
+            // a byte entirely.
+            cb.require_zero(
+                "HuffmanCode: is_nil == 0",
+                config.bitstream_decoder.is_nil(meta, Rotation::cur()),
+            );
+
+            // The 2 rows following the sentinel row initialise the 2 interleaved FSE states.
+            let is_init_state = sum::expr([
+                config.tag_config.is_change.expr_at(meta, Rotation::prev()),
+                config.tag_config.is_change.expr_at(meta, Rotation(-2)),
+            ]);
+            cb.condition(is_init_state.expr(), |cb| {
+                cb.require_zero(
+                    "init state: is_weight == 0",
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                );
+                cb.require_zero(
+                    "init state: is_nb0 == 0",
+                    config.bitstream_decoder.is_nb0(meta, Rotation::cur()),
+                );
+                cb.require_equal(
+                    "init state: state == 0x00 + readBits(nb)",
+                    meta.query_advice(config.literals_decoder.weight_state, Rotation::cur()),
+                    meta.query_advice(config.bitstream_decoder.bitstring_value, Rotation::cur()),
+                );
+            });
+
+            // Every other row emits a weight from the state that was updated 2 rows before.
+            let is_weight_row = and::expr([
+                not::expr(config.tag_config.is_change.expr_at(meta, Rotation::cur())),
+                not::expr(is_init_state),
+            ]);
+            cb.condition(is_weight_row.expr(), |cb| {
+                cb.require_equal(
+                    "weight: is_weight == 1",
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                    1.expr(),
+                );
+                cb.require_equal(
+                    "weight: weight_idx increments",
+                    meta.query_advice(config.literals_decoder.weight_idx, Rotation::cur()),
+                    select::expr(
+                        config.tag_config.is_change.expr_at(meta, Rotation(-3)),
+                        0.expr(),
+                        meta.query_advice(config.literals_decoder.weight_idx, Rotation::prev())
+                            + 1.expr(),
+                    ),
+                );
+            });
+
+            // The state is updated as: state == baseline + readBits(nb). We skip this check for
+            // the last 2 rows:
+            // - the last row does not read from the bitstream, the other state emits the last
+            //   weight.
+            // - the second-last row may read past the end of the bitstream (the bitstream is
+            //   over-consumed), after which the state it updates is no longer used.
+            cb.condition(
+                and::expr([
+                    is_weight_row,
+                    not::expr(config.tag_config.is_change.expr_at(meta, Rotation::next())),
+                    not::expr(config.tag_config.is_change.expr_at(meta, Rotation(2))),
+                ]),
+                |cb| {
+                    cb.require_equal(
+                        "weight: state == baseline + readBits(nb)",
+                        meta.query_advice(config.literals_decoder.weight_state, Rotation::cur()),
+                        meta.query_advice(config.literals_decoder.weight_baseline, Rotation::cur())
+                            + meta.query_advice(
+                                config.bitstream_decoder.bitstring_value,
+                                Rotation::cur(),
+                            ),
+                    );
+                    cb.require_equal(
+                        "weight: nb == bitstring_len",
+                        meta.query_advice(config.literals_decoder.weight_nb, Rotation::cur()),
+                        config
+                            .bitstream_decoder
+                            .bitstring_len(meta, Rotation::cur()),
+                    );
+                },
+            );
+            cb.condition(
+                config.tag_config.is_change.expr_at(meta, Rotation::next()),
+                |cb| {
+                    cb.require_equal(
+                        "last weight: state unchanged",
+                        meta.query_advice(config.literals_decoder.weight_state, Rotation::cur()),
+                        meta.query_advice(config.literals_decoder.weight_state, Rotation(-2)),
+                    );
+                    cb.require_equal(
+                        "last weight: is_nb0 == 1",
+                        config.bitstream_decoder.is_nb0(meta, Rotation::cur()),
+                        1.expr(),
+                    );
+                },
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.lookup_any(
+            "DecoderConfig: tag ZstdBlockHuffmanCode (init state)",
+            |meta| {
+                // The initial states are read with AL number of bits, i.e. 1 << nb == table_size.
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+                    sum::expr([
+                        config.tag_config.is_change.expr_at(meta, Rotation::prev()),
+                        config.tag_config.is_change.expr_at(meta, Rotation(-2)),
+                    ]),
+                ]);
+
+                [
+                    config
+                        .bitstream_decoder
+                        .bitstring_len_unchecked(meta, Rotation::cur()),
+                    meta.query_advice(config.fse_decoder.table_size, Rotation::cur()),
+                ]
+                .into_iter()
+                .zip_eq(config.pow2_table.table_exprs(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
+        meta.lookup_any(
+            "DecoderConfig: tag ZstdBlockHuffmanCode (FseTable)",
+            |meta| {
+                // The weight is emitted by the state at 2 rows before, since the 2 FSE states are
+                // interleaved.
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    meta.query_advice(config.tag_config.is_huffman_code, Rotation::cur()),
+                    config
+                        .literals_decoder
+                        .is_weight
+                        .expr_at(meta, Rotation::cur()),
+                ]);
+
+                [
+                    0.expr(), // q_first=0
+                    meta.query_advice(config.block_config.block_idx, Rotation::cur()),
+                    meta.query_advice(config.fse_decoder.table_kind, Rotation::cur()),
+                    meta.query_advice(config.fse_decoder.table_size, Rotation::cur()),
+                    0.expr(), // is_predefined
+                    meta.query_advice(config.literals_decoder.weight_state, Rotation(-2)),
+                    meta.query_advice(config.literals_decoder.weight, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.weight_baseline, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.weight_nb, Rotation::cur()),
+                    0.expr(), // is_skipped_state
+                    0.expr(), // is_padding
+                ]
+                .into_iter()
+                .zip_eq(config.fse_table.table_exprs_by_state(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
+        debug_assert!(meta.degree() <= 9);
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        /////////////////////////////// ZstdTag::ZstdBlockLstream /////////////////////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate("DecoderConfig: tag ZstdBlockLstream", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(config.q_enable, Rotation::cur()),
+                meta.query_advice(config.tag_config.is_lstream, Rotation::cur()),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // The literal streams are indexed 0, 1, 2, 3 (if 4 streams).
+            let (lstream_idx_curr, lstream_idx_prev) = (
+                meta.query_advice(config.literals_decoder.lstream_idx, Rotation::cur()),
+                meta.query_advice(config.literals_decoder.lstream_idx, Rotation::prev()),
+            );
+            cb.condition(
+                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+                |cb| {
+                    cb.require_equal(
+                        "new literal stream: lstream_idx",
+                        lstream_idx_curr.expr(),
+                        select::expr(
+                            meta.query_advice(config.tag_config.is_lstream, Rotation::prev()),
+                            lstream_idx_prev.expr() + 1.expr(),
+                            0.expr(),
+                        ),
+                    );
+                },
+            );
+            cb.condition(
+                not::expr(config.tag_config.is_change.expr_at(meta, Rotation::cur())),
+                |cb| {
+                    cb.require_equal(
+                        "same literal stream: lstream_idx unchanged",
+                        lstream_idx_curr,
+                        lstream_idx_prev,
+                    );
+                    // Prefix codes are at least 1 bit long.
+                    cb.require_zero(
+                        "same literal stream: is_nb0 == 0",
+                        config.bitstream_decoder.is_nb0(meta, Rotation::cur()),
+                    );
+                },
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.lookup_any(
+            "DecoderConfig: tag ZstdBlockLstream (HuffmanCodesTable)",
+            |meta| {
+                // The prefix code read from the literal stream decodes to the literal.
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    meta.query_advice(config.tag_config.is_lstream, Rotation::cur()),
+                    config
+                        .literals_decoder
+                        .is_literal
+                        .expr_at(meta, Rotation::cur()),
+                ]);
+
+                [
+                    meta.query_advice(config.literals_decoder.huffman_block_idx, Rotation::cur()),
+                    meta.query_advice(config.bitstream_decoder.bitstring_value, Rotation::cur()),
+                    config
+                        .bitstream_decoder
+                        .bitstring_len_unchecked(meta, Rotation::cur()),
+                    meta.query_advice(config.literals_decoder.literal, Rotation::cur()),
+                    0.expr(), // is_padding
+                ]
+                .into_iter()
+                .zip_eq(config.huffman_codes_table.table_exprs_by_code(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        /////////////////////// Huffman-coded bitstreams (HuffmanCode, Lstream) ///////////////////
+        ///////////////////////////////////////////////////////////////////////////////////////////
+        meta.create_gate(
+            "DecoderConfig: Huffman-coded bitstreams (sentinel row)",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    config
+                        .tag_config
+                        .is_huffman_bitstream(meta, Rotation::cur()),
+                    config.tag_config.is_change.expr_at(meta, Rotation::cur()),
+                ]);
+
+                let mut cb = BaseConstraintBuilder::default();
+
+                // Similar to tag=SequencesData, the FSE-compressed Huffman weights and the
+                // literal streams are read from back-to-front, starting with the sentinel
+                // bitstring, i.e. 0-7 number of 0s followed by a sentinel 1-bit.
+                cb.require_zero(
+                    "sentinel: is_nil=false",
+                    config.bitstream_decoder.is_nil(meta, Rotation::cur()),
+                );
+                cb.require_zero(
+                    "sentinel: is_nb0=false",
+                    config.bitstream_decoder.is_nb0(meta, Rotation::cur()),
+                );
+                cb.require_equal(
+                    "sentinel: bitstring_value=1",
+                    meta.query_advice(config.bitstream_decoder.bitstring_value, Rotation::cur()),
+                    1.expr(),
+                );
+                cb.require_equal(
+                    "sentinel: bit_index_end <= 7",
+                    config
+                        .bitstream_decoder
+                        .spans_one_byte(meta, Rotation::cur()),
+                    1.expr(),
+                );
+
+                cb.gate(condition)
+            },
+        );
+
+        meta.create_gate(
+            "DecoderConfig: Huffman-coded bitstreams (last row)",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(config.q_enable, Rotation::cur()),
+                    config
+                        .tag_config
+                        .is_huffman_bitstream(meta, Rotation::cur()),
+                    config.tag_config.is_change.expr_at(meta, Rotation::next()),
+                ]);
+
+                let mut cb = BaseConstraintBuilder::default();
+
+                // bitstream was consumed completely (byte-aligned):
+                // - if not_nil(cur) -> bit_index_end == 7
+                // - if nil(cur) and not_nil(prev) -> bit_index_end == 15
+                // - if nil(cur) and nil(prev) -> not_nil(-2) and bit_index_end == 23
+                let (is_nil_curr, is_nil_prev, is_nil_prev_prev) = (
+                    config.bitstream_decoder.is_nil(meta, Rotation::cur()),
+                    config.bitstream_decoder.is_nil(meta, Rotation::prev()),
+                    config.bitstream_decoder.is_nil(meta, Rotation(-2)),
+                );
+                cb.condition(not::expr(is_nil_curr.expr()), |cb| {
+                    cb.require_equal(
+                        "is_not_nil: bit_index_end==7",
+                        meta.query_advice(config.bitstream_decoder.bit_index_end, Rotation::cur()),
+                        7.expr(),
+                    );
+                });
+                cb.condition(
+                    and::expr([is_nil_curr.expr(), not::expr(is_nil_prev.expr())]),
+                    |cb| {
+                        cb.require_equal(
+                            "is_nil and is_not_nil(prev): bit_index_end==15",
+                            meta.query_advice(
+                                config.bitstream_decoder.bit_index_end,
+                                Rotation::prev(),
+                            ),
+                            15.expr(),
+                        );
+                    },
+                );
+                cb.condition(and::expr([is_nil_curr, is_nil_prev]), |cb| {
+                    cb.require_zero("is_nil and is_nil(prev): is_not_nil(-2)", is_nil_prev_prev);
+                    cb.require_equal(
+                        "is_nil and is_nil(prev): bit_index_end==23",
+                        meta.query_advice(config.bitstream_decoder.bit_index_end, Rotation(-2)),
+                        23.expr(),
+                    );
+                });
 
-                let (block_idx, regen_size) = (
-                    meta.query_advice(config.block_config.block_idx, Rotation::cur()),
-                    meta.query_advice(config.block_config.regen_size, Rotation::cur()),
-                );
-                [
-                    block_idx,
-                    byte0,
-                    byte1,
-                    byte2,
-                    size_format_bit0,
-                    size_format_bit1,
-                    regen_size,
-                    0.expr(), // not padding
-                ]
-                .into_iter()
-                .zip_eq(config.literals_header_table.table_exprs(meta))
-                .map(|(value, table)| (condition.expr() * value, table))
-                .collect()
+                cb.gate(condition)
             },
         );
 
         debug_assert!(meta.degree() <= 9);
 
-        ///////////////////////////////////////////////////////////////////////////////////////////
-        /////////////////////////// ZstdTag::ZstdBlockLiteralsRawBytes ////////////////////////////
-        ///////////////////////////////////////////////////////////////////////////////////////////
-        meta.create_gate("DecoderConfig: tag ZstdBlockLiteralsRawBytes", |meta| {
-            let condition = and::expr([
-                meta.query_fixed(config.q_enable, Rotation::cur()),
-                is_zb_raw_block(meta),
-                config.tag_config.is_change.expr_at(meta, Rotation::cur()),
-            ]);
-
-            let mut cb = BaseConstraintBuilder::default();
-
-            cb.require_equal(
-                "block's regen size is raw literals' tag length",
-                meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
-                meta.query_advice(config.block_config.regen_size, Rotation::cur()),
-            );
-
-            cb.gate(condition)
-        });
-
-        debug_assert!(meta.degree() <= 9);
-
         ///////////////////////////////////////////////////////////////////////////////////////////
         //////////////////////////// ZstdTag::ZstdBlockSequenceHeader /////////////////////////////
         ///////////////////////////////////////////////////////////////////////////////////////////
@@ -2274,38 +3456,73 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 decoded_sequences_header.num_sequences,
             );
 
-            // The compression modes for literals length, match length and offsets are expected to
-            // be either Predefined_Mode or Fse_Compressed_Mode, i.e. compression mode==0 or
-            // compression_mode==2. i.e. bit0==0.
-            cb.require_zero("ll: bit0 == 0", decoded_sequences_header.comp_mode_bit0_ll);
-            cb.require_zero("om: bit0 == 0", decoded_sequences_header.comp_mode_bit0_om);
-            cb.require_zero("ml: bit0 == 0", decoded_sequences_header.comp_mode_bit0_ml);
-
-            // Depending on bit1==0 or bit1==1 we know whether the compression mode is
-            // Predefined_Mode or Fse_Compressed_Mode. The compression_modes flag is set when
-            // Fse_Compressed_Mode is utilised.
+            // All the literals in the block have been decoded.
             cb.require_equal(
-                "block_config: compression_modes llt",
-                meta.query_advice(config.block_config.compression_modes[0], Rotation::cur()),
-                decoded_sequences_header.comp_mode_bit1_ll,
-            );
-            cb.require_equal(
-                "block_config: compression_modes mot",
-                meta.query_advice(config.block_config.compression_modes[1], Rotation::cur()),
-                decoded_sequences_header.comp_mode_bit1_om,
-            );
-            cb.require_equal(
-                "block_config: compression_modes mlt",
-                meta.query_advice(config.block_config.compression_modes[2], Rotation::cur()),
-                decoded_sequences_header.comp_mode_bit1_ml,
+                "literal_idx::prev == regen_size",
+                meta.query_advice(config.literals_decoder.literal_idx, Rotation::prev()),
+                meta.query_advice(config.block_config.regen_size, Rotation::cur()),
             );
 
-            // If all the three LLT, MOT and MLT use the Predefined_Mode, we have no FSE tables to
-            // decode in the sequences section. And the tag=ZstdBlockSequenceHeader will
-            // immediately be followed by tag=ZstdBlockSequenceData.
+            // The compression modes for literals length, match length and offsets are one of:
+            //
+            // | Compression_Mode    | bit1 | bit0 | compression_modes | rle_modes | repeat_modes |
+            // |---------------------|------|------|-------------------|-----------|--------------|
+            // | Predefined_Mode     | 0    | 0    | 0                 | 0         | 0            |
+            // | RLE_Mode            | 0    | 1    | 0                 | 1         | 0            |
+            // | Fse_Compressed_Mode | 1    | 0    | 1                 | 0         | 0            |
+            // | Repeat_Mode         | 1    | 1    | 0                 | 0         | 1            |
+            //
+            // i.e. at most one of the boolean flags is set, such that:
+            // - bit0 == rle_mode + repeat_mode
+            // - bit1 == compression_mode + repeat_mode
+            for (idx, (comp_mode_bit0, comp_mode_bit1)) in [
+                (
+                    decoded_sequences_header.comp_mode_bit0_ll,
+                    decoded_sequences_header.comp_mode_bit1_ll,
+                ),
+                (
+                    decoded_sequences_header.comp_mode_bit0_om,
+                    decoded_sequences_header.comp_mode_bit1_om,
+                ),
+                (
+                    decoded_sequences_header.comp_mode_bit0_ml,
+                    decoded_sequences_header.comp_mode_bit1_ml,
+                ),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let (compression_mode, rle_mode, repeat_mode) = (
+                    meta.query_advice(config.block_config.compression_modes[idx], Rotation::cur()),
+                    meta.query_advice(config.block_config.rle_modes[idx], Rotation::cur()),
+                    meta.query_advice(config.block_config.repeat_modes[idx], Rotation::cur()),
+                );
+                cb.require_boolean("block_config: compression_modes", compression_mode.expr());
+                cb.require_boolean("block_config: rle_modes", rle_mode.expr());
+                cb.require_boolean("block_config: repeat_modes", repeat_mode.expr());
+                cb.require_boolean(
+                    "block_config: at most one compression mode flag",
+                    compression_mode.expr() + rle_mode.expr() + repeat_mode.expr(),
+                );
+                cb.require_equal(
+                    "block_config: compression mode bit0",
+                    comp_mode_bit0,
+                    rle_mode + repeat_mode.expr(),
+                );
+                cb.require_equal(
+                    "block_config: compression mode bit1",
+                    comp_mode_bit1,
+                    compression_mode + repeat_mode,
+                );
+            }
+
+            // If none of the three LLT, MOT and MLT are described, i.e. they use the
+            // Predefined_Mode or the Repeat_Mode, we have no FSE tables to decode in the sequences
+            // section. And the tag=ZstdBlockSequenceHeader will immediately be followed by
+            // tag=ZstdBlockSequenceData.
             let no_fse_tables = config
                 .block_config
-                .are_predefined_all(meta, Rotation::cur());
+                .are_described_none(meta, Rotation::cur());
             cb.require_equal(
                 "SequenceHeader: tag_next=FseCode or tag_next=SequencesData",
                 meta.query_advice(config.tag_config.tag_next, Rotation::cur()),
@@ -2409,14 +3626,25 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             "DecoderConfig: tag ZstdBlockSequenceFseCode (table kind)",
             |meta| {
                 let condition = and::expr([
-                    meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
+                    sum::expr([
+                        meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
+                        meta.query_advice(config.tag_config.is_rle_fse_code, Rotation::cur()),
+                    ]),
                     config.tag_config.is_change.expr_at(meta, Rotation::cur()),
                 ]);
 
+                // The FSE tables described in the sequences section, i.e. in Fse_Compressed_Mode
+                // or RLE_Mode.
                 let (cmode_llt, cmode_mot, cmode_mlt) = (
-                    meta.query_advice(config.block_config.compression_modes[0], Rotation::cur()),
-                    meta.query_advice(config.block_config.compression_modes[1], Rotation::cur()),
-                    meta.query_advice(config.block_config.compression_modes[2], Rotation::cur()),
+                    config
+                        .block_config
+                        .is_described_mode(meta, 0, Rotation::cur()),
+                    config
+                        .block_config
+                        .is_described_mode(meta, 1, Rotation::cur()),
+                    config
+                        .block_config
+                        .is_described_mode(meta, 2, Rotation::cur()),
                 );
 
                 let cmodes_lc = (4.expr() * cmode_llt) + (2.expr() * cmode_mot) + cmode_mlt;
@@ -2436,6 +3664,63 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             },
         );
 
+        meta.create_gate(
+            "DecoderConfig: tag ZstdBlockSequenceFseCode (RLE_Mode)",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(config.tag_config.is_rle_fse_code, Rotation::cur()),
+                ]);
+
+                let mut cb = BaseConstraintBuilder::default();
+
+                // In RLE_Mode, the FSE table is described by a single byte, i.e. the only symbol
+                // of the table.
+                cb.require_equal(
+                    "fse(rle): tag_len == 1",
+                    meta.query_advice(config.tag_config.tag_len, Rotation::cur()),
+                    1.expr(),
+                );
+
+                cb.gate(condition)
+            },
+        );
+
+        meta.lookup_any(
+            "DecoderConfig: tag ZstdBlockSequenceFseCode (RLE_Mode symbol)",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(config.tag_config.is_rle_fse_code, Rotation::cur()),
+                ]);
+
+                let (block_idx, table_kind, symbol) = (
+                    meta.query_advice(config.block_config.block_idx, Rotation::cur()),
+                    meta.query_advice(config.fse_decoder.table_kind, Rotation::cur()),
+                    meta.query_advice(config.byte, Rotation::cur()),
+                );
+
+                // The FSE table in RLE_Mode has a single state (AL=0) which is allocated to the
+                // symbol.
+                [
+                    0.expr(), // q_first
+                    block_idx,
+                    table_kind,
+                    1.expr(), // table_size
+                    0.expr(), // is_predefined
+                    symbol,
+                    1.expr(), // symbol_count
+                    1.expr(), // symbol_count_acc
+                    0.expr(), // is_prob_less_than1
+                    0.expr(), // is_padding
+                ]
+                .into_iter()
+                .zip_eq(config.fse_table.table_exprs_by_symbol(meta))
+                .map(|(arg, table)| (condition.expr() * arg, table))
+                .collect()
+            },
+        );
+
         meta.lookup_any(
             "DecoderConfig: tag ZstdBlockSequenceFseCode (table size)",
             |meta| {
@@ -2750,11 +4035,12 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
 
                 let mut cb = BaseConstraintBuilder::default();
 
-                // 1. is_trailing_bits can occur iff tag=FseCode.
+                // 1. is_trailing_bits can occur iff tag=FseCode (SequenceFseCode or
+                //    HuffmanFseCode).
                 cb.require_equal(
                     "tag=FseCode",
-                    meta.query_advice(config.tag_config.tag, Rotation::cur()),
-                    ZstdTag::ZstdBlockSequenceFseCode.expr(),
+                    meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
+                    1.expr(),
                 );
 
                 // 2. trailing bits only occur on the last row of the tag=FseCode section.
@@ -3473,12 +4759,13 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 let (nb, table_size) = (
                     config
                         .bitstream_decoder
-                        .bitstring_len_unchecked(meta, Rotation::cur()),
+                        .bitstring_len(meta, Rotation::cur()),
                     meta.query_advice(config.fse_decoder.table_size, Rotation::cur()),
                 );
 
                 // When state is initialised, we must read AL number of bits.
-                // Since table_size == 1 << AL, we do a lookup to the pow2 table.
+                // Since table_size == 1 << AL, we do a lookup to the pow2 table. Note that AL=0
+                // for an FSE table in RLE_Mode, i.e. we read nb=0 bits.
                 [nb, table_size]
                     .into_iter()
                     .zip_eq(config.pow2_table.table_exprs(meta))
@@ -3505,6 +4792,10 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 );
                 let is_predefined_mode =
                     meta.query_advice(config.fse_decoder.is_predefined, Rotation::cur());
+                let is_repeat_mode =
+                    meta.query_advice(config.fse_decoder.is_repeat, Rotation::cur());
+                let repeat_block_idx =
+                    meta.query_advice(config.block_config.repeat_block_idx, Rotation::cur());
 
                 [
                     0.expr(), // q_first=0
@@ -3512,8 +4803,10 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     block_idx,
                     table_kind,
                     table_size,
-                    is_predefined_mode, // is_predefined
-                    0.expr(),           // is_padding
+                    is_predefined_mode,                // is_predefined
+                    is_repeat_mode.expr(),             // is_repeat
+                    is_repeat_mode * repeat_block_idx, // repeat_block_idx
+                    0.expr(),                          // is_padding
                 ]
                 .into_iter()
                 .zip_eq(config.fse_table.table_exprs_metadata(meta))
@@ -3652,6 +4945,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             // the following scenarios:
             // - end of block (is_last=true) with tag=SequenceData
             // - end of block (is_last=true) with tag=SequenceHeader and num_sequences=0
+            // - end of block (is_last=true) with tag=RawBlockBytes or tag=RleBlockBytes
             // - the last tag ended OK
             cb.require_equal(
                 "is_null: block::is_last=true on the previous row",
@@ -3659,16 +4953,24 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 1.expr(),
             );
             cb.require_equal(
-                "is_null: tag::prev check",
-                meta.query_advice(config.tag_config.tag, Rotation::prev()),
-                select::expr(
+                "is_null: tag::prev in [SH, SD, RAW, RLE]",
+                sum::expr([
+                    is_prev_sequence_header(meta),
+                    is_prev_sequence_data(meta),
+                    is_prev_raw_block_bytes(meta),
+                    is_prev_rle_block_bytes(meta),
+                ]),
+                1.expr(),
+            );
+            cb.condition(is_prev_sequence_header(meta), |cb| {
+                cb.require_equal(
+                    "is_null: tag::prev=SeqHeader",
                     config
                         .block_config
                         .is_empty_sequences(meta, Rotation::prev()),
-                    ZstdTag::ZstdBlockSequenceHeader.expr(),
-                    ZstdTag::ZstdBlockSequenceData.expr(),
-                ),
-            );
+                    1.expr(),
+                );
+            });
             cb.require_equal(
                 "is_null: tag_idx::prev == tag_len::prev",
                 meta.query_advice(config.tag_config.tag_idx, Rotation::prev()),
@@ -3697,10 +4999,11 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 config.bitstream_decoder.is_nb0(meta, Rotation::cur()),
             );
             cb.require_equal(
-                "bitstream(is_nil) can occur in [FseCode, SequencesData] tags",
+                "bitstream(is_nil) can occur in [FseCode, SequencesData, HuffmanCode, Lstream] tags",
                 sum::expr([
                     meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                     meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                    config.tag_config.is_huffman_bitstream(meta, Rotation::cur()),
                 ]),
                 1.expr(),
             );
@@ -3901,9 +5204,14 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 config.bitstream_decoder.is_nil(meta, Rotation::cur()),
             );
             cb.require_equal(
-                "bitstream(is_nb0) can occur in SequencesData",
-                meta.query_advice(config.tag_config.tag, Rotation::cur()),
-                ZstdTag::ZstdBlockSequenceData.expr(),
+                "bitstream(is_nb0) can occur in [SequencesData, HuffmanCode, Lstream] tags",
+                sum::expr([
+                    meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                    config
+                        .tag_config
+                        .is_huffman_bitstream(meta, Rotation::cur()),
+                ]),
+                1.expr(),
             );
             cb.require_zero(
                 "if is_nb0: bitstring_value == 0",
@@ -3987,6 +5295,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config.tag_config.is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ]);
 
@@ -4242,6 +5551,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 sum::expr([
                     meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                     meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                    config
+                        .tag_config
+                        .is_huffman_bitstream(meta, Rotation::cur()),
                 ]),
             ]);
 
@@ -4292,6 +5604,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config
+                            .tag_config
+                            .is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ]);
 
@@ -4382,6 +5697,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config
+                            .tag_config
+                            .is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ]);
 
@@ -4436,6 +5754,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config
+                            .tag_config
+                            .is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ]);
 
@@ -4527,6 +5848,9 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                     sum::expr([
                         meta.query_advice(config.tag_config.is_fse_code, Rotation::cur()),
                         meta.query_advice(config.tag_config.is_sequence_data, Rotation::cur()),
+                        config
+                            .tag_config
+                            .is_huffman_bitstream(meta, Rotation::cur()),
                     ]),
                 ]);
 
@@ -4585,6 +5909,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
         witness_rows: Vec<ZstdWitnessRow<Fr>>,
         literal_datas: Vec<Vec<u64>>,
         fse_aux_tables: Vec<FseAuxiliaryTableData>,
+        huffman_codes: Vec<HuffmanCodesData>,
         block_info_arr: Vec<BlockInfo>,
         sequence_info_arr: Vec<SequenceInfo>,
         address_table_arr: Vec<Vec<AddressTableRow>>,
@@ -4622,7 +5947,7 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
         /////////////////////////////////////////
         ///// Assign LiteralHeaderTable  ////////
         /////////////////////////////////////////
-        let mut literal_headers: Vec<(u64, u64, (u64, u64, u64))> = vec![]; // (block_idx, byte_offset, (byte0, byte1, byte2))
+        let mut literal_headers: Vec<(u64, u64, [u64; N_MAX_LITERAL_HEADER_BYTES])> = vec![]; // (block_idx, byte_offset, bytes)
         let literal_header_rows = witness_rows
             .iter()
             .filter(|r| r.state.tag == ZstdTag::ZstdBlockLiteralsHeader)
@@ -4635,12 +5960,14 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
             .state
             .block_idx;
         for curr_block_idx in 1..=max_block_idx {
-            let byte_idx = literal_header_rows
+            // Raw and RLE blocks do not have a literals header.
+            let Some(byte_idx) = literal_header_rows
                 .iter()
                 .find(|r| r.state.block_idx == curr_block_idx)
-                .unwrap()
-                .encoded_data
-                .byte_idx;
+                .map(|r| r.encoded_data.byte_idx)
+            else {
+                continue;
+            };
 
             let literal_bytes = literal_header_rows
                 .iter()
@@ -4648,27 +5975,20 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                 .map(|r| r.encoded_data.value_byte as u64)
                 .collect::<Vec<u64>>();
 
-            literal_headers.push((
-                curr_block_idx,
-                byte_idx,
-                (
-                    literal_bytes[0],
-                    if literal_bytes.len() > 1 {
-                        literal_bytes[1]
-                    } else {
-                        0
-                    },
-                    if literal_bytes.len() > 2 {
-                        literal_bytes[2]
-                    } else {
-                        0
-                    },
-                ),
-            ));
+            let mut bytes = [0u64; N_MAX_LITERAL_HEADER_BYTES];
+            bytes[..literal_bytes.len()].copy_from_slice(&literal_bytes);
+
+            literal_headers.push((curr_block_idx, byte_idx, bytes));
         }
         self.literals_header_table
             .assign(layouter, literal_headers, n_enabled)?;
 
+        /////////////////////////////////////////
+        ///// Assign HuffmanCodesTable  /////////
+        /////////////////////////////////////////
+        self.huffman_codes_table
+            .assign(layouter, &huffman_codes, n_enabled)?;
+
         /////////////////////////////////////////
         //// Assign Sequence-related Configs ////
         /////////////////////////////////////////
@@ -4929,13 +6249,48 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                         || Value::known(Fr::from(is_sequence_header as u64)),
                     )?;
 
-                    let is_fse_code = row.state.tag == ZstdTag::ZstdBlockSequenceFseCode;
+                    let is_huffman_fse_code = row.state.tag == ZstdTag::ZstdBlockHuffmanFseCode;
+                    let is_rle_fse_code = row.state.tag == ZstdTag::ZstdBlockSequenceFseCode
+                        && sequence_info_arr[row.state.block_idx as usize - 1].compression_mode
+                            [row.fse_data.table_kind as usize - 1]
+                            == CompressionMode::Rle;
+                    let is_fse_code = (row.state.tag == ZstdTag::ZstdBlockSequenceFseCode
+                        && !is_rle_fse_code)
+                        || is_huffman_fse_code;
                     region.assign_advice(
                         || "tag_config.is_fse_code",
                         self.tag_config.is_fse_code,
                         i,
                         || Value::known(Fr::from(is_fse_code as u64)),
                     )?;
+                    region.assign_advice(
+                        || "tag_config.is_rle_fse_code",
+                        self.tag_config.is_rle_fse_code,
+                        i,
+                        || Value::known(Fr::from(is_rle_fse_code as u64)),
+                    )?;
+                    region.assign_advice(
+                        || "tag_config.is_huffman_fse_code",
+                        self.tag_config.is_huffman_fse_code,
+                        i,
+                        || Value::known(Fr::from(is_huffman_fse_code as u64)),
+                    )?;
+
+                    let is_huffman_code = row.state.tag == ZstdTag::ZstdBlockHuffmanCode;
+                    region.assign_advice(
+                        || "tag_config.is_huffman_code",
+                        self.tag_config.is_huffman_code,
+                        i,
+                        || Value::known(Fr::from(is_huffman_code as u64)),
+                    )?;
+
+                    let is_lstream = row.state.tag == ZstdTag::ZstdBlockLstream;
+                    region.assign_advice(
+                        || "tag_config.is_lstream",
+                        self.tag_config.is_lstream,
+                        i,
+                        || Value::known(Fr::from(is_lstream as u64)),
+                    )?;
 
                     let is_null = row.state.tag == ZstdTag::Null;
                     region.assign_advice(
@@ -5051,22 +6406,34 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                             i,
                             || Value::known(Fr::from(curr_block_info.regen_size)),
                         )?;
-                        let is_predefined = match row.fse_data.table_kind {
+                        let table_mode = match row.fse_data.table_kind {
                             // default: ignored case
-                            0 => false,
+                            0 => None,
                             // LLT
-                            1 => !curr_sequence_info.compression_mode[0],
+                            1 => Some(curr_sequence_info.compression_mode[0]),
                             // MOT
-                            2 => !curr_sequence_info.compression_mode[1],
+                            2 => Some(curr_sequence_info.compression_mode[1]),
                             // MLT
-                            3 => !curr_sequence_info.compression_mode[2],
-                            _ => unreachable!("table_kind in [1, 2, 3]"),
+                            3 => Some(curr_sequence_info.compression_mode[2]),
+                            // HUF
+                            4 => None,
+                            _ => unreachable!("table_kind in [1, 2, 3, 4]"),
                         };
                         region.assign_advice(
                             || "fse_decoder.is_predefined",
                             self.fse_decoder.is_predefined,
                             i,
-                            || Value::known(Fr::from(is_predefined)),
+                            || {
+                                Value::known(Fr::from(
+                                    table_mode == Some(CompressionMode::Predefined),
+                                ))
+                            },
+                        )?;
+                        region.assign_advice(
+                            || "fse_decoder.is_repeat",
+                            self.fse_decoder.is_repeat,
+                            i,
+                            || Value::known(Fr::from(table_mode == Some(CompressionMode::Repeat))),
                         )?;
 
                         let table_names = ["LLT", "MOT", "MLT"];
@@ -5075,13 +6442,35 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                             .zip_eq(curr_sequence_info.compression_mode.iter())
                             .enumerate()
                         {
-                            region.assign_advice(
-                                || table_name,
-                                self.block_config.compression_modes[idx],
-                                i,
-                                || Value::known(Fr::from(compression_mode as u64)),
-                            )?;
+                            for (column, mode) in [
+                                (
+                                    self.block_config.compression_modes[idx],
+                                    CompressionMode::FseCompressed,
+                                ),
+                                (self.block_config.rle_modes[idx], CompressionMode::Rle),
+                                (self.block_config.repeat_modes[idx], CompressionMode::Repeat),
+                            ] {
+                                region.assign_advice(
+                                    || table_name,
+                                    column,
+                                    i,
+                                    || Value::known(Fr::from(compression_mode == mode)),
+                                )?;
+                            }
                         }
+
+                        // The most recent previous block that decoded sequences.
+                        let repeat_block_idx = sequence_info_arr[..block_idx as usize - 1]
+                            .iter()
+                            .rev()
+                            .find(|sequence_info| sequence_info.num_sequences > 0)
+                            .map_or(0, |sequence_info| sequence_info.block_idx as u64);
+                        region.assign_advice(
+                            || "block_config.repeat_block_idx",
+                            self.block_config.repeat_block_idx,
+                            i,
+                            || Value::known(Fr::from(repeat_block_idx)),
+                        )?;
                         let is_empty_sequences =
                             IsEqualChip::construct(self.block_config.is_empty_sequences.clone());
                         is_empty_sequences.assign(
@@ -5092,6 +6481,95 @@ impl<const L: usize, const R: usize> DecoderConfig<L, R> {
                         )?;
                     }
 
+                    ////////////////////////////////////////////////////////////
+                    /////////////// Assign Literals Decoding Fields ////////////
+                    ////////////////////////////////////////////////////////////
+                    let literals_data = &row.literals_data;
+                    for (col, value, annotation) in [
+                        (
+                            self.literals_decoder.is_literal.column,
+                            literals_data.is_literal as u64,
+                            "literals_decoder.is_literal",
+                        ),
+                        (
+                            self.literals_decoder.literal_idx,
+                            literals_data.literal_idx,
+                            "literals_decoder.literal_idx",
+                        ),
+                        (
+                            self.literals_decoder.literal,
+                            literals_data.literal,
+                            "literals_decoder.literal",
+                        ),
+                        (
+                            self.literals_decoder.is_last_literal.column,
+                            literals_data.is_last_literal as u64,
+                            "literals_decoder.is_last_literal",
+                        ),
+                        (
+                            self.literals_decoder.lstream_idx,
+                            literals_data.lstream_idx,
+                            "literals_decoder.lstream_idx",
+                        ),
+                        (
+                            self.literals_decoder.huffman_block_idx,
+                            literals_data.huffman_block_idx,
+                            "literals_decoder.huffman_block_idx",
+                        ),
+                        (
+                            self.literals_decoder.is_weight.column,
+                            literals_data.is_weight as u64,
+                            "literals_decoder.is_weight",
+                        ),
+                        (
+                            self.literals_decoder.weight_state,
+                            literals_data.weight_state,
+                            "literals_decoder.weight_state",
+                        ),
+                        (
+                            self.literals_decoder.weight_idx,
+                            literals_data.weight_idx,
+                            "literals_decoder.weight_idx",
+                        ),
+                        (
+                            self.literals_decoder.weight,
+                            literals_data.weight,
+                            "literals_decoder.weight",
+                        ),
+                        (
+                            self.literals_decoder.weight_baseline,
+                            literals_data.weight_baseline,
+                            "literals_decoder.weight_baseline",
+                        ),
+                        (
+                            self.literals_decoder.weight_nb,
+                            literals_data.weight_nb,
+                            "literals_decoder.weight_nb",
+                        ),
+                    ] {
+                        region.assign_advice(
+                            || annotation,
+                            col,
+                            i,
+                            || Value::known(Fr::from(value)),
+                        )?;
+                    }
+                    // The regen size is only assigned within the block's context.
+                    let regen_size = if is_block || is_block_header {
+                        block_info_arr[block_idx as usize - 1].regen_size
+                    } else {
+                        0
+                    };
+                    let literal_idx_eq_regen_size = IsEqualChip::construct(
+                        self.literals_decoder.literal_idx_eq_regen_size.clone(),
+                    );
+                    literal_idx_eq_regen_size.assign(
+                        &mut region,
+                        i,
+                        Value::known(Fr::from(literals_data.literal_idx)),
+                        Value::known(Fr::from(regen_size)),
+                    )?;
+
                     ////////////////////////////////////////////////////////////
                     ///////// Assign Extra Sequence Bitstream Fields  //////////
                     ////////////////////////////////////////////////////////////
@@ -5564,6 +7042,7 @@ mod tests {
                 witness_rows,
                 literal_bytes: decoded_literals,
                 fse_aux_tables,
+                huffman_codes,
                 block_info_arr,
                 sequence_info_arr,
                 address_table_rows: address_table_arr,
@@ -5592,6 +7071,7 @@ mod tests {
                 witness_rows,
                 decoded_literals,
                 fse_aux_tables,
+                huffman_codes,
                 block_info_arr,
                 sequence_info_arr,
                 address_table_arr,
@@ -5794,6 +7274,72 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_decoder_config_raw_rle_blocks() {
+        let raw = b"hello!!!!".to_vec();
+
+        // A frame (without the magic number) with a Raw_Block followed by the last RLE_Block.
+        //
+        // - FrameHeaderDescriptor: single segment, 1-byte FrameContentSize.
+        // - FrameContentSize: 9
+        // - BlockHeader: Block_Size=5, Block_Type=Raw_Block, Last_Block=0
+        // - Raw_Block contents: b"hello"
+        // - BlockHeader: Block_Size=4, Block_Type=RLE_Block, Last_Block=1
+        // - RLE_Block contents: b"!"
+        let compressed = [
+            vec![0x20, 0x09],
+            vec![0x28, 0x00, 0x00],
+            b"hello".to_vec(),
+            vec![0x23, 0x00, 0x00],
+            b"!".to_vec(),
+        ]
+        .concat();
+
+        let k = 18;
+        let decoder_config_tester: DecoderConfigTester<256, 256> =
+            DecoderConfigTester { raw, compressed, k };
+        let mock_prover = MockProver::<Fr>::run(k, &decoder_config_tester, vec![]).unwrap();
+        mock_prover.assert_satisfied_par();
+    }
+
+    #[test]
+    fn test_decoder_config_rle_repeat_modes() {
+        let raw = b"abcdabcdabcdwxyzwxyzwxyz".to_vec();
+
+        // A frame (without the magic number) with two compressed blocks, each with raw literals
+        // and a single sequence (LL=4, ML=8, Offset=4).
+        //
+        // - FrameHeaderDescriptor: single segment, 1-byte FrameContentSize.
+        // - FrameContentSize: 24
+        // - BlockHeader: Block_Size=11, Block_Type=Compressed_Block, Last_Block=0
+        // - LiteralsSection: Raw_Literals_Block b"abcd"
+        // - SequencesSection: 1 sequence, all tables in RLE_Mode with symbols (LL=4, OF=2, ML=5)
+        // - BlockHeader: Block_Size=8, Block_Type=Compressed_Block, Last_Block=1
+        // - LiteralsSection: Raw_Literals_Block b"wxyz"
+        // - SequencesSection: 1 sequence, all tables in Repeat_Mode
+        //
+        // The sequences bitstream 0x07 holds the sentinel bit followed by the 2 extra bits for
+        // the offset code (offset value 7), whereas the RLE tables read nb=0 bits.
+        let compressed = [
+            vec![0x20, 0x18],
+            vec![0x5c, 0x00, 0x00],
+            vec![0x20],
+            b"abcd".to_vec(),
+            vec![0x01, 0x54, 0x04, 0x02, 0x05, 0x07],
+            vec![0x45, 0x00, 0x00],
+            vec![0x20],
+            b"wxyz".to_vec(),
+            vec![0x01, 0xfc, 0x07],
+        ]
+        .concat();
+
+        let k = 18;
+        let decoder_config_tester: DecoderConfigTester<256, 256> =
+            DecoderConfigTester { raw, compressed, k };
+        let mock_prover = MockProver::<Fr>::run(k, &decoder_config_tester, vec![]).unwrap();
+        mock_prover.assert_satisfied_par();
+    }
+
     #[test]
     #[ignore = "multi_blob: heavy"]
     fn test_decoder_config_large_multi_block() -> Result<(), std::io::Error> {
@@ -0,0 +1,392 @@
+use ark_std::{end_timer, start_timer};
+use ethers_core::{types::H256, utils::keccak256};
+use gadgets::util::split_h256;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, SimpleFloorPlanner},
+    halo2curves::bn256::Fr,
+    plonk::{Circuit, Column, ConstraintSystem, Error, Instance, Selector},
+};
+use snark_verifier_sdk::CircuitExt;
+use zkevm_circuits::{
+    keccak_circuit::{
+        keccak_packed_multi::multi_keccak, KeccakCircuit, KeccakCircuitConfig,
+        KeccakCircuitConfigArgs,
+    },
+    table::{BitwiseOpTable, KeccakTable, Pow2Table, PowOfRandTable, RangeTable, U8Table},
+    util::{Challenges, SubCircuitConfig},
+};
+
+use crate::{
+    constants::DIGEST_LEN,
+    witgen::{process, MultiBlockProcessResult},
+    RlcConfig,
+};
+
+use super::{DecoderConfig, DecoderConfigArgs};
+
+/// Number of public inputs of the [`ZstdDecoderCircuit`].
+const NUM_INSTANCES: usize = 4;
+
+/// Config for the standalone zstd decoder circuit.
+#[derive(Debug, Clone)]
+pub struct ZstdDecoderCircuitConfig {
+    /// Keccak circuit configurations
+    keccak_circuit_config: KeccakCircuitConfig<Fr>,
+    /// RLC config
+    rlc_config: RlcConfig,
+    /// The zstd decoder's config.
+    decoder_config: DecoderConfig<1024, 512>,
+    /// Range table for lookup: [0, 256).
+    u8_table: U8Table,
+    /// Instance for public input; stores
+    /// - keccak256(compressed bytes) (2 elements, split hi_lo)
+    /// - keccak256(decompressed bytes) (2 elements, split hi_lo)
+    instance: Column<Instance>,
+}
+
+impl ZstdDecoderCircuitConfig {
+    /// Build a configuration for the standalone zstd decoder circuit.
+    pub fn configure(meta: &mut ConstraintSystem<Fr>, challenges: Challenges) -> Self {
+        let challenges_expr = challenges.exprs(meta);
+
+        // hash configuration for the compressed and decompressed bytes
+        let keccak_table = KeccakTable::construct(meta);
+        let keccak_circuit_config = KeccakCircuitConfig::new(
+            meta,
+            KeccakCircuitConfigArgs {
+                keccak_table: keccak_table.clone(),
+                challenges: challenges_expr.clone(),
+            },
+        );
+
+        // RLC configuration, used to look up the hashes in the keccak table.
+        let rlc_config = RlcConfig::configure(meta, &keccak_table, challenges);
+
+        // Zstd decoder.
+        let pow_rand_table = PowOfRandTable::construct(meta, &challenges_expr);
+        let pow2_table = Pow2Table::construct(meta);
+        let u8_table = U8Table::construct(meta);
+        let range8 = RangeTable::construct(meta);
+        let range16 = RangeTable::construct(meta);
+        let range512 = RangeTable::construct(meta);
+        let range_block_len = RangeTable::construct(meta);
+        let bitwise_op_table = BitwiseOpTable::construct(meta);
+
+        let decoder_config = DecoderConfig::configure(
+            meta,
+            &challenges_expr,
+            DecoderConfigArgs {
+                pow_rand_table,
+                pow2_table,
+                u8_table,
+                range8,
+                range16,
+                range512,
+                range_block_len,
+                bitwise_op_table,
+            },
+        );
+
+        let instance = meta.instance_column();
+        meta.enable_equality(instance);
+
+        debug_assert!(meta.degree() <= 9);
+
+        Self {
+            keccak_circuit_config,
+            rlc_config,
+            decoder_config,
+            u8_table,
+            instance,
+        }
+    }
+}
+
+/// Standalone circuit proving that a zstd frame decompresses to the given bytes.
+///
+/// Unlike the [`BatchCircuit`](crate::BatchCircuit), which only uses the zstd decoder to link the
+/// blob bytes to the batch bytes, this circuit exposes the decoder on its own. The public input
+/// consists of
+/// - keccak256(compressed bytes) (2 elements, split hi_lo)
+/// - keccak256(decompressed bytes) (2 elements, split hi_lo)
+///
+/// The RLCs and lengths exported by the decoder are looked up in the keccak table along with the
+/// RLCs of the digests, so the hashes in the public input are bound to the decoded frame.
+#[derive(Clone, Debug)]
+pub struct ZstdDecoderCircuit {
+    /// The zstd encoded bytes, i.e. a single zstd frame.
+    pub compressed: Vec<u8>,
+    /// The bytes the zstd frame decompresses to.
+    pub decompressed: Vec<u8>,
+    /// The degree of the circuit.
+    pub k: u32,
+    /// The public input of the circuit.
+    pub instances: Vec<Fr>,
+}
+
+impl ZstdDecoderCircuit {
+    /// Construct the circuit for a zstd frame and the bytes it decompresses to.
+    pub fn new(compressed: Vec<u8>, decompressed: Vec<u8>, k: u32) -> Self {
+        let instances = [&compressed, &decompressed]
+            .into_iter()
+            .flat_map(|bytes| {
+                let (hi, lo) = split_h256(H256::from(keccak256(bytes)));
+                [hi, lo]
+            })
+            .collect();
+
+        Self {
+            compressed,
+            decompressed,
+            k,
+            instances,
+        }
+    }
+}
+
+impl Circuit<Fr> for ZstdDecoderCircuit {
+    type Config = (ZstdDecoderCircuitConfig, Challenges);
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        unimplemented!()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        let challenges = Challenges::construct_p1(meta);
+        let config = ZstdDecoderCircuitConfig::configure(meta, challenges);
+        (config, challenges)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        let (config, challenge) = config;
+        let challenges = challenge.values(&layouter);
+
+        let witness_time = start_timer!(|| "synthesize | Zstd Decoder Circuit");
+
+        // ==============================================
+        // step 1: hash the compressed and decompressed bytes
+        // ==============================================
+        let timer = start_timer!(|| "assign keccak rows");
+        config
+            .keccak_circuit_config
+            .load_aux_tables(&mut layouter)?;
+        let keccak_capacity = KeccakCircuit::<Fr>::capacity_for_row(1 << self.k);
+        let preimages = [self.compressed.clone(), self.decompressed.clone()];
+        let keccak_witness =
+            multi_keccak(&preimages, challenges, keccak_capacity).map_err(|e| {
+                log::error!("multi keccak assignment failed: {:?}", e);
+                Error::Synthesis
+            })?;
+        layouter.assign_region(
+            || "assign keccak rows",
+            |mut region| {
+                for (offset, keccak_row) in keccak_witness.iter().enumerate() {
+                    config
+                        .keccak_circuit_config
+                        .set_row(&mut region, offset, keccak_row)?;
+                }
+                Ok(())
+            },
+        )?;
+        end_timer!(timer);
+
+        // ==============================================
+        // step 2: decode the zstd frame
+        // ==============================================
+        let timer = start_timer!(|| "assign decoder");
+        let MultiBlockProcessResult {
+            witness_rows,
+            literal_bytes: decoded_literals,
+            fse_aux_tables,
+            huffman_codes,
+            block_info_arr,
+            sequence_info_arr,
+            address_table_rows: address_table_arr,
+            sequence_exec_results,
+        } = process(&self.compressed, challenges.keccak_input());
+
+        // sanity check
+        let (recovered_bytes, sequence_exec_info_arr) = sequence_exec_results.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut out_byte, mut out_exec), res| {
+                out_byte.extend(res.recovered_bytes);
+                out_exec.push(res.exec_trace);
+                (out_byte, out_exec)
+            },
+        );
+        assert_eq!(
+            recovered_bytes, self.decompressed,
+            "original and recovered bytes mismatch"
+        );
+
+        config.u8_table.load(&mut layouter)?;
+        let decoder_exports = config.decoder_config.assign(
+            &mut layouter,
+            &self.decompressed,
+            &self.compressed,
+            witness_rows,
+            decoded_literals,
+            fse_aux_tables,
+            huffman_codes,
+            block_info_arr,
+            sequence_info_arr,
+            address_table_arr,
+            sequence_exec_info_arr,
+            &challenges,
+            self.k,
+        )?;
+        end_timer!(timer);
+
+        // ==============================================
+        // step 3: link the decoder's exports to the hashes in the public input
+        // ==============================================
+        let hash_cells = layouter.assign_region(
+            || "ZstdDecoderCircuit: hashes",
+            |mut region| -> Result<Vec<AssignedCell<Fr, Fr>>, Error> {
+                let rlc_config = &config.rlc_config;
+                rlc_config.init(&mut region)?;
+                let mut offset = 0;
+
+                let evm_word_challenge =
+                    rlc_config.read_challenge2(&mut region, challenges, &mut offset)?;
+                let byte_accumulator =
+                    rlc_config.load_private(&mut region, &Fr::from(256), &mut offset)?;
+                region.constrain_equal(
+                    byte_accumulator.cell(),
+                    rlc_config.pow_of_two_hundred_and_fifty_six_cell(
+                        byte_accumulator.cell().region_index,
+                        1,
+                    ),
+                )?;
+
+                let mut hash_cells = Vec::with_capacity(NUM_INSTANCES);
+                for (preimage, input_rlc, input_len) in [
+                    (
+                        &self.compressed,
+                        &decoder_exports.encoded_rlc,
+                        &decoder_exports.encoded_len,
+                    ),
+                    (
+                        &self.decompressed,
+                        &decoder_exports.decoded_rlc,
+                        &decoder_exports.decoded_len,
+                    ),
+                ] {
+                    let digest_cells = keccak256(preimage)
+                        .iter()
+                        .map(|&byte| {
+                            rlc_config.load_private(
+                                &mut region,
+                                &Fr::from(byte as u64),
+                                &mut offset,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let output_rlc = rlc_config.rlc(
+                        &mut region,
+                        &digest_cells,
+                        &evm_word_challenge,
+                        &mut offset,
+                    )?;
+                    rlc_config.lookup_keccak_rlcs(
+                        &mut region,
+                        input_rlc,
+                        &output_rlc,
+                        input_len,
+                        &mut offset,
+                    )?;
+
+                    for half in digest_cells.chunks(DIGEST_LEN / 2) {
+                        hash_cells.push(rlc_config.rlc(
+                            &mut region,
+                            half,
+                            &byte_accumulator,
+                            &mut offset,
+                        )?);
+                    }
+                }
+
+                Ok(hash_cells)
+            },
+        )?;
+
+        for (i, cell) in hash_cells.iter().enumerate() {
+            layouter.constrain_instance(cell.cell(), config.instance, i)?;
+        }
+
+        end_timer!(witness_time);
+
+        Ok(())
+    }
+}
+
+impl CircuitExt<Fr> for ZstdDecoderCircuit {
+    fn num_instance(&self) -> Vec<usize> {
+        // - keccak256(compressed bytes) (2 elements, split hi_lo)
+        // - keccak256(decompressed bytes) (2 elements, split hi_lo)
+        vec![NUM_INSTANCES]
+    }
+
+    fn instances(&self) -> Vec<Vec<Fr>> {
+        vec![self.instances.clone()]
+    }
+
+    fn selectors(config: &Self::Config) -> Vec<Selector> {
+        vec![
+            config.0.rlc_config.selector,
+            config.0.rlc_config.lookup_gate_selector,
+            config.0.rlc_config.enable_challenge1,
+            config.0.rlc_config.enable_challenge2,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
+    use snark_verifier_sdk::CircuitExt;
+    use std::io::Write;
+
+    use crate::{aggregation::decoder::WORKED_EXAMPLE, witgen::init_zstd_encoder};
+
+    use super::ZstdDecoderCircuit;
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+        let mut encoder = init_zstd_encoder(None);
+        encoder
+            .set_pledged_src_size(Some(raw.len() as u64))
+            .expect("Encoder src_size: raw.len()");
+        encoder.write_all(raw).expect("Encoder write_all");
+        encoder.finish().expect("Encoder success")
+    }
+
+    #[test]
+    fn test_zstd_decoder_circuit() {
+        let raw = WORKED_EXAMPLE.as_bytes().to_vec();
+        let compressed = encode(&raw);
+
+        let k = 18;
+        let circuit = ZstdDecoderCircuit::new(compressed, raw, k);
+        let mock_prover = MockProver::<Fr>::run(k, &circuit, circuit.instances()).unwrap();
+        mock_prover.assert_satisfied_par();
+    }
+
+    #[test]
+    fn test_zstd_decoder_circuit_wrong_instances() {
+        let raw = WORKED_EXAMPLE.as_bytes().to_vec();
+        let compressed = encode(&raw);
+
+        let k = 18;
+        let circuit = ZstdDecoderCircuit::new(compressed, raw, k);
+        let mut instances = circuit.instances();
+        instances[0][3] += Fr::one();
+        let mock_prover = MockProver::<Fr>::run(k, &circuit, instances).unwrap();
+        assert!(mock_prover.verify_par().is_err());
+    }
+}
@@ -8,6 +8,10 @@ pub use bitstring::BitstringTable;
 mod fse;
 pub use fse::FseTable;
 
+/// Canonical Huffman codes reconstructed from the Huffman weights.
+mod huffman_codes;
+pub use huffman_codes::HuffmanCodesTable;
+
 /// Decode the regenerated size from the literals header.
 mod literals_header;
 pub use literals_header::LiteralsHeaderTable;
@@ -7,18 +7,30 @@ pub struct RomFseTableTransition;
 impl FixedLookupValues for RomFseTableTransition {
     fn values() -> Vec<[Value<Fr>; 7]> {
         use crate::witgen::{
-            FseTableKind::{LLT, MLT, MOT},
+            FseTableKind::{HUF, LLT, MLT, MOT},
             N_MAX_BLOCKS,
         };
 
+        // The FSE table for Huffman weights (HUF) only appears in blocks that describe a new
+        // Huffman tree using FSE-compressed weights, in which case it precedes the LLT table.
+        //
+        // Raw and RLE blocks do not have any FSE tables, hence the block_idx may skip over them
+        // between the MLT table of a block and the first FSE table of the next compressed block.
         (1..N_MAX_BLOCKS)
             .flat_map(|block_idx_curr| {
-                let table_kind_prev = if block_idx_curr == 1 { None } else { Some(MLT) };
-                [
-                    (block_idx_curr - 1, block_idx_curr, table_kind_prev, LLT),
-                    (block_idx_curr, block_idx_curr, Some(LLT), MOT),
-                    (block_idx_curr, block_idx_curr, Some(MOT), MLT),
-                ]
+                (0..block_idx_curr)
+                    .flat_map(move |block_idx_prev| {
+                        let table_kind_prev = if block_idx_prev == 0 { None } else { Some(MLT) };
+                        [
+                            (block_idx_prev, block_idx_curr, table_kind_prev, LLT),
+                            (block_idx_prev, block_idx_curr, table_kind_prev, HUF),
+                        ]
+                    })
+                    .chain([
+                        (block_idx_curr, block_idx_curr, Some(HUF), LLT),
+                        (block_idx_curr, block_idx_curr, Some(LLT), MOT),
+                        (block_idx_curr, block_idx_curr, Some(MOT), MLT),
+                    ])
             })
             .map(
                 |(block_idx_prev, block_idx_curr, table_kind_prev, table_kind_curr)| {
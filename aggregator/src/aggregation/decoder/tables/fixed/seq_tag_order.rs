@@ -7,6 +7,10 @@ use crate::aggregation::decoder::{
 
 use super::FixedLookupValues;
 
+/// The order in which the FSE tables are described in the sequences section, keyed by whether the
+/// (LLT, MOT, MLT) tables are described, i.e. they utilise the FSE_Compressed_Mode or the
+/// RLE_Mode. Tables in the Predefined_Mode or Repeat_Mode are not described.
+///
 /// The possible orders are:
 ///
 /// - (1, 1, 1):
@@ -28,14 +32,18 @@ use super::FixedLookupValues;
 ///     - SequenceHeader > FseCode > SequenceData (MOT)
 /// - (0, 0, 1):
 ///     - SequenceHeader > FseCode > SequenceData (MLT)
+///
+/// Irrespective of the compression modes, the FSE-compressed Huffman weights are described as:
+///     - HuffmanHeader > HuffmanFseCode > HuffmanCode (HUF)
 pub struct RomSeqTagOrder;
 
 impl FixedLookupValues for RomSeqTagOrder {
     fn values() -> Vec<[Value<Fr>; 7]> {
-        use FseTableKind::{LLT, MLT, MOT};
+        use FseTableKind::{HUF, LLT, MLT, MOT};
         use ZstdTag::{
-            ZstdBlockSequenceData as SeqData, ZstdBlockSequenceFseCode as FseCode,
-            ZstdBlockSequenceHeader as SeqHeader,
+            ZstdBlockHuffmanCode as HufCode, ZstdBlockHuffmanFseCode as HufFseCode,
+            ZstdBlockHuffmanHeader as HufHeader, ZstdBlockSequenceData as SeqData,
+            ZstdBlockSequenceFseCode as FseCode, ZstdBlockSequenceHeader as SeqHeader,
         };
 
         [
@@ -58,6 +66,15 @@ impl FixedLookupValues for RomSeqTagOrder {
             (0, 1, 0, SeqHeader, FseCode, SeqData, MOT),
             // (0, 0, 1)
             (0, 0, 1, SeqHeader, FseCode, SeqData, MLT),
+            // Huffman weights
+            (0, 0, 0, HufHeader, HufFseCode, HufCode, HUF),
+            (0, 0, 1, HufHeader, HufFseCode, HufCode, HUF),
+            (0, 1, 0, HufHeader, HufFseCode, HufCode, HUF),
+            (0, 1, 1, HufHeader, HufFseCode, HufCode, HUF),
+            (1, 0, 0, HufHeader, HufFseCode, HufCode, HUF),
+            (1, 0, 1, HufHeader, HufFseCode, HufCode, HUF),
+            (1, 1, 0, HufHeader, HufFseCode, HufCode, HUF),
+            (1, 1, 1, HufHeader, HufFseCode, HufCode, HUF),
         ]
         .map(
             |(cmode_llt, cmode_mot, cmode_mlt, tag_prev, tag_curr, tag_next, table_kind)| {
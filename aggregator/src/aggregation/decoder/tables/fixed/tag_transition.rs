@@ -9,17 +9,37 @@ pub struct RomTagTransition;
 impl FixedLookupValues for RomTagTransition {
     fn values() -> Vec<[Value<Fr>; 7]> {
         use ZstdTag::{
-            BlockHeader, FrameContentSize, FrameHeaderDescriptor, Null, ZstdBlockLiteralsHeader,
-            ZstdBlockLiteralsRawBytes, ZstdBlockSequenceData, ZstdBlockSequenceFseCode,
+            BlockHeader, FrameContentSize, FrameHeaderDescriptor, Null, RawBlockBytes,
+            RleBlockBytes, ZstdBlockHuffmanCode, ZstdBlockHuffmanFseCode, ZstdBlockHuffmanHeader,
+            ZstdBlockJumpTable, ZstdBlockLiteralsHeader, ZstdBlockLiteralsRawBytes,
+            ZstdBlockLstream, ZstdBlockSequenceData, ZstdBlockSequenceFseCode,
             ZstdBlockSequenceHeader,
         };
 
         [
             (FrameHeaderDescriptor, FrameContentSize),
             (FrameContentSize, BlockHeader),
+            (BlockHeader, RawBlockBytes),
+            (BlockHeader, RleBlockBytes),
             (BlockHeader, ZstdBlockLiteralsHeader),
+            (RawBlockBytes, BlockHeader), // multi-block
+            (RawBlockBytes, Null),
+            (RleBlockBytes, BlockHeader), // multi-block
+            (RleBlockBytes, Null),
             (ZstdBlockLiteralsHeader, ZstdBlockLiteralsRawBytes),
+            (ZstdBlockLiteralsHeader, ZstdBlockHuffmanHeader), // compressed
+            (ZstdBlockLiteralsHeader, ZstdBlockJumpTable),     // treeless (4 streams)
+            (ZstdBlockLiteralsHeader, ZstdBlockLstream),       // treeless (1 stream)
             (ZstdBlockLiteralsRawBytes, ZstdBlockSequenceHeader),
+            (ZstdBlockHuffmanHeader, ZstdBlockHuffmanFseCode),
+            (ZstdBlockHuffmanHeader, ZstdBlockJumpTable), // direct weights (4 streams)
+            (ZstdBlockHuffmanHeader, ZstdBlockLstream),   // direct weights (1 stream)
+            (ZstdBlockHuffmanFseCode, ZstdBlockHuffmanCode),
+            (ZstdBlockHuffmanCode, ZstdBlockJumpTable),
+            (ZstdBlockHuffmanCode, ZstdBlockLstream),
+            (ZstdBlockJumpTable, ZstdBlockLstream),
+            (ZstdBlockLstream, ZstdBlockLstream),
+            (ZstdBlockLstream, ZstdBlockSequenceHeader),
             (ZstdBlockSequenceHeader, ZstdBlockSequenceFseCode),
             (ZstdBlockSequenceHeader, ZstdBlockSequenceData),
             (ZstdBlockSequenceFseCode, ZstdBlockSequenceFseCode),
@@ -39,6 +39,13 @@ const N_ROWS_PER_FSE: usize = 1 << 10;
 /// - block_idx=2, table_kind=LLT
 /// - ... and so on
 ///
+/// A block that describes its Huffman tree using FSE-compressed weights has an additional FSE
+/// table (HUF) that appears before the LLT table of that block.
+///
+/// A table in Repeat_Mode is assigned again at the block that repeats it, and each of its states
+/// is validated against the same table kind at ``repeat_block_idx``. A table in RLE_Mode has a
+/// single state (table_size == 1) that is allocated to the RLE symbol.
+///
 /// Each table spans over a maximum of 1024 rows, and the start of an FSE table is marked by the
 /// fixed column ``q_start``. Upon finishing the FSE table, remaining rows are marked with the
 /// ``is_padding`` column.
@@ -638,6 +645,55 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
             .collect()
         });
 
+        // For FSE tables repeated from a previous block (Repeat_Mode), we must validate against
+        // the FSE table of that previous block for every state in the FSE table.
+        meta.lookup_any("FseTable: repeated table validation", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                config.sorted_table.is_repeat.expr_at(meta, Rotation::cur()),
+                not::expr(config.is_skipped_state.expr_at(meta, Rotation::cur())),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let (repeat_block_idx, table_kind, table_size, state, symbol, baseline, nb) = (
+                meta.query_advice(config.sorted_table.repeat_block_idx, Rotation::cur()),
+                meta.query_advice(config.sorted_table.table_kind, Rotation::cur()),
+                meta.query_advice(config.sorted_table.table_size, Rotation::cur()),
+                meta.query_advice(config.state, Rotation::cur()),
+                meta.query_advice(config.symbol, Rotation::cur()),
+                meta.query_advice(config.baseline, Rotation::cur()),
+                meta.query_advice(config.nb, Rotation::cur()),
+            );
+
+            let fse_table_exprs = [
+                meta.query_advice(config.sorted_table.block_idx, Rotation::cur()),
+                meta.query_advice(config.sorted_table.table_kind, Rotation::cur()),
+                meta.query_advice(config.sorted_table.table_size, Rotation::cur()),
+                meta.query_advice(config.state, Rotation::cur()),
+                meta.query_advice(config.symbol, Rotation::cur()),
+                meta.query_advice(config.baseline, Rotation::cur()),
+                meta.query_advice(config.nb, Rotation::cur()),
+                config.is_skipped_state.expr_at(meta, Rotation::cur()),
+                config.is_padding.expr_at(meta, Rotation::cur()),
+            ];
+
+            [
+                repeat_block_idx,
+                table_kind,
+                table_size,
+                state,
+                symbol,
+                baseline,
+                nb,
+                0.expr(), // is_skipped_state
+                0.expr(), // is_padding
+            ]
+            .into_iter()
+            .zip_eq(fse_table_exprs)
+            .map(|(arg, table)| (condition.expr() * arg, table))
+            .collect()
+        });
+
         // For every new symbol detected.
         meta.create_gate("FseTable: new symbol", |meta| {
             let condition = and::expr([
@@ -778,6 +834,12 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
                     0,
                     || Value::known(Fr::one()),
                 )?;
+                for (col, annotation) in [
+                    (self.sorted_table.block_idx, "sorted_table.block_idx"),
+                    (self.sorted_table.table_kind, "sorted_table.table_kind"),
+                ] {
+                    region.assign_advice(|| annotation, col, 0, || Value::known(Fr::zero()))?;
+                }
 
                 // Both tables should skip the first row
                 let mut fse_offset: usize = 1;
@@ -1070,6 +1132,26 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
                                     sorted_offset,
                                     || Value::known(Fr::from(table.is_predefined as u64)),
                                 )?;
+                                region.assign_advice(
+                                    || "sorted_table.is_repeat",
+                                    self.sorted_table.is_repeat.column,
+                                    sorted_offset,
+                                    || {
+                                        Value::known(Fr::from(
+                                            table.repeat_block_idx.is_some() as u64
+                                        ))
+                                    },
+                                )?;
+                                region.assign_advice(
+                                    || "sorted_table.repeat_block_idx",
+                                    self.sorted_table.repeat_block_idx,
+                                    sorted_offset,
+                                    || {
+                                        Value::known(Fr::from(
+                                            table.repeat_block_idx.unwrap_or_default(),
+                                        ))
+                                    },
+                                )?;
                                 region.assign_advice(
                                     || "sorted_table.table_size",
                                     self.sorted_table.table_size,
@@ -1235,6 +1317,18 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
                             offset,
                             || Value::known(Fr::from(table.is_predefined as u64)),
                         )?;
+                        region.assign_advice(
+                            || "sorted_table.is_repeat",
+                            self.sorted_table.is_repeat.column,
+                            offset,
+                            || Value::known(Fr::from(table.repeat_block_idx.is_some() as u64)),
+                        )?;
+                        region.assign_advice(
+                            || "sorted_table.repeat_block_idx",
+                            self.sorted_table.repeat_block_idx,
+                            offset,
+                            || Value::known(Fr::from(table.repeat_block_idx.unwrap_or_default())),
+                        )?;
                     }
                     fse_offset = target_end_offset;
                     sorted_offset = target_end_offset;
@@ -1314,7 +1408,8 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
     }
 
     /// Lookup table expressions for (table_kind, table_size) to know that the FSE decoder values
-    /// were correctly populated even at the "init-state" stage.
+    /// were correctly populated even at the "init-state" stage. This also binds a repeated FSE
+    /// table to the block whose FSE table it repeats.
     pub fn table_exprs_metadata(&self, meta: &mut VirtualCells<Fr>) -> Vec<Expression<Fr>> {
         vec![
             meta.query_fixed(self.sorted_table.q_first, Rotation::cur()),
@@ -1325,6 +1420,8 @@ impl<const L: usize, const R: usize> FseTable<L, R> {
             self.sorted_table
                 .is_predefined
                 .expr_at(meta, Rotation::cur()),
+            self.sorted_table.is_repeat.expr_at(meta, Rotation::cur()),
+            meta.query_advice(self.sorted_table.repeat_block_idx, Rotation::cur()),
             self.is_padding.expr_at(meta, Rotation::cur()),
         ]
     }
@@ -1375,7 +1472,7 @@ struct FseSortedStatesTable {
     q_start: Column<Fixed>,
     /// The block index in which this FSE table is found.
     block_idx: Column<Advice>,
-    /// The table kind, i.e. LLT=1, MOT=2 or MLT=3.
+    /// The table kind, i.e. LLT=1, MOT=2, MLT=3 or HUF=4.
     table_kind: Column<Advice>,
     /// The number of states in the FSE table, i.e. 1 << AL.
     table_size: Column<Advice>,
@@ -1387,6 +1484,11 @@ struct FseSortedStatesTable {
     /// [doclink1]: https://github.com/facebook/zstd/blob/dev/doc/zstd_compression_format.md#default-distributions
     /// [doclink2]: https://github.com/facebook/zstd/blob/dev/doc/zstd_compression_format.md#appendix-a---decoding-tables-for-predefined-codes
     is_predefined: BooleanAdvice,
+    /// A boolean to indicate whether the FSE table is repeated from a previous block, i.e. the
+    /// sequences section of this block used the Repeat_Mode for this table kind.
+    is_repeat: BooleanAdvice,
+    /// The block index of the FSE table that is repeated, if is_repeat=true, otherwise 0.
+    repeat_block_idx: Column<Advice>,
     /// The FSE symbol, starting at the first symbol with prob>=1.
     symbol: Column<Advice>,
     /// Boolean column to mark if we are moving to the next symbol.
@@ -1443,6 +1545,10 @@ impl FseSortedStatesTable {
             is_predefined: BooleanAdvice::construct(meta, |meta| {
                 meta.query_fixed(q_enable, Rotation::cur())
             }),
+            is_repeat: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+            repeat_block_idx: meta.advice_column(),
             symbol: meta.advice_column(),
             is_new_symbol: BooleanAdvice::construct(meta, |meta| {
                 meta.query_fixed(q_enable, Rotation::cur())
@@ -1491,19 +1597,32 @@ impl FseSortedStatesTable {
 
             let mut cb = BaseConstraintBuilder::default();
 
-            // The first row is all 0s. This is then followed by a q_start==1 fixed column. We want
-            // to make sure the first FSE table belongs to block_idx=1.
-            cb.require_equal(
-                "block_idx == 1 for the first FSE table",
-                meta.query_advice(config.block_idx, Rotation::next()),
-                1.expr(),
+            // The first row is all 0s. This is then followed by a q_start==1 fixed column. The
+            // first FSE table need not belong to block_idx=1, since Raw and RLE blocks do not have
+            // FSE tables. The block_idx transition from 0 is checked via the ROM lookup for FSE
+            // table transitions.
+            cb.require_zero(
+                "block_idx == 0 on the first row",
+                meta.query_advice(config.block_idx, Rotation::cur()),
+            );
+            cb.require_zero(
+                "table_kind == 0 on the first row",
+                meta.query_advice(config.table_kind, Rotation::cur()),
             );
 
-            // The first FSE table described should be the LLT table.
-            cb.require_equal(
-                "table_kind == LLT for the first FSE table",
-                meta.query_advice(config.table_kind, Rotation::next()),
-                FseTableKind::LLT.expr(),
+            // The first FSE table described should be the LLT table, or the HUF table if the
+            // first block describes FSE-compressed Huffman weights. There are no FSE tables at
+            // all if none of the blocks are compressed blocks.
+            let table_kind = meta.query_advice(config.table_kind, Rotation::next());
+            cb.condition(
+                not::expr(config.is_padding.expr_at(meta, Rotation::next())),
+                |cb| {
+                    cb.require_zero(
+                        "table_kind == LLT or table_kind == HUF for the first FSE table",
+                        (table_kind.expr() - FseTableKind::LLT.expr())
+                            * (table_kind - FseTableKind::HUF.expr()),
+                    );
+                },
             );
 
             cb.gate(condition)
@@ -1624,6 +1743,8 @@ impl FseSortedStatesTable {
                     config.table_kind,
                     config.table_size,
                     config.is_predefined.column,
+                    config.is_repeat.column,
+                    config.repeat_block_idx,
                 ] {
                     cb.require_equal(
                         "FseSortedStatesTable: columns that remain unchanged",
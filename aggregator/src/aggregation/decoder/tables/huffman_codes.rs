@@ -0,0 +1,427 @@
+use gadgets::{
+    is_equal::{IsEqualChip, IsEqualConfig, IsEqualInstruction},
+    util::{and, not, select, sum, Expr},
+};
+use halo2_proofs::{
+    circuit::{Layouter, Value},
+    halo2curves::bn256::Fr,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, VirtualCells},
+    poly::Rotation,
+};
+use itertools::Itertools;
+use zkevm_circuits::{
+    evm_circuit::{BaseConstraintBuilder, ConstrainBuilderCommon},
+    table::{LookupTable, Pow2Table, RangeTable, U8Table},
+};
+
+use crate::aggregation::{
+    decoder::witgen::{HuffmanCodesData, N_BLOCK_SIZE_TARGET},
+    util::BooleanAdvice,
+};
+
+/// The Huffman codes table verifies that the canonical prefix codes are assigned correctly to the
+/// symbols, given the Huffman weights described in a Compressed_Literals_Block.
+///
+/// For every block that describes a Huffman tree, the symbols are laid out in increasing order of
+/// (weight, symbol). Every symbol with weight > 0 takes up 2^(weight - 1) of the 2^max_bits code
+/// space, and its prefix code is the code space accumulated before it, right-shifted by
+/// (weight - 1). Symbols with weight=0 do not have a prefix code.
+///
+/// Using the example from [RFC 8878][doclink]:
+///
+/// | Symbol | Weight | Pow2 Weight | Acc | Bit Value | Num Bits | Max Bits |
+/// |--------|--------|-------------|-----|-----------|----------|----------|
+/// | 3      | 0      | 0           | 0   | 0         | 0        | 4        | <- is_start
+/// | 4      | 1      | 1           | 0   | 0b0000    | 4        | 4        |
+/// | 5      | 1      | 1           | 1   | 0b0001    | 4        | 4        |
+/// | 2      | 2      | 2           | 2   | 0b001     | 3        | 4        |
+/// | 1      | 3      | 4           | 4   | 0b01      | 2        | 4        |
+/// | 0      | 4      | 8           | 8   | 0b1       | 1        | 4        |
+///
+/// where the code space accumulated over all symbols is 16 == 2^max_bits.
+///
+/// [doclink]: https://www.rfc-editor.org/rfc/rfc8878.html#name-huffman-coding
+#[derive(Clone, Debug)]
+pub struct HuffmanCodesTable {
+    /// Fixed column to mark the first row of the table.
+    q_first: Column<Fixed>,
+    /// The block index in which the Huffman tree was described.
+    pub block_idx: Column<Advice>,
+    /// The symbol, i.e. the literal byte.
+    pub symbol: Column<Advice>,
+    /// The Huffman weight of the symbol.
+    pub weight: Column<Advice>,
+    /// The code space taken by the symbol, i.e. 2^(weight - 1) or 0 if weight=0.
+    pub pow2_weight: Column<Advice>,
+    /// The code space accumulated by the symbols before this symbol.
+    pub acc: Column<Advice>,
+    /// The prefix code's value, as read from the reversed literal stream.
+    pub bit_value: Column<Advice>,
+    /// The number of bits in the prefix code, i.e. max_bits + 1 - weight.
+    pub num_bits: Column<Advice>,
+    /// The number of bits in the longest prefix code of this Huffman tree.
+    pub max_bits: Column<Advice>,
+    /// Whether this row is the first symbol of a Huffman tree.
+    pub is_start: BooleanAdvice,
+    /// Helper gadget to know whether weight == 0.
+    weight_eq_0: IsEqualConfig<Fr>,
+    /// Set if padded row.
+    pub is_padding: BooleanAdvice,
+}
+
+impl HuffmanCodesTable {
+    /// Construct and constrain the Huffman codes table.
+    pub fn configure(
+        meta: &mut ConstraintSystem<Fr>,
+        q_enable: Column<Fixed>,
+        u8_table: U8Table,
+        range16: RangeTable<16>,
+        range_block_len: RangeTable<{ N_BLOCK_SIZE_TARGET as usize }>,
+        pow2_table: Pow2Table<20>,
+    ) -> Self {
+        let weight = meta.advice_column();
+        let config = Self {
+            q_first: meta.fixed_column(),
+            block_idx: meta.advice_column(),
+            symbol: meta.advice_column(),
+            weight,
+            pow2_weight: meta.advice_column(),
+            acc: meta.advice_column(),
+            bit_value: meta.advice_column(),
+            num_bits: meta.advice_column(),
+            max_bits: meta.advice_column(),
+            is_start: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+            weight_eq_0: IsEqualChip::configure(
+                meta,
+                |meta| meta.query_fixed(q_enable, Rotation::cur()),
+                |meta| meta.query_advice(weight, Rotation::cur()),
+                |_| 0.expr(),
+            ),
+            is_padding: BooleanAdvice::construct(meta, |meta| {
+                meta.query_fixed(q_enable, Rotation::cur())
+            }),
+        };
+
+        meta.create_gate("HuffmanCodesTable: first row", |meta| {
+            let condition = meta.query_fixed(config.q_first, Rotation::cur());
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // The table may be empty if no block describes a Huffman tree.
+            cb.require_equal(
+                "is_start == !is_padding on first row",
+                config.is_start.expr_at(meta, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.create_gate("HuffmanCodesTable: main gate", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            let is_weight_zero = config.is_weight_zero(meta, Rotation::cur());
+
+            // Symbols with weight=0 do not take up any code space.
+            cb.condition(is_weight_zero.expr(), |cb| {
+                cb.require_zero(
+                    "weight=0: pow2_weight=0",
+                    meta.query_advice(config.pow2_weight, Rotation::cur()),
+                );
+                cb.require_zero(
+                    "weight=0: num_bits=0",
+                    meta.query_advice(config.num_bits, Rotation::cur()),
+                );
+            });
+
+            // The prefix code is the accumulated code space in units of the symbol's code space.
+            cb.condition(not::expr(is_weight_zero), |cb| {
+                cb.require_equal(
+                    "weight>0: bit_value * 2^(weight - 1) == acc",
+                    meta.query_advice(config.bit_value, Rotation::cur())
+                        * meta.query_advice(config.pow2_weight, Rotation::cur()),
+                    meta.query_advice(config.acc, Rotation::cur()),
+                );
+                cb.require_equal(
+                    "weight>0: num_bits == max_bits + 1 - weight",
+                    meta.query_advice(config.num_bits, Rotation::cur()),
+                    meta.query_advice(config.max_bits, Rotation::cur()) + 1.expr()
+                        - meta.query_advice(config.weight, Rotation::cur()),
+                );
+            });
+
+            // The first symbol of a Huffman tree starts with no accumulated code space.
+            cb.condition(config.is_start.expr_at(meta, Rotation::cur()), |cb| {
+                cb.require_zero(
+                    "is_start: acc=0",
+                    meta.query_advice(config.acc, Rotation::cur()),
+                );
+            });
+
+            cb.gate(condition)
+        });
+
+        meta.create_gate("HuffmanCodesTable: subsequent rows", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(meta.query_fixed(config.q_first, Rotation::cur())),
+            ]);
+
+            let mut cb = BaseConstraintBuilder::default();
+
+            // padding transitions from 0 -> 1 only once.
+            let is_padding_cur = config.is_padding.expr_at(meta, Rotation::cur());
+            let is_padding_prev = config.is_padding.expr_at(meta, Rotation::prev());
+            cb.require_boolean(
+                "is_padding delta is boolean",
+                is_padding_cur.expr() - is_padding_prev,
+            );
+
+            // A padded row cannot start a new Huffman tree.
+            cb.condition(is_padding_cur.expr(), |cb| {
+                cb.require_zero(
+                    "is_padding: is_start=0",
+                    config.is_start.expr_at(meta, Rotation::cur()),
+                );
+            });
+
+            // Within the same Huffman tree, the code space is accumulated.
+            cb.condition(
+                and::expr([
+                    not::expr(is_padding_cur),
+                    not::expr(config.is_start.expr_at(meta, Rotation::cur())),
+                ]),
+                |cb| {
+                    for column in [config.block_idx, config.max_bits] {
+                        cb.require_equal(
+                            "block_idx and max_bits unchanged within Huffman tree",
+                            meta.query_advice(column, Rotation::cur()),
+                            meta.query_advice(column, Rotation::prev()),
+                        );
+                    }
+                    cb.require_equal(
+                        "acc == acc::prev + pow2_weight::prev",
+                        meta.query_advice(config.acc, Rotation::cur()),
+                        meta.query_advice(config.acc, Rotation::prev())
+                            + meta.query_advice(config.pow2_weight, Rotation::prev()),
+                    );
+                },
+            );
+
+            cb.gate(condition)
+        });
+
+        meta.lookup_any("HuffmanCodesTable: pow2_weight == 2^(weight - 1)", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+                not::expr(config.is_weight_zero(meta, Rotation::cur())),
+            ]);
+
+            [
+                meta.query_advice(config.weight, Rotation::cur()) - 1.expr(),
+                meta.query_advice(config.pow2_weight, Rotation::cur()),
+            ]
+            .into_iter()
+            .zip_eq(pow2_table.table_exprs(meta))
+            .map(|(arg, table)| (condition.expr() * arg, table))
+            .collect()
+        });
+
+        meta.lookup_any("HuffmanCodesTable: code space == 2^max_bits", |meta| {
+            // The last symbol of a Huffman tree, i.e. the next row starts a new Huffman tree or is
+            // a padded row.
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+                sum::expr([
+                    config.is_start.expr_at(meta, Rotation::next()),
+                    config.is_padding.expr_at(meta, Rotation::next()),
+                ]),
+            ]);
+
+            [
+                meta.query_advice(config.max_bits, Rotation::cur()),
+                meta.query_advice(config.acc, Rotation::cur())
+                    + meta.query_advice(config.pow2_weight, Rotation::cur()),
+            ]
+            .into_iter()
+            .zip_eq(pow2_table.table_exprs(meta))
+            .map(|(arg, table)| (condition.expr() * arg, table))
+            .collect()
+        });
+
+        meta.lookup("HuffmanCodesTable: symbol in [0, 256)", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            vec![(
+                condition * meta.query_advice(config.symbol, Rotation::cur()),
+                u8_table.into(),
+            )]
+        });
+
+        meta.lookup("HuffmanCodesTable: weight in [0, 16)", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            vec![(
+                condition * meta.query_advice(config.weight, Rotation::cur()),
+                range16.into(),
+            )]
+        });
+
+        meta.lookup("HuffmanCodesTable: (weight, symbol) increasing", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(meta.query_fixed(config.q_first, Rotation::cur())),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            // Within a Huffman tree, the symbols are sorted by (weight, symbol), i.e.
+            // - (weight * 256 + symbol)::cur - (weight * 256 + symbol)::prev > 0
+            //
+            // Across Huffman trees, the block index is increasing, i.e.
+            // - block_idx::cur - block_idx::prev > 0
+            let key = |meta: &mut VirtualCells<Fr>, rotation: Rotation| {
+                meta.query_advice(config.weight, rotation) * 256.expr()
+                    + meta.query_advice(config.symbol, rotation)
+            };
+            let (key_curr, key_prev) = (key(meta, Rotation::cur()), key(meta, Rotation::prev()));
+            let (block_idx_curr, block_idx_prev) = (
+                meta.query_advice(config.block_idx, Rotation::cur()),
+                meta.query_advice(config.block_idx, Rotation::prev()),
+            );
+            let delta = select::expr(
+                config.is_start.expr_at(meta, Rotation::cur()),
+                block_idx_curr - block_idx_prev,
+                key_curr - key_prev,
+            ) - 1.expr();
+
+            vec![(condition * delta, range_block_len.into())]
+        });
+
+        debug_assert!(meta.degree() <= 9);
+        debug_assert!(meta.clone().chunk_lookups().degree() <= 9);
+
+        config
+    }
+
+    /// Assign witness to the Huffman codes table.
+    pub fn assign(
+        &self,
+        layouter: &mut impl Layouter<Fr>,
+        data: &[HuffmanCodesData],
+        n_enabled: usize,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "HuffmanCodesTable",
+            |mut region| {
+                region.assign_fixed(|| "q_first", self.q_first, 0, || Value::known(Fr::one()))?;
+
+                let mut offset = 0;
+                for huffman_codes in data.iter() {
+                    let max_bits = huffman_codes.max_bits();
+                    let mut acc = 0u64;
+                    for (i, (symbol, &weight)) in huffman_codes
+                        .weights
+                        .iter()
+                        .enumerate()
+                        .sorted_by_key(|&(symbol, &weight)| (weight, symbol))
+                        .enumerate()
+                    {
+                        let (pow2_weight, bit_value, num_bits) = if weight > 0 {
+                            (
+                                1 << (weight - 1),
+                                acc >> (weight - 1),
+                                max_bits + 1 - weight,
+                            )
+                        } else {
+                            (0, 0, 0)
+                        };
+                        for (col, value, annotation) in [
+                            (self.block_idx, huffman_codes.block_idx, "block_idx"),
+                            (self.symbol, symbol as u64, "symbol"),
+                            (self.weight, weight, "weight"),
+                            (self.pow2_weight, pow2_weight, "pow2_weight"),
+                            (self.acc, acc, "acc"),
+                            (self.bit_value, bit_value, "bit_value"),
+                            (self.num_bits, num_bits, "num_bits"),
+                            (self.max_bits, max_bits, "max_bits"),
+                            (self.is_start.column, (i == 0) as u64, "is_start"),
+                        ] {
+                            region.assign_advice(
+                                || annotation,
+                                col,
+                                offset,
+                                || Value::known(Fr::from(value)),
+                            )?;
+                        }
+                        let weight_eq_0 = IsEqualChip::construct(self.weight_eq_0.clone());
+                        weight_eq_0.assign(
+                            &mut region,
+                            offset,
+                            Value::known(Fr::from(weight)),
+                            Value::known(Fr::zero()),
+                        )?;
+                        acc += pow2_weight;
+                        offset += 1;
+                    }
+                }
+
+                for offset in offset..n_enabled {
+                    region.assign_advice(
+                        || "is_padding",
+                        self.is_padding.column,
+                        offset,
+                        || Value::known(Fr::one()),
+                    )?;
+                }
+
+                Ok(())
+            },
+        )
+    }
+}
+
+impl HuffmanCodesTable {
+    /// Whether the symbol at this row has weight=0.
+    fn is_weight_zero(&self, meta: &mut VirtualCells<Fr>, rotation: Rotation) -> Expression<Fr> {
+        let weight = meta.query_advice(self.weight, rotation);
+        self.weight_eq_0.expr_at(meta, rotation, weight, 0.expr())
+    }
+
+    /// Lookup table expressions for (block_idx, bit_value, num_bits, symbol) tuple check, i.e. to
+    /// decode a prefix code read from a literal stream into the literal.
+    pub fn table_exprs_by_code(&self, meta: &mut VirtualCells<Fr>) -> Vec<Expression<Fr>> {
+        vec![
+            meta.query_advice(self.block_idx, Rotation::cur()),
+            meta.query_advice(self.bit_value, Rotation::cur()),
+            meta.query_advice(self.num_bits, Rotation::cur()),
+            meta.query_advice(self.symbol, Rotation::cur()),
+            self.is_padding.expr_at(meta, Rotation::cur()),
+        ]
+    }
+
+    /// Lookup table expressions for (block_idx, symbol, weight) tuple check, i.e. to verify the
+    /// Huffman weights decoded from the Huffman tree description.
+    pub fn table_exprs_by_weight(&self, meta: &mut VirtualCells<Fr>) -> Vec<Expression<Fr>> {
+        vec![
+            meta.query_advice(self.block_idx, Rotation::cur()),
+            meta.query_advice(self.symbol, Rotation::cur()),
+            meta.query_advice(self.weight, Rotation::cur()),
+            self.is_padding.expr_at(meta, Rotation::cur()),
+        ]
+    }
+}
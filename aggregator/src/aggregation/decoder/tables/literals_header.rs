@@ -14,7 +14,7 @@ use zkevm_circuits::{
 use crate::aggregation::{
     decoder::witgen::{
         util::{le_bits_to_value, value_bits_le},
-        BlockType,
+        LiteralsBlockType, N_MAX_LITERAL_HEADER_BYTES,
     },
     util::BooleanAdvice,
 };
@@ -24,9 +24,9 @@ use crate::aggregation::{
 pub struct LiteralsHeaderTable {
     /// Fixed column to mark the first row of the table.
     q_first: Column<Fixed>,
-    /// The block index in which we find this literals header. Since every block will have a
-    /// literals header, and block_idx in 1..=n, we know that on the first row block_idx=1 and on
-    /// subsequent rows, block_idx increments by 1.
+    /// The block index in which we find this literals header. Only compressed blocks have a
+    /// literals header, i.e. Raw and RLE blocks are skipped. Hence block_idx >= 1 on the first row
+    /// and strictly increases on subsequent rows.
     pub block_idx: Column<Advice>,
     /// The first byte of the literals header.
     pub byte0: Column<Advice>,
@@ -34,6 +34,13 @@ pub struct LiteralsHeaderTable {
     pub byte1: Column<Advice>,
     /// The third byte.
     pub byte2: Column<Advice>,
+    /// The fourth byte.
+    pub byte3: Column<Advice>,
+    /// The fifth byte.
+    pub byte4: Column<Advice>,
+    /// Whether the literals are Huffman-compressed, i.e. Compressed_Literals_Block or
+    /// Treeless_Literals_Block. This is the bit1 of the literals block type.
+    pub is_compressed: Column<Advice>,
     /// The bit0 of size format.
     pub size_format_bit0: Column<Advice>,
     /// The bit1 of size format.
@@ -42,6 +49,12 @@ pub struct LiteralsHeaderTable {
     pub byte0_rs_3: Column<Advice>,
     /// byte0 >> 4.
     pub byte0_rs_4: Column<Advice>,
+    /// byte1 >> 6.
+    pub byte1_rs_6: Column<Advice>,
+    /// byte2 >> 2.
+    pub byte2_rs_2: Column<Advice>,
+    /// byte2 >> 6.
+    pub byte2_rs_6: Column<Advice>,
     /// Regenerated size.
     pub regen_size: Column<Advice>,
     /// Set if padded row
@@ -55,6 +68,7 @@ impl LiteralsHeaderTable {
         q_enable: Column<Fixed>,
         range8: RangeTable<8>,
         range16: RangeTable<16>,
+        range512: RangeTable<512>,
     ) -> Self {
         let config = Self {
             q_first: meta.fixed_column(),
@@ -62,34 +76,46 @@ impl LiteralsHeaderTable {
             byte0: meta.advice_column(),
             byte1: meta.advice_column(),
             byte2: meta.advice_column(),
+            byte3: meta.advice_column(),
+            byte4: meta.advice_column(),
+            is_compressed: meta.advice_column(),
             size_format_bit0: meta.advice_column(),
             size_format_bit1: meta.advice_column(),
             byte0_rs_3: meta.advice_column(),
             byte0_rs_4: meta.advice_column(),
+            byte1_rs_6: meta.advice_column(),
+            byte2_rs_2: meta.advice_column(),
+            byte2_rs_6: meta.advice_column(),
             regen_size: meta.advice_column(),
             is_padding: BooleanAdvice::construct(meta, |meta| {
                 meta.query_fixed(q_enable, Rotation::cur())
             }),
         };
 
-        meta.create_gate("LiteralsHeaderTable: first row", |meta| {
-            let condition = meta.query_fixed(config.q_first, Rotation::cur());
-
-            let mut cb = BaseConstraintBuilder::default();
-
-            cb.require_zero(
-                "is_padding=0 on first row",
-                config.is_padding.expr_at(meta, Rotation::cur()),
-            );
+        // block_idx strictly increases, starting from a block_idx >= 1.
+        //
+        // This also ensures that we are not populating conflicting literal headers for the same
+        // block_idx in this layout.
+        meta.lookup(
+            "LiteralsHeaderTable: block_idx strictly increases",
+            |meta| {
+                let condition = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+                ]);
 
-            cb.require_equal(
-                "block_idx=1 on first row",
-                meta.query_advice(config.block_idx, Rotation::cur()),
-                1.expr(),
-            );
+                let block_idx_prev = select::expr(
+                    meta.query_fixed(config.q_first, Rotation::cur()),
+                    0.expr(),
+                    meta.query_advice(config.block_idx, Rotation::prev()),
+                );
+                let range_value = meta.query_advice(config.block_idx, Rotation::cur())
+                    - block_idx_prev
+                    - 1.expr();
 
-            cb.gate(condition)
-        });
+                vec![(condition * range_value, range512.into())]
+            },
+        );
 
         meta.create_gate("LiteralsHeaderTable: main gate", |meta| {
             let condition = and::expr([
@@ -103,9 +129,12 @@ impl LiteralsHeaderTable {
             let sf1 = meta.query_advice(config.size_format_bit1, Rotation::cur());
             let byte0_rs_3 = meta.query_advice(config.byte0_rs_3, Rotation::cur());
             let byte0_rs_4 = meta.query_advice(config.byte0_rs_4, Rotation::cur());
-            let byte1_ls_4 = meta.query_advice(config.byte1, Rotation::cur()) * 16.expr();
-            let byte2_ls_12 = meta.query_advice(config.byte2, Rotation::cur()) * 4096.expr();
+            let byte1 = meta.query_advice(config.byte1, Rotation::cur());
+            let byte2 = meta.query_advice(config.byte2, Rotation::cur());
+            let byte1_ls_4 = byte1.expr() * 16.expr();
+            let byte2_ls_12 = byte2.expr() * 4096.expr();
 
+            // Raw_Literals_Block:
             // - branch0: Size_Format is 00 or 10
             // - branch1: Size_Format is 01
             // - branch2: Size_Format is 11
@@ -116,12 +145,36 @@ impl LiteralsHeaderTable {
             let branch1_regen_size = byte0_rs_4.expr() + byte1_ls_4.expr();
             let branch2_regen_size = byte0_rs_4.expr() + byte1_ls_4.expr() + byte2_ls_12;
 
-            let regen_size = select::expr(
+            let raw_regen_size = select::expr(
                 branch1,
                 branch1_regen_size,
                 select::expr(branch2, branch2_regen_size, branch0_regen_size),
             );
 
+            // Compressed_Literals_Block or Treeless_Literals_Block:
+            // - Size_Format is 00 or 01: 10 bits regenerated size
+            // - Size_Format is 10: 14 bits regenerated size
+            // - Size_Format is 11: 18 bits regenerated size
+            let byte1_and_63 =
+                byte1.expr() - meta.query_advice(config.byte1_rs_6, Rotation::cur()) * 64.expr();
+            let byte2_and_3 =
+                byte2.expr() - meta.query_advice(config.byte2_rs_2, Rotation::cur()) * 4.expr();
+            let byte2_and_63 =
+                byte2.expr() - meta.query_advice(config.byte2_rs_6, Rotation::cur()) * 64.expr();
+            let compressed_regen_size = select::expr(
+                sf1.expr(),
+                byte0_rs_4.expr()
+                    + byte1_ls_4
+                    + select::expr(sf0.expr(), byte2_and_63, byte2_and_3) * 4096.expr(),
+                byte0_rs_4 + byte1_and_63 * 16.expr(),
+            );
+
+            let regen_size = select::expr(
+                meta.query_advice(config.is_compressed, Rotation::cur()),
+                compressed_regen_size,
+                raw_regen_size,
+            );
+
             cb.require_equal(
                 "regen_size computation",
                 regen_size,
@@ -148,18 +201,6 @@ impl LiteralsHeaderTable {
 
                 cb.require_boolean("is_padding delta is boolean", is_padding_delta);
 
-                // block_idx increments.
-                //
-                // This also ensures that we are not populating conflicting literal headers for the
-                // same block_idx in this layout.
-                cb.condition(not::expr(is_padding_cur), |cb| {
-                    cb.require_equal(
-                        "block_idx increments",
-                        meta.query_advice(config.block_idx, Rotation::cur()),
-                        meta.query_advice(config.block_idx, Rotation::prev()) + 1.expr(),
-                    );
-                });
-
                 cb.gate(condition)
             },
         );
@@ -188,6 +229,81 @@ impl LiteralsHeaderTable {
             vec![(condition * range_value, range16.into())]
         });
 
+        meta.lookup("LiteralsHeaderTable: byte1 >> 6", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let range_value = meta.query_advice(config.byte1_rs_6, Rotation::cur());
+
+            vec![(condition * range_value, range8.into())]
+        });
+
+        meta.lookup("LiteralsHeaderTable: byte1 & 63", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            // 0 <= byte1 & 63 < 64, i.e. 0 <= (byte1 & 63) * 8 < 512.
+            let range_value = (meta.query_advice(config.byte1, Rotation::cur())
+                - (meta.query_advice(config.byte1_rs_6, Rotation::cur()) * 64.expr()))
+                * 8.expr();
+
+            vec![(condition * range_value, range512.into())]
+        });
+
+        meta.lookup("LiteralsHeaderTable: byte2 >> 2", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let range_value = meta.query_advice(config.byte2_rs_2, Rotation::cur());
+
+            vec![(condition * range_value, range512.into())]
+        });
+
+        meta.lookup("LiteralsHeaderTable: byte2 & 3", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            // 0 <= byte2 & 3 < 4, i.e. 0 <= (byte2 & 3) * 2 < 8.
+            let range_value = (meta.query_advice(config.byte2, Rotation::cur())
+                - (meta.query_advice(config.byte2_rs_2, Rotation::cur()) * 4.expr()))
+                * 2.expr();
+
+            vec![(condition * range_value, range8.into())]
+        });
+
+        meta.lookup("LiteralsHeaderTable: byte2 >> 6", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            let range_value = meta.query_advice(config.byte2_rs_6, Rotation::cur());
+
+            vec![(condition * range_value, range8.into())]
+        });
+
+        meta.lookup("LiteralsHeaderTable: byte2 & 63", |meta| {
+            let condition = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                not::expr(config.is_padding.expr_at(meta, Rotation::cur())),
+            ]);
+
+            // 0 <= byte2 & 63 < 64, i.e. 0 <= (byte2 & 63) * 8 < 512.
+            let range_value = (meta.query_advice(config.byte2, Rotation::cur())
+                - (meta.query_advice(config.byte2_rs_6, Rotation::cur()) * 64.expr()))
+                * 8.expr();
+
+            vec![(condition * range_value, range512.into())]
+        });
+
         debug_assert!(meta.degree() <= 9);
         debug_assert!(meta.clone().chunk_lookups().degree() <= 9);
 
@@ -198,7 +314,7 @@ impl LiteralsHeaderTable {
     pub fn assign<F: Field>(
         &self,
         layouter: &mut impl Layouter<F>,
-        literals_headers: Vec<(u64, u64, (u64, u64, u64))>,
+        literals_headers: Vec<(u64, u64, [u64; N_MAX_LITERAL_HEADER_BYTES])>,
         n_enabled: usize,
     ) -> Result<(), Error> {
         layouter.assign_region(
@@ -206,23 +322,32 @@ impl LiteralsHeaderTable {
             |mut region| {
                 region.assign_fixed(|| "q_first", self.q_first, 0, || Value::known(F::one()))?;
 
-                for (offset, (block_idx, _byte_offset, (byte0, byte1, byte2))) in
+                for (offset, (block_idx, _byte_offset, bytes)) in
                     literals_headers.clone().into_iter().enumerate()
                 {
-                    let lh_bytes = [byte0 as u8, byte1 as u8, byte2 as u8];
-                    let literals_block_type = BlockType::from(lh_bytes[0] & 0x3);
+                    let [byte0, byte1, byte2, byte3, byte4] = bytes;
+                    let lh_bytes = bytes.map(|byte| byte as u8);
+                    let literals_block_type = LiteralsBlockType::from(lh_bytes[0] & 0x3);
                     let size_format = (lh_bytes[0] >> 2) & 3;
 
                     let [n_bits_fmt, n_bits_regen, n_bytes_header]: [usize; 3] =
                         match literals_block_type {
-                            BlockType::RawBlock => match size_format {
+                            LiteralsBlockType::Raw => match size_format {
                                 0b00 | 0b10 => [1, 5, 1],
                                 0b01 => [2, 12, 2],
                                 0b11 => [2, 20, 3],
                                 _ => unreachable!("size_format out of bound"),
                             },
-                            _ => unreachable!(
-                                "BlockType::* unexpected. Must be raw bytes for literals."
+                            LiteralsBlockType::Compressed | LiteralsBlockType::Treeless => {
+                                match size_format {
+                                    0b00 | 0b01 => [2, 10, 3],
+                                    0b10 => [2, 14, 4],
+                                    0b11 => [2, 18, 5],
+                                    _ => unreachable!("size_format out of bound"),
+                                }
+                            }
+                            LiteralsBlockType::Rle => unreachable!(
+                                "LiteralsBlockType::Rle unexpected. Must be raw or Huffman-compressed."
                             ),
                         };
 
@@ -239,6 +364,13 @@ impl LiteralsHeaderTable {
                         (self.byte0, byte0, "byte0"),
                         (self.byte1, byte1, "byte1"),
                         (self.byte2, byte2, "byte2"),
+                        (self.byte3, byte3, "byte3"),
+                        (self.byte4, byte4, "byte4"),
+                        (
+                            self.is_compressed,
+                            ((byte0 & 2) >> 1),
+                            "is_compressed",
+                        ),
                         (self.regen_size, regen_size, "regen_size"),
                         (
                             self.size_format_bit0,
@@ -252,6 +384,9 @@ impl LiteralsHeaderTable {
                         ),
                         (self.byte0_rs_3, byte0 >> 3, "byte0_rs_3"),
                         (self.byte0_rs_4, byte0 >> 4, "byte0_rs_4"),
+                        (self.byte1_rs_6, byte1 >> 6, "byte1_rs_6"),
+                        (self.byte2_rs_2, byte2 >> 2, "byte2_rs_2"),
+                        (self.byte2_rs_6, byte2 >> 6, "byte2_rs_6"),
                     ] {
                         region.assign_advice(
                             || annotation,
@@ -284,6 +419,9 @@ impl LookupTable<Fr> for LiteralsHeaderTable {
             self.byte0.into(),
             self.byte1.into(),
             self.byte2.into(),
+            self.byte3.into(),
+            self.byte4.into(),
+            self.is_compressed.into(),
             self.size_format_bit0.into(),
             self.size_format_bit1.into(),
             self.regen_size.into(),
@@ -298,6 +436,9 @@ impl LookupTable<Fr> for LiteralsHeaderTable {
             String::from("byte0"),
             String::from("byte1"),
             String::from("byte2"),
+            String::from("byte3"),
+            String::from("byte4"),
+            String::from("is_compressed"),
             String::from("size_format_bit0"),
             String::from("size_format_bit1"),
             String::from("regen_size"),
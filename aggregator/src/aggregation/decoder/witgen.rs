@@ -94,6 +94,7 @@ fn process_frame_header<F: Field>(
             decoded_data: DecodedData { decoded_len: fcs },
             bitstream_read_data: BitstreamReadRow::default(),
             fse_data: FseDecodingRow::default(),
+            literals_data: LiteralsDecodingRow::default(),
         })
         .chain(fcs_bytes.iter().zip(tag_rlc_iter.iter()).enumerate().map(
             |(i, (&value_byte, &tag_rlc_acc))| ZstdWitnessRow {
@@ -120,6 +121,7 @@ fn process_frame_header<F: Field>(
                 decoded_data: DecodedData { decoded_len: fcs },
                 bitstream_read_data: BitstreamReadRow::default(),
                 fse_data: FseDecodingRow::default(),
+                literals_data: LiteralsDecodingRow::default(),
             },
         ))
         .collect::<Vec<_>>(),
@@ -133,12 +135,15 @@ pub struct AggregateBlockResult<F> {
     pub block_info: BlockInfo,
     pub sequence_info: SequenceInfo,
     pub literal_bytes: Vec<u64>,
-    pub fse_aux_tables: [FseAuxiliaryTableData; 3], // 3 sequence section FSE tables
+    pub fse_aux_tables: Vec<FseAuxiliaryTableData>, // 3 sequence section FSE tables, if any
     pub address_table_rows: Vec<AddressTableRow>,
     pub sequence_exec_result: SequenceExecResult,
     pub repeated_offset: [usize; 3], // repeated offsets are carried forward between blocks.
+    pub huffman_codes: Option<HuffmanCodesData>, // Huffman codes are carried forward between blocks.
+    pub huffman_fse_aux_table: Option<FseAuxiliaryTableData>,
 }
 
+#[allow(clippy::too_many_arguments)]
 fn process_block<F: Field>(
     src: &[u8],
     decoded_bytes: &mut Vec<u8>,
@@ -147,6 +152,8 @@ fn process_block<F: Field>(
     last_row: &ZstdWitnessRow<F>,
     randomness: Value<F>,
     repeated_offset: [usize; 3],
+    huffman_codes: Option<HuffmanCodesData>,
+    prev_fse_aux_tables: &[FseAuxiliaryTableData],
 ) -> AggregateBlockResult<F> {
     let mut witness_rows = vec![];
 
@@ -166,6 +173,8 @@ fn process_block<F: Field>(
         sequence_exec_result,
         repeated_offset,
         regen_size,
+        huffman_codes,
+        huffman_fse_aux_table,
     } = match block_info.block_type {
         BlockType::ZstdCompressedBlock => process_block_zstd(
             src,
@@ -177,8 +186,20 @@ fn process_block<F: Field>(
             block_info.block_len,
             block_info.is_last_block,
             repeated_offset,
+            huffman_codes,
+            prev_fse_aux_tables,
+        ),
+        BlockType::RawBlock | BlockType::RleBlock => process_block_raw_rle(
+            src,
+            decoded_bytes,
+            &block_info,
+            byte_offset,
+            last_row,
+            randomness,
+            repeated_offset,
+            huffman_codes,
         ),
-        _ => unreachable!("BlockType::ZstdCompressedBlock expected"),
+        BlockType::Reserved => unreachable!("Block_Type=Reserved is invalid"),
     };
     block_info.regen_size = regen_size;
     witness_rows.extend_from_slice(&rows);
@@ -193,6 +214,8 @@ fn process_block<F: Field>(
         address_table_rows,
         sequence_exec_result,
         repeated_offset,
+        huffman_codes,
+        huffman_fse_aux_table,
     }
 }
 
@@ -219,8 +242,10 @@ fn process_block_header<F: Field>(
         (bh_bytes[2] as usize * 256 * 256 + bh_bytes[1] as usize * 256 + bh_bytes[0] as usize) >> 3;
 
     let tag_next = match block_info.block_type {
+        BlockType::RawBlock => ZstdTag::RawBlockBytes,
+        BlockType::RleBlock => ZstdTag::RleBlockBytes,
         BlockType::ZstdCompressedBlock => ZstdTag::ZstdBlockLiteralsHeader,
-        _ => unreachable!("BlockType::ZstdCompressedBlock expected"),
+        BlockType::Reserved => unreachable!("Block_Type=Reserved is invalid"),
     };
 
     let tag_rlc_iter = bh_bytes
@@ -265,6 +290,7 @@ fn process_block_header<F: Field>(
                 bitstream_read_data: BitstreamReadRow::default(),
                 decoded_data: last_row.decoded_data.clone(),
                 fse_data: FseDecodingRow::default(),
+                literals_data: last_row.literals_data.carry(),
             })
             .collect::<Vec<_>>(),
         block_info,
@@ -283,11 +309,13 @@ pub struct BlockProcessingResult<F> {
     pub witness_rows: Vec<ZstdWitnessRow<F>>,
     pub literals: Vec<u64>,
     pub sequence_info: SequenceInfo,
-    pub fse_aux_tables: [FseAuxiliaryTableData; 3], // 3 sequence section FSE tables
+    pub fse_aux_tables: Vec<FseAuxiliaryTableData>, // 3 sequence section FSE tables, if any
     pub address_table_rows: Vec<AddressTableRow>,
     pub sequence_exec_result: SequenceExecResult,
     pub repeated_offset: [usize; 3], // repeated offsets are carried forward between blocks
     pub regen_size: u64,
+    pub huffman_codes: Option<HuffmanCodesData>, // Huffman codes are carried forward between blocks
+    pub huffman_fse_aux_table: Option<FseAuxiliaryTableData>,
 }
 
 #[derive(Debug, Clone)]
@@ -295,6 +323,10 @@ pub struct LiteralsBlockResult<F> {
     pub offset: usize,
     pub witness_rows: Vec<ZstdWitnessRow<F>>,
     pub literals: Vec<u64>,
+    /// The Huffman codes in use, carried forward to a following Treeless literals block.
+    pub huffman_codes: Option<HuffmanCodesData>,
+    /// The FSE table used to decode the Huffman weights, if they were FSE-compressed.
+    pub huffman_fse_aux_table: Option<FseAuxiliaryTableData>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -308,73 +340,31 @@ fn process_block_zstd<F: Field>(
     block_size: usize,
     last_block: bool,
     repeated_offset: [usize; 3],
+    huffman_codes: Option<HuffmanCodesData>,
+    prev_fse_aux_tables: &[FseAuxiliaryTableData],
 ) -> BlockProcessingResult<F> {
     let expected_end_offset = byte_offset + block_size;
     let mut witness_rows = vec![];
 
     // 1-5 bytes LiteralSectionHeader
-    let LiteralsHeaderProcessingResult {
-        offset: byte_offset,
-        witness_rows: rows,
-        regen_size,
-    } = process_block_zstd_literals_header::<F>(src, block_idx, byte_offset, last_row, randomness);
-
-    witness_rows.extend_from_slice(&rows);
+    let literals_header =
+        process_block_zstd_literals_header::<F>(src, block_idx, byte_offset, last_row, randomness);
+    let regen_size = literals_header.regen_size;
+    witness_rows.extend_from_slice(&literals_header.witness_rows);
 
     let LiteralsBlockResult {
         offset: byte_offset,
         witness_rows: rows,
         literals,
-    } = {
-        let last_row = rows.last().cloned().unwrap();
-        let multiplier =
-            (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
-        let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
-        let tag = ZstdTag::ZstdBlockLiteralsRawBytes;
-        let tag_next = ZstdTag::ZstdBlockSequenceHeader;
-        let literals = src[byte_offset..(byte_offset + regen_size)].to_vec();
-        let tag_rlc_iter = literals.iter().scan(Value::known(F::zero()), |acc, &byte| {
-            *acc = *acc * randomness + Value::known(F::from(byte as u64));
-            Some(*acc)
-        });
-        let tag_rlc = tag_rlc_iter.clone().last().expect("Literals must exist.");
-
-        LiteralsBlockResult {
-            offset: byte_offset + regen_size,
-            witness_rows: literals
-                .iter()
-                .zip(tag_rlc_iter)
-                .enumerate()
-                .map(|(i, (&value_byte, tag_rlc_acc))| ZstdWitnessRow {
-                    state: ZstdState {
-                        tag,
-                        tag_next,
-                        block_idx,
-                        max_tag_len: tag.max_len(),
-                        tag_len: regen_size as u64,
-                        tag_idx: (i + 1) as u64,
-                        is_tag_change: i == 0,
-                        tag_rlc,
-                        tag_rlc_acc,
-                    },
-                    encoded_data: EncodedData {
-                        byte_idx: (byte_offset + i + 1) as u64,
-                        encoded_len: last_row.encoded_data.encoded_len,
-                        value_byte,
-                        value_rlc,
-                        reverse: false,
-                        ..Default::default()
-                    },
-                    decoded_data: DecodedData {
-                        decoded_len: last_row.decoded_data.decoded_len,
-                    },
-                    bitstream_read_data: BitstreamReadRow::default(),
-                    fse_data: FseDecodingRow::default(),
-                })
-                .collect::<Vec<_>>(),
-            literals: literals.iter().map(|b| *b as u64).collect::<Vec<u64>>(),
-        }
-    };
+        huffman_codes,
+        huffman_fse_aux_table,
+    } = process_block_zstd_literals::<F>(
+        src,
+        block_idx,
+        &literals_header,
+        huffman_codes,
+        randomness,
+    );
 
     witness_rows.extend_from_slice(&rows);
 
@@ -400,6 +390,7 @@ fn process_block_zstd<F: Field>(
         last_block,
         randomness,
         repeated_offset,
+        prev_fse_aux_tables,
     );
 
     // sanity check:
@@ -414,7 +405,7 @@ fn process_block_zstd<F: Field>(
         witness_rows,
         literals,
         sequence_info,
-        fse_aux_tables,
+        fse_aux_tables: fse_aux_tables.to_vec(),
         address_table_rows,
         sequence_exec_result: SequenceExecResult {
             exec_trace: sequence_exec,
@@ -422,6 +413,123 @@ fn process_block_zstd<F: Field>(
         },
         repeated_offset,
         regen_size: regen_size as u64,
+        huffman_codes,
+        huffman_fse_aux_table,
+    }
+}
+
+/// Process the contents of a Raw_Block or RLE_Block.
+///
+/// The block's decoded bytes are treated as the literals of a block with no sequences, so that
+/// they are copied as-is to the decoded data during sequence execution. A Raw_Block consists of
+/// Block_Size bytes, one row per byte. An RLE_Block consists of a single byte, that is repeated
+/// Block_Size times, i.e. Block_Size rows for the same byte.
+#[allow(clippy::too_many_arguments)]
+fn process_block_raw_rle<F: Field>(
+    src: &[u8],
+    decoded_bytes: &mut Vec<u8>,
+    block_info: &BlockInfo,
+    byte_offset: usize,
+    last_row: &ZstdWitnessRow<F>,
+    randomness: Value<F>,
+    repeated_offset: [usize; 3],
+    huffman_codes: Option<HuffmanCodesData>,
+) -> BlockProcessingResult<F> {
+    let block_idx = block_info.block_idx as u64;
+    let regen_size = block_info.block_len;
+    assert!(regen_size > 0, "Raw/RLE blocks must not be empty");
+
+    let tag_next = if block_info.is_last_block {
+        ZstdTag::Null
+    } else {
+        ZstdTag::BlockHeader
+    };
+    let literals_data = |i: usize, literal: u8| LiteralsDecodingRow {
+        is_literal: true,
+        literal_idx: (i + 1) as u64,
+        literal: literal as u64,
+        is_last_literal: i + 1 == regen_size,
+        ..last_row.literals_data.carry()
+    };
+
+    let (end_offset, witness_rows, literals) = if block_info.block_type == BlockType::RawBlock {
+        let end_offset = byte_offset + regen_size;
+        let literals = src[byte_offset..end_offset].to_vec();
+        let witness_rows = process_bytes(
+            src,
+            block_idx,
+            byte_offset,
+            end_offset,
+            ZstdTag::RawBlockBytes,
+            tag_next,
+            last_row,
+            randomness,
+            |i| literals_data(i, literals[i]),
+        );
+        (end_offset, witness_rows, literals)
+    } else {
+        let value_byte = src[byte_offset];
+        let multiplier =
+            (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
+        let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+        let tag_rlc = Value::known(F::from(value_byte as u64));
+
+        // The single byte is not consumed again, i.e. byte_idx remains the same over all rows.
+        let witness_rows = (0..regen_size)
+            .map(|i| ZstdWitnessRow {
+                state: ZstdState {
+                    tag: ZstdTag::RleBlockBytes,
+                    tag_next,
+                    block_idx,
+                    max_tag_len: ZstdTag::RleBlockBytes.max_len(),
+                    tag_len: 1,
+                    tag_idx: 1,
+                    is_tag_change: i == 0,
+                    tag_rlc,
+                    tag_rlc_acc: tag_rlc,
+                },
+                encoded_data: EncodedData {
+                    byte_idx: (byte_offset + 1) as u64,
+                    encoded_len: last_row.encoded_data.encoded_len,
+                    value_byte,
+                    value_rlc,
+                    reverse: false,
+                    ..Default::default()
+                },
+                decoded_data: last_row.decoded_data.clone(),
+                bitstream_read_data: BitstreamReadRow::default(),
+                fse_data: FseDecodingRow::default(),
+                literals_data: literals_data(i, value_byte),
+            })
+            .collect::<Vec<_>>();
+        (byte_offset + 1, witness_rows, vec![value_byte; regen_size])
+    };
+
+    // All the literals are copied after the (non-existent) last sequence.
+    decoded_bytes.extend_from_slice(&literals);
+    let sequence_exec = vec![SequenceExec(
+        0,
+        SequenceExecInfo::LiteralCopy(0..regen_size),
+    )];
+
+    BlockProcessingResult {
+        offset: end_offset,
+        witness_rows,
+        literals: literals.iter().map(|&b| b as u64).collect::<Vec<u64>>(),
+        sequence_info: SequenceInfo {
+            block_idx: block_idx as usize,
+            ..Default::default()
+        },
+        fse_aux_tables: vec![],
+        address_table_rows: vec![],
+        sequence_exec_result: SequenceExecResult {
+            exec_trace: sequence_exec,
+            recovered_bytes: literals,
+        },
+        repeated_offset,
+        regen_size: regen_size as u64,
+        huffman_codes,
+        huffman_fse_aux_table: None,
     }
 }
 
@@ -449,6 +557,7 @@ fn process_sequences<F: Field>(
     last_block: bool,
     randomness: Value<F>,
     mut repeated_offset: [usize; 3],
+    prev_fse_aux_tables: &[FseAuxiliaryTableData],
 ) -> SequencesProcessingResult<F> {
     // Initialize witness values
     let mut witness_rows: Vec<ZstdWitnessRow<F>> = vec![];
@@ -489,38 +598,21 @@ fn process_sequences<F: Field>(
         .expect("Compression mode byte must exist.");
     let mode_bits = value_bits_le(compression_mode_byte);
 
-    let literal_lengths_mode = mode_bits[6] + mode_bits[7] * 2;
-    let offsets_mode = mode_bits[4] + mode_bits[5] * 2;
-    let match_lengths_mode = mode_bits[2] + mode_bits[3] * 2;
+    let literal_lengths_mode = CompressionMode::from(mode_bits[6] + mode_bits[7] * 2);
+    let offsets_mode = CompressionMode::from(mode_bits[4] + mode_bits[5] * 2);
+    let match_lengths_mode = CompressionMode::from(mode_bits[2] + mode_bits[3] * 2);
     let reserved = mode_bits[0] + mode_bits[1] * 2;
 
     assert!(reserved == 0, "Reserved bits must be 0");
-
-    // Note: Only 2 modes of FSE encoding are accepted (instead of 4):
-    // 0 - Predefined.
-    // 2 - Variable bit packing.
-    assert!(
-        literal_lengths_mode == 2 || literal_lengths_mode == 0,
-        "Only FSE_Compressed_Mode or Predefined are allowed"
-    );
-    assert!(
-        offsets_mode == 2 || offsets_mode == 0,
-        "Only FSE_Compressed_Mode or Predefined are allowed"
-    );
-    assert!(
-        match_lengths_mode == 2 || match_lengths_mode == 0,
-        "Only FSE_Compressed_Mode or Predefined are allowed"
-    );
-    sequence_info.compression_mode = [
-        literal_lengths_mode > 0,
-        offsets_mode > 0,
-        match_lengths_mode > 0,
-    ];
+    sequence_info.compression_mode = [literal_lengths_mode, offsets_mode, match_lengths_mode];
 
     let multiplier =
         (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
     let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
-    let is_all_predefined_fse = literal_lengths_mode + offsets_mode + match_lengths_mode < 1;
+    // The FSE tables in Predefined_Mode or Repeat_Mode are not described in the sequences section.
+    let is_no_fse_description = !(literal_lengths_mode.is_described()
+        || offsets_mode.is_described()
+        || match_lengths_mode.is_described());
 
     // Add witness rows for the sequence header
     let sequence_header_start_offset = byte_offset;
@@ -541,7 +633,7 @@ fn process_sequences<F: Field>(
         .map(|(i, (&value_byte, tag_rlc_acc))| ZstdWitnessRow {
             state: ZstdState {
                 tag: ZstdTag::ZstdBlockSequenceHeader,
-                tag_next: if is_all_predefined_fse {
+                tag_next: if is_no_fse_description {
                     ZstdTag::ZstdBlockSequenceData
                 } else {
                     ZstdTag::ZstdBlockSequenceFseCode
@@ -567,6 +659,7 @@ fn process_sequences<F: Field>(
             },
             bitstream_read_data: BitstreamReadRow::default(),
             fse_data: FseDecodingRow::default(),
+            literals_data: last_row.literals_data.carry(),
         })
         .collect::<Vec<_>>();
 
@@ -578,81 +671,75 @@ fn process_sequences<F: Field>(
     let byte_offset = sequence_header_end_offset;
     let fse_starting_byte_offset = byte_offset;
 
+    // The FSE table of a symbol type, depending on its compression mode:
+    // - Predefined_Mode: the table is constructed from the predefined distribution.
+    // - RLE_Mode: the table description is a single byte, i.e. the only symbol.
+    // - FSE_Compressed_Mode: the table description is the FSE-compressed distribution.
+    // - Repeat_Mode: the table is the one used by the previous block that decoded sequences.
+    let reconstruct_table =
+        |table_kind: FseTableKind, mode: CompressionMode, byte_offset: usize| match mode {
+            CompressionMode::Predefined | CompressionMode::FseCompressed => {
+                FseAuxiliaryTableData::reconstruct(
+                    src,
+                    block_idx,
+                    table_kind,
+                    byte_offset,
+                    mode == CompressionMode::Predefined,
+                )
+                .expect("Reconstructing FSE table should not fail.")
+            }
+            CompressionMode::Rle => {
+                let symbol = *src
+                    .get(byte_offset)
+                    .expect("RLE_Mode symbol byte must exist.");
+                (
+                    1,
+                    vec![],
+                    FseAuxiliaryTableData::reconstruct_rle(block_idx, table_kind, symbol),
+                )
+            }
+            CompressionMode::Repeat => {
+                let table = prev_fse_aux_tables
+                    .iter()
+                    .find(|table| table.table_kind == table_kind)
+                    .expect("Repeat_Mode requires the FSE table of a previous block.");
+                (0, vec![], table.repeat(block_idx))
+            }
+        };
+
     // Literal Length Table (LLT)
-    let (n_fse_bytes_llt, bit_boundaries_llt, table_llt) = FseAuxiliaryTableData::reconstruct(
-        src,
-        block_idx,
-        FseTableKind::LLT,
-        byte_offset,
-        literal_lengths_mode < 2,
-    )
-    .expect("Reconstructing FSE-packed Literl Length (LL) table should not fail.");
+    let (n_fse_bytes_llt, bit_boundaries_llt, table_llt) =
+        reconstruct_table(FseTableKind::LLT, literal_lengths_mode, byte_offset);
     let llt = table_llt.parse_state_table();
-    // Determine the accuracy log of LLT
-    let al_llt = if literal_lengths_mode > 0 {
-        bit_boundaries_llt
-            .first()
-            .expect("Accuracy Log should exist")
-            .1
-            + 5
-    } else {
-        6
-    };
 
     // Cooked Match Offset Table (CMOT)
     let byte_offset = byte_offset + n_fse_bytes_llt;
-    let (n_fse_bytes_cmot, bit_boundaries_cmot, table_cmot) = FseAuxiliaryTableData::reconstruct(
-        src,
-        block_idx,
-        FseTableKind::MOT,
-        byte_offset,
-        offsets_mode < 2,
-    )
-    .expect("Reconstructing FSE-packed Cooked Match Offset (CMO) table should not fail.");
+    let (n_fse_bytes_cmot, bit_boundaries_cmot, table_cmot) =
+        reconstruct_table(FseTableKind::MOT, offsets_mode, byte_offset);
     let cmot = table_cmot.parse_state_table();
-    // Determine the accuracy log of CMOT
-    let al_cmot = if offsets_mode > 0 {
-        bit_boundaries_cmot
-            .first()
-            .expect("Accuracy Log should exist")
-            .1
-            + 5
-    } else {
-        5
-    };
 
     // Match Length Table (MLT)
     let byte_offset = byte_offset + n_fse_bytes_cmot;
-    let (n_fse_bytes_mlt, bit_boundaries_mlt, table_mlt) = FseAuxiliaryTableData::reconstruct(
-        src,
-        block_idx,
-        FseTableKind::MLT,
-        byte_offset,
-        match_lengths_mode < 2,
-    )
-    .expect("Reconstructing FSE-packed Match Length (ML) table should not fail.");
+    let (n_fse_bytes_mlt, bit_boundaries_mlt, table_mlt) =
+        reconstruct_table(FseTableKind::MLT, match_lengths_mode, byte_offset);
     let mlt = table_mlt.parse_state_table();
-    // Determine the accuracy log of MLT
-    let al_mlt = if match_lengths_mode > 0 {
-        bit_boundaries_mlt
-            .first()
-            .expect("Accuracy Log should exist")
-            .1
-            + 5
-    } else {
-        6
-    };
+
+    // The accuracy log of each table, i.e. table_size == 1 << AL.
+    let al_llt = table_llt.table_size.trailing_zeros();
+    let al_cmot = table_cmot.table_size.trailing_zeros();
+    let al_mlt = table_mlt.table_size.trailing_zeros();
 
     // Add witness rows for the above three FSE tables
     let mut last_row = header_rows.last().cloned().unwrap();
-    for (start_offset, end_offset, bit_boundaries, tag_len, table, is_fse_section_end) in [
+    for (start_offset, end_offset, bit_boundaries, tag_len, table, mode, is_fse_section_end) in [
         (
             fse_starting_byte_offset,
             fse_starting_byte_offset + n_fse_bytes_llt,
             bit_boundaries_llt,
             n_fse_bytes_llt as u64,
             &table_llt,
-            offsets_mode + match_lengths_mode < 1,
+            literal_lengths_mode,
+            !(offsets_mode.is_described() || match_lengths_mode.is_described()),
         ),
         (
             fse_starting_byte_offset + n_fse_bytes_llt,
@@ -660,7 +747,8 @@ fn process_sequences<F: Field>(
             bit_boundaries_cmot,
             n_fse_bytes_cmot as u64,
             &table_cmot,
-            match_lengths_mode < 1,
+            offsets_mode,
+            !match_lengths_mode.is_described(),
         ),
         (
             fse_starting_byte_offset + n_fse_bytes_llt + n_fse_bytes_cmot,
@@ -668,341 +756,42 @@ fn process_sequences<F: Field>(
             bit_boundaries_mlt,
             n_fse_bytes_mlt as u64,
             &table_mlt,
+            match_lengths_mode,
             true,
         ),
     ] {
-        if end_offset > start_offset {
-            let mut tag_rlc_iter =
-                src[start_offset..end_offset]
-                    .iter()
-                    .scan(Value::known(F::zero()), |acc, &byte| {
-                        *acc = *acc * randomness + Value::known(F::from(byte as u64));
-                        Some(*acc)
-                    });
-            let tag_rlc = tag_rlc_iter.clone().last().expect("Tag RLC must exist");
-
-            let mut decoded: u64 = 0;
-            let mut n_acc: usize = 0;
-            let mut n_emitted: usize = 0;
-            let mut current_tag_rlc_acc = Value::known(F::zero());
-            let mut last_byte_idx: i64 = 0;
-            let mut from_pos: (i64, i64) = (1, 0);
-            let mut to_pos: (i64, i64) = (0, 0);
-            let kind = table.table_kind;
-            let mut next_symbol: i32 = -1;
-            let mut is_repeating_bit_boundary: HashMap<usize, bool> = HashMap::new();
-
-            let multiplier =
-                (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
-            let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
-            let mut last_symbol: i32 = 0;
-
-            // Convert multi-bit read operations boundaries from the stream into a convenient format
-            // so they can be easily converted into witness rows later.
-
-            // Format:
-
-            // symbol,                 The symbol being decoded now
-            // n_emitted,              The total number of unique symbols decoded
-            // from_byte_position,     Which byte the read operation starts at
-            // from_bit_position,      Which bit position the read operation
-            //                         starts at, with range ∈ [0, 8)
-            // to_byte_position,       Which byte the read operation ends at
-            // to_bit_position,        Which bit position the read operation ends at,
-            //                         with range ∈ [0, 16)
-            // value_read,             Bit value
-            // value_decoded,          The decoded value is processed from the raw bitstring value
-            // current_tag_value_acc,  Depending on the current byte position,
-            //                         the accumulator increments accordingly
-            // current_tag_rlc_acc,    Depending on the current byte position,
-            //                         the accumulator increments accordingly
-            // n_acc,                  How many states are already assigned to the current symbol
-            // table_kind,             What FSE table is being decoded
-            // table_size,             The size of current FSE table
-            // is_repeating_bits,      Whether current bitstring represents repeat bits.
-            //                         Repeat bits immediately follows a bitstring=1 read operation.
-            //                         Repeat bits indicate how many 0-state symbols to skip.
-            // is_trailing_bits,       FSE bitstreams may have trailing bits
-
-            let bitstream_rows = bit_boundaries
-                .iter()
-                .enumerate()
-                .map(|(bit_boundary_idx, (bit_idx, value_read, value_decoded))| {
-                    // First calculate the start and end position of the current read operation
-                    from_pos = if next_symbol == -1 { (1, -1) } else { to_pos };
-                    from_pos.1 += 1;
-                    if from_pos.1 == 8 || from_pos.1 == 16 {
-                        from_pos = (from_pos.0 + 1, 0);
-                    }
-                    from_pos.1 = (from_pos.1 as u64).rem_euclid(8) as i64;
-                    while from_pos.0 > last_byte_idx {
-                        current_tag_rlc_acc = tag_rlc_iter.next().unwrap();
-                        last_byte_idx += 1;
-                    }
-
-                    // Derive the end position based on how many bits are read
-                    let to_byte_idx = (bit_idx - 1) / 8;
-                    let mut to_bit_idx = bit_idx - to_byte_idx * (N_BITS_PER_BYTE as u32) - 1;
-                    if from_pos.0 < (to_byte_idx + 1) as i64 {
-                        to_bit_idx += 8;
-                    }
-                    to_pos = ((to_byte_idx + 1) as i64, to_bit_idx as i64);
-
-                    if bit_boundary_idx < 1 {
-                        // Read Scenarios 1: Accuracy log bits (Always the First Read)
-                        next_symbol += 1;
-                        assert_eq!(value_read, value_decoded, "no varbit packing for AL bits");
-                        (
-                            0,
-                            n_emitted,
-                            from_pos.0 as usize,
-                            from_pos.1 as usize,
-                            to_pos.0 as usize,
-                            to_pos.1 as usize,
-                            *value_read,
-                            *value_decoded,
-                            current_tag_rlc_acc,
-                            n_acc,
-                            kind as u64,
-                            table.table_size,
-                            false,
-                            false,
-                        )
-                    } else if !is_repeating_bit_boundary.contains_key(&bit_boundary_idx) {
-                        if n_acc >= (table.table_size as usize) {
-                            // Read Scenarios 2: Trailing Bits
-                            assert_eq!(
-                                value_read, value_decoded,
-                                "no varbit packing for trailing bits"
-                            );
-                            (
-                                last_symbol as u64,
-                                n_emitted,
-                                from_pos.0 as usize,
-                                from_pos.1 as usize,
-                                to_pos.0 as usize,
-                                to_pos.1 as usize,
-                                *value_read,
-                                *value_decoded,
-                                current_tag_rlc_acc,
-                                n_acc,
-                                kind as u64,
-                                table.table_size,
-                                false,
-                                true,
-                            )
-                        } else {
-                            // Read Scenarios 3: Regular Decoding State
-                            assert!(next_symbol >= 0);
-                            decoded = next_symbol as u64;
-                            n_emitted += 1;
-                            last_symbol = next_symbol;
-                            next_symbol += 1;
-                            match *value_decoded {
-                                0 => {
-                                    // When a symbol has a value==0, it signifies a case of prob=-1
-                                    // (or probability "less
-                                    // than 1"), where
-                                    // such symbols are allocated states from the
-                                    // end and retreating. Exactly 1 state is allocated in this
-                                    // case.
-                                    n_acc += 1;
-                                }
-                                1 => {
-                                    let mut repeating_bit_boundary_idx = bit_boundary_idx + 1;
-                                    loop {
-                                        let repeating_bits =
-                                            bit_boundaries[repeating_bit_boundary_idx].1;
-                                        next_symbol += repeating_bits as i32; // skip symbols
-                                        is_repeating_bit_boundary
-                                            .insert(repeating_bit_boundary_idx, true);
-
-                                        if repeating_bits < 3 {
-                                            break;
-                                        } else {
-                                            repeating_bit_boundary_idx += 1;
-                                        }
-                                    }
-                                }
-                                _ => {
-                                    n_acc += (*value_decoded - 1) as usize;
-                                }
-                            }
-
-                            (
-                                decoded,
-                                n_emitted,
-                                from_pos.0 as usize,
-                                from_pos.1 as usize,
-                                to_pos.0 as usize,
-                                to_pos.1 as usize,
-                                *value_read,
-                                *value_decoded,
-                                current_tag_rlc_acc,
-                                n_acc,
-                                kind as u64,
-                                table.table_size,
-                                false,
-                                false,
-                            )
-                        }
-                    } else {
-                        // Read Scenarios 3: Repeating Bits
-                        let symbol = last_symbol as u64 + value_decoded;
-                        last_symbol = symbol as i32;
-                        assert_eq!(
-                            value_read, value_decoded,
-                            "no varbit packing for repeat-bits flag"
-                        );
-                        (
-                            symbol,
-                            n_emitted,
-                            from_pos.0 as usize,
-                            from_pos.1 as usize,
-                            to_pos.0 as usize,
-                            to_pos.1 as usize,
-                            *value_read,
-                            *value_decoded,
-                            current_tag_rlc_acc,
-                            n_acc,
-                            // FseDecoder-specific witness values
-                            kind as u64,
-                            table.table_size,
-                            true,
-                            false,
-                        )
-                    }
-                })
-                .collect::<Vec<(
-                    u64,
-                    usize,
-                    usize,
-                    usize,
-                    usize,
-                    usize,
-                    u64,
-                    u64,
-                    Value<F>,
-                    usize,
-                    u64,
-                    u64,
-                    bool,
-                    bool,
-                )>>();
-
-            // Transform bitstream rows into witness rows
-            for (j, row) in bitstream_rows.iter().enumerate() {
-                witness_rows.push(ZstdWitnessRow {
-                    state: ZstdState {
-                        tag: ZstdTag::ZstdBlockSequenceFseCode,
-                        tag_next: if is_fse_section_end {
-                            ZstdTag::ZstdBlockSequenceData
-                        } else {
-                            ZstdTag::ZstdBlockSequenceFseCode
-                        },
-                        block_idx,
-                        max_tag_len: ZstdTag::ZstdBlockSequenceFseCode.max_len(),
-                        tag_len,
-                        tag_idx: row.2 as u64,
-                        is_tag_change: j == 0,
-                        tag_rlc,
-                        tag_rlc_acc: row.8,
-                    },
-                    encoded_data: EncodedData {
-                        byte_idx: (start_offset + row.2) as u64,
-                        encoded_len,
-                        value_byte: src[start_offset + row.2 - 1],
-                        value_rlc,
-                        reverse: false,
-                        ..Default::default()
-                    },
-                    bitstream_read_data: BitstreamReadRow {
-                        bit_start_idx: row.3,
-                        bit_end_idx: row.5,
-                        bit_value: row.6,
-                        is_zero_bit_read: false,
-                        ..Default::default()
-                    },
-                    decoded_data: DecodedData {
-                        decoded_len: last_row.decoded_data.decoded_len,
-                    },
-                    fse_data: FseDecodingRow {
-                        table_kind: row.10,
-                        table_size: row.11,
-                        symbol: row.0,
-                        num_emitted: row.1 as u64,
-                        value_decoded: row.7,
-                        probability_acc: row.9 as u64,
-                        is_repeat_bits_loop: row.12,
-                        is_trailing_bits: row.13,
-                    },
-                });
-
-                // The maximum allowed accuracy log for literals length and match length tables is
-                // 9, This provision will produce a skipped byte row in only one
-                // scenario: The previous byte ended on the second last bit, and the
-                // subsequent read consumes 9 bits, which produces a range covering
-                // the second byte entirely, resulting in a nil row.
-                if (row.5 - row.3 + 1) > 8 && row.5 >= 15 {
-                    last_row = witness_rows.last().cloned().unwrap();
-                    let byte_value = src[start_offset + row.2];
-
-                    witness_rows.push(ZstdWitnessRow {
-                        state: ZstdState {
-                            tag: ZstdTag::ZstdBlockSequenceFseCode,
-                            tag_next: if is_fse_section_end {
-                                ZstdTag::ZstdBlockSequenceData
-                            } else {
-                                ZstdTag::ZstdBlockSequenceFseCode
-                            },
-                            block_idx,
-                            max_tag_len: ZstdTag::ZstdBlockSequenceFseCode.max_len(),
-                            tag_len,
-                            tag_idx: (row.2 + 1) as u64,
-                            is_tag_change: false,
-                            tag_rlc,
-                            tag_rlc_acc: row.8 * randomness
-                                + Value::known(F::from(byte_value as u64)),
-                        },
-                        encoded_data: EncodedData {
-                            byte_idx: (start_offset + row.2 + 1) as u64,
-                            encoded_len,
-                            value_byte: byte_value,
-                            value_rlc,
-                            reverse: false,
-                            ..Default::default()
-                        },
-                        bitstream_read_data: BitstreamReadRow {
-                            // Deterministic start and end bit idx note:
-                            // There's only one scenario that can produce a nil row in the FSE table
-                            // section. This read operation must end on
-                            // the last bit of the second byte.
-                            bit_start_idx: 7,
-                            bit_end_idx: 7,
-                            bit_value: 0,
-                            is_zero_bit_read: false,
-                            is_nil: true,
-                            is_update_state: 0u64,
-                            ..Default::default()
-                        },
-                        decoded_data: DecodedData {
-                            decoded_len: last_row.decoded_data.decoded_len,
-                        },
-                        fse_data: FseDecodingRow {
-                            table_kind: row.10,
-                            table_size: row.11,
-                            symbol: row.0,
-                            num_emitted: row.1 as u64,
-                            value_decoded: row.7,
-                            probability_acc: row.9 as u64,
-                            is_repeat_bits_loop: false,
-                            is_trailing_bits: row.13,
-                        },
-                    })
-                }
-
-                last_row = witness_rows.last().cloned().unwrap();
-            }
+        let tag_next = if is_fse_section_end {
+            ZstdTag::ZstdBlockSequenceData
+        } else {
+            ZstdTag::ZstdBlockSequenceFseCode
+        };
+        if mode == CompressionMode::Rle {
+            witness_rows.push(process_fse_table_rle::<F>(
+                src,
+                block_idx,
+                start_offset,
+                table,
+                tag_next,
+                &last_row,
+                randomness,
+            ));
+            last_row = witness_rows.last().cloned().unwrap();
+        } else if end_offset > start_offset {
+            let rows = process_fse_table_description::<F>(
+                src,
+                block_idx,
+                start_offset,
+                end_offset,
+                &bit_boundaries,
+                tag_len,
+                table,
+                ZstdTag::ZstdBlockSequenceFseCode,
+                tag_next,
+                &last_row,
+                randomness,
+            );
+            witness_rows.extend_from_slice(&rows);
+            last_row = witness_rows.last().cloned().unwrap();
         }
     }
 
@@ -1096,6 +885,7 @@ fn process_sequences<F: Field>(
         },
         decoded_data: last_row.decoded_data.clone(),
         fse_data: FseDecodingRow::default(),
+        literals_data: last_row.literals_data.carry(),
     });
 
     // Exclude the leading zero section
@@ -1320,6 +1110,7 @@ fn process_sequences<F: Field>(
                 table_size,
                 ..Default::default()
             },
+            literals_data: last_row.literals_data.carry(),
         });
 
         // When the range of a multi-byte read operation from the bitstream covers an entire byte,
@@ -1404,6 +1195,7 @@ fn process_sequences<F: Field>(
                         table_size,
                         ..Default::default()
                     },
+                    literals_data: last_row.literals_data.carry(),
                 })
             }
         }
@@ -1428,6 +1220,13 @@ fn process_sequences<F: Field>(
             );
 
             raw_sequence_instructions.push(new_instruction);
+
+            // The FSE states are not updated after the last sequence. We must stop here, since an
+            // FSE table in RLE_Mode reads nb=0 bits to update its state and the bitstream would
+            // never be exhausted.
+            if raw_sequence_instructions.len() == sequence_info.num_sequences {
+                break;
+            }
         }
 
         let next_nb = if is_init {
@@ -1597,99 +1396,1159 @@ fn process_sequences<F: Field>(
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct LiteralsHeaderProcessingResult<F> {
-    pub offset: usize,
-    pub witness_rows: Vec<ZstdWitnessRow<F>>,
-    pub regen_size: usize,
-}
-
-fn process_block_zstd_literals_header<F: Field>(
+/// Witness rows for reading an FSE table description, i.e. the accuracy log followed by the
+/// variable bit-packed normalised probabilities of the symbols.
+#[allow(clippy::too_many_arguments)]
+fn process_fse_table_description<F: Field>(
     src: &[u8],
     block_idx: u64,
-    byte_offset: usize,
+    start_offset: usize,
+    end_offset: usize,
+    bit_boundaries: &[(u32, u64, u64)],
+    tag_len: u64,
+    table: &FseAuxiliaryTableData,
+    tag: ZstdTag,
+    tag_next: ZstdTag,
     last_row: &ZstdWitnessRow<F>,
     randomness: Value<F>,
-) -> LiteralsHeaderProcessingResult<F> {
-    let lh_bytes = src
-        .iter()
-        .skip(byte_offset)
-        .take(N_MAX_LITERAL_HEADER_BYTES)
-        .cloned()
-        .collect::<Vec<u8>>();
-
-    let literals_block_type = BlockType::from(lh_bytes[0] & 0x3);
-    let size_format = (lh_bytes[0] >> 2) & 3;
-
-    let [n_bits_fmt, n_bits_regen, n_bytes_header]: [usize; 3] = match literals_block_type {
-        BlockType::RawBlock => match size_format {
-            0b00 | 0b10 => [1, 5, 1],
-            0b01 => [2, 12, 2],
-            0b11 => [2, 20, 3],
-            _ => unreachable!("size_format out of bound"),
-        },
-        _ => unreachable!("BlockType::* unexpected. Must be raw bytes for literals."),
-    };
-
-    // Bits for representing regenerated_size and compressed_size
-    let sizing_bits = &lh_bytes.clone().into_iter().fold(vec![], |mut acc, b| {
-        acc.extend(value_bits_le(b));
-        acc
-    })[(2 + n_bits_fmt)..(n_bytes_header * N_BITS_PER_BYTE)];
-
-    let regen_size = le_bits_to_value(&sizing_bits[0..n_bits_regen]) as usize;
-    let tag_next = match literals_block_type {
-        BlockType::RawBlock => ZstdTag::ZstdBlockLiteralsRawBytes,
-        _ => unreachable!("BlockType::* unexpected. Must be raw bytes for literals."),
-    };
+) -> Vec<ZstdWitnessRow<F>> {
+    let mut witness_rows: Vec<ZstdWitnessRow<F>> = vec![];
+    let encoded_len = last_row.encoded_data.encoded_len;
+    let mut last_row = last_row.clone();
 
-    let tag_rlc_iter =
-        lh_bytes
+    let mut tag_rlc_iter =
+        src[start_offset..end_offset]
             .iter()
-            .take(n_bytes_header)
             .scan(Value::known(F::zero()), |acc, &byte| {
                 *acc = *acc * randomness + Value::known(F::from(byte as u64));
                 Some(*acc)
             });
-    let tag_rlc = tag_rlc_iter.clone().last().expect("Tag RLC expected");
+    let tag_rlc = tag_rlc_iter.clone().last().expect("Tag RLC must exist");
+
+    let mut decoded: u64 = 0;
+    let mut n_acc: usize = 0;
+    let mut n_emitted: usize = 0;
+    let mut current_tag_rlc_acc = Value::known(F::zero());
+    let mut last_byte_idx: i64 = 0;
+    let mut from_pos: (i64, i64) = (1, 0);
+    let mut to_pos: (i64, i64) = (0, 0);
+    let kind = table.table_kind;
+    let mut next_symbol: i32 = -1;
+    let mut is_repeating_bit_boundary: HashMap<usize, bool> = HashMap::new();
 
     let multiplier =
         (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
     let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+    let mut last_symbol: i32 = 0;
+
+    // Convert multi-bit read operations boundaries from the stream into a convenient format
+    // so they can be easily converted into witness rows later.
+
+    // Format:
+
+    // symbol,                 The symbol being decoded now
+    // n_emitted,              The total number of unique symbols decoded
+    // from_byte_position,     Which byte the read operation starts at
+    // from_bit_position,      Which bit position the read operation
+    //                         starts at, with range ∈ [0, 8)
+    // to_byte_position,       Which byte the read operation ends at
+    // to_bit_position,        Which bit position the read operation ends at,
+    //                         with range ∈ [0, 16)
+    // value_read,             Bit value
+    // value_decoded,          The decoded value is processed from the raw bitstring value
+    // current_tag_value_acc,  Depending on the current byte position,
+    //                         the accumulator increments accordingly
+    // current_tag_rlc_acc,    Depending on the current byte position,
+    //                         the accumulator increments accordingly
+    // n_acc,                  How many states are already assigned to the current symbol
+    // table_kind,             What FSE table is being decoded
+    // table_size,             The size of current FSE table
+    // is_repeating_bits,      Whether current bitstring represents repeat bits.
+    //                         Repeat bits immediately follows a bitstring=1 read operation.
+    //                         Repeat bits indicate how many 0-state symbols to skip.
+    // is_trailing_bits,       FSE bitstreams may have trailing bits
+
+    let bitstream_rows = bit_boundaries
+        .iter()
+        .enumerate()
+        .map(|(bit_boundary_idx, (bit_idx, value_read, value_decoded))| {
+            // First calculate the start and end position of the current read operation
+            from_pos = if next_symbol == -1 { (1, -1) } else { to_pos };
+            from_pos.1 += 1;
+            if from_pos.1 == 8 || from_pos.1 == 16 {
+                from_pos = (from_pos.0 + 1, 0);
+            }
+            from_pos.1 = (from_pos.1 as u64).rem_euclid(8) as i64;
+            while from_pos.0 > last_byte_idx {
+                current_tag_rlc_acc = tag_rlc_iter.next().unwrap();
+                last_byte_idx += 1;
+            }
 
-    LiteralsHeaderProcessingResult {
-        offset: byte_offset + n_bytes_header,
-        witness_rows: lh_bytes
-            .iter()
-            .take(n_bytes_header)
-            .zip(tag_rlc_iter)
-            .enumerate()
-            .map(|(i, (&value_byte, tag_rlc_acc))| ZstdWitnessRow {
-                state: ZstdState {
-                    tag: ZstdTag::ZstdBlockLiteralsHeader,
-                    tag_next,
-                    block_idx,
-                    max_tag_len: ZstdTag::ZstdBlockLiteralsHeader.max_len(),
-                    tag_len: n_bytes_header as u64,
-                    tag_idx: (i + 1) as u64,
-                    is_tag_change: i == 0,
-                    tag_rlc,
-                    tag_rlc_acc,
-                },
-                encoded_data: EncodedData {
-                    byte_idx: (byte_offset + i + 1) as u64,
-                    encoded_len: last_row.encoded_data.encoded_len,
-                    value_byte,
-                    reverse: false,
-                    value_rlc,
-                    ..Default::default()
-                },
-                bitstream_read_data: BitstreamReadRow::default(),
-                decoded_data: last_row.decoded_data.clone(),
-                fse_data: FseDecodingRow::default(),
-            })
+            // Derive the end position based on how many bits are read
+            let to_byte_idx = (bit_idx - 1) / 8;
+            let mut to_bit_idx = bit_idx - to_byte_idx * (N_BITS_PER_BYTE as u32) - 1;
+            if from_pos.0 < (to_byte_idx + 1) as i64 {
+                to_bit_idx += 8;
+            }
+            to_pos = ((to_byte_idx + 1) as i64, to_bit_idx as i64);
+
+            if bit_boundary_idx < 1 {
+                // Read Scenarios 1: Accuracy log bits (Always the First Read)
+                next_symbol += 1;
+                assert_eq!(value_read, value_decoded, "no varbit packing for AL bits");
+                (
+                    0,
+                    n_emitted,
+                    from_pos.0 as usize,
+                    from_pos.1 as usize,
+                    to_pos.0 as usize,
+                    to_pos.1 as usize,
+                    *value_read,
+                    *value_decoded,
+                    current_tag_rlc_acc,
+                    n_acc,
+                    kind as u64,
+                    table.table_size,
+                    false,
+                    false,
+                )
+            } else if !is_repeating_bit_boundary.contains_key(&bit_boundary_idx) {
+                if n_acc >= (table.table_size as usize) {
+                    // Read Scenarios 2: Trailing Bits
+                    assert_eq!(
+                        value_read, value_decoded,
+                        "no varbit packing for trailing bits"
+                    );
+                    (
+                        last_symbol as u64,
+                        n_emitted,
+                        from_pos.0 as usize,
+                        from_pos.1 as usize,
+                        to_pos.0 as usize,
+                        to_pos.1 as usize,
+                        *value_read,
+                        *value_decoded,
+                        current_tag_rlc_acc,
+                        n_acc,
+                        kind as u64,
+                        table.table_size,
+                        false,
+                        true,
+                    )
+                } else {
+                    // Read Scenarios 3: Regular Decoding State
+                    assert!(next_symbol >= 0);
+                    decoded = next_symbol as u64;
+                    n_emitted += 1;
+                    last_symbol = next_symbol;
+                    next_symbol += 1;
+                    match *value_decoded {
+                        0 => {
+                            // When a symbol has a value==0, it signifies a case of prob=-1
+                            // (or probability "less
+                            // than 1"), where
+                            // such symbols are allocated states from the
+                            // end and retreating. Exactly 1 state is allocated in this
+                            // case.
+                            n_acc += 1;
+                        }
+                        1 => {
+                            let mut repeating_bit_boundary_idx = bit_boundary_idx + 1;
+                            loop {
+                                let repeating_bits = bit_boundaries[repeating_bit_boundary_idx].1;
+                                next_symbol += repeating_bits as i32; // skip symbols
+                                is_repeating_bit_boundary.insert(repeating_bit_boundary_idx, true);
+
+                                if repeating_bits < 3 {
+                                    break;
+                                } else {
+                                    repeating_bit_boundary_idx += 1;
+                                }
+                            }
+                        }
+                        _ => {
+                            n_acc += (*value_decoded - 1) as usize;
+                        }
+                    }
+
+                    (
+                        decoded,
+                        n_emitted,
+                        from_pos.0 as usize,
+                        from_pos.1 as usize,
+                        to_pos.0 as usize,
+                        to_pos.1 as usize,
+                        *value_read,
+                        *value_decoded,
+                        current_tag_rlc_acc,
+                        n_acc,
+                        kind as u64,
+                        table.table_size,
+                        false,
+                        false,
+                    )
+                }
+            } else {
+                // Read Scenarios 3: Repeating Bits
+                let symbol = last_symbol as u64 + value_decoded;
+                last_symbol = symbol as i32;
+                assert_eq!(
+                    value_read, value_decoded,
+                    "no varbit packing for repeat-bits flag"
+                );
+                (
+                    symbol,
+                    n_emitted,
+                    from_pos.0 as usize,
+                    from_pos.1 as usize,
+                    to_pos.0 as usize,
+                    to_pos.1 as usize,
+                    *value_read,
+                    *value_decoded,
+                    current_tag_rlc_acc,
+                    n_acc,
+                    // FseDecoder-specific witness values
+                    kind as u64,
+                    table.table_size,
+                    true,
+                    false,
+                )
+            }
+        })
+        .collect::<Vec<(
+            u64,
+            usize,
+            usize,
+            usize,
+            usize,
+            usize,
+            u64,
+            u64,
+            Value<F>,
+            usize,
+            u64,
+            u64,
+            bool,
+            bool,
+        )>>();
+
+    // Transform bitstream rows into witness rows
+    for (j, row) in bitstream_rows.iter().enumerate() {
+        witness_rows.push(ZstdWitnessRow {
+            state: ZstdState {
+                tag,
+                tag_next,
+                block_idx,
+                max_tag_len: tag.max_len(),
+                tag_len,
+                tag_idx: row.2 as u64,
+                is_tag_change: j == 0,
+                tag_rlc,
+                tag_rlc_acc: row.8,
+            },
+            encoded_data: EncodedData {
+                byte_idx: (start_offset + row.2) as u64,
+                encoded_len,
+                value_byte: src[start_offset + row.2 - 1],
+                value_rlc,
+                reverse: false,
+                ..Default::default()
+            },
+            bitstream_read_data: BitstreamReadRow {
+                bit_start_idx: row.3,
+                bit_end_idx: row.5,
+                bit_value: row.6,
+                is_zero_bit_read: false,
+                ..Default::default()
+            },
+            decoded_data: DecodedData {
+                decoded_len: last_row.decoded_data.decoded_len,
+            },
+            fse_data: FseDecodingRow {
+                table_kind: row.10,
+                table_size: row.11,
+                symbol: row.0,
+                num_emitted: row.1 as u64,
+                value_decoded: row.7,
+                probability_acc: row.9 as u64,
+                is_repeat_bits_loop: row.12,
+                is_trailing_bits: row.13,
+            },
+            literals_data: last_row.literals_data.carry(),
+        });
+
+        // The maximum allowed accuracy log for literals length and match length tables is
+        // 9, This provision will produce a skipped byte row in only one
+        // scenario: The previous byte ended on the second last bit, and the
+        // subsequent read consumes 9 bits, which produces a range covering
+        // the second byte entirely, resulting in a nil row.
+        if (row.5 - row.3 + 1) > 8 && row.5 >= 15 {
+            last_row = witness_rows.last().cloned().unwrap();
+            let byte_value = src[start_offset + row.2];
+
+            witness_rows.push(ZstdWitnessRow {
+                state: ZstdState {
+                    tag,
+                    tag_next,
+                    block_idx,
+                    max_tag_len: tag.max_len(),
+                    tag_len,
+                    tag_idx: (row.2 + 1) as u64,
+                    is_tag_change: false,
+                    tag_rlc,
+                    tag_rlc_acc: row.8 * randomness + Value::known(F::from(byte_value as u64)),
+                },
+                encoded_data: EncodedData {
+                    byte_idx: (start_offset + row.2 + 1) as u64,
+                    encoded_len,
+                    value_byte: byte_value,
+                    value_rlc,
+                    reverse: false,
+                    ..Default::default()
+                },
+                bitstream_read_data: BitstreamReadRow {
+                    // Deterministic start and end bit idx note:
+                    // There's only one scenario that can produce a nil row in the FSE table
+                    // section. This read operation must end on
+                    // the last bit of the second byte.
+                    bit_start_idx: 7,
+                    bit_end_idx: 7,
+                    bit_value: 0,
+                    is_zero_bit_read: false,
+                    is_nil: true,
+                    is_update_state: 0u64,
+                    ..Default::default()
+                },
+                decoded_data: DecodedData {
+                    decoded_len: last_row.decoded_data.decoded_len,
+                },
+                fse_data: FseDecodingRow {
+                    table_kind: row.10,
+                    table_size: row.11,
+                    symbol: row.0,
+                    num_emitted: row.1 as u64,
+                    value_decoded: row.7,
+                    probability_acc: row.9 as u64,
+                    is_repeat_bits_loop: false,
+                    is_trailing_bits: row.13,
+                },
+                literals_data: last_row.literals_data.carry(),
+            })
+        }
+
+        last_row = witness_rows.last().cloned().unwrap();
+    }
+
+    witness_rows
+}
+
+/// Witness row for the description of an FSE table in RLE_Mode, i.e. a single byte that is the
+/// only symbol of the table.
+fn process_fse_table_rle<F: Field>(
+    src: &[u8],
+    block_idx: u64,
+    byte_offset: usize,
+    table: &FseAuxiliaryTableData,
+    tag_next: ZstdTag,
+    last_row: &ZstdWitnessRow<F>,
+    randomness: Value<F>,
+) -> ZstdWitnessRow<F> {
+    let value_byte = src[byte_offset];
+    let multiplier =
+        (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
+    let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+    let tag_rlc = Value::known(F::from(value_byte as u64));
+
+    ZstdWitnessRow {
+        state: ZstdState {
+            tag: ZstdTag::ZstdBlockSequenceFseCode,
+            tag_next,
+            block_idx,
+            max_tag_len: ZstdTag::ZstdBlockSequenceFseCode.max_len(),
+            tag_len: 1,
+            tag_idx: 1,
+            is_tag_change: true,
+            tag_rlc,
+            tag_rlc_acc: tag_rlc,
+        },
+        encoded_data: EncodedData {
+            byte_idx: (byte_offset + 1) as u64,
+            encoded_len: last_row.encoded_data.encoded_len,
+            value_byte,
+            value_rlc,
+            reverse: false,
+            ..Default::default()
+        },
+        bitstream_read_data: BitstreamReadRow::default(),
+        decoded_data: DecodedData {
+            decoded_len: last_row.decoded_data.decoded_len,
+        },
+        fse_data: FseDecodingRow {
+            table_kind: table.table_kind as u64,
+            table_size: table.table_size,
+            symbol: value_byte as u64,
+            ..Default::default()
+        },
+        literals_data: last_row.literals_data.carry(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LiteralsHeaderProcessingResult<F> {
+    pub offset: usize,
+    pub witness_rows: Vec<ZstdWitnessRow<F>>,
+    pub literals_block_type: LiteralsBlockType,
+    pub regen_size: usize,
+    /// The size of the Huffman tree description and the Huffman-coded literal streams. Only
+    /// applicable for Compressed and Treeless literals blocks.
+    pub compressed_size: usize,
+    /// The number of Huffman-coded literal streams, i.e. 1 or 4.
+    pub num_streams: usize,
+}
+
+fn process_block_zstd_literals_header<F: Field>(
+    src: &[u8],
+    block_idx: u64,
+    byte_offset: usize,
+    last_row: &ZstdWitnessRow<F>,
+    randomness: Value<F>,
+) -> LiteralsHeaderProcessingResult<F> {
+    let lh_bytes = src
+        .iter()
+        .skip(byte_offset)
+        .take(N_MAX_LITERAL_HEADER_BYTES)
+        .cloned()
+        .collect::<Vec<u8>>();
+
+    let literals_block_type = LiteralsBlockType::from(lh_bytes[0] & 0x3);
+    let size_format = (lh_bytes[0] >> 2) & 3;
+
+    let [n_bits_fmt, n_bits_regen, n_bits_compressed, num_streams, n_bytes_header]: [usize; 5] =
+        match literals_block_type {
+            LiteralsBlockType::Raw => match size_format {
+                0b00 | 0b10 => [1, 5, 0, 1, 1],
+                0b01 => [2, 12, 0, 1, 2],
+                0b11 => [2, 20, 0, 1, 3],
+                _ => unreachable!("size_format out of bound"),
+            },
+            LiteralsBlockType::Compressed | LiteralsBlockType::Treeless => match size_format {
+                0b00 => [2, 10, 10, 1, 3],
+                0b01 => [2, 10, 10, 4, 3],
+                0b10 => [2, 14, 14, 4, 4],
+                0b11 => [2, 18, 18, 4, 5],
+                _ => unreachable!("size_format out of bound"),
+            },
+            LiteralsBlockType::Rle => {
+                unreachable!(
+                    "LiteralsBlockType::Rle unexpected. Must be raw or Huffman-compressed."
+                )
+            }
+        };
+
+    // Bits for representing regenerated_size and compressed_size
+    let sizing_bits = &lh_bytes.clone().into_iter().fold(vec![], |mut acc, b| {
+        acc.extend(value_bits_le(b));
+        acc
+    })[(2 + n_bits_fmt)..(n_bytes_header * N_BITS_PER_BYTE)];
+
+    let regen_size = le_bits_to_value(&sizing_bits[0..n_bits_regen]) as usize;
+    let compressed_size =
+        le_bits_to_value(&sizing_bits[n_bits_regen..(n_bits_regen + n_bits_compressed)]) as usize;
+    let tag_next = match literals_block_type {
+        LiteralsBlockType::Raw => ZstdTag::ZstdBlockLiteralsRawBytes,
+        LiteralsBlockType::Compressed => ZstdTag::ZstdBlockHuffmanHeader,
+        LiteralsBlockType::Treeless if num_streams == 4 => ZstdTag::ZstdBlockJumpTable,
+        LiteralsBlockType::Treeless => ZstdTag::ZstdBlockLstream,
+        LiteralsBlockType::Rle => unreachable!("LiteralsBlockType::Rle unexpected."),
+    };
+
+    // A Compressed literals block describes a new Huffman tree, whereas a Treeless literals block
+    // continues to use the Huffman tree from the previous block.
+    let literals_data = if literals_block_type == LiteralsBlockType::Compressed {
+        LiteralsDecodingRow {
+            huffman_block_idx: block_idx,
+            ..Default::default()
+        }
+    } else {
+        last_row.literals_data.carry()
+    };
+
+    let tag_rlc_iter =
+        lh_bytes
+            .iter()
+            .take(n_bytes_header)
+            .scan(Value::known(F::zero()), |acc, &byte| {
+                *acc = *acc * randomness + Value::known(F::from(byte as u64));
+                Some(*acc)
+            });
+    let tag_rlc = tag_rlc_iter.clone().last().expect("Tag RLC expected");
+
+    let multiplier =
+        (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
+    let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+
+    LiteralsHeaderProcessingResult {
+        offset: byte_offset + n_bytes_header,
+        witness_rows: lh_bytes
+            .iter()
+            .take(n_bytes_header)
+            .zip(tag_rlc_iter)
+            .enumerate()
+            .map(|(i, (&value_byte, tag_rlc_acc))| ZstdWitnessRow {
+                state: ZstdState {
+                    tag: ZstdTag::ZstdBlockLiteralsHeader,
+                    tag_next,
+                    block_idx,
+                    max_tag_len: ZstdTag::ZstdBlockLiteralsHeader.max_len(),
+                    tag_len: n_bytes_header as u64,
+                    tag_idx: (i + 1) as u64,
+                    is_tag_change: i == 0,
+                    tag_rlc,
+                    tag_rlc_acc,
+                },
+                encoded_data: EncodedData {
+                    byte_idx: (byte_offset + i + 1) as u64,
+                    encoded_len: last_row.encoded_data.encoded_len,
+                    value_byte,
+                    reverse: false,
+                    value_rlc,
+                    ..Default::default()
+                },
+                bitstream_read_data: BitstreamReadRow::default(),
+                decoded_data: last_row.decoded_data.clone(),
+                fse_data: FseDecodingRow::default(),
+                literals_data: literals_data.clone(),
+            })
             .collect::<Vec<_>>(),
+        literals_block_type,
         regen_size,
+        compressed_size,
+        num_streams,
+    }
+}
+
+/// Witness rows for a tag whose bytes are processed from front-to-back, one byte per row.
+#[allow(clippy::too_many_arguments)]
+fn process_bytes<F: Field>(
+    src: &[u8],
+    block_idx: u64,
+    byte_offset: usize,
+    end_offset: usize,
+    tag: ZstdTag,
+    tag_next: ZstdTag,
+    last_row: &ZstdWitnessRow<F>,
+    randomness: Value<F>,
+    literals_data: impl Fn(usize) -> LiteralsDecodingRow,
+) -> Vec<ZstdWitnessRow<F>> {
+    let multiplier =
+        (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
+    let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+    let tag_rlc_iter =
+        src[byte_offset..end_offset]
+            .iter()
+            .scan(Value::known(F::zero()), |acc, &byte| {
+                *acc = *acc * randomness + Value::known(F::from(byte as u64));
+                Some(*acc)
+            });
+    let tag_rlc = tag_rlc_iter.clone().last().expect("Tag RLC must exist");
+
+    src[byte_offset..end_offset]
+        .iter()
+        .zip(tag_rlc_iter)
+        .enumerate()
+        .map(|(i, (&value_byte, tag_rlc_acc))| ZstdWitnessRow {
+            state: ZstdState {
+                tag,
+                tag_next,
+                block_idx,
+                max_tag_len: tag.max_len(),
+                tag_len: (end_offset - byte_offset) as u64,
+                tag_idx: (i + 1) as u64,
+                is_tag_change: i == 0,
+                tag_rlc,
+                tag_rlc_acc,
+            },
+            encoded_data: EncodedData {
+                byte_idx: (byte_offset + i + 1) as u64,
+                encoded_len: last_row.encoded_data.encoded_len,
+                value_byte,
+                value_rlc,
+                reverse: false,
+                ..Default::default()
+            },
+            decoded_data: last_row.decoded_data.clone(),
+            bitstream_read_data: BitstreamReadRow::default(),
+            fse_data: FseDecodingRow::default(),
+            literals_data: literals_data(i),
+        })
+        .collect()
+}
+
+/// Witness rows for a tag whose bytes are processed as a reversed bitstream, i.e. bytes from
+/// back-to-front and bits from the most-significant bit.
+///
+/// The first row reads the leading 0-bits and the sentinel 1-bit. Every subsequent row is a read
+/// operation of `nb` bits, apart from the nil rows for the bytes entirely covered by a read
+/// operation. A read operation that goes past the end of the bitstream only reads the remaining
+/// bits, since the bitstream is assumed to be followed by 0-bits.
+#[allow(clippy::too_many_arguments)]
+fn process_reversed_bitstream<F: Field>(
+    src: &[u8],
+    block_idx: u64,
+    byte_offset: usize,
+    end_offset: usize,
+    tag: ZstdTag,
+    tag_next: ZstdTag,
+    last_row: &ZstdWitnessRow<F>,
+    randomness: Value<F>,
+    sentinel_literals_data: LiteralsDecodingRow,
+    reads: Vec<(usize, LiteralsDecodingRow)>,
+) -> Vec<ZstdWitnessRow<F>> {
+    let n_bytes = end_offset - byte_offset;
+    let n_bits = n_bytes * N_BITS_PER_BYTE;
+    let bitstream = reversed_bits(&src[byte_offset..end_offset]);
+
+    let multiplier =
+        (0..last_row.state.tag_len).fold(Value::known(F::one()), |acc, _| acc * randomness);
+    let value_rlc = last_row.encoded_data.value_rlc * multiplier + last_row.state.tag_rlc;
+    let tag_rlc_accs = src[byte_offset..end_offset]
+        .iter()
+        .scan(Value::known(F::zero()), |acc, &byte| {
+            *acc = *acc * randomness + Value::known(F::from(byte as u64));
+            Some(*acc)
+        })
+        .collect::<Vec<Value<F>>>();
+    let tag_rlc = *tag_rlc_accs.last().expect("Tag RLC must exist");
+
+    // The row at the 1-indexed byte_idx, counting from the back of the bitstream.
+    let witness_row = |byte_idx: usize,
+                       bitstream_read_data: BitstreamReadRow,
+                       literals_data: LiteralsDecodingRow| ZstdWitnessRow {
+        state: ZstdState {
+            tag,
+            tag_next,
+            block_idx,
+            max_tag_len: tag.max_len(),
+            tag_len: n_bytes as u64,
+            tag_idx: byte_idx as u64,
+            is_tag_change: false,
+            tag_rlc,
+            tag_rlc_acc: tag_rlc_accs[n_bytes - byte_idx],
+        },
+        encoded_data: EncodedData {
+            byte_idx: (byte_offset + byte_idx) as u64,
+            encoded_len: last_row.encoded_data.encoded_len,
+            value_byte: src[end_offset - byte_idx],
+            value_rlc,
+            reverse: true,
+            reverse_len: n_bytes as u64,
+            reverse_idx: (n_bytes - (byte_idx - 1)) as u64,
+        },
+        bitstream_read_data,
+        decoded_data: last_row.decoded_data.clone(),
+        fse_data: FseDecodingRow::default(),
+        literals_data,
+    };
+
+    // Add a witness row for leading 0s and the sentinel 1-bit
+    let padding_end_idx = bitstream
+        .iter()
+        .position(|&bit| bit == 1)
+        .expect("Sentinel bit must exist");
+    let mut sentinel_row = witness_row(
+        1,
+        BitstreamReadRow {
+            bit_start_idx: 0,
+            bit_end_idx: padding_end_idx,
+            bit_value: 1,
+            ..Default::default()
+        },
+        sentinel_literals_data,
+    );
+    sentinel_row.state.is_tag_change = true;
+    let mut witness_rows = vec![sentinel_row];
+
+    let mut current_bit_idx = padding_end_idx + 1;
+    for (nb, literals_data) in reads {
+        let nb = nb.min(n_bits - current_bit_idx);
+        if nb == 0 {
+            // A read of 0 bits holds on to the last bit read so far.
+            let held_bit_idx = current_bit_idx - 1;
+            witness_rows.push(witness_row(
+                held_bit_idx / N_BITS_PER_BYTE + 1,
+                BitstreamReadRow {
+                    bit_start_idx: held_bit_idx % N_BITS_PER_BYTE,
+                    bit_end_idx: held_bit_idx % N_BITS_PER_BYTE,
+                    bit_value: 0,
+                    is_zero_bit_read: true,
+                    ..Default::default()
+                },
+                literals_data,
+            ));
+            continue;
+        }
+
+        let current_byte_idx = current_bit_idx / N_BITS_PER_BYTE + 1;
+        let from_bit_idx = current_bit_idx % N_BITS_PER_BYTE;
+        let to_bit_idx = from_bit_idx + (nb - 1);
+        witness_rows.push(witness_row(
+            current_byte_idx,
+            BitstreamReadRow {
+                bit_start_idx: from_bit_idx,
+                bit_end_idx: to_bit_idx,
+                bit_value: be_bits_to_value(&bitstream[current_bit_idx..(current_bit_idx + nb)]),
+                ..Default::default()
+            },
+            literals_data.clone(),
+        ));
+
+        // Bytes entirely covered by the read operation are represented by nil rows.
+        for (i, boundary) in [15, 23].into_iter().enumerate() {
+            if to_bit_idx >= boundary {
+                let wrap_by = match to_bit_idx {
+                    15 => 8,
+                    16..=23 => 16,
+                    v => unreachable!("unexpected bit_index_end={:?}", v),
+                };
+                witness_rows.push(witness_row(
+                    current_byte_idx + i + 1,
+                    BitstreamReadRow {
+                        bit_start_idx: to_bit_idx - wrap_by,
+                        bit_end_idx: to_bit_idx - wrap_by,
+                        bit_value: 0,
+                        is_nil: true,
+                        ..Default::default()
+                    },
+                    LiteralsDecodingRow {
+                        is_literal: false,
+                        is_last_literal: false,
+                        ..literals_data.clone()
+                    },
+                ));
+            }
+        }
+
+        current_bit_idx += nb;
+    }
+
+    witness_rows
+}
+
+/// Decode the Huffman weights that are FSE-compressed. The FSE bitstream is decoded using two
+/// interleaved FSE states, until the bitstream is over-consumed.
+///
+/// Returns the decoded weights along with the read operations, i.e. the number of bits read and
+/// the weight decoded at every read.
+fn decode_huffman_weights_fse(
+    bitstream: &[u8],
+    table: &FseAuxiliaryTableData,
+    huffman_block_idx: u64,
+) -> (Vec<u64>, Vec<(usize, LiteralsDecodingRow)>) {
+    let state_table = table.parse_state_table();
+    let accuracy_log = bit_length(table.table_size) as usize - 1;
+    let n_bits = bitstream.len();
+    let sentinel_idx = bitstream
+        .iter()
+        .position(|&bit| bit == 1)
+        .expect("Sentinel bit must exist");
+    let mut current_bit_idx = sentinel_idx + 1;
+    // the bitstream is followed by 0-bits.
+    let read = |current_bit_idx: &mut usize, nb: usize| -> u64 {
+        let value = (*current_bit_idx..(*current_bit_idx + nb))
+            .map(|i| bitstream.get(i).cloned().unwrap_or(0))
+            .fold(0, |acc, bit| acc * 2 + bit as u64);
+        *current_bit_idx += nb;
+        value
+    };
+
+    let mut weights = vec![];
+    let mut reads = vec![];
+
+    // Initial states.
+    let mut states = [
+        read(&mut current_bit_idx, accuracy_log),
+        read(&mut current_bit_idx, accuracy_log),
+    ];
+    for &state in states.iter() {
+        reads.push((
+            accuracy_log,
+            LiteralsDecodingRow {
+                huffman_block_idx,
+                weight_state: state,
+                ..Default::default()
+            },
+        ));
+    }
+
+    let mut i = 0;
+    loop {
+        let (weight, baseline, nb) = state_table[&states[i]];
+        let weight_idx = weights.len() as u64;
+        weights.push(weight);
+        states[i] = baseline + read(&mut current_bit_idx, nb as usize);
+        reads.push((
+            nb as usize,
+            LiteralsDecodingRow {
+                huffman_block_idx,
+                weight_state: states[i],
+                weight_idx,
+                is_weight: true,
+                weight,
+                weight_baseline: baseline,
+                weight_nb: nb,
+                ..Default::default()
+            },
+        ));
+        i = 1 - i;
+
+        // Once the bitstream is over-consumed, the other state emits the last weight.
+        if current_bit_idx > n_bits {
+            let (weight, baseline, nb) = state_table[&states[i]];
+            reads.push((
+                0,
+                LiteralsDecodingRow {
+                    huffman_block_idx,
+                    weight_state: states[i],
+                    weight_idx: weights.len() as u64,
+                    is_weight: true,
+                    weight,
+                    weight_baseline: baseline,
+                    weight_nb: nb,
+                    ..Default::default()
+                },
+            ));
+            weights.push(weight);
+            break;
+        }
+    }
+
+    (weights, reads)
+}
+
+/// Decode a Huffman-coded literal stream into exactly n_literals literals. The stream must be
+/// consumed entirely.
+///
+/// Returns the number of bits read for every literal, along with the literal.
+fn decode_lstream(
+    bitstream: &[u8],
+    huffman_codes: &HuffmanCodesData,
+    n_literals: usize,
+) -> Vec<(usize, u64)> {
+    let codes = huffman_codes.parse_canonical_codes();
+    let max_bits = huffman_codes.max_bits() as usize;
+    let mut current_bit_idx = bitstream
+        .iter()
+        .position(|&bit| bit == 1)
+        .expect("Sentinel bit must exist")
+        + 1;
+
+    let literals = (0..n_literals)
+        .map(|_| {
+            let (nb, literal) = (1..=max_bits)
+                .find_map(|nb| {
+                    let bits = bitstream.get(current_bit_idx..(current_bit_idx + nb))?;
+                    codes
+                        .get(&(be_bits_to_value(bits), nb as u64))
+                        .map(|&literal| (nb, literal))
+                })
+                .expect("Huffman code must exist");
+            current_bit_idx += nb;
+            (nb, literal)
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        current_bit_idx,
+        bitstream.len(),
+        "Huffman-coded literal stream must be consumed entirely"
+    );
+
+    literals
+}
+
+/// Process the literals section's content that follows the literals header, i.e. the raw bytes
+/// or the (optional) Huffman tree description, jump table and Huffman-coded literal streams.
+fn process_block_zstd_literals<F: Field>(
+    src: &[u8],
+    block_idx: u64,
+    literals_header: &LiteralsHeaderProcessingResult<F>,
+    huffman_codes: Option<HuffmanCodesData>,
+    randomness: Value<F>,
+) -> LiteralsBlockResult<F> {
+    let byte_offset = literals_header.offset;
+    let regen_size = literals_header.regen_size;
+    let last_row = literals_header
+        .witness_rows
+        .last()
+        .expect("last row expected to exist");
+
+    if literals_header.literals_block_type == LiteralsBlockType::Raw {
+        let literals = src[byte_offset..(byte_offset + regen_size)].to_vec();
+        assert!(!literals.is_empty(), "Literals must exist.");
+        let witness_rows = process_bytes(
+            src,
+            block_idx,
+            byte_offset,
+            byte_offset + regen_size,
+            ZstdTag::ZstdBlockLiteralsRawBytes,
+            ZstdTag::ZstdBlockSequenceHeader,
+            last_row,
+            randomness,
+            |i| LiteralsDecodingRow {
+                is_literal: true,
+                literal_idx: (i + 1) as u64,
+                literal: literals[i] as u64,
+                is_last_literal: i + 1 == regen_size,
+                ..last_row.literals_data.carry()
+            },
+        );
+
+        return LiteralsBlockResult {
+            offset: byte_offset + regen_size,
+            witness_rows,
+            literals: literals.iter().map(|b| *b as u64).collect::<Vec<u64>>(),
+            huffman_codes,
+            huffman_fse_aux_table: None,
+        };
+    }
+
+    let end_offset = byte_offset + literals_header.compressed_size;
+    let num_streams = literals_header.num_streams;
+    let tag_after_tree = if num_streams == 4 {
+        ZstdTag::ZstdBlockJumpTable
+    } else {
+        ZstdTag::ZstdBlockLstream
+    };
+    let huffman_block_idx = last_row.literals_data.huffman_block_idx;
+    let mut witness_rows: Vec<ZstdWitnessRow<F>> = vec![];
+    let mut byte_offset = byte_offset;
+    let mut huffman_fse_aux_table = None;
+
+    ///////////////////////////////////////////////////
+    ///// Literals Part 1: Huffman Tree Description ///
+    ///////////////////////////////////////////////////
+    let huffman_codes = if literals_header.literals_block_type == LiteralsBlockType::Compressed {
+        let header_byte = src[byte_offset];
+        let weights = if header_byte >= 128 {
+            // The weights are directly represented, 2 weights per byte.
+            let n_weights = (header_byte - 127) as usize;
+            let n_weight_bytes = (n_weights + 1) / 2;
+            witness_rows.extend_from_slice(&process_bytes(
+                src,
+                block_idx,
+                byte_offset,
+                byte_offset + 1 + n_weight_bytes,
+                ZstdTag::ZstdBlockHuffmanHeader,
+                tag_after_tree,
+                last_row,
+                randomness,
+                |i| LiteralsDecodingRow {
+                    huffman_block_idx,
+                    weight_idx: if i > 0 { 2 * (i as u64 - 1) } else { 0 },
+                    is_weight: i > 0,
+                    weight: if i > 0 {
+                        (src[byte_offset + i] >> 4) as u64
+                    } else {
+                        0
+                    },
+                    ..Default::default()
+                },
+            ));
+            byte_offset += 1 + n_weight_bytes;
+
+            src[(byte_offset - n_weight_bytes)..byte_offset]
+                .iter()
+                .flat_map(|&byte| [(byte >> 4) as u64, (byte & 0xf) as u64])
+                .take(n_weights)
+                .collect::<Vec<u64>>()
+        } else {
+            // The weights are FSE-compressed, with the header byte being the number of bytes
+            // taken by the FSE table description and the FSE bitstream.
+            witness_rows.extend_from_slice(&process_bytes(
+                src,
+                block_idx,
+                byte_offset,
+                byte_offset + 1,
+                ZstdTag::ZstdBlockHuffmanHeader,
+                ZstdTag::ZstdBlockHuffmanFseCode,
+                last_row,
+                randomness,
+                |_| LiteralsDecodingRow {
+                    huffman_block_idx,
+                    ..Default::default()
+                },
+            ));
+            byte_offset += 1;
+            let weights_end_offset = byte_offset + header_byte as usize;
+
+            let (n_fse_bytes, bit_boundaries, table) = FseAuxiliaryTableData::reconstruct(
+                src,
+                block_idx,
+                FseTableKind::HUF,
+                byte_offset,
+                false,
+            )
+            .expect("Reconstructing FSE-packed Huffman weights table should not fail.");
+            assert!(
+                table.table_size <= 1 << 6,
+                "Huffman weights FSE table accuracy log must not exceed 6"
+            );
+            let rows = process_fse_table_description::<F>(
+                src,
+                block_idx,
+                byte_offset,
+                byte_offset + n_fse_bytes,
+                &bit_boundaries,
+                n_fse_bytes as u64,
+                &table,
+                ZstdTag::ZstdBlockHuffmanFseCode,
+                ZstdTag::ZstdBlockHuffmanCode,
+                witness_rows.last().expect("last row expected to exist"),
+                randomness,
+            );
+            witness_rows.extend_from_slice(&rows);
+            byte_offset += n_fse_bytes;
+
+            let (weights, reads) = decode_huffman_weights_fse(
+                &reversed_bits(&src[byte_offset..weights_end_offset]),
+                &table,
+                huffman_block_idx,
+            );
+            let rows = process_reversed_bitstream::<F>(
+                src,
+                block_idx,
+                byte_offset,
+                weights_end_offset,
+                ZstdTag::ZstdBlockHuffmanCode,
+                tag_after_tree,
+                witness_rows.last().expect("last row expected to exist"),
+                randomness,
+                LiteralsDecodingRow {
+                    huffman_block_idx,
+                    ..Default::default()
+                },
+                reads,
+            );
+            // The FSE table that the weights are decoded with.
+            witness_rows.extend(rows.into_iter().map(|row| ZstdWitnessRow {
+                fse_data: FseDecodingRow {
+                    table_kind: FseTableKind::HUF as u64,
+                    table_size: table.table_size,
+                    ..Default::default()
+                },
+                ..row
+            }));
+            byte_offset = weights_end_offset;
+            huffman_fse_aux_table = Some(table);
+
+            weights
+        };
+
+        HuffmanCodesData::reconstruct(block_idx, &weights)
+    } else {
+        huffman_codes.expect("Treeless literals block must follow a Huffman tree description")
+    };
+
+    ///////////////////////////////////////////////////
+    ///// Literals Part 2: Jump Table /////////////////
+    ///////////////////////////////////////////////////
+    let lstream_lens = if num_streams == 4 {
+        let rows = process_bytes(
+            src,
+            block_idx,
+            byte_offset,
+            byte_offset + 6,
+            ZstdTag::ZstdBlockJumpTable,
+            ZstdTag::ZstdBlockLstream,
+            witness_rows.last().unwrap_or(last_row),
+            randomness,
+            |_| LiteralsDecodingRow {
+                huffman_block_idx,
+                ..Default::default()
+            },
+        );
+        witness_rows.extend_from_slice(&rows);
+        let lens = src[byte_offset..(byte_offset + 6)]
+            .chunks(2)
+            .map(|le_bytes| le_bytes[0] as usize + ((le_bytes[1] as usize) << 8))
+            .collect::<Vec<usize>>();
+        byte_offset += 6;
+        let len_last = end_offset - byte_offset - lens.iter().sum::<usize>();
+        vec![lens[0], lens[1], lens[2], len_last]
+    } else {
+        vec![end_offset - byte_offset]
+    };
+
+    ///////////////////////////////////////////////////
+    ///// Literals Part 3: Huffman-coded Lstreams /////
+    ///////////////////////////////////////////////////
+    let n_literals_per_stream = (regen_size + 3) / 4;
+    let mut literals = vec![];
+    for (lstream_idx, &lstream_len) in lstream_lens.iter().enumerate() {
+        let is_last_lstream = lstream_idx == num_streams - 1;
+        let n_literals = if is_last_lstream {
+            regen_size - n_literals_per_stream * (num_streams - 1)
+        } else {
+            n_literals_per_stream
+        };
+        let lstream_end_offset = byte_offset + lstream_len;
+
+        let reads = decode_lstream(
+            &reversed_bits(&src[byte_offset..lstream_end_offset]),
+            &huffman_codes,
+            n_literals,
+        )
+        .into_iter()
+        .map(|(nb, literal)| {
+            literals.push(literal);
+            (
+                nb,
+                LiteralsDecodingRow {
+                    is_literal: true,
+                    literal_idx: literals.len() as u64,
+                    literal,
+                    is_last_literal: literals.len() == regen_size,
+                    lstream_idx: lstream_idx as u64,
+                    huffman_block_idx,
+                    ..Default::default()
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+        let rows = process_reversed_bitstream::<F>(
+            src,
+            block_idx,
+            byte_offset,
+            lstream_end_offset,
+            ZstdTag::ZstdBlockLstream,
+            if is_last_lstream {
+                ZstdTag::ZstdBlockSequenceHeader
+            } else {
+                ZstdTag::ZstdBlockLstream
+            },
+            witness_rows.last().unwrap_or(last_row),
+            randomness,
+            LiteralsDecodingRow {
+                literal_idx: (n_literals_per_stream * lstream_idx) as u64,
+                lstream_idx: lstream_idx as u64,
+                huffman_block_idx,
+                ..Default::default()
+            },
+            reads,
+        );
+        witness_rows.extend_from_slice(&rows);
+        byte_offset = lstream_end_offset;
+    }
+
+    assert_eq!(literals.len(), regen_size, "regenerated size mismatch");
+
+    LiteralsBlockResult {
+        offset: end_offset,
+        witness_rows,
+        literals,
+        huffman_codes: Some(huffman_codes),
+        huffman_fse_aux_table,
     }
 }
 
@@ -1699,6 +2558,7 @@ pub struct MultiBlockProcessResult<F> {
     pub witness_rows: Vec<ZstdWitnessRow<F>>,
     pub literal_bytes: Vec<Vec<u64>>, // literals
     pub fse_aux_tables: Vec<FseAuxiliaryTableData>,
+    pub huffman_codes: Vec<HuffmanCodesData>,
     pub block_info_arr: Vec<BlockInfo>,
     pub sequence_info_arr: Vec<SequenceInfo>,
     pub address_table_rows: Vec<Vec<AddressTableRow>>,
@@ -1711,6 +2571,7 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
     let mut decoded_bytes: Vec<u8> = vec![];
     let mut literals: Vec<Vec<u64>> = vec![];
     let mut fse_aux_tables: Vec<FseAuxiliaryTableData> = vec![];
+    let mut huffman_codes_arr: Vec<HuffmanCodesData> = vec![];
     let mut block_info_arr: Vec<BlockInfo> = vec![];
     let mut sequence_info_arr: Vec<SequenceInfo> = vec![];
     let mut address_table_arr: Vec<Vec<AddressTableRow>> = vec![];
@@ -1727,6 +2588,10 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
 
     let mut block_idx: u64 = 1;
     let mut repeated_offset = [1, 4, 8];
+    let mut huffman_codes: Option<HuffmanCodesData> = None;
+    // The sequences section FSE tables of the last block that decoded sequences, which may be
+    // repeated by a following block (Repeat_Mode).
+    let mut prev_fse_aux_tables: Vec<FseAuxiliaryTableData> = vec![];
     loop {
         let AggregateBlockResult {
             offset,
@@ -1738,6 +2603,8 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
             address_table_rows,
             sequence_exec_result,
             repeated_offset: end_repeated_offset,
+            huffman_codes: end_huffman_codes,
+            huffman_fse_aux_table,
         } = process_block::<F>(
             src,
             &mut decoded_bytes,
@@ -1746,11 +2613,20 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
             witness_rows.last().expect("last row expected to exist"),
             randomness,
             repeated_offset,
+            huffman_codes,
+            &prev_fse_aux_tables,
         );
         log::debug!("processed block={:?}: offset={:?}", block_idx, offset);
 
         witness_rows.extend_from_slice(&rows);
         literals.push(new_literals);
+        // The Huffman weights FSE table precedes the sequences section FSE tables of a block.
+        if let Some(huffman_fse_aux_table) = huffman_fse_aux_table {
+            fse_aux_tables.push(huffman_fse_aux_table);
+        }
+        if !new_fse_aux_tables.is_empty() {
+            prev_fse_aux_tables.clone_from(&new_fse_aux_tables);
+        }
         for fse_aux_table in new_fse_aux_tables {
             fse_aux_tables.push(fse_aux_table);
         }
@@ -1759,6 +2635,12 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
         sequence_info_arr.push(sequence_info);
         address_table_arr.push(address_table_rows);
         sequence_exec_info_arr.push(sequence_exec_result);
+        if let Some(codes) = end_huffman_codes.as_ref() {
+            if codes.block_idx == block_idx {
+                huffman_codes_arr.push(codes.clone());
+            }
+        }
+        huffman_codes = end_huffman_codes;
 
         if block_info.is_last_block {
             assert!(offset >= src.len());
@@ -1774,6 +2656,7 @@ pub fn process<F: Field>(src: &[u8], randomness: Value<F>) -> MultiBlockProcessR
         witness_rows,
         literal_bytes: literals,
         fse_aux_tables,
+        huffman_codes: huffman_codes_arr,
         block_info_arr,
         sequence_info_arr,
         address_table_rows: address_table_arr,
@@ -1880,6 +2763,7 @@ mod tests {
                 witness_rows: _w,
                 literal_bytes: _l,
                 fse_aux_tables: _f,
+                huffman_codes: _h,
                 block_info_arr: _b,
                 sequence_info_arr: _s,
                 address_table_rows: _a,
@@ -1896,4 +2780,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_zstd_witness_processing_huffman_literals() -> Result<(), std::io::Error> {
+        use super::*;
+        use halo2_proofs::halo2curves::bn256::Fr;
+
+        // The worked example compressed by the reference zstd implementation (level=19, without
+        // the magic number and content checksum), whose literals section is Huffman-compressed
+        // with FSE-compressed weights and 4 literal streams.
+        let raw_input_bytes = fs::read("./data/worked-example")?;
+        let compressed = hex::decode(
+            fs::read_to_string("./data/test_huffman/worked-example-level19.hex")?.trim_end(),
+        )
+        .expect("Failed to decode hex data");
+
+        let MultiBlockProcessResult {
+            witness_rows,
+            literal_bytes: _l,
+            fse_aux_tables,
+            huffman_codes,
+            block_info_arr: _b,
+            sequence_info_arr: _s,
+            address_table_rows: _a,
+            sequence_exec_results,
+        } = process::<Fr>(&compressed, Value::known(Fr::from(123456789)));
+
+        assert_eq!(huffman_codes.len(), 1);
+        assert_eq!(fse_aux_tables[0].table_kind, FseTableKind::HUF);
+        assert_eq!(
+            witness_rows
+                .iter()
+                .filter(|row| row.state.tag == ZstdTag::ZstdBlockLstream && row.state.is_tag_change)
+                .count(),
+            4
+        );
+
+        let decoded_bytes = sequence_exec_results
+            .into_iter()
+            .flat_map(|r| r.recovered_bytes)
+            .collect::<Vec<u8>>();
+
+        assert!(raw_input_bytes == decoded_bytes);
+
+        Ok(())
+    }
 }
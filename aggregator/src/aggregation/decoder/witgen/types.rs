@@ -10,10 +10,10 @@ use strum_macros::EnumIter;
 
 use super::{
     params::N_BITS_PER_BYTE,
-    util::{read_variable_bit_packing, smaller_powers_of_two, value_bits_le},
+    util::{bit_length, read_variable_bit_packing, smaller_powers_of_two, value_bits_le},
 };
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum BlockType {
     #[default]
     RawBlock = 0,
@@ -34,6 +34,67 @@ impl From<u8> for BlockType {
     }
 }
 
+/// The type of a zstd block's literals section.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum LiteralsBlockType {
+    /// Literals are stored uncompressed.
+    #[default]
+    Raw = 0,
+    /// Literals consist of a single byte repeated regenerated_size times.
+    Rle,
+    /// Literals are Huffman-compressed, preceded by the Huffman tree description.
+    Compressed,
+    /// Literals are Huffman-compressed, re-using the Huffman tree from a previous block.
+    Treeless,
+}
+
+impl From<u8> for LiteralsBlockType {
+    fn from(src: u8) -> Self {
+        match src {
+            0 => Self::Raw,
+            1 => Self::Rle,
+            2 => Self::Compressed,
+            3 => Self::Treeless,
+            _ => unreachable!("LiteralsBlockType is 2 bits"),
+        }
+    }
+}
+
+/// The Compression_Mode of the FSE table for literal lengths, offsets or match lengths, as per
+/// the sequences section header.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum CompressionMode {
+    /// The FSE table is constructed from the predefined distribution.
+    #[default]
+    Predefined = 0,
+    /// The table description is a single byte, i.e. the only symbol of a table with AL=0.
+    Rle,
+    /// The table description is the FSE-compressed normalised distribution.
+    FseCompressed,
+    /// The FSE table is the one used by the previous block that decoded sequences.
+    Repeat,
+}
+
+impl From<u8> for CompressionMode {
+    fn from(src: u8) -> Self {
+        match src {
+            0 => Self::Predefined,
+            1 => Self::Rle,
+            2 => Self::FseCompressed,
+            3 => Self::Repeat,
+            _ => unreachable!("CompressionMode is 2 bits"),
+        }
+    }
+}
+
+impl CompressionMode {
+    /// Whether the table is described in the sequences section, i.e. RLE_Mode or
+    /// FSE_Compressed_Mode.
+    pub fn is_described(&self) -> bool {
+        matches!(self, Self::Rle | Self::FseCompressed)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy)]
 pub struct BlockInfo {
     pub block_idx: usize,
@@ -47,7 +108,7 @@ pub struct BlockInfo {
 pub struct SequenceInfo {
     pub block_idx: usize,
     pub num_sequences: usize,
-    pub compression_mode: [bool; 3],
+    pub compression_mode: [CompressionMode; 3],
 }
 
 /// The type for indicate each range in output bytes by sequence execution
@@ -104,10 +165,25 @@ pub enum ZstdTag {
     FrameContentSize,
     /// The block's header.
     BlockHeader,
+    /// Raw_Block's bytes, which are copied as-is to the decoded data.
+    RawBlockBytes,
+    /// RLE_Block's single byte, which is repeated Block_Size times in the decoded data.
+    RleBlockBytes,
     /// Zstd block's literals header.
     ZstdBlockLiteralsHeader,
     /// Zstd blocks might contain raw bytes.
     ZstdBlockLiteralsRawBytes,
+    /// Header byte of the Huffman tree description, followed by the 4-bits weights if they are
+    /// directly represented.
+    ZstdBlockHuffmanHeader,
+    /// FSE table description used to decode the FSE-compressed Huffman weights.
+    ZstdBlockHuffmanFseCode,
+    /// FSE bitstream of the Huffman weights.
+    ZstdBlockHuffmanCode,
+    /// Jump table giving the sizes of the first 3 Huffman-coded literal streams.
+    ZstdBlockJumpTable,
+    /// Huffman-coded literal stream.
+    ZstdBlockLstream,
     /// Beginning of sequence section.
     ZstdBlockSequenceHeader,
     /// Zstd block's FSE code.
@@ -124,8 +200,15 @@ impl ZstdTag {
             Self::FrameHeaderDescriptor => false,
             Self::FrameContentSize => false,
             Self::BlockHeader => false,
+            Self::RawBlockBytes => true,
+            Self::RleBlockBytes => true,
             Self::ZstdBlockLiteralsHeader => true,
             Self::ZstdBlockLiteralsRawBytes => true,
+            Self::ZstdBlockHuffmanHeader => true,
+            Self::ZstdBlockHuffmanFseCode => true,
+            Self::ZstdBlockHuffmanCode => true,
+            Self::ZstdBlockJumpTable => true,
+            Self::ZstdBlockLstream => true,
             Self::ZstdBlockSequenceHeader => true,
             Self::ZstdBlockSequenceFseCode => true,
             Self::ZstdBlockSequenceData => true,
@@ -139,8 +222,15 @@ impl ZstdTag {
             Self::FrameHeaderDescriptor => false,
             Self::FrameContentSize => false,
             Self::BlockHeader => false,
+            Self::RawBlockBytes => false,
+            Self::RleBlockBytes => false,
             Self::ZstdBlockLiteralsHeader => false,
             Self::ZstdBlockLiteralsRawBytes => false,
+            Self::ZstdBlockHuffmanHeader => false,
+            Self::ZstdBlockHuffmanFseCode => false,
+            Self::ZstdBlockHuffmanCode => true,
+            Self::ZstdBlockJumpTable => false,
+            Self::ZstdBlockLstream => true,
             Self::ZstdBlockSequenceHeader => false,
             Self::ZstdBlockSequenceFseCode => false,
             Self::ZstdBlockSequenceData => true,
@@ -154,10 +244,17 @@ impl ZstdTag {
             Self::FrameHeaderDescriptor => 1,
             Self::FrameContentSize => 8,
             Self::BlockHeader => 3,
-            // as per spec, should be 5. But given that our encoder does not compress literals, it
-            // is 3.
-            Self::ZstdBlockLiteralsHeader => 3,
+            Self::RawBlockBytes => (1 << 17) - 1,
+            Self::RleBlockBytes => 1,
+            Self::ZstdBlockLiteralsHeader => 5,
             Self::ZstdBlockLiteralsRawBytes => (1 << 17) - 1,
+            // header byte followed by up to 255 weights packed 2 per byte.
+            Self::ZstdBlockHuffmanHeader => 129,
+            // the header byte < 128 covers both the FSE table description and the bitstream.
+            Self::ZstdBlockHuffmanFseCode => 127,
+            Self::ZstdBlockHuffmanCode => 127,
+            Self::ZstdBlockJumpTable => 6,
+            Self::ZstdBlockLstream => (1 << 17) - 1,
             Self::ZstdBlockSequenceHeader => 4,
             Self::ZstdBlockSequenceFseCode => 128,
             Self::ZstdBlockSequenceData => (1 << 17) - 1,
@@ -173,7 +270,8 @@ impl From<ZstdTag> for usize {
     }
 }
 
-/// FSE table variants that we observe in the sequences section.
+/// FSE table variants that we observe in the sequences section, and the FSE table used to
+/// compress the Huffman weights in the literals section.
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[allow(clippy::upper_case_acronyms)]
 pub enum FseTableKind {
@@ -183,6 +281,8 @@ pub enum FseTableKind {
     MOT,
     /// Match length FSE table.
     MLT,
+    /// Huffman weights FSE table.
+    HUF,
 }
 
 impl_expr!(FseTableKind);
@@ -199,6 +299,11 @@ impl std::fmt::Display for ZstdTag {
                 Self::BlockHeader => "BlockHeader",
                 Self::ZstdBlockLiteralsHeader => "ZstdBlockLiteralsHeader",
                 Self::ZstdBlockLiteralsRawBytes => "ZstdBlockLiteralsRawBytes",
+                Self::ZstdBlockHuffmanHeader => "ZstdBlockHuffmanHeader",
+                Self::ZstdBlockHuffmanFseCode => "ZstdBlockHuffmanFseCode",
+                Self::ZstdBlockHuffmanCode => "ZstdBlockHuffmanCode",
+                Self::ZstdBlockJumpTable => "ZstdBlockJumpTable",
+                Self::ZstdBlockLstream => "ZstdBlockLstream",
                 Self::ZstdBlockSequenceHeader => "ZstdBlockSequenceHeader",
                 Self::ZstdBlockSequenceFseCode => "ZstdBlockSequenceFseCode",
                 Self::ZstdBlockSequenceData => "ZstdBlockSequenceData",
@@ -276,7 +381,7 @@ pub struct DecodedData {
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct FseDecodingRow {
     /// The FSE table that is being decoded. Possible values are:
-    /// - LLT = 1, MOT = 2, MLT = 3
+    /// - LLT = 1, MOT = 2, MLT = 3, HUF = 4
     pub table_kind: u64,
     /// The number of states in the FSE table. table_size == 1 << AL, where AL is the accuracy log
     /// of the FSE table.
@@ -350,6 +455,145 @@ pub struct BitstreamReadRow {
     pub is_update_state: u64,
 }
 
+/// Literals decoding data from witness generation
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LiteralsDecodingRow {
+    /// Whether a literal is emitted at this row.
+    pub is_literal: bool,
+    /// The number of literals emitted so far in the block. Since literals are 1-indexed, this is
+    /// also the index of the literal emitted at this row.
+    pub literal_idx: u64,
+    /// The literal emitted at this row.
+    pub literal: u64,
+    /// Whether the literal emitted at this row is the last literal of the block.
+    pub is_last_literal: bool,
+    /// The 0-indexed Huffman-coded literal stream that is being decoded.
+    pub lstream_idx: u64,
+    /// The index of the block in which the Huffman tree description currently in use appeared.
+    /// Treeless literals blocks re-use the Huffman codes from a previous block.
+    pub huffman_block_idx: u64,
+    /// While decoding FSE-compressed Huffman weights, the FSE state at this row.
+    pub weight_state: u64,
+    /// The 0-indexed symbol whose Huffman weight is decoded at this row.
+    pub weight_idx: u64,
+    /// Whether a Huffman weight is decoded at this row.
+    pub is_weight: bool,
+    /// The Huffman weight decoded at this row.
+    pub weight: u64,
+    /// The baseline of the FSE state that emitted the Huffman weight at this row.
+    pub weight_baseline: u64,
+    /// The number of bits read at the FSE state that emitted the Huffman weight at this row.
+    pub weight_nb: u64,
+}
+
+impl LiteralsDecodingRow {
+    /// The fields that persist beyond the literals section of a block.
+    pub fn carry(&self) -> Self {
+        Self {
+            huffman_block_idx: self.huffman_block_idx,
+            ..Default::default()
+        }
+    }
+}
+
+/// A single row in the Huffman codes table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HuffmanCodeRow {
+    /// The symbol (literal byte) this prefix code decodes to.
+    pub symbol: u64,
+    /// The Huffman weight of the symbol.
+    pub weight: u64,
+    /// The prefix code's value, as read (MSB first) from the reversed literal stream.
+    pub bit_value: u64,
+    /// The number of bits in the prefix code, i.e. max_bits + 1 - weight.
+    pub num_bits: u64,
+}
+
+/// Huffman codes reconstructed from the Huffman tree description in a block's literals section.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HuffmanCodesData {
+    /// The block index in which this Huffman tree description appears.
+    pub block_idx: u64,
+    /// The Huffman weight of every symbol, including the last symbol's weight that is implied and
+    /// not described. A symbol with weight=0 does not appear in the literals.
+    pub weights: Vec<u64>,
+}
+
+impl HuffmanCodesData {
+    /// The maximum number of bits in a prefix code as per the zstd spec.
+    pub const MAX_BITS: u64 = 11;
+
+    /// Reconstruct the Huffman codes from the described weights, i.e. all but the last symbol's
+    /// weight. The last weight is implied as the sum of 2^(weight-1) must be a power of 2.
+    pub fn reconstruct(block_idx: u64, described_weights: &[u64]) -> Self {
+        let sum = described_weights
+            .iter()
+            .filter(|&&weight| weight > 0)
+            .map(|&weight| 1 << (weight - 1))
+            .sum::<u64>();
+        assert!(sum > 0, "Huffman weights must not all be 0");
+        let max_bits = bit_length(sum);
+        assert!(max_bits <= Self::MAX_BITS, "Huffman max_bits exceeds 11");
+        let left = (1 << max_bits) - sum;
+        assert!(
+            left & (left - 1) == 0,
+            "Huffman weights do not sum to a power of 2"
+        );
+        let last_weight = bit_length(left);
+
+        Self {
+            block_idx,
+            weights: described_weights
+                .iter()
+                .cloned()
+                .chain(std::iter::once(last_weight))
+                .collect(),
+        }
+    }
+
+    /// The number of bits required to decode the longest prefix code.
+    pub fn max_bits(&self) -> u64 {
+        let sum = self
+            .weights
+            .iter()
+            .filter(|&&weight| weight > 0)
+            .map(|&weight| 1 << (weight - 1))
+            .sum::<u64>();
+        bit_length(sum) - 1
+    }
+
+    /// The canonical prefix codes, assigned to symbols sorted by (weight, symbol). Every symbol
+    /// with weight w takes up 2^(w-1) of the 2^max_bits code space.
+    pub fn codes(&self) -> Vec<HuffmanCodeRow> {
+        let max_bits = self.max_bits();
+        let mut acc = 0u64;
+        self.weights
+            .iter()
+            .enumerate()
+            .filter(|(_symbol, &weight)| weight > 0)
+            .sorted_by_key(|&(symbol, &weight)| (weight, symbol))
+            .map(|(symbol, &weight)| {
+                let row = HuffmanCodeRow {
+                    symbol: symbol as u64,
+                    weight,
+                    bit_value: acc >> (weight - 1),
+                    num_bits: max_bits + 1 - weight,
+                };
+                acc += 1 << (weight - 1);
+                row
+            })
+            .collect()
+    }
+
+    /// Convert the codes into a (bit_value, num_bits) => symbol map, for decoding literal streams.
+    pub fn parse_canonical_codes(&self) -> HuffmanCodesMapping {
+        self.codes()
+            .into_iter()
+            .map(|row| ((row.bit_value, row.num_bits), row.symbol))
+            .collect()
+    }
+}
+
 /// Sequence data is interleaved with 6 bitstreams. Each producing a different type of value.
 #[derive(Clone, Copy, Debug)]
 pub enum SequenceDataTag {
@@ -534,7 +778,9 @@ pub struct FseAuxiliaryTableData {
     pub block_idx: u64,
     /// Indicates whether the table is pre-defined.
     pub is_predefined: bool,
-    /// The FSE table kind, variants are: LLT=1, MOT=2, MLT=3.
+    /// The block index of the table repeated by this table, if it is in Repeat_Mode.
+    pub repeat_block_idx: Option<u64>,
+    /// The FSE table kind, variants are: LLT=1, MOT=2, MLT=3, HUF=4.
     pub table_kind: FseTableKind,
     /// The FSE table's size, i.e. 1 << AL (accuracy log).
     pub table_size: u64,
@@ -557,6 +803,9 @@ pub struct FseAuxiliaryTableData {
 type FseStateMapping = BTreeMap<u64, (u64, u64, u64)>;
 type ReconstructedFse = (usize, Vec<(u32, u64, u64)>, FseAuxiliaryTableData);
 
+/// Map<(bit_value, num_bits), symbol> for the canonical Huffman prefix codes.
+pub type HuffmanCodesMapping = BTreeMap<(u64, u64), u64>;
+
 impl FseAuxiliaryTableData {
     /// While we reconstruct an FSE table from a bitstream, we do not know before reconstruction
     /// how many exact bytes we would finally be reading.
@@ -608,6 +857,7 @@ impl FseAuxiliaryTableData {
                     ],
                     6,
                 ),
+                FseTableKind::HUF => unreachable!("Huffman weights have no predefined FSE table"),
             };
             for (symbol, freq) in predefined_frequencies.into_iter().enumerate() {
                 normalised_probs.insert(symbol as u64, freq);
@@ -727,6 +977,7 @@ impl FseAuxiliaryTableData {
             Self {
                 block_idx,
                 is_predefined,
+                repeat_block_idx: None,
                 table_kind,
                 table_size,
                 normalised_probs,
@@ -736,6 +987,36 @@ impl FseAuxiliaryTableData {
         ))
     }
 
+    /// Construct the FSE table of a symbol type in RLE_Mode, i.e. a table with a single state
+    /// (AL=0) that always emits the given symbol and reads no bits.
+    pub fn reconstruct_rle(block_idx: u64, table_kind: FseTableKind, symbol: u8) -> Self {
+        let normalised_probs = BTreeMap::from([(symbol as u64, 1)]);
+        let (sym_to_states, sym_to_sorted_states) =
+            Self::transform_normalised_probs(&normalised_probs, 0);
+
+        Self {
+            block_idx,
+            is_predefined: false,
+            repeat_block_idx: None,
+            table_kind,
+            table_size: 1,
+            normalised_probs,
+            sym_to_states,
+            sym_to_sorted_states,
+        }
+    }
+
+    /// Construct the FSE table of a symbol type in Repeat_Mode, i.e. the same table as the one
+    /// used in a previous block.
+    pub fn repeat(&self, block_idx: u64) -> Self {
+        Self {
+            block_idx,
+            is_predefined: false,
+            repeat_block_idx: Some(self.block_idx),
+            ..self.clone()
+        }
+    }
+
     #[allow(non_snake_case)]
     fn transform_normalised_probs(
         normalised_probs: &BTreeMap<u64, i32>,
@@ -891,6 +1172,8 @@ pub struct ZstdWitnessRow<F> {
     pub fse_data: FseDecodingRow,
     /// Bitstream reader
     pub bitstream_read_data: BitstreamReadRow,
+    /// Literals decoding data
+    pub literals_data: LiteralsDecodingRow,
 }
 
 impl<F: Field> ZstdWitnessRow<F> {
@@ -905,6 +1188,7 @@ impl<F: Field> ZstdWitnessRow<F> {
             decoded_data: DecodedData::default(),
             fse_data: FseDecodingRow::default(),
             bitstream_read_data: BitstreamReadRow::default(),
+            literals_data: LiteralsDecodingRow::default(),
         }
     }
 }
@@ -1108,4 +1392,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_huffman_codes_reconstruction() {
+        // The example from RFC 8478 (section 4.2.1): the described weights are [4, 3, 2, 0, 1]
+        // and the weight of the last symbol (literal=5) is implied as 1.
+        let huffman_codes = HuffmanCodesData::reconstruct(1, &[4, 3, 2, 0, 1]);
+        assert_eq!(huffman_codes.weights, vec![4, 3, 2, 0, 1, 1]);
+        assert_eq!(huffman_codes.max_bits(), 4);
+
+        // (symbol, weight, bit_value, num_bits)
+        let expected_codes = vec![
+            (4, 1, 0b0000, 4),
+            (5, 1, 0b0001, 4),
+            (2, 2, 0b001, 3),
+            (1, 3, 0b01, 2),
+            (0, 4, 0b1, 1),
+        ];
+        assert_eq!(
+            huffman_codes
+                .codes()
+                .into_iter()
+                .map(|row| (row.symbol, row.weight, row.bit_value, row.num_bits))
+                .collect::<Vec<_>>(),
+            expected_codes,
+        );
+
+        let canonical_codes = huffman_codes.parse_canonical_codes();
+        assert_eq!(canonical_codes.get(&(0b0001, 4)), Some(&5));
+        assert_eq!(canonical_codes.get(&(0b1, 1)), Some(&0));
+        assert_eq!(canonical_codes.get(&(0b000, 3)), None);
+    }
 }
@@ -120,6 +120,19 @@ pub fn value_bits_le(value_byte: u8) -> [u8; N_BITS_PER_BYTE] {
         .expect("expected N_BITS_PER_BYTE elements")
 }
 
+/// Returns the bits of a reversed bitstream, i.e. the bytes from back-to-front with the bits of
+/// each byte from the most-significant bit.
+pub fn reversed_bits(src: &[u8]) -> Vec<u8> {
+    src.iter()
+        .rev()
+        .flat_map(|&byte| {
+            let mut bits = value_bits_le(byte);
+            bits.reverse();
+            bits
+        })
+        .collect()
+}
+
 pub fn le_bits_to_value(bits: &[u8]) -> u64 {
     assert!(bits.len() <= 32);
     let mut m: u64 = 1;
@@ -6,16 +6,21 @@ use halo2_proofs::{
 };
 use zkevm_circuits::util::Challenges;
 
-// TODO: remove MAX_AGG_SNARKS and make this generic over N_SNARKS
-use crate::{DIGEST_LEN, MAX_AGG_SNARKS};
+// TODO: remove MAX_BATCH_CAPACITY and make this generic over N_SNARKS
+use crate::{
+    constants::{BATCH_EXTRA_BLOB_LEN, BATCH_HEADER_LEN},
+    DIGEST_LEN, MAX_BATCH_CAPACITY,
+};
 
 use super::RlcConfig;
 
-const FIXED_OFFSET_32: usize = MAX_AGG_SNARKS + 1;
+const FIXED_OFFSET_32: usize = MAX_BATCH_CAPACITY + 1;
 const FIXED_OFFSET_168: usize = FIXED_OFFSET_32 + 1;
 const FIXED_OFFSET_232: usize = FIXED_OFFSET_168 + 1;
 const FIXED_OFFSET_2_POW_32: usize = FIXED_OFFSET_232 + 1;
-const FIXED_OFFSET_256: usize = FIXED_OFFSET_2_POW_32 + 1;
+const FIXED_OFFSET_BATCH_HEADER_LEN: usize = FIXED_OFFSET_2_POW_32 + 1;
+const FIXED_OFFSET_BATCH_EXTRA_BLOB_LEN: usize = FIXED_OFFSET_BATCH_HEADER_LEN + 1;
+const FIXED_OFFSET_256: usize = FIXED_OFFSET_BATCH_EXTRA_BLOB_LEN + 1;
 const FIXED_OFFSET_EMPTY_KECCAK: usize = FIXED_OFFSET_256 + POWS_OF_256;
 
 pub(crate) const POWS_OF_256: usize = 10;
@@ -25,31 +30,33 @@ impl RlcConfig {
     ///
     /// The layout for fixed cells is:
     ///
-    /// | Offset                 | Fixed value          |
-    /// |------------------------|----------------------|
-    /// | 0                      | 0                    |
-    /// | 1                      | 1                    |
-    /// | i ...                  | i ...                |
-    /// | MAX_AGG_SNARKS         | MAX_AGG_SNARKS       |
-    /// | MAX_AGG_SNARKS + 1     | 32                   |
-    /// | MAX_AGG_SNARKS + 2     | 168                  |
-    /// | MAX_AGG_SNARKS + 3     | 232                  |
-    /// | MAX_AGG_SNARKS + 4     | 2 ^ 32               |
-    /// | MAX_AGG_SNARKS + 5     | 256                  |
-    /// | MAX_AGG_SNARKS + 6     | 256 ^ 2              |
-    /// | MAX_AGG_SNARKS + 7     | 256 ^ 3              |
-    /// | MAX_AGG_SNARKS + j ... | 256 ^ (j - 4)        |
-    /// | MAX_AGG_SNARKS + 14    | 256 ^ 10             |
-    /// | MAX_AGG_SNARKS + 15    | EMPTY_KECCAK[0]      |
-    /// | MAX_AGG_SNARKS + 16    | EMPTY_KECCAK[1]      |
-    /// | MAX_AGG_SNARKS + k ... | EMPTY_KECCAK[k - 15] |
-    /// | MAX_AGG_SNARKS + 46    | EMPTY_KECCAK[31]     |
-    /// |------------------------|----------------------|
+    /// | Offset                     | Fixed value          |
+    /// |----------------------------|----------------------|
+    /// | 0                          | 0                    |
+    /// | 1                          | 1                    |
+    /// | i ...                      | i ...                |
+    /// | MAX_BATCH_CAPACITY         | MAX_BATCH_CAPACITY   |
+    /// | MAX_BATCH_CAPACITY + 1     | 32                   |
+    /// | MAX_BATCH_CAPACITY + 2     | 168                  |
+    /// | MAX_BATCH_CAPACITY + 3     | 232                  |
+    /// | MAX_BATCH_CAPACITY + 4     | 2 ^ 32               |
+    /// | MAX_BATCH_CAPACITY + 5     | 193                  |
+    /// | MAX_BATCH_CAPACITY + 6     | 96                   |
+    /// | MAX_BATCH_CAPACITY + 7     | 256                  |
+    /// | MAX_BATCH_CAPACITY + 8     | 256 ^ 2              |
+    /// | MAX_BATCH_CAPACITY + 9     | 256 ^ 3              |
+    /// | MAX_BATCH_CAPACITY + j ... | 256 ^ (j - 6)        |
+    /// | MAX_BATCH_CAPACITY + 16    | 256 ^ 10             |
+    /// | MAX_BATCH_CAPACITY + 17    | EMPTY_KECCAK[0]      |
+    /// | MAX_BATCH_CAPACITY + 18    | EMPTY_KECCAK[1]      |
+    /// | MAX_BATCH_CAPACITY + k ... | EMPTY_KECCAK[k - 17] |
+    /// | MAX_BATCH_CAPACITY + 48    | EMPTY_KECCAK[31]     |
+    /// |----------------------------|----------------------|
     pub(crate) fn init(&self, region: &mut Region<Fr>) -> Result<(), Error> {
         let mut offset = 0;
 
-        // [0, ..., MAX_AGG_SNARKS]
-        for const_val in 0..=MAX_AGG_SNARKS {
+        // [0, ..., MAX_BATCH_CAPACITY]
+        for const_val in 0..=MAX_BATCH_CAPACITY {
             region.assign_fixed(
                 || format!("const at offset={offset}"),
                 self.fixed,
@@ -60,8 +67,15 @@ impl RlcConfig {
         }
         assert_eq!(offset, FIXED_OFFSET_32);
 
-        // [32, 168, 232, 1 << 32]
-        for const_val in [32, 168, 232, 1 << 32] {
+        // [32, 168, 232, 1 << 32, BATCH_HEADER_LEN, BATCH_EXTRA_BLOB_LEN]
+        for const_val in [
+            32,
+            168,
+            232,
+            1 << 32,
+            BATCH_HEADER_LEN as u64,
+            BATCH_EXTRA_BLOB_LEN as u64,
+        ] {
             region.assign_fixed(
                 || format!("const at offset={offset}"),
                 self.fixed,
@@ -130,12 +144,12 @@ impl RlcConfig {
     }
 
     #[inline]
-    pub(crate) fn fixed_up_to_max_agg_snarks_cell(
+    pub(crate) fn fixed_up_to_max_batch_capacity_cell(
         &self,
         region_index: RegionIndex,
         index: usize,
     ) -> Cell {
-        assert!(index <= MAX_AGG_SNARKS, "only up to MAX_AGG_SNARKS");
+        assert!(index <= MAX_BATCH_CAPACITY, "only up to MAX_BATCH_CAPACITY");
         Cell {
             region_index,
             row_offset: index,
@@ -143,6 +157,24 @@ impl RlcConfig {
         }
     }
 
+    #[inline]
+    pub(crate) fn batch_header_len_cell(&self, region_index: RegionIndex) -> Cell {
+        Cell {
+            region_index,
+            row_offset: FIXED_OFFSET_BATCH_HEADER_LEN,
+            column: self.fixed.into(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn batch_extra_blob_len_cell(&self, region_index: RegionIndex) -> Cell {
+        Cell {
+            region_index,
+            row_offset: FIXED_OFFSET_BATCH_EXTRA_BLOB_LEN,
+            column: self.fixed.into(),
+        }
+    }
+
     #[inline]
     pub(crate) fn pow_of_two_hundred_and_fifty_six_cell(
         &self,
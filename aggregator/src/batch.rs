@@ -5,8 +5,14 @@ use eth_types::H256;
 use ethers_core::utils::keccak256;
 use gadgets::{util::split_h256, Field};
 use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
 
-use crate::{aggregation::BatchData, blob_consistency::BlobConsistencyWitness, chunk::ChunkInfo};
+use crate::{
+    aggregation::BatchData,
+    blob_consistency::{BlobConsistency, BlobConsistencyWitness, DefaultBlobConsistency},
+    chunk::ChunkInfo,
+    MAX_BLOBS, MULTI_BLOB_BATCH_VERSION,
+};
 
 /// Batch header provides additional fields from the context (within recursion)
 /// for constructing the preimage of the batch hash.
@@ -30,6 +36,17 @@ pub struct BatchHeader<const N_SNARKS: usize> {
     pub blob_versioned_hash: H256,
     /// The blob data proof: z (32), y (32)
     pub blob_data_proof: [H256; 2],
+    /// The versioned hashes of the blobs after the first one, if the batch's data spans several
+    /// blobs. Only set from [`MULTI_BLOB_BATCH_VERSION`] on, and zero for the unused blobs.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub extra_blob_versioned_hashes: [H256; MAX_BLOBS - 1],
+    /// The blob data proofs of the blobs after the first one: z (32), y (32)
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub extra_blob_data_proofs: [[H256; 2]; MAX_BLOBS - 1],
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
 }
 
 impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
@@ -44,6 +61,31 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
         last_block_timestamp: u64,
         chunks: &[ChunkInfo],
         blob_bytes: &[u8],
+    ) -> Self {
+        Self::construct_from_chunks_for::<DefaultBlobConsistency>(
+            version,
+            batch_index,
+            l1_message_popped,
+            total_l1_message_popped,
+            parent_batch_hash,
+            last_block_timestamp,
+            chunks,
+            blob_bytes,
+        )
+    }
+
+    /// Constructs the correct batch header from chunks data and context variables, where the blob
+    /// is committed to as per the blob consistency check B.
+    #[allow(clippy::too_many_arguments)]
+    pub fn construct_from_chunks_for<B: BlobConsistency<N_SNARKS>>(
+        version: u8,
+        batch_index: u64,
+        l1_message_popped: u64,
+        total_l1_message_popped: u64,
+        parent_batch_hash: H256,
+        last_block_timestamp: u64,
+        chunks: &[ChunkInfo],
+        blob_bytes: &[u8],
     ) -> Self {
         assert_ne!(chunks.len(), 0);
         assert!(chunks.len() <= N_SNARKS);
@@ -75,8 +117,17 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
         let batch_data_hash = keccak256(batch_data_hash_preimage);
 
         let batch_data = BatchData::<N_SNARKS>::new(number_of_valid_chunks, &chunks_with_padding);
-        let blob_consistency_witness = BlobConsistencyWitness::new(blob_bytes, &batch_data);
-        Self {
+        let [blob_consistency_witness, extra_blob_consistency_witnesses @ ..] =
+            B::witnesses(blob_bytes, &batch_data);
+        // L1 never posts a blob without data, hence the unused blobs are left out of the header.
+        let extra_blob_data_proofs = extra_blob_consistency_witnesses.map(|witness| {
+            if witness.id().is_zero() {
+                Default::default()
+            } else {
+                witness.blob_data_proof()
+            }
+        });
+        let batch_header = Self {
             version,
             batch_index,
             l1_message_popped,
@@ -86,11 +137,40 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
             data_hash: batch_data_hash.into(),
             blob_versioned_hash: blob_consistency_witness.id(),
             blob_data_proof: blob_consistency_witness.blob_data_proof(),
-        }
+            extra_blob_versioned_hashes: extra_blob_consistency_witnesses
+                .map(|witness| witness.id()),
+            extra_blob_data_proofs,
+        };
+        // sanity check that the batch version allows for the number of blobs
+        batch_header.num_blobs();
+        batch_header
     }
 
-    /// Returns the batch hash as per BatchHeaderV3.
+    /// Returns the number of blobs that carry the batch's data. Only batches from
+    /// [`MULTI_BLOB_BATCH_VERSION`] on may span more than one blob.
+    pub fn num_blobs(&self) -> usize {
+        let num_blobs = 1 + self
+            .extra_blob_versioned_hashes
+            .iter()
+            .take_while(|versioned_hash| !versioned_hash.is_zero())
+            .count();
+        assert!(
+            num_blobs == 1 || self.version == MULTI_BLOB_BATCH_VERSION,
+            "batch version {} does not support {num_blobs} blobs",
+            self.version
+        );
+        num_blobs
+    }
+
+    /// Returns the batch hash as per BatchHeaderV3. A batch of [`MULTI_BLOB_BATCH_VERSION`] that
+    /// spans several blobs further commits to the versioned hash and blob data proof of each
+    /// blob after the first one.
     pub fn batch_hash(&self) -> H256 {
+        keccak256(self.batch_hash_preimage()).into()
+    }
+
+    /// Returns the preimage of the batch hash.
+    pub(crate) fn batch_hash_preimage(&self) -> Vec<u8> {
         // the current batch hash is build as
         // keccak256(
         //     version ||
@@ -104,7 +184,18 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
         //     z ||
         //     y
         // )
-        let batch_hash_preimage = [
+        //
+        // followed by versioned_hash[i] || z[i] || y[i] for each extra blob i the batch uses.
+        let extra_blobs = self
+            .extra_blob_versioned_hashes
+            .iter()
+            .zip(self.extra_blob_data_proofs.iter())
+            .take(self.num_blobs() - 1)
+            .flat_map(|(versioned_hash, [z, y])| {
+                [versioned_hash.as_bytes(), z.as_bytes(), y.as_bytes()].concat()
+            });
+
+        [
             vec![self.version].as_slice(),
             self.batch_index.to_be_bytes().as_ref(),
             self.l1_message_popped.to_be_bytes().as_ref(),
@@ -113,11 +204,13 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
             self.blob_versioned_hash.as_bytes(),
             self.parent_batch_hash.as_bytes(),
             self.last_block_timestamp.to_be_bytes().as_ref(),
-            self.blob_data_proof[0].to_fixed_bytes().as_ref(),
-            self.blob_data_proof[1].to_fixed_bytes().as_ref(),
+            self.blob_data_proof[0].as_bytes(),
+            self.blob_data_proof[1].as_bytes(),
         ]
-        .concat();
-        keccak256(batch_hash_preimage).into()
+        .concat()
+        .into_iter()
+        .chain(extra_blobs)
+        .collect()
     }
 }
 
@@ -126,10 +219,13 @@ impl<const N_SNARKS: usize> BatchHeader<N_SNARKS> {
 /// - the last (#N_SNARKS-k) chunks are from empty traces
 /// A BatchHash consists of 2 hashes.
 /// - batchHash := keccak256(version || batch_index || l1_message_popped || total_l1_message_popped ||
-///   batch_data_hash || versioned_hash || parent_batch_hash || last_block_timestamp || z || y)
+///   batch_data_hash || versioned_hash || parent_batch_hash || last_block_timestamp || z || y ||
+///   extra_blobs), where extra_blobs is empty unless the batch spans several blobs
 /// - batch_data_hash := keccak(chunk_0.data_hash || ... || chunk_k-1.data_hash)
+///
+/// The blob consistency witnesses are computed as per the [`BlobConsistency`] check B.
 #[derive(Default, Debug, Clone)]
-pub struct BatchHash<const N_SNARKS: usize> {
+pub struct BatchHash<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS> = DefaultBlobConsistency> {
     /// Chain ID of the network.
     pub(crate) chain_id: u64,
     /// chunks with padding.
@@ -148,7 +244,7 @@ pub struct BatchHash<const N_SNARKS: usize> {
     /// the current batch hash is calculated as:
     /// - keccak256( version || batch_index || l1_message_popped || total_l1_message_popped ||
     ///   batch_data_hash || versioned_hash || parent_batch_hash || last_block_timestamp ||
-    ///   z || y)
+    ///   z || y || extra_blobs)
     pub(crate) current_batch_hash: H256,
     /// The number of chunks that contain meaningful data, i.e. not padded chunks.
     pub(crate) number_of_valid_chunks: usize,
@@ -156,11 +252,12 @@ pub struct BatchHash<const N_SNARKS: usize> {
     pub(crate) batch_header: BatchHeader<N_SNARKS>,
     /// The blob bytes (may be encoded batch bytes, or may be raw batch bytes).
     pub(crate) blob_bytes: Vec<u8>,
-    /// Witness data to prove that the blob used as advice in the circuit matches the blob from the data availability layer.
-    pub blob_consistency_witness: BlobConsistencyWitness,
+    /// Witness data to prove that each blob used as advice in the circuit matches the blob from the data availability layer.
+    pub blob_consistency_witnesses: [BlobConsistencyWitness; MAX_BLOBS],
+    _blob_consistency: PhantomData<B>,
 }
 
-impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
+impl<const N_SNARKS: usize, B: BlobConsistency<N_SNARKS>> BatchHash<N_SNARKS, B> {
     /// Build Batch hash from an ordered list of chunks. Will pad if needed
     pub fn construct_with_unpadded(
         chunks: &[ChunkInfo],
@@ -273,16 +370,21 @@ impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
 
         let batch_data = BatchData::<N_SNARKS>::new(number_of_valid_chunks, chunks_with_padding);
         let current_batch_hash = batch_header.batch_hash();
-        let blob_consistency_witness = BlobConsistencyWitness::new(blob_bytes, &batch_data);
+        let blob_consistency_witnesses = B::witnesses(blob_bytes, &batch_data);
 
         log::info!(
-            "batch hash {:?}, datahash {}, z {}, y {}, versioned hash {:x}",
+            "batch hash {:?}, datahash {}",
             current_batch_hash,
             hex::encode(batch_data_hash),
-            hex::encode(blob_consistency_witness.challenge().to_bytes()),
-            hex::encode(blob_consistency_witness.evaluation().to_bytes()),
-            blob_consistency_witness.id(),
         );
+        for (i, blob_consistency_witness) in blob_consistency_witnesses.iter().enumerate() {
+            log::info!(
+                "blob {i}: z {}, y {}, versioned hash {:x}",
+                hex::encode(blob_consistency_witness.challenge().to_bytes()),
+                hex::encode(blob_consistency_witness.evaluation().to_bytes()),
+                blob_consistency_witness.id(),
+            );
+        }
 
         Self {
             chain_id: chunks_with_padding[0].chain_id,
@@ -295,7 +397,8 @@ impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
             number_of_valid_chunks,
             batch_header,
             blob_bytes: blob_bytes.to_vec(),
-            blob_consistency_witness,
+            blob_consistency_witnesses,
+            _blob_consistency: PhantomData,
         }
     }
 
@@ -308,7 +411,7 @@ impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
     /// - batch_data_hash_preimage
     /// - preimage for blob metadata
     /// - chunk\[i\].flattened_l2_signed_data for i in \[0, N_SNARKS)
-    /// - preimage for challenge digest
+    /// - preimage for challenge digest of blob\[i\] for i in \[0, MAX_BLOBS)
     pub(crate) fn extract_hash_preimages(&self) -> Vec<Vec<u8>> {
         let mut res = vec![];
 
@@ -323,31 +426,14 @@ impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
         //     parent_batch_hash ||
         //     last_block_timestamp ||
         //     z ||
-        //     y
+        //     y ||
+        //     extra_blobs
         // )
-        let batch_hash_preimage = [
-            [self.batch_header.version].as_ref(),
-            self.batch_header.batch_index.to_be_bytes().as_ref(),
-            self.batch_header.l1_message_popped.to_be_bytes().as_ref(),
-            self.batch_header
-                .total_l1_message_popped
-                .to_be_bytes()
-                .as_ref(),
-            self.data_hash.as_bytes(),
-            self.batch_header.blob_versioned_hash.as_bytes(),
-            self.batch_header.parent_batch_hash.as_bytes(),
-            self.batch_header
-                .last_block_timestamp
-                .to_be_bytes()
-                .as_ref(),
-            self.batch_header.blob_data_proof[0]
-                .to_fixed_bytes()
-                .as_ref(),
-            self.batch_header.blob_data_proof[1]
-                .to_fixed_bytes()
-                .as_ref(),
-        ]
-        .concat();
+        let batch_hash_preimage = BatchHeader {
+            data_hash: self.data_hash,
+            ..self.batch_header
+        }
+        .batch_hash_preimage();
         res.push(batch_hash_preimage);
 
         // compute piHash for each chunk for i in [0..N_SNARKS)
@@ -380,9 +466,12 @@ impl<const N_SNARKS: usize> BatchHash<N_SNARKS> {
         // These include:
         // - preimage for batch metadata
         // - preimage for each chunk's flattened L2 signed tx data
-        // - preimage for the challenge digest
+        // - preimage for each blob's challenge digest
         let batch_data = BatchData::from(self);
-        let dynamic_preimages = batch_data.preimages(self.batch_header.blob_versioned_hash);
+        let versioned_hashes = self
+            .blob_consistency_witnesses
+            .map(|blob_consistency_witness| blob_consistency_witness.id());
+        let dynamic_preimages = batch_data.preimages(&versioned_hashes);
         for dynamic_preimage in dynamic_preimages {
             res.push(dynamic_preimage);
         }
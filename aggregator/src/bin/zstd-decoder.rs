@@ -0,0 +1,97 @@
+use std::{fs, path::Path};
+
+use aggregator::{decode_bytes, ZstdDecoderCircuit};
+use anyhow::{bail, Context};
+use clap::Parser;
+use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
+use rand::rngs::OsRng;
+use snark_verifier::loader::halo2::halo2_ecc::halo2_base::utils::fs::gen_srs;
+use snark_verifier_sdk::{gen_pk, gen_snark_shplonk, verify_snark_shplonk, CircuitExt};
+
+/// Prove and verify that a zstd frame decompresses to the given bytes.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// File holding the zstd frame.
+    #[clap(long)]
+    compressed: String,
+
+    /// File holding the bytes the zstd frame is expected to decompress to.
+    #[clap(long)]
+    decompressed: String,
+
+    /// Read both files as hex strings instead of raw bytes.
+    #[clap(long)]
+    hex: bool,
+
+    /// Degree of the circuit.
+    #[clap(long, default_value_t = 21)]
+    degree: u32,
+
+    /// Only run the mock prover.
+    #[clap(long)]
+    mock: bool,
+}
+
+fn read_bytes(path: &str, hex: bool) -> anyhow::Result<Vec<u8>> {
+    let path = Path::new(path);
+    if hex {
+        let data = fs::read_to_string(path).with_context(|| format!("reading {path:?}"))?;
+        hex::decode(data.trim().trim_start_matches("0x"))
+            .with_context(|| format!("decoding hex in {path:?}"))
+    } else {
+        fs::read(path).with_context(|| format!("reading {path:?}"))
+    }
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+    let compressed = read_bytes(&args.compressed, args.hex)?;
+    let decompressed = read_bytes(&args.decompressed, args.hex)?;
+
+    // sanity check before synthesizing the circuit, the leading byte marks the bytes as encoded.
+    let recovered =
+        decode_bytes(&[&[1u8], compressed.as_slice()].concat()).context("decoding zstd frame")?;
+    if recovered != decompressed {
+        bail!(
+            "zstd frame decompresses to {} bytes which do not match the {} expected bytes",
+            recovered.len(),
+            decompressed.len()
+        );
+    }
+    log::info!(
+        "len(compressed)={} len(decompressed)={}",
+        compressed.len(),
+        decompressed.len()
+    );
+
+    let circuit = ZstdDecoderCircuit::new(compressed, decompressed, args.degree);
+    for (i, instance) in circuit.instances()[0].iter().enumerate() {
+        log::info!("{}-th public input: {:?}", i, instance);
+    }
+
+    if args.mock {
+        let mock_prover = MockProver::<Fr>::run(args.degree, &circuit, circuit.instances())
+            .map_err(|e| anyhow::anyhow!("mock prover failed: {e:?}"))?;
+        if let Err(errs) = mock_prover.verify_par() {
+            bail!("mock prover verification failed: {errs:#?}");
+        }
+        log::info!("mock prover verification succeeded");
+        return Ok(());
+    }
+
+    let params = gen_srs(args.degree);
+    let pk = gen_pk(&params, &circuit, None);
+    let snark = gen_snark_shplonk(&params, &pk, circuit, &mut OsRng, None::<String>)
+        .map_err(|e| anyhow::anyhow!("proof generation failed: {e:?}"))?;
+    log::info!("proof size: {}", snark.proof.len());
+
+    if !verify_snark_shplonk::<ZstdDecoderCircuit>(&params, snark, pk.get_vk()) {
+        bail!("proof verification failed");
+    }
+    log::info!("proof verification succeeded");
+
+    Ok(())
+}
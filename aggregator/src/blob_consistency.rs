@@ -1,27 +1,199 @@
 use cfg_if::cfg_if;
+use eth_types::{ToBigEndian, ToLittleEndian, H256, U256};
+use halo2_base::{gates::range::RangeConfig, Context};
+use halo2_ecc::bigint::CRTInteger;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Value},
+    halo2curves::{bls12_381::Scalar, bn256::Fr},
+    plonk::{ConstraintSystem, Error, Expression},
+};
+use snark_verifier_sdk::LIMBS;
+use std::fmt::Debug;
+use zkevm_circuits::{table::U8Table, util::Challenges};
 
-// enum DataAvailability {
-//     Eip4844,
-//     Avail,
-// }
+use crate::{BatchData, RlcConfig, MAX_BLOBS};
 
 mod blob_data;
-use blob_data::{AssignedBlobDataExport, BlobDataConfig};
+pub use blob_data::AssignedBlobDataExport;
+use blob_data::BlobDataConfig;
 
-// TODO: remove dead code instead
-#[allow(dead_code)]
 mod avail;
+pub use avail::{get_blob_commitment, Avail, LocalDataAvailability};
 
-// TODO: remove dead code instead
-#[allow(dead_code)]
 mod eip4844;
+pub use eip4844::{
+    get_blob_bytes, get_versioned_hash, AssignedBarycentricEvaluationConfig, Eip4844, BLOB_WIDTH,
+    N_BLOB_BYTES,
+};
+use eip4844::{PointEvaluationAssignments, BLS_MODULUS};
 
 cfg_if! {
     if #[cfg(feature = "da-avail")] {
-        // const DATA_AVAILABILITY: DataAvailability = DataAvailability::Avail;
-        pub use avail::{BlobConsistencyConfig, BlobConsistencyWitness, BLOB_WIDTH, get_blob_bytes};
+        /// The blob consistency check used by default, i.e. Avail.
+        pub type DefaultBlobConsistency = Avail;
     } else {
-        // const DATA_AVAILABILITY: DatayAvailability = DataAvailability::Eip4844;
-        pub use eip4844::{BlobConsistencyConfig, BlobConsistencyWitness, BLOB_WIDTH, get_blob_bytes};
+        /// The blob consistency check used by default, i.e. EIP-4844.
+        pub type DefaultBlobConsistency = Eip4844;
     }
 }
+
+/// Segment the blob bytes of a batch, i.e. the encoding flag followed by the (possibly encoded)
+/// batch bytes, into the MAX_BLOBS blobs that carry them. Every blob but the last non-empty one is
+/// full, and the blobs that are not needed are empty.
+pub fn segment_blob_bytes(blob_bytes: &[u8]) -> [&[u8]; MAX_BLOBS] {
+    assert!(
+        !blob_bytes.is_empty(),
+        "blob bytes start with the encoding flag"
+    );
+    assert!(
+        blob_bytes.len() <= N_BLOB_BYTES * MAX_BLOBS,
+        "too many blob bytes: {} > {}",
+        blob_bytes.len(),
+        N_BLOB_BYTES * MAX_BLOBS
+    );
+
+    let mut segments = [&blob_bytes[..0]; MAX_BLOBS];
+    for (segment, bytes) in segments.iter_mut().zip(blob_bytes.chunks(N_BLOB_BYTES)) {
+        *segment = bytes;
+    }
+    segments
+}
+
+/// Check that the blob used as advice in the batch circuit matches the commitment to the blob
+/// obtained from the data availability layer.
+///
+/// The implementors are marker types, such that the batch circuit can be made generic over the data
+/// availability layer, see [`BatchCircuit`](crate::BatchCircuit).
+pub trait BlobConsistency<const N_SNARKS: usize>: Clone + Debug + Default {
+    /// The circuit configuration of the consistency check.
+    type Config: Clone + Debug;
+
+    /// Configure the consistency check.
+    fn configure(
+        meta: &mut ConstraintSystem<Fr>,
+        challenges: &Challenges<Expression<Fr>>,
+        u8_table: U8Table,
+        range: RangeConfig<Fr>,
+    ) -> Self::Config;
+
+    /// Assign the evaluation of the polynomial of a single blob at the challenge derived from the
+    /// challenge digest.
+    fn assign_barycentric(
+        config: &Self::Config,
+        ctx: &mut Context<Fr>,
+        blob_bytes: &[u8],
+        challenge_digest: U256,
+    ) -> AssignedBarycentricEvaluationConfig;
+
+    /// Assign the blob bytes of the batch, spanning all the MAX_BLOBS blobs, and export their RLC
+    /// and coefficients.
+    fn assign_blob_data(
+        config: &Self::Config,
+        layouter: &mut impl Layouter<Fr>,
+        challenges: Challenges<Value<Fr>>,
+        rlc_config: &RlcConfig,
+        blob_bytes: &[u8],
+    ) -> Result<AssignedBlobDataExport, Error>;
+
+    /// Constrain the coefficients of a single blob to be the ones used in its barycentric
+    /// evaluation.
+    fn link(
+        layouter: &mut impl Layouter<Fr>,
+        blob_crts_limbs: &[[AssignedCell<Fr, Fr>; LIMBS]],
+        barycentric_crts: &[CRTInteger<Fr>],
+    ) -> Result<(), Error>;
+
+    /// The commitment to a single blob on the data availability layer. It takes the place of the
+    /// versioned hash in the batch header.
+    fn commitment(blob_bytes: &[u8]) -> H256;
+
+    /// Compute the witness of the consistency check for a single blob.
+    fn witness(blob_bytes: &[u8], batch_data: &BatchData<N_SNARKS>) -> BlobConsistencyWitness {
+        BlobConsistencyWitness::new(Self::commitment(blob_bytes), blob_bytes, batch_data)
+    }
+
+    /// Compute the witnesses of the consistency check for each of the MAX_BLOBS blobs carrying the
+    /// blob bytes of the batch, see [`segment_blob_bytes`]. The blobs that are not needed are never
+    /// posted, hence their id is zero.
+    fn witnesses(
+        blob_bytes: &[u8],
+        batch_data: &BatchData<N_SNARKS>,
+    ) -> [BlobConsistencyWitness; MAX_BLOBS] {
+        segment_blob_bytes(blob_bytes).map(|segment| {
+            if segment.is_empty() {
+                BlobConsistencyWitness::new(H256::zero(), segment, batch_data)
+            } else {
+                Self::witness(segment, batch_data)
+            }
+        })
+    }
+}
+
+/// Witness data to prove that the blob used as advice in the circuit matches the blob from the
+/// data availability layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobConsistencyWitness {
+    id: H256,
+    challenge_digest: H256,
+    evaluation: Scalar,
+}
+
+impl BlobConsistencyWitness {
+    fn new<const N_SNARKS: usize>(
+        id: H256,
+        blob_bytes: &[u8],
+        batch_data: &BatchData<N_SNARKS>,
+    ) -> Self {
+        let point_evaluation_assignments =
+            PointEvaluationAssignments::new(batch_data, blob_bytes, id);
+        Self {
+            id,
+            challenge_digest: digest_from_word(point_evaluation_assignments.challenge_digest),
+            evaluation: scalar_from_word(point_evaluation_assignments.evaluation),
+        }
+    }
+
+    /// The commitment to the blob, e.g. the versioned hash for EIP-4844.
+    pub fn id(&self) -> H256 {
+        self.id
+    }
+
+    pub fn challenge_digest(&self) -> U256 {
+        word_from_digest(self.challenge_digest)
+    }
+
+    pub fn challenge(&self) -> Scalar {
+        scalar_from_digest(self.challenge_digest)
+    }
+
+    pub fn evaluation(&self) -> Scalar {
+        self.evaluation
+    }
+
+    pub fn blob_data_proof(&self) -> [H256; 2] {
+        [self.challenge(), self.evaluation].map(digest_from_scalar)
+    }
+}
+
+fn digest_from_word(x: U256) -> H256 {
+    H256::from_slice(&x.to_be_bytes())
+}
+
+fn digest_from_scalar(x: Scalar) -> H256 {
+    let mut bytes = x.to_bytes();
+    bytes.reverse();
+    H256::from_slice(&bytes)
+}
+
+fn scalar_from_word(x: U256) -> Scalar {
+    let (_quotient, remainder) = x.div_mod(*BLS_MODULUS);
+    Scalar::from_bytes(&remainder.to_le_bytes()).expect("non-canonical bytes")
+}
+
+fn scalar_from_digest(x: H256) -> Scalar {
+    scalar_from_word(word_from_digest(x))
+}
+
+fn word_from_digest(x: H256) -> U256 {
+    U256::from_big_endian(&x.to_fixed_bytes())
+}
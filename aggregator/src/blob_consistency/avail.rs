@@ -1,7 +1,14 @@
-use super::AssignedBlobDataExport;
-use crate::{BatchData, RlcConfig};
-use eth_types::{H256, U256};
-use halo2_base::{gates::range::RangeConfig, AssignedValue, Context};
+use super::{
+    eip4844::{
+        get_coefficients, interpolate, AssignedBarycentricEvaluationConfig, BlobConsistencyConfig,
+        BLOB_WIDTH,
+    },
+    scalar_from_digest, AssignedBlobDataExport, BlobConsistency,
+};
+use crate::RlcConfig;
+use eth_types::{ToBigEndian, H256, U256};
+use ethers_core::utils::keccak256;
+use halo2_base::{gates::range::RangeConfig, Context};
 use halo2_ecc::bigint::CRTInteger;
 use halo2_proofs::halo2curves::bls12_381::Scalar;
 use halo2_proofs::{
@@ -9,97 +16,166 @@ use halo2_proofs::{
     halo2curves::bn256::Fr,
     plonk::{ConstraintSystem, Error, Expression},
 };
+use itertools::Itertools;
 use snark_verifier_sdk::LIMBS;
+use std::collections::HashMap;
 use zkevm_circuits::{table::U8Table, util::Challenges};
 
-pub const BLOB_WIDTH: usize = 4096;
+/// Get the commitment to the blob as posted to the data availability layer.
+///
+/// The commitment is the root of a binary keccak Merkle tree, whose leaves are the keccak digests
+/// of the BLOB_WIDTH coefficients (as 32-bytes big-endian words) of the blob polynomial. Unlike the
+/// EIP-4844 versioned hash, the commitment does not rely on a KZG trusted setup and individual
+/// coefficients can be opened against it with a Merkle path.
+pub fn get_blob_commitment(coefficients: &[U256; BLOB_WIDTH]) -> H256 {
+    let mut layer = coefficients
+        .iter()
+        .map(|coeff| keccak256(coeff.to_be_bytes()))
+        .collect_vec();
+    while layer.len() > 1 {
+        layer = layer
+            .chunks_exact(2)
+            .map(|pair| keccak256([pair[0], pair[1]].concat()))
+            .collect_vec();
+    }
+    H256::from(layer[0])
+}
+
+/// The blob consistency check for the Avail data availability layer.
+///
+/// The circuit argues the same statement as for EIP-4844, i.e. the blob polynomial interpolated
+/// over the blob bytes evaluates to `y` at the challenge `z`. The challenge is derived from the
+/// blob commitment (see [`get_blob_commitment`]), which takes the place of the versioned hash in
+/// the batch header. The data availability layer, or a verifier holding the blob, checks the
+/// commitment and the evaluation against the posted blob (see [`LocalDataAvailability`]).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Avail;
 
-#[derive(Debug, Clone)]
-pub struct BlobConsistencyConfig<const N_SNARKS: usize> {}
+impl<const N_SNARKS: usize> BlobConsistency<N_SNARKS> for Avail {
+    type Config = BlobConsistencyConfig<N_SNARKS>;
 
-impl<const N_SNARKS: usize> BlobConsistencyConfig<N_SNARKS> {
-    pub fn construct(
-        _meta: &mut ConstraintSystem<Fr>,
-        _challenges: &Challenges<Expression<Fr>>,
-        _u8_table: U8Table,
-        _: RangeConfig<Fr>,
-    ) -> Self {
-        unimplemented!()
+    fn configure(
+        meta: &mut ConstraintSystem<Fr>,
+        challenges: &Challenges<Expression<Fr>>,
+        u8_table: U8Table,
+        range: RangeConfig<Fr>,
+    ) -> Self::Config {
+        BlobConsistencyConfig::construct(meta, challenges, u8_table, range)
     }
 
-    pub fn assign_barycentric(
-        &self,
-        _ctx: &mut Context<Fr>,
-        _bytes: &[u8],
-        _challenge: U256,
+    fn assign_barycentric(
+        config: &Self::Config,
+        ctx: &mut Context<Fr>,
+        blob_bytes: &[u8],
+        challenge_digest: U256,
     ) -> AssignedBarycentricEvaluationConfig {
-        unimplemented!()
+        config.assign_barycentric(ctx, blob_bytes, challenge_digest)
     }
 
-    pub fn assign_blob_data(
-        &self,
-        _layouter: &mut impl Layouter<Fr>,
-        _challenge_value: Challenges<Value<Fr>>,
-        _rlc_config: &RlcConfig,
-        _blob_bytes: &[u8],
+    fn assign_blob_data(
+        config: &Self::Config,
+        layouter: &mut impl Layouter<Fr>,
+        challenges: Challenges<Value<Fr>>,
+        rlc_config: &RlcConfig,
+        blob_bytes: &[u8],
     ) -> Result<AssignedBlobDataExport, Error> {
-        unimplemented!()
+        config.assign_blob_data(layouter, challenges, rlc_config, blob_bytes)
     }
 
-    pub fn link(
-        _layouter: &mut impl Layouter<Fr>,
-        _blob_crts_limbs: &[[AssignedCell<Fr, Fr>; LIMBS]],
-        _barycentric_crts: &[CRTInteger<Fr>],
+    fn link(
+        layouter: &mut impl Layouter<Fr>,
+        blob_crts_limbs: &[[AssignedCell<Fr, Fr>; LIMBS]],
+        barycentric_crts: &[CRTInteger<Fr>],
     ) -> Result<(), Error> {
-        unimplemented!()
+        BlobConsistencyConfig::<N_SNARKS>::link(layouter, blob_crts_limbs, barycentric_crts)
+    }
+
+    fn commitment(blob_bytes: &[u8]) -> H256 {
+        get_blob_commitment(&get_coefficients(blob_bytes))
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-pub struct BlobConsistencyWitness {
-    blob_versioned_hash: H256,
-    challenge_digest: H256,
-    evaluation: Scalar,
+/// A local stand-in for the Avail data availability layer.
+///
+/// Blobs are stored under their commitment, and a batch is checked against the posted blob the
+/// same way the point evaluation precompile checks an EIP-4844 blob, i.e. given the commitment
+/// and the `blob_data_proof` (challenge `z` and evaluation `y`) from the batch header.
+#[derive(Debug, Default)]
+pub struct LocalDataAvailability {
+    blobs: HashMap<H256, [U256; BLOB_WIDTH]>,
 }
 
-impl BlobConsistencyWitness {
-    pub fn new<const N_SNARKS: usize>(_bytes: &[u8], _batch_data: &BatchData<N_SNARKS>) -> Self {
-        unimplemented!()
+impl LocalDataAvailability {
+    /// Post the blob bytes, returning the commitment the blob is stored under.
+    pub fn submit(&mut self, blob_bytes: &[u8]) -> H256 {
+        let coefficients = get_coefficients(blob_bytes);
+        let commitment = get_blob_commitment(&coefficients);
+        self.blobs.insert(commitment, coefficients);
+        commitment
     }
 
-    pub fn id(&self) -> H256 {
-        unimplemented!()
+    /// Whether a blob was posted under the given commitment.
+    pub fn contains(&self, commitment: H256) -> bool {
+        self.blobs.contains_key(&commitment)
     }
 
-    pub fn challenge_digest(&self) -> U256 {
-        unimplemented!()
+    /// Check that the blob posted under the commitment evaluates to `y` at `z`, where
+    /// `blob_data_proof` is `[z, y]` as found in the batch header.
+    pub fn verify(&self, commitment: H256, blob_data_proof: [H256; 2]) -> bool {
+        let [challenge, evaluation] = blob_data_proof.map(scalar_from_digest);
+        self.blobs.get(&commitment).is_some_and(|coefficients| {
+            let coefficients = coefficients.map(|coeff| Scalar::from_raw(coeff.0));
+            interpolate(challenge, &coefficients) == evaluation
+        })
     }
+}
 
-    pub fn challenge(&self) -> Scalar {
-        unimplemented!()
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        blob_consistency::{get_blob_bytes, get_versioned_hash},
+        BatchData, MAX_AGG_SNARKS,
+    };
 
-    pub fn evaluation(&self) -> Scalar {
-        unimplemented!()
+    fn blob_bytes(batch_data: &BatchData<MAX_AGG_SNARKS>) -> Vec<u8> {
+        get_blob_bytes(&batch_data.get_batch_data_bytes())
     }
 
-    pub fn blob_data_proof(&self) -> [H256; 2] {
-        unimplemented!()
+    #[test]
+    fn blob_commitment() {
+        let batch_data: BatchData<MAX_AGG_SNARKS> = (&vec![vec![1, 2, 3], vec![7, 8, 9]]).into();
+        let coeffs = get_coefficients(&blob_bytes(&batch_data));
+
+        let commitment = get_blob_commitment(&coeffs);
+        assert_eq!(commitment, get_blob_commitment(&coeffs));
+        assert_ne!(commitment, get_versioned_hash(&coeffs));
+
+        let mut tampered = coeffs;
+        tampered[BLOB_WIDTH - 1] = tampered[BLOB_WIDTH - 1] + U256::one();
+        assert_ne!(commitment, get_blob_commitment(&tampered));
     }
-}
 
-#[derive(Default)]
-pub struct AssignedBarycentricEvaluationConfig {
-    /// CRTIntegers for the BLOB_WIDTH number of blob polynomial coefficients, followed by a
-    /// CRTInteger for the challenge digest.
-    pub(crate) barycentric_assignments: Vec<CRTInteger<Fr>>,
-    /// 32 Assigned cells representing the LE-bytes of challenge z.
-    pub(crate) z_le: Vec<AssignedValue<Fr>>,
-    /// 32 Assigned cells representing the LE-bytes of evaluation y.
-    pub(crate) y_le: Vec<AssignedValue<Fr>>,
-}
+    #[test]
+    fn local_data_availability() {
+        let batch_data: BatchData<MAX_AGG_SNARKS> = (&vec![vec![1, 2, 3], vec![7, 8, 9]]).into();
+        let blob_bytes = blob_bytes(&batch_data);
+        let witness = <Avail as BlobConsistency<MAX_AGG_SNARKS>>::witness(&blob_bytes, &batch_data);
+
+        let mut da = LocalDataAvailability::default();
+        assert!(!da.contains(witness.id()));
+        assert_eq!(da.submit(&blob_bytes), witness.id());
+        assert!(da.contains(witness.id()));
+        assert!(da.verify(witness.id(), witness.blob_data_proof()));
 
-/// Get the blob data bytes that will be populated in BlobDataConfig.
-pub fn get_blob_bytes(_batch_bytes: &[u8]) -> Vec<u8> {
-    unimplemented!("trick for linting");
+        // wrong evaluation
+        let [z, _y] = witness.blob_data_proof();
+        assert!(!da.verify(witness.id(), [z, H256::repeat_byte(1)]));
+
+        // a different blob
+        let other_batch_data: BatchData<MAX_AGG_SNARKS> = (&vec![vec![4, 5, 6]]).into();
+        let other_commitment = da.submit(&self::blob_bytes(&other_batch_data));
+        assert_ne!(other_commitment, witness.id());
+        assert!(!da.verify(other_commitment, witness.blob_data_proof()));
+    }
 }
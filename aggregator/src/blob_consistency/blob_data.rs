@@ -4,7 +4,7 @@ use crate::{
         POWS_OF_256,
     },
     blob_consistency::BLOB_WIDTH,
-    RlcConfig,
+    RlcConfig, MAX_BLOBS,
 };
 use gadgets::util::Expr;
 use halo2_proofs::{
@@ -23,22 +23,23 @@ use zkevm_circuits::{table::U8Table, util::Challenges};
 /// byte in each 32-bytes chunk to zero, i.e. effectively we use only 31 bytes.
 ///
 /// Since the check for the most-significant byte being zero is already done in the
-/// BarycentricConfig, in the BlobDataConfig we only represent the 31 meaningful bytes. Hence the
-/// BlobDataConfig has 4096 * 31 rows. Each row is a byte value and the purpose of the
-/// BlobDataConfig is to compute a random-linear combination of these bytes. These bytes are in
-/// fact the zstd encoded form of the raw batch data represented in BatchDataConfig.
+/// BarycentricConfig, in the BlobDataConfig we only represent the 31 meaningful bytes. The data of
+/// a batch spans up to MAX_BLOBS blobs, laid out one after the other, hence the BlobDataConfig has
+/// MAX_BLOBS * 4096 * 31 rows. Each row is a byte value and the purpose of the BlobDataConfig is
+/// to compute a random-linear combination of these bytes. These bytes are in fact the zstd encoded
+/// form of the raw batch data represented in BatchDataConfig.
 #[derive(Clone, Debug)]
 pub struct BlobDataConfig<const N_SNARKS: usize> {
     /// Selector to mark the first row in the layout, enabled at offset=0.
     q_first: Column<Fixed>,
-    /// Whether the row is enabled or not. We need exactly N_BLOB_BYTES rows, enabled from offset=1
-    /// to offset=N_BLOB_BYTES.
+    /// Whether the row is enabled or not. We need exactly MAX_BLOBS * N_BLOB_BYTES rows, enabled
+    /// from offset=1 to offset=MAX_BLOBS * N_BLOB_BYTES.
     q_enabled: Selector,
     /// The byte value at this row.
     byte: Column<Advice>,
-    /// Whether or not this is a padded row. This can be the case if not all bytes in the blob
-    /// (4096 * 31) could be filled. Padded bytes must be 0 and bytes_rlc must continue while in
-    /// the padded region.
+    /// Whether or not this is a padded row. This can be the case if not all bytes in the blobs
+    /// (MAX_BLOBS * 4096 * 31) could be filled. Padded bytes must be 0 and bytes_rlc must continue
+    /// while in the padded region.
     is_padding: Column<Advice>,
     /// running RLC of bytes seen so far. It remains unchanged once padded territory starts.
     bytes_rlc: Column<Advice>,
@@ -52,7 +53,10 @@ pub struct AssignedBlobDataExport {
     pub bytes_rlc: AssignedCell<Fr, Fr>,
     pub bytes_len: AssignedCell<Fr, Fr>,
     pub cooked_len: AssignedCell<Fr, Fr>,
-    pub blob_crts_limbs: [[AssignedCell<Fr, Fr>; LIMBS]; BLOB_WIDTH],
+    /// The limbs of the coefficients of every blob, i.e. MAX_BLOBS * BLOB_WIDTH of them.
+    pub blob_crts_limbs: Vec<[AssignedCell<Fr, Fr>; LIMBS]>,
+    /// Whether the first row of each blob is padded, i.e. whether the blob carries no data at all.
+    pub blobs_are_padding: Vec<AssignedCell<Fr, Fr>>,
 }
 
 impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
@@ -71,6 +75,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
         };
 
         meta.enable_equality(config.byte);
+        meta.enable_equality(config.is_padding);
         meta.enable_equality(config.bytes_rlc);
         meta.enable_equality(config.bytes_len);
 
@@ -158,10 +163,11 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
         rlc_config: &RlcConfig,
         blob_bytes: &[u8],
     ) -> Result<AssignedBlobDataExport, Error> {
-        let (assigned_bytes, bytes_rlc, bytes_len, enable_encoding_bool) = layouter.assign_region(
-            || "BlobData bytes",
-            |mut region| self.assign_rows(&mut region, blob_bytes, &challenge_value),
-        )?;
+        let (assigned_bytes, bytes_rlc, bytes_len, blobs_are_padding, enable_encoding_bool) =
+            layouter.assign_region(
+                || "BlobData bytes",
+                |mut region| self.assign_rows(&mut region, blob_bytes, &challenge_value),
+            )?;
         let enable_encoding = assigned_bytes[0].clone();
 
         let (cooked_len, blob_crts_limbs) = layouter.assign_region(
@@ -178,6 +184,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
             bytes_len,
             cooked_len,
             blob_crts_limbs,
+            blobs_are_padding,
         })
     }
 
@@ -192,17 +199,21 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
             Vec<AssignedCell<Fr, Fr>>,
             AssignedCell<Fr, Fr>,
             AssignedCell<Fr, Fr>,
+            Vec<AssignedCell<Fr, Fr>>,
             bool,
         ),
         Error,
     > {
         let enable_encoding = blob_bytes[0].eq(&1);
 
-        assert!(blob_bytes.len() <= N_BLOB_BYTES, "too many blob bytes");
+        assert!(
+            blob_bytes.len() <= MAX_BLOBS * N_BLOB_BYTES,
+            "too many blob bytes"
+        );
 
         // Assign fixed column and selector.
         region.assign_fixed(|| "q_first", self.q_first, 0, || Value::known(Fr::one()))?;
-        for i in 1..=N_BLOB_BYTES {
+        for i in 1..=MAX_BLOBS * N_BLOB_BYTES {
             self.q_enabled.enable(region, i)?;
             region.assign_fixed(|| "q_first", self.q_first, i, || Value::known(Fr::zero()))?;
         }
@@ -216,7 +227,8 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
             )?;
         }
 
-        let mut assigned_bytes = Vec::with_capacity(N_BLOB_BYTES);
+        let mut assigned_bytes = Vec::with_capacity(MAX_BLOBS * N_BLOB_BYTES);
+        let mut blobs_are_padding = Vec::with_capacity(MAX_BLOBS);
         let mut bytes_rlc = Value::known(Fr::zero());
         let mut last_bytes_rlc = None;
         let mut last_bytes_len = None;
@@ -233,12 +245,15 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
                 offset,
                 || byte_value,
             )?);
-            region.assign_advice(
+            let is_padding = region.assign_advice(
                 || "is_padding",
                 self.is_padding,
                 offset,
                 || Value::known(Fr::zero()),
             )?;
+            if i % N_BLOB_BYTES == 0 {
+                blobs_are_padding.push(is_padding);
+            }
             last_bytes_rlc =
                 Some(region.assign_advice(|| "bytes_rlc", self.bytes_rlc, offset, || bytes_rlc)?);
             last_bytes_len = Some(region.assign_advice(
@@ -251,7 +266,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
 
         let mut last_bytes_rlc = last_bytes_rlc.expect("at least 1 byte guaranteed");
         let mut last_bytes_len = last_bytes_len.expect("at least 1 byte guaranteed");
-        for i in blob_bytes.len()..N_BLOB_BYTES {
+        for i in blob_bytes.len()..MAX_BLOBS * N_BLOB_BYTES {
             let offset = i + 1;
             assigned_bytes.push(region.assign_advice(
                 || "byte",
@@ -259,12 +274,15 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
                 offset,
                 || Value::known(Fr::zero()),
             )?);
-            region.assign_advice(
+            let is_padding = region.assign_advice(
                 || "is_padding",
                 self.is_padding,
                 offset,
                 || Value::known(Fr::one()),
             )?;
+            if i % N_BLOB_BYTES == 0 {
+                blobs_are_padding.push(is_padding);
+            }
             last_bytes_rlc = region.assign_advice(
                 || "bytes_rlc",
                 self.bytes_rlc,
@@ -283,6 +301,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
             assigned_bytes,
             last_bytes_rlc,
             last_bytes_len,
+            blobs_are_padding,
             enable_encoding,
         ))
     }
@@ -294,13 +313,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
         rlc_config: &RlcConfig,
         assigned_bytes: &[AssignedCell<Fr, Fr>],
         bytes_len: &AssignedCell<Fr, Fr>,
-    ) -> Result<
-        (
-            AssignedCell<Fr, Fr>,
-            [[AssignedCell<Fr, Fr>; LIMBS]; BLOB_WIDTH],
-        ),
-        Error,
-    > {
+    ) -> Result<(AssignedCell<Fr, Fr>, Vec<[AssignedCell<Fr, Fr>; LIMBS]>), Error> {
         rlc_config.init(region)?;
         let mut rlc_config_offset = 0;
 
@@ -332,7 +345,7 @@ impl<const N_SNARKS: usize> BlobDataConfig<N_SNARKS> {
         rlc_config.enforce_binary(region, &assigned_bytes[0], &mut rlc_config_offset)?;
 
         // Compute 88 bit limbs so we can later check to equality to the inputs in the barycentric
-        // evaluation circuit.
+        // evaluation circuit of each blob.
         let blob_crts_limbs = blob_crts_limbs(
             region,
             rlc_config,
@@ -356,7 +369,12 @@ fn blob_crts_limbs(
     bytes: &[AssignedCell<Fr, Fr>],
     powers_of_256: &[AssignedCell<Fr, Fr>],
     offset: &mut usize,
-) -> [[AssignedCell<Fr, Fr>; LIMBS]; BLOB_WIDTH] {
+) -> Vec<[AssignedCell<Fr, Fr>; LIMBS]> {
+    assert_eq!(
+        bytes.len(),
+        MAX_BLOBS * BLOB_WIDTH * N_DATA_BYTES_PER_COEFFICIENT
+    );
+
     bytes
         .chunks_exact(N_DATA_BYTES_PER_COEFFICIENT)
         .map(|coefficient_bytes| {
@@ -381,6 +399,4 @@ fn blob_crts_limbs(
                 .unwrap()
         })
         .collect_vec()
-        .try_into()
-        .unwrap()
 }
@@ -1,26 +1,23 @@
 /// Config to evaluate blob polynomial at a random challenge.
 mod barycentric;
-use barycentric::{
-    interpolate, AssignedBarycentricEvaluationConfig, BarycentricEvaluationConfig, BLS_MODULUS,
-};
+pub use barycentric::AssignedBarycentricEvaluationConfig;
+pub(super) use barycentric::{interpolate, BarycentricEvaluationConfig, BLS_MODULUS};
 
 /// blob struct and constants
 mod blob;
-use blob::PointEvaluationAssignments;
+pub(super) use blob::PointEvaluationAssignments;
 
 #[cfg(test)]
 mod tests;
 
-use super::{AssignedBlobDataExport, BlobDataConfig};
+use super::{AssignedBlobDataExport, BlobConsistency, BlobDataConfig};
 use crate::{
-    aggregation::batch_data::N_DATA_BYTES_PER_COEFFICIENT, constants::N_BYTES_U256, BatchData,
-    RlcConfig,
+    aggregation::batch_data::N_DATA_BYTES_PER_COEFFICIENT, constants::N_BYTES_U256, RlcConfig,
 };
-use eth_types::{ToBigEndian, ToLittleEndian, H256, U256};
+use eth_types::{ToBigEndian, H256, U256};
 use ethers_core::k256::sha2::{Digest, Sha256};
 use halo2_base::{gates::range::RangeConfig, Context};
 use halo2_ecc::bigint::CRTInteger;
-use halo2_proofs::halo2curves::bls12_381::Scalar;
 use halo2_proofs::{
     circuit::{AssignedCell, Layouter, Value},
     halo2curves::bn256::Fr,
@@ -37,7 +34,7 @@ pub const BLOB_WIDTH: usize = 4096;
 pub const N_BLOB_BYTES: usize = BLOB_WIDTH * N_DATA_BYTES_PER_COEFFICIENT;
 
 /// Get the BLOB_WIDTH number of scalar field elements, as 32-bytes unsigned integers.
-fn get_coefficients(blob_bytes: &[u8]) -> [U256; BLOB_WIDTH] {
+pub(super) fn get_coefficients(blob_bytes: &[u8]) -> [U256; BLOB_WIDTH] {
     let mut coefficients = [[0u8; N_BYTES_U256]; BLOB_WIDTH];
 
     assert!(
@@ -163,68 +160,51 @@ impl<const N_SNARKS: usize> BlobConsistencyConfig<N_SNARKS> {
     }
 }
 
+/// The blob consistency check for EIP-4844 blobs, where the blob is committed to by its versioned
+/// hash.
 #[derive(Debug, Clone, Copy, Default)]
-pub struct BlobConsistencyWitness {
-    blob_versioned_hash: H256,
-    challenge_digest: H256,
-    evaluation: Scalar,
-}
+pub struct Eip4844;
 
-impl BlobConsistencyWitness {
-    pub fn new<const N_SNARKS: usize>(bytes: &[u8], batch_data: &BatchData<N_SNARKS>) -> Self {
-        let coeffs = get_coefficients(bytes);
-        let blob_versioned_hash = get_versioned_hash(&coeffs);
-        let point_evaluation_assignments =
-            PointEvaluationAssignments::new(batch_data, bytes, blob_versioned_hash);
-        Self {
-            blob_versioned_hash,
-            challenge_digest: digest_from_word(point_evaluation_assignments.challenge_digest),
-            evaluation: scalar_from_word(point_evaluation_assignments.evaluation),
-        }
-    }
+impl<const N_SNARKS: usize> BlobConsistency<N_SNARKS> for Eip4844 {
+    type Config = BlobConsistencyConfig<N_SNARKS>;
 
-    pub fn id(&self) -> H256 {
-        self.blob_versioned_hash
+    fn configure(
+        meta: &mut ConstraintSystem<Fr>,
+        challenges: &Challenges<Expression<Fr>>,
+        u8_table: U8Table,
+        range: RangeConfig<Fr>,
+    ) -> Self::Config {
+        BlobConsistencyConfig::construct(meta, challenges, u8_table, range)
     }
 
-    pub fn challenge_digest(&self) -> U256 {
-        word_from_digest(self.challenge_digest)
+    fn assign_barycentric(
+        config: &Self::Config,
+        ctx: &mut Context<Fr>,
+        blob_bytes: &[u8],
+        challenge_digest: U256,
+    ) -> AssignedBarycentricEvaluationConfig {
+        config.assign_barycentric(ctx, blob_bytes, challenge_digest)
     }
 
-    pub fn challenge(&self) -> Scalar {
-        scalar_from_digest(self.challenge_digest)
+    fn assign_blob_data(
+        config: &Self::Config,
+        layouter: &mut impl Layouter<Fr>,
+        challenges: Challenges<Value<Fr>>,
+        rlc_config: &RlcConfig,
+        blob_bytes: &[u8],
+    ) -> Result<AssignedBlobDataExport, Error> {
+        config.assign_blob_data(layouter, challenges, rlc_config, blob_bytes)
     }
 
-    pub fn evaluation(&self) -> Scalar {
-        self.evaluation
+    fn link(
+        layouter: &mut impl Layouter<Fr>,
+        blob_crts_limbs: &[[AssignedCell<Fr, Fr>; LIMBS]],
+        barycentric_crts: &[CRTInteger<Fr>],
+    ) -> Result<(), Error> {
+        BlobConsistencyConfig::<N_SNARKS>::link(layouter, blob_crts_limbs, barycentric_crts)
     }
 
-    pub fn blob_data_proof(&self) -> [H256; 2] {
-        [self.challenge(), self.evaluation].map(digest_from_scalar)
+    fn commitment(blob_bytes: &[u8]) -> H256 {
+        get_versioned_hash(&get_coefficients(blob_bytes))
     }
 }
-
-fn digest_from_word(x: U256) -> H256 {
-    H256::from_slice(&x.to_be_bytes())
-}
-
-fn digest_from_scalar(x: Scalar) -> H256 {
-    let mut bytes = x.to_bytes();
-    bytes.reverse();
-    H256::from_slice(&bytes)
-}
-
-fn scalar_from_word(x: U256) -> Scalar {
-    let (_quotient, remainder) = x.div_mod(*BLS_MODULUS);
-    Scalar::from_bytes(&remainder.to_le_bytes()).expect("non-canonical bytes")
-}
-
-fn scalar_from_digest(x: H256) -> Scalar {
-    scalar_from_word(word_from_digest(x))
-}
-
-fn word_from_digest(x: H256) -> U256 {
-    U256::from_big_endian(&x.to_fixed_bytes())
-}
-
-// word_from_scalar would not be used.
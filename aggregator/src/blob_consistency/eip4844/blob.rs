@@ -9,24 +9,18 @@ pub const BLOB_WIDTH: usize = 4096;
 
 #[derive(Clone, Debug)]
 pub struct PointEvaluationAssignments {
-    /// The random challenge scalar z.
-    pub challenge: U256,
     /// The 32-bytes keccak digest for the challenge. We have the relation:
     /// - challenge := challenge_digest % BLS_MODULUS.
     pub challenge_digest: U256,
     /// The evaluation of the blob polynomial at challenge.
     pub evaluation: U256,
-    /// The blob polynomial represented in evaluation form.
-    pub coefficients: [U256; BLOB_WIDTH],
 }
 
 impl Default for PointEvaluationAssignments {
     fn default() -> Self {
         Self {
-            challenge: U256::default(),
             challenge_digest: U256::default(),
             evaluation: U256::default(),
-            coefficients: [U256::default(); BLOB_WIDTH],
         }
     }
 }
@@ -56,10 +50,8 @@ impl PointEvaluationAssignments {
         );
 
         Self {
-            challenge,
             challenge_digest,
             evaluation,
-            coefficients,
         }
     }
 }
@@ -159,7 +151,7 @@ mod tests {
             println!(
                 "[[ {:60} ]]\nchallenge (z) = {:0>64x}, evaluation (y) = {:0>64x}, versioned hash = {:0>64x}, batch_hash = {:0>64x}\n\n",
                 annotation,
-                point_evaluation_assignments.challenge,
+                point_evaluation_assignments.challenge_digest.div_mod(*BLS_MODULUS).1,
                 point_evaluation_assignments.evaluation,
                 versioned_hash,
                 batch_hash.current_batch_hash,
@@ -179,9 +171,6 @@ mod tests {
         let blob_bytes = get_blob_bytes(&batch_bytes);
         let coeffs = get_coefficients(&blob_bytes);
         let versioned_hash = get_versioned_hash(&coeffs);
-        let point_evaluation_assignments =
-            PointEvaluationAssignments::new(&default_batch, &blob_bytes, versioned_hash);
-        let versioned_hash = get_versioned_hash(&point_evaluation_assignments.coefficients);
         assert_eq!(
             default_batch.get_challenge_digest(versioned_hash),
             U256::from(keccak256(
@@ -1,4 +1,5 @@
 use ark_std::test_rng;
+use eth_types::H256;
 use halo2_base::{
     gates::range::{RangeConfig, RangeStrategy},
     Context, ContextParams,
@@ -9,6 +10,7 @@ use halo2_proofs::{
     halo2curves::bn256::Fr,
     plonk::{Circuit, ConstraintSystem, Error},
 };
+use itertools::Itertools;
 use rand::Rng;
 use std::fs;
 use zkevm_circuits::{
@@ -27,12 +29,12 @@ use crate::{
             blob::PointEvaluationAssignments, get_blob_bytes, get_coefficients, get_versioned_hash,
             AssignedBarycentricEvaluationConfig, BarycentricEvaluationConfig,
         },
-        BlobDataConfig,
+        segment_blob_bytes, BlobDataConfig,
     },
     constants::N_BYTES_U256,
     decode_bytes,
     param::ConfigParams,
-    BatchDataConfig, ChunkInfo, MAX_AGG_SNARKS,
+    BatchDataConfig, ChunkInfo, MAX_AGG_SNARKS, MAX_BLOBS,
 };
 
 #[derive(Default)]
@@ -40,7 +42,7 @@ struct BlobCircuit {
     data: BatchData<MAX_AGG_SNARKS>,
 
     overwrite_num_valid_chunks: bool,
-    overwrite_challenge_digest: Option<usize>,
+    overwrite_challenge_digest: Option<(usize, usize)>,
     overwrite_chunk_data_digests: Option<(usize, usize)>,
     overwrite_chunk_idx: Option<usize>,
     overwrite_accumulator: Option<usize>,
@@ -122,22 +124,29 @@ impl Circuit<Fr> for BlobCircuit {
 
         let batch_bytes = self.data.get_batch_data_bytes();
         let blob_bytes = get_blob_bytes(&batch_bytes);
-        let coeffs = get_coefficients(&blob_bytes);
-        let versioned_hash = get_versioned_hash(&coeffs);
+        let segments = segment_blob_bytes(&blob_bytes);
+        // the blobs that are not needed are never posted, hence their versioned hash is zero.
+        let versioned_hashes = segments.map(|segment| {
+            if segment.is_empty() {
+                H256::zero()
+            } else {
+                get_versioned_hash(&get_coefficients(segment))
+            }
+        });
 
         config.keccak_table.dev_load(
             &mut layouter,
-            &self.data.preimages(versioned_hash),
+            &self.data.preimages(&versioned_hashes),
             &challenge_values,
         )?;
 
         let mut first_pass = halo2_base::SKIP_FIRST_PASS;
-        let barycentric_assignments = layouter.assign_region(
+        let barycentric = layouter.assign_region(
             || "barycentric config",
-            |region| -> Result<AssignedBarycentricEvaluationConfig, Error> {
+            |region| -> Result<Vec<AssignedBarycentricEvaluationConfig>, Error> {
                 if first_pass {
                     first_pass = false;
-                    return Ok(AssignedBarycentricEvaluationConfig::default());
+                    return Ok(vec![]);
                 }
 
                 let gate = &config.barycentric.scalar.range.gate;
@@ -150,13 +159,23 @@ impl Circuit<Fr> for BlobCircuit {
                     },
                 );
 
-                let point_eval =
-                    PointEvaluationAssignments::new(&self.data, &blob_bytes, versioned_hash);
-                Ok(config
-                    .barycentric
-                    .assign(&mut ctx, &blob_bytes, point_eval.challenge_digest))
+                Ok(segments
+                    .iter()
+                    .zip_eq(versioned_hashes.iter())
+                    .map(|(segment, &versioned_hash)| {
+                        let point_eval =
+                            PointEvaluationAssignments::new(&self.data, segment, versioned_hash);
+                        config
+                            .barycentric
+                            .assign(&mut ctx, segment, point_eval.challenge_digest)
+                    })
+                    .collect())
             },
         )?;
+        let barycentric_assignments = barycentric
+            .iter()
+            .map(|barycentric| barycentric.barycentric_assignments.as_slice())
+            .collect::<Vec<_>>();
 
         let chunks_are_padding = layouter.assign_region(
             || "dev: chunks are padding or not",
@@ -192,14 +211,14 @@ impl Circuit<Fr> for BlobCircuit {
                     &mut region,
                     challenge_values,
                     &self.data,
-                    versioned_hash,
+                    &versioned_hashes,
                 )?;
                 let assigned_batch_data_export = config.batch_data_config.assign_internal_checks(
                     &mut region,
                     challenge_values,
                     &config.rlc,
                     &chunks_are_padding,
-                    &barycentric_assignments.barycentric_assignments,
+                    &barycentric_assignments,
                     &assigned_rows,
                 )?;
 
@@ -224,12 +243,12 @@ impl Circuit<Fr> for BlobCircuit {
                 if self.overwrite_num_valid_chunks {
                     increment_cell(&mut region, &assigned_batch_data_export.num_valid_chunks)?;
                 }
-                if let Some(i) = self.overwrite_challenge_digest {
-                    increment_cell(
-                        &mut region,
-                        &assigned_rows[BatchData::<MAX_AGG_SNARKS>::n_rows() - N_BYTES_U256 + i]
-                            .byte,
-                    )?;
+                if let Some((blob, i)) = self.overwrite_challenge_digest {
+                    // the challenge digests of the blobs occupy the last rows
+                    let row = BatchData::<MAX_AGG_SNARKS>::n_rows()
+                        - N_BYTES_U256 * (MAX_BLOBS - blob)
+                        + i;
+                    increment_cell(&mut region, &assigned_rows[row].byte)?;
                 }
                 if let Some((i, j)) = self.overwrite_chunk_data_digests {
                     increment_cell(
@@ -362,6 +381,7 @@ fn zstd_encoding_consistency() {
         witness_rows: _w,
         literal_bytes: _l,
         fse_aux_tables: _f,
+        huffman_codes: _h,
         block_info_arr: _b,
         sequence_info_arr: _s,
         address_table_rows: _a,
@@ -416,6 +436,7 @@ fn zstd_encoding_consistency_from_batch() {
         witness_rows: _w,
         literal_bytes: _l,
         fse_aux_tables: _f,
+        huffman_codes: _h,
         block_info_arr: _b,
         sequence_info_arr: _s,
         address_table_rows: _a,
@@ -447,6 +468,27 @@ fn generic_batch_data_is_valid() {
     assert_eq!(check_data(generic_batch_data()), Ok(()));
 }
 
+#[test]
+fn multi_blob_batch_data_is_valid() {
+    // random bytes are not compressible, so the blob bytes spill over into the second blob.
+    let mut rng = test_rng();
+    let chunks = (0..3)
+        .map(|_| {
+            (0..N_BLOB_BYTES / 2)
+                .map(|_| rng.gen())
+                .collect::<Vec<u8>>()
+        })
+        .collect::<Vec<_>>();
+    let batch_data = BatchData::from(&chunks);
+
+    let blob_bytes = get_blob_bytes(&batch_data.get_batch_data_bytes());
+    let segments = segment_blob_bytes(&blob_bytes);
+    assert_eq!(segments[0].len(), N_BLOB_BYTES);
+    assert!(!segments[1].is_empty());
+
+    assert_eq!(check_data(batch_data), Ok(()));
+}
+
 #[test]
 fn inconsistent_chunk_size() {
     let mut blob_data = generic_batch_data();
@@ -487,10 +529,17 @@ fn overwrite_num_valid_chunks() {
 
 #[test]
 fn overwrite_challenge_digest_byte() {
-    for i in [0, 1, 10, 31] {
+    for indices in [
+        (0, 0),
+        (0, 1),
+        (0, 10),
+        (0, 31),
+        (MAX_BLOBS - 1, 0),
+        (MAX_BLOBS - 1, 31),
+    ] {
         let circuit = BlobCircuit {
             data: generic_batch_data(),
-            overwrite_challenge_digest: Some(i),
+            overwrite_challenge_digest: Some(indices),
             ..Default::default()
         };
         assert!(check_circuit(&circuit).is_err())
@@ -633,6 +682,13 @@ use super::*;
 
 #[test]
 fn test_conversions() {
+    use crate::blob_consistency::{
+        digest_from_scalar, digest_from_word, scalar_from_digest, scalar_from_word,
+        word_from_digest,
+    };
+    use eth_types::{H256, U256};
+    use halo2_proofs::halo2curves::bls12_381::Scalar;
+
     let scalar = Scalar::one();
     let word = U256::one();
     let mut digest = H256::zero();
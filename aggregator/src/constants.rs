@@ -45,12 +45,21 @@ pub(crate) const CHUNK_TX_DATA_HASH_INDEX: usize = 136;
 // - last_block_timestamp      8 bytes
 // - z                         32 bytes
 // - y                         32 bytes
+//
+// followed by, for each extra blob a batch of MULTI_BLOB_BATCH_VERSION uses,
+// - blob_versioned_hash       32 bytes
+// - z                         32 bytes
+// - y                         32 bytes
 
 pub(crate) const BATCH_DATA_HASH_OFFSET: usize = 25;
 pub(crate) const BATCH_BLOB_VERSIONED_HASH_OFFSET: usize = 57;
 pub(crate) const BATCH_PARENT_BATCH_HASH: usize = 89;
 pub(crate) const BATCH_Z_OFFSET: usize = 129;
 pub(crate) const BATCH_Y_OFFSET: usize = 161;
+pub(crate) const BATCH_HEADER_LEN: usize = 193;
+pub(crate) const BATCH_EXTRA_BLOB_LEN: usize = 96;
+pub(crate) const MAX_BATCH_HASH_PREIMAGE_LEN: usize =
+    BATCH_HEADER_LEN + (MAX_BLOBS - 1) * BATCH_EXTRA_BLOB_LEN;
 
 // ================================
 // indices for public inputs
@@ -86,5 +95,20 @@ pub(crate) const BITS: usize = 88;
 /// will be padded.
 pub const MAX_AGG_SNARKS: usize = 45;
 
+/// Max number of snarks a batch circuit of any capacity
+/// may aggregate, i.e. the largest N_SNARKS. The fixed
+/// cells of the RlcConfig cover the indices up to it.
+pub const MAX_BATCH_CAPACITY: usize = 90;
+
+/// Max number of blobs the data of a batch can span.
+/// If the data fits in fewer blobs, dummy blobs
+/// will be padded.
+pub const MAX_BLOBS: usize = 2;
+
+/// The batch version from which on the data of a batch
+/// may span more than one blob. Earlier versions keep
+/// the BatchHeaderV3 layout with a single blob.
+pub const MULTI_BLOB_BATCH_VERSION: u8 = 4;
+
 // Number of bytes in a u256.
 pub const N_BYTES_U256: usize = 32;
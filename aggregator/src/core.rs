@@ -33,13 +33,13 @@ use zkevm_circuits::{
 
 use crate::{
     constants::{
-        BATCH_BLOB_VERSIONED_HASH_OFFSET, BATCH_Y_OFFSET, BATCH_Z_OFFSET, CHAIN_ID_LEN, DIGEST_LEN,
-        LOG_DEGREE,
+        BATCH_BLOB_VERSIONED_HASH_OFFSET, BATCH_EXTRA_BLOB_LEN, BATCH_HEADER_LEN, BATCH_Y_OFFSET,
+        BATCH_Z_OFFSET, CHAIN_ID_LEN, DIGEST_LEN, LOG_DEGREE, MAX_BATCH_HASH_PREIMAGE_LEN,
     },
     util::{assert_conditional_equal, parse_hash_preimage_cells},
     RlcConfig, BATCH_DATA_HASH_OFFSET, BATCH_PARENT_BATCH_HASH, BITS, CHUNK_CHAIN_ID_INDEX,
-    CHUNK_DATA_HASH_INDEX, CHUNK_TX_DATA_HASH_INDEX, LIMBS, POST_STATE_ROOT_INDEX,
-    PREV_STATE_ROOT_INDEX, WITHDRAW_ROOT_INDEX,
+    CHUNK_DATA_HASH_INDEX, CHUNK_TX_DATA_HASH_INDEX, LIMBS, MAX_BLOBS, MULTI_BLOB_BATCH_VERSION,
+    POST_STATE_ROOT_INDEX, PREV_STATE_ROOT_INDEX, WITHDRAW_ROOT_INDEX,
 };
 
 /// Subroutine for the witness generations.
@@ -155,6 +155,7 @@ pub(crate) struct ExtractedHashCells<const N_SNARKS: usize> {
     data_lens: Vec<AssignedCell<Fr, Fr>>,
     num_valid_snarks: AssignedCell<Fr, Fr>,
     chunks_are_padding: Vec<AssignedCell<Fr, Fr>>,
+    blobs_are_valid: Vec<AssignedCell<Fr, Fr>>,
 }
 
 // Computed cells to be constrained against public input. These cells are processed into hi/lo format from ExtractedHashCells.
@@ -175,8 +176,10 @@ impl<const N_SNARKS: usize> ExtractedHashCells<N_SNARKS> {
         num_valid_chunks: usize,
         preimages: &[Vec<u8>],
         chunk_is_valid_cell32s: &[AssignedCell<Fr, Fr>],
+        batch_hash_preimage_flags: &[AssignedCell<Fr, Fr>],
         num_valid_snarks: AssignedCell<Fr, Fr>,
         chunks_are_padding: Vec<AssignedCell<Fr, Fr>>,
+        blobs_are_valid: Vec<AssignedCell<Fr, Fr>>,
     ) -> Result<Self, halo2_proofs::plonk::Error> {
         let mut inputs = vec![];
         let mut input_rlcs = vec![];
@@ -185,25 +188,41 @@ impl<const N_SNARKS: usize> ExtractedHashCells<N_SNARKS> {
         let mut data_lens = vec![];
 
         // preimages are padded as follows
-        // - the first hash is batch_hash
+        // - the first hash is batch_hash, its input is padded to the extra fields of MAX_BLOBS
+        //   blobs
         // - the next hashes are chunk\[i\].piHash, we padded it to N_SNARKS by repeating the last
         //   chunk
         // - the last hash is batch_data_hash, its input is padded to 32*N_SNARKS
         log::trace!("preimage len: {}", preimages.len());
-        for preimage in preimages
+        for (i, preimage) in preimages
             .iter()
             .take(num_valid_chunks + 1)
             .chain(repeat(&preimages[num_valid_chunks]).take(N_SNARKS - num_valid_chunks))
+            .enumerate()
         {
             {
+                let padded_len = if i == 0 {
+                    MAX_BATCH_HASH_PREIMAGE_LEN
+                } else {
+                    preimage.len()
+                };
                 let mut preimage_cells = vec![];
-                for input in preimage.iter() {
-                    let v = Fr::from(*input as u64);
+                for input in preimage.iter().cloned().chain(repeat(0)).take(padded_len) {
+                    let v = Fr::from(input as u64);
                     let cell = plonk_config.load_private(region, &v, offset)?;
                     preimage_cells.push(cell);
                 }
-                let input_rlc =
-                    plonk_config.rlc(region, &preimage_cells, keccak_input_challenge, offset)?;
+                let input_rlc = if i == 0 {
+                    plonk_config.rlc_with_flag(
+                        region,
+                        &preimage_cells,
+                        keccak_input_challenge,
+                        batch_hash_preimage_flags,
+                        offset,
+                    )?
+                } else {
+                    plonk_config.rlc(region, &preimage_cells, keccak_input_challenge, offset)?
+                };
                 inputs.push(preimage_cells);
                 input_rlcs.push(input_rlc);
             }
@@ -289,6 +308,7 @@ impl<const N_SNARKS: usize> ExtractedHashCells<N_SNARKS> {
             data_lens,
             num_valid_snarks,
             chunks_are_padding,
+            blobs_are_valid,
         })
     }
 
@@ -324,9 +344,14 @@ impl<const N_SNARKS: usize> ExtractedHashCells<N_SNARKS> {
 
 #[derive(Default)]
 pub(crate) struct ExpectedBlobCells {
-    pub(crate) z: Vec<AssignedCell<Fr, Fr>>,
-    pub(crate) y: Vec<AssignedCell<Fr, Fr>>,
-    pub(crate) versioned_hash: Vec<AssignedCell<Fr, Fr>>,
+    /// The challenge z of each blob.
+    pub(crate) z: Vec<Vec<AssignedCell<Fr, Fr>>>,
+    /// The evaluation y of each blob.
+    pub(crate) y: Vec<Vec<AssignedCell<Fr, Fr>>>,
+    /// The versioned hash of each blob.
+    pub(crate) versioned_hashes: Vec<Vec<AssignedCell<Fr, Fr>>>,
+    /// Whether each blob carries data of the batch, i.e. whether the batch hash commits to it.
+    pub(crate) blobs_are_valid: Vec<AssignedCell<Fr, Fr>>,
     pub(crate) chunk_tx_data_digests: Vec<Vec<AssignedCell<Fr, Fr>>>,
 }
 
@@ -352,7 +377,7 @@ pub(crate) struct AssignedBatchHash {
 // 6. chunk[i]'s chunk_pi_hash_rlc_cells == chunk[i-1].chunk_pi_hash_rlc_cells when chunk[i] is
 // padded
 // 7. the hash input length are correct
-// - hashes[0] has 200 bytes
+// - hashes[0] has 193 bytes, and 96 more bytes for each extra blob
 // - hashes[1..N_SNARKS+1] has 168 bytes input
 // - batch's data_hash length is 32 * number_of_valid_snarks
 // 8. batch data hash is correct w.r.t. its RLCs
@@ -387,13 +412,37 @@ pub(crate) fn assign_batch_hashes<const N_SNARKS: usize>(
             preimages,
         )?;
 
+    // the first blob's fields are located in the batch header, while the fields of the other
+    // blobs are appended right after it.
     let batch_hash_input = &extracted_hash_cells.inputs[0]; //[0..INPUT_LEN_PER_ROUND * 2];
+    let blob_offsets = (0..MAX_BLOBS)
+        .map(|i| match i {
+            0 => (
+                BATCH_Z_OFFSET,
+                BATCH_Y_OFFSET,
+                BATCH_BLOB_VERSIONED_HASH_OFFSET,
+            ),
+            _ => {
+                let offset = BATCH_HEADER_LEN + (i - 1) * BATCH_EXTRA_BLOB_LEN;
+                (offset + DIGEST_LEN, offset + 2 * DIGEST_LEN, offset)
+            }
+        })
+        .collect::<Vec<_>>();
+    let digest_cells_at = |offset: usize| batch_hash_input[offset..offset + DIGEST_LEN].to_vec();
     let expected_blob_cells = ExpectedBlobCells {
-        z: batch_hash_input[BATCH_Z_OFFSET..BATCH_Z_OFFSET + DIGEST_LEN].to_vec(),
-        y: batch_hash_input[BATCH_Y_OFFSET..BATCH_Y_OFFSET + DIGEST_LEN].to_vec(),
-        versioned_hash: batch_hash_input
-            [BATCH_BLOB_VERSIONED_HASH_OFFSET..BATCH_BLOB_VERSIONED_HASH_OFFSET + DIGEST_LEN]
-            .to_vec(),
+        z: blob_offsets
+            .iter()
+            .map(|&(z_offset, _, _)| digest_cells_at(z_offset))
+            .collect(),
+        y: blob_offsets
+            .iter()
+            .map(|&(_, y_offset, _)| digest_cells_at(y_offset))
+            .collect(),
+        versioned_hashes: blob_offsets
+            .iter()
+            .map(|&(_, _, versioned_hash_offset)| digest_cells_at(versioned_hash_offset))
+            .collect(),
+        blobs_are_valid: extracted_hash_cells.blobs_are_valid.clone(),
         chunk_tx_data_digests: (0..N_SNARKS)
             .map(|i| {
                 extracted_hash_cells.inputs[i + 1]
@@ -429,10 +478,11 @@ pub(crate) fn assign_keccak_table(
     //      l1_message_popped ||
     //      total_l1_message_popped ||
     //      batch_data_hash ||
-    //      versioned_hash ||
+    //      versioned_hash[0] ||
     //      parent_batch_hash ||
     //      last_block_timestamp ||
-    //      z || y)
+    //      z[0] || y[0] ||
+    //      versioned_hash[1] || z[1] || y[1] || ...), for the blobs the batch uses
     // (2) chunk[i].piHash preimage =
     //      (chain id ||
     //      chunk[i].prevStateRoot || chunk[i].postStateRoot ||
@@ -469,7 +519,7 @@ pub(crate) fn assign_keccak_table(
 // 6. chunk[i]'s chunk_pi_hash_rlc_cells == chunk[i-1].chunk_pi_hash_rlc_cells when chunk[i] is
 // padded
 // 7. the hash input length are correct
-// - hashes[0] has 200 bytes
+// - hashes[0] has 193 bytes, and 96 more bytes for each extra blob
 // - hashes[1..N_SNARKS+1] has 168 bytes input
 // - batch's data_hash length is 32 * number_of_valid_snarks
 // 8. batch data hash is correct w.r.t. its RLCs
@@ -530,6 +580,41 @@ pub(crate) fn conditional_constraints<const N_SNARKS: usize>(
 
                 log::trace!("number of valid chunks: {:?}", num_valid_snarks.value());
 
+                // ====================================================
+                // build the flags to indicate the blobs are used or not
+                // ====================================================
+                // the batch hash preimage holds the fields of the first blob, followed by those of
+                // each extra blob the batch uses.
+                let num_blobs = 1 + (preimages[0].len() - BATCH_HEADER_LEN) / BATCH_EXTRA_BLOB_LEN;
+                let blob_is_valid_cells = (0..MAX_BLOBS)
+                    .map(|i| -> Result<_, halo2_proofs::plonk::Error> {
+                        rlc_config.load_private(
+                            &mut region,
+                            &Fr::from((i < num_blobs) as u64),
+                            &mut offset,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, halo2_proofs::plonk::Error>>()?;
+                // the first blob is always used, whether the others are is checked against the
+                // blob data.
+                region.constrain_equal(
+                    blob_is_valid_cells[0].cell(),
+                    rlc_config.one_cell(blob_is_valid_cells[0].cell().region_index),
+                )?;
+                for blob_is_valid in blob_is_valid_cells.iter().skip(1) {
+                    rlc_config.enforce_binary(&mut region, blob_is_valid, &mut offset)?;
+                }
+                let batch_hash_preimage_flags = repeat(&blob_is_valid_cells[0])
+                    .take(BATCH_HEADER_LEN)
+                    .chain(
+                        blob_is_valid_cells
+                            .iter()
+                            .skip(1)
+                            .flat_map(|cell| repeat(cell).take(BATCH_EXTRA_BLOB_LEN)),
+                    )
+                    .cloned()
+                    .collect::<Vec<_>>();
+
                 // ====================================================
                 // extract the hash cells from the witnesses and check against the lookup table
                 // ====================================================
@@ -542,8 +627,10 @@ pub(crate) fn conditional_constraints<const N_SNARKS: usize>(
                     num_valid_chunks,
                     preimages,
                     &chunk_is_valid_cell32s,
+                    &batch_hash_preimage_flags,
                     num_valid_snarks,
                     chunks_are_padding.clone(),
+                    blob_is_valid_cells.clone(),
                 )?;
                 assigned_hash_cells.check_against_lookup_table(
                     rlc_config,
@@ -572,10 +659,11 @@ pub(crate) fn conditional_constraints<const N_SNARKS: usize>(
                 //      l1_message_popped ||
                 //      total_l1_message_popped ||
                 //      batch_data_hash ||
-                //      versioned_hash ||
+                //      versioned_hash[0] ||
                 //      parent_batch_hash ||
                 //      last_block_timestamp ||
-                //      z || y)
+                //      z[0] || y[0] ||
+                //      versioned_hash[1] || z[1] || y[1] || ...), for the blobs the batch uses
                 //
                 // batchDataHash = keccak(chunk[0].dataHash || ... || chunk[k-1].dataHash)
 
@@ -605,7 +693,68 @@ pub(crate) fn conditional_constraints<const N_SNARKS: usize>(
                 )?;
 
                 // ====================================================
-                // 1.a batch_parent_batch_hash is the same from public input
+                // 1.a the batch hash preimage covers the extra blobs the batch uses, and only
+                // batches of MULTI_BLOB_BATCH_VERSION may use them
+                // ====================================================
+                let batch_header_len = rlc_config.load_private(
+                    &mut region,
+                    &Fr::from(BATCH_HEADER_LEN as u64),
+                    &mut offset,
+                )?;
+                region.constrain_equal(
+                    batch_header_len.cell(),
+                    rlc_config.batch_header_len_cell(batch_header_len.cell().region_index),
+                )?;
+                let batch_extra_blob_len = rlc_config.load_private(
+                    &mut region,
+                    &Fr::from(BATCH_EXTRA_BLOB_LEN as u64),
+                    &mut offset,
+                )?;
+                region.constrain_equal(
+                    batch_extra_blob_len.cell(),
+                    rlc_config.batch_extra_blob_len_cell(batch_extra_blob_len.cell().region_index),
+                )?;
+                let batch_extra_blobs_len = rlc_config.inner_product(
+                    &mut region,
+                    &blob_is_valid_cells[1..],
+                    &repeat(batch_extra_blob_len)
+                        .take(MAX_BLOBS - 1)
+                        .collect::<Vec<_>>(),
+                    &mut offset,
+                )?;
+                let batch_hash_preimage_len = rlc_config.add(
+                    &mut region,
+                    &batch_header_len,
+                    &batch_extra_blobs_len,
+                    &mut offset,
+                )?;
+                region.constrain_equal(
+                    batch_hash_preimage_len.cell(),
+                    assigned_hash_cells.data_lens[0].cell(),
+                )?;
+
+                let multi_blob_batch_version = rlc_config.load_private(
+                    &mut region,
+                    &Fr::from(MULTI_BLOB_BATCH_VERSION as u64),
+                    &mut offset,
+                )?;
+                region.constrain_equal(
+                    multi_blob_batch_version.cell(),
+                    rlc_config.fixed_up_to_max_batch_capacity_cell(
+                        multi_blob_batch_version.cell().region_index,
+                        MULTI_BLOB_BATCH_VERSION as usize,
+                    ),
+                )?;
+                rlc_config.conditional_enforce_equal(
+                    &mut region,
+                    &batch_hash_preimage[0],
+                    &multi_blob_batch_version,
+                    &blob_is_valid_cells[1],
+                    &mut offset,
+                )?;
+
+                // ====================================================
+                // 1.b batch_parent_batch_hash is the same from public input
                 // ====================================================
                 let batch_parent_batch_hash_hi = rlc_config.rlc(
                     &mut region,
@@ -625,7 +774,7 @@ pub(crate) fn conditional_constraints<const N_SNARKS: usize>(
                 )?;
 
                 // ====================================================
-                // 1.b result batch_hash is the same from public input
+                // 1.c result batch_hash is the same from public input
                 // ====================================================
                 let batch_hash_results = assigned_hash_cells.outputs[0].clone();
                 let batch_hash_hi = rlc_config.rlc(
@@ -27,10 +27,13 @@ mod tests;
 pub use self::core::extract_proof_and_instances_with_pairing_check;
 pub use aggregation::*;
 pub use batch::{BatchHash, BatchHeader};
-pub use blob_consistency::get_blob_bytes;
+pub use blob_consistency::{
+    get_blob_bytes, segment_blob_bytes, Avail, BlobConsistency, BlobConsistencyWitness,
+    DefaultBlobConsistency, Eip4844,
+};
 pub use chunk::ChunkInfo;
 pub use compression::*;
-pub use constants::MAX_AGG_SNARKS;
 pub(crate) use constants::*;
+pub use constants::{MAX_AGG_SNARKS, MAX_BATCH_CAPACITY, MAX_BLOBS, MULTI_BLOB_BATCH_VERSION};
 pub use param::*;
 pub use recursion::*;
@@ -16,7 +16,7 @@ pub struct ConfigParams {
 }
 
 impl ConfigParams {
-    /// Same with scroll-prover/integration/configs/layer3.config
+    /// Same with configs/layer3.config
     pub(crate) fn aggregation_param() -> Self {
         Self {
             strategy: FpStrategy::Simple,
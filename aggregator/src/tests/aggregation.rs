@@ -1,24 +1,45 @@
-use std::{fs, path::Path, process};
+use std::{
+    env, fs,
+    path::Path,
+    process,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
 
 use ark_std::{end_timer, start_timer, test_rng};
-use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr, poly::commitment::Params};
+use halo2_proofs::{
+    dev::MockProver, halo2curves::bn256::Fr, plonk::ConstraintSystem, poly::commitment::Params,
+};
 use itertools::Itertools;
+use rand::Rng;
 use snark_verifier::loader::halo2::halo2_ecc::halo2_base::utils::fs::gen_srs;
 use snark_verifier_sdk::{gen_pk, gen_snark_shplonk, verify_snark_shplonk, CircuitExt};
+use zkevm_circuits::util::Challenges;
 
 use crate::{
     aggregation::encode_bytes,
-    aggregation::BatchCircuit,
+    aggregation::{batch_data::N_BLOB_BYTES, BatchCircuit, BatchCircuitConfig},
     batch::{BatchHash, BatchHeader},
-    constants::MAX_AGG_SNARKS,
+    constants::{LOG_DEGREE, MAX_AGG_SNARKS, MAX_BATCH_CAPACITY, MULTI_BLOB_BATCH_VERSION},
     layer_0,
     tests::mock_chunk::MockChunkCircuit,
-    BatchData, ChunkInfo,
+    BatchData, ChunkInfo, ConfigParams, DefaultBlobConsistency,
 };
 
+/// The batch circuit reads its config from the AGGREGATION_CONFIG env var, hence the tests that
+/// set it must not run along with the ones that rely on the default config.
+static AGGREGATION_CONFIG: Mutex<()> = Mutex::new(());
+
+/// Serialises the tests that configure a batch circuit, see [`AGGREGATION_CONFIG`].
+fn lock_aggregation_config() -> MutexGuard<'static, ()> {
+    AGGREGATION_CONFIG
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
 #[test]
 #[ignore = "dbg: insufficient number of advice columns"]
 fn batch_circuit_raw() {
+    let _guard = lock_aggregation_config();
     let k = 21;
     let circuit: BatchCircuit<MAX_AGG_SNARKS> = build_batch_circuit_skip_encoding();
     let instance = circuit.instances();
@@ -29,6 +50,7 @@ fn batch_circuit_raw() {
 #[test]
 #[ignore = "dbg: insufficient number of advice columns"]
 fn batch_circuit_encode() {
+    let _guard = lock_aggregation_config();
     let k = 21;
     let circuit: BatchCircuit<MAX_AGG_SNARKS> = build_new_batch_circuit(2, k);
     let instance = circuit.instances();
@@ -39,6 +61,7 @@ fn batch_circuit_encode() {
 #[ignore]
 #[test]
 fn test_2_snark_batch_circuit() {
+    let _guard = lock_aggregation_config();
     let k = 21;
 
     let circuit: BatchCircuit<2> = build_new_batch_circuit(1, k);
@@ -50,6 +73,7 @@ fn test_2_snark_batch_circuit() {
 #[ignore]
 #[test]
 fn test_14_snark_batch_circuit() {
+    let _guard = lock_aggregation_config();
     let k = 21;
 
     let circuit: BatchCircuit<14> = build_new_batch_circuit(12, k);
@@ -58,9 +82,60 @@ fn test_14_snark_batch_circuit() {
     mock_prover.assert_satisfied_par();
 }
 
+#[test]
+fn test_multi_blob_batch_circuit() {
+    // the blob data and barycentric evaluation of every blob must fit in the batch circuit.
+    let _guard = lock_aggregation_config();
+    let circuit: BatchCircuit<2> = build_multi_blob_batch_circuit();
+    let instance = circuit.instances();
+    let mock_prover = MockProver::<Fr>::run(LOG_DEGREE, &circuit, instance).unwrap();
+    mock_prover.assert_satisfied_par();
+}
+
+#[test]
+fn test_batch_circuit_capacity_configs() {
+    fn configure<const N_SNARKS: usize>(path: &str) {
+        let params: ConfigParams = serde_json::from_reader(fs::File::open(path).unwrap()).unwrap();
+        assert_eq!(params.degree, LOG_DEGREE);
+
+        let mut meta = ConstraintSystem::<Fr>::default();
+        let challenges = Challenges::construct_p1(&mut meta);
+        BatchCircuitConfig::<N_SNARKS, DefaultBlobConsistency>::configure(
+            &mut meta, &params, challenges,
+        );
+    }
+
+    configure::<15>("./configs/layer3_15.config");
+    configure::<MAX_AGG_SNARKS>("./configs/layer3.config");
+    configure::<MAX_BATCH_CAPACITY>("./configs/layer3_90.config");
+}
+
+#[test]
+fn test_15_snark_batch_circuit_capacity() {
+    let _guard = lock_aggregation_config();
+    env::set_var("AGGREGATION_CONFIG", "./configs/layer3_15.config");
+    let circuit: BatchCircuit<15> = build_new_batch_circuit(15, LOG_DEGREE);
+    let instance = circuit.instances();
+    let mock_prover = MockProver::<Fr>::run(LOG_DEGREE, &circuit, instance).unwrap();
+    mock_prover.assert_satisfied_par();
+    env::remove_var("AGGREGATION_CONFIG");
+}
+
+#[test]
+fn test_90_snark_batch_circuit_capacity() {
+    let _guard = lock_aggregation_config();
+    env::set_var("AGGREGATION_CONFIG", "./configs/layer3_90.config");
+    let circuit: BatchCircuit<MAX_BATCH_CAPACITY> = build_new_batch_circuit(2, LOG_DEGREE);
+    let instance = circuit.instances();
+    let mock_prover = MockProver::<Fr>::run(LOG_DEGREE, &circuit, instance).unwrap();
+    mock_prover.assert_satisfied_par();
+    env::remove_var("AGGREGATION_CONFIG");
+}
+
 #[ignore = "it takes too much time"]
 #[test]
 fn test_batch_circuit_all_possible_num_snarks() {
+    let _guard = lock_aggregation_config();
     //env_logger::init();
 
     let k = 20;
@@ -80,6 +155,7 @@ fn test_batch_circuit_all_possible_num_snarks() {
 #[ignore = "it takes too much time"]
 #[test]
 fn test_batch_circuit_full() {
+    let _guard = lock_aggregation_config();
     //env_logger::init();
     let process_id = process::id();
     let k = 25;
@@ -130,6 +206,7 @@ fn test_batch_circuit_full() {
 #[test]
 #[ignore = "it takes too much time"]
 fn test_batch_circuit_variadic() {
+    let _guard = lock_aggregation_config();
     let k = 20;
 
     let circuit1: BatchCircuit<MAX_AGG_SNARKS> = build_new_batch_circuit(5, k);
@@ -234,6 +311,89 @@ fn build_new_batch_circuit<const N_SNARKS: usize>(
     .unwrap()
 }
 
+/// Build a batch circuit whose blob bytes span two blobs.
+fn build_multi_blob_batch_circuit<const N_SNARKS: usize>() -> BatchCircuit<N_SNARKS> {
+    let k0 = 8;
+    let num_real_chunks = 2;
+
+    let mut rng = test_rng();
+    let params = gen_srs(k0);
+
+    // random bytes are not compressible, so the blob bytes spill over into the second blob.
+    let mut chunks_without_padding = (0..num_real_chunks)
+        .map(|_| {
+            let mut chunk = ChunkInfo::mock_random_chunk_info_for_testing(&mut rng);
+            chunk.tx_bytes = (0..N_BLOB_BYTES * 3 / 4).map(|_| rng.gen()).collect();
+            chunk
+        })
+        .collect_vec();
+    for i in 0..num_real_chunks - 1 {
+        chunks_without_padding[i + 1].prev_state_root = chunks_without_padding[i].post_state_root;
+    }
+    let padded_chunk =
+        ChunkInfo::mock_padded_chunk_info_for_testing(&chunks_without_padding[num_real_chunks - 1]);
+    let chunks_with_padding = [
+        chunks_without_padding,
+        vec![padded_chunk; N_SNARKS - num_real_chunks],
+    ]
+    .concat();
+    let batch_data = BatchData::<N_SNARKS>::new(num_real_chunks, &chunks_with_padding);
+    let batch_bytes = batch_data.get_batch_data_bytes();
+    let blob_bytes = encode_bytes(&batch_bytes);
+    assert!(blob_bytes.len() > N_BLOB_BYTES);
+
+    let batch_header = BatchHeader::construct_from_chunks(
+        MULTI_BLOB_BATCH_VERSION,
+        1,
+        0,
+        0,
+        Default::default(),
+        0,
+        &chunks_with_padding,
+        &blob_bytes,
+    );
+    assert_eq!(batch_header.num_blobs(), 2);
+
+    // ==========================
+    // real chunks
+    // ==========================
+    let real_snarks = {
+        let circuits = chunks_with_padding
+            .iter()
+            .take(num_real_chunks)
+            .map(|chunk| MockChunkCircuit::new(true, chunk.clone()))
+            .collect_vec();
+        circuits
+            .iter()
+            .map(|circuit| {
+                let circuit = circuit.clone();
+                layer_0!(circuit, MockChunkCircuit, params, k0, path)
+            })
+            .collect_vec()
+    };
+    let snark_protocol = real_snarks[0].protocol.clone();
+
+    // ==========================
+    // padded chunks
+    // ==========================
+    let padded_snarks = { vec![real_snarks.last().unwrap().clone(); N_SNARKS - num_real_chunks] };
+
+    // ==========================
+    // batch
+    // ==========================
+    let batch_hash = BatchHash::construct(&chunks_with_padding, batch_header, &blob_bytes);
+
+    BatchCircuit::new(
+        &params,
+        [real_snarks, padded_snarks].concat().as_ref(),
+        rng,
+        batch_hash,
+        &snark_protocol,
+        &snark_protocol,
+    )
+    .unwrap()
+}
+
 /// Build a batch circuit where blob == batch, i.e. no encoding.
 fn build_batch_circuit_skip_encoding<const N_SNARKS: usize>() -> BatchCircuit<N_SNARKS> {
     let k0 = 8;
@@ -35,9 +35,10 @@ use eth_types::{
 };
 use ethers_core::utils::keccak256;
 pub use execution::{
-    BigModExp, CopyAccessList, CopyBytes, CopyDataType, CopyEvent, CopyEventStepsBuilder, CopyStep,
-    EcAddOp, EcMulOp, EcPairingOp, EcPairingPair, ExecState, ExecStep, ExpEvent, ExpStep,
-    NumberOrHash, PrecompileEvent, PrecompileEvents, N_BYTES_PER_PAIR, N_PAIRING_PER_OP, SHA256,
+    BigModExp, Blake2F, CopyAccessList, CopyBytes, CopyDataType, CopyEvent, CopyEventStepsBuilder,
+    CopyStep, EcAddOp, EcMulOp, EcPairingOp, EcPairingPair, ExecState, ExecStep, ExpEvent, ExpStep,
+    NumberOrHash, PrecompileEvent, PrecompileEvents, Ripemd160, N_BYTES_PER_PAIR, N_PAIRING_PER_OP,
+    SHA256,
 };
 pub use input_state_ref::CircuitInputStateRef;
 use itertools::Itertools;
@@ -57,6 +58,17 @@ pub struct PrecompileEcParams {
     pub ec_mul: usize,
     /// Maximum number of EcPairing ops supported in one block.
     pub ec_pairing: usize,
+    /// Maximum number of EcPairing ops supported in one block whose pairing checks share one
+    /// final exponentiation, in addition to `ec_pairing`. Only the ops succeeding for valid
+    /// inputs can be batched. At most [`Self::MAX_EC_PAIRING_BATCHED`].
+    pub ec_pairing_batched: usize,
+}
+
+impl PrecompileEcParams {
+    /// Upper bound of `ec_pairing_batched`. The ECC circuit's columns for the batched pairing
+    /// checks are sized for `ec_pairing_batched` when it is configured with these params, and for
+    /// this bound otherwise.
+    pub const MAX_EC_PAIRING_BATCHED: usize = 6;
 }
 
 impl Default for PrecompileEcParams {
@@ -65,6 +77,7 @@ impl Default for PrecompileEcParams {
             ec_add: 50,
             ec_mul: 50,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         }
     }
 }
@@ -579,7 +592,13 @@ impl<'a> CircuitInputBuilder {
         {
             let steps_gas_cost: u64 = begin_tx_steps.iter().map(|st| st.gas_cost.0).sum();
             let real_gas_cost = if geth_trace.struct_logs.is_empty() {
-                GasCost(geth_trace.gas.0)
+                if tx.is_create() && geth_trace.failed {
+                    // A deployment collision consumes all gas, of which only the intrinsic
+                    // gas is charged by BeginTx.
+                    GasCost(steps_gas_cost)
+                } else {
+                    GasCost(geth_trace.gas.0)
+                }
             } else {
                 GasCost(tx.gas - geth_trace.struct_logs[0].gas.0)
             };
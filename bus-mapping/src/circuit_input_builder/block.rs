@@ -9,7 +9,7 @@ use crate::{
     operation::{OperationContainer, RWCounter},
     Error,
 };
-use eth_types::{Address, Word, H256};
+use eth_types::{evm_types::block_utils::calc_blob_base_fee, Address, Word, H256, U64};
 use std::collections::{BTreeMap, HashMap};
 
 /// Context of a [`Block`] which can mutate in a [`Transaction`].
@@ -88,6 +88,8 @@ pub struct Block {
     pub difficulty: Word,
     /// base fee
     pub base_fee: Word,
+    /// blob base fee (EIP-4844)
+    pub blob_base_fee: Word,
     /// start l1 queue index
     pub start_l1_queue_index: u64,
     /// Parent block hash
@@ -143,6 +145,14 @@ impl Block {
                 eth_block.difficulty
             },
             base_fee: eth_block.base_fee_per_gas.unwrap_or_default(),
+            blob_base_fee: calc_blob_base_fee(
+                eth_block
+                    .other
+                    .get_deserialized::<U64>("excessBlobGas")
+                    .and_then(Result::ok)
+                    .unwrap_or_default()
+                    .as_u64(),
+            ),
             parent_hash: eth_block.parent_hash,
             state_root: eth_block.state_root,
         })
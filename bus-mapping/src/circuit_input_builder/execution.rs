@@ -935,6 +935,36 @@ impl PrecompileEvents {
             .collect()
     }
 
+    /// Get all RIPEMD-160 events.
+    pub fn get_ripemd160_events(&self) -> Vec<Ripemd160> {
+        self.events
+            .iter()
+            .filter_map(|e| {
+                if let PrecompileEvent::Ripemd160(op) = e {
+                    Some(op)
+                } else {
+                    None
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Get all BLAKE2F events.
+    pub fn get_blake2f_events(&self) -> Vec<Blake2F> {
+        self.events
+            .iter()
+            .filter_map(|e| {
+                if let PrecompileEvent::Blake2F(op) = e {
+                    Some(op)
+                } else {
+                    None
+                }
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Get all p256 verify events.
     pub fn get_p256_verify_events(&self) -> Vec<SignData<Fq_R1, Secp256r1Affine>> {
         self.events
@@ -966,8 +996,12 @@ pub enum PrecompileEvent {
     ModExp(BigModExp),
     /// Represents the I/O from SHA256 call.
     SHA256(SHA256),
+    /// Represents the I/O from RIPEMD-160 call.
+    Ripemd160(Ripemd160),
     /// Represents the I/O from P256Verify call.
     P256Verify(SignData<Fq_R1, Secp256r1Affine>),
+    /// Represents the I/O from BLAKE2F call.
+    Blake2F(Blake2F),
 }
 
 impl Default for PrecompileEvent {
@@ -1371,6 +1405,12 @@ impl EcPairingOp {
         self.pairs.iter().all(|pair| pair.is_valid())
     }
 
+    /// Whether the pairing check passes if and only if the inputs are valid, in which case the
+    /// op can be checked in a batch of pairing checks.
+    pub fn is_batchable(&self) -> bool {
+        self.is_valid() == (self.output == Word::one())
+    }
+
     /// Dummy pairing op that satisfies the pairing check.
     pub fn dummy_pairing_check_ok() -> Self {
         let g1 = G1Affine::from(G1Affine::generator() * Fr::from(2));
@@ -1392,26 +1432,38 @@ impl EcPairingOp {
 }
 
 /// Event representing an exponentiation `a ^ b == d (mod m)` in precompile modexp.
-#[derive(Clone, Debug)]
+///
+/// Every call to the precompile results in an event, including the ones failing due to invalid
+/// input lengths or insufficient gas, as the input of the call is still parsed by the circuit.
+#[derive(Clone, Debug, Default)]
 pub struct BigModExp {
-    /// Base `a` for the exponentiation.
-    pub base: Word,
-    /// Exponent `b` for the exponentiation.
-    pub exponent: Word,
-    /// Modulus `m`
-    pub modulus: Word,
-    /// Mod exponentiation result.
-    pub result: Word,
+    /// The call input following the 96-byte lengths header, right-padded with zeros or truncated
+    /// to `MODEXP_INPUT_LIMIT - 96` bytes.
+    pub input: Vec<u8>,
+    /// Byte lengths of the base `a`, exponent `b` and modulus `m`, laid out in this order at the
+    /// start of `input`. All zero if one of the lengths exceeds `MODEXP_SIZE_LIMIT`.
+    pub lens: [usize; 3],
+    /// Whether the call succeeded, in which case the result is computed.
+    pub is_success: bool,
+    /// Mod exponentiation result `d` in big-endian, as long as the modulus if the call succeeded.
+    pub result: Vec<u8>,
 }
 
-impl Default for BigModExp {
-    fn default() -> Self {
-        Self {
-            modulus: 1.into(),
-            base: Default::default(),
-            exponent: Default::default(),
-            result: Default::default(),
-        }
+impl BigModExp {
+    /// Big-endian bytes of the base `a`.
+    pub fn base(&self) -> &[u8] {
+        &self.input[..self.lens[0]]
+    }
+
+    /// Big-endian bytes of the exponent `b`.
+    pub fn exponent(&self) -> &[u8] {
+        &self.input[self.lens[0]..self.lens[0] + self.lens[1]]
+    }
+
+    /// Big-endian bytes of the modulus `m`.
+    pub fn modulus(&self) -> &[u8] {
+        let offset = self.lens[0] + self.lens[1];
+        &self.input[offset..offset + self.lens[2]]
     }
 }
 
@@ -1423,3 +1475,65 @@ pub struct SHA256 {
     /// digest
     pub digest: [u8; 32],
 }
+
+/// Event representing a RIPEMD-160 hash in precompile ripemd160.
+#[derive(Clone, Debug, Default)]
+pub struct Ripemd160 {
+    /// input bytes
+    pub input: Vec<u8>,
+    /// digest, without the 12 leading zero bytes of the call output
+    pub digest: [u8; 20],
+}
+
+/// Event representing a BLAKE2b compression (EIP-152) in precompile blake2f.
+#[derive(Clone, Debug, Default)]
+pub struct Blake2F {
+    /// number of rounds
+    pub rounds: u32,
+    /// state vector
+    pub h: [u64; 8],
+    /// message block vector
+    pub m: [u64; 16],
+    /// offset counters
+    pub t: [u64; 2],
+    /// final block indicator flag
+    pub f: bool,
+    /// state vector after compression
+    pub output: [u64; 8],
+}
+
+impl Blake2F {
+    /// Parse the event from the 213-byte input and the 64-byte output of a
+    /// successful blake2f call.
+    pub fn new_from_bytes(input: &[u8], output: &[u8]) -> Self {
+        assert_eq!(input.len(), 213, "blake2f input must be 213 bytes");
+        assert_eq!(output.len(), 64, "blake2f output must be 64 bytes");
+        let le_u64 = |bytes: &[u8]| u64::from_le_bytes(bytes.try_into().unwrap());
+        let words = |bytes: &[u8]| bytes.chunks_exact(8).map(le_u64).collect::<Vec<_>>();
+
+        Self {
+            rounds: u32::from_be_bytes(input[0..4].try_into().unwrap()),
+            h: words(&input[4..68]).try_into().unwrap(),
+            m: words(&input[68..196]).try_into().unwrap(),
+            t: words(&input[196..212]).try_into().unwrap(),
+            f: input[212] == 1,
+            output: words(output).try_into().unwrap(),
+        }
+    }
+
+    /// The 213-byte input of the blake2f call.
+    pub fn input_bytes(&self) -> Vec<u8> {
+        std::iter::empty()
+            .chain(self.rounds.to_be_bytes())
+            .chain(self.h.iter().flat_map(|w| w.to_le_bytes()))
+            .chain(self.m.iter().flat_map(|w| w.to_le_bytes()))
+            .chain(self.t.iter().flat_map(|w| w.to_le_bytes()))
+            .chain([self.f as u8])
+            .collect()
+    }
+
+    /// The 64-byte output of the blake2f call.
+    pub fn output_bytes(&self) -> Vec<u8> {
+        self.output.iter().flat_map(|w| w.to_le_bytes()).collect()
+    }
+}
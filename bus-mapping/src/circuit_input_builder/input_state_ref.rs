@@ -2,8 +2,8 @@
 
 use super::{
     get_call_memory_offset_length, get_create_init_code, BlockContext, Blocks, Call, CallContext,
-    CallKind, CodeSource, CopyEvent, ExecState, ExecStep, ExpEvent, PrecompileEvent, Transaction,
-    TransactionContext,
+    CallKind, CodeSource, CopyBytes, CopyDataType, CopyEvent, ExecState, ExecStep, ExpEvent,
+    NumberOrHash, PrecompileEvent, Transaction, TransactionContext,
 };
 #[cfg(feature = "scroll")]
 use crate::util::KECCAK_CODE_HASH_EMPTY;
@@ -16,10 +16,10 @@ use crate::{
     exec_trace::OperationRef,
     operation::{
         AccountField, AccountOp, CallContextField, CallContextOp, MemoryOp, Op, OpEnum, Operation,
-        StackOp, Target, TxAccessListAccountOp, TxAccessListAccountStorageOp, TxLogField, TxLogOp,
-        TxReceiptField, TxReceiptOp, RW,
+        StackOp, Target, TxAccessListAccountOp, TxAccessListAccountStorageOp, TxCreatedAccountOp,
+        TxLogField, TxLogOp, TxReceiptField, TxReceiptOp, RW,
     },
-    precompile::PrecompileCalls,
+    precompile::{self, PrecompileCalls},
     Error,
 };
 use eth_types::{
@@ -122,9 +122,12 @@ impl<'a> CircuitInputStateRef<'a> {
             .steps()
             .last()
             .expect("steps should have at least one BeginTx step");
+        // A creation tx colliding with an existing account fails in BeginTx directly.
+        let is_deployment_collision =
+            prev_step.exec_state == ExecState::BeginTx && !self.tx.calls()[0].is_success;
         ExecStep {
             exec_state: ExecState::EndTx,
-            gas_left: if prev_step.error.is_none() {
+            gas_left: if prev_step.error.is_none() && !is_deployment_collision {
                 let mut gas_left = prev_step.gas_left.0 - prev_step.gas_cost.0;
                 // handling for contract creation tx
                 let call = self.tx.calls()[0].clone();
@@ -146,7 +149,8 @@ impl<'a> CircuitInputStateRef<'a> {
 
                 Gas(gas_left)
             } else {
-                // consume all remaining gas when non revert err happens
+                // consume all remaining gas when non revert err happens, or when the
+                // deployment collides
                 Gas(0)
             },
             rwc: self.block_ctx.rwc,
@@ -652,6 +656,45 @@ impl<'a> CircuitInputStateRef<'a> {
         )
     }
 
+    /// Mark `address` as created in the current transaction (EIP-6780).
+    pub fn tx_created_account_write(
+        &mut self,
+        step: &mut ExecStep,
+        address: Address,
+    ) -> Result<(), Error> {
+        let is_created = self.sdb.check_account_in_created_list(&address);
+        self.push_op_reversible(
+            step,
+            TxCreatedAccountOp {
+                tx_id: self.tx_ctx.id(),
+                address,
+                is_created: true,
+                is_created_prev: is_created,
+            },
+        )
+    }
+
+    /// Read whether `address` has been created in the current transaction
+    /// (EIP-6780), pushing a read type [`TxCreatedAccountOp`].
+    pub fn tx_created_account_read(
+        &mut self,
+        step: &mut ExecStep,
+        address: Address,
+    ) -> Result<bool, Error> {
+        let is_created = self.sdb.check_account_in_created_list(&address);
+        self.push_op(
+            step,
+            RW::READ,
+            TxCreatedAccountOp {
+                tx_id: self.tx_ctx.id(),
+                address,
+                is_created,
+                is_created_prev: is_created,
+            },
+        )?;
+        Ok(is_created)
+    }
+
     /// Push a write type [`TxAccessListAccountOp`] into the
     /// [`OperationContainer`](crate::operation::OperationContainer) with the
     /// next [`RWCounter`](crate::operation::RWCounter), and then
@@ -1051,7 +1094,17 @@ impl<'a> CircuitInputStateRef<'a> {
 
     /// Check if address is a precompiled or not.
     pub fn is_precompiled(&self, address: &Address) -> bool {
-        address.0[0..19] == [0u8; 19] && (1..=9).contains(&address.0[19])
+        precompile::is_precompiled(address)
+    }
+
+    /// Return the hash of the code executed when calling an account with code `code_hash`. If the
+    /// code is an EIP-7702 delegation designator, the code of the delegate is executed.
+    pub fn executed_code_hash(&self, code_hash: H256) -> H256 {
+        self.code_db
+            .delegated_address(&code_hash)
+            .map_or(code_hash, |delegate| {
+                self.sdb.get_account(&delegate).1.code_hash
+            })
     }
 
     /// Parse [`Call`] from a *CALL*/CREATE* step without information about success and persistent.
@@ -1098,7 +1151,10 @@ impl<'a> CircuitInputStateRef<'a> {
                     if !found {
                         (CodeSource::Address(code_address), CodeDB::empty_code_hash())
                     } else {
-                        (CodeSource::Address(code_address), account.code_hash)
+                        (
+                            CodeSource::Address(code_address),
+                            self.executed_code_hash(account.code_hash),
+                        )
                     }
                 }
             }
@@ -1220,6 +1276,14 @@ impl<'a> CircuitInputStateRef<'a> {
                     None
                 }
             }
+            OperationRef(Target::TxCreatedAccount, idx) => {
+                let operation = &self.block.container.tx_created_account[*idx];
+                if operation.rw().is_write() && operation.reversible() {
+                    Some(OpEnum::TxCreatedAccount(operation.op().reverse()))
+                } else {
+                    None
+                }
+            }
             _ => None,
         }
     }
@@ -1256,6 +1320,14 @@ impl<'a> CircuitInputStateRef<'a> {
             OpEnum::TxRefund(op) => {
                 self.sdb.set_refund(op.value);
             }
+            OpEnum::TxCreatedAccount(op) => {
+                if !op.is_created_prev && op.is_created {
+                    self.sdb.add_account_to_created_list(op.address);
+                }
+                if op.is_created_prev && !op.is_created {
+                    self.sdb.remove_account_from_created_list(&op.address);
+                }
+            }
             _ => unreachable!(),
         };
     }
@@ -1638,6 +1710,38 @@ impl<'a> CircuitInputStateRef<'a> {
         result
     }
 
+    /// Push a copy event of the EIP-7702 delegation designator with hash `code_hash` from the
+    /// bytecode into an RLC, through which the circuit reads the delegate address.
+    pub fn push_copy_delegation_designator(
+        &mut self,
+        step: &mut ExecStep,
+        code_hash: H256,
+    ) -> Result<(), Error> {
+        let bytecode: Bytecode = self.code(code_hash)?.into();
+        let copy_steps = bytecode
+            .code
+            .iter()
+            .map(|element| (element.value, element.is_code, false))
+            .collect::<Vec<_>>();
+        let rw_counter_start = self.block_ctx.rwc;
+        self.push_copy(
+            step,
+            CopyEvent {
+                src_id: NumberOrHash::Hash(code_hash),
+                src_type: CopyDataType::Bytecode,
+                src_addr: 0,
+                src_addr_end: copy_steps.len() as u64,
+                dst_id: NumberOrHash::Hash(code_hash),
+                dst_type: CopyDataType::RlcAcc,
+                dst_addr: 0,
+                log_id: None,
+                rw_counter_start,
+                copy_bytes: CopyBytes::new(copy_steps, None, None),
+                access_list: vec![],
+            },
+        )
+    }
+
     /// Push a exponentiation event to the state.
     pub fn push_exponentiation(&mut self, event: ExpEvent) {
         self.block.add_exp_event(event)
@@ -1872,9 +1976,13 @@ impl<'a> CircuitInputStateRef<'a> {
                 //   on top of the stack (step.stack.last())
                 // Therefore we postpone the oog handling to the implementor of callop.
                 if is_precompiled(&code_address) {
-                    let precompile_call: PrecompileCalls = code_address[19].into();
+                    let precompile_call: PrecompileCalls = code_address.into();
                     match precompile_call {
-                        PrecompileCalls::Ripemd160 | PrecompileCalls::Blake2F => {
+                        // ripemd160 and blake2f are disabled in scroll, otherwise their failures
+                        // are handled by the callop and their own gadgets.
+                        PrecompileCalls::Ripemd160 | PrecompileCalls::Blake2F
+                            if cfg!(feature = "scroll") =>
+                        {
                             // Log the precompile address and gas left. Since this failure is mainly
                             // caused by out of gas.
                             log::trace!(
@@ -1,7 +1,8 @@
 use super::*;
 use crate::{
     error::{
-        ContractAddressCollisionError, DepthError, ExecError, InsufficientBalanceError, OogError,
+        get_step_reported_error, ContractAddressCollisionError, DepthError, ExecError,
+        InsufficientBalanceError, OogError,
     },
     operation::RWCounter,
 };
@@ -1463,6 +1464,96 @@ fn tracer_err_return_data_out_of_bounds() {
 //
 // These errors can be found in the trace step error field.
 
+#[test]
+fn tracer_err_reported_mapping() {
+    let oog_cases = [
+        (OpcodeId::MSTORE, OogError::StaticMemoryExpansion),
+        (OpcodeId::RETURN, OogError::DynamicMemoryExpansion),
+        (OpcodeId::MCOPY, OogError::MemoryCopy),
+        (OpcodeId::EXTCODEHASH, OogError::AccountAccess),
+        (OpcodeId::LOG2, OogError::Log),
+        (OpcodeId::EXP, OogError::Exp),
+        (OpcodeId::SHA3, OogError::Sha3),
+        (OpcodeId::STATICCALL, OogError::Call),
+        (OpcodeId::SSTORE, OogError::SloadSstore),
+        (OpcodeId::CREATE2, OogError::Create),
+        (OpcodeId::SELFDESTRUCT, OogError::SelfDestruct),
+        (OpcodeId::ADD, OogError::Constant),
+    ];
+    for (op, oog_error) in oog_cases {
+        for error in [GethExecError::OutOfGas, GethExecError::GasUintOverflow] {
+            assert_eq!(
+                get_step_reported_error(&op, error).unwrap(),
+                ExecError::OutOfGas(oog_error.clone()),
+                "{op:?} {error}"
+            );
+        }
+    }
+
+    for op in [OpcodeId::CREATE, OpcodeId::CREATE2] {
+        assert_eq!(
+            get_step_reported_error(&op, GethExecError::MaxInitCodeSizeExceeded).unwrap(),
+            ExecError::OutOfGas(OogError::Create)
+        );
+    }
+    assert_eq!(
+        get_step_reported_error(
+            &OpcodeId::PUSH1,
+            GethExecError::StackOverflow {
+                stack_len: 1024,
+                limit: 1024
+            }
+        )
+        .unwrap(),
+        ExecError::StackOverflow
+    );
+    assert_eq!(
+        get_step_reported_error(
+            &OpcodeId::ADD,
+            GethExecError::StackUnderflow {
+                stack_len: 1,
+                required: 2
+            }
+        )
+        .unwrap(),
+        ExecError::StackUnderflow
+    );
+    assert_eq!(
+        get_step_reported_error(&OpcodeId::SSTORE, GethExecError::WriteProtection).unwrap(),
+        ExecError::WriteProtection
+    );
+}
+
+#[test]
+fn tracer_err_reported_unexpected() {
+    // These errors are not reported in the step error field, but derived from the trace.
+    let cases = [
+        (OpcodeId::MSTORE, GethExecError::MaxInitCodeSizeExceeded),
+        (OpcodeId::CALL, GethExecError::Depth),
+        (OpcodeId::CALL, GethExecError::InsufficientBalance),
+        (OpcodeId::CREATE, GethExecError::ContractAddressCollision),
+        (OpcodeId::CREATE, GethExecError::NonceUintOverflow),
+        (OpcodeId::RETURN, GethExecError::CodeStoreOutOfGas),
+        (OpcodeId::RETURN, GethExecError::MaxCodeSizeExceeded),
+        (OpcodeId::RETURN, GethExecError::InvalidCode),
+        (OpcodeId::JUMP, GethExecError::InvalidJump),
+        (
+            OpcodeId::RETURNDATACOPY,
+            GethExecError::ReturnDataOutOfBounds,
+        ),
+        (OpcodeId::REVERT, GethExecError::ExecutionReverted),
+    ];
+    for (op, error) in cases {
+        assert!(
+            matches!(
+                get_step_reported_error(&op, error),
+                Err(Error::InvalidGethExecTrace(_))
+            ),
+            "{op:?} {error}"
+        );
+    }
+}
+
 #[test]
 fn tracer_err_gas_uint_overflow() {
     // MSTORE a value at an offset so high that the gast cost is big enough
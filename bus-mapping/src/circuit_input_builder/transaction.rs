@@ -9,7 +9,7 @@ use eth_types::evm_types::gas_utils::tx_data_gas_cost;
 use eth_types::{
     evm_types::OpcodeId,
     geth_types,
-    geth_types::{get_rlp_unsigned, TxType},
+    geth_types::{get_authorization_list, get_rlp_signed, get_rlp_unsigned, Authorization, TxType},
     state_db::{CodeDB, StateDB},
     AccessList, Address, GethExecTrace, Signature, Word, H256,
 };
@@ -205,6 +205,10 @@ pub struct Transaction {
     pub l1_fee_committed: TxL1Fee,
     /// EIP2930
     pub access_list: Option<AccessList>,
+    /// EIP4844 blob versioned hashes
+    pub blob_versioned_hashes: Vec<H256>,
+    /// EIP7702 authorization list
+    pub authorization_list: Vec<Authorization>,
     /// Calls made in the transaction
     pub(crate) calls: Vec<Call>,
     /// Execution steps
@@ -266,6 +270,8 @@ impl Transaction {
             l1_fee: Default::default(),
             l1_fee_committed: Default::default(),
             access_list: None,
+            blob_versioned_hashes: Vec::new(),
+            authorization_list: Vec::new(),
         }
     }
 
@@ -293,7 +299,12 @@ impl Transaction {
                 log::error!("tx.to not found {}", address);
                 return Err(Error::AccountNotFound(address));
             }
-            let code_hash = account.code_hash;
+            // the code of a delegated account (EIP-7702) is the code of its delegate.
+            let code_hash = code_db
+                .delegated_address(&account.code_hash)
+                .map_or(account.code_hash, |delegate| {
+                    sdb.get_account(&delegate).1.code_hash
+                });
             Call {
                 call_id,
                 kind: CallKind::Call,
@@ -366,7 +377,7 @@ impl Transaction {
             l1_fee,
             l1_fee_committed
         );
-        let rlp_signed_bytes = eth_tx.rlp().to_vec();
+        let rlp_signed_bytes = get_rlp_signed(eth_tx);
         //debug_assert_eq!(H256(ethers_core::utils::keccak256(&bytes)), eth_tx.hash);
 
         Ok(Self {
@@ -374,7 +385,7 @@ impl Transaction {
             hash: eth_tx.hash,
             chain_id: tx_chain_id,
             tx_type,
-            rlp_bytes: eth_tx.rlp().to_vec(),
+            rlp_bytes: rlp_signed_bytes.clone(),
             rlp_unsigned_bytes: get_rlp_unsigned(eth_tx),
             rlp_signed_bytes,
             nonce: eth_tx.nonce.as_u64(),
@@ -396,6 +407,12 @@ impl Transaction {
             l1_fee,
             l1_fee_committed,
             access_list: eth_tx.access_list.clone(),
+            blob_versioned_hashes: eth_tx
+                .other
+                .get_deserialized("blobVersionedHashes")
+                .and_then(Result::ok)
+                .unwrap_or_default(),
+            authorization_list: get_authorization_list(eth_tx),
         })
     }
 
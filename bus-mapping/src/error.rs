@@ -93,7 +93,7 @@ pub enum OogError {
     Call,
     /// Out of Gas for Precompile.
     /// ecrecover/ecadd/ecmul/ecpairing/identity oog can should be handled by this.
-    /// modexp oog is handled inside modexp gadget.
+    /// modexp and blake2f oog are handled inside their own gadgets.
     /// disabled precompiles are handled by PrecompileFailedGadget.
     Precompile,
     /// Out of Gas for CREATE and CREATE2
@@ -218,6 +218,12 @@ pub(crate) fn get_step_reported_error(
             };
             ExecError::OutOfGas(oog_err)
         }
+        // EIP-3860: CREATE and CREATE2 consume all gas if the init code exceeds the max size.
+        GethExecError::MaxInitCodeSizeExceeded
+            if matches!(op, OpcodeId::CREATE | OpcodeId::CREATE2) =>
+        {
+            ExecError::OutOfGas(OogError::Create)
+        }
         GethExecError::StackOverflow { .. } => ExecError::StackOverflow,
         GethExecError::StackUnderflow { .. } => ExecError::StackUnderflow,
         GethExecError::WriteProtection => ExecError::WriteProtection,
@@ -11,16 +11,17 @@ use crate::{
     operation::{
         AccountField, AccountOp, CallContextField, StorageOp, TxReceiptField, TxRefundOp, RW,
     },
-    precompile::{execute_precompiled, PrecompileCalls},
+    precompile::{execute_precompiled, PrecompileCalls, PRECOMPILE_WARM_ADDRESSES},
     Error,
 };
 use eth_types::{
     evm_types::{
-        gas_utils::{tx_access_list_gas_cost, tx_data_gas_cost},
+        gas_utils::{tx_access_list_gas_cost, tx_authorization_list_gas_cost, tx_data_gas_cost},
         GasCost, MAX_REFUND_QUOTIENT_OF_GAS_USED,
     },
+    geth_types::MAX_AUTHORIZATIONS_PER_TX,
     state_db::CodeDB,
-    utils::is_precompiled,
+    utils::{delegation_designator, is_precompiled},
     Bytecode, ToWord, Word,
 };
 use ethers_core::utils::get_contract_address;
@@ -147,8 +148,13 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
         nonce_prev.into(),
     )?;
 
+    // Apply the authorization list of EIP-7702 set-code txs.
+    if state.tx.tx_type.is_eip7702() {
+        gen_tx_authorization_ops(state, &mut exec_step)?;
+    }
+
     // Add precompile contract address to access list
-    for address in 1..=9 {
+    for &address in PRECOMPILE_WARM_ADDRESSES {
         let address = eth_types::Address::from_low_u64_be(address);
         let is_warm_prev = !state.sdb.add_account_to_access_list(address);
         state.tx_access_list_account_write(
@@ -182,6 +188,37 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
         )?;
     }
 
+    // The code executed by a call into a delegated account (EIP-7702) is the code of its
+    // delegate, which is warmed up as well. Since the authorization list may have just changed
+    // the delegation of the callee, the code of the root call is resolved again here.
+    let callee_code_hash = state.sdb.get_account(&call.address).1.code_hash;
+    let delegate_address = state.code_db.delegated_address(&callee_code_hash);
+    if let Some(delegate_address) = delegate_address.filter(|_| !call.is_create()) {
+        state.push_copy_delegation_designator(&mut exec_step, callee_code_hash)?;
+        let is_warm_prev = !state.sdb.add_account_to_access_list(delegate_address);
+        state.tx_access_list_account_write(
+            &mut exec_step,
+            state.tx_ctx.id(),
+            delegate_address,
+            true,
+            is_warm_prev,
+        )?;
+        let delegate_code_hash = state.sdb.get_account(&delegate_address).1.code_hash_read();
+        state.account_read(
+            &mut exec_step,
+            delegate_address,
+            AccountField::CodeHash,
+            delegate_code_hash.to_word(),
+        )?;
+    }
+    let call = if call.is_create() {
+        call
+    } else {
+        let code_hash = state.executed_code_hash(callee_code_hash);
+        state.call_mut()?.code_hash = code_hash;
+        state.call()?.clone()
+    };
+
     // Calculate gas cost of init code only for EIP-3860 of Shanghai.
     let init_code_gas_cost = if state.tx.is_create() {
         (state.tx.input.len() as u64 + 31) / 32 * eth_types::evm_types::INIT_CODE_WORD_GAS
@@ -192,14 +229,16 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
     // Calculate intrinsic gas cost
     let call_data_gas_cost = tx_data_gas_cost(&state.tx.input);
     let access_list_gas_cost = tx_access_list_gas_cost(&state.tx.access_list);
+    let authorization_list_gas_cost = tx_authorization_list_gas_cost(&state.tx.authorization_list);
     let intrinsic_gas_cost = if state.tx.is_create() {
         GasCost::CREATION_TX.as_u64()
     } else {
         GasCost::TX.as_u64()
     } + call_data_gas_cost
         + access_list_gas_cost
+        + authorization_list_gas_cost
         + init_code_gas_cost;
-    log::trace!("intrinsic_gas_cost {intrinsic_gas_cost}, call_data_gas_cost {call_data_gas_cost}, access_list_gas_cost {access_list_gas_cost}, init_code_gas_cost {init_code_gas_cost}, &mut exec_step.gas_cost {:?}", &mut exec_step.gas_cost);
+    log::trace!("intrinsic_gas_cost {intrinsic_gas_cost}, call_data_gas_cost {call_data_gas_cost}, access_list_gas_cost {access_list_gas_cost}, authorization_list_gas_cost {authorization_list_gas_cost}, init_code_gas_cost {init_code_gas_cost}, &mut exec_step.gas_cost {:?}", &mut exec_step.gas_cost);
     exec_step.gas_cost = GasCost(intrinsic_gas_cost);
 
     // Get code_hash of callee account
@@ -220,11 +259,12 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
     };
     // call_code is code being executed
     let call_code_hash = call.code_hash.to_word();
-    if !state.tx.is_create() && !account_code_hash.is_zero() {
+    if !state.tx.is_create() && !account_code_hash.is_zero() && delegate_address.is_none() {
         debug_assert_eq!(account_code_hash, call_code_hash);
     }
     let account_code_hash_is_empty_or_zero =
         account_code_hash.is_zero() || account_code_hash == CodeDB::empty_code_hash().to_word();
+    let call_code_hash_is_empty = call.code_hash == CodeDB::empty_code_hash();
 
     state.account_read(
         &mut exec_step,
@@ -233,6 +273,7 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
         account_code_hash,
     )?;
 
+    let mut is_deployment_collision = false;
     if state.tx.is_create()
         && ((!account_code_hash_is_empty_or_zero) || !callee_account.nonce.is_zero())
     {
@@ -240,7 +281,8 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
         // tracer: https://github.com/ethereum/go-ethereum/issues/28439
         // which may also act as the data source for our statedb,
         // we have to relax the constraint a bit and fix it silently
-        if account_code_hash_is_empty_or_zero && callee_account.nonce == 1.into() {
+        if account_code_hash_is_empty_or_zero && callee_account.nonce == 1.into() && call.is_success
+        {
             log::warn!(
                 "fix deployment nonce for {:?} silently for the prestate tracer",
                 call.address,
@@ -249,11 +291,14 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
             fixed_account.nonce = Word::zero();
             state.sdb.set_account(&call.address, fixed_account);
         } else {
-            unimplemented!(
+            // The creation fails without executing the init code, and consumes all gas.
+            log::warn!(
                 "deployment collision at {:?}, account {:?}",
                 call.address,
                 callee_account
             );
+            debug_assert!(!call.is_success, "deployment collision must fail the tx");
+            is_deployment_collision = true;
         }
     }
 
@@ -268,7 +313,7 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
         call.caller_address,
         call.address,
         callee_exists,
-        call.is_create(),
+        call.is_create() && !is_deployment_collision,
         call.value,
         Some(fee),
     )?;
@@ -291,6 +336,8 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
             stream.out().to_vec()
         };
         state.block.sha3_inputs.push(address_preimage);
+    }
+    if state.tx.is_create() && !is_deployment_collision {
         // 2. add init code to keccak circuit.
         let initcode = state.tx.input.clone();
         let length = initcode.len();
@@ -324,12 +371,22 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
 
     let mut precompile_step = None;
 
-    // There are 4 branches from here.
-    match (
-        call.is_create(),
-        is_precompile,
-        account_code_hash_is_empty_or_zero,
-    ) {
+    // There are 5 branches from here.
+    match (call.is_create(), is_precompile, call_code_hash_is_empty) {
+        // 5. Creation transaction colliding with an existing account.
+        //
+        // The callee nonce is read to prove the collision, the value transfer is reverted and
+        // the tx ends right away.
+        (true, _, _) if is_deployment_collision => {
+            state.account_read(
+                &mut exec_step,
+                call.address,
+                AccountField::Nonce,
+                callee_account.nonce,
+            )?;
+            state.handle_reversion(&mut [&mut exec_step]);
+            state.tx_ctx.pop_call_ctx(false);
+        }
         // 1. Creation transaction.
         (true, _, _) => {
             state.push_op_reversible(
@@ -341,6 +398,10 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
                     value_prev: 0.into(),
                 },
             )?;
+            // EIP-6780: remember the deployed account so that a SELFDESTRUCT in
+            // this transaction can still delete it.
+            #[cfg(not(feature = "scroll"))]
+            state.tx_created_account_write(&mut exec_step, call.address)?;
             for (field, value) in [
                 (CallContextField::Depth, call.depth.into()),
                 (
@@ -418,7 +479,7 @@ pub fn gen_begin_tx_steps(state: &mut CircuitInputStateRef) -> Result<Vec<ExecSt
                 state.call_context_write(&mut exec_step, call.call_id, field, value)?;
             }
 
-            let precompile_call: PrecompileCalls = call.address.0[19].into();
+            let precompile_call: PrecompileCalls = call.address.into();
             let (result, precompile_call_gas_cost, has_oog_err) = execute_precompiled(
                 &precompile_call.into(),
                 &state.tx.input,
@@ -836,13 +897,137 @@ fn gen_tx_l1_fee_ops(
     Ok(())
 }
 
+// Apply the authorization list of an EIP-7702 tx. For each valid authorization, the authority
+// is warmed up, its nonce is increased and its code is set to the delegation designator of the
+// authorized address. Invalid authorizations are skipped. None of these writes is reverted if
+// the tx fails.
+fn gen_tx_authorization_ops(
+    state: &mut CircuitInputStateRef,
+    exec_step: &mut ExecStep,
+) -> Result<(), Error> {
+    if state.tx.authorization_list.len() > MAX_AUTHORIZATIONS_PER_TX {
+        return Err(Error::InternalError("too many authorizations in tx"));
+    }
+
+    let tx_id = state.tx_ctx.id();
+    let chain_id = state.block.chain_id;
+    let mut refund = state.sdb.refund();
+    let refund_prev = refund;
+    for authorization in state.tx.authorization_list.clone() {
+        let Some(authority) = authorization.authority(chain_id) else {
+            continue;
+        };
+        let is_warm_prev = !state.sdb.add_account_to_access_list(authority);
+        state.tx_access_list_account_write(exec_step, tx_id, authority, true, is_warm_prev)?;
+
+        let authority_account = state.sdb.get_account(&authority).1.clone();
+        let authority_exists = !authority_account.is_empty();
+        state.account_read(
+            exec_step,
+            authority,
+            AccountField::CodeHash,
+            authority_account.code_hash_read().to_word(),
+        )?;
+        if authority_exists {
+            state.account_read(
+                exec_step,
+                authority,
+                AccountField::Nonce,
+                authority_account.nonce,
+            )?;
+        }
+        // The authority must not be a contract, and its nonce has to match the authorization.
+        let is_code_empty = authority_account.code_hash == CodeDB::empty_code_hash();
+        let is_delegated = state
+            .code_db
+            .delegated_address(&authority_account.code_hash)
+            .is_some();
+        if !(is_code_empty || is_delegated)
+            || authority_account.nonce != authorization.nonce.as_u64().into()
+        {
+            continue;
+        }
+
+        if authority_exists {
+            refund += GasCost::AUTHORIZATION_PER_EMPTY_ACCOUNT.as_u64()
+                - GasCost::AUTHORIZATION_PER_AUTH_BASE.as_u64();
+        }
+
+        // Delegating to the zero address clears the delegation.
+        let code = if authorization.address.is_zero() {
+            vec![]
+        } else {
+            delegation_designator(&authorization.address)
+        };
+        state.block.sha3_inputs.push(code.clone());
+        let code_hash = state.code_db.insert(code.clone());
+        if !authorization.address.is_zero() {
+            state.push_copy_delegation_designator(exec_step, code_hash)?;
+        }
+        state.account_write(
+            exec_step,
+            authority,
+            AccountField::CodeHash,
+            code_hash.to_word(),
+            authority_account.code_hash_read().to_word(),
+        )?;
+        #[cfg(feature = "scroll")]
+        {
+            // the account may have been created by the code hash write above
+            let account = state.sdb.get_account(&authority).1;
+            let keccak_code_hash_prev = if account.is_empty() {
+                Word::zero()
+            } else {
+                account.keccak_code_hash.to_word()
+            };
+            state.account_write(
+                exec_step,
+                authority,
+                AccountField::KeccakCodeHash,
+                ethers_core::utils::keccak256(&code).into(),
+                keccak_code_hash_prev,
+            )?;
+            state.account_write(
+                exec_step,
+                authority,
+                AccountField::CodeSize,
+                code.len().into(),
+                authority_account.code_size,
+            )?;
+        }
+        state.account_write(
+            exec_step,
+            authority,
+            AccountField::Nonce,
+            authority_account.nonce + 1,
+            authority_account.nonce,
+        )?;
+    }
+
+    state.push_op(
+        exec_step,
+        RW::WRITE,
+        TxRefundOp {
+            tx_id,
+            value: refund,
+            value_prev: refund_prev,
+        },
+    )?;
+    state.sdb.set_refund(refund);
+
+    Ok(())
+}
+
 // Add two copy-events for tx access-list addresses and storage keys for
 // EIP-1559 and EIP-2930.
 fn gen_tx_access_list_ops(
     state: &mut CircuitInputStateRef,
     exec_step: &mut ExecStep,
 ) -> Result<(), Error> {
-    if !(state.tx.tx_type.is_eip1559() || state.tx.tx_type.is_eip2930()) {
+    if !(state.tx.tx_type.is_eip1559()
+        || state.tx.tx_type.is_eip2930()
+        || state.tx.tx_type.is_eip7702())
+    {
         return Ok(());
     }
 
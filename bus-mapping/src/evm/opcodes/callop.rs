@@ -140,9 +140,17 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
         let callee_code_hash = callee_call.code_hash;
         let callee_acc = state.sdb.get_account(&callee_address).1;
         let callee_exists = !callee_acc.is_empty();
+        // The code of a delegated account (EIP-7702) is the delegation designator, while
+        // `callee_code_hash` is the code of its delegate which is executed.
+        let delegate_address = state.code_db.delegated_address(&callee_acc.code_hash);
+        let designator_code_hash = callee_acc.code_hash;
         let (callee_code_hash_word, is_empty_code_hash) = if callee_exists {
             (
-                callee_code_hash.to_word(),
+                if delegate_address.is_some() {
+                    callee_acc.code_hash.to_word()
+                } else {
+                    callee_code_hash.to_word()
+                },
                 callee_code_hash == CodeDB::empty_code_hash(),
             )
         } else {
@@ -166,6 +174,36 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
             },
         )?;
 
+        // Calls into a delegated account also access the delegate.
+        let is_delegate_warm = if let Some(delegate_address) = delegate_address {
+            state.push_copy_delegation_designator(&mut exec_step, designator_code_hash)?;
+            let is_warm = state.sdb.check_account_in_access_list(&delegate_address);
+            state.push_op_reversible(
+                &mut exec_step,
+                TxAccessListAccountOp {
+                    tx_id,
+                    address: delegate_address,
+                    is_warm: true,
+                    is_warm_prev: is_warm,
+                },
+            )?;
+            let delegate_code_hash = state
+                .sdb
+                .get_account(&delegate_address)
+                .1
+                .code_hash_read()
+                .to_word();
+            state.account_read(
+                &mut exec_step,
+                delegate_address,
+                AccountField::CodeHash,
+                delegate_code_hash,
+            )?;
+            Some(is_warm)
+        } else {
+            None
+        };
+
         // Switch to callee's call context
         state.push_call(callee_call.clone());
 
@@ -239,6 +277,10 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
                 }
         } else {
             0
+        } + match is_delegate_warm {
+            Some(true) => GasCost::WARM_ACCESS.as_u64(),
+            Some(false) => GasCost::COLD_ACCOUNT_ACCESS.as_u64(),
+            None => 0,
         } + memory_expansion_gas_cost;
         let gas_specified = stack_inputs[0];
         debug_assert!(
@@ -287,7 +329,7 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
             // 1. Call to precompiled.
             (false, true, _) => {
                 let code_address = code_address.unwrap();
-                let precompile_call: PrecompileCalls = code_address.0[19].into();
+                let precompile_call: PrecompileCalls = code_address.into();
 
                 // get the result of the precompile call.
                 // For failed call, it will cost all gas provided.
@@ -491,8 +533,14 @@ impl<const N_ARGS: usize> Opcode for CallOpcode<N_ARGS> {
                 } else {
                     None
                 };
-                // modexp's oog error is handled in ModExpGadget
-                if has_oog_err && precompile_call != PrecompileCalls::Modexp {
+                // modexp's and blake2f's oog errors are handled in ModExpGadget and
+                // Blake2FGadget
+                if has_oog_err
+                    && !matches!(
+                        precompile_call,
+                        PrecompileCalls::Modexp | PrecompileCalls::Blake2F
+                    )
+                {
                     log::debug!(
                         "precompile call ({:?}) runs out of gas: callee_gas_left_with_stipend = {}",
                         precompile_call,
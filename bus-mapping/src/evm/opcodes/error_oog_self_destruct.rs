@@ -0,0 +1,164 @@
+use crate::{
+    circuit_input_builder::{CircuitInputStateRef, ExecStep},
+    error::{ExecError, OogError},
+    evm::{Opcode, OpcodeId},
+    operation::{AccountField, CallContextField, TxAccessListAccountOp, RW},
+    Error,
+};
+use eth_types::{GethExecStep, ToAddress, ToWord, H256, U256};
+
+#[derive(Debug, Copy, Clone)]
+pub struct ErrorOOGSelfDestruct;
+
+impl Opcode for ErrorOOGSelfDestruct {
+    fn gen_associated_ops(
+        state: &mut CircuitInputStateRef,
+        geth_steps: &[GethExecStep],
+    ) -> Result<Vec<ExecStep>, Error> {
+        let geth_step = &geth_steps[0];
+        let mut exec_step = state.new_step(geth_step)?;
+        exec_step.error = Some(ExecError::OutOfGas(OogError::SelfDestruct));
+
+        assert_eq!(geth_step.op, OpcodeId::SELFDESTRUCT);
+        // Read beneficiary address from stack.
+        let beneficiary_word = state.stack_pop(&mut exec_step)?;
+        let beneficiary = beneficiary_word.to_address();
+        #[cfg(feature = "enable-stack")]
+        assert_eq!(beneficiary_word, geth_step.stack.last()?);
+
+        let call = state.call()?.clone();
+        state.call_context_read(
+            &mut exec_step,
+            call.call_id,
+            CallContextField::TxId,
+            U256::from(state.tx_ctx.id()),
+        )?;
+        state.call_context_read(
+            &mut exec_step,
+            call.call_id,
+            CallContextField::CalleeAddress,
+            call.address.to_word(),
+        )?;
+
+        // read `is_warm` state of the beneficiary
+        let is_warm = state.sdb.check_account_in_access_list(&beneficiary);
+        state.push_op(
+            &mut exec_step,
+            RW::READ,
+            TxAccessListAccountOp {
+                tx_id: state.tx_ctx.id(),
+                address: beneficiary,
+                is_warm,
+                is_warm_prev: is_warm,
+            },
+        )?;
+
+        // The new account surcharge depends on the existence of the beneficiary
+        // and on the balance to be sent.
+        let beneficiary_account = state.sdb.get_account(&beneficiary).1;
+        let beneficiary_code_hash = if beneficiary_account.is_empty() {
+            H256::zero()
+        } else {
+            beneficiary_account.code_hash
+        };
+        state.account_read(
+            &mut exec_step,
+            beneficiary,
+            AccountField::CodeHash,
+            beneficiary_code_hash.to_word(),
+        )?;
+        let balance = state.sdb.get_account(&call.address).1.balance;
+        state.account_read(&mut exec_step, call.address, AccountField::Balance, balance)?;
+
+        // common error handling
+        state.handle_return((None, None), &mut [&mut exec_step], geth_steps, true)?;
+        Ok(vec![exec_step])
+    }
+}
+
+#[cfg(test)]
+mod oog_self_destruct_tests {
+    use crate::{
+        circuit_input_builder::ExecState,
+        error::{ExecError, OogError},
+        mock::BlockData,
+        operation::{AccountField, AccountOp, TxAccessListAccountOp, RW},
+    };
+    use eth_types::{address, bytecode, evm_types::OpcodeId, geth_types::GethData, ToWord, Word};
+    use mock::TestContext;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_oog_self_destruct_cold_beneficiary() {
+        let beneficiary = address!("0x00000000000000000000000000000000000000be");
+        let code = bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        };
+        let balance = Word::from(1000u64);
+
+        // Enough gas for the constant cost of SELFDESTRUCT, but not for the
+        // cold beneficiary access.
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x0000000000000000000000000000000000000010"))
+                    .balance(balance)
+                    .code(code);
+                accs[1]
+                    .address(address!("0x0000000000000000000000000000000000cafe01"))
+                    .balance(Word::from(1u64 << 20));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[0].address)
+                    .from(accs[1].address)
+                    .gas(26103.into());
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .unwrap();
+        assert_eq!(
+            step.error,
+            Some(ExecError::OutOfGas(OogError::SelfDestruct))
+        );
+
+        let container = builder.block.container.clone();
+        let operation = &container.tx_access_list_account[step.bus_mapping_instance[3].as_usize()];
+        assert_eq!(operation.rw(), RW::READ);
+        assert_eq!(
+            operation.op(),
+            &TxAccessListAccountOp {
+                tx_id: 1,
+                address: beneficiary,
+                is_warm: false,
+                is_warm_prev: false,
+            }
+        );
+
+        let operation = &container.account[step.bus_mapping_instance[5].as_usize()];
+        assert_eq!(operation.rw(), RW::READ);
+        assert_eq!(
+            operation.op(),
+            &AccountOp {
+                address: address!("0x0000000000000000000000000000000000000010"),
+                field: AccountField::Balance,
+                value: balance,
+                value_prev: balance,
+            }
+        );
+    }
+}
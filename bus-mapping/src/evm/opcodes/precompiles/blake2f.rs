@@ -0,0 +1,30 @@
+use crate::{
+    circuit_input_builder::{Blake2F, PrecompileEvent},
+    precompile::PrecompileAuxData,
+};
+
+pub(crate) fn opt_data(
+    input_bytes: &[u8],
+    output_bytes: &[u8],
+    return_bytes: &[u8],
+) -> (Option<PrecompileEvent>, Option<PrecompileAuxData>) {
+    // only a successful call (valid input with enough gas) has an output, which is what
+    // the blake2f circuit proves.
+    let opt_event = if output_bytes.is_empty() {
+        None
+    } else {
+        Some(PrecompileEvent::Blake2F(Blake2F::new_from_bytes(
+            input_bytes,
+            output_bytes,
+        )))
+    };
+
+    (
+        opt_event,
+        Some(PrecompileAuxData::Blake2F {
+            input_bytes: input_bytes.to_vec(),
+            output_bytes: output_bytes.to_vec(),
+            return_bytes: return_bytes.to_vec(),
+        }),
+    )
+}
@@ -2,24 +2,28 @@ use eth_types::{GethExecStep, ToWord, Word};
 
 use crate::{
     circuit_input_builder::{
-        Call, CircuitInputStateRef, ExecState, ExecStep, PrecompileEvent, SHA256,
+        Call, CircuitInputStateRef, ExecState, ExecStep, PrecompileEvent, Ripemd160, SHA256,
     },
     operation::CallContextField,
     precompile::{PrecompileAuxData, PrecompileCalls},
     Error,
 };
 
+mod blake2f;
 mod ec_add;
 mod ec_mul;
 mod ec_pairing;
 mod ecrecover;
 mod modexp;
+mod p256_verify;
 
+use blake2f::opt_data as opt_data_blake2f;
 use ec_add::opt_data as opt_data_ec_add;
 use ec_mul::opt_data as opt_data_ec_mul;
 use ec_pairing::opt_data as opt_data_ec_pairing;
 use ecrecover::opt_data as opt_data_ecrecover;
 use modexp::opt_data as opt_data_modexp;
+use p256_verify::opt_data as opt_data_p256_verify;
 
 pub fn gen_associated_ops(
     state: &mut CircuitInputStateRef,
@@ -65,7 +69,13 @@ pub fn gen_ops(
         PrecompileCalls::Bn128Pairing => {
             opt_data_ec_pairing(input_bytes, output_bytes, return_bytes)
         }
-        PrecompileCalls::Modexp => opt_data_modexp(input_bytes, output_bytes, return_bytes),
+        PrecompileCalls::Modexp => {
+            opt_data_modexp(input_bytes, output_bytes, return_bytes, call.is_success)
+        }
+        PrecompileCalls::Blake2F => opt_data_blake2f(input_bytes, output_bytes, return_bytes),
+        PrecompileCalls::P256Verify => {
+            opt_data_p256_verify(input_bytes, output_bytes, return_bytes)
+        }
         PrecompileCalls::Identity => (
             None,
             Some(PrecompileAuxData::Identity {
@@ -91,17 +101,23 @@ pub fn gen_ops(
                 return_bytes: return_bytes.to_vec(),
             }),
         ),
-        _ => {
-            log::warn!("precompile {:?} unsupported in circuits", precompile);
-            (
-                None,
-                Some(PrecompileAuxData::Base {
-                    input_bytes: input_bytes.to_vec(),
-                    output_bytes: output_bytes.to_vec(),
-                    return_bytes: return_bytes.to_vec(),
-                }),
-            )
-        }
+        PrecompileCalls::Ripemd160 => (
+            if output_bytes.is_empty() {
+                None
+            } else {
+                Some(PrecompileEvent::Ripemd160(Ripemd160 {
+                    input: input_bytes.to_vec(),
+                    digest: output_bytes[12..]
+                        .try_into()
+                        .expect("output bytes must be 32 bytes"),
+                }))
+            },
+            Some(PrecompileAuxData::Ripemd160 {
+                input_bytes: input_bytes.to_vec(),
+                output_bytes: output_bytes.to_vec(),
+                return_bytes: return_bytes.to_vec(),
+            }),
+        ),
     };
     log::trace!("precompile event {opt_event:?}, aux data {aux_data:?}");
     if let Some(event) = opt_event {
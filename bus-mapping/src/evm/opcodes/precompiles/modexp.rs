@@ -1,28 +1,36 @@
 use crate::{
     circuit_input_builder::{BigModExp, PrecompileEvent},
-    precompile::{ModExpAuxData, PrecompileAuxData},
+    precompile::{ModExpAuxData, PrecompileAuxData, MODEXP_INPUT_LIMIT},
 };
 
-use eth_types::Word;
-
 pub(crate) fn opt_data(
     input_bytes: &[u8],
     output_bytes: &[u8],
     return_bytes: &[u8],
+    is_success: bool,
 ) -> (Option<PrecompileEvent>, Option<PrecompileAuxData>) {
     let aux_data = ModExpAuxData::new(input_bytes, output_bytes, return_bytes);
-    if aux_data.valid {
-        let event = BigModExp {
-            base: Word::from_big_endian(&aux_data.inputs[0]),
-            exponent: Word::from_big_endian(&aux_data.inputs[1]),
-            modulus: Word::from_big_endian(&aux_data.inputs[2]),
-            result: Word::from_big_endian(&aux_data.output),
-        };
-        (
-            Some(PrecompileEvent::ModExp(event)),
-            Some(PrecompileAuxData::Modexp(aux_data)),
-        )
-    } else {
-        (None, Some(PrecompileAuxData::Modexp(aux_data)))
-    }
+
+    // the circuit binds the whole (padded) input to the call, hence also parses invalid ones.
+    let mut input = input_bytes.get(96..).unwrap_or_default().to_vec();
+    input.resize(MODEXP_INPUT_LIMIT - 96, 0);
+    let event = BigModExp {
+        input,
+        lens: if aux_data.valid {
+            aux_data.input_lens.map(|len| len.as_usize())
+        } else {
+            [0; 3]
+        },
+        is_success,
+        result: if is_success {
+            output_bytes.to_vec()
+        } else {
+            vec![]
+        },
+    };
+
+    (
+        Some(PrecompileEvent::ModExp(event)),
+        Some(PrecompileAuxData::Modexp(aux_data)),
+    )
 }
@@ -0,0 +1,114 @@
+use crate::{
+    circuit_input_builder::PrecompileEvent,
+    precompile::{P256VerifyAuxData, PrecompileAuxData},
+};
+
+pub(crate) fn opt_data(
+    input_bytes: &[u8],
+    output_bytes: &[u8],
+    return_bytes: &[u8],
+) -> (Option<PrecompileEvent>, Option<PrecompileAuxData>) {
+    let aux_data = P256VerifyAuxData::new(input_bytes, output_bytes, return_bytes);
+
+    // Only well-formed inputs are verified through the sig circuit, this includes invalid
+    // signatures and public keys that are not on the curve.
+    let opt_event = aux_data.sign_data().map(PrecompileEvent::P256Verify);
+    (opt_event, Some(PrecompileAuxData::P256Verify(aux_data)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::opt_data;
+    use crate::{
+        circuit_input_builder::PrecompileEvent,
+        precompile::{execute_precompiled, PrecompileCalls},
+    };
+    use eth_types::{sign_types::sign, Word};
+    use halo2_proofs::halo2curves::{
+        group::{ff::PrimeField, prime::PrimeCurveAffine, Curve},
+        secp256r1::{Fp, Fq, Secp256r1Affine},
+        Coordinates, CurveAffine,
+    };
+
+    fn be_bytes<T: PrimeField<Repr = [u8; 32]>>(value: T) -> Vec<u8> {
+        value.to_repr().into_iter().rev().collect()
+    }
+
+    fn p256_verify_input(msg_hash: Fq, randomness: Fq, sk: Fq) -> Vec<u8> {
+        let pk = (Secp256r1Affine::generator() * sk).to_affine();
+        let (sig_r, sig_s, _) = sign::<Fp, Fq, Secp256r1Affine>(randomness, sk, msg_hash);
+        let pk = Option::<Coordinates<_>>::from(pk.coordinates()).unwrap();
+        [
+            be_bytes(msg_hash),
+            be_bytes(sig_r),
+            be_bytes(sig_s),
+            be_bytes(*pk.x()),
+            be_bytes(*pk.y()),
+        ]
+        .concat()
+    }
+
+    fn call(input: &[u8]) -> (Vec<u8>, Option<PrecompileEvent>) {
+        let (output, gas_cost, is_oog) =
+            execute_precompiled(&PrecompileCalls::P256Verify.into(), input, 10_000);
+        assert_eq!(gas_cost, 3450);
+        assert!(!is_oog);
+        let (opt_event, _) = opt_data(input, &output, &output);
+        (output, opt_event)
+    }
+
+    #[test]
+    fn p256_verify_valid_signature() {
+        let input = p256_verify_input(Fq::from(0xcafe), Fq::from(0x1234), Fq::from(0xbeef));
+        let (output, opt_event) = call(&input);
+        assert_eq!(Word::from_big_endian(&output), Word::one());
+        assert!(matches!(
+            opt_event,
+            Some(PrecompileEvent::P256Verify(sign_data)) if sign_data.is_valid()
+        ));
+    }
+
+    #[test]
+    fn p256_verify_invalid_signature() {
+        let mut input = p256_verify_input(Fq::from(0xcafe), Fq::from(0x1234), Fq::from(0xbeef));
+        // tamper with the message hash.
+        input[31] ^= 1;
+        let (output, opt_event) = call(&input);
+        assert!(output.is_empty());
+        assert!(matches!(
+            opt_event,
+            Some(PrecompileEvent::P256Verify(sign_data)) if !sign_data.is_valid()
+        ));
+
+        // the public key is not on the curve.
+        let mut input = p256_verify_input(Fq::from(0xcafe), Fq::from(0x1234), Fq::from(0xbeef));
+        input[159] ^= 1;
+        let (output, opt_event) = call(&input);
+        assert!(output.is_empty());
+        assert!(matches!(
+            opt_event,
+            Some(PrecompileEvent::P256Verify(sign_data)) if !sign_data.is_valid()
+        ));
+    }
+
+    #[test]
+    fn p256_verify_malformed_input() {
+        let input = p256_verify_input(Fq::from(0xcafe), Fq::from(0x1234), Fq::from(0xbeef));
+        for input in [
+            input[..159].to_vec(),
+            [input.clone(), vec![0]].concat(),
+            vec![],
+        ] {
+            let (output, opt_event) = call(&input);
+            assert!(output.is_empty());
+            assert!(opt_event.is_none());
+        }
+
+        // r = 0 is out of range.
+        let mut input = input;
+        input[32..64].fill(0);
+        let (output, opt_event) = call(&input);
+        assert!(output.is_empty());
+        assert!(opt_event.is_none());
+    }
+}
@@ -0,0 +1,478 @@
+use super::Opcode;
+use crate::{
+    circuit_input_builder::{CircuitInputStateRef, ExecStep},
+    operation::{AccountField, AccountOp, CallContextField, StorageOp, TxAccessListAccountOp},
+    Error,
+};
+use eth_types::{evm_types::GasCost, Address, GethExecStep, ToAddress, ToWord, Word, H256, U256};
+
+/// Placeholder structure used to implement [`Opcode`] trait over it
+/// corresponding to the
+/// [`OpcodeId::SELFDESTRUCT`](crate::evm::OpcodeId::SELFDESTRUCT) `OpcodeId`.
+///
+/// Follows EIP-6780: the whole balance is always sent to the beneficiary, but
+/// the account itself is only deleted when it has been created in the same
+/// transaction. In that case every non-zero storage slot is cleared first, one
+/// step per slot, ahead of the step that executes the opcode.
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct SelfDestruct;
+
+impl Opcode for SelfDestruct {
+    fn gen_associated_ops(
+        state: &mut CircuitInputStateRef,
+        geth_steps: &[GethExecStep],
+    ) -> Result<Vec<ExecStep>, Error> {
+        let geth_step = &geth_steps[0];
+        let call = state.call()?.clone();
+        let sender = call.address;
+
+        if state.sdb.check_account_in_created_list(&sender) {
+            for key in state.sdb.non_zero_storage_keys(&sender) {
+                let clear_step = gen_storage_clear_step(state, geth_step, sender, key)?;
+                // Pushed right away so that the reversion of the clearing
+                // write is attached to this step rather than the next one.
+                state.tx.steps_mut().push(clear_step);
+            }
+        }
+
+        let mut exec_step = state.new_step(geth_step)?;
+
+        // Read beneficiary address from stack.
+        let beneficiary_word = state.stack_pop(&mut exec_step)?;
+        let beneficiary = beneficiary_word.to_address();
+        #[cfg(feature = "enable-stack")]
+        assert_eq!(beneficiary_word, geth_step.stack.last()?);
+
+        for (field, value) in [
+            (CallContextField::TxId, U256::from(state.tx_ctx.id())),
+            (
+                CallContextField::IsStatic,
+                U256::from(call.is_static as u64),
+            ),
+            (
+                CallContextField::RwCounterEndOfReversion,
+                U256::from(call.rw_counter_end_of_reversion as u64),
+            ),
+            (
+                CallContextField::IsPersistent,
+                U256::from(call.is_persistent as u64),
+            ),
+            (CallContextField::CalleeAddress, sender.to_word()),
+        ] {
+            state.call_context_read(&mut exec_step, call.call_id, field, value)?;
+        }
+
+        // Update transaction access list for beneficiary address.
+        let is_warm = state.sdb.check_account_in_access_list(&beneficiary);
+        state.push_op_reversible(
+            &mut exec_step,
+            TxAccessListAccountOp {
+                tx_id: state.tx_ctx.id(),
+                address: beneficiary,
+                is_warm: true,
+                is_warm_prev: is_warm,
+            },
+        )?;
+
+        // Read beneficiary code hash to determine whether it exists.
+        let beneficiary_account = state.sdb.get_account(&beneficiary).1.clone();
+        let beneficiary_exists = !beneficiary_account.is_empty();
+        let beneficiary_code_hash = if beneficiary_exists {
+            beneficiary_account.code_hash
+        } else {
+            H256::zero()
+        };
+        state.account_read(
+            &mut exec_step,
+            beneficiary,
+            AccountField::CodeHash,
+            beneficiary_code_hash.to_word(),
+        )?;
+
+        let is_created = state.tx_created_account_read(&mut exec_step, sender)?;
+
+        let (found, sender_account) = state.sdb.get_account(&sender);
+        if !found {
+            return Err(Error::AccountNotFound(sender));
+        }
+        let sender_account = sender_account.clone();
+        let value = sender_account.balance;
+        log::trace!(
+            "self destruct, sender {:?} beneficiary {:?} value {:?} is_created {}",
+            sender,
+            beneficiary,
+            value,
+            is_created
+        );
+
+        // The balance stays untouched only when a pre-existing account names
+        // itself as beneficiary; otherwise it is moved out (or burnt).
+        let sender_balance = if beneficiary == sender && !is_created {
+            value
+        } else {
+            Word::zero()
+        };
+        state.push_op_reversible(
+            &mut exec_step,
+            AccountOp {
+                address: sender,
+                field: AccountField::Balance,
+                value: sender_balance,
+                value_prev: value,
+            },
+        )?;
+        if beneficiary != sender {
+            state.transfer_to(
+                &mut exec_step,
+                beneficiary,
+                beneficiary_exists,
+                false,
+                value,
+                true,
+            )?;
+        }
+
+        // Delete the account only if it has been created in this transaction.
+        if is_created {
+            for (field, value_prev) in [
+                (AccountField::Nonce, sender_account.nonce),
+                (AccountField::CodeHash, sender_account.code_hash.to_word()),
+                #[cfg(feature = "scroll")]
+                (
+                    AccountField::KeccakCodeHash,
+                    sender_account.keccak_code_hash.to_word(),
+                ),
+                #[cfg(feature = "scroll")]
+                (AccountField::CodeSize, sender_account.code_size),
+            ] {
+                state.push_op_reversible(
+                    &mut exec_step,
+                    AccountOp {
+                        address: sender,
+                        field,
+                        value: Word::zero(),
+                        value_prev,
+                    },
+                )?;
+            }
+            if call.is_persistent {
+                state.sdb.destruct_account(sender);
+            }
+        }
+
+        // SELFDESTRUCT halts the current call successfully.
+        state.call_context_read(
+            &mut exec_step,
+            call.call_id,
+            CallContextField::IsSuccess,
+            1.into(),
+        )?;
+        if let Ok(caller) = state.caller_ctx_mut() {
+            caller.return_data.clear();
+        }
+        state.handle_return(
+            (None, None),
+            &mut [&mut exec_step],
+            geth_steps,
+            !call.is_root,
+        )?;
+
+        Ok(vec![exec_step])
+    }
+}
+
+/// Generate the step clearing the storage slot `key` of `address`, an account
+/// created in the current transaction which is being self destructed.
+fn gen_storage_clear_step(
+    state: &mut CircuitInputStateRef,
+    geth_step: &GethExecStep,
+    address: Address,
+    key: Word,
+) -> Result<ExecStep, Error> {
+    let mut exec_step = state.new_step(geth_step)?;
+    exec_step.gas_cost = GasCost(0);
+
+    let call = state.call()?.clone();
+    for (field, value) in [
+        (CallContextField::TxId, U256::from(state.tx_ctx.id())),
+        (
+            CallContextField::RwCounterEndOfReversion,
+            U256::from(call.rw_counter_end_of_reversion as u64),
+        ),
+        (
+            CallContextField::IsPersistent,
+            U256::from(call.is_persistent as u64),
+        ),
+        (CallContextField::CalleeAddress, address.to_word()),
+    ] {
+        state.call_context_read(&mut exec_step, call.call_id, field, value)?;
+    }
+
+    let is_created = state.tx_created_account_read(&mut exec_step, address)?;
+    debug_assert!(is_created);
+
+    let value_prev = *state.sdb.get_storage(&address, &key).1;
+    let committed_value = *state.sdb.get_committed_storage(&address, &key).1;
+    state.push_op_reversible(
+        &mut exec_step,
+        StorageOp::new(
+            address,
+            key,
+            Word::zero(),
+            value_prev,
+            state.tx_ctx.id(),
+            committed_value,
+        ),
+    )?;
+
+    Ok(exec_step)
+}
+
+#[cfg(test)]
+mod selfdestruct_tests {
+    use crate::{
+        circuit_input_builder::ExecState,
+        mock::BlockData,
+        operation::{AccountField, AccountOp, StorageOp, Target, TxCreatedAccountOp, RW},
+    };
+    use eth_types::{address, bytecode, evm_types::OpcodeId, geth_types::GethData, ToWord, Word};
+    use mock::{eth, test_ctx::helpers::*, TestContext};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn selfdestruct_pre_existing_account_only_transfers_balance() {
+        let beneficiary = address!("0x00000000000000000000000000000000000000be");
+        let code = bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        };
+
+        let block: GethData = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(address!("0x000000000000000000000000000000000000cafe"))
+                    .balance(Word::from(1_000_000))
+                    .code(code);
+                accs[1].address(beneficiary).balance(Word::from(10));
+                accs[2]
+                    .address(address!("0x0000000000000000000000000000000000000010"))
+                    .balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[0].address).from(accs[2].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .unwrap();
+
+        let container = &builder.block.container;
+        let created_read = step
+            .bus_mapping_instance
+            .iter()
+            .find_map(|op_ref| match op_ref.target() {
+                Target::TxCreatedAccount => {
+                    Some(container.tx_created_account[op_ref.as_usize()].clone())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(created_read.rw(), RW::READ);
+        assert!(!created_read.op().is_created);
+
+        let account_writes = step
+            .bus_mapping_instance
+            .iter()
+            .filter_map(|op_ref| match op_ref.target() {
+                Target::Account => Some(container.account[op_ref.as_usize()].clone()),
+                _ => None,
+            })
+            .filter(|op| op.rw() == RW::WRITE)
+            .map(|op| op.op().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            account_writes,
+            vec![
+                AccountOp {
+                    address: address!("0x000000000000000000000000000000000000cafe"),
+                    field: AccountField::Balance,
+                    value: Word::zero(),
+                    value_prev: Word::from(1_000_000),
+                },
+                AccountOp {
+                    address: beneficiary,
+                    field: AccountField::Balance,
+                    value: Word::from(1_000_010),
+                    value_prev: Word::from(10),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn selfdestruct_account_created_in_same_tx() {
+        // init code that self destructs during deployment
+        let init_code = bytecode! {
+            PUSH20(address!("0x00000000000000000000000000000000000000be").to_word())
+            SELFDESTRUCT
+        };
+
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(bytecode! {}),
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[1].address)
+                    .value(Word::from(100))
+                    .input(init_code.code().into());
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let step = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .find(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .unwrap();
+
+        let container = &builder.block.container;
+        let created_read: TxCreatedAccountOp = step
+            .bus_mapping_instance
+            .iter()
+            .find_map(|op_ref| match op_ref.target() {
+                Target::TxCreatedAccount => {
+                    Some(container.tx_created_account[op_ref.as_usize()].op().clone())
+                }
+                _ => None,
+            })
+            .unwrap();
+        assert!(created_read.is_created);
+
+        let deleted_fields = step
+            .bus_mapping_instance
+            .iter()
+            .filter_map(|op_ref| match op_ref.target() {
+                Target::Account => Some(container.account[op_ref.as_usize()].clone()),
+                _ => None,
+            })
+            .filter(|op| op.rw() == RW::WRITE && op.op().address == created_read.address)
+            .filter(|op| op.op().value.is_zero())
+            .map(|op| op.op().field)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            deleted_fields,
+            vec![
+                AccountField::Balance,
+                AccountField::Nonce,
+                AccountField::CodeHash,
+                #[cfg(feature = "scroll")]
+                AccountField::KeccakCodeHash,
+                #[cfg(feature = "scroll")]
+                AccountField::CodeSize,
+            ]
+        );
+    }
+
+    #[test]
+    fn selfdestruct_account_created_in_same_tx_clears_storage() {
+        // init code that writes two storage slots and self destructs during
+        // deployment
+        let init_code = bytecode! {
+            PUSH1(0x11)
+            PUSH1(0x02)
+            SSTORE
+            PUSH1(0x22)
+            PUSH1(0x01)
+            SSTORE
+            PUSH20(address!("0x00000000000000000000000000000000000000be").to_word())
+            SELFDESTRUCT
+        };
+
+        let block: GethData = TestContext::<2, 1>::new(
+            None,
+            account_0_code_account_1_no_code(bytecode! {}),
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[1].address)
+                    .value(Word::from(100))
+                    .input(init_code.code().into());
+            },
+            |block, _tx| block,
+        )
+        .unwrap()
+        .into();
+
+        let mut builder = BlockData::new_from_geth_data(block.clone()).new_circuit_input_builder();
+        builder
+            .handle_block(&block.eth_block, &block.geth_traces)
+            .unwrap();
+
+        let steps = builder.block.txs()[0]
+            .steps()
+            .iter()
+            .filter(|step| step.exec_state == ExecState::Op(OpcodeId::SELFDESTRUCT))
+            .collect::<Vec<_>>();
+        assert_eq!(steps.len(), 3);
+
+        let container = &builder.block.container;
+        let storage_writes = steps
+            .iter()
+            .map(|step| {
+                step.bus_mapping_instance
+                    .iter()
+                    .filter_map(|op_ref| match op_ref.target() {
+                        Target::Storage => Some(container.storage[op_ref.as_usize()].clone()),
+                        _ => None,
+                    })
+                    .map(|op| {
+                        assert_eq!(op.rw(), RW::WRITE);
+                        op.op().clone()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let created = storage_writes[0][0].address;
+        assert_eq!(
+            storage_writes,
+            vec![
+                vec![StorageOp::new(
+                    created,
+                    Word::from(1),
+                    Word::zero(),
+                    Word::from(0x22),
+                    1,
+                    Word::zero()
+                )],
+                vec![StorageOp::new(
+                    created,
+                    Word::from(2),
+                    Word::zero(),
+                    Word::from(0x11),
+                    1,
+                    Word::zero()
+                )],
+                vec![],
+            ]
+        );
+        assert!(builder.sdb.non_zero_storage_keys(&created).is_empty());
+    }
+}
@@ -1,6 +1,16 @@
 //! precompile helpers
 
-use eth_types::{evm_types::GasCost, Address, ToBigEndian, Word};
+use eth_types::{
+    evm_types::GasCost,
+    sign_types::{biguint_to_32bytes_le, SignData, SECP256R1_Q},
+    utils::P256_VERIFY_PRECOMPILE_ADDRESS,
+    Address, Bytes, ToBigEndian, ToLittleEndian, Word,
+};
+use halo2_proofs::halo2curves::{
+    group::ff::PrimeField,
+    secp256r1::{Fp, Fq, Secp256r1Affine},
+};
+use num::{BigUint, Integer};
 use revm_precompile::{Precompile, PrecompileError, PrecompileErrors, Precompiles};
 use strum_macros::EnumIter;
 
@@ -16,16 +26,20 @@ pub(crate) fn execute_precompiled(
     #[cfg(not(feature = "scroll"))]
     let precompiles = Precompiles::berlin();
 
-    let Some(Precompile::Standard(precompile_fn)) =
-        precompiles.get(address.as_fixed_bytes().into())
-    else {
-        panic!("calling non-exist precompiled contract address")
-    };
     log::trace!(
         "calling precompile with gas {gas}, len {}, data {}",
         input.len(),
         hex::encode(input)
     );
+    // the secp256r1 verification precompile is not provided by revm.
+    if *address == P256_VERIFY_PRECOMPILE_ADDRESS {
+        return p256_verify(input, gas);
+    }
+    let Some(Precompile::Standard(precompile_fn)) =
+        precompiles.get(address.as_fixed_bytes().into())
+    else {
+        panic!("calling non-exist precompiled contract address")
+    };
     let (return_data, gas_cost, is_oog, is_ok) = match precompile_fn(&input.to_vec().into(), gas) {
         Ok(output) => (output.bytes.to_vec(), output.gas_used, false, true),
         Err(PrecompileErrors::Error(PrecompileError::OutOfGas)) => (vec![], gas, true, false),
@@ -41,6 +55,26 @@ pub(crate) fn execute_precompiled(
     (return_data, gas_cost, is_oog)
 }
 
+/// RIP-7212 secp256r1 signature verification. The output is 32 bytes encoding 1 if the signature
+/// is valid, and empty otherwise. The call itself only fails when running out of gas.
+fn p256_verify(input: &[u8], gas: u64) -> (Vec<u8>, u64, bool) {
+    let gas_cost = GasCost::PRECOMPILE_P256VERIFY.as_u64();
+    if gas < gas_cost {
+        return (vec![], gas, true);
+    }
+    let is_valid = P256VerifyAuxData::new(input, &[], &[])
+        .sign_data()
+        .map(|sign_data| sign_data.is_valid())
+        .unwrap_or_default();
+    let output = if is_valid {
+        Word::one().to_be_bytes().to_vec()
+    } else {
+        vec![]
+    };
+    log::trace!("called p256 verify precompile with is_valid {is_valid}");
+    (output, gas_cost, false)
+}
+
 /// Addresses of the precompiled contracts.
 #[derive(Copy, Clone, Debug, Eq, PartialEq, EnumIter)]
 pub enum PrecompileCalls {
@@ -62,6 +96,32 @@ pub enum PrecompileCalls {
     Bn128Pairing = 0x08,
     /// Compression function
     Blake2F = 0x09,
+    /// secp256r1 signature verification (RIP-7212)
+    P256Verify = 0x100,
+}
+
+/// The last precompiled contract of the contiguous range starting at 0x01. The EIP-4844 point
+/// evaluation precompile at 0x0a is not implemented: verifying its KZG proof requires a
+/// BLS12-381 pairing check that no circuit provides, so calls to 0x0a are handled as calls to an
+/// account without code.
+pub const LAST_PRECOMPILE: PrecompileCalls = PrecompileCalls::Blake2F;
+
+/// Whether the secp256r1 verification precompile at 0x100 is enabled. It lives outside of the
+/// contiguous range ending at [`LAST_PRECOMPILE`] and is only enabled in scroll.
+pub const P256_VERIFY_ENABLED: bool = cfg!(feature = "scroll");
+
+/// Addresses of the precompiled contracts that are added to the access list at the beginning of
+/// a transaction.
+pub const PRECOMPILE_WARM_ADDRESSES: &[u64] = if P256_VERIFY_ENABLED {
+    &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0x100]
+} else {
+    &[1, 2, 3, 4, 5, 6, 7, 8, 9]
+};
+
+/// Check if address is an enabled precompiled contract.
+pub fn is_precompiled(address: &Address) -> bool {
+    (address.0[0..19] == [0u8; 19] && (1..=LAST_PRECOMPILE as u8).contains(&address.0[19]))
+        || (P256_VERIFY_ENABLED && *address == P256_VERIFY_PRECOMPILE_ADDRESS)
 }
 
 impl Default for PrecompileCalls {
@@ -73,7 +133,7 @@ impl Default for PrecompileCalls {
 impl From<PrecompileCalls> for Address {
     fn from(value: PrecompileCalls) -> Self {
         let mut addr = [0u8; 20];
-        addr[19] = value as u8;
+        addr[18..].copy_from_slice(&(value as u16).to_be_bytes());
         Self(addr)
     }
 }
@@ -107,6 +167,16 @@ impl From<u8> for PrecompileCalls {
     }
 }
 
+impl From<Address> for PrecompileCalls {
+    fn from(value: Address) -> Self {
+        if value == P256_VERIFY_PRECOMPILE_ADDRESS {
+            Self::P256Verify
+        } else {
+            value.0[19].into()
+        }
+    }
+}
+
 impl PrecompileCalls {
     /// Get the base gas cost for the precompile call.
     pub fn base_gas_cost(&self) -> GasCost {
@@ -120,6 +190,7 @@ impl PrecompileCalls {
             Self::Bn128Mul => GasCost::PRECOMPILE_BN256MUL,
             Self::Bn128Pairing => GasCost::PRECOMPILE_BN256PAIRING,
             Self::Blake2F => GasCost::PRECOMPILE_BLAKE2F,
+            Self::P256Verify => GasCost::PRECOMPILE_P256VERIFY,
         }
     }
 
@@ -196,24 +267,101 @@ impl EcrecoverAuxData {
     }
 }
 
+/// Length of a well-formed P256Verify input: [msg_hash | r | s | x | y].
+pub const P256_VERIFY_INPUT_LEN: usize = 160;
+
+/// Auxiliary data for P256Verify
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct P256VerifyAuxData {
+    /// Hash of the message being signed.
+    pub msg_hash: Word,
+    /// r-component of signature.
+    pub sig_r: Word,
+    /// s-component of signature.
+    pub sig_s: Word,
+    /// x co-ordinate of the public key.
+    pub pk_x: Word,
+    /// y co-ordinate of the public key.
+    pub pk_y: Word,
+    /// Whether the signature was verified.
+    pub is_valid: bool,
+    /// Input bytes to the p256 verify call.
+    pub input_bytes: Vec<u8>,
+    /// Output bytes from the p256 verify call.
+    pub output_bytes: Vec<u8>,
+    /// Bytes returned to the caller from the p256 verify call.
+    pub return_bytes: Vec<u8>,
+}
+
+impl P256VerifyAuxData {
+    /// Create a new instance of p256 verify auxiliary data.
+    pub fn new(input: &[u8], output: &[u8], return_bytes: &[u8]) -> Self {
+        let mut resized_input = input.to_vec();
+        resized_input.resize(P256_VERIFY_INPUT_LEN, 0u8);
+
+        Self {
+            msg_hash: Word::from_big_endian(&resized_input[0x00..0x20]),
+            sig_r: Word::from_big_endian(&resized_input[0x20..0x40]),
+            sig_s: Word::from_big_endian(&resized_input[0x40..0x60]),
+            pk_x: Word::from_big_endian(&resized_input[0x60..0x80]),
+            pk_y: Word::from_big_endian(&resized_input[0x80..0xa0]),
+            is_valid: !output.is_empty(),
+            input_bytes: input.to_vec(),
+            output_bytes: output.to_vec(),
+            return_bytes: return_bytes.to_vec(),
+        }
+    }
+
+    /// Returns the signature data to be verified by the sig circuit if the input is well-formed,
+    /// i.e. it is exactly 160 bytes, 0 < r, s < n, (x, y) < p and (x, y) != (0, 0). The public
+    /// key is not required to be on the curve, the sig circuit reports such signatures as invalid.
+    pub fn sign_data(&self) -> Option<SignData<Fq, Secp256r1Affine>> {
+        if self.input_bytes.len() != P256_VERIFY_INPUT_LEN {
+            return None;
+        }
+        let sig_r: Option<Fq> = Fq::from_repr(self.sig_r.to_le_bytes()).into();
+        let sig_s: Option<Fq> = Fq::from_repr(self.sig_s.to_le_bytes()).into();
+        let pk_x: Option<Fp> = Fp::from_repr(self.pk_x.to_le_bytes()).into();
+        let pk_y: Option<Fp> = Fp::from_repr(self.pk_y.to_le_bytes()).into();
+        let (sig_r, sig_s, pk_x, pk_y) = (sig_r?, sig_s?, pk_x?, pk_y?);
+        if self.sig_r.is_zero()
+            || self.sig_s.is_zero()
+            || (self.pk_x.is_zero() && self.pk_y.is_zero())
+        {
+            return None;
+        }
+
+        Some(SignData {
+            signature: (sig_r, sig_s, 0),
+            pk: Secp256r1Affine { x: pk_x, y: pk_y },
+            msg: Bytes::default(),
+            msg_hash: {
+                let msg_hash = BigUint::from_bytes_be(&self.msg_hash.to_be_bytes());
+                let msg_hash = msg_hash.mod_floor(&*SECP256R1_Q);
+                let msg_hash_le = biguint_to_32bytes_le(msg_hash);
+                Fq::from_repr(msg_hash_le).unwrap()
+            },
+        })
+    }
+}
+
 /// size limit of modexp
-pub const MODEXP_SIZE_LIMIT: usize = 32;
+pub const MODEXP_SIZE_LIMIT: usize = 512;
 /// size of input limit
-pub const MODEXP_INPUT_LIMIT: usize = 192;
+pub const MODEXP_INPUT_LIMIT: usize = 96 + 3 * MODEXP_SIZE_LIMIT;
 
 /// Auxiliary data for Modexp
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct ModExpAuxData {
     /// The specified len of inputs: [base, exp, modulus]
     pub input_lens: [Word; 3],
-    /// Input value [base, exp, modulus], limited to SIZE_LIMIT
-    pub inputs: [[u8; MODEXP_SIZE_LIMIT]; 3],
+    /// Input value [base, exp, modulus] in big-endian, each as long as its specified len, or empty
+    /// if the input is invalid
+    pub inputs: [Vec<u8>; 3],
     /// Input valid.
     pub valid: bool,
     /// len of output, limited to lens of moduls, but can be 0
     pub output_len: usize,
-    /// output of modexp.
-    pub output: [u8; MODEXP_SIZE_LIMIT],
     /// Input to the modexp call.
     pub input_bytes: Vec<u8>,
     /// Output bytes from the modexp call.
@@ -223,19 +371,6 @@ pub struct ModExpAuxData {
 }
 
 impl ModExpAuxData {
-    /// If mem is smaller than U256, left pad zero
-    /// Or else, keep the least 32bytes.
-    fn parse_memory_to_value(mem: &[u8]) -> [u8; MODEXP_SIZE_LIMIT] {
-        let mut value_bytes = [0u8; MODEXP_SIZE_LIMIT];
-        if !mem.is_empty() {
-            let copy_len = mem.len().min(MODEXP_SIZE_LIMIT);
-            let src_offset = mem.len() - copy_len;
-            let dst_offset = MODEXP_SIZE_LIMIT - copy_len;
-            value_bytes.as_mut_slice()[dst_offset..].copy_from_slice(&mem[src_offset..]);
-        }
-        value_bytes
-    }
-
     /// check input
     pub fn check_input(input: &[u8]) -> (bool, [Word; 3]) {
         let mut i = input.chunks(32);
@@ -260,41 +395,29 @@ impl ModExpAuxData {
             resized_input.resize(96, 0);
         }
 
-        let (input_valid, [base_len, exp_len, modulus_len]) = Self::check_input(&resized_input);
-
-        let base_mem_len = if input_valid { base_len.as_usize() } else { 0 };
-        let exp_mem_len = if input_valid { exp_len.as_usize() } else { 0 };
-        let modulus_mem_len = if input_valid {
-            modulus_len.as_usize()
+        let (input_valid, input_lens) = Self::check_input(&resized_input);
+
+        let inputs = if input_valid {
+            let lens = input_lens.map(|len| len.as_usize());
+            let expected_len = 96 + lens.iter().sum::<usize>();
+            if resized_input.len() < expected_len {
+                resized_input.resize(expected_len, 0);
+            }
+            let mut rest = &resized_input[96..];
+            lens.map(|len| {
+                let (value, tail) = rest.split_at(len);
+                rest = tail;
+                value.to_vec()
+            })
         } else {
-            0
+            Default::default()
         };
 
-        let (base, exp, modulus) = if base_mem_len == 0 && modulus_mem_len == 0 {
-            // special case
-            ([0u8; 32], [0u8; 32], [0u8; 32])
-        } else {
-            // In non scroll mode, this can be dangerous.
-            // If base and mod are all 0, exp can be very huge.
-            resized_input.resize(96 + base_mem_len + exp_mem_len + modulus_mem_len, 0);
-            let mut cur_input_begin = &resized_input[96..];
-
-            let base = Self::parse_memory_to_value(&cur_input_begin[..base_mem_len]);
-            cur_input_begin = &cur_input_begin[base_mem_len..];
-            let exp = Self::parse_memory_to_value(&cur_input_begin[..exp_mem_len]);
-            cur_input_begin = &cur_input_begin[exp_mem_len..];
-            let modulus = Self::parse_memory_to_value(&cur_input_begin[..modulus_mem_len]);
-            (base, exp, modulus)
-        };
-        let output_len = output.len();
-        let output = Self::parse_memory_to_value(output);
-
         Self {
             valid: input_valid,
-            input_lens: [base_len, exp_len, modulus_len],
-            inputs: [base, exp, modulus],
-            output,
-            output_len,
+            input_lens,
+            inputs,
+            output_len: output.len(),
             input_bytes: input.to_vec(),
             output_bytes: output.to_vec(),
             return_bytes: return_bytes.to_vec(),
@@ -410,15 +533,6 @@ pub enum EcPairingError {
 /// Auxiliary data attached to an internal state for precompile verification.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum PrecompileAuxData {
-    /// Base precompile (used for SHA256, RIPEMD-160 and BLAKE2F).
-    Base {
-        /// input bytes to the identity call.
-        input_bytes: Vec<u8>,
-        /// output bytes from the identity call.
-        output_bytes: Vec<u8>,
-        /// bytes returned back to the caller from the identity call.
-        return_bytes: Vec<u8>,
-    },
     /// Identity.
     Identity {
         /// input bytes to the identity call.
@@ -437,6 +551,26 @@ pub enum PrecompileAuxData {
         /// bytes returned back to the caller from the sha256 call.
         return_bytes: Vec<u8>,
     },
+    /// RIPEMD-160
+    Ripemd160 {
+        /// input bytes to the ripemd160 call.
+        input_bytes: Vec<u8>,
+        /// output bytes from the ripemd160 call.
+        output_bytes: Vec<u8>,
+        /// bytes returned back to the caller from the ripemd160 call.
+        return_bytes: Vec<u8>,
+    },
+    /// BLAKE2F
+    Blake2F {
+        /// input bytes to the blake2f call.
+        input_bytes: Vec<u8>,
+        /// output bytes from the blake2f call.
+        output_bytes: Vec<u8>,
+        /// bytes returned back to the caller from the blake2f call.
+        return_bytes: Vec<u8>,
+    },
+    /// secp256r1 signature verification.
+    P256Verify(P256VerifyAuxData),
     /// Ecrecover.
     Ecrecover(EcrecoverAuxData),
     /// Modexp.
@@ -196,10 +196,18 @@ impl GasCost {
     pub const PRECOMPILE_MODEXP_MIN: Self = Self(200);
     /// Base gas cost for precompile call: BLAKE2F
     pub const PRECOMPILE_BLAKE2F: Self = Self(0);
+    /// Per-round gas cost for BLAKE2F
+    pub const PRECOMPILE_BLAKE2F_PER_ROUND: Self = Self(1);
+    /// Gas cost for precompile call: secp256r1 signature verification (RIP-7212)
+    pub const PRECOMPILE_P256VERIFY: Self = Self(3_450);
     /// Gas cost per address in tx access list (EIP 2930)
     pub const ACCESS_LIST_PER_ADDRESS: Self = Self(2400);
     /// Gas cost per storage key in tx access list (EIP 2930)
     pub const ACCESS_LIST_PER_STORAGE_KEY: Self = Self(1900);
+    /// Gas cost per tuple in tx authorization list (EIP 7702)
+    pub const AUTHORIZATION_PER_EMPTY_ACCOUNT: Self = Self(25000);
+    /// Gas cost per tuple in tx authorization list if the authority exists (EIP 7702)
+    pub const AUTHORIZATION_PER_AUTH_BASE: Self = Self(12500);
 }
 
 impl GasCost {
@@ -27,3 +27,31 @@ pub fn is_valid_block_number(block_number: U256, current_block_number: U256) ->
                 .checked_sub(NUM_PREV_BLOCK_ALLOWED.into())
                 .unwrap_or_default()
 }
+
+/// Minimum base fee per blob gas, defined in EIP-4844.
+pub const MIN_BASE_FEE_PER_BLOB_GAS: u64 = 1;
+/// Controls the maximum rate of change for the blob base fee, defined in EIP-4844.
+pub const BLOB_BASE_FEE_UPDATE_FRACTION: u64 = 3_338_477;
+
+/// Calculate the blob base fee (returned by BLOBBASEFEE) from the block's excess blob gas.
+/// <https://eips.ethereum.org/EIPS/eip-4844#gas-accounting>
+pub fn calc_blob_base_fee(excess_blob_gas: u64) -> U256 {
+    fake_exponential(
+        MIN_BASE_FEE_PER_BLOB_GAS.into(),
+        excess_blob_gas.into(),
+        BLOB_BASE_FEE_UPDATE_FRACTION.into(),
+    )
+}
+
+/// Approximate `factor * e ** (numerator / denominator)` using Taylor expansion.
+fn fake_exponential(factor: U256, numerator: U256, denominator: U256) -> U256 {
+    let mut output = U256::zero();
+    let mut numerator_accum = factor * denominator;
+    let mut i = U256::one();
+    while !numerator_accum.is_zero() {
+        output += numerator_accum;
+        numerator_accum = numerator_accum * numerator / (denominator * i);
+        i += U256::one();
+    }
+    output / denominator
+}
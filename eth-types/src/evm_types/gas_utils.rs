@@ -1,7 +1,7 @@
 //! Utility functions to help calculate gas
 
 use super::GasCost;
-use crate::{AccessList, Word};
+use crate::{geth_types::Authorization, AccessList, Word};
 
 /// Calculate memory expansion gas cost by current and next memory word size.
 pub fn memory_expansion_gas_cost(curr_memory_word_size: u64, next_memory_word_size: u64) -> u64 {
@@ -61,6 +61,11 @@ pub fn tx_access_list_gas_cost(access_list: &Option<AccessList>) -> u64 {
     })
 }
 
+/// Calculate intrinsic gas cost for the authorization list of EIP-7702 transaction.
+pub fn tx_authorization_list_gas_cost(authorization_list: &[Authorization]) -> u64 {
+    authorization_list.len() as u64 * GasCost::AUTHORIZATION_PER_EMPTY_ACCOUNT.as_u64()
+}
+
 /// Calculate gas cost for transaction data.
 pub fn tx_data_gas_cost(data: &[u8]) -> u64 {
     data.iter()
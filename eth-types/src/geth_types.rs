@@ -3,7 +3,10 @@
 #[cfg(feature = "scroll")]
 use crate::l2_types::BlockTrace;
 use crate::{
-    sign_types::{biguint_to_32bytes_le, ct_option_ok_or, recover_pk2, SignData, SECP256K1_Q},
+    sign_types::{
+        biguint_to_32bytes_le, ct_option_ok_or, recover_pk2, SignData, SECP256K1_HALF_Q,
+        SECP256K1_Q,
+    },
     AccessList, Address, Block, Bytes, Error, GethExecTrace, Hash, ToBigEndian, ToLittleEndian,
     Word, U64,
 };
@@ -11,13 +14,14 @@ use ethers_core::types::{
     transaction::eip2718::TypedTransaction, Eip1559TransactionRequest, Eip2930TransactionRequest,
     NameOrAddress, TransactionRequest, H256,
 };
+use ethers_core::utils::rlp::{Encodable, RlpStream};
 use halo2curves::{
     group::ff::PrimeField,
     secp256k1::{Fq as Fq_K1, Secp256k1Affine},
 };
 use num::Integer;
 use num_bigint::BigUint;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Serialize, Serializer};
 use serde_with::serde_as;
 use sha3::{Digest, Keccak256};
 use std::collections::HashMap;
@@ -37,6 +41,8 @@ pub enum TxType {
     Eip2930,
     /// L1 Message tx
     L1Msg,
+    /// EIP 7702 set-code tx
+    Eip7702,
 }
 
 impl From<TxType> for usize {
@@ -52,6 +58,9 @@ impl From<TxType> for u64 {
 }
 
 impl TxType {
+    /// EIP-2718 type byte of EIP-7702 set-code txs
+    pub const EIP7702_TX_TYPE: u8 = 4;
+
     /// If this type is L1Msg or not
     pub fn is_l1_msg(&self) -> bool {
         matches!(*self, Self::L1Msg)
@@ -77,11 +86,17 @@ impl TxType {
         matches!(*self, TxType::Eip2930)
     }
 
+    /// If this type is Eip7702 or not
+    pub fn is_eip7702(&self) -> bool {
+        matches!(*self, TxType::Eip7702)
+    }
+
     /// Get the type of transaction
     pub fn get_tx_type(tx: &crate::Transaction) -> Self {
         match tx.transaction_type {
             Some(x) if x == U64::from(1) => Self::Eip2930,
             Some(x) if x == U64::from(2) => Self::Eip1559,
+            Some(x) if x == U64::from(Self::EIP7702_TX_TYPE) => Self::Eip7702,
             Some(x) if x == U64::from(0x7e) => Self::L1Msg,
             _ => {
                 if cfg!(feature = "scroll") {
@@ -122,6 +137,10 @@ impl TxType {
             TxType::L1Msg => {
                 unreachable!("L1 msg does not have signature")
             }
+            TxType::Eip7702 => {
+                assert!(v <= 1);
+                v
+            }
         };
 
         recovery_id as u8
@@ -158,6 +177,143 @@ pub fn get_rlp_unsigned(tx: &crate::Transaction) -> Vec<u8> {
             // L1 msg does not have signature
             vec![]
         }
+        TxType::Eip7702 => {
+            let mut stream = RlpStream::new_list(10);
+            append_eip7702_fields(&mut stream, tx);
+            [&[TxType::EIP7702_TX_TYPE], stream.as_raw()].concat()
+        }
+    }
+}
+
+/// Get the signed RLP bytes of the tx.
+/// ethers-core does not know the EIP-7702 envelope, so it is encoded manually.
+pub fn get_rlp_signed(tx: &crate::Transaction) -> Vec<u8> {
+    match TxType::get_tx_type(tx) {
+        TxType::Eip7702 => {
+            let mut stream = RlpStream::new_list(13);
+            append_eip7702_fields(&mut stream, tx);
+            stream.append(&tx.v);
+            stream.append(&tx.r);
+            stream.append(&tx.s);
+            [&[TxType::EIP7702_TX_TYPE], stream.as_raw()].concat()
+        }
+        _ => tx.rlp().to_vec(),
+    }
+}
+
+/// Append the unsigned fields of an EIP-7702 tx:
+/// `[chain_id, nonce, max_priority_fee_per_gas, max_fee_per_gas, gas_limit, destination, value,
+/// data, access_list, authorization_list]`
+fn append_eip7702_fields(stream: &mut RlpStream, tx: &crate::Transaction) {
+    stream.append(&tx.chain_id.unwrap_or_default());
+    stream.append(&tx.nonce);
+    stream.append(&tx.max_priority_fee_per_gas.unwrap_or_default());
+    stream.append(&tx.max_fee_per_gas.unwrap_or_default());
+    stream.append(&tx.gas);
+    // set-code txs can not be used to create contracts.
+    stream.append(&tx.to.expect("EIP-7702 tx must have a destination"));
+    stream.append(&tx.value);
+    stream.append(&tx.input);
+    stream.append(&tx.access_list.clone().unwrap_or_default());
+    stream.append_list(&get_authorization_list(tx));
+}
+
+/// Get the EIP-7702 authorization list of the tx, it is empty for all other tx types.
+pub fn get_authorization_list(tx: &crate::Transaction) -> Vec<Authorization> {
+    tx.other
+        .get_deserialized("authorizationList")
+        .and_then(Result::ok)
+        .unwrap_or_default()
+}
+
+/// Magic byte prepended to the RLP encoding of an authorization tuple before hashing.
+pub const AUTHORIZATION_MAGIC: u8 = 0x05;
+
+/// Maximum number of authorization tuples of a set-code tx that the circuits can process.
+pub const MAX_AUTHORIZATIONS_PER_TX: usize = 4;
+
+/// EIP-7702 authorization tuple `[chain_id, address, nonce, y_parity, r, s]`, which lets the
+/// authority (the signer) delegate the code of its account to `address`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Authorization {
+    /// Chain id the authorization is valid on, zero means any chain.
+    pub chain_id: Word,
+    /// Address the authority delegates its code to.
+    pub address: Address,
+    /// Expected nonce of the authority.
+    pub nonce: U64,
+    /// "y_parity" value of the signature
+    pub y_parity: U64,
+    /// "r" value of the signature
+    pub r: Word,
+    /// "s" value of the signature
+    pub s: Word,
+}
+
+impl Encodable for Authorization {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(6);
+        s.append(&self.chain_id);
+        s.append(&self.address);
+        s.append(&self.nonce);
+        s.append(&self.y_parity);
+        s.append(&self.r);
+        s.append(&self.s);
+    }
+}
+
+impl Authorization {
+    /// The signed message: `MAGIC || rlp([chain_id, address, nonce])`
+    pub fn rlp_unsigned(&self) -> Vec<u8> {
+        let mut stream = RlpStream::new_list(3);
+        stream.append(&self.chain_id);
+        stream.append(&self.address);
+        stream.append(&self.nonce);
+        [&[AUTHORIZATION_MAGIC], stream.as_raw()].concat()
+    }
+
+    /// secp256k1 method:
+    /// Return the SignData associated with this authorization. Signatures with a high `s` are
+    /// rejected as required by EIP-2.
+    pub fn sign_data(&self) -> Result<SignData<Fq_K1, Secp256k1Affine>, Error> {
+        if self.y_parity > U64::one() || self.s > *SECP256K1_HALF_Q {
+            return Err(Error::Signature);
+        }
+        let sig_r_le = self.r.to_le_bytes();
+        let sig_s_le = self.s.to_le_bytes();
+        let sig_r = ct_option_ok_or(Fq_K1::from_repr(sig_r_le), Error::Signature)?;
+        let sig_s = ct_option_ok_or(Fq_K1::from_repr(sig_s_le), Error::Signature)?;
+        let msg = self.rlp_unsigned().into();
+        let msg_hash: [u8; 32] = Keccak256::digest(&msg)
+            .as_slice()
+            .to_vec()
+            .try_into()
+            .expect("hash length isn't 32 bytes");
+        let v = self.y_parity.as_u64() as u8;
+        let pk = recover_pk2(v, &self.r, &self.s, &msg_hash)?;
+        // msg_hash = msg_hash % q
+        let msg_hash = BigUint::from_bytes_be(msg_hash.as_slice());
+        let msg_hash = msg_hash.mod_floor(&*SECP256K1_Q);
+        let msg_hash_le = biguint_to_32bytes_le(msg_hash);
+        let msg_hash = ct_option_ok_or(Fq_K1::from_repr(msg_hash_le), Error::Signature)?;
+        Ok(SignData {
+            signature: (sig_r, sig_s, v),
+            pk,
+            msg,
+            msg_hash,
+        })
+    }
+
+    /// Return the authority of the tuple if it is valid on the chain `chain_id`, i.e. the chain
+    /// id matches (or is zero), the nonce can still be increased and the signature recovers.
+    pub fn authority(&self, chain_id: u64) -> Option<Address> {
+        if !(self.chain_id.is_zero() || self.chain_id == Word::from(chain_id))
+            || self.nonce == U64::MAX
+        {
+            return None;
+        }
+        self.sign_data().ok().map(|sign_data| sign_data.get_addr())
     }
 }
 
@@ -280,6 +436,8 @@ pub struct Transaction {
     pub call_data: Bytes,
     /// Access list
     pub access_list: Option<AccessList>,
+    /// Authorization list (EIP 7702)
+    pub authorization_list: Vec<Authorization>,
 
     /// "v" value of the transaction signature
     pub v: u64,
@@ -300,7 +458,7 @@ pub struct Transaction {
 
 impl From<&Transaction> for crate::Transaction {
     fn from(tx: &Transaction) -> crate::Transaction {
-        crate::Transaction {
+        let mut eth_tx = crate::Transaction {
             from: tx.from,
             to: tx.to,
             nonce: tx.nonce,
@@ -316,7 +474,16 @@ impl From<&Transaction> for crate::Transaction {
             s: tx.s,
             hash: tx.hash,
             ..Default::default()
+        };
+        if tx.tx_type.is_eip7702() {
+            eth_tx.transaction_type = Some(TxType::EIP7702_TX_TYPE.into());
+            eth_tx.other.insert(
+                "authorizationList".to_string(),
+                serde_json::to_value(&tx.authorization_list)
+                    .expect("authorization list is serializable"),
+            );
         }
+        eth_tx
     }
 }
 
@@ -334,10 +501,11 @@ impl From<&crate::Transaction> for Transaction {
             gas_fee_cap: tx.max_fee_per_gas,
             call_data: tx.input.clone(),
             access_list: tx.access_list.clone(),
+            authorization_list: get_authorization_list(tx),
             v: tx.v.as_u64(),
             r: tx.r,
             s: tx.s,
-            rlp_bytes: tx.rlp().to_vec(),
+            rlp_bytes: get_rlp_signed(tx),
             rlp_unsigned_bytes: get_rlp_unsigned(tx),
             hash: tx.hash,
         }
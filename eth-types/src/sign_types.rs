@@ -80,7 +80,9 @@ pub fn verify<
     _v: Option<bool>,
 ) -> bool {
     // Verify
-    let s_inv = s.invert().unwrap();
+    let Some(s_inv) = Option::<Fq>::from(s.invert()) else {
+        return false;
+    };
     let u_1 = msg_hash * s_inv;
     let u_2 = r * s_inv;
 
@@ -89,7 +91,12 @@ pub fn verify<
 
     let u2_affine = pub_key * u_2;
 
-    let r_point = (u1_affine + u2_affine).to_affine().coordinates().unwrap();
+    // the signature is invalid if u1 * G + u2 * pk is the point at infinity.
+    let Some(r_point) =
+        Option::<Coordinates<_>>::from((u1_affine + u2_affine).to_affine().coordinates())
+    else {
+        return false;
+    };
     let x_candidate = r_point.x();
     let r_candidate = mod_n(*x_candidate);
 
@@ -178,6 +185,16 @@ impl SignData<Fq_R1, Secp256r1Affine> {
         let pk_hash = keccak256(pk_bytes_swap_endianness(&pk_bytes_le_generic(&self.pk)));
         Address::from_slice(&pk_hash[12..])
     }
+
+    /// Whether the public key is a point on the curve other than the identity and the signature
+    /// is valid under it.
+    pub fn is_valid(&self) -> bool {
+        let pk_is_valid: bool = (self.pk.is_on_curve() & !self.pk.is_identity()).into();
+        let (sig_r, sig_s, _) = self.signature;
+        pk_is_valid
+            && sig_r != Fq_R1::zero()
+            && verify::<Fp_R1, Fq_R1, Secp256r1Affine>(self.pk, sig_r, sig_s, self.msg_hash, None)
+    }
 }
 
 static SIGN_DATA_DEFAULT: LazyLock<SignData<Fq_K1, Secp256k1Affine>> = LazyLock::new(|| {
@@ -303,6 +320,15 @@ pub fn recover_pk2(
 pub static SECP256K1_Q: LazyLock<BigUint> =
     LazyLock::new(|| BigUint::from_bytes_le(&(Fq_K1::zero() - Fq_K1::one()).to_repr()) + 1u64);
 
+/// Half of the secp256k1 curve order, the upper bound of `s` in signatures that follow EIP-2.
+pub static SECP256K1_HALF_Q: LazyLock<Word> =
+    LazyLock::new(|| Word::from_little_endian(&SECP256K1_Q.to_bytes_le()) / 2);
+
+/// Secp256r1 Curve Scalar.  Reference: Section 2.4.2 (parameter `n`) in "SEC 2: Recommended
+/// Elliptic Curve Domain Parameters" document at http://www.secg.org/sec2-v2.pdf
+pub static SECP256R1_Q: LazyLock<BigUint> =
+    LazyLock::new(|| BigUint::from_bytes_le(&(Fq_R1::zero() - Fq_R1::one()).to_repr()) + 1u64);
+
 /// Helper function to convert a `CtOption` into an `Result`.  Similar to
 /// `Option::ok_or`.
 pub fn ct_option_ok_or<T, E>(v: CtOption<T>, err: E) -> Result<T, E> {
@@ -330,14 +356,16 @@ pub fn pk_bytes_le(pk: &Secp256k1Affine) -> [u8; 64] {
 /// Return both secp256k1 and secp256r1 public key (x, y) coordinates in little endian bytes.
 pub fn pk_bytes_le_generic<
     Fp: PrimeField<Repr = [u8; 32]>, // + GroupEncoding<Repr = [u8; 32]>, // 32 bytes for secp256k1 and secp256r1 curve
-    Affine: CurveAffine<Base = Fp>,
+    Affine: CurveAffine<Base = Fp> + CurveAffineExt,
 >(
     pk: &Affine,
 ) -> [u8; 64] {
-    let pk_coord = Option::<Coordinates<_>>::from(pk.coordinates()).expect("point is the identity");
+    // the raw coordinates are used so that public keys which are not on the curve, as accepted by
+    // the p256verify precompile, can still be hashed.
+    let (x, y) = pk.into_coordinates();
     let mut pk_le = [0u8; 64];
-    pk_le[..32].copy_from_slice(&pk_coord.x().to_repr());
-    pk_le[32..].copy_from_slice(&pk_coord.y().to_repr());
+    pk_le[..32].copy_from_slice(&x.to_repr());
+    pk_le[32..].copy_from_slice(&y.to_repr());
     pk_le
 }
 
@@ -2,7 +2,7 @@
 //! Ethereum State Trie.
 
 use crate::{
-    utils::{hash_code, is_precompiled},
+    utils::{hash_code, is_precompiled, parse_delegation_designator},
     Address, Hash, Word, H256, KECCAK_CODE_HASH_EMPTY, U256,
 };
 use std::{
@@ -64,6 +64,13 @@ impl CodeDB {
     pub fn hash(code: &[u8]) -> Hash {
         H256(hash_code(code).into())
     }
+
+    /// Return the address the code delegates to if it is an EIP-7702 delegation designator.
+    pub fn delegated_address(&self, code_hash: &Hash) -> Option<Address> {
+        self.0
+            .get(code_hash)
+            .and_then(|code| parse_delegation_designator(code))
+    }
 }
 
 /// Account of the Ethereum State Trie, which contains an in-memory key-value
@@ -142,6 +149,9 @@ pub struct StateDB {
     // Accounts that have been through `SELFDESTRUCT` under the situation that `is_persistent` is
     // `true`. These accounts will be reset once `commit_tx` is called.
     destructed_account: HashSet<Address>,
+    // Accounts that have been created in current transaction, used by `SELFDESTRUCT` to decide
+    // whether the account should be deleted (EIP-6780).
+    created_account: HashSet<Address>,
     // Accounts that are still "empty", but an Account Rw {value_prev: 0x0, value: empty_code_hash}
     // has already been applied.
     // TODO: a better name?
@@ -240,6 +250,25 @@ impl StateDB {
         }
     }
 
+    /// Get the keys of the non-zero storage slots of [`Account`] at `addr`,
+    /// including the writes of the current transaction, in ascending order.
+    pub fn non_zero_storage_keys(&self, addr: &Address) -> Vec<Word> {
+        let (_, acc) = self.get_account(addr);
+        acc.storage
+            .keys()
+            .chain(
+                self.dirty_storage
+                    .keys()
+                    .filter(|(dirty_addr, _)| dirty_addr == addr)
+                    .map(|(_, key)| key),
+            )
+            .filter(|key| !self.get_storage(addr, key).1.is_zero())
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+
     /// Get a mutable reference to the storage value from [`Account`] at `addr`,
     /// at `key`.  Returns false when the [`Account`] or `key` wasn't found in
     /// the state and it is created.  If the [`Account`] or `key` is not found
@@ -328,6 +357,23 @@ impl StateDB {
         debug_assert!(exist);
     }
 
+    /// Check whether `addr` has been created in current transaction.
+    pub fn check_account_in_created_list(&self, addr: &Address) -> bool {
+        self.created_account.contains(addr)
+    }
+
+    /// Add `addr` into the list of accounts created in current transaction.
+    /// Returns `true` if it's not in the list before.
+    pub fn add_account_to_created_list(&mut self, addr: Address) -> bool {
+        self.created_account.insert(addr)
+    }
+
+    /// Remove `addr` from the list of accounts created in current transaction.
+    pub fn remove_account_from_created_list(&mut self, addr: &Address) {
+        let exist = self.created_account.remove(addr);
+        debug_assert!(exist);
+    }
+
     /// Set account as self destructed.
     pub fn destruct_account(&mut self, addr: Address) {
         self.state.insert(addr, Account::zero());
@@ -356,6 +402,7 @@ impl StateDB {
         }
         self.dirty_storage = HashMap::new();
         self.touched_account = HashSet::new();
+        self.created_account = HashSet::new();
         for addr in self.destructed_account.clone() {
             let (_, account) = self.get_account_mut(&addr);
             *account = ACCOUNT_ZERO.clone();
@@ -428,4 +475,19 @@ mod statedb_tests {
         assert!(found);
         assert_eq!(value, &Word::from(102));
     }
+
+    #[test]
+    fn codedb_delegated_address() {
+        let delegate = address!("0x0000000000000000000000000000000000000003");
+        let mut codedb = CodeDB::new();
+
+        let designator_hash = codedb.insert(crate::utils::delegation_designator(&delegate));
+        assert_eq!(codedb.delegated_address(&designator_hash), Some(delegate));
+
+        // Regular code and unknown code hashes are not delegated.
+        let code_hash = codedb.insert(vec![0xef, 0x01, 0x00]);
+        assert_eq!(codedb.delegated_address(&code_hash), None);
+        assert_eq!(codedb.delegated_address(&CodeDB::empty_code_hash()), None);
+        assert_eq!(codedb.delegated_address(&Hash::zero()), None);
+    }
 }
@@ -6,6 +6,12 @@ use revm_precompile::Precompiles;
 mod codehash;
 pub use codehash::*;
 
+/// Address of the secp256r1 signature verification precompile (RIP-7212). It is not part of the
+/// revm precompile sets, so it is checked separately.
+pub const P256_VERIFY_PRECOMPILE_ADDRESS: Address = crate::H160([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x00,
+]);
+
 /// Check if address is a precompiled or not.
 pub fn is_precompiled(address: &Address) -> bool {
     #[cfg(feature = "scroll")]
@@ -13,4 +19,20 @@ pub fn is_precompiled(address: &Address) -> bool {
     #[cfg(not(feature = "scroll"))]
     let precompiles = Precompiles::berlin();
     precompiles.get(address.as_fixed_bytes().into()).is_some()
+        || (cfg!(feature = "scroll") && *address == P256_VERIFY_PRECOMPILE_ADDRESS)
+}
+
+/// Prefix of the EIP-7702 delegation designator `0xef0100 || address`.
+pub const DELEGATION_PREFIX: [u8; 3] = [0xef, 0x01, 0x00];
+
+/// Code of an account which delegates its code to `address` (EIP-7702).
+pub fn delegation_designator(address: &Address) -> Vec<u8> {
+    [&DELEGATION_PREFIX[..], address.as_bytes()].concat()
+}
+
+/// Return the address which the code delegates to, if it is a delegation designator.
+pub fn parse_delegation_designator(code: &[u8]) -> Option<Address> {
+    (code.len() == DELEGATION_PREFIX.len() + Address::len_bytes()
+        && code.starts_with(&DELEGATION_PREFIX))
+    .then(|| Address::from_slice(&code[DELEGATION_PREFIX.len()..]))
 }
@@ -1,5 +1,6 @@
 //! Some handy helpers
 
+use super::P256_VERIFY_PRECOMPILE_ADDRESS;
 use crate::{Address, Hash};
 use revm_precompile::Precompiles;
 
@@ -10,6 +11,7 @@ pub fn is_precompiled(address: &Address) -> bool {
     #[cfg(not(feature = "scroll"))]
     let precompiles = Precompiles::berlin();
     precompiles.get(address.as_fixed_bytes().into()).is_some()
+        || (cfg!(feature = "scroll") && *address == P256_VERIFY_PRECOMPILE_ADDRESS)
 }
 
 /// Default number of bytes to pack into a field element.
@@ -236,6 +236,8 @@ func Trace(config TraceConfig) ([]*ExecutionResult, error) {
 		Difficulty:  toBigInt(config.Block.Difficulty),
 		Random:      &randao,
 		BaseFee:     toBigInt(config.Block.BaseFee),
+		// No blob-carrying txs, so the blob base fee stays at MIN_BASE_FEE_PER_BLOB_GAS.
+		BlobBaseFee: big.NewInt(1),
 		GasLimit:    blockGasLimit,
 	}
 
@@ -97,6 +97,7 @@ const CIRCUITS_PARAMS: CircuitsParams = CircuitsParams {
         ec_add: MAX_EC_ADD,
         ec_mul: MAX_EC_MUL,
         ec_pairing: MAX_EC_PAIRING,
+        ec_pairing_batched: 0,
     },
 };
 
@@ -44,6 +44,7 @@ const CIRCUITS_PARAMS: CircuitsParams = CircuitsParams {
         ec_add: 10,
         ec_mul: 10,
         ec_pairing: 4,
+        ec_pairing_batched: 0,
     },
 };
 
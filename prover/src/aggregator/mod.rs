@@ -5,7 +5,10 @@ mod prover;
 pub use prover::{check_chunk_hashes, Prover};
 
 mod recursion;
-pub use recursion::RecursionTask;
+pub use recursion::{
+    RecursionTask, BUNDLE_PI_DIGEST, BUNDLE_PI_INIT_STATE, BUNDLE_PI_LEN, BUNDLE_PI_ROUND,
+    BUNDLE_PI_STATE,
+};
 
 mod verifier;
 pub use verifier::Verifier;
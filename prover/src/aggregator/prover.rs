@@ -1,6 +1,6 @@
 use std::{env, path::PathBuf};
 
-use aggregator::{decode_bytes, BatchData, BatchHash, BatchHeader, ChunkInfo, MAX_AGG_SNARKS};
+use aggregator::{decode_bytes, BatchData, BatchHash, BatchHeader, ChunkInfo, MAX_BLOBS};
 use eth_types::H256;
 use sha2::{Digest, Sha256};
 use snark_verifier_sdk::Snark;
@@ -8,7 +8,7 @@ use snark_verifier_sdk::Snark;
 use crate::{
     aggregator::BatchProverError,
     common,
-    config::LayerId,
+    config::{BatchCapacity, LayerId, BATCH_CAPACITY},
     consts::{
         BATCH_KECCAK_ROW, BATCH_VK_FILENAME, BUNDLE_VK_FILENAME, FD_HALO2_CHUNK_PROTOCOL,
         FD_SP1_CHUNK_PROTOCOL,
@@ -48,6 +48,9 @@ pub struct Prover<'params> {
     ///
     /// The verifying key is specified in its raw byte-encoded format.
     raw_vk_bundle: Option<Vec<u8>>,
+    /// The capacity of the [`BatchCircuit`][aggregator::BatchCircuit] at
+    /// [`Layer-3`][LayerId::Layer3], i.e. the max number of chunks in a batch.
+    capacity: BatchCapacity,
 }
 
 impl<'params> Prover<'params> {
@@ -56,6 +59,8 @@ impl<'params> Prover<'params> {
     ///
     /// Panics if the SNARK [`protocols`][snark_verifier::Protocol] for both [`chunk proof variants`][crate::proof::ChunkKind]
     /// are not found in the assets directory.
+    ///
+    /// The capacity of the batch circuit is read from [`BATCH_CAPACITY`].
     pub fn from_params_and_assets(params_map: &'params ParamsMap, assets_dir: &str) -> Self {
         // Set the number of rows in the keccak-circuit's config. The value is eventually read
         // to configure the keccak config at runtime.
@@ -92,15 +97,24 @@ impl<'params> Prover<'params> {
             );
         }
 
+        let capacity = *BATCH_CAPACITY;
+        log::info!("batch-prover: batch capacity of {capacity} chunks");
+
         Self {
             prover_impl,
             halo2_protocol,
             sp1_protocol,
             raw_vk_batch,
             raw_vk_bundle,
+            capacity,
         }
     }
 
+    /// Returns the capacity of the batch circuit, i.e. the max number of chunks in a batch.
+    pub fn capacity(&self) -> BatchCapacity {
+        self.capacity
+    }
+
     /// Returns the optional verifying key for [`Layer-4`][LayerId::Layer4] in byte-encoded form.
     pub fn get_batch_vk(&self) -> Option<Vec<u8>> {
         self.prover_impl
@@ -124,12 +138,23 @@ impl<'params> Prover<'params> {
     ///
     /// Returns early if a batch proof with a matching proof identifier is found on disk in the
     /// provided output directory.
-    pub fn gen_batch_proof(
+    ///
+    /// Returns an error if the task is not meant for the [`capacity`][Self::capacity] of the
+    /// prover.
+    pub fn gen_batch_proof<const N_SNARKS: usize>(
         &mut self,
-        batch: BatchProvingTask,
+        batch: BatchProvingTask<N_SNARKS>,
         name: Option<&str>,
         output_dir: Option<&str>,
     ) -> Result<BatchProofV2, ProverError> {
+        if N_SNARKS != self.capacity.max_chunks() {
+            return Err(BatchProverError::Custom(format!(
+                "batch proving task for a capacity of {N_SNARKS} chunks, but the prover's is {}",
+                self.capacity
+            ))
+            .into());
+        }
+
         // Denotes the identifier for this batch proving task. Eventually a generated proof is
         // cached to disk using this identifier.
         let name = name.map_or_else(|| batch.identifier(), |name| name.to_string());
@@ -143,7 +168,7 @@ impl<'params> Prover<'params> {
 
         // Load from disk or generate the layer-3 SNARK using the batch circuit.
         let (layer3_snark, batch_hash) =
-            self.load_or_gen_last_agg_snark::<MAX_AGG_SNARKS>(batch, &name, output_dir)?;
+            self.load_or_gen_last_agg_snark(batch, &name, output_dir)?;
 
         // Load from disk or generate the layer-4 SNARK using thin compression circuit.
         let layer4_snark = self
@@ -251,15 +276,15 @@ impl<'params> Prover<'params> {
     /// Returns early if the SNARK was located on disk.
     fn load_or_gen_last_agg_snark<const N_SNARKS: usize>(
         &mut self,
-        batch: BatchProvingTask,
+        batch: BatchProvingTask<N_SNARKS>,
         name: &str,
         output_dir: Option<&str>,
     ) -> Result<(Snark, H256), ProverError> {
         // Early return with an error if the number of SNARKs to aggregate is not within limits.
         let num_chunks = batch.chunk_proofs.len();
-        if !(1..=MAX_AGG_SNARKS).contains(&num_chunks) {
+        if !(1..=N_SNARKS).contains(&num_chunks) {
             return Err(BatchProverError::Custom(format!(
-                "1 <= num_chunks <= MAX_AGG_SNARKS, found={num_chunks}"
+                "1 <= num_chunks <= {N_SNARKS}, found={num_chunks}"
             ))
             .into());
         }
@@ -279,8 +304,8 @@ impl<'params> Prover<'params> {
             .map(Snark::try_from)
             .collect::<Result<Vec<Snark>, ProverError>>()?;
 
-        // Pad the SNARKs with the last SNARK until we have MAX_AGG_SNARKS number of SNARKs.
-        if num_chunks < MAX_AGG_SNARKS {
+        // Pad the SNARKs with the last SNARK until we have N_SNARKS number of SNARKs.
+        if num_chunks < N_SNARKS {
             let padding_chunk_info = {
                 let mut last_chunk = chunk_infos.last().expect("num_chunks > 0").clone();
                 last_chunk.is_padding = true;
@@ -288,9 +313,9 @@ impl<'params> Prover<'params> {
             };
             let padding_snark = layer2_snarks.last().expect("num_chunks > 0").clone();
 
-            // Extend to MAX_AGG_SNARKS for both chunk infos and layer-2 snarks.
-            chunk_infos.resize(MAX_AGG_SNARKS, padding_chunk_info);
-            layer2_snarks.resize(MAX_AGG_SNARKS, padding_snark);
+            // Extend to N_SNARKS for both chunk infos and layer-2 snarks.
+            chunk_infos.resize(N_SNARKS, padding_chunk_info);
+            layer2_snarks.resize(N_SNARKS, padding_snark);
         }
 
         // Reconstruct the batch header.
@@ -340,6 +365,27 @@ impl<'params> Prover<'params> {
             ))
             .into());
         }
+        // The extra blobs of a batch spanning several blobs must match.
+        for i in 0..MAX_BLOBS - 1 {
+            if batch_header.extra_blob_versioned_hashes[i]
+                != batch.batch_header.extra_blob_versioned_hashes[i]
+                || batch_header.extra_blob_data_proofs[i]
+                    != batch.batch_header.extra_blob_data_proofs[i]
+            {
+                return Err(BatchProverError::Custom(format!(
+                    "BatchHeader(sanity) extra blob {i} mismatch! expected={:?}, got={:?}",
+                    (
+                        batch.batch_header.extra_blob_versioned_hashes[i],
+                        batch.batch_header.extra_blob_data_proofs[i],
+                    ),
+                    (
+                        batch_header.extra_blob_versioned_hashes[i],
+                        batch_header.extra_blob_data_proofs[i],
+                    ),
+                ))
+                .into());
+            }
+        }
 
         // Build relevant types that are used for batch circuit witness assignments.
         let batch_info: BatchHash<N_SNARKS> =
@@ -6,8 +6,8 @@ use snark_verifier_sdk::Snark;
 const ST_INSTANCE: usize = 4;
 
 /// Additional public inputs, specifically:
-/// - withdraw root (hi, lo)
 /// - chain ID
+/// - withdraw root (hi, lo)
 const ADD_INSTANCE: usize = 3;
 
 /// Number of public inputs to describe the state.
@@ -16,6 +16,28 @@ const NUM_INSTANCES: usize = ST_INSTANCE + ADD_INSTANCE;
 /// Number of public inputs to describe the initial state.
 const NUM_INIT_INSTANCES: usize = ST_INSTANCE;
 
+/// Index of the preprocessed digest in the public inputs of the bundle proof, i.e. the instances
+/// of the [`RecursionCircuit`][aggregator::RecursionCircuit] that follow the accumulator.
+pub const BUNDLE_PI_DIGEST: usize = 0;
+
+/// Index of the initial state in the bundle public inputs:
+/// - (hi, lo) finalised state root
+/// - (hi, lo) finalised batch hash
+pub const BUNDLE_PI_INIT_STATE: usize = BUNDLE_PI_DIGEST + 1;
+
+/// Index of the current state in the bundle public inputs:
+/// - (hi, lo) pending state root
+/// - (hi, lo) pending batch hash
+/// - chain ID
+/// - (hi, lo) pending withdraw root
+pub const BUNDLE_PI_STATE: usize = BUNDLE_PI_INIT_STATE + NUM_INIT_INSTANCES;
+
+/// Index of the recursion round in the bundle public inputs.
+pub const BUNDLE_PI_ROUND: usize = BUNDLE_PI_STATE + NUM_INSTANCES;
+
+/// Number of public inputs of the bundle proof.
+pub const BUNDLE_PI_LEN: usize = BUNDLE_PI_ROUND + 1;
+
 #[derive(Clone, Debug)]
 pub struct RecursionTask<'a, const N_SNARK: usize> {
     /// The [`snarks`][Snark] from the [`BatchCircuit`][aggregator::BatchCircuit].
@@ -0,0 +1,221 @@
+//! Offline spot-checks of the proofs output by the prover.
+//!
+//! ```text
+//! prover-cli verify chunk --proof proof_chunk_0.json --vk vk_chunk_0.vkey --params ./params
+//! prover-cli verify batch --proof proof_batch_0.json --vk vk_batch_0.vkey --params ./params
+//! prover-cli verify bundle --proof proof_bundle_0.json --vk vk_bundle_0.vkey --params ./params
+//! ```
+//!
+//! The shape of the compression circuit, and hence the degree of the KZG setup, is read from the
+//! layer configs in the assets directory, i.e. `SCROLL_PROVER_ASSETS_DIR` or `--assets`.
+use std::{
+    env,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context};
+use clap::{Parser, Subcommand, ValueEnum};
+use halo2_proofs::{halo2curves::bn256::Bn256, poly::kzg::commitment::ParamsKZG};
+use prover::{
+    deserialize_vk, eth_types::H256, load_params, read, read_json_deep, BatchProofV2,
+    BatchVerifier, BundleProofV2, ChunkProofV2, ChunkVerifier, CompressionCircuit, Verifier,
+    BUNDLE_PI_DIGEST, BUNDLE_PI_INIT_STATE, BUNDLE_PI_LEN, BUNDLE_PI_ROUND, BUNDLE_PI_STATE,
+    LAYER2_CONFIG_PATH, LAYER2_DEGREE, LAYER4_CONFIG_PATH, LAYER4_DEGREE, LAYER6_CONFIG_PATH,
+    LAYER6_DEGREE,
+};
+use snark_verifier::pcs::kzg::{Bdfg21, Kzg};
+
+/// Inspect and verify chunk, batch and bundle proofs.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Verify a proof and print its public inputs.
+    Verify {
+        /// Kind of the proof.
+        #[clap(value_enum)]
+        kind: ProofKind,
+
+        /// JSON-encoded proof.
+        #[clap(long)]
+        proof: PathBuf,
+
+        /// Verifying key of the outermost compression circuit.
+        #[clap(long)]
+        vk: PathBuf,
+
+        /// Directory with the KZG setup parameters.
+        #[clap(long)]
+        params: String,
+
+        /// Directory with the layer configs, overrides `SCROLL_PROVER_ASSETS_DIR`.
+        #[clap(long)]
+        assets: Option<PathBuf>,
+
+        /// Deployment code of the verifier contract for bundle proofs. If omitted, it is
+        /// generated from the verifying key, which requires `solc` in PATH.
+        #[clap(long)]
+        evm_verifier: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ProofKind {
+    Chunk,
+    Batch,
+    Bundle,
+}
+
+/// Join the hi and lo halves of a 32-byte value split across two public inputs.
+fn hi_lo(hi: H256, lo: H256) -> H256 {
+    H256::from_slice(&[&hi[16..], &lo[16..]].concat())
+}
+
+/// Point the compression circuit at the shape of the given layer and load its KZG setup.
+fn load_layer_params(
+    params_dir: &str,
+    config_path: &Path,
+    degree: u32,
+) -> anyhow::Result<ParamsKZG<Bn256>> {
+    env::set_var("COMPRESSION_CONFIG", config_path);
+    load_params(params_dir, degree, None)
+        .with_context(|| format!("loading params of degree {degree} from {params_dir}"))
+}
+
+fn verify_chunk(proof: &Path, vk: &Path, params_dir: &str) -> anyhow::Result<()> {
+    let proof: ChunkProofV2 = read_json_deep(proof)?;
+    let raw_vk = read(vk)?;
+    let params = load_layer_params(params_dir, &LAYER2_CONFIG_PATH, *LAYER2_DEGREE)?;
+
+    let chunk_info = proof.inner.chunk_info();
+    println!("chunk kind:         {:?}", proof.inner.chunk_kind());
+    println!("chain id:           {}", chunk_info.chain_id);
+    println!("prev state root:    {:?}", chunk_info.prev_state_root);
+    println!("post state root:    {:?}", chunk_info.post_state_root);
+    println!("withdraw root:      {:?}", chunk_info.withdraw_root);
+    println!("data hash:          {:?}", chunk_info.data_hash);
+    println!("public input hash:  {:?}", chunk_info.public_input_hash());
+
+    // the chunk pi hash is exposed byte by byte.
+    let pi_hash_bytes = proof
+        .public_inputs()?
+        .iter()
+        .map(|word| word[31])
+        .collect::<Vec<_>>();
+    if pi_hash_bytes != chunk_info.public_input_hash().as_bytes() {
+        bail!(
+            "chunk info does not match the public inputs: 0x{}",
+            hex::encode(pi_hash_bytes)
+        );
+    }
+
+    ChunkVerifier::from(Verifier::<CompressionCircuit>::from_params(
+        &params, &raw_vk,
+    ))
+    .verify_chunk_proof(&proof)?;
+    Ok(())
+}
+
+fn verify_batch(proof: &Path, vk: &Path, params_dir: &str) -> anyhow::Result<()> {
+    let proof: BatchProofV2 = read_json_deep(proof)?;
+    let raw_vk = read(vk)?;
+    let params = load_layer_params(params_dir, &LAYER4_CONFIG_PATH, *LAYER4_DEGREE)?;
+
+    let pi = proof.public_inputs()?;
+    if pi.len() != 11 {
+        bail!("expected 11 public inputs, got {}", pi.len());
+    }
+    println!("batch hash:         {:?}", proof.inner.batch_hash);
+    println!("parent state root:  {:?}", hi_lo(pi[0], pi[1]));
+    println!("parent batch hash:  {:?}", hi_lo(pi[2], pi[3]));
+    println!("state root:         {:?}", hi_lo(pi[4], pi[5]));
+    println!("chain id:           {}", pi[8].to_low_u64_be());
+    println!("withdraw root:      {:?}", hi_lo(pi[9], pi[10]));
+
+    let batch_hash = hi_lo(pi[6], pi[7]);
+    if batch_hash != proof.inner.batch_hash {
+        bail!("batch hash does not match the public inputs: {batch_hash:?}");
+    }
+
+    let verifier = BatchVerifier::new(
+        &params,
+        deserialize_vk::<CompressionCircuit>(&raw_vk),
+        vec![],
+    );
+    verifier.verify_batch_proof(&proof)?;
+    Ok(())
+}
+
+fn verify_bundle(
+    proof: &Path,
+    vk: &Path,
+    params_dir: &str,
+    evm_verifier: Option<&Path>,
+) -> anyhow::Result<()> {
+    let proof: BundleProofV2 = read_json_deep(proof)?;
+    let raw_vk = read(vk)?;
+    let params = load_layer_params(params_dir, &LAYER6_CONFIG_PATH, *LAYER6_DEGREE)?;
+
+    let pi = proof.public_inputs()?;
+    if pi.len() != BUNDLE_PI_LEN {
+        bail!("expected {BUNDLE_PI_LEN} public inputs, got {}", pi.len());
+    }
+    let init = &pi[BUNDLE_PI_INIT_STATE..BUNDLE_PI_STATE];
+    let state = &pi[BUNDLE_PI_STATE..BUNDLE_PI_ROUND];
+    println!("preprocessed digest: {:?}", pi[BUNDLE_PI_DIGEST]);
+    println!("pre state root:      {:?}", hi_lo(init[0], init[1]));
+    println!("pre batch hash:      {:?}", hi_lo(init[2], init[3]));
+    println!("post state root:     {:?}", hi_lo(state[0], state[1]));
+    println!("post batch hash:     {:?}", hi_lo(state[2], state[3]));
+    println!("chain id:            {}", state[4].to_low_u64_be());
+    println!("post withdraw root:  {:?}", hi_lo(state[5], state[6]));
+    let round = pi[BUNDLE_PI_ROUND].to_low_u64_be();
+    println!("recursion round:     {round}");
+
+    let vk = deserialize_vk::<CompressionCircuit>(&raw_vk);
+    let deployment_code = match evm_verifier {
+        Some(path) => read(path)?,
+        None => snark_verifier_sdk::gen_evm_verifier::<CompressionCircuit, Kzg<Bn256, Bdfg21>>(
+            &params,
+            &vk,
+            vec![proof.instances.len() / 32],
+            None,
+        ),
+    };
+
+    BatchVerifier::new(&params, vk, deployment_code).verify_bundle_proof(&proof)?;
+    Ok(())
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let Command::Verify {
+        kind,
+        proof,
+        vk,
+        params,
+        assets,
+        evm_verifier,
+    } = Args::parse().command;
+
+    // must happen before the layer configs are first read.
+    if let Some(assets) = assets {
+        env::set_var("SCROLL_PROVER_ASSETS_DIR", assets);
+    }
+
+    match kind {
+        ProofKind::Chunk => verify_chunk(&proof, &vk, &params),
+        ProofKind::Batch => verify_batch(&proof, &vk, &params),
+        ProofKind::Bundle => verify_bundle(&proof, &vk, &params, evm_verifier.as_deref()),
+    }
+    .with_context(|| format!("verifying {kind:?} proof {proof:?}"))?;
+
+    println!("{kind:?} proof verified");
+    Ok(())
+}
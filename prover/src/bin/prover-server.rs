@@ -0,0 +1,84 @@
+//! Local proving daemon, see [`prover::server`] for the protocol.
+//!
+//! ```text
+//! prover-server --socket /tmp/prover.sock --params ./params --assets ./configs
+//! ```
+use std::{
+    env, fs,
+    os::unix::{fs::FileTypeExt, net::UnixListener},
+    path::PathBuf,
+    thread,
+};
+
+use anyhow::{bail, Context};
+use clap::Parser;
+use prover::{
+    server::{serve, JobQueue, LocalProvers},
+    Prover, BATCH_PROVER_DEGREES, CHUNK_PROVER_DEGREES,
+};
+
+/// Serve chunk, batch and bundle proving tasks over a Unix socket.
+#[derive(Parser, Debug)]
+#[clap(author, version, about, long_about = None)]
+struct Args {
+    /// Path of the Unix socket to listen on.
+    #[clap(long)]
+    socket: PathBuf,
+
+    /// Directory with the KZG setup parameters.
+    #[clap(long)]
+    params: String,
+
+    /// Directory with the layer configs and verifying keys, overrides `SCROLL_PROVER_ASSETS_DIR`.
+    #[clap(long)]
+    assets: Option<String>,
+
+    /// Directory to cache the generated proofs in.
+    #[clap(long)]
+    output_dir: Option<String>,
+}
+
+fn main() -> anyhow::Result<()> {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args = Args::parse();
+
+    // must happen before the layer configs are first read.
+    if let Some(assets) = &args.assets {
+        env::set_var("SCROLL_PROVER_ASSETS_DIR", assets);
+    }
+    let assets_dir = prover::ASSETS_DIR.to_string_lossy().into_owned();
+
+    // remove the socket left behind by a previous run.
+    if let Ok(metadata) = fs::symlink_metadata(&args.socket) {
+        if !metadata.file_type().is_socket() {
+            bail!("{:?} exists and is not a socket", args.socket);
+        }
+        fs::remove_file(&args.socket)?;
+    }
+    let listener = UnixListener::bind(&args.socket)
+        .with_context(|| format!("binding to {:?}", args.socket))?;
+
+    let degrees = CHUNK_PROVER_DEGREES
+        .iter()
+        .chain(BATCH_PROVER_DEGREES.iter())
+        .copied()
+        .collect::<Vec<_>>();
+    let params_map = Prover::load_params_map(&args.params, &degrees);
+    let mut provers =
+        LocalProvers::from_params_and_assets(&params_map, &assets_dir, args.output_dir);
+
+    let queue = JobQueue::default();
+    {
+        let queue = queue.clone();
+        thread::spawn(move || {
+            if let Err(e) = serve(listener, queue) {
+                log::error!("stopped accepting connections: {e}");
+            }
+        });
+    }
+    log::info!("listening on {:?}", args.socket);
+
+    queue.work(&mut provers);
+    Ok(())
+}
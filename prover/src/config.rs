@@ -3,6 +3,7 @@ use std::{
     fmt,
     fs::File,
     path::{Path, PathBuf},
+    str::FromStr,
     sync::LazyLock,
 };
 
@@ -27,10 +28,17 @@ pub static LAYER1_CONFIG_PATH: LazyLock<PathBuf> =
 pub static LAYER2_CONFIG_PATH: LazyLock<PathBuf> =
     LazyLock::new(|| asset_file_path("layer2.config"));
 
+/// The capacity of the [`BatchCircuit`][aggregator::BatchCircuit] used at
+/// [`Layer-3`][LayerId::Layer3], i.e. the max number of chunks in a batch.
+pub static BATCH_CAPACITY: LazyLock<BatchCapacity> =
+    LazyLock::new(|| read_env_var("SCROLL_PROVER_BATCH_CAPACITY", BatchCapacity::default()));
+
 /// The path to the [`Config Parameters`][aggregator::ConfigParams] JSON file that define the shape
 /// of the [`Layer-3`][LayerId::Layer3] [`Circuit`][halo2_proofs::plonk::Circuit].
+///
+/// The shape depends on the configured [`BATCH_CAPACITY`].
 pub static LAYER3_CONFIG_PATH: LazyLock<PathBuf> =
-    LazyLock::new(|| asset_file_path("layer3.config"));
+    LazyLock::new(|| asset_file_path(&BATCH_CAPACITY.asset_filename("layer3", "config")));
 
 /// The path to the [`Config Parameters`][aggregator::ConfigParams] JSON file that define the shape
 /// of the [`Layer-4`][LayerId::Layer4] [`Circuit`][halo2_proofs::plonk::Circuit].
@@ -92,6 +100,68 @@ pub static BATCH_PROVER_DEGREES: LazyLock<Vec<u32>> = LazyLock::new(|| {
     ]))
 });
 
+/// The prebuilt capacity variants of the [`BatchCircuit`][aggregator::BatchCircuit], i.e. the
+/// max number of chunks that can be aggregated in a batch.
+///
+/// Every variant has its own [`Layer-3`][LayerId::Layer3] circuit, and hence its own proving and
+/// verifying keys for the batch and bundle proofs. The Layer-3 config of each variant is shipped in
+/// `aggregator/configs`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BatchCapacity {
+    /// Up to 15 chunks per batch.
+    Chunks15,
+    /// Up to [`MAX_AGG_SNARKS`][aggregator::MAX_AGG_SNARKS], i.e. 45, chunks per batch.
+    #[default]
+    Chunks45,
+    /// Up to [`MAX_BATCH_CAPACITY`][aggregator::MAX_BATCH_CAPACITY], i.e. 90, chunks per batch.
+    Chunks90,
+}
+
+impl fmt::Display for BatchCapacity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.max_chunks())
+    }
+}
+
+impl FromStr for BatchCapacity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "15" => Ok(Self::Chunks15),
+            "45" => Ok(Self::Chunks45),
+            "90" => Ok(Self::Chunks90),
+            _ => Err(format!(
+                "unsupported batch capacity: {s}, expected one of 15, 45, 90"
+            )),
+        }
+    }
+}
+
+impl BatchCapacity {
+    /// The max number of chunks in a batch, i.e. the `N_SNARKS` of the
+    /// [`BatchCircuit`][aggregator::BatchCircuit].
+    pub const fn max_chunks(&self) -> usize {
+        match self {
+            Self::Chunks15 => 15,
+            Self::Chunks45 => aggregator::MAX_AGG_SNARKS,
+            Self::Chunks90 => aggregator::MAX_BATCH_CAPACITY,
+        }
+    }
+
+    /// The name of an asset file that depends on the capacity, such as the
+    /// [`Config Parameters`][aggregator::ConfigParams] for [`Layer-3`][LayerId::Layer3] or the
+    /// verifying keys. The default capacity uses `{stem}.{extension}`, while the other variants
+    /// use `{stem}_{max_chunks}.{extension}`.
+    pub fn asset_filename(&self, stem: &str, extension: &str) -> String {
+        if *self == Self::default() {
+            format!("{stem}.{extension}")
+        } else {
+            format!("{stem}_{}.{extension}", self.max_chunks())
+        }
+    }
+}
+
 /// The various proof layers in the proof generation pipeline.
 #[derive(Clone, Copy, Debug)]
 pub enum LayerId {
@@ -194,3 +264,37 @@ fn layer_degree<P: AsRef<Path> + fmt::Debug>(path: P) -> u32 {
 
     params.degree
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_capacity() {
+        assert_eq!(
+            BatchCapacity::default().max_chunks(),
+            aggregator::MAX_AGG_SNARKS
+        );
+        assert_eq!(
+            BatchCapacity::default().asset_filename("layer3", "config"),
+            "layer3.config"
+        );
+
+        for capacity in [
+            BatchCapacity::Chunks15,
+            BatchCapacity::Chunks45,
+            BatchCapacity::Chunks90,
+        ] {
+            assert_eq!(capacity.to_string().parse(), Ok(capacity));
+        }
+        assert_eq!(
+            BatchCapacity::Chunks15.asset_filename("vk_batch", "vkey"),
+            "vk_batch_15.vkey"
+        );
+        assert_eq!(
+            BatchCapacity::Chunks90.asset_filename("layer3", "config"),
+            "layer3_90.config"
+        );
+        assert!("30".parse::<BatchCapacity>().is_err());
+    }
+}
@@ -1,14 +1,22 @@
 use std::sync::LazyLock;
 
-use crate::utils::read_env_var;
+use crate::{config::BATCH_CAPACITY, utils::read_env_var};
 
 // TODO: is it a good design to use LazyLock? Why not read env var each time?
 
+/// The batch and bundle verifying keys depend on the [`BATCH_CAPACITY`], hence so do their default
+/// filenames.
 pub fn bundle_vk_filename() -> String {
-    read_env_var("BUNDLE_VK_FILENAME", "vk_bundle.vkey".to_string())
+    read_env_var(
+        "BUNDLE_VK_FILENAME",
+        BATCH_CAPACITY.asset_filename("vk_bundle", "vkey"),
+    )
 }
 pub fn batch_vk_filename() -> String {
-    read_env_var("BATCH_VK_FILENAME", "vk_batch.vkey".to_string())
+    read_env_var(
+        "BATCH_VK_FILENAME",
+        BATCH_CAPACITY.asset_filename("vk_batch", "vkey"),
+    )
 }
 pub fn chunk_vk_filename() -> String {
     read_env_var("CHUNK_VK_FILENAME", "vk_chunk.vkey".to_string())
@@ -37,8 +45,13 @@ pub static CHUNK_VK_FILENAME: LazyLock<String> = LazyLock::new(chunk_vk_filename
 pub static BATCH_VK_FILENAME: LazyLock<String> = LazyLock::new(batch_vk_filename);
 pub static BUNDLE_VK_FILENAME: LazyLock<String> = LazyLock::new(bundle_vk_filename);
 
-pub static DEPLOYMENT_CODE_FILENAME: LazyLock<String> =
-    LazyLock::new(|| read_env_var("DEPLOYMENT_CODE_FILENAME", "evm_verifier.bin".to_string()));
+/// The EVM verifier of the bundle proof, hence it depends on the [`BATCH_CAPACITY`] as well.
+pub static DEPLOYMENT_CODE_FILENAME: LazyLock<String> = LazyLock::new(|| {
+    read_env_var(
+        "DEPLOYMENT_CODE_FILENAME",
+        BATCH_CAPACITY.asset_filename("evm_verifier", "bin"),
+    )
+});
 
 // For our k=21 agg circuit, 12 means it can include 2**21 / (12 * 25) * 136.0 = 0.95M bytes
 pub static BATCH_KECCAK_ROW: LazyLock<usize> =
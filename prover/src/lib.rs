@@ -40,7 +40,8 @@
 mod aggregator;
 pub use aggregator::{
     check_chunk_hashes, get_blob_bytes, BatchData, BatchHash, BatchHeader, BatchProver,
-    BatchProverError, BatchVerifier, RecursionTask, MAX_AGG_SNARKS,
+    BatchProverError, BatchVerifier, RecursionTask, BUNDLE_PI_DIGEST, BUNDLE_PI_INIT_STATE,
+    BUNDLE_PI_LEN, BUNDLE_PI_ROUND, BUNDLE_PI_STATE, MAX_AGG_SNARKS,
 };
 
 mod common;
@@ -58,6 +59,8 @@ pub use error::*;
 mod proof;
 pub use proof::*;
 
+pub mod server;
+
 mod test;
 pub use test::{batch_prove, bundle_prove, chunk_prove, inner_prove};
 
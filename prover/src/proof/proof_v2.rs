@@ -108,6 +108,21 @@ impl<Inner: Proof + serde::de::DeserializeOwned> ProofV2<Inner> {
         Ok(())
     }
 
+    /// The public values following the KZG accumulator, each as a big-endian 32-byte word.
+    pub fn public_inputs(&self) -> Result<Vec<H256>, ProverError> {
+        let len = self.instances.len();
+        if len < ACCUMULATOR_BYTES || len % 32 != 0 {
+            return Err(ProverError::Custom(format!(
+                "instances of {len} bytes are not an accumulator followed by 32-byte words"
+            )));
+        }
+
+        Ok(self.instances[ACCUMULATOR_BYTES..]
+            .chunks(32)
+            .map(H256::from_slice)
+            .collect())
+    }
+
     /// Deserialize public values in the native scalar field.
     fn deserialize_instances(&self) -> Vec<Vec<Fr>> {
         vec![self
@@ -183,16 +198,16 @@ const ACCUMULATOR_BYTES: usize = ACCUMULATOR_LEN * 32;
 ///
 /// They are the following:
 /// - Fr: Preprocessed Digest
-/// - Fr: Recursion Round
 /// - (Fr, Fr): Pre State Root (finalized)
 /// - (Fr, Fr): Pre Batch Hash (finalized)
 /// - (Fr, Fr): Post State Root (pending finalization)
 /// - (Fr, Fr): Post Batch Hash (pending finalization)
 /// - Fr: Chain ID
 /// - (Fr, Fr): Post Withdraw Root (pending finalization)
+/// - Fr: Recursion Round
 ///
-/// In total these are 13 scalar field elements.
-const PUBLIC_INPUT_LEN: usize = 13;
+/// In total these are 13 scalar field elements, see [`BUNDLE_PI_LEN`][crate::BUNDLE_PI_LEN].
+const PUBLIC_INPUT_LEN: usize = crate::BUNDLE_PI_LEN;
 
 /// Each scalar field [`Fr`] element is encoded using 32 bytes.
 const PUBLIC_INPUT_BYTES: usize = PUBLIC_INPUT_LEN * 32;
@@ -346,7 +361,10 @@ impl Proof for BundleProofV2Metadata {
 mod tests {
     use tempdir::TempDir;
 
-    use crate::{deploy_and_call, read, read_json, BundleProofV2, EvmProof};
+    use crate::{
+        deploy_and_call, read, read_json, BundleProofV2, EvmProof, BUNDLE_PI_LEN, BUNDLE_PI_ROUND,
+        BUNDLE_PI_STATE,
+    };
 
     #[test]
     fn bundle_proof_backwards_compat() -> anyhow::Result<()> {
@@ -366,6 +384,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn bundle_proof_public_inputs() -> anyhow::Result<()> {
+        let evm_proof = read_json::<_, EvmProof>("test_data/evm-proof.json")?;
+        let bundle_proof = BundleProofV2::new_from_raw(
+            &evm_proof.proof.proof,
+            &evm_proof.proof.instances,
+            &evm_proof.proof.vk,
+        )?;
+
+        let pi = bundle_proof.public_inputs()?;
+        assert_eq!(pi.len(), BUNDLE_PI_LEN);
+        // the chain ID follows the (hi, lo) pending state root and batch hash.
+        assert_eq!(pi[BUNDLE_PI_STATE + 4].to_low_u64_be(), 534352);
+        assert_eq!(pi[BUNDLE_PI_ROUND].to_low_u64_be(), 1);
+
+        // instances that are not made of whole 32-byte words are rejected.
+        let mut truncated = bundle_proof;
+        truncated.instances.pop();
+        assert!(truncated.public_inputs().is_err());
+        truncated.instances.truncate(31);
+        assert!(truncated.public_inputs().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn verify_bundle_proof() -> anyhow::Result<()> {
         // Create a tmp test directory.
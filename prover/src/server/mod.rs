@@ -0,0 +1,367 @@
+//! A local proving daemon, serving chunk, batch and bundle proving tasks over a Unix socket.
+//!
+//! Clients exchange newline-delimited JSON with the daemon: each line written to the socket is a
+//! [`Request`], answered by a single line holding a [`Response`]. For instance:
+//!
+//! ```text
+//! > {"method":"submit","params":{"kind":"chunk","task":{"chunk_info":null,"block_traces":[...]}}}
+//! < {"submitted":{"id":0}}
+//! > {"method":"status","params":{"id":0}}
+//! < {"status":{"status":"proving","elapsed_secs":42}}
+//! > {"method":"wait","params":{"id":0}}
+//! < {"status":{"status":"done","output":{"kind":"chunk","proof":{...}},"elapsed_secs":97}}
+//! ```
+//!
+//! Tasks are proven one at a time by a [`ProvingBackend`] in submission order, see [`JobQueue`].
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    os::unix::net::{UnixListener, UnixStream},
+    path::Path,
+    thread,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BatchCapacity, BatchProofV2, BatchProver, BatchProvingTask, BundleProofV2, BundleProvingTask,
+    ChunkProofV2, ChunkProver, ChunkProverError, ChunkProvingTask, ParamsMap, ProverError,
+};
+
+mod queue;
+pub use queue::{JobId, JobQueue, JobStatus, MAX_FINISHED_JOBS};
+
+/// A task accepted by the daemon.
+///
+/// A batch task is specific to a [`BatchCapacity`], and is only proven by a daemon configured with
+/// that capacity.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "task", rename_all = "snake_case")]
+pub enum ProvingTask {
+    Chunk(ChunkProvingTask),
+    Batch(BatchProvingTask),
+    #[serde(rename = "batch_15")]
+    Batch15(BatchProvingTask<{ BatchCapacity::Chunks15.max_chunks() }>),
+    #[serde(rename = "batch_90")]
+    Batch90(BatchProvingTask<{ BatchCapacity::Chunks90.max_chunks() }>),
+    Bundle(BundleProvingTask),
+}
+
+impl ProvingTask {
+    /// The kind of the task, for logging purposes.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Chunk(_) => "chunk",
+            Self::Batch(_) => "batch",
+            Self::Batch15(_) => "batch_15",
+            Self::Batch90(_) => "batch_90",
+            Self::Bundle(_) => "bundle",
+        }
+    }
+}
+
+/// The proof generated for a [`ProvingTask`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "kind", content = "proof", rename_all = "snake_case")]
+pub enum ProvingOutput {
+    Chunk(ChunkProofV2),
+    Batch(BatchProofV2),
+    Bundle(BundleProofV2),
+}
+
+/// Generates proofs for the tasks taken from the [`JobQueue`].
+pub trait ProvingBackend {
+    fn prove(&mut self, task: ProvingTask) -> Result<ProvingOutput, ProverError>;
+}
+
+/// The backend of the daemon, generating proofs with the [`ChunkProver`] and [`BatchProver`].
+///
+/// The provers are set up once, such that their proving keys are generated on the first task of
+/// each kind and kept across tasks.
+#[derive(Debug)]
+pub struct LocalProvers<'params> {
+    chunk_prover: ChunkProver<'params>,
+    batch_prover: BatchProver<'params>,
+    /// Directory the proofs are written to and loaded from, if any.
+    output_dir: Option<String>,
+}
+
+impl<'params> LocalProvers<'params> {
+    /// Set up the provers given a map of degree to KZG setup params, covering the degrees of both
+    /// the chunk and batch provers, and a directory to find stored assets.
+    pub fn from_params_and_assets(
+        params_map: &'params ParamsMap,
+        assets_dir: &str,
+        output_dir: Option<String>,
+    ) -> Self {
+        Self {
+            chunk_prover: ChunkProver::from_params_and_assets(params_map, assets_dir),
+            batch_prover: BatchProver::from_params_and_assets(params_map, assets_dir),
+            output_dir,
+        }
+    }
+}
+
+impl ProvingBackend for LocalProvers<'_> {
+    fn prove(&mut self, task: ProvingTask) -> Result<ProvingOutput, ProverError> {
+        let output_dir = self.output_dir.as_deref();
+        match task {
+            ProvingTask::Chunk(chunk) => {
+                if chunk.is_empty() {
+                    return Err(ChunkProverError::Custom(String::from("empty chunk")).into());
+                }
+                self.chunk_prover
+                    .gen_halo2_chunk_proof(chunk, None, None, output_dir)
+                    .map(ProvingOutput::Chunk)
+            }
+            ProvingTask::Batch(batch) => self
+                .batch_prover
+                .gen_batch_proof(batch, None, output_dir)
+                .map(ProvingOutput::Batch),
+            ProvingTask::Batch15(batch) => self
+                .batch_prover
+                .gen_batch_proof(batch, None, output_dir)
+                .map(ProvingOutput::Batch),
+            ProvingTask::Batch90(batch) => self
+                .batch_prover
+                .gen_batch_proof(batch, None, output_dir)
+                .map(ProvingOutput::Batch),
+            ProvingTask::Bundle(bundle) => self
+                .batch_prover
+                .gen_bundle_proof(bundle, None, output_dir)
+                .map(ProvingOutput::Bundle),
+        }
+    }
+}
+
+/// A request sent to the daemon.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "method", content = "params", rename_all = "snake_case")]
+pub enum Request {
+    /// Enqueue a task.
+    Submit(ProvingTask),
+    /// Report the progress of a job without blocking.
+    Status { id: JobId },
+    /// Block until a job is finished.
+    Wait { id: JobId },
+}
+
+/// The response of the daemon to a [`Request`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Submitted { id: JobId },
+    Status(JobStatus),
+    Error { message: String },
+}
+
+impl Response {
+    fn handle(queue: &JobQueue, request: Request) -> Self {
+        let status = match request {
+            Request::Submit(task) => {
+                return Self::Submitted {
+                    id: queue.submit(task),
+                }
+            }
+            Request::Status { id } => queue.status(id),
+            Request::Wait { id } => queue.wait(id),
+        };
+        match status {
+            Some(status) => Self::Status(status),
+            None => Self::Error {
+                message: String::from("unknown job, or its result was already returned"),
+            },
+        }
+    }
+}
+
+fn handle_connection(stream: UnixStream, queue: &JobQueue) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str(&line) {
+            Ok(request) => Response::handle(queue, request),
+            Err(e) => Response::Error {
+                message: format!("invalid request: {e}"),
+            },
+        };
+        serde_json::to_writer(&mut writer, &response)?;
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Accept connections on the listener and answer their requests against the queue, each
+/// connection being served on its own thread.
+///
+/// The jobs are proven by whoever calls [`JobQueue::work`] on the same queue.
+pub fn serve(listener: UnixListener, queue: JobQueue) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        let queue = queue.clone();
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, &queue) {
+                log::warn!("connection closed: {e}");
+            }
+        });
+    }
+    Ok(())
+}
+
+/// A blocking client of the daemon.
+#[derive(Debug)]
+pub struct Client {
+    reader: BufReader<UnixStream>,
+    writer: UnixStream,
+}
+
+impl Client {
+    /// Connect to the daemon listening on the given socket.
+    pub fn connect<P: AsRef<Path>>(socket: P) -> io::Result<Self> {
+        let writer = UnixStream::connect(socket)?;
+        let reader = BufReader::new(writer.try_clone()?);
+        Ok(Self { reader, writer })
+    }
+
+    /// Send a request and read the response.
+    pub fn request(&mut self, request: &Request) -> io::Result<Response> {
+        serde_json::to_writer(&mut self.writer, request)?;
+        self.writer.write_all(b"\n")?;
+
+        let mut line = String::new();
+        if self.reader.read_line(&mut line)? == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(serde_json::from_str(&line)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempdir::TempDir;
+
+    use super::*;
+
+    /// Proves bundles with a dummy proof, panics on chunks and fails batches.
+    struct MockProvers;
+
+    impl ProvingBackend for MockProvers {
+        fn prove(&mut self, task: ProvingTask) -> Result<ProvingOutput, ProverError> {
+            match task {
+                ProvingTask::Chunk(_) => panic!("mock panic"),
+                ProvingTask::Batch(_) | ProvingTask::Batch15(_) | ProvingTask::Batch90(_) => {
+                    Err(ProverError::Custom(String::from("mock failure")))
+                }
+                ProvingTask::Bundle(_) => {
+                    BundleProofV2::new_from_raw(&[1, 2, 3], &[0; 25 * 32], &[])
+                        .map(ProvingOutput::Bundle)
+                }
+            }
+        }
+    }
+
+    fn bundle_task() -> ProvingTask {
+        ProvingTask::Bundle(BundleProvingTask {
+            batch_proofs: vec![],
+        })
+    }
+
+    #[test]
+    fn queue_reports_progress() {
+        let queue = JobQueue::default();
+        let first = queue.submit(bundle_task());
+        let second = queue.submit(ProvingTask::Chunk(ChunkProvingTask::new(vec![])));
+        assert!(matches!(
+            queue.status(second),
+            Some(JobStatus::Queued { position: 1 })
+        ));
+
+        let worker = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.work(&mut MockProvers))
+        };
+
+        match queue.wait(first) {
+            Some(JobStatus::Done {
+                output: ProvingOutput::Bundle(proof),
+                ..
+            }) => assert_eq!(proof.proof, vec![1, 2, 3]),
+            status => panic!("unexpected status: {status:?}"),
+        }
+        match queue.wait(second) {
+            Some(JobStatus::Failed { error }) => assert_eq!(error, "prover panicked"),
+            status => panic!("unexpected status: {status:?}"),
+        }
+        // results are only returned once.
+        assert!(queue.status(first).is_none());
+
+        queue.shutdown();
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn queue_drops_unfetched_results() {
+        let queue = JobQueue::default();
+        let ids = (0..=MAX_FINISHED_JOBS)
+            .map(|_| queue.submit(bundle_task()))
+            .collect::<Vec<_>>();
+
+        let worker = {
+            let queue = queue.clone();
+            thread::spawn(move || queue.work(&mut MockProvers))
+        };
+        assert!(matches!(
+            queue.wait(ids[MAX_FINISHED_JOBS]),
+            Some(JobStatus::Done { .. })
+        ));
+        // the oldest result is dropped, the others are still there to fetch.
+        assert!(queue.status(ids[0]).is_none());
+        assert!(matches!(queue.status(ids[1]), Some(JobStatus::Done { .. })));
+
+        queue.shutdown();
+        worker.join().unwrap();
+    }
+
+    #[test]
+    fn serve_over_unix_socket() {
+        let dir = TempDir::new("prover-server").unwrap();
+        let socket = dir.path().join("prover.sock");
+
+        let queue = JobQueue::default();
+        let listener = UnixListener::bind(&socket).unwrap();
+        {
+            let queue = queue.clone();
+            thread::spawn(move || serve(listener, queue));
+        }
+        {
+            let queue = queue.clone();
+            thread::spawn(move || queue.work(&mut MockProvers));
+        }
+
+        let mut client = Client::connect(&socket).unwrap();
+        let chunk = ProvingTask::Chunk(ChunkProvingTask::new(vec![]));
+        for (task, proven) in [(chunk, false), (bundle_task(), true)] {
+            let id = match client.request(&Request::Submit(task)).unwrap() {
+                Response::Submitted { id } => id,
+                response => panic!("unexpected response: {response:?}"),
+            };
+            match client.request(&Request::Wait { id }).unwrap() {
+                Response::Status(JobStatus::Done { .. }) => assert!(proven),
+                Response::Status(JobStatus::Failed { error }) => {
+                    assert!(!proven);
+                    assert_eq!(error, "prover panicked");
+                }
+                response => panic!("unexpected response: {response:?}"),
+            }
+        }
+
+        assert!(matches!(
+            client.request(&Request::Status { id: 42 }).unwrap(),
+            Response::Error { .. }
+        ));
+
+        queue.shutdown();
+    }
+}
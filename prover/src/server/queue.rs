@@ -0,0 +1,186 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    time::Instant,
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{ProvingBackend, ProvingOutput, ProvingTask};
+
+/// Identifier of a job submitted to the [`JobQueue`].
+pub type JobId = u64;
+
+/// The number of finished jobs whose status is kept until a client fetches it. Beyond that, the
+/// oldest results are dropped, so that proofs never fetched do not pile up in memory.
+pub const MAX_FINISHED_JOBS: usize = 64;
+
+/// The progress of a job, as reported to clients.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum JobStatus {
+    /// The job waits for the jobs ahead of it, `position` being the number of such jobs.
+    Queued { position: usize },
+    /// The job is being proven.
+    Proving { elapsed_secs: u64 },
+    /// The proof was generated.
+    Done {
+        output: ProvingOutput,
+        elapsed_secs: u64,
+    },
+    /// Proof generation failed.
+    Failed { error: String },
+}
+
+impl JobStatus {
+    /// Returns true if the job will not make any further progress.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, Self::Done { .. } | Self::Failed { .. })
+    }
+}
+
+#[derive(Debug, Default)]
+struct Jobs {
+    /// The id assigned to the next submitted job.
+    next_id: JobId,
+    /// Jobs waiting for the worker, in submission order.
+    pending: VecDeque<(JobId, ProvingTask)>,
+    /// The job being proven by the worker, if any.
+    active: Option<(JobId, Instant)>,
+    /// Finished jobs whose status has not been returned yet, at most [`MAX_FINISHED_JOBS`].
+    finished: BTreeMap<JobId, JobStatus>,
+    /// Set once the worker should stop picking up jobs.
+    shutdown: bool,
+}
+
+impl Jobs {
+    /// The status of a job. The status of a finished job is only returned once, after which the
+    /// job, and in particular its proof, is forgotten.
+    fn take_status(&mut self, id: JobId) -> Option<JobStatus> {
+        if let Some((active, started)) = self.active {
+            if active == id {
+                return Some(JobStatus::Proving {
+                    elapsed_secs: started.elapsed().as_secs(),
+                });
+            }
+        }
+        if let Some(position) = self.pending.iter().position(|(pending, _)| *pending == id) {
+            return Some(JobStatus::Queued { position });
+        }
+        self.finished.remove(&id)
+    }
+
+    /// Record the status of a finished job, dropping the oldest unfetched result if there are too
+    /// many of them.
+    fn finish(&mut self, id: JobId, status: JobStatus) {
+        self.finished.insert(id, status);
+        if self.finished.len() > MAX_FINISHED_JOBS {
+            if let Some((dropped, _)) = self.finished.pop_first() {
+                log::warn!("job {dropped}: result dropped as it was never fetched");
+            }
+        }
+    }
+}
+
+/// A FIFO queue of proving tasks shared between the clients submitting them and the single worker
+/// proving them.
+///
+/// There is a single worker as proof generation already saturates the machine, and that way the
+/// proving keys of the worker's provers stay warm across jobs.
+#[derive(Debug, Clone, Default)]
+pub struct JobQueue {
+    inner: Arc<(Mutex<Jobs>, Condvar)>,
+}
+
+impl JobQueue {
+    fn jobs(&self) -> MutexGuard<'_, Jobs> {
+        self.inner.0.lock().expect("poisoned job queue")
+    }
+
+    /// Enqueue a proving task, returning the id of the job.
+    pub fn submit(&self, task: ProvingTask) -> JobId {
+        let mut jobs = self.jobs();
+        let id = jobs.next_id;
+        jobs.next_id += 1;
+        jobs.pending.push_back((id, task));
+        log::info!("job {id}: queued at position {}", jobs.pending.len() - 1);
+
+        self.inner.1.notify_all();
+        id
+    }
+
+    /// The status of a job, or `None` for unknown jobs.
+    ///
+    /// The status of a finished job is only returned once, and only among the last
+    /// [`MAX_FINISHED_JOBS`] unfetched ones.
+    pub fn status(&self, id: JobId) -> Option<JobStatus> {
+        self.jobs().take_status(id)
+    }
+
+    /// Block until a job is finished and return its status, or `None` for unknown jobs.
+    pub fn wait(&self, id: JobId) -> Option<JobStatus> {
+        let mut jobs = self.jobs();
+        loop {
+            match jobs.take_status(id) {
+                Some(status) if !status.is_finished() => {
+                    jobs = self.inner.1.wait(jobs).expect("poisoned job queue");
+                }
+                status => return status,
+            }
+        }
+    }
+
+    /// Make [`work`][Self::work] return once the job being proven, if any, is finished.
+    pub fn shutdown(&self) {
+        self.jobs().shutdown = true;
+        self.inner.1.notify_all();
+    }
+
+    /// Prove the queued tasks one after the other with the given backend, until
+    /// [`shutdown`][Self::shutdown] is called.
+    ///
+    /// A panic while proving fails the job rather than bringing down the worker.
+    pub fn work<B: ProvingBackend>(&self, backend: &mut B) {
+        loop {
+            let (id, task) = {
+                let mut jobs = self.jobs();
+                loop {
+                    if jobs.shutdown {
+                        return;
+                    }
+                    if let Some((id, task)) = jobs.pending.pop_front() {
+                        jobs.active = Some((id, Instant::now()));
+                        break (id, task);
+                    }
+                    jobs = self.inner.1.wait(jobs).expect("poisoned job queue");
+                }
+            };
+
+            log::info!("job {id}: proving {} task", task.kind());
+            let started = Instant::now();
+            let status = match catch_unwind(AssertUnwindSafe(|| backend.prove(task))) {
+                Ok(Ok(output)) => JobStatus::Done {
+                    output,
+                    elapsed_secs: started.elapsed().as_secs(),
+                },
+                Ok(Err(e)) => JobStatus::Failed {
+                    error: e.to_string(),
+                },
+                Err(_) => JobStatus::Failed {
+                    error: String::from("prover panicked"),
+                },
+            };
+            log::info!(
+                "job {id}: finished after {}s, success = {}",
+                started.elapsed().as_secs(),
+                matches!(status, JobStatus::Done { .. })
+            );
+
+            let mut jobs = self.jobs();
+            jobs.active = None;
+            jobs.finish(id, status);
+            self.inner.1.notify_all();
+        }
+    }
+}
@@ -60,21 +60,29 @@ impl ChunkProvingTask {
 }
 
 /// Defines a proving task for batch proof generation.
+///
+/// The task is specific to the [`capacity`][crate::BatchCapacity] `N_SNARKS` of the batch circuit,
+/// since the metadata of the [`BatchData`][aggregator::BatchData] encoded in the blob has one entry
+/// per chunk the batch may hold.
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct BatchProvingTask {
+pub struct BatchProvingTask<const N_SNARKS: usize = MAX_AGG_SNARKS> {
     /// Chunk proofs for the contiguous list of chunks within the batch.
     pub chunk_proofs: Vec<ChunkProofV2>,
     /// The [`BatchHeader`], as computed on-chain for this batch.
     ///
+    /// Up to version 3, the batch hash matches the codec below. A batch of
+    /// [`MULTI_BLOB_BATCH_VERSION`][aggregator::MULTI_BLOB_BATCH_VERSION] whose data spans several
+    /// blobs further commits to the versioned hash and blob data proof of each extra blob.
+    ///
     /// Ref: https://github.com/scroll-tech/scroll-contracts/blob/2ac4f3f7e090d7127db4b13b3627cb3ce2d762bc/src/libraries/codec/BatchHeaderV3Codec.sol
-    pub batch_header: BatchHeader<MAX_AGG_SNARKS>,
+    pub batch_header: BatchHeader<N_SNARKS>,
     /// The bytes encoding the batch data that will finally be published on-chain in the form of an
     /// EIP-4844 blob.
     #[serde(with = "base64")]
     pub blob_bytes: Vec<u8>,
 }
 
-impl BatchProvingTask {
+impl<const N_SNARKS: usize> BatchProvingTask<N_SNARKS> {
     /// An identifier for the batch. It is the public input hash of the last chunk in the batch.
     ///
     /// This is used as a file descriptor to save to (load from) disk in order to avoid proof
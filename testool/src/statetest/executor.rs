@@ -56,8 +56,6 @@ pub enum StateTestError {
     SkipTestMaxGasLimit(u64),
     #[error("SkipTestMaxSteps({0})")]
     SkipTestMaxSteps(usize),
-    #[error("SkipTestSelfDestruct")]
-    SkipTestSelfDestruct,
     #[error("SkipTestDifficulty")]
     // scroll evm always returns 0 for "difficulty" opcode
     SkipTestDifficulty,
@@ -71,8 +69,7 @@ pub enum StateTestError {
 
 impl StateTestError {
     pub fn is_skip(&self) -> bool {
-        // Avoid lint `variant is never constructed` if no feature skip-self-destruct.
-        let _ = StateTestError::SkipTestSelfDestruct;
+        // Avoid lint `variant is never constructed`.
         let _ = StateTestError::SkipTestDifficulty;
         let _ = StateTestError::SkipTestBalanceOverflow;
 
@@ -80,7 +77,6 @@ impl StateTestError {
             self,
             StateTestError::SkipTestMaxSteps(_)
                 | StateTestError::SkipTestMaxGasLimit(_)
-                | StateTestError::SkipTestSelfDestruct
                 | StateTestError::SkipTestBalanceOverflow
                 | StateTestError::SkipTestDifficulty
         )
@@ -198,6 +194,7 @@ pub fn into_traceconfig(st: StateTest) -> (String, TraceConfig, StateTestResult)
                 gas_tip_cap: st.max_priority_fee_per_gas,
                 call_data: st.data,
                 access_list: st.access_list,
+                authorization_list: vec![],
                 v,
                 r: sig.r,
                 s: sig.s,
@@ -237,16 +234,6 @@ fn check_geth_traces(
     suite: &TestSuite,
     verbose: bool,
 ) -> Result<(), StateTestError> {
-    #[cfg(all(feature = "skip-self-destruct", not(feature = "scroll")))]
-    if geth_traces.iter().any(|gt| {
-        gt.struct_logs.iter().any(|sl| {
-            sl.op == eth_types::evm_types::OpcodeId::SELFDESTRUCT
-                || sl.op == eth_types::evm_types::OpcodeId::INVALID(0xff)
-        })
-    }) {
-        return Err(StateTestError::SkipTestSelfDestruct);
-    }
-
     if geth_traces[0].struct_logs.len() as u64 > suite.max_steps {
         return Err(StateTestError::SkipTestMaxSteps(
             geth_traces[0].struct_logs.len(),
@@ -457,6 +444,7 @@ fn get_params_for_super_circuit_test() -> CircuitsParams {
             ec_add: 50,
             ec_mul: 50,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         },
     }
 }
@@ -481,6 +469,7 @@ fn get_params_for_sub_circuit_test() -> CircuitsParams {
             ec_add: 50,
             ec_mul: 50,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         },
     }
 }
@@ -182,7 +182,7 @@ impl Report {
                         // ignore
                         let big_test = result.details.starts_with("SkipTestMaxGasLimit")
                             || result.details.starts_with("SkipTestMaxSteps");
-                        if result.details.starts_with("SkipTestSelfDestruct") || big_test {
+                        if big_test {
                             None
                         } else {
                             // eg: SkipTestBalanceOverflow
@@ -0,0 +1,1111 @@
+//! The BLAKE2F circuit verifies the BLAKE2b compression function F (EIP-152) for the
+//! precompile blake2f calls.
+//!
+//! Every round of a compression is laid out in a block of [`ROWS_PER_ROUND`] rows, so that a
+//! compression with `rounds` rounds takes `max(rounds, 1)` consecutive blocks. The working vector
+//! and the inputs are carried from one block to the next by copy constraints, and every block
+//! writes a row to the [`Blake2fTable`]; only the rows with `is_final = 1` hold the result of all
+//! the rounds of a compression.
+//!
+//! All the values are 64-bit words decomposed into little-endian bytes. Additions are done with an
+//! explicit carry, XOR with byte rotations is done with a lookup to a `(a, b, a ^ b)` table on
+//! every pair of bytes, and the rotation by 63 bits is done by extracting the highest bit.
+
+#[cfg(any(feature = "test", test, feature = "test-circuits"))]
+mod dev;
+#[cfg(any(feature = "test", test))]
+mod test;
+#[cfg(any(feature = "test", test, feature = "test-circuits"))]
+pub use dev::Blake2fCircuit as TestBlake2fCircuit;
+
+use crate::{
+    evm_circuit::util::{
+        constraint_builder::{BaseConstraintBuilder, ConstrainBuilderCommon},
+        pow_of_two,
+    },
+    table::{Blake2fTable, LookupTable},
+    util::{Challenges, Field, SubCircuit, SubCircuitConfig},
+    witness,
+};
+use bus_mapping::circuit_input_builder::Blake2F;
+use gadgets::util::{expr_from_bytes, Expr};
+#[cfg(feature = "onephase")]
+use halo2_proofs::plonk::FirstPhase as SecondPhase;
+#[cfg(not(feature = "onephase"))]
+use halo2_proofs::plonk::SecondPhase;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{
+        Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn,
+        VirtualCells,
+    },
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// BLAKE2b initialization vector.
+const IV: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+/// BLAKE2b message schedule.
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+/// Indices of the working vector mixed by each of the 8 G functions of a round.
+const G_INDICES: [[usize; 4]; 8] = [
+    [0, 4, 8, 12],
+    [1, 5, 9, 13],
+    [2, 6, 10, 14],
+    [3, 7, 11, 15],
+    [0, 5, 10, 15],
+    [1, 6, 11, 12],
+    [2, 7, 8, 13],
+    [3, 4, 9, 14],
+];
+
+/// Rotations (in bytes) supported by the XOR operation.
+const XOR_ROTATIONS: [usize; 4] = [0, 2, 3, 4];
+
+/// Number of words in the input: rounds, h[8], m[16], t[2] and f.
+const INPUT_WORDS: usize = 28;
+
+/// Rows taken by a G function: add3, xor, add, xor, add3, xor, add, xor, rotr63.
+const G_ROWS: usize = 4 + 3 + 3 + 3 + 4 + 3 + 3 + 3 + 2;
+
+/// Rows taken by a round, which is:
+/// - the `is_first` row,
+/// - the fresh input words with their RLC,
+/// - the selections between the carried and the fresh inputs, input RLC, rounds left and round
+///   flags,
+/// - the flag mask and the 3 XORs of the initial working vector,
+/// - the selections of the working vector,
+/// - the rounds left check and update,
+/// - the message schedule selections,
+/// - the 8 G functions,
+/// - the selections of the working vector after the round,
+/// - the 16 XORs of the output, the output RLC and the `is_final` check.
+pub const ROWS_PER_ROUND: usize = 1
+    + INPUT_WORDS
+    + 3 * (INPUT_WORDS + 1 + 1 + 10)
+    + (2 + 3 * 3)
+    + 3 * 16
+    + (2 + 3)
+    + 11 * 16
+    + G_ROWS * 8
+    + 3 * 16
+    + 3 * 16
+    + 8
+    + 2;
+
+/// Number of rows of the XOR table.
+const XOR_TABLE_ROWS: usize = 1 << 16;
+
+/// Layout for the BLAKE2F circuit.
+#[derive(Clone, Debug)]
+pub struct Blake2fCircuitConfig<F> {
+    /// Enabled on every assigned row, decomposes the value into bytes.
+    q_word: Selector,
+    /// XOR of the current and next rows, rotated by `XOR_ROTATIONS` bytes.
+    q_xor: [Selector; 4],
+    /// Rotation by 63 bits of the current row.
+    q_rotr63: Selector,
+    /// Addition of 2 rows.
+    q_add: Selector,
+    /// Addition of 3 rows.
+    q_add3: Selector,
+    /// Selection between 2 rows.
+    q_select: Selector,
+    /// Selection between 10 rows by the one-hot round flags.
+    q_mux: Selector,
+    /// Whether the value of the current row is zero.
+    q_is_zero: Selector,
+    /// Decrement of the rounds left, until zero.
+    q_round_step: Selector,
+    /// All-ones mask of the final block indicator flag.
+    q_flag_mask: Selector,
+    /// First RLC row of the input, holding the big-endian number of rounds.
+    q_rlc_rounds: Selector,
+    /// RLC row of a little-endian 64-bit word.
+    q_rlc_word: Selector,
+    /// First RLC row of the output.
+    q_rlc_word_first: Selector,
+    /// Last RLC row of the input, holding the final block indicator flag.
+    q_rlc_flag: Selector,
+    /// 64-bit word
+    value: Column<Advice>,
+    /// Little-endian bytes of the word
+    bytes: [Column<Advice>; 8],
+    /// Auxiliary witness of the operations
+    aux: Column<Advice>,
+    /// RLC accumulator
+    rlc: Column<Advice>,
+    /// XOR table, with columns `a`, `b` and `a ^ b` for all pairs of bytes.
+    xor_table: [TableColumn; 3],
+    /// BLAKE2F table
+    pub blake2f_table: Blake2fTable,
+    _marker: PhantomData<F>,
+}
+
+/// Arguments to configure the BLAKE2F circuit
+#[derive(Clone, Debug)]
+pub struct Blake2fCircuitConfigArgs<F: Field> {
+    /// BLAKE2F table
+    pub blake2f_table: Blake2fTable,
+    /// Challenges randomness
+    pub challenges: Challenges<Expression<F>>,
+}
+
+impl<F: Field> SubCircuitConfig<F> for Blake2fCircuitConfig<F> {
+    type ConfigArgs = Blake2fCircuitConfigArgs<F>;
+
+    /// Return a new Blake2fCircuitConfig
+    fn new(
+        meta: &mut ConstraintSystem<F>,
+        Self::ConfigArgs {
+            blake2f_table,
+            challenges,
+        }: Self::ConfigArgs,
+    ) -> Self {
+        let q_word = meta.complex_selector();
+        let q_xor = [(); 4].map(|_| meta.complex_selector());
+        let q_rotr63 = meta.complex_selector();
+        let q_add = meta.selector();
+        let q_add3 = meta.selector();
+        let q_select = meta.selector();
+        let q_mux = meta.selector();
+        let q_is_zero = meta.selector();
+        let q_round_step = meta.selector();
+        let q_flag_mask = meta.selector();
+        let q_rlc_rounds = meta.selector();
+        let q_rlc_word = meta.selector();
+        let q_rlc_word_first = meta.selector();
+        let q_rlc_flag = meta.selector();
+
+        let value = meta.advice_column();
+        let bytes = [(); 8].map(|_| meta.advice_column());
+        let aux = meta.advice_column();
+        let rlc = meta.advice_column_in(SecondPhase);
+        for column in [value, aux, rlc] {
+            meta.enable_equality(column);
+        }
+        let constants: Column<Fixed> = meta.fixed_column();
+        meta.enable_constant(constants);
+        let xor_table = [(); 3].map(|_| meta.lookup_table_column());
+
+        let two_pow_63 = Expression::Constant(pow_of_two::<F>(63));
+        let two_pow_64 = Expression::Constant(pow_of_two::<F>(64));
+        let r = challenges.keccak_input();
+        let r_pows = std::iter::successors(Some(1.expr()), |pow: &Expression<F>| {
+            Some(pow.clone() * r.clone())
+        })
+        .take(9)
+        .collect::<Vec<_>>();
+
+        meta.create_gate("blake2f word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let bytes = bytes.map(|column| meta.query_advice(column, Rotation::cur()));
+            cb.require_equal(
+                "value is composed of its little-endian bytes",
+                meta.query_advice(value, Rotation::cur()),
+                expr_from_bytes(&bytes),
+            );
+
+            cb.gate(meta.query_selector(q_word))
+        });
+        for column in bytes {
+            meta.lookup("blake2f byte range", |meta| {
+                let byte = meta.query_selector(q_word) * meta.query_advice(column, Rotation::cur());
+                vec![
+                    (byte.clone(), xor_table[0]),
+                    (0.expr(), xor_table[1]),
+                    (byte, xor_table[2]),
+                ]
+            });
+        }
+
+        // z = rotr(x ^ y, 8 * rotation) with x, y and z in 3 consecutive rows, so that the j-th
+        // byte of z is the XOR of the (j + rotation)-th bytes of x and y.
+        for j in 0..8 {
+            meta.lookup("blake2f xor", |meta| {
+                let (mut x, mut y, mut z): (Expression<F>, Expression<F>, Expression<F>) =
+                    (0.expr(), 0.expr(), 0.expr());
+                for (&q_xor, rotation) in q_xor.iter().zip(XOR_ROTATIONS) {
+                    let q_xor = meta.query_selector(q_xor);
+                    let column = bytes[(j + rotation) % 8];
+                    x = x + q_xor.clone() * meta.query_advice(column, Rotation::cur());
+                    y = y + q_xor.clone() * meta.query_advice(column, Rotation::next());
+                    z = z + q_xor * meta.query_advice(bytes[j], Rotation(2));
+                }
+                vec![(x, xor_table[0]), (y, xor_table[1]), (z, xor_table[2])]
+            });
+        }
+
+        meta.create_gate("blake2f rotr63", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let x = meta.query_advice(value, Rotation::cur());
+            let hi = meta.query_advice(aux, Rotation::cur());
+            cb.require_boolean("highest bit is boolean", hi.clone());
+            cb.require_equal(
+                "rotr(x, 63) == 2 * (x - hi * 2^63) + hi",
+                meta.query_advice(value, Rotation::next()),
+                2.expr() * (x - hi.clone() * two_pow_63.clone()) + hi,
+            );
+
+            cb.gate(meta.query_selector(q_rotr63))
+        });
+        meta.lookup("blake2f rotr63 highest bit", |meta| {
+            // The highest byte without its highest bit is less than 128.
+            let lo = meta.query_selector(q_rotr63)
+                * 2.expr()
+                * (meta.query_advice(bytes[7], Rotation::cur())
+                    - 128.expr() * meta.query_advice(aux, Rotation::cur()));
+            vec![
+                (lo.clone(), xor_table[0]),
+                (0.expr(), xor_table[1]),
+                (lo, xor_table[2]),
+            ]
+        });
+
+        meta.create_gate("blake2f add", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let [a, b, c] = [0, 1, 2].map(|i| meta.query_advice(value, Rotation(i)));
+            let carry = meta.query_advice(aux, Rotation(2));
+            cb.require_boolean("carry is boolean", carry.clone());
+            cb.require_equal(
+                "a + b == c + carry * 2^64",
+                a + b,
+                c + carry * two_pow_64.clone(),
+            );
+
+            cb.gate(meta.query_selector(q_add))
+        });
+
+        meta.create_gate("blake2f add3", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let [a, b, c, d] = [0, 1, 2, 3].map(|i| meta.query_advice(value, Rotation(i)));
+            let carry = meta.query_advice(aux, Rotation(3));
+            cb.require_in_set(
+                "carry is 0, 1 or 2",
+                carry.clone(),
+                vec![0.expr(), 1.expr(), 2.expr()],
+            );
+            cb.require_equal(
+                "a + b + c == d + carry * 2^64",
+                a + b + c,
+                d + carry * two_pow_64.clone(),
+            );
+
+            cb.gate(meta.query_selector(q_add3))
+        });
+
+        meta.create_gate("blake2f select", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let cond = meta.query_advice(aux, Rotation(2));
+            cb.require_boolean("condition is boolean", cond.clone());
+            for column in [value, rlc] {
+                let [a, b, out] = [0, 1, 2].map(|i| meta.query_advice(column, Rotation(i)));
+                cb.require_equal(
+                    "out == cond ? b : a",
+                    out,
+                    a.clone() + cond.clone() * (b - a),
+                );
+            }
+
+            cb.gate(meta.query_selector(q_select))
+        });
+
+        meta.create_gate("blake2f mux", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let selected = (0..10).fold(0.expr(), |acc, i| {
+                acc + meta.query_advice(aux, Rotation(i)) * meta.query_advice(value, Rotation(i))
+            });
+            cb.require_equal(
+                "out == sum(flag[i] * option[i])",
+                meta.query_advice(value, Rotation(10)),
+                selected,
+            );
+
+            cb.gate(meta.query_selector(q_mux))
+        });
+
+        meta.create_gate("blake2f is_zero", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let x = meta.query_advice(value, Rotation::cur());
+            let x_inv = meta.query_advice(aux, Rotation::cur());
+            let is_zero = meta.query_advice(value, Rotation::next());
+            cb.require_equal(
+                "is_zero == 1 - x * x_inv",
+                is_zero.clone(),
+                1.expr() - x.clone() * x_inv,
+            );
+            cb.require_zero("x * is_zero == 0", x * is_zero);
+
+            cb.gate(meta.query_selector(q_is_zero))
+        });
+
+        meta.create_gate("blake2f round step", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let [rounds_left, is_zero, rounds_left_next] =
+                [0, 1, 2].map(|i| meta.query_advice(value, Rotation(i)));
+            cb.require_equal(
+                "rounds_left_next == rounds_left - 1 + is_zero",
+                rounds_left_next,
+                rounds_left - 1.expr() + is_zero,
+            );
+
+            cb.gate(meta.query_selector(q_round_step))
+        });
+
+        meta.create_gate("blake2f flag mask", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let f = meta.query_advice(value, Rotation::cur());
+            cb.require_boolean("final block indicator flag is boolean", f.clone());
+            for column in bytes {
+                cb.require_equal(
+                    "mask byte == 255 * f",
+                    meta.query_advice(column, Rotation::next()),
+                    255.expr() * f.clone(),
+                );
+            }
+
+            cb.gate(meta.query_selector(q_flag_mask))
+        });
+
+        meta.create_gate("blake2f rlc rounds", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            for &column in &bytes[4..] {
+                cb.require_zero(
+                    "rounds fit in 4 bytes",
+                    meta.query_advice(column, Rotation::cur()),
+                );
+            }
+            // the rounds are big-endian in the input
+            let rounds_rlc = (0..4).fold(0.expr(), |acc, j| {
+                acc + meta.query_advice(bytes[j], Rotation::cur()) * r_pows[j].clone()
+            });
+            cb.require_equal(
+                "rlc == rlc(rounds)",
+                meta.query_advice(rlc, Rotation::cur()),
+                rounds_rlc,
+            );
+
+            cb.gate(meta.query_selector(q_rlc_rounds))
+        });
+
+        meta.create_gate("blake2f rlc word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let rlc_prev = meta.query_advice(rlc, Rotation::prev());
+            let word_rlc = word_rlc(meta, &bytes, &r_pows);
+            cb.require_equal(
+                "rlc == rlc::prev * r^8 + rlc(word)",
+                meta.query_advice(rlc, Rotation::cur()),
+                rlc_prev * r_pows[8].clone() + word_rlc,
+            );
+
+            cb.gate(meta.query_selector(q_rlc_word))
+        });
+
+        meta.create_gate("blake2f rlc first word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let word_rlc = word_rlc(meta, &bytes, &r_pows);
+            cb.require_equal(
+                "rlc == rlc(word)",
+                meta.query_advice(rlc, Rotation::cur()),
+                word_rlc,
+            );
+
+            cb.gate(meta.query_selector(q_rlc_word_first))
+        });
+
+        meta.create_gate("blake2f rlc flag", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let rlc_prev = meta.query_advice(rlc, Rotation::prev());
+            cb.require_equal(
+                "rlc == rlc::prev * r + f",
+                meta.query_advice(rlc, Rotation::cur()),
+                rlc_prev * r.clone() + meta.query_advice(value, Rotation::cur()),
+            );
+
+            cb.gate(meta.query_selector(q_rlc_flag))
+        });
+
+        meta.annotate_lookup_column(xor_table[0], || "BLAKE2F_xor_a");
+        meta.annotate_lookup_column(xor_table[1], || "BLAKE2F_xor_b");
+        meta.annotate_lookup_column(xor_table[2], || "BLAKE2F_xor_a^b");
+
+        Self {
+            q_word,
+            q_xor,
+            q_rotr63,
+            q_add,
+            q_add3,
+            q_select,
+            q_mux,
+            q_is_zero,
+            q_round_step,
+            q_flag_mask,
+            q_rlc_rounds,
+            q_rlc_word,
+            q_rlc_word_first,
+            q_rlc_flag,
+            value,
+            bytes,
+            aux,
+            rlc,
+            xor_table,
+            blake2f_table,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> Blake2fCircuitConfig<F> {
+    fn load_xor_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "blake2f xor table",
+            |mut table| {
+                for (offset, (a, b)) in (0..256u64)
+                    .flat_map(|a| (0..256u64).map(move |b| (a, b)))
+                    .enumerate()
+                {
+                    for (&column, value) in self.xor_table.iter().zip([a, b, a ^ b]) {
+                        table.assign_cell(
+                            || format!("xor table row {offset}"),
+                            column,
+                            offset,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Assign the compressions, padded with zero-round compressions up to
+    /// `max_rounds` blocks when it is not 0.
+    pub(crate) fn assign(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        events: &[Blake2F],
+        max_rounds: usize,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<(), Error> {
+        self.load_xor_table(layouter)?;
+
+        let total_rounds = events.iter().map(num_rounds).sum::<usize>();
+        if max_rounds != 0 && total_rounds > max_rounds {
+            log::error!("blake2f rounds ({total_rounds}) exceed limit ({max_rounds})");
+            return Err(Error::Synthesis);
+        }
+        let padding = Blake2F::default();
+        let num_padding = max_rounds.saturating_sub(total_rounds);
+
+        layouter.assign_region(
+            || "blake2f circuit",
+            |mut region| {
+                let mut assigner = Assigner {
+                    config: self,
+                    region: &mut region,
+                    offset: 0,
+                    challenge: challenges.keccak_input(),
+                };
+                let mut carry = None;
+                let events = events
+                    .iter()
+                    .map(|event| (event, true))
+                    .chain(std::iter::repeat((&padding, false)).take(num_padding));
+                for (event, is_real) in events {
+                    for round in 0..num_rounds(event) {
+                        carry = Some(assigner.assign_round(event, round == 0, carry.as_ref())?);
+                    }
+                    let carry = carry.as_ref().expect("at least one round per compression");
+                    if is_real && (carry.output != event.output || !carry.is_final) {
+                        log::error!("blake2f output mismatch for {event:?}");
+                        return Err(Error::Synthesis);
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// RLC of the little-endian bytes of the current row, in that order.
+fn word_rlc<F: Field>(
+    meta: &mut VirtualCells<'_, F>,
+    bytes: &[Column<Advice>; 8],
+    r_pows: &[Expression<F>],
+) -> Expression<F> {
+    bytes
+        .iter()
+        .enumerate()
+        .fold(0.expr(), |acc, (j, &column)| {
+            acc + meta.query_advice(column, Rotation::cur()) * r_pows[7 - j].clone()
+        })
+}
+
+/// Number of blocks of a compression.
+fn num_rounds(event: &Blake2F) -> usize {
+    (event.rounds as usize).max(1)
+}
+
+/// A 64-bit word assigned in the value column.
+#[derive(Clone, Debug)]
+struct Word<F: Field> {
+    cell: AssignedCell<F, F>,
+    value: u64,
+}
+
+/// A row with its word and RLC.
+struct Row<F: Field> {
+    word: Word<F>,
+    rlc: AssignedCell<F, F>,
+}
+
+/// Source of the value of a row.
+enum Src<'w, F: Field> {
+    /// Copy of an assigned word
+    Word(&'w Word<F>),
+    /// Constant
+    Const(u64),
+    /// Free witness
+    Free(u64),
+}
+
+impl<F: Field> Src<'_, F> {
+    fn value(&self) -> u64 {
+        match self {
+            Self::Word(word) => word.value,
+            Self::Const(value) | Self::Free(value) => *value,
+        }
+    }
+}
+
+/// Source of the auxiliary witness of a row.
+enum Aux<'w, F: Field> {
+    /// Zero
+    Zero,
+    /// Copy of an assigned word
+    Word(&'w Word<F>),
+    /// Free witness
+    Free(F),
+}
+
+/// State carried from a round to the next one.
+struct Carry<F: Field> {
+    /// rounds, h, m, t and f
+    inputs: Vec<Word<F>>,
+    input_rlc: AssignedCell<F, F>,
+    rounds_left: Word<F>,
+    /// one-hot flags of the round index modulo 10
+    flags: Vec<Word<F>>,
+    /// working vector
+    v: Vec<Word<F>>,
+    output: [u64; 8],
+    is_final: bool,
+}
+
+struct Assigner<'r, 'a, F: Field> {
+    config: &'r Blake2fCircuitConfig<F>,
+    region: &'r mut Region<'a, F>,
+    offset: usize,
+    challenge: Value<F>,
+}
+
+impl<F: Field> Assigner<'_, '_, F> {
+    fn row(&mut self, value: Src<'_, F>, aux: Aux<'_, F>, rlc: Value<F>) -> Result<Row<F>, Error> {
+        let config = self.config;
+        let offset = self.offset;
+
+        let word = match value {
+            Src::Word(word) => Word {
+                cell: word.cell.copy_advice(
+                    || "blake2f value",
+                    self.region,
+                    config.value,
+                    offset,
+                )?,
+                value: word.value,
+            },
+            Src::Const(value) => Word {
+                cell: self.region.assign_advice_from_constant(
+                    || "blake2f value",
+                    config.value,
+                    offset,
+                    F::from(value),
+                )?,
+                value,
+            },
+            Src::Free(value) => Word {
+                cell: self.region.assign_advice(
+                    || "blake2f value",
+                    config.value,
+                    offset,
+                    || Value::known(F::from(value)),
+                )?,
+                value,
+            },
+        };
+        for (&column, byte) in config.bytes.iter().zip(word.value.to_le_bytes()) {
+            self.region.assign_advice(
+                || "blake2f byte",
+                column,
+                offset,
+                || Value::known(F::from(byte as u64)),
+            )?;
+        }
+        match aux {
+            Aux::Zero => {
+                self.region.assign_advice(
+                    || "blake2f aux",
+                    config.aux,
+                    offset,
+                    || Value::known(F::zero()),
+                )?;
+            }
+            Aux::Word(word) => {
+                word.cell
+                    .copy_advice(|| "blake2f aux", self.region, config.aux, offset)?;
+            }
+            Aux::Free(value) => {
+                self.region.assign_advice(
+                    || "blake2f aux",
+                    config.aux,
+                    offset,
+                    || Value::known(value),
+                )?;
+            }
+        }
+        let rlc = self
+            .region
+            .assign_advice(|| "blake2f rlc", config.rlc, offset, || rlc)?;
+        config.q_word.enable(self.region, offset)?;
+
+        self.offset += 1;
+        Ok(Row { word, rlc })
+    }
+
+    fn word(&mut self, value: Src<'_, F>) -> Result<Word<F>, Error> {
+        Ok(self.row(value, Aux::Zero, Value::known(F::zero()))?.word)
+    }
+
+    fn word_with_aux(&mut self, value: Src<'_, F>, aux: Aux<'_, F>) -> Result<Word<F>, Error> {
+        Ok(self.row(value, aux, Value::known(F::zero()))?.word)
+    }
+
+    /// Row in the RLC of `rlc_bytes`, accumulated over `rlc_prev`.
+    fn rlc_row(
+        &mut self,
+        selector: Selector,
+        value: Src<'_, F>,
+        rlc_bytes: &[u8],
+        rlc_prev: Value<F>,
+    ) -> Result<Row<F>, Error> {
+        let rlc = rlc_prev.zip(self.challenge).map(|(rlc_prev, r)| {
+            rlc_bytes
+                .iter()
+                .fold(rlc_prev, |acc, &byte| acc * r + F::from(byte as u64))
+        });
+        selector.enable(self.region, self.offset)?;
+        self.row(value, Aux::Zero, rlc)
+    }
+
+    /// rotr(x ^ y, 8 * rotation)
+    fn xor(&mut self, x: Src<'_, F>, y: Src<'_, F>, rotation: usize) -> Result<Word<F>, Error> {
+        let selector = XOR_ROTATIONS
+            .iter()
+            .position(|&r| r == rotation)
+            .expect("unsupported xor rotation");
+        self.config.q_xor[selector].enable(self.region, self.offset)?;
+        let x = self.word(x)?;
+        let y = self.word(y)?;
+        self.word(Src::Free(
+            (x.value ^ y.value).rotate_right(8 * rotation as u32),
+        ))
+    }
+
+    /// rotr(x, 63)
+    fn rotr63(&mut self, x: Src<'_, F>) -> Result<Word<F>, Error> {
+        self.config.q_rotr63.enable(self.region, self.offset)?;
+        let hi = x.value() >> 63;
+        let x = self.word_with_aux(x, Aux::Free(F::from(hi)))?;
+        self.word(Src::Free(x.value.rotate_right(63)))
+    }
+
+    /// Wrapping addition of 2 words.
+    fn add(&mut self, terms: [Src<'_, F>; 2]) -> Result<Word<F>, Error> {
+        self.config.q_add.enable(self.region, self.offset)?;
+        self.add_terms(terms.into_iter())
+    }
+
+    /// Wrapping addition of 3 words.
+    fn add3(&mut self, terms: [Src<'_, F>; 3]) -> Result<Word<F>, Error> {
+        self.config.q_add3.enable(self.region, self.offset)?;
+        self.add_terms(terms.into_iter())
+    }
+
+    fn add_terms<'w>(&mut self, terms: impl Iterator<Item = Src<'w, F>>) -> Result<Word<F>, Error>
+    where
+        F: 'w,
+    {
+        let mut sum = 0u128;
+        for term in terms {
+            sum += self.word(term)?.value as u128;
+        }
+        self.word_with_aux(
+            Src::Free(sum as u64),
+            Aux::Free(F::from((sum >> 64) as u64)),
+        )
+    }
+
+    /// cond ? b : a
+    fn select(&mut self, cond: &Word<F>, a: Src<'_, F>, b: Src<'_, F>) -> Result<Word<F>, Error> {
+        self.config.q_select.enable(self.region, self.offset)?;
+        let a = self.word(a)?;
+        let b = self.word(b)?;
+        let out = if cond.value == 1 { b.value } else { a.value };
+        self.word_with_aux(Src::Free(out), Aux::Word(cond))
+    }
+
+    /// cond ? b : a, on the RLC column. `a` is zero when it is `None`.
+    fn select_rlc(
+        &mut self,
+        cond: &Word<F>,
+        a: Option<&AssignedCell<F, F>>,
+        b: &AssignedCell<F, F>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        self.config.q_select.enable(self.region, self.offset)?;
+        let a_value = a.map_or(Value::known(F::zero()), |a| a.value().copied());
+        let a_row = self.row(Src::Free(0), Aux::Zero, a_value)?;
+        if let Some(a) = a {
+            self.region.constrain_equal(a.cell(), a_row.rlc.cell())?;
+        }
+        let b_row = self.row(Src::Free(0), Aux::Zero, b.value().copied())?;
+        self.region.constrain_equal(b.cell(), b_row.rlc.cell())?;
+        let out = if cond.value == 1 {
+            b_row.rlc
+        } else {
+            a_row.rlc
+        };
+        Ok(self
+            .row(Src::Free(0), Aux::Word(cond), out.value().copied())?
+            .rlc)
+    }
+
+    /// The option with the flag set among one-hot flags.
+    fn mux(&mut self, flags: &[Word<F>], options: &[&Word<F>]) -> Result<Word<F>, Error> {
+        self.config.q_mux.enable(self.region, self.offset)?;
+        let mut out = 0;
+        for (flag, option) in flags.iter().zip(options) {
+            self.word_with_aux(Src::Word(option), Aux::Word(flag))?;
+            if flag.value == 1 {
+                out = option.value;
+            }
+        }
+        self.word(Src::Free(out))
+    }
+
+    /// x == 0
+    fn is_zero(&mut self, x: &Word<F>) -> Result<Word<F>, Error> {
+        self.config.q_is_zero.enable(self.region, self.offset)?;
+        let x_inv = F::from(x.value).invert().unwrap_or(F::zero());
+        self.word_with_aux(Src::Word(x), Aux::Free(x_inv))?;
+        self.word(Src::Free((x.value == 0) as u64))
+    }
+
+    /// rounds_left - 1 + is_zero
+    fn round_step(&mut self, rounds_left: &Word<F>, is_zero: &Word<F>) -> Result<Word<F>, Error> {
+        self.config.q_round_step.enable(self.region, self.offset)?;
+        self.word(Src::Word(rounds_left))?;
+        self.word(Src::Word(is_zero))?;
+        self.word(Src::Free(rounds_left.value + is_zero.value - 1))
+    }
+
+    /// f ? 0xff..ff : 0
+    fn flag_mask(&mut self, f: &Word<F>) -> Result<Word<F>, Error> {
+        self.config.q_flag_mask.enable(self.region, self.offset)?;
+        self.word(Src::Word(f))?;
+        self.word(Src::Free(if f.value == 1 { u64::MAX } else { 0 }))
+    }
+
+    /// The G mixing function on `v[a]`, `v[b]`, `v[c]` and `v[d]`.
+    fn g(
+        &mut self,
+        v: &mut [Word<F>],
+        [a, b, c, d]: [usize; 4],
+        x: &Word<F>,
+        y: &Word<F>,
+    ) -> Result<(), Error> {
+        v[a] = self.add3([Src::Word(&v[a]), Src::Word(&v[b]), Src::Word(x)])?;
+        v[d] = self.xor(Src::Word(&v[d]), Src::Word(&v[a]), 4)?;
+        v[c] = self.add([Src::Word(&v[c]), Src::Word(&v[d])])?;
+        v[b] = self.xor(Src::Word(&v[b]), Src::Word(&v[c]), 3)?;
+        v[a] = self.add3([Src::Word(&v[a]), Src::Word(&v[b]), Src::Word(y)])?;
+        v[d] = self.xor(Src::Word(&v[d]), Src::Word(&v[a]), 2)?;
+        v[c] = self.add([Src::Word(&v[c]), Src::Word(&v[d])])?;
+        let b_xor_c = self.xor(Src::Word(&v[b]), Src::Word(&v[c]), 0)?;
+        v[b] = self.rotr63(Src::Word(&b_xor_c))?;
+        Ok(())
+    }
+
+    /// Assign a round of `event`, which starts the compression when `is_first`
+    /// and otherwise continues from `carry`.
+    fn assign_round(
+        &mut self,
+        event: &Blake2F,
+        is_first: bool,
+        carry: Option<&Carry<F>>,
+    ) -> Result<Carry<F>, Error> {
+        let config = self.config;
+        let start = self.offset;
+        let is_first = self.word(if carry.is_none() {
+            Src::Const(1)
+        } else {
+            Src::Free(is_first as u64)
+        })?;
+
+        // fresh inputs and their RLC
+        let mut fresh = Vec::with_capacity(INPUT_WORDS);
+        let row = self.rlc_row(
+            config.q_rlc_rounds,
+            Src::Free(event.rounds as u64),
+            &event.rounds.to_be_bytes(),
+            Value::known(F::zero()),
+        )?;
+        let mut rlc = row.rlc.value().copied();
+        fresh.push(row.word);
+        for &word in event.h.iter().chain(&event.m).chain(&event.t) {
+            let row = self.rlc_row(config.q_rlc_word, Src::Free(word), &word.to_le_bytes(), rlc)?;
+            rlc = row.rlc.value().copied();
+            fresh.push(row.word);
+        }
+        let row = self.rlc_row(
+            config.q_rlc_flag,
+            Src::Free(event.f as u64),
+            &[event.f as u8],
+            rlc,
+        )?;
+        fresh.push(row.word);
+        let fresh_input_rlc = row.rlc;
+
+        // start from the fresh inputs or continue from the carried ones
+        // the carried words are dummy zeros in the first round of the circuit
+        let mut inputs = Vec::with_capacity(INPUT_WORDS);
+        for (i, word) in fresh.iter().enumerate() {
+            let a = carry.map_or(Src::Free(0), |carry| Src::Word(&carry.inputs[i]));
+            inputs.push(self.select(&is_first, a, Src::Word(word))?);
+        }
+        let input_rlc = self.select_rlc(
+            &is_first,
+            carry.map(|carry| &carry.input_rlc),
+            &fresh_input_rlc,
+        )?;
+        let rounds_left = self.select(
+            &is_first,
+            carry.map_or(Src::Free(0), |carry| Src::Word(&carry.rounds_left)),
+            Src::Word(&fresh[0]),
+        )?;
+        let mut flags = Vec::with_capacity(10);
+        for j in 0..10 {
+            // the flags of the previous round, rotated by one
+            let a = carry.map_or(Src::Free(0), |carry| Src::Word(&carry.flags[(j + 9) % 10]));
+            flags.push(self.select(&is_first, a, Src::Const((j == 0) as u64))?);
+        }
+        let (h, m, t, f) = (&inputs[1..9], &inputs[9..25], &inputs[25..27], &inputs[27]);
+
+        // initial working vector
+        let mask = self.flag_mask(f)?;
+        let v12 = self.xor(Src::Const(IV[4]), Src::Word(&t[0]), 0)?;
+        let v13 = self.xor(Src::Const(IV[5]), Src::Word(&t[1]), 0)?;
+        let v14 = self.xor(Src::Const(IV[6]), Src::Word(&mask), 0)?;
+        let mut v = Vec::with_capacity(16);
+        for i in 0..16 {
+            let init = match i {
+                0..=7 => Src::Word(&h[i]),
+                12 => Src::Word(&v12),
+                13 => Src::Word(&v13),
+                14 => Src::Word(&v14),
+                _ => Src::Const(IV[i - 8]),
+            };
+            let a = carry.map_or(Src::Free(0), |carry| Src::Word(&carry.v[i]));
+            v.push(self.select(&is_first, a, init)?);
+        }
+
+        // no round is applied once all the rounds are done
+        let no_round = self.is_zero(&rounds_left)?;
+        let rounds_left = self.round_step(&rounds_left, &no_round)?;
+
+        // message schedule of the round
+        let mut s = Vec::with_capacity(16);
+        for k in 0..16 {
+            let options = SIGMA.iter().map(|sigma| &m[sigma[k]]).collect::<Vec<_>>();
+            s.push(self.mux(&flags, &options)?);
+        }
+
+        let mut mixed = v.clone();
+        for (i, indices) in G_INDICES.into_iter().enumerate() {
+            self.g(&mut mixed, indices, &s[2 * i], &s[2 * i + 1])?;
+        }
+        let mut v_next = Vec::with_capacity(16);
+        for (mixed, v) in mixed.iter().zip(&v) {
+            v_next.push(self.select(&no_round, Src::Word(mixed), Src::Word(v))?);
+        }
+
+        // output and its RLC
+        let mut output = Vec::with_capacity(8);
+        for i in 0..8 {
+            let v_xor = self.xor(Src::Word(&v_next[i]), Src::Word(&v_next[i + 8]), 0)?;
+            output.push(self.xor(Src::Word(&h[i]), Src::Word(&v_xor), 0)?);
+        }
+        let mut rlc = Value::known(F::zero());
+        let mut output_rlc = None;
+        for (i, word) in output.iter().enumerate() {
+            let selector = if i == 0 {
+                config.q_rlc_word_first
+            } else {
+                config.q_rlc_word
+            };
+            let row = self.rlc_row(selector, Src::Word(word), &word.value.to_le_bytes(), rlc)?;
+            rlc = row.rlc.value().copied();
+            output_rlc = Some(row.rlc);
+        }
+        let output_rlc = output_rlc.expect("output has 8 words");
+        let is_final = self.is_zero(&rounds_left)?;
+        debug_assert_eq!(self.offset - start, ROWS_PER_ROUND);
+
+        // blake2f table row
+        let table = &config.blake2f_table;
+        self.region.assign_fixed(
+            || "blake2f table q_enable",
+            table.q_enable,
+            start,
+            || Value::known(F::one()),
+        )?;
+        for (cell, column) in [
+            (&is_final.cell, table.is_final),
+            (&inputs[0].cell, table.rounds),
+            (&input_rlc, table.input_rlc),
+            (&output_rlc, table.output_rlc),
+        ] {
+            cell.copy_advice(|| "blake2f table", self.region, column, start)?;
+        }
+
+        Ok(Carry {
+            inputs,
+            input_rlc,
+            rounds_left,
+            flags,
+            v: v_next,
+            output: output
+                .iter()
+                .map(|word| word.value)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            is_final: is_final.value == 1,
+        })
+    }
+}
+
+/// BLAKE2F circuit for precompile blake2f
+#[derive(Clone, Debug, Default)]
+pub struct Blake2fCircuit<F: Field> {
+    /// BLAKE2F compressions
+    pub events: Vec<Blake2F>,
+    /// Maximum number of rounds, or 0 to assign only the compressions
+    pub max_rounds: usize,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> Blake2fCircuit<F> {
+    /// Return a new Blake2fCircuit working within `row_limit` rows, or with
+    /// exactly the rows of the compressions if it is 0.
+    pub fn new(events: Vec<Blake2F>, row_limit: usize) -> Self {
+        if row_limit != 0 {
+            let expected_rows = Self::expected_rows(&events);
+            log::info!(
+                "blake2f circuit work with {} compressions, set with maximum {} rows",
+                events.len(),
+                row_limit
+            );
+            assert!(
+                expected_rows <= row_limit,
+                "no enough rows for blake2f circuit, expected {expected_rows}, limit {row_limit}",
+            );
+        }
+        Self {
+            events,
+            max_rounds: row_limit / ROWS_PER_ROUND,
+            _marker: PhantomData,
+        }
+    }
+
+    fn expected_rows(events: &[Blake2F]) -> usize {
+        events.iter().map(num_rounds).sum::<usize>() * ROWS_PER_ROUND
+    }
+}
+
+impl<F: Field> SubCircuit<F> for Blake2fCircuit<F> {
+    type Config = Blake2fCircuitConfig<F>;
+
+    fn unusable_rows() -> usize {
+        // The value column is queried at 11 distinct rotations in the mux gate,
+        // so returns 14 unusable rows.
+        14
+    }
+
+    fn new_from_block(block: &witness::Block) -> Self {
+        Self::new(block.get_blake2f(), block.circuits_params.max_keccak_rows)
+    }
+
+    fn min_num_rows_block(block: &witness::Block) -> (usize, usize) {
+        let real_rows = Self::expected_rows(&block.get_blake2f());
+        (
+            real_rows,
+            real_rows
+                .max(block.circuits_params.max_keccak_rows)
+                .max(XOR_TABLE_ROWS),
+        )
+    }
+
+    fn synthesize_sub(
+        &self,
+        config: &Self::Config,
+        challenges: &Challenges<Value<F>>,
+        layouter: &mut impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.assign(layouter, &self.events, self.max_rounds, challenges)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    blake2f_circuit::{Blake2fCircuit, ROWS_PER_ROUND},
+    util::{unusable_rows, SubCircuit},
+};
+use bus_mapping::circuit_input_builder::Blake2F;
+use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
+
+/// h, m and t of the EIP-152 test vectors, without the rounds and the flag.
+const INPUT_HMT: &str = "48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b616263000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000003000000000000000000000000000000";
+
+/// EIP-152 test vectors 4 to 7.
+const VECTORS: [(u32, bool, &str); 4] = [
+    (
+        0,
+        true,
+        "08c9bcf367e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5d282e6ad7f520e511f6c3e2b8c68059b9442be0454267ce079217e1319cde05b",
+    ),
+    (
+        12,
+        true,
+        "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+    ),
+    (
+        12,
+        false,
+        "75ab69d3190a562c51aef8d88f1c2775876944407270c42c9844252c26d2875298743e7f6d5ea2f2d3e8d226039cd31b4e426ac4f2d3d666a610c2116fde4735",
+    ),
+    (
+        1,
+        true,
+        "b63a380cb2897d521994a85234ee2c181b5f844d2c624c002677e9703449d2fba551b3a8333bcdf5f2f7e08993d53923de3d64fcc68c034e717b9293fed7a421",
+    ),
+];
+
+fn event(rounds: u32, f: bool, output: &str) -> Blake2F {
+    let input = std::iter::empty()
+        .chain(rounds.to_be_bytes())
+        .chain(hex::decode(INPUT_HMT).unwrap())
+        .chain([f as u8])
+        .collect::<Vec<_>>();
+    Blake2F::new_from_bytes(&input, &hex::decode(output).unwrap())
+}
+
+fn run(
+    events: Vec<Blake2F>,
+    row_limit: usize,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let circuit = Blake2fCircuit::<Fr>::new(events, row_limit);
+    let prover = MockProver::<Fr>::run(17, &circuit, vec![]).unwrap();
+    prover.verify_par()
+}
+
+#[test]
+fn blake2f_circuit_unusable_rows() {
+    assert_eq!(
+        Blake2fCircuit::<Fr>::unusable_rows(),
+        unusable_rows::<Fr, Blake2fCircuit::<Fr>>(),
+    )
+}
+
+#[test]
+fn blake2f_circuit_eip152_vectors() {
+    for (rounds, f, output) in VECTORS {
+        assert_eq!(run(vec![event(rounds, f, output)], 0), Ok(()));
+    }
+}
+
+#[test]
+fn blake2f_circuit_multiple_compressions_with_padding() {
+    let events = VECTORS
+        .iter()
+        .map(|&(rounds, f, output)| event(rounds, f, output))
+        .collect();
+    assert_eq!(run(events, 40 * ROWS_PER_ROUND), Ok(()));
+}
+
+#[test]
+fn blake2f_circuit_wrong_output() {
+    let mut event = event(VECTORS[1].0, VECTORS[1].1, VECTORS[1].2);
+    event.output[0] ^= 1;
+    let circuit = Blake2fCircuit::<Fr>::new(vec![event], 0);
+    assert!(MockProver::<Fr>::run(17, &circuit, vec![]).is_err());
+}
@@ -5,7 +5,9 @@ use std::{iter, marker::PhantomData};
 
 use crate::util::Field;
 use bus_mapping::{
-    circuit_input_builder::{EcAddOp, EcMulOp, EcPairingOp, N_BYTES_PER_PAIR, N_PAIRING_PER_OP},
+    circuit_input_builder::{
+        EcAddOp, EcMulOp, EcPairingOp, PrecompileEcParams, N_BYTES_PER_PAIR, N_PAIRING_PER_OP,
+    },
     precompile::PrecompileCalls,
 };
 use eth_types::{ToLittleEndian, U256};
@@ -26,13 +28,18 @@ use halo2_ecc::{
         FieldChip, FieldExtPoint,
     },
 };
+#[cfg(feature = "onephase")]
+use halo2_proofs::plonk::FirstPhase as SecondPhase;
+#[cfg(not(feature = "onephase"))]
+use halo2_proofs::plonk::SecondPhase;
 use halo2_proofs::{
     circuit::{Layouter, Value},
     halo2curves::{
         bn256::{Fq, Fq12, Fq2, Fr, G1Affine, G2Affine},
         CurveAffine,
     },
-    plonk::{ConstraintSystem, Error, Expression},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
 };
 use itertools::Itertools;
 use log::error;
@@ -51,9 +58,10 @@ mod test;
 mod util;
 
 use util::{
-    EcAddAssigned, EcAddDecomposed, EcMulAssigned, EcMulDecomposed, EcOpsAssigned,
-    EcPairingAssigned, EcPairingDecomposed, G1Assigned, G1Decomposed, G2Decomposed, ScalarAssigned,
-    LOG_TOTAL_NUM_ROWS,
+    calc_batched_pairing_advices, calc_batched_pairing_lookup_advices, EcAddAssigned,
+    EcAddDecomposed, EcMulAssigned, EcMulDecomposed, EcOpsAssigned, EcPairingAssigned,
+    EcPairingDecomposed, EcPairingPrechecked, G1Assigned, G1Decomposed, G2Decomposed,
+    PairingBatchedPair, ScalarAssigned, TupleG1sG2s, BATCH_RAND_BITS, LOG_TOTAL_NUM_ROWS,
 };
 
 macro_rules! log_context_cursor {
@@ -69,6 +77,9 @@ pub struct EccCircuitConfigArgs<F: Field> {
     pub ecc_table: EccTable,
     /// zkEVM challenge API.
     pub challenges: Challenges<Expression<F>>,
+    /// Maximum number of batched EcPairing ops, which the columns for the batched pairing checks
+    /// are sized for. At most [`PrecompileEcParams::MAX_EC_PAIRING_BATCHED`].
+    pub max_pairing_batched_ops: usize,
 }
 
 /// Config for the ECC circuit.
@@ -78,6 +89,10 @@ pub struct EccCircuitConfig<F: Field> {
     fp_config: FpConfig<F, Fq>,
     /// Lookup table for I/Os to the EcAdd, EcMul and EcPairing operations.
     ecc_table: EccTable,
+    /// The randomness the batched pairing checks are combined with, fixed to the keccak input
+    /// challenge at the first row.
+    batch_rand: Column<Advice>,
+    q_batch_rand: Selector,
 
     /// Number of limbs to represent Fp.
     num_limbs: usize,
@@ -94,23 +109,38 @@ impl<F: Field> SubCircuitConfig<F> for EccCircuitConfig<F> {
         meta: &mut ConstraintSystem<F>,
         Self::ConfigArgs {
             ecc_table,
-            challenges: _,
+            challenges,
+            max_pairing_batched_ops,
         }: Self::ConfigArgs,
     ) -> Self {
+        assert!(
+            max_pairing_batched_ops <= PrecompileEcParams::MAX_EC_PAIRING_BATCHED,
+            "at most {} batched EcPairing ops are supported, got {max_pairing_batched_ops}",
+            PrecompileEcParams::MAX_EC_PAIRING_BATCHED,
+        );
         let num_limbs = 3;
         let limb_bits = 88;
+        // the batched pairing checks are done in the second phase, as they are combined with
+        // randomness.
+        let num_batched_advice = calc_batched_pairing_advices(max_pairing_batched_ops);
+        let num_batched_lookup_advice =
+            calc_batched_pairing_lookup_advices(max_pairing_batched_ops);
         #[cfg(feature = "onephase")]
-        let num_advice = [35];
+        let (num_advice, num_lookup_advice) =
+            ([35 + num_batched_advice], [17 + num_batched_lookup_advice]);
         #[cfg(not(feature = "onephase"))]
-        let num_advice = [35, 1];
+        let (num_advice, num_lookup_advice) = (
+            [35, 1 + num_batched_advice],
+            [17, num_batched_lookup_advice],
+        );
 
         let fp_config = FpConfig::configure(
             meta,
             FpStrategy::Simple,
             &num_advice,
-            &[17], // num lookup advice
-            1,     // num fixed
-            13,    // lookup bits
+            &num_lookup_advice,
+            1,  // num fixed
+            13, // lookup bits
             limb_bits,
             num_limbs,
             modulus::<Fq>(),
@@ -122,9 +152,20 @@ impl<F: Field> SubCircuitConfig<F> for EccCircuitConfig<F> {
             meta.enable_equality(column);
         }
 
+        let batch_rand = meta.advice_column_in(SecondPhase);
+        let q_batch_rand = meta.selector();
+        meta.enable_equality(batch_rand);
+        meta.create_gate("ecc circuit: batch randomness", |meta| {
+            let q_batch_rand = meta.query_selector(q_batch_rand);
+            let batch_rand = meta.query_advice(batch_rand, Rotation::cur());
+            vec![q_batch_rand * (batch_rand - challenges.keccak_input())]
+        });
+
         Self {
             fp_config,
             ecc_table,
+            batch_rand,
+            q_batch_rand,
             num_limbs,
             limb_bits,
             _marker: PhantomData,
@@ -141,6 +182,11 @@ impl<F: Field> SubCircuitConfig<F> for EccCircuitConfig<F> {
 /// We follow a strategy to pre-allocate maximum number of cells for each of the above ECC
 /// operations, which means a witness that exceeds the pre-allocated number of cells for any of the
 /// operations will be invalid.
+///
+/// The pairing checks of the EcPairing operations succeeding for valid inputs can be batched,
+/// sharing a single final exponentiation, see [`EccCircuit::check_ec_pairings_batched`]. The
+/// other EcPairing operations, and the ones in excess of the batch capacity, are checked on their
+/// own.
 #[derive(Clone, Debug, Default)]
 pub struct EccCircuit<F: Field, const XI_0: i64> {
     /// Maximum number of EcAdd operations supported in one instance of the ECC Circuit.
@@ -150,6 +196,9 @@ pub struct EccCircuit<F: Field, const XI_0: i64> {
     pub max_mul_ops: usize,
     /// Maximum number of pairing operations supported in one instance of the ECC Circuit.
     pub max_pairing_ops: usize,
+    /// Maximum number of pairing operations checked in the batch in one instance of the ECC
+    /// Circuit, at most [`PrecompileEcParams::MAX_EC_PAIRING_BATCHED`].
+    pub max_pairing_batched_ops: usize,
 
     /// EcAdd operations provided as witness data to the ECC circuit.
     pub add_ops: Vec<EcAddOp>,
@@ -176,6 +225,21 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
         (1 << LOG_TOTAL_NUM_ROWS) - (max_blinding_factor + 3)
     }
 
+    /// Splits the EcPairing operations into the ones checked in the batch and the ones checked on
+    /// their own. The batch is filled first.
+    fn split_pairing_ops(&self) -> (Vec<EcPairingOp>, Vec<EcPairingOp>) {
+        let (mut batched, mut single): (Vec<_>, Vec<_>) = self
+            .pairing_ops
+            .iter()
+            .filter(|op| !op.skip_by_ecc_circuit())
+            .cloned()
+            .partition(|op| op.is_batchable());
+        if batched.len() > self.max_pairing_batched_ops {
+            single.extend(batched.split_off(self.max_pairing_batched_ops));
+        }
+        (batched, single)
+    }
+
     /// Assign witness from the ecXX ops to the circuit.
     pub(crate) fn assign(
         &self,
@@ -183,21 +247,23 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
         config: &<Self as SubCircuit<F>>::Config,
         challenges: &Challenges<Value<F>>,
     ) -> Result<(), Error> {
+        let (pairing_ops_batched, pairing_ops) = self.split_pairing_ops();
         if self.add_ops.len() > self.max_add_ops
             || self.mul_ops.len() > self.max_mul_ops
-            || self.pairing_ops.len() > self.max_pairing_ops
+            || pairing_ops.len() > self.max_pairing_ops
         {
             error!(
                 "add ops = {}, mul ops = {}, pairing ops = {} > max add ops = {}, max mul ops = {}, max pairing ops = {}",
                 self.add_ops.len(),
                 self.mul_ops.len(),
-                self.pairing_ops.len(),
+                pairing_ops.len(),
                 self.max_add_ops,
                 self.max_mul_ops,
                 self.max_pairing_ops,
             );
             return Err(Error::Synthesis);
         }
+        let dummy_pairing_check_ok = EcPairingOp::dummy_pairing_check_ok();
 
         // keccak powers of randomness.
         let keccak_powers = std::iter::successors(Some(Value::known(F::one())), |coeff| {
@@ -276,13 +342,31 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                     decompose_ec_op!(EcMulOp, self.mul_ops, self.max_mul_ops, decompose_ec_mul_op);
 
                 // e(G1 . G2) * ... * e(G1 . G2) -> Gt
-                let ec_pairings_decomposed = decompose_ec_op!(
+                let mut ec_pairings_decomposed = decompose_ec_op!(
                     EcPairingOp,
-                    self.pairing_ops,
+                    pairing_ops,
                     self.max_pairing_ops,
                     decompose_ec_pairing_op
                 );
 
+                // the batched ops are padded with ops satisfying the pairing check.
+                let (ec_pairings_batched_decomposed, ec_pairings_batched_pairs): (Vec<_>, Vec<_>) =
+                    pairing_ops_batched
+                        .iter()
+                        .chain(std::iter::repeat(&dummy_pairing_check_ok))
+                        .take(self.max_pairing_batched_ops)
+                        .map(|op| {
+                            self.decompose_ec_pairing_batched_op(
+                                &mut ctx,
+                                &ecc_chip,
+                                &pairing_chip,
+                                &powers_of_256,
+                                op,
+                            )
+                        })
+                        .unzip();
+                ec_pairings_decomposed.extend(ec_pairings_batched_decomposed);
+
                 #[cfg(not(feature = "onephase"))]
                 {
                     // finalize after first phase.
@@ -290,6 +374,16 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                     ctx.next_phase();
                 }
 
+                // e(r_1 . G1 . G2) * ... * e(r_n . G1 . G2) -> Gt
+                let batch_rand = self.check_ec_pairings_batched(
+                    &mut ctx,
+                    &ecc_chip,
+                    &pairing_chip,
+                    &fp12_chip,
+                    &ec_pairings_batched_pairs,
+                    challenges.keccak_input(),
+                );
+
                 let ec_adds_assigned = assign_ec_op!(ec_adds_decomposed, assign_ec_add);
                 let ec_muls_assigned = assign_ec_op!(ec_muls_decomposed, assign_ec_mul);
                 let ec_pairings_assigned = assign_ec_op!(ec_pairings_decomposed, assign_ec_pairing);
@@ -303,6 +397,7 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                     ec_adds_assigned,
                     ec_muls_assigned,
                     ec_pairings_assigned,
+                    batch_rand: Some(batch_rand),
                 })
             },
         )?;
@@ -470,6 +565,12 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                     }
                 }
 
+                // fix the randomness of the batched pairing checks.
+                if let Some(batch_rand) = &assigned_ec_ops.batch_rand {
+                    config.q_batch_rand.enable(&mut region, 0)?;
+                    batch_rand.copy_advice(&mut region, config.batch_rand, 0);
+                }
+
                 Ok(())
             },
         )?;
@@ -694,6 +795,149 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
         let fp2_chip = Fp2Chip::<F, FpConfig<F, Fq>, Fq2>::construct(pairing_chip.fp_chip.clone());
         let ecc2_chip = EccChip::construct(fp2_chip.clone());
 
+        let EcPairingPrechecked {
+            decomposed_pairs,
+            input_cells,
+            all_pairs_zero,
+            is_valid,
+        } = self.precheck_ec_pairing_op(ctx, ecc_chip, &fp2_chip, powers_of_256, op);
+
+        // dummy G1, G2 points and G1::identity, G2::generator.
+        let dummy_g1 = ecc_chip.load_random_point::<G1Affine>(ctx);
+        let dummy_g2 = ecc2_chip.load_random_point::<G2Affine>(ctx);
+        let identity_g1 = EcPoint::construct(
+            ecc_chip
+                .field_chip()
+                .load_constant(ctx, fe_to_biguint(&Fq::zero())),
+            ecc_chip
+                .field_chip()
+                .load_constant(ctx, fe_to_biguint(&Fq::zero())),
+        );
+        let generator_g2 = {
+            let g2_gen = G2Affine::generator();
+            EcPoint::<F, FieldExtPoint<CRTInteger<F>>>::construct(
+                ecc2_chip.field_chip().load_constant(ctx, g2_gen.x),
+                ecc2_chip.field_chip().load_constant(ctx, g2_gen.y),
+            )
+        };
+        // A pairing op satisfying the pairing check.
+        let (dummy_pair_check_ok_g1s, dummy_pair_check_ok_g2s) =
+            self.load_pairing_check_ok_pairs(ctx, ecc_chip, &ecc2_chip);
+
+        // process pairs so that we pass only valid input to the multi_miller_loop.
+        let pairs = decomposed_pairs
+            .iter()
+            .enumerate()
+            .map(|(idx, (is_zero_pair, is_pair_valid, g1, g2))| {
+                // we should swap (G1, G2) with (G1::identity, G2::generator) if:
+                // - G1 == (0, 0) && G2 is valid
+                // - G2 == (0, 0, 0, 0) && G1 is valid
+                //
+                // we should swap (G1, G2) with (G1::random, G2::random) if:
+                // - G1 is invalid
+                // - G2 is invalid
+                (
+                    {
+                        let swapped_g1 =
+                            ecc_chip.select(ctx, &g1.ec_point, &dummy_g1, is_pair_valid);
+                        let swapped_g1 =
+                            ecc_chip.select(ctx, &identity_g1, &swapped_g1, is_zero_pair);
+                        ecc_chip.select(
+                            ctx,
+                            &dummy_pair_check_ok_g1s[idx],
+                            &swapped_g1,
+                            &all_pairs_zero,
+                        )
+                    },
+                    {
+                        let swapped_x =
+                            fp2_chip.select(ctx, &g2.ec_point.x, &dummy_g2.x, is_pair_valid);
+                        let swapped_x =
+                            fp2_chip.select(ctx, &generator_g2.x, &swapped_x, is_zero_pair);
+                        let swapped_x = fp2_chip.select(
+                            ctx,
+                            &dummy_pair_check_ok_g2s[idx].x,
+                            &swapped_x,
+                            &all_pairs_zero,
+                        );
+                        let swapped_y =
+                            fp2_chip.select(ctx, &g2.ec_point.y, &dummy_g2.y, is_pair_valid);
+                        let swapped_y =
+                            fp2_chip.select(ctx, &generator_g2.y, &swapped_y, is_zero_pair);
+                        let swapped_y = fp2_chip.select(
+                            ctx,
+                            &dummy_pair_check_ok_g2s[idx].y,
+                            &swapped_y,
+                            &all_pairs_zero,
+                        );
+                        EcPoint::construct(swapped_x, swapped_y)
+                    },
+                )
+            })
+            .collect_vec();
+        let pairs = pairs.iter().map(|(g1, g2)| (g1, g2)).collect_vec();
+
+        // multi-miller loop and final exponentiation to do pairing check.
+        let success = {
+            let gt = {
+                let gt = pairing_chip.multi_miller_loop(ctx, pairs);
+                pairing_chip.final_exp(ctx, &gt)
+            };
+            // whether pairing check was successful.
+            let one = fp12_chip.load_constant(ctx, Fq12::one());
+            fp12_chip.is_equal(ctx, &gt, &one)
+        };
+        // success == true only if pairing check and validity are both satisfied.
+        let success = ecc_chip.field_chip().range().gate().and(
+            ctx,
+            QuantumCell::Existing(is_valid),
+            QuantumCell::Existing(success),
+        );
+        // if all inputs were zeroes, i.e. either:
+        // - G1 == (0, 0) and G2 == random valid point on G2
+        // - G2 == (0, 0, 0, 0) and G1 == random valid point on G1
+        //
+        // then success == true, i.e. success - all_pairs_zero == boolean
+        let success_minus_all_pairs_zero = ecc_chip
+            .field_chip()
+            .range()
+            .gate()
+            .load_witness(ctx, success.value - all_pairs_zero.value);
+        ecc_chip
+            .field_chip()
+            .range()
+            .gate()
+            .assert_bit(ctx, success_minus_all_pairs_zero);
+
+        let op_output = ecc_chip.field_chip().range().gate().load_witness(
+            ctx,
+            Value::known(op.output.to_scalar().expect("EcPairing output = {0, 1}")),
+        );
+        ecc_chip.field_chip().range().gate().assert_equal(
+            ctx,
+            QuantumCell::Existing(success),
+            QuantumCell::Existing(op_output),
+        );
+
+        log::trace!("[ECC] EcPairingAssignment END:");
+        log_context_cursor!(ctx);
+
+        EcPairingDecomposed {
+            is_valid,
+            input_cells,
+            success,
+        }
+    }
+
+    /// Decomposes the (G1, G2) pairs of an EcPairing operation and checks their validity.
+    fn precheck_ec_pairing_op(
+        &self,
+        ctx: &mut Context<F>,
+        ecc_chip: &EccChip<F, FpConfig<F, Fq>>,
+        fp2_chip: &Fp2Chip<F, FpConfig<F, Fq>, Fq2>,
+        powers_of_256: &[QuantumCell<F>],
+        op: &EcPairingOp,
+    ) -> EcPairingPrechecked<F> {
         let decomposed_pairs = op
             .pairs
             .iter()
@@ -751,7 +995,7 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                 );
                 let g2_is_on_curve_or_infinity = self.is_on_curveg2_or_infinity(
                     ctx,
-                    &fp2_chip,
+                    fp2_chip,
                     &g2_point.x,
                     g2x_is_zero,
                     &g2_point.y,
@@ -852,9 +1096,82 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                 .collect_vec(),
         );
 
-        // dummy G1, G2 points and G1::identity, G2::generator.
-        let dummy_g1 = ecc_chip.load_random_point::<G1Affine>(ctx);
-        let dummy_g2 = ecc2_chip.load_random_point::<G2Affine>(ctx);
+        // if the entire input to ecPairing is valid.
+        let is_valid = ecc_chip.field_chip().range().gate().and_many(
+            ctx,
+            decomposed_pairs
+                .iter()
+                .map(|&(_, is_pair_valid, _, _)| QuantumCell::Existing(is_pair_valid))
+                .collect_vec(),
+        );
+
+        EcPairingPrechecked {
+            decomposed_pairs,
+            input_cells,
+            all_pairs_zero,
+            is_valid,
+        }
+    }
+
+    /// Loads the (G1, G2) pairs of an EcPairing operation satisfying the pairing check as
+    /// constants.
+    fn load_pairing_check_ok_pairs(
+        &self,
+        ctx: &mut Context<F>,
+        ecc_chip: &EccChip<F, FpConfig<F, Fq>>,
+        ecc2_chip: &EccChip<F, Fp2Chip<F, FpConfig<F, Fq>, Fq2>>,
+    ) -> TupleG1sG2s<F> {
+        EcPairingOp::dummy_pairing_check_ok()
+            .pairs
+            .iter()
+            .map(|pair| {
+                let (g1_point, g2_point) = pair.as_g1_g2().expect("dummy pairing check OK pair");
+                (
+                    EcPoint::<F, CRTInteger<F>>::construct(
+                        ecc_chip
+                            .field_chip()
+                            .load_constant(ctx, fe_to_biguint(&g1_point.x)),
+                        ecc_chip
+                            .field_chip()
+                            .load_constant(ctx, fe_to_biguint(&g1_point.y)),
+                    ),
+                    EcPoint::<F, FieldExtPoint<CRTInteger<F>>>::construct(
+                        ecc2_chip.field_chip().load_constant(ctx, g2_point.x),
+                        ecc2_chip.field_chip().load_constant(ctx, g2_point.y),
+                    ),
+                )
+            })
+            .unzip()
+    }
+
+    /// Decomposes an EcPairing operation checked in the batch, see
+    /// [`EccCircuit::check_ec_pairings_batched`], and returns the (G1, G2) pairs to be checked.
+    ///
+    /// Only operations whose pairing check passes if and only if their inputs are valid are
+    /// batched, so the success is the validity of the inputs. Invalid inputs, as well as inputs
+    /// of zero pairs only, are swapped for pairs satisfying the pairing check.
+    fn decompose_ec_pairing_batched_op(
+        &self,
+        ctx: &mut Context<F>,
+        ecc_chip: &EccChip<F, FpConfig<F, Fq>>,
+        pairing_chip: &PairingChip<F>,
+        powers_of_256: &[QuantumCell<F>],
+        op: &EcPairingOp,
+    ) -> (EcPairingDecomposed<F>, Vec<PairingBatchedPair<F>>) {
+        log::trace!("[ECC] ==> EcPairing (batched) Assignment START:");
+        log_context_cursor!(ctx);
+
+        let gate = ecc_chip.field_chip().range().gate();
+        let fp2_chip = Fp2Chip::<F, FpConfig<F, Fq>, Fq2>::construct(pairing_chip.fp_chip.clone());
+        let ecc2_chip = EccChip::construct(fp2_chip.clone());
+
+        let EcPairingPrechecked {
+            decomposed_pairs,
+            input_cells,
+            all_pairs_zero,
+            is_valid,
+        } = self.precheck_ec_pairing_op(ctx, ecc_chip, &fp2_chip, powers_of_256, op);
+
         let identity_g1 = EcPoint::construct(
             ecc_chip
                 .field_chip()
@@ -870,147 +1187,185 @@ impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
                 ecc2_chip.field_chip().load_constant(ctx, g2_gen.y),
             )
         };
-        // A pairing op satisfying the pairing check.
-        type TupleG1sG2s<F> = (
-            Vec<EcPoint<F, CRTInteger<F>>>,
-            Vec<EcPoint<F, FieldExtPoint<CRTInteger<F>>>>,
+        let (dummy_pair_check_ok_g1s, dummy_pair_check_ok_g2s) =
+            self.load_pairing_check_ok_pairs(ctx, ecc_chip, &ecc2_chip);
+        let dummy_pair_check_ok_g1_is_identity = EcPairingOp::dummy_pairing_check_ok()
+            .pairs
+            .map(|pair| pair.g1_point == (U256::zero(), U256::zero()));
+
+        // whether to swap all pairs for the ones satisfying the pairing check.
+        let is_invalid = gate.not(ctx, QuantumCell::Existing(is_valid));
+        let use_dummy = gate.or(
+            ctx,
+            QuantumCell::Existing(all_pairs_zero),
+            QuantumCell::Existing(is_invalid),
         );
-        let (dummy_pair_check_ok_g1s, dummy_pair_check_ok_g2s): TupleG1sG2s<F> =
-            EcPairingOp::dummy_pairing_check_ok()
-                .pairs
-                .iter()
-                .map(|pair| {
-                    let (g1_point, g2_point) =
-                        pair.as_g1_g2().expect("dummy pairing check OK pair");
-                    (
-                        EcPoint::<F, CRTInteger<F>>::construct(
-                            ecc_chip
-                                .field_chip()
-                                .load_constant(ctx, fe_to_biguint(&g1_point.x)),
-                            ecc_chip
-                                .field_chip()
-                                .load_constant(ctx, fe_to_biguint(&g1_point.y)),
-                        ),
-                        EcPoint::<F, FieldExtPoint<CRTInteger<F>>>::construct(
-                            ecc2_chip.field_chip().load_constant(ctx, g2_point.x),
-                            ecc2_chip.field_chip().load_constant(ctx, g2_point.y),
-                        ),
-                    )
-                })
-                .unzip();
+        let not_use_dummy = gate.not(ctx, QuantumCell::Existing(use_dummy));
 
-        // process pairs so that we pass only valid input to the multi_miller_loop.
         let pairs = decomposed_pairs
             .iter()
             .enumerate()
-            .map(|(idx, (is_zero_pair, is_pair_valid, g1, g2))| {
-                // we should swap (G1, G2) with (G1::identity, G2::generator) if:
-                // - G1 == (0, 0) && G2 is valid
-                // - G2 == (0, 0, 0, 0) && G1 is valid
-                //
-                // we should swap (G1, G2) with (G1::random, G2::random) if:
-                // - G1 is invalid
-                // - G2 is invalid
-                (
-                    {
-                        let swapped_g1 =
-                            ecc_chip.select(ctx, &g1.ec_point, &dummy_g1, is_pair_valid);
-                        let swapped_g1 =
-                            ecc_chip.select(ctx, &identity_g1, &swapped_g1, is_zero_pair);
-                        ecc_chip.select(
-                            ctx,
-                            &dummy_pair_check_ok_g1s[idx],
-                            &swapped_g1,
-                            &all_pairs_zero,
-                        )
-                    },
-                    {
-                        let swapped_x =
-                            fp2_chip.select(ctx, &g2.ec_point.x, &dummy_g2.x, is_pair_valid);
-                        let swapped_x =
-                            fp2_chip.select(ctx, &generator_g2.x, &swapped_x, is_zero_pair);
-                        let swapped_x = fp2_chip.select(
-                            ctx,
-                            &dummy_pair_check_ok_g2s[idx].x,
-                            &swapped_x,
-                            &all_pairs_zero,
-                        );
-                        let swapped_y =
-                            fp2_chip.select(ctx, &g2.ec_point.y, &dummy_g2.y, is_pair_valid);
-                        let swapped_y =
-                            fp2_chip.select(ctx, &generator_g2.y, &swapped_y, is_zero_pair);
-                        let swapped_y = fp2_chip.select(
-                            ctx,
-                            &dummy_pair_check_ok_g2s[idx].y,
-                            &swapped_y,
-                            &all_pairs_zero,
-                        );
-                        EcPoint::construct(swapped_x, swapped_y)
-                    },
-                )
+            .map(|(idx, (is_zero_pair, _, g1, g2))| {
+                // a zero pair is swapped with (G1::identity, G2::generator).
+                let g1_point = ecc_chip.select(ctx, &identity_g1, &g1.ec_point, is_zero_pair);
+                let g1_point =
+                    ecc_chip.select(ctx, &dummy_pair_check_ok_g1s[idx], &g1_point, &use_dummy);
+                let g2_x = fp2_chip.select(ctx, &generator_g2.x, &g2.ec_point.x, is_zero_pair);
+                let g2_x = fp2_chip.select(ctx, &dummy_pair_check_ok_g2s[idx].x, &g2_x, &use_dummy);
+                let g2_y = fp2_chip.select(ctx, &generator_g2.y, &g2.ec_point.y, is_zero_pair);
+                let g2_y = fp2_chip.select(ctx, &dummy_pair_check_ok_g2s[idx].y, &g2_y, &use_dummy);
+                let g1_is_identity = if dummy_pair_check_ok_g1_is_identity[idx] {
+                    gate.or(
+                        ctx,
+                        QuantumCell::Existing(use_dummy),
+                        QuantumCell::Existing(*is_zero_pair),
+                    )
+                } else {
+                    gate.and(
+                        ctx,
+                        QuantumCell::Existing(not_use_dummy),
+                        QuantumCell::Existing(*is_zero_pair),
+                    )
+                };
+                PairingBatchedPair {
+                    g1: g1_point,
+                    g2: EcPoint::construct(g2_x, g2_y),
+                    g1_is_identity,
+                }
             })
             .collect_vec();
-        let pairs = pairs.iter().map(|(g1, g2)| (g1, g2)).collect_vec();
 
-        // if the entire input to ecPairing is valid.
-        let is_valid = ecc_chip.field_chip().range().gate().and_many(
+        // the op is batched only if its pairing check passes for valid inputs.
+        let op_output = gate.load_witness(
             ctx,
-            decomposed_pairs
-                .iter()
-                .map(|&(_, is_pair_valid, _, _)| QuantumCell::Existing(is_pair_valid))
-                .collect_vec(),
+            Value::known(op.output.to_scalar().expect("EcPairing output = {0, 1}")),
+        );
+        gate.assert_equal(
+            ctx,
+            QuantumCell::Existing(is_valid),
+            QuantumCell::Existing(op_output),
         );
 
-        // multi-miller loop and final exponentiation to do pairing check.
-        let success = {
+        log::trace!("[ECC] EcPairing (batched) Assignment END:");
+        log_context_cursor!(ctx);
+
+        (
+            EcPairingDecomposed {
+                is_valid,
+                input_cells,
+                success: is_valid,
+            },
+            pairs,
+        )
+    }
+
+    /// Checks the pairing checks of the batched EcPairing operations with a single final
+    /// exponentiation, and returns the randomness they are combined with, which is to be fixed to
+    /// the keccak input challenge.
+    ///
+    /// With rho_i the low [`BATCH_RAND_BITS`] bits of r^(i+1), we check:
+    /// prod_i prod_j e(rho_i * G1_ij, G2_ij) == 1
+    /// As the pairs are assigned in the first phase, before the challenge r is drawn, a failing
+    /// pairing check is not cancelled out by the others except with negligible probability.
+    fn check_ec_pairings_batched(
+        &self,
+        ctx: &mut Context<F>,
+        ecc_chip: &EccChip<F, FpConfig<F, Fq>>,
+        pairing_chip: &PairingChip<F>,
+        fp12_chip: &Fp12Chip<F, FpConfig<F, Fq>, Fq12, XI_0>,
+        batched_pairs: &[Vec<PairingBatchedPair<F>>],
+        randomness: Value<F>,
+    ) -> AssignedValue<F> {
+        log::trace!("[ECC] ==> EcPairing batch check START:");
+        log_context_cursor!(ctx);
+
+        let gate = ecc_chip.field_chip().range().gate();
+        let batch_rand = gate.load_witness(ctx, randomness);
+
+        // the point at infinity is swapped with a random point for the scalar multiplication.
+        let random_g1 = ecc_chip.load_random_point::<G1Affine>(ctx);
+        let identity_g1 = EcPoint::construct(
+            ecc_chip
+                .field_chip()
+                .load_constant(ctx, fe_to_biguint(&Fq::zero())),
+            ecc_chip
+                .field_chip()
+                .load_constant(ctx, fe_to_biguint(&Fq::zero())),
+        );
+
+        let mut rand_pow = batch_rand;
+        let mut pairs = vec![];
+        for (idx, op_pairs) in batched_pairs.iter().enumerate() {
+            if idx > 0 {
+                rand_pow = gate.mul(
+                    ctx,
+                    QuantumCell::Existing(rand_pow),
+                    QuantumCell::Existing(batch_rand),
+                );
+            }
+            let rho = self.truncate_rand(ctx, ecc_chip, rand_pow);
+            for pair in op_pairs {
+                let g1 = ecc_chip.select(ctx, &random_g1, &pair.g1, &pair.g1_is_identity);
+                let g1 = ecc_chip.scalar_mult::<G1Affine>(ctx, &g1, &vec![rho], BATCH_RAND_BITS, 4);
+                let g1 = ecc_chip.select(ctx, &identity_g1, &g1, &pair.g1_is_identity);
+                pairs.push((g1, &pair.g2));
+            }
+        }
+
+        if !pairs.is_empty() {
             let gt = {
-                let gt = pairing_chip.multi_miller_loop(ctx, pairs);
+                let gt = pairing_chip
+                    .multi_miller_loop(ctx, pairs.iter().map(|(g1, g2)| (g1, *g2)).collect_vec());
                 pairing_chip.final_exp(ctx, &gt)
             };
-            // whether pairing check was successful.
             let one = fp12_chip.load_constant(ctx, Fq12::one());
-            fp12_chip.is_equal(ctx, &gt, &one)
+            let success = fp12_chip.is_equal(ctx, &gt, &one);
+            gate.assert_is_const(ctx, &success, F::one());
+        }
+
+        log::trace!("[ECC] EcPairing batch check END:");
+        log_context_cursor!(ctx);
+
+        batch_rand
+    }
+
+    /// Returns the low [`BATCH_RAND_BITS`] bits of a value.
+    fn truncate_rand(
+        &self,
+        ctx: &mut Context<F>,
+        ecc_chip: &EccChip<F, FpConfig<F, Fq>>,
+        value: AssignedValue<F>,
+    ) -> AssignedValue<F> {
+        let range = ecc_chip.field_chip().range();
+        let gate = range.gate();
+
+        let split = |v: F| {
+            let repr = v.to_repr();
+            let (mut lo, mut hi) = ([0u8; 32], [0u8; 32]);
+            lo[..16].copy_from_slice(&repr[..16]);
+            hi[..16].copy_from_slice(&repr[16..]);
+            (F::from_repr(lo).unwrap(), F::from_repr(hi).unwrap())
         };
-        // success == true only if pairing check and validity are both satisfied.
-        let success = ecc_chip.field_chip().range().gate().and(
-            ctx,
-            QuantumCell::Existing(is_valid),
-            QuantumCell::Existing(success),
-        );
-        // if all inputs were zeroes, i.e. either:
-        // - G1 == (0, 0) and G2 == random valid point on G2
-        // - G2 == (0, 0, 0, 0) and G1 == random valid point on G1
-        //
-        // then success == true, i.e. success - all_pairs_zero == boolean
-        let success_minus_all_pairs_zero = ecc_chip
-            .field_chip()
-            .range()
-            .gate()
-            .load_witness(ctx, success.value - all_pairs_zero.value);
-        ecc_chip
-            .field_chip()
-            .range()
-            .gate()
-            .assert_bit(ctx, success_minus_all_pairs_zero);
+        let lo = gate.load_witness(ctx, value.value.map(|v| split(v).0));
+        let hi = gate.load_witness(ctx, value.value.map(|v| split(v).1));
+        range.range_check(ctx, &lo, BATCH_RAND_BITS);
+        range.range_check(ctx, &hi, F::NUM_BITS as usize - BATCH_RAND_BITS);
 
-        let op_output = ecc_chip.field_chip().range().gate().load_witness(
+        let value_recovered = gate.inner_product(
             ctx,
-            Value::known(op.output.to_scalar().expect("EcPairing output = {0, 1}")),
+            vec![QuantumCell::Existing(lo), QuantumCell::Existing(hi)],
+            vec![
+                QuantumCell::Constant(F::one()),
+                QuantumCell::Constant(F::from_u128(1 << 127) * F::from(2)),
+            ],
         );
-        ecc_chip.field_chip().range().gate().assert_equal(
+        gate.assert_equal(
             ctx,
-            QuantumCell::Existing(success),
-            QuantumCell::Existing(op_output),
+            QuantumCell::Existing(value_recovered),
+            QuantumCell::Existing(value),
         );
 
-        log::trace!("[ECC] EcPairingAssignment END:");
-        log_context_cursor!(ctx);
-
-        EcPairingDecomposed {
-            is_valid,
-            input_cells,
-            success,
-        }
+        lo
     }
 
     /// Handles Phase2 for EcAdd operation and returns the RLC'd x and y co-ordinates of the G1
@@ -1318,10 +1673,18 @@ impl<F: Field, const XI_0: i64> SubCircuit<F> for EccCircuit<F, XI_0> {
     type Config = EccCircuitConfig<F>;
 
     fn new_from_block(block: &Block) -> Self {
+        assert!(
+            block.circuits_params.max_ec_ops.ec_pairing_batched
+                <= PrecompileEcParams::MAX_EC_PAIRING_BATCHED,
+            "max batched pairing ops = {} > {}",
+            block.circuits_params.max_ec_ops.ec_pairing_batched,
+            PrecompileEcParams::MAX_EC_PAIRING_BATCHED,
+        );
         Self {
             max_add_ops: block.circuits_params.max_ec_ops.ec_add,
             max_mul_ops: block.circuits_params.max_ec_ops.ec_mul,
             max_pairing_ops: block.circuits_params.max_ec_ops.ec_pairing,
+            max_pairing_batched_ops: block.circuits_params.max_ec_ops.ec_pairing_batched,
             add_ops: block.get_ec_add_ops(),
             mul_ops: block.get_ec_mul_ops(),
             pairing_ops: block.get_ec_pairing_ops(),
@@ -1362,10 +1725,13 @@ impl<F: Field, const XI_0: i64> SubCircuit<F> for EccCircuit<F, XI_0> {
 
         let ec_adds = block.get_ec_add_ops().len();
         let ec_muls = block.get_ec_mul_ops().len();
-        let ec_pairings = block.get_ec_pairing_ops().len();
+        let (ec_pairings_batched, ec_pairings) = {
+            let (batched, single) = Self::new_from_block(block).split_pairing_ops();
+            (batched.len(), single.len())
+        };
         let max_ec_ops = &block.circuits_params.max_ec_ops;
-        log::debug!("ecc circuit row usage: ecadd {ec_adds}/{}, ecmul {ec_muls}/{}, ecpairing {ec_pairings}/{}",
-        max_ec_ops.ec_add, max_ec_ops.ec_mul, max_ec_ops.ec_pairing);
+        log::debug!("ecc circuit row usage: ecadd {ec_adds}/{}, ecmul {ec_muls}/{}, ecpairing {ec_pairings}/{}, ecpairing batched {ec_pairings_batched}/{}",
+        max_ec_ops.ec_add, max_ec_ops.ec_mul, max_ec_ops.ec_pairing, max_ec_ops.ec_pairing_batched);
 
         // Instead of showing actual minimum row usage,
         // halo2-lib based circuits use min_row_num to represent a percentage of total-used capacity
@@ -1374,6 +1740,8 @@ impl<F: Field, const XI_0: i64> SubCircuit<F> for EccCircuit<F, XI_0> {
             (row_num / max_ec_ops.ec_add) * ec_adds,
             (row_num / max_ec_ops.ec_mul) * ec_muls,
             (row_num / max_ec_ops.ec_pairing) * ec_pairings,
+            // the batch is empty if it has no capacity.
+            (row_num / max_ec_ops.ec_pairing_batched.max(1)) * ec_pairings_batched,
         ]
         .into_iter()
         .max()
@@ -1,4 +1,5 @@
 use crate::util::Field;
+use bus_mapping::circuit_input_builder::PrecompileEcParams;
 use halo2_proofs::{
     circuit::{Layouter, SimpleFloorPlanner},
     plonk::{Challenge, Circuit, ConstraintSystem, Error},
@@ -14,13 +15,51 @@ use super::{EccCircuit, EccCircuitConfig, EccCircuitConfigArgs};
 impl<F: Field, const XI_0: i64> Circuit<F> for EccCircuit<F, XI_0> {
     type Config = (EccCircuitConfig<F>, Challenges<Challenge>);
     type FloorPlanner = SimpleFloorPlanner;
-    type Params = ();
+    type Params = PrecompileEcParams;
 
     fn without_witnesses(&self) -> Self {
-        Self::default()
+        Self {
+            max_add_ops: self.max_add_ops,
+            max_mul_ops: self.max_mul_ops,
+            max_pairing_ops: self.max_pairing_ops,
+            max_pairing_batched_ops: self.max_pairing_batched_ops,
+            ..Self::default()
+        }
     }
 
+    fn params(&self) -> Self::Params {
+        PrecompileEcParams {
+            ec_add: self.max_add_ops,
+            ec_mul: self.max_mul_ops,
+            ec_pairing: self.max_pairing_ops,
+            ec_pairing_batched: self.max_pairing_batched_ops,
+        }
+    }
+
+    fn configure_with_params(meta: &mut ConstraintSystem<F>, params: Self::Params) -> Self::Config {
+        Self::configure_with_batched_ops(meta, params.ec_pairing_batched)
+    }
+
+    /// Without params, the circuit is sized for the most batched EcPairing ops.
     fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        Self::configure_with_batched_ops(meta, PrecompileEcParams::MAX_EC_PAIRING_BATCHED)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let challenge_values = config.1.values(&layouter);
+        self.synthesize_sub(&config.0, &challenge_values, &mut layouter)
+    }
+}
+
+impl<F: Field, const XI_0: i64> EccCircuit<F, XI_0> {
+    fn configure_with_batched_ops(
+        meta: &mut ConstraintSystem<F>,
+        max_pairing_batched_ops: usize,
+    ) -> (EccCircuitConfig<F>, Challenges<Challenge>) {
         let ecc_table = EccTable::construct(meta);
         let challenges = Challenges::construct(meta);
         let challenge_exprs = challenges.exprs(meta);
@@ -30,18 +69,10 @@ impl<F: Field, const XI_0: i64> Circuit<F> for EccCircuit<F, XI_0> {
                 EccCircuitConfigArgs {
                     ecc_table,
                     challenges: challenge_exprs,
+                    max_pairing_batched_ops,
                 },
             ),
             challenges,
         )
     }
-
-    fn synthesize(
-        &self,
-        config: Self::Config,
-        mut layouter: impl Layouter<F>,
-    ) -> Result<(), Error> {
-        let challenge_values = config.1.values(&layouter);
-        self.synthesize_sub(&config.0, &challenge_values, &mut layouter)
-    }
 }
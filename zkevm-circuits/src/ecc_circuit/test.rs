@@ -1,5 +1,7 @@
 #![allow(clippy::needless_borrows_for_generic_args)]
 use std::{
+    cell::RefCell,
+    iter,
     marker::PhantomData,
     ops::{Add, Mul, Neg},
 };
@@ -9,14 +11,33 @@ use bus_mapping::circuit_input_builder::{
     EcAddOp, EcMulOp, EcPairingOp, EcPairingPair, PrecompileEcParams,
 };
 use eth_types::U256;
+use halo2_base::{Context, QuantumCell, SKIP_FIRST_PASS};
+use halo2_ecc::{
+    bn254::pairing::PairingChip,
+    ecc::EccChip,
+    fields::{fp::FpConfig, fp12::Fp12Chip},
+};
 use halo2_proofs::{
     arithmetic::Field as ArithmeticField,
+    circuit::{Layouter, SimpleFloorPlanner},
     dev::MockProver,
-    halo2curves::bn256::{Fr, G1Affine, G2Affine},
+    halo2curves::bn256::{Fq, Fq12, Fr, G1Affine, G2Affine},
+    plonk::{Challenge, Circuit, ConstraintSystem, Error},
 };
+use itertools::Itertools;
 use rand::{CryptoRng, Rng, RngCore};
 
-use crate::ecc_circuit::EccCircuit;
+use crate::{
+    ecc_circuit::{
+        util::{
+            self, EC_PAIRING_BATCHED_CELLS, EC_PAIRING_BATCHED_LOOKUP_CELLS,
+            EC_PAIRING_FINAL_EXP_CELLS, EC_PAIRING_FINAL_EXP_LOOKUP_CELLS,
+        },
+        EccCircuit, EccCircuitConfig,
+    },
+    evm_circuit::param::N_BYTES_WORD,
+    util::Challenges,
+};
 
 fn run<F: Field, const MUST_FAIL: bool>(
     k: u32,
@@ -29,6 +50,7 @@ fn run<F: Field, const MUST_FAIL: bool>(
         max_add_ops: max_ec_ops.ec_add,
         max_mul_ops: max_ec_ops.ec_mul,
         max_pairing_ops: max_ec_ops.ec_pairing,
+        max_pairing_batched_ops: max_ec_ops.ec_pairing_batched,
         add_ops,
         mul_ops,
         pairing_ops,
@@ -416,6 +438,7 @@ fn test_ecc_circuit_valid_invalid() {
             ec_add: 0,
             ec_mul: 0,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         },
         vec![],
         vec![],
@@ -428,6 +451,7 @@ fn test_ecc_circuit_valid_invalid() {
             ec_add: 0,
             ec_mul: 0,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         },
         vec![],
         vec![],
@@ -440,6 +464,7 @@ fn test_ecc_circuit_valid_invalid() {
             ec_add: 0,
             ec_mul: 0,
             ec_pairing: 2,
+            ec_pairing_batched: 0,
         },
         vec![],
         vec![],
@@ -495,6 +520,7 @@ fn test_invalid_ec_add() {
             ec_add: ec_adds.len(),
             ec_mul: 0,
             ec_pairing: 0,
+            ec_pairing_batched: 0,
         },
         ec_adds,
         vec![],
@@ -538,6 +564,7 @@ fn test_invalid_ec_add() {
             ec_add: ec_adds.len(),
             ec_mul: 0,
             ec_pairing: 0,
+            ec_pairing_batched: 0,
         },
         ec_adds,
         vec![],
@@ -577,6 +604,48 @@ fn test_ecc_circuit_negative() {
     );
 }
 
+#[test]
+fn test_ecc_circuit_pairing_batched() {
+    use crate::ecc_circuit::util::LOG_TOTAL_NUM_ROWS;
+    use halo2_proofs::halo2curves::bn256::Fr;
+    use valid_invalid_cases::{EC_PAIRING_OPS2, EC_PAIRING_OPS3, EC_PAIRING_OPS4};
+
+    let mut rng = rand::thread_rng();
+
+    // the valid op failing the pairing check is checked on its own, the others in the batch.
+    run::<Fr, false>(
+        LOG_TOTAL_NUM_ROWS,
+        PrecompileEcParams {
+            ec_add: 0,
+            ec_mul: 0,
+            ec_pairing: 1,
+            ec_pairing_batched: PrecompileEcParams::MAX_EC_PAIRING_BATCHED,
+        },
+        vec![],
+        vec![],
+        EC_PAIRING_OPS2
+            .iter()
+            .chain(EC_PAIRING_OPS3.iter())
+            .chain(EC_PAIRING_OPS4.iter())
+            .cloned()
+            .chain(gen(&mut rng, 1, false))
+            .collect(),
+    );
+
+    run::<Fr, true>(
+        LOG_TOTAL_NUM_ROWS,
+        PrecompileEcParams {
+            ec_add: 0,
+            ec_mul: 0,
+            ec_pairing: 2,
+            ec_pairing_batched: 2,
+        },
+        vec![],
+        vec![],
+        gen(&mut rng, 2, true),
+    );
+}
+
 #[test]
 fn variadic_size_check() {
     use crate::ecc_circuit::util::LOG_TOTAL_NUM_ROWS;
@@ -591,6 +660,7 @@ fn variadic_size_check() {
         max_add_ops: default_params.ec_add,
         max_mul_ops: default_params.ec_mul,
         max_pairing_ops: default_params.ec_pairing,
+        max_pairing_batched_ops: default_params.ec_pairing_batched,
         add_ops: gen(&mut rng, 25, false),
         mul_ops: gen(&mut rng, 20, false),
         pairing_ops: EC_PAIRING_OPS1.clone(),
@@ -602,6 +672,7 @@ fn variadic_size_check() {
         max_add_ops: default_params.ec_add,
         max_mul_ops: default_params.ec_mul,
         max_pairing_ops: default_params.ec_pairing,
+        max_pairing_batched_ops: default_params.ec_pairing_batched,
         add_ops: {
             let mut ops = gen(&mut rng, 30, false);
             ops.extend_from_slice(&EC_ADD_OPS);
@@ -620,3 +691,143 @@ fn variadic_size_check() {
     assert_eq!(prover1.fixed(), prover2.fixed());
     assert_eq!(prover1.permutation(), prover2.permutation());
 }
+
+/// Measures the cells used by the batched pairing checks of `num_batched` ops, in the phase of
+/// the checks, by assigning them on their own.
+struct BatchedPairingUsage<F: Field> {
+    num_batched: usize,
+    /// The (advice, lookup advice) cells used by the checks, set once assigned.
+    usage: RefCell<Option<(usize, usize)>>,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> BatchedPairingUsage<F> {
+    fn measure(num_batched: usize) -> (usize, usize) {
+        let circuit = Self {
+            num_batched,
+            usage: RefCell::new(None),
+            _marker: PhantomData,
+        };
+        MockProver::<F>::run(util::LOG_TOTAL_NUM_ROWS, &circuit, vec![]).unwrap();
+        circuit.usage.into_inner().unwrap()
+    }
+}
+
+impl<F: Field> Circuit<F> for BatchedPairingUsage<F> {
+    type Config = (EccCircuitConfig<F>, Challenges<Challenge>);
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        Self {
+            num_batched: self.num_batched,
+            usage: RefCell::new(None),
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        EccCircuit::<F, 9>::configure(meta)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let (config, challenges) = config;
+        let challenges = challenges.values(&layouter);
+        let ecc_circuit = EccCircuit::<F, 9>::default();
+
+        let ecc_chip = EccChip::<F, FpConfig<F, Fq>>::construct(config.fp_config.clone());
+        let pairing_chip = PairingChip::construct(config.fp_config.clone());
+        let fp12_chip =
+            Fp12Chip::<F, FpConfig<F, Fq>, Fq12, 9>::construct(config.fp_config.clone());
+        let powers_of_256 = iter::successors(Some(F::one()), |coeff| Some(F::from(256) * coeff))
+            .take(N_BYTES_WORD)
+            .map(QuantumCell::Constant)
+            .collect_vec();
+        let op = EcPairingOp::dummy_pairing_check_ok();
+
+        let mut first_pass = SKIP_FIRST_PASS;
+        layouter.assign_region(
+            || "batched pairing checks",
+            |region| {
+                if first_pass {
+                    first_pass = false;
+                    return Ok(());
+                }
+
+                let mut ctx = config.fp_config.new_context(region);
+                let pairs = (0..self.num_batched)
+                    .map(|_| {
+                        ecc_circuit
+                            .decompose_ec_pairing_batched_op(
+                                &mut ctx,
+                                &ecc_chip,
+                                &pairing_chip,
+                                &powers_of_256,
+                                &op,
+                            )
+                            .1
+                    })
+                    .collect_vec();
+                #[cfg(not(feature = "onephase"))]
+                {
+                    config.fp_config.finalize(&mut ctx);
+                    ctx.next_phase();
+                }
+
+                let used_cells = |ctx: &Context<F>| {
+                    let (column, row) = ctx.advice_alloc[ctx.current_phase()];
+                    (column * ctx.max_rows + row, ctx.cells_to_lookup.len())
+                };
+                let (cells_before, lookup_cells_before) = used_cells(&ctx);
+                ecc_circuit.check_ec_pairings_batched(
+                    &mut ctx,
+                    &ecc_chip,
+                    &pairing_chip,
+                    &fp12_chip,
+                    &pairs,
+                    challenges.keccak_input(),
+                );
+                let (cells, lookup_cells) = used_cells(&ctx);
+                *self.usage.borrow_mut() =
+                    Some((cells - cells_before, lookup_cells - lookup_cells_before));
+
+                config.fp_config.finalize(&mut ctx);
+                Ok(())
+            },
+        )?;
+        config.fp_config.range.load_lookup_table(&mut layouter)
+    }
+}
+
+#[test]
+fn batched_pairing_cell_usage() {
+    use halo2_proofs::halo2curves::bn256::Fr;
+
+    // the usage is affine in the number of batched ops.
+    let (cells_1, lookup_cells_1) = BatchedPairingUsage::<Fr>::measure(1);
+    let (cells_2, lookup_cells_2) = BatchedPairingUsage::<Fr>::measure(2);
+    let (cells_per_op, lookup_cells_per_op) = (cells_2 - cells_1, lookup_cells_2 - lookup_cells_1);
+    let (cells_shared, lookup_cells_shared) =
+        (cells_1 - cells_per_op, lookup_cells_1 - lookup_cells_per_op);
+    log::info!(
+        "batched pairing checks, per op: {cells_per_op} cells, {lookup_cells_per_op} lookups"
+    );
+    log::info!(
+        "batched pairing checks, shared: {cells_shared} cells, {lookup_cells_shared} lookups"
+    );
+
+    assert!(cells_per_op <= EC_PAIRING_BATCHED_CELLS, "{cells_per_op}");
+    assert!(
+        lookup_cells_per_op <= EC_PAIRING_BATCHED_LOOKUP_CELLS,
+        "{lookup_cells_per_op}"
+    );
+    assert!(cells_shared <= EC_PAIRING_FINAL_EXP_CELLS, "{cells_shared}");
+    assert!(
+        lookup_cells_shared <= EC_PAIRING_FINAL_EXP_LOOKUP_CELLS,
+        "{lookup_cells_shared}"
+    );
+}
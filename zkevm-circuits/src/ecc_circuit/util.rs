@@ -12,6 +12,45 @@ pub(super) const EC_MUL_CELLS: usize = 405_500; // actual: 405_476
 pub(super) const EC_PAIRING_CELLS: usize = 6_627_500; // actual: 6_627_442
 pub(super) const COLUMN_NUM_LIMIT: usize = 150; // Max number of columns allowed
 
+// Cell usage accounting for the batched pairing checks, in the second phase: the Miller loop lines
+// and the 128-bit scalar multiplications of each batched op, and the part shared by all of them,
+// i.e. the squarings of the multi Miller loop and the final exponentiation. These are upper
+// bounds of the usage measured by the `batched_pairing_cell_usage` test.
+pub(super) const EC_PAIRING_BATCHED_CELLS: usize = 4_500_000;
+pub(super) const EC_PAIRING_BATCHED_LOOKUP_CELLS: usize = 2_000_000;
+pub(super) const EC_PAIRING_FINAL_EXP_CELLS: usize = 3_000_000;
+pub(super) const EC_PAIRING_FINAL_EXP_LOOKUP_CELLS: usize = 1_300_000;
+// Number of bits of the random scalars the batched pairing checks are combined with.
+pub(super) const BATCH_RAND_BITS: usize = 128;
+
+fn calc_required_columns(total_cells: usize) -> usize {
+    let row_num = 1 << LOG_TOTAL_NUM_ROWS;
+    let num_columns = (total_cells + row_num - 1) / row_num;
+    assert!(
+        num_columns <= COLUMN_NUM_LIMIT,
+        "the required columns {num_columns} exceeds {COLUMN_NUM_LIMIT}"
+    );
+    num_columns
+}
+
+/// Number of second phase advice columns required for the batched pairing checks.
+pub(super) fn calc_batched_pairing_advices(num_batched: usize) -> usize {
+    if num_batched == 0 {
+        return 0;
+    }
+    calc_required_columns(num_batched * EC_PAIRING_BATCHED_CELLS + EC_PAIRING_FINAL_EXP_CELLS)
+}
+
+/// Number of second phase lookup advice columns required for the batched pairing checks.
+pub(super) fn calc_batched_pairing_lookup_advices(num_batched: usize) -> usize {
+    if num_batched == 0 {
+        return 0;
+    }
+    calc_required_columns(
+        num_batched * EC_PAIRING_BATCHED_LOOKUP_CELLS + EC_PAIRING_FINAL_EXP_LOOKUP_CELLS,
+    )
+}
+
 /// Decomposed state of a G1 curve point.
 pub(super) struct G1Decomposed<F: Field> {
     /// EcPoint on G1.
@@ -90,6 +129,35 @@ pub(super) struct EcPairingDecomposed<F: Field> {
     pub success: AssignedValue<F>,
 }
 
+/// State of the (G1, G2) pairs of an EcPairing operation after checking their validity.
+pub(super) struct EcPairingPrechecked<F: Field> {
+    /// For each pair, whether it is a zero pair, whether it is valid, and its G1 and G2 points.
+    pub decomposed_pairs: Vec<(
+        AssignedValue<F>,
+        AssignedValue<F>,
+        G1Decomposed<F>,
+        G2Decomposed<F>,
+    )>,
+    pub input_cells: Vec<QuantumCell<F>>,
+    pub all_pairs_zero: AssignedValue<F>,
+    pub is_valid: AssignedValue<F>,
+}
+
+/// G1 and G2 points of (G1, G2) pairs.
+pub(super) type TupleG1sG2s<F> = (
+    Vec<EcPoint<F, CRTInteger<F>>>,
+    Vec<EcPoint<F, FieldExtPoint<CRTInteger<F>>>>,
+);
+
+/// A (G1, G2) pair of an EcPairing operation checked in the batch, post first phase.
+pub(super) struct PairingBatchedPair<F: Field> {
+    pub g1: EcPoint<F, CRTInteger<F>>,
+    pub g2: EcPoint<F, FieldExtPoint<CRTInteger<F>>>,
+    /// Whether g1 is the point at infinity (0, 0), which is left out of the scalar
+    /// multiplication.
+    pub g1_is_identity: AssignedValue<F>,
+}
+
 /// State of EcPairing operation post second phase.
 pub(super) struct EcPairingAssigned<F: Field> {
     pub is_valid: AssignedValue<F>,
@@ -104,4 +172,6 @@ pub(super) struct EcOpsAssigned<F: Field> {
     pub ec_adds_assigned: Vec<EcAddAssigned<F>>,
     pub ec_muls_assigned: Vec<EcMulAssigned<F>>,
     pub ec_pairings_assigned: Vec<EcPairingAssigned<F>>,
+    /// The randomness the batched EcPairing operations are combined with.
+    pub batch_rand: Option<AssignedValue<F>>,
 }
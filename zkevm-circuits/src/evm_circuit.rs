@@ -22,8 +22,9 @@ pub use crate::witness;
 use crate::{
     evm_circuit::param::{MAX_STEP_HEIGHT, STEP_STATE_HEIGHT},
     table::{
-        BlockTable, BytecodeTable, CopyTable, EccTable, ExpTable, KeccakTable, LookupTable,
-        ModExpTable, PowOfRandTable, RwTable, SHA256Table, SigTable, TxTable,
+        Blake2fTable, BlockTable, BytecodeTable, CopyTable, EccTable, ExpTable, KeccakTable,
+        LookupTable, ModExpTable, PowOfRandTable, Ripemd160Table, RwTable, SHA256Table, SigTable,
+        TxTable,
     },
     util::{Field, SubCircuit, SubCircuitConfig},
 };
@@ -51,6 +52,8 @@ pub struct EvmCircuitConfig<F> {
     copy_table: CopyTable,
     keccak_table: KeccakTable,
     sha256_table: SHA256Table,
+    ripemd160_table: Ripemd160Table,
+    blake2f_table: Blake2fTable,
     exp_table: ExpTable,
     sig_table: SigTable,
     modexp_table: ModExpTable,
@@ -79,6 +82,10 @@ pub struct EvmCircuitConfigArgs<F: Field> {
     pub keccak_table: KeccakTable,
     /// SHA256Table
     pub sha256_table: SHA256Table,
+    /// Ripemd160Table
+    pub ripemd160_table: Ripemd160Table,
+    /// Blake2fTable
+    pub blake2f_table: Blake2fTable,
     /// ExpTable
     pub exp_table: ExpTable,
     /// SigTable
@@ -116,6 +123,8 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
             copy_table,
             keccak_table,
             sha256_table,
+            ripemd160_table,
+            blake2f_table,
             exp_table,
             sig_table,
             modexp_table,
@@ -139,6 +148,8 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
             &copy_table,
             &keccak_table,
             &sha256_table,
+            &ripemd160_table,
+            &blake2f_table,
             &exp_table,
             &sig_table,
             &modexp_table,
@@ -158,6 +169,8 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
         block_table.annotate_columns(meta);
         copy_table.annotate_columns(meta);
         keccak_table.annotate_columns(meta);
+        ripemd160_table.annotate_columns(meta);
+        blake2f_table.annotate_columns(meta);
         exp_table.annotate_columns(meta);
         sig_table.annotate_columns(meta);
         modexp_table.annotate_columns(meta);
@@ -177,6 +190,8 @@ impl<F: Field> SubCircuitConfig<F> for EvmCircuitConfig<F> {
             copy_table,
             keccak_table,
             sha256_table,
+            ripemd160_table,
+            blake2f_table,
             exp_table,
             sig_table,
             modexp_table,
@@ -472,6 +487,8 @@ impl<F: Field> Circuit<F> for EvmCircuit<F> {
         let copy_table = CopyTable::construct(meta, q_copy_table);
         let keccak_table = KeccakTable::construct(meta);
         let sha256_table = SHA256Table::construct(meta);
+        let ripemd160_table = Ripemd160Table::construct(meta);
+        let blake2f_table = Blake2fTable::construct(meta);
         let exp_table = ExpTable::construct(meta);
         let sig_table = SigTable::construct(meta);
         let modexp_table = ModExpTable::construct(meta);
@@ -491,6 +508,8 @@ impl<F: Field> Circuit<F> for EvmCircuit<F> {
                     copy_table,
                     keccak_table,
                     sha256_table,
+                    ripemd160_table,
+                    blake2f_table,
                     exp_table,
                     sig_table,
                     modexp_table,
@@ -564,13 +583,19 @@ impl<F: Field> Circuit<F> for EvmCircuit<F> {
                 .map(|evt| (&evt.input, &evt.digest)),
             &challenges,
         )?;
+        config
+            .ripemd160_table
+            .dev_load(&mut layouter, &block.get_ripemd160(), &challenges)?;
+        config
+            .blake2f_table
+            .dev_load(&mut layouter, &block.get_blake2f(), &challenges)?;
         config.exp_table.dev_load(&mut layouter, block)?;
         config
             .sig_table
             .dev_load(&mut layouter, block, &challenges)?;
         config
             .modexp_table
-            .dev_load(&mut layouter, &block.get_big_modexp())?;
+            .dev_load(&mut layouter, &block.get_big_modexp(), &challenges)?;
         config.ecc_table.dev_load(
             &mut layouter,
             block.circuits_params.max_ec_ops,
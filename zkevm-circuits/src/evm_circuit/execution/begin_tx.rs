@@ -6,7 +6,8 @@ use crate::{
         util::{
             and,
             common_gadget::{
-                TransferGadgetInfo, TransferWithGasFeeGadget, TxAccessListGadget, TxEip1559Gadget,
+                DelegateAddressGadget, DelegationDesignatorGadget, TransferGadgetInfo,
+                TransferWithGasFeeGadget, TxAccessListGadget, TxEip1559Gadget, TxEip7702Gadget,
                 TxL1FeeGadget, TxL1MsgGadget,
             },
             constraint_builder::{
@@ -16,7 +17,7 @@ use crate::{
             from_bytes,
             math_gadget::{
                 ConstantDivisionGadget, ContractCreateGadget, IsEqualGadget, IsZeroGadget,
-                LtGadget, MulWordByU64Gadget, RangeCheckGadget,
+                LtGadget, MinMaxGadget, MulWordByU64Gadget, RangeCheckGadget,
             },
             precompile_gadget::PrecompileGadget,
             CachedRegion, Cell, StepRws, Word,
@@ -30,14 +31,19 @@ use crate::{
     util::Field,
 };
 use array_init::array_init;
-use bus_mapping::{circuit_input_builder::CopyDataType, precompile::PrecompileCalls};
+use bus_mapping::{
+    circuit_input_builder::{CopyDataType, NumberOrHash},
+    precompile::{
+        PrecompileCalls, LAST_PRECOMPILE, P256_VERIFY_ENABLED, PRECOMPILE_WARM_ADDRESSES,
+    },
+};
 use eth_types::{utils::is_precompiled, Address, ToLittleEndian, U256};
 use ethers_core::utils::{get_contract_address, keccak256, rlp::RlpStream};
 use gadgets::util::{expr_from_bytes, not, select, Expr};
 use gadgets::ToScalar;
 use halo2_proofs::{circuit::Value, plonk::Error};
 
-const PRECOMPILE_COUNT: usize = 9;
+const PRECOMPILE_COUNT: usize = PRECOMPILE_WARM_ADDRESSES.len();
 
 #[derive(Clone, Debug)]
 pub(crate) struct BeginTxGadget<F> {
@@ -76,9 +82,14 @@ pub(crate) struct BeginTxGadget<F> {
     call_code_hash: Cell<F>,
     call_code_hash_is_empty: IsEqualGadget<F>,
     call_code_hash_is_zero: IsZeroGadget<F>,
+    // A creation tx fails when the contract address already has code or a nonce.
+    callee_nonce: Cell<F>,
+    callee_nonce_is_zero: IsZeroGadget<F>,
     is_precompile_lt: LtGadget<F, N_BYTES_ACCOUNT_ADDRESS>,
+    is_p256_verify: IsEqualGadget<F>,
     precompile_gadget: PrecompileGadget<F>,
     precompile_input_len: Cell<F>, // the number of input bytes taken for the precompile call.
+    precompile_input_len_capped: MinMaxGadget<F, N_BYTES_U64>,
     precompile_input_bytes_rlc: Cell<F>, // input bytes to precompile call.
     /// Keccak256(RLP([tx_caller_address, tx_nonce]))
     caller_nonce_hash_bytes: [Cell<F>; N_BYTES_WORD],
@@ -96,10 +107,17 @@ pub(crate) struct BeginTxGadget<F> {
     are_precompile_warm: [Cell<F>; PRECOMPILE_COUNT],
     // EIP-3651 (Warm COINBASE) for Shanghai
     coinbase: Cell<F>,
+    // EIP-7702: a delegated callee executes the code of its delegate, which is
+    // added to the access list.
+    is_callee_delegated: DelegationDesignatorGadget<F>,
+    delegate_address: DelegateAddressGadget<F>,
+    is_delegate_warm: Cell<F>,
+    delegate_code_hash: Cell<F>,
     tx_l1_fee: TxL1FeeGadget<F>,
     tx_l1_msg: TxL1MsgGadget<F>,
     tx_access_list: TxAccessListGadget<F>,
     tx_eip1559: TxEip1559Gadget<F>,
+    tx_eip7702: TxEip7702Gadget<F>,
 }
 
 impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
@@ -223,6 +241,9 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             None,
         ); // rwc_delta += 1
 
+        // Apply the authorization list of EIP-7702 txs.
+        let tx_eip7702 = TxEip7702Gadget::construct(cb, tx_id.expr(), tx_type.expr());
+
         // Calculate transaction gas fee
         let mul_gas_fee_by_gas =
             MulWordByU64Gadget::construct(cb, tx_gas_price.clone(), tx_gas.expr());
@@ -238,42 +259,53 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             from_bytes::expr(&tx_fee.cells[..16]),
         );
 
-        // a valid precompile address is: 1 <= addr <= 9 (addr != 0 && addr < 0xA)
-        let is_precompile_lt = LtGadget::construct(cb, tx_callee_address.expr(), 0xA.expr());
+        // a valid precompile address is: 1 <= addr <= LAST_PRECOMPILE, or the secp256r1
+        // verification precompile at 0x100 when it is enabled.
+        let is_precompile_lt = LtGadget::construct(
+            cb,
+            tx_callee_address.expr(),
+            (LAST_PRECOMPILE.address() + 1).expr(),
+        );
+        let is_p256_verify = IsEqualGadget::construct(
+            cb,
+            tx_callee_address.expr(),
+            PrecompileCalls::P256Verify.address().expr(),
+        );
         let is_precompile = and::expr([
             not::expr(tx_callee_address_is_zero.expr()),
             is_precompile_lt.expr(),
-        ]);
+        ]) + if P256_VERIFY_ENABLED {
+            is_p256_verify.expr()
+        } else {
+            0.expr()
+        };
         let precompile_input_len = cb.query_cell();
 
         let tx_call_data_word_length =
             ConstantDivisionGadget::construct(cb, tx_call_data_length.expr() + 31.expr(), 32);
 
-        // Use intrinsic gas
-        // TODO2: constrain calling precompile directly
-
+        // Use intrinsic gas. A tx calling a precompile directly pays the same intrinsic gas as
+        // any other call tx, the precompile's own cost is deducted by the next step.
         let intrinsic_gas_cost = cb.query_cell();
-        cb.condition(not::expr(is_precompile.expr()), |cb| {
-            // Calculate gas cost of init code only for EIP-3860 of Shanghai.
-            let init_code_gas_cost = select::expr(
+        // Calculate gas cost of init code only for EIP-3860 of Shanghai.
+        let init_code_gas_cost = select::expr(
+            tx_is_create.expr(),
+            tx_call_data_word_length.quotient().expr()
+                * eth_types::evm_types::INIT_CODE_WORD_GAS.expr(),
+            0.expr(),
+        );
+        cb.require_equal(
+            "calculate intrinsic gas cost",
+            intrinsic_gas_cost.expr(),
+            select::expr(
                 tx_is_create.expr(),
-                tx_call_data_word_length.quotient().expr()
-                    * eth_types::evm_types::INIT_CODE_WORD_GAS.expr(),
-                0.expr(),
-            );
-
-            cb.require_equal(
-                "calculate intrinsic gas cost",
-                intrinsic_gas_cost.expr(),
-                select::expr(
-                    tx_is_create.expr(),
-                    eth_types::evm_types::GasCost::CREATION_TX.expr(),
-                    eth_types::evm_types::GasCost::TX.expr(),
-                ) + tx_call_data_gas_cost.expr()
-                    + tx_access_list.gas_cost()
-                    + init_code_gas_cost,
-            )
-        });
+                eth_types::evm_types::GasCost::CREATION_TX.expr(),
+                eth_types::evm_types::GasCost::TX.expr(),
+            ) + tx_call_data_gas_cost.expr()
+                + tx_access_list.gas_cost()
+                + tx_eip7702.gas_cost()
+                + init_code_gas_cost,
+        );
         // Check gas_left is sufficient
         let gas_left = tx_gas.expr() - intrinsic_gas_cost.expr();
         let sufficient_gas_left = RangeCheckGadget::construct(cb, gas_left.clone());
@@ -283,7 +315,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         let is_coinbase_warm = cb.query_bool();
         let are_precompile_warm = array_init(|_| cb.query_bool());
 
-        for (addr, is_warm) in (1..=PRECOMPILE_COUNT).zip(are_precompile_warm.iter()) {
+        for (&addr, is_warm) in PRECOMPILE_WARM_ADDRESSES.iter().zip(are_precompile_warm.iter()) {
             cb.account_access_list_write(tx_id.expr(), addr.expr(), 1.expr(), is_warm.expr(), None);
         } // rwc_delta += PRECOMPILE_COUNT
 
@@ -325,6 +357,36 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         let account_code_hash_is_zero = IsZeroGadget::construct(cb, account_code_hash.expr());
         let account_code_hash_is_empty_or_zero =
             account_code_hash_is_empty.expr() + account_code_hash_is_zero.expr();
+
+        // Contract creation tx has no delegated callee.
+        let is_callee_delegated = DelegationDesignatorGadget::construct(
+            cb,
+            account_code_hash.clone(),
+            not::expr(tx_is_create.expr()) * not::expr(account_code_hash_is_zero.expr()),
+        );
+        let delegate_address = DelegateAddressGadget::construct(
+            cb,
+            account_code_hash.expr(),
+            is_callee_delegated.expr(),
+        );
+        let is_delegate_warm = cb.query_bool();
+        let delegate_code_hash = cb.query_cell_phase2();
+        cb.condition(is_callee_delegated.expr(), |cb| {
+            cb.account_access_list_write(
+                tx_id.expr(),
+                delegate_address.expr(),
+                1.expr(),
+                is_delegate_warm.expr(),
+                None,
+            );
+            cb.account_read(
+                delegate_address.expr(),
+                AccountFieldTag::CodeHash,
+                delegate_code_hash.expr(),
+            );
+        }); // rwc_delta += 2 * is_callee_delegated
+        let eip7702_rw_delta = tx_eip7702.rw_delta() + 2.expr() * is_callee_delegated.expr();
+
         #[cfg(feature = "scroll")]
         let account_keccak_code_hash = cb.query_cell_phase2();
 
@@ -340,6 +402,30 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             AccountFieldTag::CodeHash,
             account_code_hash.expr(),
         ); // rwc_delta += 1
+        cb.condition(not::expr(tx_is_create.expr()), |cb| {
+            cb.require_equal(
+                "call code hash is the code hash of the callee or of its delegate",
+                call_code_hash.expr(),
+                select::expr(
+                    is_callee_delegated.expr(),
+                    delegate_code_hash.expr(),
+                    account_code_hash.expr(),
+                ),
+            );
+        });
+
+        // The callee nonce is only read in case of a deployment collision, otherwise it is
+        // constrained to be zero by the callee nonce write of the creation.
+        let callee_nonce = cb.query_cell();
+        let callee_nonce_is_zero = IsZeroGadget::construct(cb, callee_nonce.expr());
+        let is_deployment_collision = and::expr([
+            tx_is_create.expr(),
+            not::expr(and::expr([
+                account_code_hash_is_empty_or_zero.expr(),
+                callee_nonce_is_zero.expr(),
+            ])),
+        ]);
+        let is_create_success = tx_is_create.expr() - is_deployment_collision.expr();
 
         // Transfer value from caller to callee, creating account if necessary.
         let transfer_with_gas_fee = TransferWithGasFeeGadget::construct(
@@ -347,7 +433,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             tx_caller_address.expr(),
             call_callee_address.expr(),
             not::expr(account_code_hash_is_zero.expr()),
-            tx_is_create.expr(),
+            is_create_success.expr(),
             account_code_hash.expr(),
             #[cfg(feature = "scroll")]
             account_keccak_code_hash.expr(),
@@ -381,6 +467,15 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 call_callee_address.expr(),
                 expr_from_bytes(&caller_nonce_hash_bytes[0..N_BYTES_ACCOUNT_ADDRESS]),
             );
+            let output_rlc = cb.word_rlc::<N_BYTES_WORD>(
+                caller_nonce_hash_bytes
+                    .iter()
+                    .map(Expr::expr)
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap(),
+            );
+            cb.keccak_table_lookup(create.input_rlc(cb), create.input_length(), output_rlc);
         });
         cb.require_equal(
             "tx nonce equivalence",
@@ -396,17 +491,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         });
 
         // 1. Handle contract creation transaction.
-        let (init_code_rlc, keccak_code_hash) = cb.condition(tx_is_create.expr(), |cb| {
-            let output_rlc = cb.word_rlc::<N_BYTES_WORD>(
-                caller_nonce_hash_bytes
-                    .iter()
-                    .map(Expr::expr)
-                    .collect::<Vec<_>>()
-                    .try_into()
-                    .unwrap(),
-            );
-            cb.keccak_table_lookup(create.input_rlc(cb), create.input_length(), output_rlc);
-
+        let (init_code_rlc, keccak_code_hash) = cb.condition(is_create_success.expr(), |cb| {
             let keccak_code_hash = cb.query_cell_phase2();
             let init_code_rlc = cb.query_cell_phase2();
             // keccak table lookup for init code.
@@ -445,6 +530,19 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 0.expr(),
                 Some(&mut reversion_info),
             );
+            // EIP-6780, the deployed contract may be deleted by SELFDESTRUCT within this tx
+            #[cfg(not(feature = "scroll"))]
+            cb.tx_created_account_write(
+                tx_id.expr(),
+                call_callee_address.expr(),
+                1.expr(),
+                0.expr(),
+                Some(&mut reversion_info),
+            );
+            #[cfg(feature = "scroll")]
+            let callee_init_write_num = 1;
+            #[cfg(not(feature = "scroll"))]
+            let callee_init_write_num = 2;
             for (field_tag, value) in [
                 (CallContextFieldTag::Depth, 1.expr()),
                 (CallContextFieldTag::CallerAddress, tx_caller_address.expr()),
@@ -470,7 +568,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             }
 
             cb.require_step_state_transition(StepStateTransition {
-                // 21 + a reads and writes:
+                // 22 + callee_init_write_num + a reads and writes:
                 //   - a TxL1FeeGadget
                 //   - Write CallContext TxId
                 //   - Write CallContext RwCounterEndOfReversion
@@ -481,9 +579,11 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 //   - Write TxAccessListAccount (Caller)
                 //   - Write TxAccessListAccount (Callee)
                 //   - Write TxAccessListAccount (Coinbase) only for Shanghai
+                //   - a TxEip7702Gadget and the delegate of the callee
                 //   - Read Account CodeHash
                 //   - a TransferWithGasFeeGadget
                 //   - Write Account (Callee) Nonce (Reversible)
+                //   - Write TxCreatedAccount (Callee) (Reversible, not in scroll mode)
                 //   - Write CallContext Depth
                 //   - Write CallContext CallerAddress
                 //   - Write CallContext CalleeAddress
@@ -498,10 +598,12 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 //   - Write CallContext IsCreate
                 //   - Write CallContext CodeHash
                 rw_counter: Delta(
-                    23.expr()
+                    22.expr()
+                        + callee_init_write_num.expr()
                         + l1_rw_delta.expr()
                         + transfer_with_gas_fee.rw_delta()
                         + tx_access_list.rw_delta_expr()
+                        + eip7702_rw_delta.expr()
                         + PRECOMPILE_COUNT.expr(),
                 ),
                 call_id: To(call_id.expr()),
@@ -509,10 +611,13 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 is_create: To(tx_is_create.expr()),
                 code_hash: To(cb.curr.state.code_hash.expr()),
                 gas_left: To(gas_left.clone()),
-                // There are a + 1 reversible writes:
+                // There are a + callee_init_write_num reversible writes:
                 //  - a TransferWithGasFeeGadget
                 //  - Callee Account Nonce
-                reversible_write_counter: To(transfer_with_gas_fee.reversible_w_delta() + 1.expr()),
+                //  - Callee TxCreatedAccount (not in scroll mode)
+                reversible_write_counter: To(
+                    transfer_with_gas_fee.reversible_w_delta() + callee_init_write_num.expr()
+                ),
                 log_id: To(0.expr()),
                 end_tx: To(is_call_data_empty.expr()),
                 ..StepStateTransition::new_context()
@@ -527,6 +632,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
            + constructed the base precompile gadget
            + connected the additional copy event and the base precompile gadget with a lookup to copy table with following constraints:
               * copy source is from the `TxCalldata` of current tx id
+              * the copied len is the call data length, capped for precompiles with a fixed input size
               * copy target is the root call we have setup, and with the form of `RlcAcc`
               * The copied len and rlc of copied bytes would be passed to base precompile gadget,
                 so the base precompile gadget verify the RLC of the call data against its input bytes.
@@ -534,7 +640,9 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             Notice we need an additional copy event like we have done in the `CallOp` step
 
             We simply drop any checks to the output bytes which precompile would return,
-            since they are omitted as the return data from a transaction.
+            since they are omitted as the return data from a transaction. The precompile step
+            ends the tx (see `gen_restore_context`), so its success and the gas it consumed are
+            what the receipt records.
         */
         let (precompile_gadget, precompile_input_bytes_rlc) =
             cb.condition(is_precompile.expr(), |cb| {
@@ -544,11 +652,8 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                     account_code_hash_is_empty_or_zero.expr(),
                     true.expr(),
                 );
-                // cb.require_equal(
-                //     "Go to EndTx when Tx to precompile",
-                //     cb.next.execution_state_selector([ExecutionState::EndTx]),
-                //     1.expr(),
-                // );
+                // the next step is constrained to be the precompile (or its OOG error) by the
+                // `PrecompileGadget` below, which then goes to EndTx as the root call.
 
                 // call's dummy context for precompile.
                 for (field_tag, value) in [
@@ -613,6 +718,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                     //   - Write TxAccessListAccount (Caller)
                     //   - Write TxAccessListAccount (Callee)
                     //   - Write TxAccessListAccount (Coinbase) only for Shanghai
+                    //   - a TxEip7702Gadget and the delegate of the callee
                     //   - Read Account CodeHash
                     //   - a TxL1FeeGadget
                     //   - a TransferWithGasFeeGadget
@@ -637,6 +743,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                             + l1_rw_delta.expr()
                             + transfer_with_gas_fee.rw_delta()
                             + tx_access_list.rw_delta_expr()
+                            + eip7702_rw_delta.expr()
                             + PRECOMPILE_COUNT.expr(),
                     ),
                     call_id: To(call_id.expr()),
@@ -660,11 +767,28 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                 (precompile_gadget, precompile_input_bytes_rlc)
             });
 
+        // precompiles with a fixed input size only take that many bytes of the call data.
+        let precompile_input_len_capped = cb.condition(is_precompile.expr(), |cb| {
+            let (has_input_len_limit, input_len_limit) = precompile_gadget.input_len_limit();
+            let precompile_input_len_capped =
+                MinMaxGadget::construct(cb, tx_call_data_length.expr(), input_len_limit);
+            cb.require_equal(
+                "precompile_input_len == min(call_data_length, input_len_limit)",
+                precompile_input_len.expr(),
+                select::expr(
+                    has_input_len_limit,
+                    precompile_input_len_capped.min(),
+                    tx_call_data_length.expr(),
+                ),
+            );
+            precompile_input_len_capped
+        });
+
         // 3. Call to account with empty code.
         cb.condition(
             and::expr([
                 not::expr(tx_is_create.expr()),
-                account_code_hash_is_empty_or_zero.expr(),
+                call_code_hash_is_empty_or_zero.expr(),
                 not::expr(is_precompile.expr()),
             ]),
             |cb| {
@@ -690,6 +814,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                     //   - Write TxAccessListAccount (Caller)
                     //   - Write TxAccessListAccount (Callee)
                     //   - Write TxAccessListAccount (Coinbase) only for Shanghai
+                    //   - a TxEip7702Gadget and the delegate of the callee
                     //   - Read Account CodeHash
                     //   - a TxL1FeeGadget
                     //   - a TransferWithGasFeeGadget
@@ -698,6 +823,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                             + l1_rw_delta.expr()
                             + transfer_with_gas_fee.rw_delta()
                             + tx_access_list.rw_delta_expr()
+                            + eip7702_rw_delta.expr()
                             + PRECOMPILE_COUNT.expr(),
                     ),
                     call_id: To(call_id.expr()),
@@ -711,7 +837,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         cb.condition(
             and::expr([
                 not::expr(tx_is_create.expr()),
-                not::expr(account_code_hash_is_empty_or_zero),
+                not::expr(call_code_hash_is_empty_or_zero.expr()),
             ]),
             |cb| {
                 // Setup first call's context.
@@ -734,7 +860,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                     (CallContextFieldTag::LastCalleeReturnDataLength, 0.expr()),
                     (CallContextFieldTag::IsRoot, 1.expr()),
                     (CallContextFieldTag::IsCreate, tx_is_create.expr()),
-                    (CallContextFieldTag::CodeHash, account_code_hash.expr()),
+                    (CallContextFieldTag::CodeHash, call_code_hash.expr()),
                 ] {
                     cb.call_context_lookup(true.expr(), Some(call_id.expr()), field_tag, value);
                 }
@@ -751,6 +877,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                     //   - Write TxAccessListAccount (Caller)
                     //   - Write TxAccessListAccount (Callee)
                     //   - Write TxAccessListAccount (Coinbase) only for Shanghai
+                    //   - a TxEip7702Gadget and the delegate of the callee
                     //   - Read Account CodeHash
                     //   - a TransferWithGasFeeGadget
                     //   - Write CallContext Depth
@@ -771,12 +898,13 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
                             + l1_rw_delta.expr()
                             + transfer_with_gas_fee.rw_delta()
                             + tx_access_list.rw_delta_expr()
+                            + eip7702_rw_delta.expr()
                             + PRECOMPILE_COUNT.expr(),
                     ),
                     call_id: To(call_id.expr()),
                     is_root: To(true.expr()),
                     is_create: To(tx_is_create.expr()),
-                    code_hash: To(account_code_hash.expr()),
+                    code_hash: To(call_code_hash.expr()),
                     gas_left: To(gas_left),
                     reversible_write_counter: To(transfer_with_gas_fee.reversible_w_delta()),
                     log_id: To(0.expr()),
@@ -785,6 +913,62 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             },
         );
 
+        // 5. Creation transaction colliding with an existing account.
+        cb.condition(is_deployment_collision.expr(), |cb| {
+            cb.account_read(
+                call_callee_address.expr(),
+                AccountFieldTag::Nonce,
+                callee_nonce.expr(),
+            ); // rwc_delta += 1
+            cb.require_zero(
+                "Tx with deployment collision should not be persistent",
+                reversion_info.is_persistent(),
+            );
+            cb.require_equal(
+                "Go to EndTx when Tx has deployment collision",
+                cb.next.execution_state_selector([ExecutionState::EndTx]),
+                1.expr(),
+            );
+
+            // 10 reads and writes:
+            //   - Write CallContext TxId
+            //   - Write CallContext RwCounterEndOfReversion
+            //   - Write CallContext IsPersistent
+            //   - Write CallContext IsSuccess
+            //   - Write Account (Caller) Nonce
+            //   - Write TxAccessListAccount (Precompile) x PRECOMPILE_COUNT
+            //   - Write TxAccessListAccount (Caller)
+            //   - Write TxAccessListAccount (Callee)
+            //   - Write TxAccessListAccount (Coinbase) only for Shanghai
+            //   - a TxEip7702Gadget and the delegate of the callee
+            //   - Read Account CodeHash
+            //   - a TxL1FeeGadget
+            //   - a TransferWithGasFeeGadget
+            //   - Read Account (Callee) Nonce
+            let rw_counter_delta = 10.expr()
+                + l1_rw_delta.expr()
+                + transfer_with_gas_fee.rw_delta()
+                + tx_access_list.rw_delta_expr()
+                + eip7702_rw_delta.expr()
+                + PRECOMPILE_COUNT.expr();
+            // The reversible writes of the value transfer are reverted right after this step.
+            cb.require_equal(
+                "rw_counter_end_of_reversion = rw_counter_end_of_step + reversible_counter",
+                reversion_info.rw_counter_end_of_reversion(),
+                cb.curr.state.rw_counter.expr() + rw_counter_delta.clone() - 1.expr()
+                    + transfer_with_gas_fee.reversible_w_delta(),
+            );
+
+            cb.require_step_state_transition(StepStateTransition {
+                rw_counter: Delta(rw_counter_delta + transfer_with_gas_fee.reversible_w_delta()),
+                call_id: To(call_id.expr()),
+                // All gas is consumed.
+                gas_left: To(0.expr()),
+                end_tx: To(1.expr()),
+                ..StepStateTransition::any()
+            });
+        });
+
         Self {
             tx_id,
             tx_type,
@@ -819,9 +1003,13 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             call_code_hash,
             call_code_hash_is_empty,
             call_code_hash_is_zero,
+            callee_nonce,
+            callee_nonce_is_zero,
             is_precompile_lt,
+            is_p256_verify,
             precompile_gadget,
             precompile_input_len,
+            precompile_input_len_capped,
             precompile_input_bytes_rlc,
             caller_nonce_hash_bytes,
             keccak_code_hash,
@@ -832,10 +1020,15 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             is_coinbase_warm,
             are_precompile_warm,
             coinbase,
+            is_callee_delegated,
+            delegate_address,
+            is_delegate_warm,
+            delegate_code_hash,
             tx_l1_fee,
             tx_l1_msg,
             tx_access_list,
             tx_eip1559,
+            tx_eip7702,
         }
     }
 
@@ -863,10 +1056,12 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         // IsPersistent
         // IsSuccess
         // Nonce
+        // authorization list
         // Precompiles
         // caller addr
         // callee addr
         // coinbase
+        // delegate addr, delegate CodeHash
 
         let mut rws = StepRws::new(block, step);
         let rw = rws.next();
@@ -922,6 +1117,9 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
 
         let nonce_rw = rw.account_nonce_pair();
 
+        self.tx_eip7702
+            .assign(region, offset, block, tx, &mut rws)?;
+
         let are_precompile_warm: [_; PRECOMPILE_COUNT] =
             array_init(|_| rws.next().tx_access_list_value_pair().1);
 
@@ -930,15 +1128,67 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
 
         let is_coinbase_warm = rws.next().tx_access_list_value_pair().1;
 
-        let account_code_hash = rws.next().account_codehash_pair().1;
+        // The delegate of the callee is warmed up before the callee code hash is read.
+        let rw = rws.next();
+        let (delegate, account_code_hash) = if rw.tag() == RwTableTag::TxAccessListAccount {
+            let is_delegate_warm = rw.tx_access_list_value_pair().1;
+            let delegate_address = rw.address().expect("access list rw has an address");
+            let delegate_code_hash = rws.next().account_codehash_pair().1;
+            (
+                Some((delegate_address, is_delegate_warm, delegate_code_hash)),
+                rws.next().account_codehash_pair().1,
+            )
+        } else {
+            (None, rw.account_codehash_pair().1)
+        };
+        let (delegate_address, is_delegate_warm, delegate_code_hash) = delegate.unwrap_or_default();
+        let is_callee_delegated = self.is_callee_delegated.assign(
+            region,
+            offset,
+            block,
+            if tx.is_create {
+                U256::zero()
+            } else {
+                account_code_hash
+            },
+        )?;
+        debug_assert_eq!(is_callee_delegated, delegate.is_some());
+        self.delegate_address
+            .assign(region, offset, delegate_address)?;
+        self.is_delegate_warm
+            .assign(region, offset, Value::known(F::from(is_delegate_warm)))?;
+        self.delegate_code_hash
+            .assign(region, offset, region.code_hash(delegate_code_hash))?;
+        // The code hash being executed, which is the code hash of the delegate if any.
+        let call_code_hash = if tx.is_create {
+            call.code_hash
+        } else if delegate.is_some() {
+            delegate_code_hash
+        } else {
+            account_code_hash
+        };
+        // A deployment collision fails the creation within this step, so that the call is
+        // reverted right after it.
+        let is_deployment_collision = tx.is_create
+            && !call.is_persistent
+            && call.rw_counter_end_of_reversion < step.rw_counter + step.rw_indices.len();
         let transfer_assign_result = self.transfer_with_gas_fee.assign_from_rws(
             region,
             offset,
             !account_code_hash.is_zero(),
-            tx.is_create,
+            tx.is_create && !is_deployment_collision,
             tx.value,
             &mut rws,
         )?;
+        let callee_nonce = if is_deployment_collision {
+            rws.next().account_nonce_pair().1.as_u64()
+        } else {
+            0
+        };
+        self.callee_nonce
+            .assign(region, offset, Value::known(F::from(callee_nonce)))?;
+        self.callee_nonce_is_zero
+            .assign(region, offset, F::from(callee_nonce))?;
 
         let tx_fee = transfer_assign_result.gas_fee.unwrap();
         self.tx_fee
@@ -1010,8 +1260,18 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             .assign(region, offset, Value::known(callee_address))?;
         self.tx_callee_address_is_zero
             .assign(region, offset, callee_address)?;
-        self.is_precompile_lt
-            .assign(region, offset, callee_address, F::from(0xA))?;
+        self.is_precompile_lt.assign(
+            region,
+            offset,
+            callee_address,
+            F::from(LAST_PRECOMPILE.address() + 1),
+        )?;
+        self.is_p256_verify.assign(
+            region,
+            offset,
+            callee_address,
+            F::from(PrecompileCalls::P256Verify.address()),
+        )?;
         // precompile related assignment.
         let (precompile_input_len, precompile_input_bytes_rlc) = if tx
             .callee_address
@@ -1019,25 +1279,40 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
             .map(is_precompiled)
             .unwrap_or_default()
         {
-            let precompile_call: PrecompileCalls = tx.callee_address.unwrap().0[19].into();
-            let input_len = if let Some(input_len) = precompile_call.input_len() {
-                std::cmp::min(input_len, tx.call_data_length)
-            } else {
-                tx.call_data_length
-            };
-
-            let input_bytes_rlc = region.keccak_rlc(
-                &tx.call_data
-                    .iter()
-                    .cloned()
-                    .take(input_len)
-                    .rev()
-                    .collect::<Vec<_>>(),
-            );
+            let precompile_call: PrecompileCalls = tx.callee_address.unwrap().into();
+            let input_len_limit = precompile_call.input_len();
+            self.precompile_input_len_capped.assign(
+                region,
+                offset,
+                F::from(tx.call_data_length as u64),
+                F::from(input_len_limit.unwrap_or_default() as u64),
+            )?;
+            // the input is the call data copied by the bus-mapping, truncated to the input length
+            // limit of the precompile. There is no copy event for empty call data.
+            let input_bytes = block
+                .copy_events
+                .iter()
+                .find(|event| {
+                    event.src_type == CopyDataType::TxCalldata
+                        && event.src_id == NumberOrHash::Number(tx.id)
+                        && event.dst_type == CopyDataType::RlcAcc
+                })
+                .map(|event| {
+                    event
+                        .copy_bytes
+                        .bytes
+                        .iter()
+                        .map(|&(byte, _, _)| byte)
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+
+            let input_bytes_rlc =
+                region.keccak_rlc(&input_bytes.iter().cloned().rev().collect::<Vec<_>>());
             log::trace!("input_bytes_rlc: {input_bytes_rlc:?}");
             self.precompile_gadget
                 .assign(region, offset, precompile_call)?;
-            (input_len as u64, input_bytes_rlc)
+            (input_bytes.len() as u64, input_bytes_rlc)
         } else {
             (0, Value::known(F::zero()))
         };
@@ -1098,7 +1373,7 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         self.sufficient_gas_left
             .assign(region, offset, F::from(tx.gas - step.gas_cost))?;
         self.call_code_hash
-            .assign(region, offset, region.code_hash(call.code_hash))?;
+            .assign(region, offset, region.code_hash(call_code_hash))?;
         let untrimmed_contract_addr = {
             let mut stream = RlpStream::new();
             stream.begin_list(2);
@@ -1129,13 +1404,13 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
         self.call_code_hash_is_empty.assign_value(
             region,
             offset,
-            region.code_hash(call.code_hash),
+            region.code_hash(call_code_hash),
             region.empty_code_hash_rlc(),
         )?;
         self.call_code_hash_is_zero.assign_value(
             region,
             offset,
-            region.code_hash(call.code_hash),
+            region.code_hash(call_code_hash),
         )?;
 
         let untrimmed_contract_addr = {
@@ -1253,8 +1528,11 @@ impl<F: Field> ExecutionGadget<F> for BeginTxGadget<F> {
 mod test {
     use std::{str::FromStr, vec};
 
-    use crate::{evm_circuit::test::rand_bytes, test_util::CircuitTestBuilder};
-    use bus_mapping::evm::OpcodeId;
+    use crate::{
+        evm_circuit::{step::ExecutionState, test::rand_bytes},
+        test_util::CircuitTestBuilder,
+    };
+    use bus_mapping::{circuit_input_builder::CopyDataType, evm::OpcodeId};
     use eth_types::{
         self, address, bytecode, evm_types::GasCost, word, Address, Bytecode, Hash, Word, U256,
     };
@@ -1627,6 +1905,89 @@ mod test {
         CircuitTestBuilder::new_from_test_ctx(ctx).run();
     }
 
+    fn precompile_tx_ctx(precompile: Address, input: Vec<u8>) -> TestContext<1, 1> {
+        TestContext::<1, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).balance(eth(20));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .to(precompile)
+                    .input(Bytes::from(input));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn begin_tx_precompile_truncated_input() {
+        // ecAdd only takes the first 128 bytes of the call data.
+        let ctx = precompile_tx_ctx(
+            address!("0x0000000000000000000000000000000000000006"),
+            vec![0x00; 160],
+        );
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    #[test]
+    fn begin_tx_precompile_untruncated_input() {
+        let ctx = precompile_tx_ctx(
+            address!("0x0000000000000000000000000000000000000006"),
+            vec![0x00; 160],
+        );
+
+        CircuitTestBuilder::new_from_test_ctx(ctx)
+            .block_modifier(Box::new(|block| {
+                // copy the whole call data into the ecAdd input, instead of its first 128 bytes.
+                let call_data = block.txs[0].call_data.clone();
+                let input_copy = block
+                    .copy_events
+                    .iter_mut()
+                    .find(|event| event.dst_type == CopyDataType::RlcAcc)
+                    .unwrap();
+                assert_eq!(input_copy.copy_bytes.bytes.len(), 128);
+                input_copy.src_addr_end = call_data.len() as u64;
+                input_copy.copy_bytes.bytes = call_data
+                    .into_iter()
+                    .map(|byte| (byte, false, false))
+                    .collect();
+            }))
+            .evm_checks(Some(Box::new(|prover, gate_rows, lookup_rows| {
+                assert!(prover
+                    .verify_at_rows_par(gate_rows.iter().cloned(), lookup_rows.iter().cloned())
+                    .is_err())
+            })))
+            .run();
+    }
+
+    #[test]
+    fn begin_tx_precompile_wrong_intrinsic_gas() {
+        let ctx = precompile_tx_ctx(
+            address!("0x0000000000000000000000000000000000000004"),
+            vec![0x01, 0x02, 0x03],
+        );
+
+        CircuitTestBuilder::new_from_test_ctx(ctx)
+            .block_modifier(Box::new(|block| {
+                // pay 1 gas less intrinsic gas than the 21000 + 3 * 16 of the tx.
+                assert_eq!(
+                    block.txs[0].steps[0].execution_state,
+                    ExecutionState::BeginTx
+                );
+                block.txs[0].steps[0].gas_cost -= 1;
+            }))
+            .evm_checks(Some(Box::new(|prover, gate_rows, lookup_rows| {
+                assert!(prover
+                    .verify_at_rows_par(gate_rows.iter().cloned(), lookup_rows.iter().cloned())
+                    .is_err())
+            })))
+            .run();
+    }
+
     /// TODO: remove this test after we have built gadget for blake2f/rimp160
     #[test]
     fn begin_tx_precompile_fail() {
@@ -1704,6 +2065,37 @@ mod test {
         CircuitTestBuilder::new_from_test_ctx(ctx).run();
     }
 
+    #[test]
+    fn begin_tx_deploy_collision() {
+        let deployer = MOCK_ACCOUNTS[0];
+        let nonce = U256::from(3);
+        let collided_address = get_contract_address(deployer, nonce);
+
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(deployer).nonce(nonce).balance(eth(10));
+                accs[1]
+                    .address(collided_address)
+                    .balance(eth(1))
+                    .code(code_with_return());
+            },
+            |mut txs, _| {
+                txs[0]
+                    .from(deployer)
+                    .nonce(nonce)
+                    .gas_price(gwei(2))
+                    .gas(Word::from(0x10000))
+                    .value(eth(2))
+                    .input(code_with_return().into());
+            },
+            |block, _| block,
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
     // Test that we handle the case where account creation tx happens for an account that already
     // has a non-zero balance and codehash.
     // This is not possible in real world.
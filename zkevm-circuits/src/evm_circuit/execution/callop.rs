@@ -6,14 +6,16 @@ use crate::{
         util::{
             and,
             common_gadget::{
-                BytecodeLookupGadget, CommonCallGadget, TransferGadget, TransferGadgetInfo,
+                BytecodeLookupGadget, CommonCallGadget, DelegateAddressGadget,
+                DelegationDesignatorGadget, TransferGadget, TransferGadgetInfo,
             },
             constraint_builder::{
                 ConstrainBuilderCommon, EVMConstraintBuilder, ReversionInfo, StepStateTransition,
                 Transition::{Delta, To},
             },
             math_gadget::{
-                ConstantDivisionGadget, IsZeroGadget, LtGadget, LtWordGadget, MinMaxGadget,
+                ConstantDivisionGadget, IsEqualGadget, IsZeroGadget, LtGadget, LtWordGadget,
+                MinMaxGadget,
             },
             memory_gadget::{CommonMemoryAddressGadget, MemoryAddressGadget},
             not, or,
@@ -22,14 +24,16 @@ use crate::{
         },
         witness::{Block, Call, ExecStep, Transaction},
     },
-    table::{AccountFieldTag, CallContextFieldTag},
+    table::{AccountFieldTag, CallContextFieldTag, RwTableTag},
     util::{Expr, Field},
 };
 use bus_mapping::{
-    circuit_input_builder::CopyDataType, evm::OpcodeId, precompile::PrecompileCalls,
+    circuit_input_builder::CopyDataType,
+    evm::OpcodeId,
+    precompile::{PrecompileCalls, LAST_PRECOMPILE, P256_VERIFY_ENABLED},
 };
 use eth_types::{
-    evm_types::{memory::MemoryWordRange, GAS_STIPEND_CALL_WITH_VALUE},
+    evm_types::{memory::MemoryWordRange, GasCost, GAS_STIPEND_CALL_WITH_VALUE},
     utils::is_precompiled,
     ToAddress, ToBigEndian, ToLittleEndian, U256,
 };
@@ -59,6 +63,13 @@ pub(crate) struct CallOpGadget<F> {
     current_value: Word<F>,
     is_warm: Cell<F>,
     is_warm_prev: Cell<F>,
+    // EIP-7702: a delegated callee executes the code of its delegate.
+    is_delegated: DelegationDesignatorGadget<F>,
+    delegate_address: DelegateAddressGadget<F>,
+    is_delegate_warm_prev: Cell<F>,
+    delegate_code_hash: Cell<F>,
+    delegate_code_hash_is_empty: IsEqualGadget<F>,
+    delegate_code_hash_is_zero: IsZeroGadget<F>,
     callee_reversion_info: ReversionInfo<F>,
     transfer: TransferGadget<F>,
     code_hash_previous: Cell<F>,
@@ -74,6 +85,7 @@ pub(crate) struct CallOpGadget<F> {
     // to handle precompile calls
     is_code_address_zero: IsZeroGadget<F>,
     is_precompile_lt: LtGadget<F, N_BYTES_ACCOUNT_ADDRESS>,
+    is_p256_verify: IsEqualGadget<F>,
     precompile_gadget: PrecompileGadget<F>,
     precompile_return_length: Cell<F>,
     precompile_return_length_zero: IsZeroGadget<F>,
@@ -171,6 +183,43 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
         );
         // rwc_delta = 7 + is_delegatecall * 2 + call_gadget.rw_delta()
 
+        // Add the delegate of a delegated callee to access list
+        let is_delegated = DelegationDesignatorGadget::construct(
+            cb,
+            call_gadget.phase2_callee_code_hash.clone(),
+            not::expr(call_gadget.callee_not_exists.expr()),
+        );
+        let delegate_address = DelegateAddressGadget::construct(
+            cb,
+            call_gadget.phase2_callee_code_hash.expr(),
+            is_delegated.expr(),
+        );
+        let is_delegate_warm_prev = cb.query_bool();
+        let delegate_code_hash = cb.query_cell_phase2();
+        let delegate_code_hash_is_empty =
+            IsEqualGadget::construct(cb, delegate_code_hash.expr(), cb.empty_code_hash_rlc());
+        let delegate_code_hash_is_zero = IsZeroGadget::construct(cb, delegate_code_hash.expr());
+        cb.condition(is_delegated.expr(), |cb| {
+            cb.account_access_list_write(
+                tx_id.expr(),
+                delegate_address.expr(),
+                1.expr(),
+                is_delegate_warm_prev.expr(),
+                Some(&mut reversion_info),
+            );
+            cb.account_read(
+                delegate_address.expr(),
+                AccountFieldTag::CodeHash,
+                delegate_code_hash.expr(),
+            );
+        });
+        // rwc_delta = 7 + is_delegatecall * 2 + call_gadget.rw_delta() + is_delegated * 2
+        let code_hash = select::expr(
+            is_delegated.expr(),
+            delegate_code_hash.expr(),
+            call_gadget.phase2_callee_code_hash.expr(),
+        );
+
         // Propagate rw_counter_end_of_reversion and is_persistent
         let mut callee_reversion_info = cb.reversion_info_write(Some(callee_call_id.expr()));
         // rwc_delta = 7 + is_delegatecall * 2 + call_gadget.rw_delta() +
@@ -222,14 +271,27 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
         });
 
         // whether the call is to a precompiled contract.
-        // precompile contracts are stored from address 0x01 to 0x09.
+        // precompile contracts are stored from address 0x01 to LAST_PRECOMPILE, plus the
+        // secp256r1 verification precompile at 0x100 when it is enabled.
         let is_code_address_zero = IsZeroGadget::construct(cb, call_gadget.callee_address_expr());
-        let is_precompile_lt =
-            LtGadget::construct(cb, call_gadget.callee_address_expr(), 0x0A.expr());
+        let is_precompile_lt = LtGadget::construct(
+            cb,
+            call_gadget.callee_address_expr(),
+            (LAST_PRECOMPILE.address() + 1).expr(),
+        );
+        let is_p256_verify = IsEqualGadget::construct(
+            cb,
+            call_gadget.callee_address_expr(),
+            PrecompileCalls::P256Verify.address().expr(),
+        );
         let is_precompile = and::expr([
             not::expr(is_code_address_zero.expr()),
             is_precompile_lt.expr(),
-        ]);
+        ]) + if P256_VERIFY_ENABLED {
+            is_p256_verify.expr()
+        } else {
+            0.expr()
+        };
         let precompile_return_length = cb.query_cell();
         let precompile_return_length_zero =
             IsZeroGadget::construct(cb, precompile_return_length.expr());
@@ -281,12 +343,22 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
         // no_callee_code is true when the account exists and has empty
         // code hash, or when the account doesn't exist (which we encode with
         // code_hash = 0).
-        let no_callee_code =
-            call_gadget.is_empty_code_hash.expr() + call_gadget.callee_not_exists.expr();
+        // A delegated callee has no code if its delegate has no code.
+        let no_callee_code = select::expr(
+            is_delegated.expr(),
+            delegate_code_hash_is_empty.expr() + delegate_code_hash_is_zero.expr(),
+            call_gadget.is_empty_code_hash.expr() + call_gadget.callee_not_exists.expr(),
+        );
 
         // Sum up and verify gas cost.
         // Only CALL opcode could invoke transfer to make empty account into non-empty.
-        let gas_cost = call_gadget.gas_cost_expr(is_warm_prev.expr(), is_call.expr());
+        let gas_cost = call_gadget.gas_cost_expr(is_warm_prev.expr(), is_call.expr())
+            + is_delegated.expr()
+                * select::expr(
+                    is_delegate_warm_prev.expr(),
+                    GasCost::WARM_ACCESS.expr(),
+                    GasCost::COLD_ACCOUNT_ACCESS.expr(),
+                );
         // Apply EIP 150
         let gas_available = cb.curr.state.gas_left.expr() - gas_cost.clone();
         let one_64th_gas = cb.annotation("one_64th_gas", |cb| {
@@ -310,8 +382,9 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
             + is_delegatecall.expr() * 2.expr()
             + call_gadget.rw_delta()
             + callee_reversion_info.rw_delta()
-            + transfer_rwc_delta.expr();
-        let caller_reversible_rwc_delta = 1.expr(); // AccessList
+            + transfer_rwc_delta.expr()
+            + is_delegated.expr() * 2.expr();
+        let caller_reversible_rwc_delta = 1.expr() + is_delegated.expr(); // AccessList
         let callee_reversible_rwc_delta = is_call.expr() * transfer.reversible_w_delta();
 
         // 1. handle precompile calls.
@@ -392,7 +465,8 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
                     ),
                     (
                         CallContextFieldTag::ReversibleWriteCounter,
-                        cb.curr.state.reversible_write_counter.expr() + 1.expr(),
+                        cb.curr.state.reversible_write_counter.expr()
+                            + caller_reversible_rwc_delta.expr(),
                     ),
                     (CallContextFieldTag::LastCalleeId, callee_call_id.expr()),
                     (CallContextFieldTag::LastCalleeReturnDataOffset, 0.expr()),
@@ -632,7 +706,8 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
                     ),
                     (
                         CallContextFieldTag::ReversibleWriteCounter,
-                        cb.curr.state.reversible_write_counter.expr() + 1.expr(),
+                        cb.curr.state.reversible_write_counter.expr()
+                            + caller_reversible_rwc_delta.expr(),
                     ),
                 ] {
                     cb.call_context_lookup(true.expr(), None, field_tag, value);
@@ -673,10 +748,7 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
                     (CallContextFieldTag::LastCalleeReturnDataLength, 0.expr()),
                     (CallContextFieldTag::IsRoot, 0.expr()),
                     (CallContextFieldTag::IsCreate, 0.expr()),
-                    (
-                        CallContextFieldTag::CodeHash,
-                        call_gadget.phase2_callee_code_hash.expr(),
-                    ),
+                    (CallContextFieldTag::CodeHash, code_hash.expr()),
                 ] {
                     cb.call_context_lookup(
                         true.expr(),
@@ -707,7 +779,7 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
                     call_id: To(callee_call_id.expr()),
                     is_root: To(false.expr()),
                     is_create: To(false.expr()),
-                    code_hash: To(call_gadget.phase2_callee_code_hash.expr()),
+                    code_hash: To(code_hash),
                     gas_left: To(callee_gas_left),
                     reversible_write_counter: To(callee_reversible_rwc_delta.expr()),
                     ..StepStateTransition::new_context()
@@ -731,6 +803,12 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
             call: call_gadget,
             is_warm,
             is_warm_prev,
+            is_delegated,
+            delegate_address,
+            is_delegate_warm_prev,
+            delegate_code_hash,
+            delegate_code_hash_is_empty,
+            delegate_code_hash_is_zero,
             callee_reversion_info,
             transfer,
             code_hash_previous,
@@ -744,6 +822,7 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
             // precompile related fields.
             is_code_address_zero,
             is_precompile_lt,
+            is_p256_verify,
             precompile_gadget,
             precompile_return_length,
             precompile_return_length_zero,
@@ -807,8 +886,49 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
 
         let (is_warm, is_warm_prev) = rws.next().tx_access_list_value_pair();
 
-        let [callee_rw_counter_end_of_reversion, callee_is_persistent] =
-            [(); 2].map(|_| rws.next().call_context_value());
+        // The delegate of a delegated callee is added to access list before the callee
+        // reversion info is written.
+        let rw = rws.next();
+        let (delegate, rw) = if rw.tag() == RwTableTag::TxAccessListAccount {
+            let delegate_address = rw.address().expect("access list rw has an address");
+            let is_delegate_warm_prev = rw.tx_access_list_value_pair().1;
+            let delegate_code_hash = rws.next().account_codehash_pair().0;
+            (
+                Some((delegate_address, is_delegate_warm_prev, delegate_code_hash)),
+                rws.next(),
+            )
+        } else {
+            (None, rw)
+        };
+        let (delegate_address, is_delegate_warm_prev, delegate_code_hash) =
+            delegate.unwrap_or_default();
+        let is_delegated = self
+            .is_delegated
+            .assign(region, offset, block, callee_code_hash)?;
+        debug_assert_eq!(is_delegated, delegate.is_some());
+        self.delegate_address
+            .assign(region, offset, delegate_address)?;
+        self.is_delegate_warm_prev.assign(
+            region,
+            offset,
+            Value::known(F::from(is_delegate_warm_prev as u64)),
+        )?;
+        self.delegate_code_hash
+            .assign(region, offset, region.code_hash(delegate_code_hash))?;
+        self.delegate_code_hash_is_empty.assign_value(
+            region,
+            offset,
+            region.code_hash(delegate_code_hash),
+            region.empty_code_hash_rlc(),
+        )?;
+        self.delegate_code_hash_is_zero.assign_value(
+            region,
+            offset,
+            region.code_hash(delegate_code_hash),
+        )?;
+
+        let callee_rw_counter_end_of_reversion = rw.call_context_value();
+        let callee_is_persistent = rws.next().call_context_value();
 
         // check if it is insufficient balance case.
         // get caller balance
@@ -935,7 +1055,11 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
             is_call,
             has_value,
             !callee_exists,
-        )?;
+        )? + match delegate {
+            Some((_, true, _)) => GasCost::WARM_ACCESS.as_u64(),
+            Some((_, false, _)) => GasCost::COLD_ACCOUNT_ACCESS.as_u64(),
+            None => 0,
+        };
         let gas_available = step.gas_left - gas_cost;
         self.one_64th_gas
             .assign(region, offset, gas_available.into())?;
@@ -955,8 +1079,18 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
         let code_address: F = callee_address.to_address().to_scalar().unwrap();
         self.is_code_address_zero
             .assign(region, offset, code_address)?;
-        self.is_precompile_lt
-            .assign(region, offset, code_address, 0x0Au64.into())?;
+        self.is_precompile_lt.assign(
+            region,
+            offset,
+            code_address,
+            F::from(LAST_PRECOMPILE.address() + 1),
+        )?;
+        self.is_p256_verify.assign(
+            region,
+            offset,
+            code_address,
+            F::from(PrecompileCalls::P256Verify.address()),
+        )?;
         log::trace!("callop is precompile call {}", is_precompile_call);
         let precompile_return_length = if is_precompile_call && is_precheck_ok {
             rws.offset_add(15); // skip
@@ -996,7 +1130,7 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
             output_rws,
             return_rws,
         ) = if is_precheck_ok && is_precompiled(&callee_address.to_address()) {
-            let precompile_call: PrecompileCalls = precompile_addr.0[19].into();
+            let precompile_call: PrecompileCalls = precompile_addr.into();
             let input_len = if let Some(input_len) = precompile_call.input_len() {
                 min(input_len, cd_length.as_usize())
             } else {
@@ -1147,7 +1281,7 @@ impl<F: Field> ExecutionGadget<F> for CallOpGadget<F> {
 
         if is_precompile_call {
             self.precompile_gadget
-                .assign(region, offset, precompile_addr.0[19].into())?;
+                .assign(region, offset, precompile_addr.into())?;
         }
 
         Ok(())
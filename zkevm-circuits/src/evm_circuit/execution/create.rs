@@ -78,6 +78,8 @@ pub(crate) struct CreateGadget<F, const IS_CREATE2: bool, const S: ExecutionStat
     keccak_code_hash: Cell<F>,
     #[cfg(feature = "scroll")]
     prev_keccak_code_hash: Cell<F>,
+    #[cfg(not(feature = "scroll"))]
+    callee_was_created: Cell<F>,
     copy_rw_increase: Cell<F>,
 }
 
@@ -350,6 +352,15 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
         });
 
         let mut callee_reversion_info = cb.reversion_info_write(Some(callee_call_id.expr()));
+        #[cfg(not(feature = "scroll"))]
+        let callee_was_created = cb.query_bool();
+
+        // EIP 161 nonce write, plus the EIP-6780 created flag write when not in
+        // scroll mode.
+        #[cfg(feature = "scroll")]
+        let callee_init_reversible_write_num = 1;
+        #[cfg(not(feature = "scroll"))]
+        let callee_init_reversible_write_num = 2;
 
         // Case1: Handle the case where an error of ErrDepth, ErrInsufficientBalance or
         // ErrNonceUintOverflow occurred.
@@ -425,6 +436,15 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                     0.expr(),
                     Some(&mut callee_reversion_info),
                 );
+                // EIP-6780, the contract may be deleted by SELFDESTRUCT within this tx
+                #[cfg(not(feature = "scroll"))]
+                cb.tx_created_account_write(
+                    tx_id.expr(),
+                    contract_addr.clone(),
+                    1.expr(),
+                    callee_was_created.expr(),
+                    Some(&mut callee_reversion_info),
+                );
 
                 cb.condition(init_code.has_length(), |cb| {
                     for (field_tag, value) in [
@@ -462,7 +482,9 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                         is_create: To(true.expr()),
                         code_hash: To(create.code_hash_word_rlc()),
                         gas_left: To(callee_gas_left),
-                        reversible_write_counter: To(1.expr() + transfer.reversible_w_delta()),
+                        reversible_write_counter: To(
+                            callee_init_reversible_write_num.expr() + transfer.reversible_w_delta()
+                        ),
                         ..StepStateTransition::new_context()
                     });
                 });
@@ -485,7 +507,11 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                         program_counter: Delta(1.expr()),
                         stack_pointer: Delta(2.expr() + IS_CREATE2.expr()),
                         gas_left: Delta(-gas_cost.expr()),
-                        reversible_write_counter: Delta(3.expr() + transfer.reversible_w_delta()),
+                        reversible_write_counter: Delta(
+                            2.expr()
+                                + callee_init_reversible_write_num.expr()
+                                + transfer.reversible_w_delta(),
+                        ),
                         ..Default::default()
                     })
                 });
@@ -562,6 +588,8 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
             keccak_code_hash,
             #[cfg(feature = "scroll")]
             prev_keccak_code_hash,
+            #[cfg(not(feature = "scroll"))]
+            callee_was_created,
             copy_rw_increase,
         }
     }
@@ -734,6 +762,14 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
                     offset,
                     region.word_rlc(_transfer_assign_result.account_keccak_code_hash.unwrap()),
                 )?;
+
+                rws.next(); // callee nonce += 1
+                #[cfg(not(feature = "scroll"))]
+                self.callee_was_created.assign(
+                    region,
+                    offset,
+                    Value::known(F::from(rws.next().tx_created_account_value_pair().1 as u64)),
+                )?;
             }
 
             let code_hash = CodeDB::hash(&values);
@@ -791,7 +827,6 @@ impl<F: Field, const IS_CREATE2: bool, const S: ExecutionState> ExecutionGadget<
             } else if init_code_length.as_usize() == 0 {
                 F::one()
             } else {
-                rws.next(); // callee nonce += 1
                 rws.next(); // caller id
                 let rw = rws.next();
                 debug_assert_eq!(rw.tag(), RwTableTag::CallContext);
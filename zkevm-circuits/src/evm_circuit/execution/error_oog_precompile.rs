@@ -6,7 +6,8 @@ use crate::{
         util::{
             common_gadget::RestoreContextGadget,
             constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
-            math_gadget::{BinaryNumberGadget, ConstantDivisionGadget, LtGadget},
+            math_gadget::{ConstantDivisionGadget, LtGadget},
+            precompile_gadget::PrecompileAddressGadget,
             CachedRegion, Cell,
         },
     },
@@ -23,7 +24,7 @@ use halo2_proofs::{circuit::Value, plonk::Error};
 #[derive(Clone, Debug)]
 pub(crate) struct ErrorOOGPrecompileGadget<F> {
     precompile_addr: Cell<F>,
-    addr_bits: BinaryNumberGadget<F, 4>,
+    addr_bits: PrecompileAddressGadget<F>,
     call_data_length: Cell<F>,
     is_root: Cell<F>,
     n_pairs: ConstantDivisionGadget<F, N_BYTES_MEMORY_WORD_SIZE>,
@@ -43,7 +44,7 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
 
         // read callee_address
         let precompile_addr = cb.call_context(None, CallContextFieldTag::CalleeAddress);
-        let addr_bits = BinaryNumberGadget::construct(cb, precompile_addr.expr());
+        let addr_bits = PrecompileAddressGadget::construct(cb, precompile_addr.expr());
 
         // read call data length
         let call_data_length = cb.call_context(None, CallContextFieldTag::CallDataLength);
@@ -60,13 +61,18 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
                 )
             },
         );
-        let n_words = cb.condition(addr_bits.value_equals(PrecompileCalls::Identity), |cb| {
-            ConstantDivisionGadget::construct(
-                cb,
-                call_data_length.expr() + (N_BYTES_WORD - 1).expr(),
-                N_BYTES_WORD as u64,
-            )
-        });
+        let n_words = cb.condition(
+            addr_bits.value_equals(PrecompileCalls::Sha256)
+                + addr_bits.value_equals(PrecompileCalls::Ripemd160)
+                + addr_bits.value_equals(PrecompileCalls::Identity),
+            |cb| {
+                ConstantDivisionGadget::construct(
+                    cb,
+                    call_data_length.expr() + (N_BYTES_WORD - 1).expr(),
+                    N_BYTES_WORD as u64,
+                )
+            },
+        );
 
         // calculate required gas for precompile
         let precompiles_required_gas = [
@@ -80,8 +86,12 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
                 GasCost::PRECOMPILE_SHA256_BASE.expr()
                     + n_words.quotient() * GasCost::PRECOMPILE_SHA256_PER_WORD.expr(),
             ),
-            // addr_bits.value_equals(PrecompileCalls::Ripemd160),
-            // addr_bits.value_equals(PrecompileCalls::Blake2F),
+            (
+                addr_bits.value_equals(PrecompileCalls::Ripemd160),
+                GasCost::PRECOMPILE_RIPEMD160_BASE.expr()
+                    + n_words.quotient() * GasCost::PRECOMPILE_RIPEMD160_PER_WORD.expr(),
+            ),
+            // blake2f is handled in Blake2FGadget
             (
                 addr_bits.value_equals(PrecompileCalls::Identity),
                 GasCost::PRECOMPILE_IDENTITY_BASE.expr()
@@ -101,6 +111,10 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
                 GasCost::PRECOMPILE_BN256PAIRING.expr()
                     + n_pairs.quotient() * GasCost::PRECOMPILE_BN256PAIRING_PER_PAIR.expr(),
             ),
+            (
+                addr_bits.value_equals(PrecompileCalls::P256Verify),
+                GasCost::PRECOMPILE_P256VERIFY.expr(),
+            ),
         ];
 
         cb.require_equal(
@@ -166,8 +180,8 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
             offset,
             Value::known(precompile_addr.to_scalar().unwrap()),
         )?;
-        self.addr_bits
-            .assign(region, offset, precompile_addr.to_fixed_bytes()[19])?;
+        let precompile_call: PrecompileCalls = precompile_addr.into();
+        self.addr_bits.assign(region, offset, precompile_call)?;
 
         // call_data_length
         self.call_data_length.assign(
@@ -193,12 +207,16 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
         )?;
 
         // required_gas
-        let precompile_call: PrecompileCalls = precompile_addr.to_fixed_bytes()[19].into();
         let required_gas = match precompile_call {
             PrecompileCalls::Bn128Pairing => {
                 precompile_call.base_gas_cost().as_u64()
                     + n_pairs * GasCost::PRECOMPILE_BN256PAIRING_PER_PAIR.as_u64()
             }
+            PrecompileCalls::Ripemd160 => {
+                let n_words = (call.call_data_length + 31) / 32;
+                precompile_call.base_gas_cost().as_u64()
+                    + n_words * GasCost::PRECOMPILE_RIPEMD160_PER_WORD.as_u64()
+            }
             PrecompileCalls::Identity => {
                 let n_words = (call.call_data_length + 31) / 32;
                 precompile_call.base_gas_cost().as_u64()
@@ -209,9 +227,10 @@ impl<F: Field> ExecutionGadget<F> for ErrorOOGPrecompileGadget<F> {
                 precompile_call.base_gas_cost().as_u64()
                     + n_words * GasCost::PRECOMPILE_SHA256_PER_WORD.as_u64()
             }
-            PrecompileCalls::Bn128Add | PrecompileCalls::Bn128Mul | PrecompileCalls::Ecrecover => {
-                precompile_call.base_gas_cost().as_u64()
-            }
+            PrecompileCalls::Bn128Add
+            | PrecompileCalls::Bn128Mul
+            | PrecompileCalls::Ecrecover
+            | PrecompileCalls::P256Verify => precompile_call.base_gas_cost().as_u64(),
             _ => unreachable!(),
         };
 
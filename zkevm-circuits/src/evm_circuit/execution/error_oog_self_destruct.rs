@@ -0,0 +1,336 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        param::{N_BYTES_ACCOUNT_ADDRESS, N_BYTES_GAS},
+        step::ExecutionState,
+        util::{
+            common_gadget::CommonErrorGadget,
+            constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
+            from_bytes,
+            math_gadget::{IsZeroGadget, LtGadget},
+            not, CachedRegion, Cell, Word,
+        },
+        witness::{Block, Call, ExecStep, Transaction},
+    },
+    table::{AccountFieldTag, CallContextFieldTag},
+    util::{Expr, Field},
+};
+use eth_types::{
+    evm_types::{GasCost, OpcodeId},
+    ToLittleEndian, ToScalar,
+};
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+/// Gadget to implement the corresponding out of gas errors for
+/// [`OpcodeId::SELFDESTRUCT`].
+#[derive(Clone, Debug)]
+pub(crate) struct ErrorOOGSelfDestructGadget<F> {
+    opcode: Cell<F>,
+    beneficiary: Word<F>,
+    tx_id: Cell<F>,
+    callee_address: Cell<F>,
+    is_warm: Cell<F>,
+    beneficiary_code_hash: Cell<F>,
+    beneficiary_not_exists: IsZeroGadget<F>,
+    balance: Word<F>,
+    balance_is_zero: IsZeroGadget<F>,
+    insufficient_gas: LtGadget<F, N_BYTES_GAS>,
+    common_error_gadget: CommonErrorGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for ErrorOOGSelfDestructGadget<F> {
+    const NAME: &'static str = "ErrorOutOfGasSELFDESTRUCT";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::ErrorOutOfGasSELFDESTRUCT;
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let opcode = cb.query_cell();
+        cb.require_equal(
+            "ErrorOutOfGasSELFDESTRUCT opcode must be SELFDESTRUCT",
+            opcode.expr(),
+            OpcodeId::SELFDESTRUCT.expr(),
+        );
+
+        let beneficiary = cb.query_word_rlc();
+        let beneficiary_address = from_bytes::expr(&beneficiary.cells[..N_BYTES_ACCOUNT_ADDRESS]);
+        cb.stack_pop(beneficiary.expr());
+
+        let tx_id = cb.call_context(None, CallContextFieldTag::TxId);
+        let callee_address = cb.call_context(None, CallContextFieldTag::CalleeAddress);
+
+        let is_warm = cb.query_bool();
+        cb.account_access_list_read(tx_id.expr(), beneficiary_address.expr(), is_warm.expr());
+
+        let beneficiary_code_hash = cb.query_cell_phase2();
+        cb.account_read(
+            beneficiary_address.expr(),
+            AccountFieldTag::CodeHash,
+            beneficiary_code_hash.expr(),
+        );
+        let beneficiary_not_exists = IsZeroGadget::construct(cb, beneficiary_code_hash.expr());
+
+        let balance = cb.query_word_rlc();
+        cb.account_read(
+            callee_address.expr(),
+            AccountFieldTag::Balance,
+            balance.expr(),
+        );
+        let balance_is_zero = IsZeroGadget::construct(cb, balance.expr());
+
+        // Same gas cost model as the successful SELFDESTRUCT.
+        let gas_cost = GasCost::SELFDESTRUCT.expr()
+            + not::expr(is_warm.expr()) * GasCost::COLD_ACCOUNT_ACCESS.expr()
+            + beneficiary_not_exists.expr()
+                * not::expr(balance_is_zero.expr())
+                * GasCost::NEW_ACCOUNT.expr();
+
+        let insufficient_gas = LtGadget::construct(cb, cb.curr.state.gas_left.expr(), gas_cost);
+        cb.require_equal(
+            "Gas left is less than gas cost",
+            insufficient_gas.expr(),
+            1.expr(),
+        );
+
+        let common_error_gadget = CommonErrorGadget::construct(cb, opcode.expr(), 8.expr());
+
+        Self {
+            opcode,
+            beneficiary,
+            tx_id,
+            callee_address,
+            is_warm,
+            beneficiary_code_hash,
+            beneficiary_not_exists,
+            balance,
+            balance_is_zero,
+            insufficient_gas,
+            common_error_gadget,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        let opcode = step.opcode.unwrap();
+        self.opcode
+            .assign(region, offset, Value::known(F::from(opcode.as_u64())))?;
+
+        let beneficiary = block.rws[step.rw_indices[0]].stack_value();
+        self.beneficiary
+            .assign(region, offset, Some(beneficiary.to_le_bytes()))?;
+
+        self.tx_id
+            .assign(region, offset, Value::known(F::from(tx.id as u64)))?;
+        self.callee_address.assign(
+            region,
+            offset,
+            Value::known(
+                call.callee_address
+                    .to_scalar()
+                    .expect("unexpected Address -> Scalar conversion failure"),
+            ),
+        )?;
+
+        let (_, is_warm) = block.rws[step.rw_indices[3]].tx_access_list_value_pair();
+        self.is_warm
+            .assign(region, offset, Value::known(F::from(is_warm)))?;
+
+        let beneficiary_code_hash = block.rws[step.rw_indices[4]].account_value_pair().0;
+        self.beneficiary_code_hash.assign(
+            region,
+            offset,
+            region.code_hash(beneficiary_code_hash),
+        )?;
+        self.beneficiary_not_exists.assign_value(
+            region,
+            offset,
+            region.code_hash(beneficiary_code_hash),
+        )?;
+
+        let balance = block.rws[step.rw_indices[5]].account_value_pair().0;
+        self.balance
+            .assign(region, offset, Some(balance.to_le_bytes()))?;
+        self.balance_is_zero
+            .assign_value(region, offset, region.word_rlc(balance))?;
+
+        let gas_cost = GasCost::SELFDESTRUCT.as_u64()
+            + if is_warm {
+                0
+            } else {
+                GasCost::COLD_ACCOUNT_ACCESS.as_u64()
+            }
+            + if beneficiary_code_hash.is_zero() && !balance.is_zero() {
+                GasCost::NEW_ACCOUNT.as_u64()
+            } else {
+                0
+            };
+        self.insufficient_gas
+            .assign(region, offset, F::from(step.gas_left), F::from(gas_cost))?;
+
+        self.common_error_gadget
+            .assign(region, offset, block, call, step, 8)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_util::CircuitTestBuilder;
+    use eth_types::{
+        address, bytecode,
+        evm_types::{GasCost, OpcodeId},
+        Address, Bytecode, ToWord, Word,
+    };
+    use mock::{TestContext, MOCK_ACCOUNTS};
+    use std::sync::LazyLock;
+
+    static BENEFICIARY: LazyLock<Address> =
+        LazyLock::new(|| address!("0x00000000000000000000000000000000000000be"));
+
+    fn selfdestruct_code(beneficiary: Address, is_warm: bool) -> Bytecode {
+        let mut code = Bytecode::default();
+        if is_warm {
+            code.append(&bytecode! {
+                PUSH20(beneficiary.to_word())
+                BALANCE
+                POP
+            });
+        }
+        code.append(&bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        });
+        code
+    }
+
+    /// Gas cost of the SELFDESTRUCT step, also counting the cost of the
+    /// preceding opcodes.
+    fn selfdestruct_gas(is_warm: bool, is_new_account: bool) -> u64 {
+        let warm_up = if is_warm {
+            OpcodeId::PUSH20.constant_gas_cost().0
+                + GasCost::COLD_ACCOUNT_ACCESS.as_u64()
+                + OpcodeId::POP.constant_gas_cost().0
+        } else {
+            0
+        };
+        warm_up
+            + OpcodeId::PUSH20.constant_gas_cost().0
+            + GasCost::SELFDESTRUCT.as_u64()
+            + if is_warm {
+                0
+            } else {
+                GasCost::COLD_ACCOUNT_ACCESS.as_u64()
+            }
+            + if is_new_account {
+                GasCost::NEW_ACCOUNT.as_u64()
+            } else {
+                0
+            }
+    }
+
+    fn test_root(beneficiary_exists: bool, is_warm: bool, balance: Word) {
+        let code = selfdestruct_code(*BENEFICIARY, is_warm);
+        let is_new_account = !beneficiary_exists && !balance.is_zero();
+        let gas = GasCost::TX.as_u64() + selfdestruct_gas(is_warm, is_new_account) - 1;
+
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(balance)
+                    .code(code);
+                accs[1]
+                    .address(MOCK_ACCOUNTS[1])
+                    .balance(Word::from(1_u64 << 20));
+                if beneficiary_exists {
+                    accs[2].address(*BENEFICIARY).balance(Word::from(1));
+                } else {
+                    accs[2]
+                        .address(MOCK_ACCOUNTS[2])
+                        .balance(Word::from(1_u64 << 20));
+                }
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[0].address)
+                    .from(accs[1].address)
+                    .gas(gas.into());
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    fn test_internal(beneficiary_exists: bool, is_warm: bool, balance: Word) {
+        let code_b = selfdestruct_code(*BENEFICIARY, is_warm);
+        let is_new_account = !beneficiary_exists && !balance.is_zero();
+        let gas = selfdestruct_gas(is_warm, is_new_account) - 1;
+
+        // code A calls code B with insufficient gas.
+        let code_a = bytecode! {
+            PUSH1(0x00) // retLength
+            PUSH1(0x00) // retOffset
+            PUSH1(0x00) // argsLength
+            PUSH1(0x00) // argsOffset
+            PUSH1(0x00) // value
+            PUSH20(MOCK_ACCOUNTS[0].to_word()) // addr
+            PUSH32(Word::from(gas)) // gas
+            CALL
+            STOP
+        };
+
+        let ctx = TestContext::<4, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(balance)
+                    .code(code_b);
+                accs[1].address(MOCK_ACCOUNTS[1]).code(code_a);
+                if beneficiary_exists {
+                    accs[2].address(*BENEFICIARY).balance(Word::from(1));
+                } else {
+                    accs[2]
+                        .address(MOCK_ACCOUNTS[2])
+                        .balance(Word::from(1_u64 << 20));
+                }
+                accs[3]
+                    .address(MOCK_ACCOUNTS[3])
+                    .balance(Word::from(1_u64 << 20));
+            },
+            |mut txs, accs| {
+                txs[0].to(accs[1].address).from(accs[3].address);
+            },
+            |block, _tx| block,
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    #[test]
+    fn error_oog_self_destruct_root() {
+        for (beneficiary_exists, is_warm) in [(true, false), (true, true), (false, false)] {
+            test_root(beneficiary_exists, is_warm, Word::from(1000));
+            test_root(beneficiary_exists, is_warm, Word::zero());
+        }
+    }
+
+    #[test]
+    fn error_oog_self_destruct_internal() {
+        for (beneficiary_exists, is_warm) in [(true, false), (true, true), (false, false)] {
+            test_internal(beneficiary_exists, is_warm, Word::from(1000));
+            test_internal(beneficiary_exists, is_warm, Word::zero());
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use bus_mapping::precompile::PrecompileAuxData;
+use eth_types::evm_types::GasCost;
+use gadgets::util::{and, not, select, Expr};
+use gadgets::ToScalar;
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        param::N_BYTES_GAS,
+        step::ExecutionState,
+        util::{
+            common_gadget::RestoreContextGadget,
+            constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
+            from_bytes,
+            math_gadget::{IsEqualGadget, IsZeroGadget, LtGadget},
+            rlc, CachedRegion, Cell,
+        },
+    },
+    table::CallContextFieldTag,
+    util::Field,
+    witness::{Block, Call, ExecStep, Transaction},
+};
+
+/// Length of the blake2f input: rounds (4) || h (64) || m (128) || t (16) || f (1).
+pub(crate) const BLAKE2F_INPUT_LEN: usize = 213;
+/// Length of the blake2f output, i.e. the compressed state vector h.
+pub(crate) const BLAKE2F_OUTPUT_LEN: usize = 64;
+
+#[derive(Clone, Debug)]
+pub struct Blake2FGadget<F> {
+    input_bytes_rlc: Cell<F>,
+    output_bytes_rlc: Cell<F>,
+    return_bytes_rlc: Cell<F>,
+
+    is_success: Cell<F>,
+    callee_address: Cell<F>,
+    is_root: Cell<F>,
+    call_data_offset: Cell<F>,
+    call_data_length: Cell<F>,
+    return_data_offset: Cell<F>,
+    return_data_length: Cell<F>,
+    restore_context: RestoreContextGadget<F>,
+
+    input_bytes: [Cell<F>; BLAKE2F_INPUT_LEN],
+    is_valid_length: IsEqualGadget<F>,
+    is_valid_flag: IsZeroGadget<F>,
+    is_gas_insufficient: LtGadget<F, N_BYTES_GAS>,
+}
+
+impl<F: Field> ExecutionGadget<F> for Blake2FGadget<F> {
+    const EXECUTION_STATE: ExecutionState = ExecutionState::PrecompileBlake2f;
+
+    const NAME: &'static str = "BLAKE2F";
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let (input_bytes_rlc, output_bytes_rlc, return_bytes_rlc) = (
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+        );
+        let [is_success, callee_address, is_root, call_data_offset, call_data_length, return_data_offset, return_data_length] =
+            [
+                CallContextFieldTag::IsSuccess,
+                CallContextFieldTag::CalleeAddress,
+                CallContextFieldTag::IsRoot,
+                CallContextFieldTag::CallDataOffset,
+                CallContextFieldTag::CallDataLength,
+                CallContextFieldTag::ReturnDataOffset,
+                CallContextFieldTag::ReturnDataLength,
+            ]
+            .map(|tag| cb.call_context(None, tag));
+
+        cb.precompile_info_lookup(
+            cb.execution_state().as_u64().expr(),
+            callee_address.expr(),
+            cb.execution_state().precompile_base_gas_cost().expr(),
+        );
+
+        // the input is only decoded when it has exactly 213 bytes, any other length fails.
+        let input_bytes = cb.query_bytes();
+        let is_valid_length =
+            IsEqualGadget::construct(cb, call_data_length.expr(), BLAKE2F_INPUT_LEN.expr());
+        cb.condition(is_valid_length.expr(), |cb| {
+            cb.require_equal(
+                "input bytes rlc == rlc(rounds || h || m || t || f)",
+                input_bytes_rlc.expr(),
+                rlc::expr(
+                    &input_bytes
+                        .iter()
+                        .rev()
+                        .map(|byte| byte.expr())
+                        .collect::<Vec<_>>(),
+                    cb.challenges().keccak_input(),
+                ),
+            );
+        });
+
+        // rounds is a big-endian u32 and the final block indicator flag must be 0 or 1.
+        let rounds = from_bytes::expr(
+            &input_bytes[..4]
+                .iter()
+                .rev()
+                .map(|byte| byte.expr())
+                .collect::<Vec<_>>(),
+        );
+        let flag = input_bytes[BLAKE2F_INPUT_LEN - 1].expr();
+        let is_valid_flag = IsZeroGadget::construct(cb, flag.expr() * (flag - 1.expr()));
+
+        let required_gas = rounds.expr() * GasCost::PRECOMPILE_BLAKE2F_PER_ROUND.expr();
+        let is_gas_insufficient =
+            LtGadget::construct(cb, cb.curr.state.gas_left.expr(), required_gas.expr());
+
+        cb.require_equal(
+            "call success if valid input and enough gas",
+            is_success.expr(),
+            and::expr([
+                is_valid_length.expr(),
+                is_valid_flag.expr(),
+                not::expr(is_gas_insufficient.expr()),
+            ]),
+        );
+
+        // blake2f verify lookup
+        cb.condition(is_success.expr(), |cb| {
+            cb.blake2f_table_lookup(
+                rounds.expr(),
+                input_bytes_rlc.expr(),
+                output_bytes_rlc.expr(),
+            );
+        });
+        cb.condition(not::expr(is_success.expr()), |cb| {
+            cb.require_zero("no output for failed call", output_bytes_rlc.expr());
+        });
+
+        let gas_cost = select::expr(
+            is_success.expr(),
+            required_gas,
+            cb.curr.state.gas_left.expr(),
+        );
+
+        let restore_context = super::gen_restore_context(
+            cb,
+            is_root.expr(),
+            is_success.expr(),
+            gas_cost.expr(),
+            select::expr(is_success.expr(), BLAKE2F_OUTPUT_LEN.expr(), 0x00.expr()), // ReturnDataLength
+        );
+
+        Self {
+            input_bytes_rlc,
+            output_bytes_rlc,
+            return_bytes_rlc,
+
+            is_success,
+            callee_address,
+            is_root,
+            call_data_offset,
+            call_data_length,
+            return_data_offset,
+            return_data_length,
+            restore_context,
+
+            input_bytes,
+            is_valid_length,
+            is_valid_flag,
+            is_gas_insufficient,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        _tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        if let Some(PrecompileAuxData::Blake2F {
+            input_bytes,
+            output_bytes,
+            return_bytes,
+        }) = &step.aux_data
+        {
+            for (col, bytes) in [
+                (&self.input_bytes_rlc, input_bytes),
+                (&self.output_bytes_rlc, output_bytes),
+                (&self.return_bytes_rlc, return_bytes),
+            ] {
+                col.assign(
+                    region,
+                    offset,
+                    region
+                        .challenges()
+                        .keccak_input()
+                        .map(|r| rlc::value(bytes.iter().rev(), r)),
+                )?;
+            }
+
+            let decoded = if input_bytes.len() == BLAKE2F_INPUT_LEN {
+                input_bytes.clone()
+            } else {
+                vec![0; BLAKE2F_INPUT_LEN]
+            };
+            for (cell, &byte) in self.input_bytes.iter().zip(decoded.iter()) {
+                cell.assign(region, offset, Value::known(F::from(byte as u64)))?;
+            }
+
+            let flag = F::from(decoded[BLAKE2F_INPUT_LEN - 1] as u64);
+            self.is_valid_flag
+                .assign(region, offset, flag * (flag - F::one()))?;
+
+            let rounds = u32::from_be_bytes(decoded[..4].try_into().unwrap()) as u64;
+            self.is_gas_insufficient.assign(
+                region,
+                offset,
+                F::from(step.gas_left),
+                F::from(rounds * GasCost::PRECOMPILE_BLAKE2F_PER_ROUND.as_u64()),
+            )?;
+        } else {
+            log::error!("unexpected aux_data {:?} for blake2f", step.aux_data);
+            return Err(Error::Synthesis);
+        }
+
+        self.is_valid_length.assign(
+            region,
+            offset,
+            F::from(call.call_data_length),
+            F::from(BLAKE2F_INPUT_LEN as u64),
+        )?;
+        self.is_success.assign(
+            region,
+            offset,
+            Value::known(F::from(u64::from(call.is_success))),
+        )?;
+        self.callee_address.assign(
+            region,
+            offset,
+            Value::known(call.code_address.unwrap().to_scalar().unwrap()),
+        )?;
+        self.is_root
+            .assign(region, offset, Value::known(F::from(call.is_root as u64)))?;
+        self.call_data_offset.assign(
+            region,
+            offset,
+            Value::known(F::from(call.call_data_offset)),
+        )?;
+        self.call_data_length.assign(
+            region,
+            offset,
+            Value::known(F::from(call.call_data_length)),
+        )?;
+        self.return_data_offset.assign(
+            region,
+            offset,
+            Value::known(F::from(call.return_data_offset)),
+        )?;
+        self.return_data_length.assign(
+            region,
+            offset,
+            Value::known(F::from(call.return_data_length)),
+        )?;
+        self.restore_context
+            .assign(region, offset, block, call, step, 7)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bus_mapping::{
+        evm::{OpcodeId, PrecompileCallArgs},
+        precompile::PrecompileCalls,
+    };
+    use eth_types::{bytecode, word, Bytecode, ToWord};
+    use itertools::Itertools;
+    use mock::TestContext;
+    use std::sync::LazyLock;
+
+    use crate::test_util::CircuitTestBuilder;
+
+    /// Store the 213-byte input of EIP-152 test vector 5 ("abc", 12 rounds) at memory 0x00,
+    /// with the given rounds and final block indicator flag.
+    fn setup_code(rounds: u32, flag: u8) -> Bytecode {
+        let mut code = bytecode! {
+            PUSH32(word!("48c9bdf267e6096a3ba7ca8485ae67bb2bf894fe72f36e3cf1361d5f3af54fa5"))
+            PUSH1(0x04)
+            MSTORE
+            PUSH32(word!("d182e6ad7f520e511f6c3e2b8c68059b6bbd41fbabd9831f79217e1319cde05b"))
+            PUSH1(0x24)
+            MSTORE
+            PUSH32(word!("6162630000000000000000000000000000000000000000000000000000000000"))
+            PUSH1(0x44)
+            MSTORE
+            PUSH1(0x03)
+            PUSH1(0xc4)
+            MSTORE8
+        };
+        // rounds is stored in big-endian at [0x00, 0x04).
+        for (i, byte) in rounds.to_be_bytes().into_iter().enumerate() {
+            code.push(1, byte as u64);
+            code.push(1, i);
+            code.write_op(OpcodeId::MSTORE8);
+        }
+        code.push(1, flag as u64);
+        code.push(1, 0xd4);
+        code.write_op(OpcodeId::MSTORE8);
+        code
+    }
+
+    static TEST_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+        vec![
+            PrecompileCallArgs {
+                name: "eip-152 test vector 5",
+                setup_code: setup_code(12, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "eip-152 test vector 6 (not final block)",
+                setup_code: setup_code(12, 0),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "zero rounds",
+                setup_code: setup_code(0, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x100.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "more rounds than a sigma period, truncated return",
+                setup_code: setup_code(23, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x20.into(),
+                ret_size: 0x10.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+        ]
+    });
+
+    static TEST_INVALID_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+        vec![
+            PrecompileCallArgs {
+                name: "input too short",
+                setup_code: setup_code(12, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd4.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "input too long",
+                setup_code: setup_code(12, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd6.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "invalid final block indicator flag",
+                setup_code: setup_code(12, 2),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "oog",
+                setup_code: setup_code(100_000, 1),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xd5.into(),
+                ret_offset: 0x00.into(),
+                ret_size: 0x40.into(),
+                address: PrecompileCalls::Blake2F.address().to_word(),
+                gas: 50_000.into(),
+                ..Default::default()
+            },
+        ]
+    });
+
+    #[test]
+    fn precompile_blake2f_test() {
+        let call_kinds = vec![
+            OpcodeId::CALL,
+            OpcodeId::STATICCALL,
+            OpcodeId::DELEGATECALL,
+            OpcodeId::CALLCODE,
+        ];
+
+        for (test_vector, &call_kind) in TEST_VECTOR.iter().cartesian_product(&call_kinds) {
+            let bytecode = test_vector.with_call_op(call_kind);
+
+            CircuitTestBuilder::new_from_test_ctx(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+            )
+            .block_modifier(Box::new(|blk| {
+                assert_eq!(blk.get_blake2f().len(), 1);
+            }))
+            .run();
+        }
+    }
+
+    #[test]
+    fn precompile_blake2f_invalid_test() {
+        for test_vector in TEST_INVALID_VECTOR.iter() {
+            let bytecode = test_vector.with_call_op(OpcodeId::STATICCALL);
+
+            CircuitTestBuilder::new_from_test_ctx(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+            )
+            .block_modifier(Box::new(|blk| {
+                assert_eq!(blk.get_blake2f().len(), 0);
+            }))
+            .run();
+        }
+    }
+}
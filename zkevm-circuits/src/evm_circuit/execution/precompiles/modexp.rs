@@ -1,6 +1,6 @@
 use crate::util::Field;
 use bus_mapping::precompile::{
-    PrecompileAuxData, PrecompileCalls, MODEXP_INPUT_LIMIT, MODEXP_SIZE_LIMIT,
+    ModExpAuxData, PrecompileAuxData, PrecompileCalls, MODEXP_INPUT_LIMIT, MODEXP_SIZE_LIMIT,
 };
 use eth_types::{evm_types::GasCost, ToBigEndian, U256};
 use gadgets::util::{self, not, select, Expr};
@@ -30,100 +30,10 @@ use crate::{
     witness::{Block, Call, ExecStep, Transaction},
 };
 
-#[derive(Clone, Debug)]
-struct RandPowRepresent<F, const BIT_LIMIT: usize> {
-    bits: BinaryNumberGadget<F, BIT_LIMIT>,
-    pow_lookup: Cell<F>,
-    cache_for_degree: Option<Cell<F>>,
-    pow: Expression<F>,
-}
-
-impl<F: Field, const BIT_LIMIT: usize> RandPowRepresent<F, BIT_LIMIT> {
-    const BIT_EXP_MAX_DEGREE: usize = 4;
-
-    /// build randomness r, r**2, ... r**(2**BIT_LIMIT)
-    pub fn base_pows_expr(randomness: Expression<F>) -> [Expression<F>; BIT_LIMIT] {
-        std::iter::successors(Some(randomness), |r| Some(r.clone() * r.clone()))
-            .take(BIT_LIMIT)
-            .collect::<Vec<_>>()
-            .try_into()
-            .expect("same length")
-    }
-
-    /// refer to a binary represent of exponent (like BinaryNumberGadget), can
-    /// link another expression so the expr is linked_val * r ** exponent
-    pub fn configure(
-        cb: &mut EVMConstraintBuilder<F>,
-        _randomness: Expression<F>,
-        exponent: Expression<F>,
-        linked_val: Option<Expression<F>>,
-    ) -> Self {
-        let bits = BinaryNumberGadget::construct(cb, exponent);
-        let pow_lookup = cb.query_cell_phase2();
-        cb.pow_of_rand_lookup(bits.value(), pow_lookup.expr());
-
-        let pow = linked_val.unwrap_or_else(|| 1.expr()) * pow_lookup.expr();
-        // we would cache the pow expression in case degree is too largest
-        let cache_for_degree = if pow.degree() > Self::BIT_EXP_MAX_DEGREE {
-            let cached_cell = cb.query_cell_phase2();
-            cb.require_equal(
-                "pow_assemble cached current expression",
-                cached_cell.expr(),
-                pow.clone(),
-            );
-            Some(cached_cell)
-        } else {
-            None
-        };
-
-        Self {
-            bits,
-            pow_lookup,
-            cache_for_degree,
-            pow,
-        }
-    }
-
-    pub fn expr(&self) -> Expression<F> {
-        self.pow.clone()
-    }
-
-    pub fn assign(
-        &self,
-        region: &mut CachedRegion<'_, '_, F>,
-        offset: usize,
-        exponent: usize,
-        linked_value: Option<Value<F>>,
-    ) -> Result<Value<F>, Error> {
-        assert!(
-            2usize.pow(BIT_LIMIT as u32) > exponent,
-            "exponent ({exponent}) can not exceed bit limit (2**{BIT_LIMIT}-1)"
-        );
-        self.bits.assign(region, offset, exponent)?;
-
-        let pow_of_rand = region
-            .challenges()
-            .keccak_input()
-            .map(|v| v.pow([exponent as u64, 0, 0, 0]));
-        let value_should_assigned =
-            linked_value.unwrap_or_else(|| Value::known(F::one())) * pow_of_rand;
-
-        self.pow_lookup.assign(region, offset, pow_of_rand)?;
-
-        if let Some(cell) = &self.cache_for_degree {
-            cell.assign(region, offset, value_should_assigned)?;
-        }
-
-        Ok(value_should_assigned)
-    }
-}
-
 const SIZE_LIMIT: usize = MODEXP_SIZE_LIMIT;
-const SIZE_REPRESENT_BITS: usize = 6;
 const SIZE_REPRESENT_BYTES: usize = SIZE_LIMIT / 256 + 1;
-const INPUT_LIMIT: usize = 32 * 6;
-const INPUT_REPRESENT_BYTES: usize = MODEXP_INPUT_LIMIT / 256 + 1;
-const INPUT_REPRESENT_BITS: usize = 8;
+/// Number of input bytes following the 96-byte lengths header, which hold the operands.
+const REST_BYTES: usize = MODEXP_INPUT_LIMIT - 96;
 
 type Word<F> = [Cell<F>; 32];
 
@@ -164,6 +74,14 @@ fn expr_from_bytes<F: Field, E: Expr<F>>(bytes: &[E]) -> Expression<F> {
         .fold(0.expr(), |acc, byte| acc * F::from(256) + byte.expr())
 }
 
+// the leading (up to) 32 bytes of exp, right-aligned in a word
+fn exp_head(exp: &[u8]) -> [u8; N_BYTES_WORD] {
+    let head_len = exp.len().min(N_BYTES_WORD);
+    let mut head = [0u8; N_BYTES_WORD];
+    head[N_BYTES_WORD - head_len..].copy_from_slice(&exp[..head_len]);
+    head
+}
+
 #[derive(Clone, Debug)]
 struct SizeRepresent<F> {
     len_bytes: Word<F>,
@@ -246,29 +164,22 @@ impl<F: Field> SizeRepresent<F> {
     }
 }
 
-type RandPow<F> = RandPowRepresent<F, SIZE_REPRESENT_BITS>;
-
-// parse as (valid, len, value: [base, exp, modulus])
-type InputParsedResult = (bool, [U256; 3], [[u8; SIZE_LIMIT]; 3]);
-type OutputParsedResult = (usize, [u8; SIZE_LIMIT]);
-
 #[derive(Clone, Debug)]
 struct ModExpInputs<F> {
     base_len: SizeRepresent<F>,
     modulus_len: SizeRepresent<F>,
     exp_len: SizeRepresent<F>,
-    base_pow: RandPow<F>,
-    base: Word<F>,
-    modulus_pow: RandPow<F>,
-    modulus: Word<F>,
-    exp_pow: RandPow<F>,
-    exp: Word<F>,
     input_valid: Cell<F>,
+    /// The lengths of base, exp and modulus, zero if the input is invalid.
+    lens_expected: [Expression<F>; 3],
+    /// The leading (up to) 32 bytes of exp, right-aligned, which determine the gas cost.
+    exp_head: Word<F>,
+    /// RLC of the input following the lengths header, padded to `MODEXP_INPUT_LIMIT` bytes in
+    /// total, which is parsed into the operands by the modexp circuit.
+    rest_rlc: Cell<F>,
+    /// r ** REST_BYTES
+    rest_pow: Cell<F>,
     input_bytes_rlc: Expression<F>,
-    input_len_expected: Expression<F>,
-    pub base_limbs: Limbs<F>,
-    pub exp_limbs: Limbs<F>,
-    pub modulus_limbs: Limbs<F>,
 }
 
 impl<F: Field> ModExpInputs<F> {
@@ -277,18 +188,13 @@ impl<F: Field> ModExpInputs<F> {
         let modulus_len = SizeRepresent::configure(cb);
         let exp_len = SizeRepresent::configure(cb);
 
-        let r_pow_32 =
-            RandPowRepresent::<_, 6>::base_pows_expr(cb.challenges().keccak_input())[5].clone(); //r**32
+        let r_pow_32 = std::iter::successors(Some(cb.challenges().keccak_input()), |r| {
+            Some(r.clone().square())
+        })
+        .nth(5)
+        .expect("infinite iterator"); //r**32
         let r_pow_64 = r_pow_32.clone().square();
 
-        let base = cb.query_bytes();
-        let modulus = cb.query_bytes();
-        let exp = cb.query_bytes();
-
-        let base_limbs = Limbs::configure(cb, &base);
-        let exp_limbs = Limbs::configure(cb, &exp);
-        let modulus_limbs = Limbs::configure(cb, &modulus);
-
         let input_valid = cb.query_bool();
         cb.require_equal(
             "mark input valid by checking 3 lens is valid",
@@ -300,71 +206,32 @@ impl<F: Field> ModExpInputs<F> {
             ]),
         );
 
-        let base_len_expected = util::select::expr(input_valid.expr(), base_len.value(), 0.expr());
-
-        let exp_len_expected = util::select::expr(input_valid.expr(), exp_len.value(), 0.expr());
-
-        let modulus_len_expected =
-            util::select::expr(input_valid.expr(), modulus_len.value(), 0.expr());
-
-        let input_len_expected = 96.expr()
-            + base_len_expected.clone()
-            + exp_len_expected.clone()
-            + modulus_len_expected.clone();
-
-        // we put correct size in each input word if input is valid
-        // else we just put as most as possible bytes (32) into it
-        // so we finally handle the memory in limited sized (32*3)
-        let modulus_pow = RandPow::configure(
-            cb,
-            cb.challenges().keccak_input(),
-            modulus_len_expected,
-            None,
-        );
-
-        // exp_pow = r**(modulus_len + exp_len)
-        let exp_pow = RandPow::configure(
-            cb,
-            cb.challenges().keccak_input(),
-            exp_len_expected,
-            Some(modulus_pow.expr()),
-        );
-
-        // base_pow = r**(modulus_len + exp_len + base_len)
-        let base_pow = RandPow::configure(
-            cb,
-            cb.challenges().keccak_input(),
-            base_len_expected,
-            Some(exp_pow.expr()),
-        );
+        let lens_expected = [&base_len, &exp_len, &modulus_len]
+            .map(|len| util::select::expr(input_valid.expr(), len.value(), 0.expr()));
 
-        let input_bytes_rlc = rlc_word_rev(&modulus, cb.challenges().keccak_input()) //rlc of base
-            + modulus_pow.expr() * rlc_word_rev(&exp, cb.challenges().keccak_input()) //rlc of exp plus r**base_len
-            + exp_pow.expr() * rlc_word_rev(&base, cb.challenges().keccak_input()) //rlc of exp plus r**(base_len + exp_len)
-            + base_pow.expr() * modulus_len.memory_rlc()
-            + base_pow.expr() * r_pow_32 * exp_len.memory_rlc()
-            + base_pow.expr() * r_pow_64 * base_len.memory_rlc();
+        let exp_head = cb.query_bytes();
+        let rest_rlc = cb.query_cell_phase2();
+        let rest_pow = cb.query_cell_phase2();
+        cb.pow_of_rand_lookup(REST_BYTES.expr(), rest_pow.expr());
 
-        // println!("phase 2 cell used {}",
-        //     padding_pow.phase2_cell_cost() + [&modulus_pow, &exp_pow,
-        // &base_pow].iter().map(|pw|pw.phase2_cell_cost()).sum::<usize>() );
+        // the input is the 3 lengths followed by the rest, which holds the operands if the input
+        // is valid.
+        let input_bytes_rlc = (base_len.memory_rlc() * r_pow_64
+            + exp_len.memory_rlc() * r_pow_32
+            + modulus_len.memory_rlc())
+            * rest_pow.expr()
+            + rest_rlc.expr();
 
         Self {
             base_len,
             modulus_len,
             exp_len,
-            base_pow,
-            base,
-            modulus_pow,
-            modulus,
-            exp_pow,
-            exp,
             input_valid,
+            lens_expected,
+            exp_head,
+            rest_rlc,
+            rest_pow,
             input_bytes_rlc,
-            input_len_expected,
-            base_limbs,
-            exp_limbs,
-            modulus_limbs,
         }
     }
 
@@ -374,59 +241,51 @@ impl<F: Field> ModExpInputs<F> {
     pub fn is_valid(&self) -> Expression<F> {
         self.input_valid.expr()
     }
-    pub fn len_expected(&self) -> Expression<F> {
-        self.input_len_expected.clone()
+    pub fn lens_expected(&self) -> [Expression<F>; 3] {
+        self.lens_expected.clone()
     }
     pub fn bytes_rlc(&self) -> Expression<F> {
         self.input_bytes_rlc.clone()
     }
+    pub fn rest_rlc(&self) -> Expression<F> {
+        self.rest_rlc.expr()
+    }
 
     pub fn assign(
         &self,
         region: &mut CachedRegion<'_, '_, F>,
         offset: usize,
-        (input_valid, lens, values): InputParsedResult,
+        data: &ModExpAuxData,
     ) -> Result<(), Error> {
         self.input_valid.assign(
             region,
             offset,
-            Value::known(if input_valid { F::one() } else { F::zero() }),
+            Value::known(if data.valid { F::one() } else { F::zero() }),
         )?;
 
         for (len, len_represent) in
-            lens.iter()
+            data.input_lens
+                .iter()
                 .zip([&self.base_len, &self.exp_len, &self.modulus_len])
         {
             len_represent.assign(region, offset, len)?;
         }
 
-        let mut linked_v = None;
-        for (len, pow) in lens
-            .iter()
-            .zip([&self.base_pow, &self.exp_pow, &self.modulus_pow])
-            .rev()
-        {
-            let assigned = pow.assign(
-                region,
-                offset,
-                if input_valid { len.as_usize() } else { 0 },
-                linked_v,
-            )?;
-
-            linked_v = Some(assigned);
-        }
-
-        for (val_r, input_limbs) in
-            values
-                .iter()
-                .zip([&self.base_limbs, &self.exp_limbs, &self.modulus_limbs])
-        {
-            input_limbs.assign(region, offset, val_r)?;
-        }
+        assign_word(region, offset, &self.exp_head, exp_head(&data.inputs[1]))?;
 
-        for (&val, input_bytes) in values.iter().zip([&self.base, &self.exp, &self.modulus]) {
-            assign_word(region, offset, input_bytes, val)?;
-        }
+        let mut input_bytes = data.input_bytes.clone();
+        input_bytes.resize(MODEXP_INPUT_LIMIT, 0);
+        let keccak_input = region.challenges().keccak_input();
+        self.rest_rlc.assign(
+            region,
+            offset,
+            keccak_input.map(|r| rlc::value(input_bytes[96..].iter().rev(), r)),
+        )?;
+        self.rest_pow.assign(
+            region,
+            offset,
+            keccak_input.map(|r| r.pow([REST_BYTES as u64, 0, 0, 0])),
+        )?;
 
         Ok(())
     }
@@ -434,10 +293,9 @@ impl<F: Field> ModExpInputs<F> {
 
 #[derive(Clone, Debug)]
 struct ModExpOutputs<F> {
-    result: Word<F>,
+    result_rlc: Cell<F>,
     is_result_zero: IsZeroGadget<F>,
     output_bytes_rlc: Expression<F>,
-    pub result_limbs: Limbs<F>,
 }
 
 impl<F: Field> ModExpOutputs<F> {
@@ -451,20 +309,15 @@ impl<F: Field> ModExpOutputs<F> {
             IsZeroGadget::construct(cb, output_len)
         });
 
-        let result = cb.query_bytes();
-        let result_limbs = Limbs::configure(cb, &result);
+        let result_rlc = cb.query_cell_phase2();
 
-        let output_bytes_rlc = util::select::expr(
-            is_result_zero.expr(),
-            0.expr(),
-            rlc_word_rev(&result, cb.challenges().keccak_input()),
-        );
+        let output_bytes_rlc =
+            util::select::expr(is_result_zero.expr(), 0.expr(), result_rlc.expr());
 
         Self {
-            result,
+            result_rlc,
             is_result_zero,
             output_bytes_rlc,
-            result_limbs,
         }
     }
 
@@ -472,6 +325,10 @@ impl<F: Field> ModExpOutputs<F> {
         self.is_result_zero.expr()
     }
 
+    pub fn result_rlc(&self) -> Expression<F> {
+        self.result_rlc.expr()
+    }
+
     pub fn bytes_rlc(&self) -> Expression<F> {
         self.output_bytes_rlc.clone()
     }
@@ -480,87 +337,27 @@ impl<F: Field> ModExpOutputs<F> {
         &self,
         region: &mut CachedRegion<'_, '_, F>,
         offset: usize,
-        (output_len, data): OutputParsedResult,
+        output_len: usize,
+        result_rlc: Value<F>,
     ) -> Result<(), Error> {
         self.is_result_zero
             .assign(region, offset, F::from(output_len as u64))?;
-        self.result_limbs.assign(region, offset, &data)?;
-        assign_word(region, offset, &self.result, data)?;
-        Ok(())
-    }
-}
-
-#[derive(Clone, Debug)]
-pub(crate) struct Limbs<F> {
-    byte14_split_lo: Cell<F>,
-    byte14_split_hi: Cell<F>,
-    limbs: [Expression<F>; 3],
-}
-
-impl<F: Field> Limbs<F> {
-    pub fn configure(cb: &mut EVMConstraintBuilder<F>, word: &Word<F>) -> Self {
-        let byte14_split_lo = cb.query_byte();
-        let byte14_split_hi = cb.query_byte();
-
-        cb.require_equal(
-            "split 14th byte in word into half",
-            word[MODEXP_SIZE_LIMIT - 14].expr(),
-            byte14_split_lo.expr() + byte14_split_hi.expr(),
-        );
-
-        let inv_16 = Expression::Constant(F::from(16u64).invert().unwrap());
-
-        let limbs = [
-            expr_from_bytes(
-                &std::iter::once(&byte14_split_lo)
-                    .chain(&word[MODEXP_SIZE_LIMIT - 13..])
-                    .collect::<Vec<_>>(),
-            ),
-            expr_from_bytes(
-                &word[MODEXP_SIZE_LIMIT - 27..MODEXP_SIZE_LIMIT - 14]
-                    .iter()
-                    .chain(std::iter::once(&byte14_split_hi))
-                    .collect::<Vec<_>>(),
-            ) * inv_16,
-            expr_from_bytes(&word[..MODEXP_SIZE_LIMIT - 27]),
-        ];
-
-        Self {
-            byte14_split_lo,
-            byte14_split_hi,
-            limbs,
-        }
-    }
-
-    pub fn limbs(&self) -> [Expression<F>; 3] {
-        self.limbs.clone()
-    }
-
-    pub fn assign(
-        &self,
-        region: &mut CachedRegion<'_, '_, F>,
-        offset: usize,
-        big_int: &[u8; MODEXP_SIZE_LIMIT],
-    ) -> Result<(), Error> {
-        let byte14_lo = big_int[MODEXP_SIZE_LIMIT - 14] & 0xf;
-        let byte14_hi = big_int[MODEXP_SIZE_LIMIT - 14] & 0xf0;
-
-        self.byte14_split_lo
-            .assign(region, offset, Value::known(F::from(byte14_lo as u64)))?;
-        self.byte14_split_hi
-            .assign(region, offset, Value::known(F::from(byte14_hi as u64)))?;
+        self.result_rlc.assign(region, offset, result_rlc)?;
         Ok(())
     }
 }
 
 #[derive(Clone, Debug)]
 pub(crate) struct ModExpGasCost<F> {
-    max_length: MinMaxGadget<F, 1>,
+    max_length: MinMaxGadget<F, 2>,
     words: ConstantDivisionGadget<F, 1>,
-    exp_is_zero: IsZeroGadget<F>,
+    exp_is_long: LtGadget<F, 2>,
+    exp_head_is_zero: IsZeroGadget<F>,
     exp_byte_size: ByteSizeGadget<F>,
     exp_msb_bit_length: BitLengthGadget<F>,
     exp_msb: BinaryNumberGadget<F, N_BITS_U8>,
+    iteration_count: MinMaxGadget<F, 2>,
+    iteration_count_cached: Cell<F>,
     calc_gas: ConstantDivisionGadget<F, N_BYTES_U64>,
     dynamic_gas: MinMaxGadget<F, N_BYTES_U64>,
 }
@@ -568,23 +365,27 @@ pub(crate) struct ModExpGasCost<F> {
 impl<F: Field> ModExpGasCost<F> {
     fn construct(
         cb: &mut EVMConstraintBuilder<F>,
-        b_size: &SizeRepresent<F>,
-        exp: &[Cell<F>; MODEXP_SIZE_LIMIT],
-        m_size: &SizeRepresent<F>,
+        [b_size, e_size, m_size]: [Expression<F>; 3],
+        exp_head: &Word<F>,
     ) -> Self {
-        let max_length = MinMaxGadget::construct(cb, b_size.value(), m_size.value());
+        let max_length = MinMaxGadget::construct(cb, b_size, m_size);
         let words = ConstantDivisionGadget::construct(cb, max_length.max() + 7.expr(), 8);
         let multiplication_complexity = words.quotient() * words.quotient();
-        let exp_is_zero = IsZeroGadget::construct(
+        let exp_is_long = LtGadget::construct(cb, N_BYTES_WORD.expr(), e_size.expr());
+        let exp_head_is_zero = IsZeroGadget::construct(
             cb,
-            rlc::expr(&exp.clone().map(|c| c.expr()), cb.challenges().evm_word()),
+            rlc::expr(
+                &exp_head.clone().map(|c| c.expr()),
+                cb.challenges().evm_word(),
+            ),
         );
 
         let (exp_byte_size, exp_msb, exp_msb_bit_length) =
-            cb.condition(not::expr(exp_is_zero.expr()), |cb| {
+            cb.condition(not::expr(exp_head_is_zero.expr()), |cb| {
                 let exp_byte_size = ByteSizeGadget::construct(
                     cb,
-                    exp.iter()
+                    exp_head
+                        .iter()
                         .rev()
                         .map(Expr::expr)
                         .collect::<Vec<Expression<F>>>()
@@ -606,18 +407,36 @@ impl<F: Field> ModExpGasCost<F> {
                 );
                 (exp_byte_size, exp_msb, exp_msb_bit_length)
             });
-        let exp_bit_length =
+        let exp_head_bit_length =
             (exp_byte_size.size() - 1.expr()) * N_BITS_U8.expr() + exp_msb_bit_length.size();
 
-        // We already restrict Esize <= 32. So we can completely ignore the branch concerning
-        // Esize > 32. We only care about whether or not exponent is zero.
-        let iteration_count = select::expr(
-            exp_is_zero.expr(),
-            0.expr(),
-            exp_bit_length.expr() - 1.expr(),
+        // EIP-2565: the iteration count is the bit length of exp minus one for Esize <= 32, and
+        // 8 * (Esize - 32) plus the bit length of its leading 32 bytes minus one otherwise, with
+        // a minimum of 1.
+        let iteration_count = MinMaxGadget::construct(
+            cb,
+            1.expr(),
+            select::expr(
+                exp_is_long.expr(),
+                (e_size - N_BYTES_WORD.expr()) * N_BITS_U8.expr(),
+                0.expr(),
+            ) + select::expr(
+                exp_head_is_zero.expr(),
+                0.expr(),
+                exp_head_bit_length - 1.expr(),
+            ),
+        );
+        let iteration_count_cached = cb.query_cell();
+        cb.require_equal(
+            "cache iteration count for degree",
+            iteration_count_cached.expr(),
+            iteration_count.max(),
+        );
+        let calc_gas = ConstantDivisionGadget::construct(
+            cb,
+            multiplication_complexity * iteration_count_cached.expr(),
+            3,
         );
-        let calc_gas =
-            ConstantDivisionGadget::construct(cb, multiplication_complexity * iteration_count, 3);
         let dynamic_gas = MinMaxGadget::construct(
             cb,
             GasCost::PRECOMPILE_MODEXP_MIN.expr(),
@@ -627,10 +446,13 @@ impl<F: Field> ModExpGasCost<F> {
         Self {
             max_length,
             words,
-            exp_is_zero,
+            exp_is_long,
+            exp_head_is_zero,
             exp_byte_size,
             exp_msb_bit_length,
             exp_msb,
+            iteration_count,
+            iteration_count_cached,
             calc_gas,
             dynamic_gas,
         }
@@ -640,65 +462,66 @@ impl<F: Field> ModExpGasCost<F> {
         &self,
         region: &mut CachedRegion<'_, '_, F>,
         offset: usize,
-        b_size: &U256,
-        m_size: &U256,
-        exponent: &[u8; MODEXP_SIZE_LIMIT],
+        [b_size, e_size, m_size]: [usize; 3],
+        exp_head: &[u8; N_BYTES_WORD],
     ) -> Result<u64, Error> {
-        let mut base_len = [0u8; 32];
-        base_len[(32 - SIZE_REPRESENT_BYTES)..]
-            .copy_from_slice(&b_size.to_be_bytes()[(32 - SIZE_REPRESENT_BYTES)..]);
-        let mut mod_len = [0u8; 32];
-        mod_len[(32 - SIZE_REPRESENT_BYTES)..]
-            .copy_from_slice(&m_size.to_be_bytes()[(32 - SIZE_REPRESENT_BYTES)..]);
-        let b_size = U256::from_big_endian(&base_len);
-        let m_size = U256::from_big_endian(&mod_len);
-
         self.max_length.assign(
             region,
             offset,
-            b_size.to_scalar().expect("Bsize is within scalar field"),
-            m_size.to_scalar().expect("Msize is within scalar field"),
+            F::from(b_size as u64),
+            F::from(m_size as u64),
         )?;
-        self.words
-            .assign(region, offset, b_size.max(m_size).as_u128() + 7u128)?;
-        let exp_word = U256::from_big_endian(exponent);
-        self.exp_is_zero.assign_value(
+        let max_length = b_size.max(m_size) as u64;
+        self.words.assign(region, offset, max_length as u128 + 7)?;
+        self.exp_is_long.assign(
+            region,
+            offset,
+            F::from(N_BYTES_WORD as u64),
+            F::from(e_size as u64),
+        )?;
+        let exp_word = U256::from_big_endian(exp_head);
+        self.exp_head_is_zero.assign_value(
             region,
             offset,
             region
                 .challenges()
                 .evm_word()
-                .map(|r| rlc::value(exponent, r)),
+                .map(|r| rlc::value(exp_head, r)),
         )?;
         self.exp_byte_size
             .assign(region, offset, ByteOrWord::Word(exp_word))?;
         let exp_byte_size = (exp_word.bits() + 7) / 8;
         let exp_msb = if exp_byte_size > 0 {
-            exponent[N_BYTES_WORD - exp_byte_size]
+            exp_head[N_BYTES_WORD - exp_byte_size]
         } else {
             0
         };
         self.exp_msb.assign(region, offset, exp_msb)?;
         self.exp_msb_bit_length
             .assign(region, offset, ByteOrWord::Byte(exp_msb))?;
-        let exp_bit_length = exp_word.bits();
-        let max_length = b_size.max(m_size);
+
         let words = (max_length + 7) / 8;
         let multiplication_complexity = words * words;
-        let iteration_count = if exp_word.is_zero() {
-            0
-        } else {
-            exp_bit_length - 1
-        };
+        let iteration_count = (8 * e_size.saturating_sub(N_BYTES_WORD) + exp_word.bits())
+            .saturating_sub(if exp_word.is_zero() { 0 } else { 1 })
+            as u64;
+        self.iteration_count
+            .assign(region, offset, F::one(), F::from(iteration_count))?;
+        let iteration_count = iteration_count.max(1);
+        self.iteration_count_cached.assign(
+            region,
+            offset,
+            Value::known(F::from(iteration_count)),
+        )?;
         let numerator = multiplication_complexity * iteration_count;
-        self.calc_gas.assign(region, offset, numerator.as_u128())?;
+        self.calc_gas.assign(region, offset, numerator as u128)?;
         self.dynamic_gas.assign(
             region,
             offset,
             F::from(GasCost::PRECOMPILE_MODEXP_MIN.0),
-            F::from((numerator / 3).as_u64()),
+            F::from(numerator / 3),
         )?;
-        let gas_cost = std::cmp::max(GasCost::PRECOMPILE_MODEXP_MIN.0, (numerator / 3).as_u64());
+        let gas_cost = std::cmp::max(GasCost::PRECOMPILE_MODEXP_MIN.0, numerator / 3);
 
         Ok(gas_cost)
     }
@@ -723,13 +546,11 @@ pub struct ModExpGadget<F> {
     restore_context_gadget: RestoreContextGadget<F>,
 
     input: ModExpInputs<F>,
-    padding_zero: RandPowRepresent<F, INPUT_REPRESENT_BITS>,
     output: ModExpOutputs<F>,
 
     input_bytes_acc: Cell<F>,
     is_gas_insufficient: LtGadget<F, N_BYTES_U64>,
     gas_cost_gadget: ModExpGasCost<F>,
-    garbage_bytes_holder: [Cell<F>; INPUT_LIMIT - 96],
 }
 
 impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
@@ -765,15 +586,8 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
         );
 
         let input = ModExpInputs::configure(cb);
-        let padding_zero = RandPowRepresent::configure(
-            cb,
-            cb.challenges().keccak_input(),
-            MODEXP_INPUT_LIMIT.expr() - input.len_expected(),
-            None,
-        );
 
-        let gas_cost_gadget =
-            ModExpGasCost::construct(cb, &input.base_len, &input.exp, &input.modulus_len);
+        let gas_cost_gadget = ModExpGasCost::construct(cb, input.lens_expected(), &input.exp_head);
         let is_gas_insufficient = LtGadget::construct(
             cb,
             cb.curr.state.gas_left.expr(),
@@ -789,30 +603,22 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
             call_success.clone(),
         );
 
-        let output = ModExpOutputs::configure(
-            cb,
-            //FIXME: there may be still some edge cases lead to nil output (even modulus_len is
-            // not 0)
-            call_success,
-            input.modulus_len(),
-        );
-
-        cb.condition(util::not::expr(output.is_nil()), |cb| {
-            cb.modexp_table_lookup(
-                input.base_limbs.limbs(),
-                input.exp_limbs.limbs(),
-                input.modulus_limbs.limbs(),
-                output.result_limbs.limbs(),
-            );
-        });
+        let output = ModExpOutputs::configure(cb, call_success, input.modulus_len());
 
-        let garbage_bytes_holder = cb.query_bytes();
+        // the call is looked up whether it succeeds or not, as the modexp circuit parses the
+        // operands and computes the result only for successful calls.
+        cb.modexp_table_lookup(
+            input.rest_rlc(),
+            input.lens_expected(),
+            rlc_word_rev(&input.exp_head, cb.challenges().keccak_input()),
+            is_success.expr(),
+            output.result_rlc(),
+        );
 
         cb.require_equal(
             "input acc bytes with padding must equal",
             input_bytes_acc.expr(),
-            padding_zero.expr() * input.bytes_rlc()
-                + rlc_rev(&garbage_bytes_holder, cb.challenges().keccak_input()),
+            input.bytes_rlc(),
         );
 
         cb.require_equal(
@@ -875,12 +681,10 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
             return_data_length,
             restore_context_gadget,
             input,
-            padding_zero,
             output,
             input_bytes_acc,
             is_gas_insufficient,
             gas_cost_gadget,
-            garbage_bytes_holder,
         }
     }
 
@@ -894,34 +698,7 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
         step: &ExecStep,
     ) -> Result<(), Error> {
         if let Some(PrecompileAuxData::Modexp(data)) = &step.aux_data {
-            self.input
-                .assign(region, offset, (data.valid, data.input_lens, data.inputs))?;
-
-            let input_expected_len = 96
-                + if data.valid {
-                    data.input_lens.iter().map(U256::as_usize).sum::<usize>()
-                } else {
-                    0
-                };
-
-            let garbage_bytes = if call.call_data_length as usize > input_expected_len {
-                let mut bts = vec![0; input_expected_len - 96]; //front prefix zero
-                bts.append(&mut Vec::from(&data.input_bytes[input_expected_len..]));
-                bts.resize(96, 0); //padding zero
-                bts
-            } else {
-                Vec::from([0u8; 96])
-            };
-
-            self.padding_zero
-                .assign(region, offset, INPUT_LIMIT - input_expected_len, None)?;
-
-            for (cell, bt) in self.garbage_bytes_holder.iter().zip(garbage_bytes) {
-                cell.assign(region, offset, Value::known(F::from(bt as u64)))?;
-            }
-
-            self.output
-                .assign(region, offset, (data.output_len, data.output))?;
+            self.input.assign(region, offset, data)?;
 
             let input_rlc = region
                 .challenges()
@@ -938,9 +715,9 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
                     .map(|r| rlc::value(data.return_bytes.iter().rev(), r)),
             )?;
 
-            // if the input to modexp has more than 192 bytes, then we only keep the first 192 bytes
-            // and discard the remaining bytes
-            let input_len_limit = INPUT_LIMIT as u64;
+            // if the input to modexp has more than MODEXP_INPUT_LIMIT bytes, then we only keep the
+            // first MODEXP_INPUT_LIMIT bytes and discard the remaining bytes
+            let input_len_limit = MODEXP_INPUT_LIMIT as u64;
             let n_padded_zeros = if call.call_data_length > input_len_limit {
                 0
             } else {
@@ -959,22 +736,37 @@ impl<F: Field> ExecutionGadget<F> for ModExpGadget<F> {
             self.input_bytes_acc
                 .assign(region, offset, n_padded_zeroes_pow * input_rlc)?;
             self.output_bytes_rlc.assign(region, offset, output_rlc)?;
-
-            let required_gas_cost = self.gas_cost_gadget.assign(
+            self.output.assign(
                 region,
                 offset,
-                &data.input_lens[0],
-                &data.input_lens[2],
-                &data.inputs[1],
+                data.output_len,
+                if call.is_success {
+                    output_rlc
+                } else {
+                    Value::known(F::zero())
+                },
             )?;
+
+            let lens = if data.valid {
+                data.input_lens.map(|len| len.as_usize())
+            } else {
+                [0; 3]
+            };
+            let required_gas_cost =
+                self.gas_cost_gadget
+                    .assign(region, offset, lens, &exp_head(&data.inputs[1]))?;
             self.is_gas_insufficient.assign(
                 region,
                 offset,
                 F::from(step.gas_left),
                 F::from(required_gas_cost),
             )?;
-            self.pad_right
-                .assign(region, offset, call.call_data_length.into(), 192.into())?;
+            self.pad_right.assign(
+                region,
+                offset,
+                call.call_data_length.into(),
+                (MODEXP_INPUT_LIMIT as u64).into(),
+            )?;
             self.padding.assign(
                 region,
                 offset,
@@ -1038,71 +830,12 @@ mod test {
         precompile::PrecompileCalls,
     };
     use eth_types::{bytecode, word, ToWord};
-    use ethers_core::k256::elliptic_curve::PrimeField;
     use itertools::Itertools;
     use mock::TestContext;
     use std::sync::LazyLock;
 
     use crate::test_util::CircuitTestBuilder;
 
-    #[test]
-    fn test_limbs() {
-        use crate::table::ModExpTable;
-        use halo2_proofs::halo2curves::bn256::Fr;
-        use misc_precompiled_circuit::circuits::modexp::Number;
-        use num_bigint::BigUint;
-
-        // simply take an hash for test
-        let bi = BigUint::parse_bytes(
-            b"fcb51a0695d8f838b1ee009b3fbf66bda078cd64590202a864a8f3e8c4315c47",
-            16,
-        )
-        .unwrap();
-        let n = Number::<Fr>::from_bn(&bi);
-        let w = word!("0xfcb51a0695d8f838b1ee009b3fbf66bda078cd64590202a864a8f3e8c4315c47");
-        let mut bytes = [0u8; 32];
-        w.to_big_endian(&mut bytes);
-        assert_eq!(BigUint::from_bytes_be(&bytes), bi);
-
-        let byte14_lo = bytes[MODEXP_SIZE_LIMIT - 14] & 0xf;
-        let byte14_hi = bytes[MODEXP_SIZE_LIMIT - 14] & 0xf0;
-
-        let limb0: Fr = U256::from_big_endian(
-            &(std::iter::once(byte14_lo))
-                .chain(bytes[MODEXP_SIZE_LIMIT - 13..].iter().copied())
-                .collect::<Vec<_>>(),
-        )
-        .to_scalar()
-        .unwrap();
-
-        let limb1: Fr = U256::from_big_endian(
-            &bytes[MODEXP_SIZE_LIMIT - 27..MODEXP_SIZE_LIMIT - 14]
-                .iter()
-                .copied()
-                .chain(std::iter::once(byte14_hi))
-                .collect::<Vec<_>>(),
-        )
-        .to_scalar()
-        .unwrap();
-
-        let limb2: Fr = U256::from_big_endian(&bytes[..MODEXP_SIZE_LIMIT - 27])
-            .to_scalar()
-            .unwrap();
-
-        assert_eq!(limb0, n.limbs[0].value);
-        assert_eq!(limb1, n.limbs[1].value * Fr::from(16_u64));
-        assert_eq!(limb2, n.limbs[2].value);
-
-        let nt: Fr = ModExpTable::native_u256(&w);
-
-        let table_split = ModExpTable::split_u256_108bit_limbs(&w);
-        assert_eq!(Fr::from_u128(table_split[0]), n.limbs[0].value);
-        assert_eq!(Fr::from_u128(table_split[1]), n.limbs[1].value);
-        assert_eq!(Fr::from_u128(table_split[2]), n.limbs[2].value);
-        assert_eq!(nt, n.limbs[3].value);
-        //Limb::new(None, value)
-    }
-
     static TEST_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
         vec![
             PrecompileCallArgs {
@@ -1470,10 +1203,10 @@ mod test {
         ]
     });
 
-    static TEST_INVALID_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+    static TEST_BIG_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
         vec![
             PrecompileCallArgs {
-                name: "modexp Msize length too large invalid",
+                name: "modexp Msize length over 32 bytes",
                 setup_code: bytecode! {
                     // Base size
                     PUSH1(0x1)
@@ -1501,7 +1234,7 @@ mod test {
                 ..Default::default()
             },
             PrecompileCallArgs {
-                name: "modexp Base&Esize&Msize length too large invalid",
+                name: "modexp Base&Esize&Msize length over 32 bytes",
                 setup_code: bytecode! {
                     // Base size
                     PUSH1(0x21)
@@ -1537,6 +1270,72 @@ mod test {
                 gas: 1000.into(),
                 ..Default::default()
             },
+            PrecompileCallArgs {
+                name: "modexp operands at the size limit",
+                setup_code: bytecode! {
+                    // Base size
+                    PUSH2(0x0200)
+                    PUSH1(0x00)
+                    MSTORE
+                    // Esize
+                    PUSH1(0x1)
+                    PUSH1(0x20)
+                    MSTORE
+                    // Msize
+                    PUSH2(0x0200)
+                    PUSH1(0x40)
+                    MSTORE
+                    // B, E and M
+                    PUSH32(word!("0xfcb51a0695d8f838b1ee009b3fbf66bda078cd64590202a864a8f3e8c4315c47"))
+                    PUSH1(0x60)
+                    MSTORE
+                    PUSH32(word!("0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"))
+                    PUSH2(0x0260)
+                    MSTORE
+                    PUSH32(word!("0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"))
+                    PUSH2(0x0441)
+                    MSTORE
+                },
+                call_data_offset: 0x0.into(),
+                call_data_length: 0x0461.into(),
+                ret_offset: 0x0480.into(),
+                ret_size: 0x0200.into(),
+                address: PrecompileCalls::Modexp.address().to_word(),
+                ..Default::default()
+            },
+        ]
+    });
+
+    static TEST_INVALID_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+        vec![
+            PrecompileCallArgs {
+                name: "modexp Msize length too large invalid",
+                setup_code: bytecode! {
+                    // Base size
+                    PUSH1(0x1)
+                    PUSH1(0x00)
+                    MSTORE
+                    // Esize
+                    PUSH1(0x1)
+                    PUSH1(0x20)
+                    MSTORE
+                    // Msize
+                    PUSH2(0x0201)
+                    PUSH1(0x40)
+                    MSTORE
+                    // B, E and M
+                    PUSH32(word!("0x08090A0000000000000000000000000000000000000000000000000000000000"))
+                    PUSH1(0x60)
+                    MSTORE
+                },
+                call_data_offset: 0x0.into(),
+                call_data_length: 0x63.into(),
+                ret_offset: 0x9f.into(),
+                ret_size: 0x01.into(),
+                address: PrecompileCalls::Modexp.address().to_word(),
+                gas: 100000.into(),
+                ..Default::default()
+            },
             PrecompileCallArgs {
                 name: "from test tool: zero padding and invalid size",
                 setup_code: bytecode! {
@@ -1609,6 +1408,18 @@ mod test {
         }
     }
 
+    #[test]
+    fn precompile_modexp_test_big() {
+        for test_vector in TEST_BIG_VECTOR.iter() {
+            let bytecode = test_vector.with_call_op(OpcodeId::STATICCALL);
+
+            CircuitTestBuilder::new_from_test_ctx(
+                TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+            )
+            .run();
+        }
+    }
+
     // notice, "invalid" test would not actually work until bus-mapping put calling fail case being
     // handle in normal CallOp, i.e. return None in
     // bus_mapping::circuit_input_builder::input_state_ref::CircuitInputStateRef::get_step_err
@@ -0,0 +1,671 @@
+use bus_mapping::precompile::{PrecompileAuxData, P256_VERIFY_INPUT_LEN};
+use eth_types::{evm_types::GasCost, word, ToBigEndian, ToLittleEndian, U256};
+use ethers_core::utils::keccak256;
+use gadgets::util::{and, not, select, sum, Expr};
+use gadgets::ToScalar;
+use halo2_proofs::{
+    circuit::Value,
+    plonk::{Error, Expression},
+};
+use std::sync::LazyLock;
+
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        param::N_BYTES_WORD,
+        step::ExecutionState,
+        util::{
+            common_gadget::RestoreContextGadget,
+            constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
+            from_bytes,
+            math_gadget::{IsEqualGadget, IsZeroGadget, LtWordGadget, ModGadget},
+            rlc, CachedRegion, Cell, Word,
+        },
+    },
+    table::{CallContextFieldTag, SIG_V_SECP256R1},
+    util::Field,
+    witness::{Block, Call, ExecStep, Transaction},
+};
+
+/// Order of the secp256r1 group.
+static SECP256R1_N: LazyLock<U256> =
+    LazyLock::new(|| word!("0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"));
+/// Modulus of the secp256r1 base field.
+static SECP256R1_P: LazyLock<U256> =
+    LazyLock::new(|| word!("0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff"));
+
+#[derive(Clone, Debug)]
+pub struct P256VerifyGadget<F> {
+    input_bytes_rlc: Cell<F>,
+    output_bytes_rlc: Cell<F>,
+    return_bytes_rlc: Cell<F>,
+
+    is_valid: Cell<F>,
+    is_valid_length: IsEqualGadget<F>,
+    msg_hash_keccak_rlc: Cell<F>,
+    sig_r_keccak_rlc: Cell<F>,
+    sig_s_keccak_rlc: Cell<F>,
+    pk_x_keccak_rlc: Cell<F>,
+    pk_y_keccak_rlc: Cell<F>,
+    pk_hash: Word<F>,
+
+    msg_hash_raw: Word<F>,
+    msg_hash: Word<F>,
+    n_modulus: Word<F>,
+    msg_hash_mod: ModGadget<F, true>,
+
+    sig_r: Word<F>,
+    sig_r_canonical: LtWordGadget<F>,
+    sig_r_is_zero: IsZeroGadget<F>,
+    sig_s: Word<F>,
+    sig_s_canonical: LtWordGadget<F>,
+    sig_s_is_zero: IsZeroGadget<F>,
+
+    p_modulus: Word<F>,
+    pk_x: Word<F>,
+    pk_x_canonical: LtWordGadget<F>,
+    pk_y: Word<F>,
+    pk_y_canonical: LtWordGadget<F>,
+    pk_is_zero: IsZeroGadget<F>,
+
+    is_success: Cell<F>,
+    callee_address: Cell<F>,
+    is_root: Cell<F>,
+    call_data_offset: Cell<F>,
+    call_data_length: Cell<F>,
+    return_data_offset: Cell<F>,
+    return_data_length: Cell<F>,
+    restore_context: RestoreContextGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for P256VerifyGadget<F> {
+    const EXECUTION_STATE: ExecutionState = ExecutionState::PrecompileP256Verify;
+
+    const NAME: &'static str = "P256VERIFY";
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let (input_bytes_rlc, output_bytes_rlc, return_bytes_rlc) = (
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+        );
+        let (
+            is_valid,
+            msg_hash_keccak_rlc,
+            sig_r_keccak_rlc,
+            sig_s_keccak_rlc,
+            pk_x_keccak_rlc,
+            pk_y_keccak_rlc,
+        ) = (
+            cb.query_bool(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+            cb.query_cell_phase2(),
+        );
+        let pk_hash = cb.query_word_rlc();
+
+        let msg_hash_raw = cb.query_word_rlc();
+        let msg_hash = cb.query_word_rlc();
+        let n_modulus = cb.query_word_rlc();
+        let msg_hash_mod = ModGadget::construct(cb, [&msg_hash_raw, &n_modulus, &msg_hash]);
+
+        // 0 < r, s < n
+        let sig_r = cb.query_word_rlc();
+        let sig_r_canonical = LtWordGadget::construct(cb, &sig_r, &n_modulus);
+        let sig_r_is_zero = IsZeroGadget::construct(cb, sum::expr(&sig_r.cells));
+        let sig_s = cb.query_word_rlc();
+        let sig_s_canonical = LtWordGadget::construct(cb, &sig_s, &n_modulus);
+        let sig_s_is_zero = IsZeroGadget::construct(cb, sum::expr(&sig_s.cells));
+
+        // x, y < p and (x, y) != (0, 0). Whether the public key is on the curve is left to the
+        // sig circuit, which reports such a signature as invalid.
+        let p_modulus = cb.query_word_rlc();
+        let pk_x = cb.query_word_rlc();
+        let pk_x_canonical = LtWordGadget::construct(cb, &pk_x, &p_modulus);
+        let pk_y = cb.query_word_rlc();
+        let pk_y_canonical = LtWordGadget::construct(cb, &pk_y, &p_modulus);
+        let pk_is_zero =
+            IsZeroGadget::construct(cb, sum::expr(pk_x.cells.iter().chain(pk_y.cells.iter())));
+
+        for (word_keccak_rlc, word, name) in [
+            (&msg_hash_keccak_rlc, &msg_hash_raw, "msg hash"),
+            (&sig_r_keccak_rlc, &sig_r, "sig_r"),
+            (&sig_s_keccak_rlc, &sig_s, "sig_s"),
+            (&pk_x_keccak_rlc, &pk_x, "pk_x"),
+            (&pk_y_keccak_rlc, &pk_y, "pk_y"),
+        ] {
+            cb.require_equal(
+                "word cells assigned incorrectly",
+                word_keccak_rlc.expr(),
+                cb.keccak_rlc::<N_BYTES_WORD>(
+                    word.cells
+                        .iter()
+                        .map(Expr::expr)
+                        .collect::<Vec<Expression<F>>>()
+                        .try_into()
+                        .unwrap_or_else(|_| panic!("{name} is 32 bytes")),
+                ),
+            );
+        }
+        cb.require_equal(
+            "Secp256r1::Fq modulus assigned correctly",
+            n_modulus.expr(),
+            cb.word_rlc::<N_BYTES_WORD>(SECP256R1_N.to_le_bytes().map(|b| b.expr())),
+        );
+        cb.require_equal(
+            "Secp256r1::Fp modulus assigned correctly",
+            p_modulus.expr(),
+            cb.word_rlc::<N_BYTES_WORD>(SECP256R1_P.to_le_bytes().map(|b| b.expr())),
+        );
+
+        let [is_success, callee_address, is_root, call_data_offset, call_data_length, return_data_offset, return_data_length] =
+            [
+                CallContextFieldTag::IsSuccess,
+                CallContextFieldTag::CalleeAddress,
+                CallContextFieldTag::IsRoot,
+                CallContextFieldTag::CallDataOffset,
+                CallContextFieldTag::CallDataLength,
+                CallContextFieldTag::ReturnDataOffset,
+                CallContextFieldTag::ReturnDataLength,
+            ]
+            .map(|tag| cb.call_context(None, tag));
+
+        // p256verify only fails when it runs out of gas, which is handled by
+        // ErrorOutOfGasPrecompile.
+        cb.require_equal("p256verify call is successful", is_success.expr(), 1.expr());
+
+        cb.precompile_info_lookup(
+            cb.execution_state().as_u64().expr(),
+            callee_address.expr(),
+            cb.execution_state().precompile_base_gas_cost().expr(),
+        );
+
+        // the input is not padded, any other length than 160 bytes is invalid.
+        let is_valid_length =
+            IsEqualGadget::construct(cb, call_data_length.expr(), P256_VERIFY_INPUT_LEN.expr());
+        let (r_pow_32, r_pow_64, r_pow_96, r_pow_128) = {
+            let challenges = cb.challenges().keccak_powers_of_randomness::<16>();
+            let r_pow_16 = challenges[15].clone();
+            let r_pow_32 = r_pow_16.square();
+            let r_pow_64 = r_pow_32.expr().square();
+            let r_pow_96 = r_pow_64.expr() * r_pow_32.expr();
+            let r_pow_128 = r_pow_64.expr().square();
+            (r_pow_32, r_pow_64, r_pow_96, r_pow_128)
+        };
+        cb.condition(is_valid_length.expr(), |cb| {
+            cb.require_equal(
+                "input bytes (RLC) = [msg_hash | sig_r | sig_s | pk_x | pk_y]",
+                input_bytes_rlc.expr(),
+                (msg_hash_keccak_rlc.expr() * r_pow_128)
+                    + (sig_r_keccak_rlc.expr() * r_pow_96)
+                    + (sig_s_keccak_rlc.expr() * r_pow_64.expr())
+                    + (pk_x_keccak_rlc.expr() * r_pow_32.expr())
+                    + pk_y_keccak_rlc.expr(),
+            );
+        });
+
+        let is_well_formed = and::expr([
+            is_valid_length.expr(),
+            sig_r_canonical.expr(),
+            sig_s_canonical.expr(),
+            not::expr(sig_r_is_zero.expr()),
+            not::expr(sig_s_is_zero.expr()),
+            pk_x_canonical.expr(),
+            pk_y_canonical.expr(),
+            not::expr(pk_is_zero.expr()),
+        ]);
+
+        // the sig table does not hold the public key, it is bound to the row through its address
+        // instead, i.e. the lower 20 bytes of keccak256(pk_x | pk_y).
+        //
+        // || msg_hash | v | r | s | address | is_valid ||
+        cb.condition(is_well_formed.expr(), |cb| {
+            cb.keccak_table_lookup(
+                pk_x_keccak_rlc.expr() * r_pow_32 + pk_y_keccak_rlc.expr(),
+                64.expr(),
+                pk_hash.expr(),
+            );
+            cb.sig_table_lookup(
+                msg_hash.expr(),
+                SIG_V_SECP256R1.expr(),
+                sig_r.expr(),
+                sig_s.expr(),
+                from_bytes::expr(&pk_hash.cells[..20]),
+                is_valid.expr(),
+            );
+        });
+        cb.condition(not::expr(is_well_formed), |cb| {
+            cb.require_zero("is_valid == false if input is malformed", is_valid.expr());
+        });
+
+        // the output is either 32-byte 1 or empty, so its RLC equals is_valid.
+        cb.require_equal(
+            "output bytes (RLC) = is_valid",
+            output_bytes_rlc.expr(),
+            is_valid.expr(),
+        );
+
+        let restore_context = super::gen_restore_context(
+            cb,
+            is_root.expr(),
+            is_success.expr(),
+            GasCost::PRECOMPILE_P256VERIFY.expr(),
+            select::expr(is_valid.expr(), 0x20.expr(), 0x00.expr()), // ReturnDataLength
+        );
+
+        Self {
+            input_bytes_rlc,
+            output_bytes_rlc,
+            return_bytes_rlc,
+
+            is_valid,
+            is_valid_length,
+            msg_hash_keccak_rlc,
+            sig_r_keccak_rlc,
+            sig_s_keccak_rlc,
+            pk_x_keccak_rlc,
+            pk_y_keccak_rlc,
+            pk_hash,
+
+            msg_hash_raw,
+            msg_hash,
+            n_modulus,
+            msg_hash_mod,
+
+            sig_r,
+            sig_r_canonical,
+            sig_r_is_zero,
+            sig_s,
+            sig_s_canonical,
+            sig_s_is_zero,
+
+            p_modulus,
+            pk_x,
+            pk_x_canonical,
+            pk_y,
+            pk_y_canonical,
+            pk_is_zero,
+
+            is_success,
+            callee_address,
+            is_root,
+            call_data_offset,
+            call_data_length,
+            return_data_offset,
+            return_data_length,
+            restore_context,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        _tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        if let Some(PrecompileAuxData::P256Verify(aux_data)) = &step.aux_data {
+            for (cell, bytes) in [
+                (&self.input_bytes_rlc, &aux_data.input_bytes),
+                (&self.output_bytes_rlc, &aux_data.output_bytes),
+                (&self.return_bytes_rlc, &aux_data.return_bytes),
+            ] {
+                cell.assign(
+                    region,
+                    offset,
+                    region
+                        .challenges()
+                        .keccak_input()
+                        .map(|r| rlc::value(bytes.iter().rev(), r)),
+                )?;
+            }
+            self.is_valid.assign(
+                region,
+                offset,
+                Value::known(F::from(aux_data.is_valid as u64)),
+            )?;
+            self.is_valid_length.assign(
+                region,
+                offset,
+                F::from(call.call_data_length),
+                F::from(P256_VERIFY_INPUT_LEN as u64),
+            )?;
+
+            for (word_keccak_rlc, word_rlc, value) in [
+                (
+                    &self.msg_hash_keccak_rlc,
+                    &self.msg_hash_raw,
+                    aux_data.msg_hash,
+                ),
+                (&self.sig_r_keccak_rlc, &self.sig_r, aux_data.sig_r),
+                (&self.sig_s_keccak_rlc, &self.sig_s, aux_data.sig_s),
+                (&self.pk_x_keccak_rlc, &self.pk_x, aux_data.pk_x),
+                (&self.pk_y_keccak_rlc, &self.pk_y, aux_data.pk_y),
+            ] {
+                word_keccak_rlc.assign(
+                    region,
+                    offset,
+                    region
+                        .challenges()
+                        .keccak_input()
+                        .map(|r| rlc::value(&value.to_le_bytes(), r)),
+                )?;
+                word_rlc.assign(region, offset, Some(value.to_le_bytes()))?;
+            }
+            let pk_hash = U256::from_big_endian(&keccak256(
+                [aux_data.pk_x.to_be_bytes(), aux_data.pk_y.to_be_bytes()].concat(),
+            ));
+            self.pk_hash
+                .assign(region, offset, Some(pk_hash.to_le_bytes()))?;
+
+            let (quotient, remainder) = aux_data.msg_hash.div_mod(*SECP256R1_N);
+            self.msg_hash
+                .assign(region, offset, Some(remainder.to_le_bytes()))?;
+            self.n_modulus
+                .assign(region, offset, Some(SECP256R1_N.to_le_bytes()))?;
+            self.msg_hash_mod.assign(
+                region,
+                offset,
+                aux_data.msg_hash,
+                *SECP256R1_N,
+                remainder,
+                quotient,
+            )?;
+            self.p_modulus
+                .assign(region, offset, Some(SECP256R1_P.to_le_bytes()))?;
+
+            for (lt_gadget, value, modulus) in [
+                (&self.sig_r_canonical, aux_data.sig_r, *SECP256R1_N),
+                (&self.sig_s_canonical, aux_data.sig_s, *SECP256R1_N),
+                (&self.pk_x_canonical, aux_data.pk_x, *SECP256R1_P),
+                (&self.pk_y_canonical, aux_data.pk_y, *SECP256R1_P),
+            ] {
+                lt_gadget.assign(region, offset, value, modulus)?;
+            }
+            let bytes_sum = |words: &[U256]| {
+                F::from(
+                    words
+                        .iter()
+                        .flat_map(|word| word.to_le_bytes())
+                        .map(|b| b as u64)
+                        .sum::<u64>(),
+                )
+            };
+            self.sig_r_is_zero
+                .assign(region, offset, bytes_sum(&[aux_data.sig_r]))?;
+            self.sig_s_is_zero
+                .assign(region, offset, bytes_sum(&[aux_data.sig_s]))?;
+            self.pk_is_zero
+                .assign(region, offset, bytes_sum(&[aux_data.pk_x, aux_data.pk_y]))?;
+        } else {
+            log::error!("unexpected aux_data {:?} for p256verify", step.aux_data);
+            return Err(Error::Synthesis);
+        }
+
+        self.is_success.assign(
+            region,
+            offset,
+            Value::known(F::from(u64::from(call.is_success))),
+        )?;
+        self.callee_address.assign(
+            region,
+            offset,
+            Value::known(call.code_address.unwrap().to_scalar().unwrap()),
+        )?;
+        self.is_root
+            .assign(region, offset, Value::known(F::from(call.is_root as u64)))?;
+        self.call_data_offset.assign(
+            region,
+            offset,
+            Value::known(F::from(call.call_data_offset)),
+        )?;
+        self.call_data_length.assign(
+            region,
+            offset,
+            Value::known(F::from(call.call_data_length)),
+        )?;
+        self.return_data_offset.assign(
+            region,
+            offset,
+            Value::known(F::from(call.return_data_offset)),
+        )?;
+        self.return_data_length.assign(
+            region,
+            offset,
+            Value::known(F::from(call.return_data_length)),
+        )?;
+        self.restore_context
+            .assign(region, offset, block, call, step, 7)
+    }
+}
+
+#[cfg(all(test, feature = "scroll"))]
+mod test {
+    use bus_mapping::{
+        circuit_input_builder::{CircuitInputBuilder, CircuitsParams},
+        evm::{OpcodeId, PrecompileCallArgs},
+        precompile::PrecompileCalls,
+    };
+    use eth_types::{bytecode, evm_types::GasCost, word, Bytecode, ToWord};
+    use mock::TestContext;
+    use rayon::{
+        iter::{IndexedParallelIterator, ParallelIterator},
+        prelude::IntoParallelRefIterator,
+    };
+    use std::sync::LazyLock;
+
+    use crate::{
+        test_util::CircuitTestBuilder,
+        witness::{block_convert, Block},
+    };
+
+    /// Store the 160-byte input of a valid signature at memory 0x00, with the given public key y
+    /// co-ordinate.
+    fn setup_code(pk_y: eth_types::Word) -> Bytecode {
+        bytecode! {
+            // msg hash
+            PUSH32(word!("0x4cee90eb86eaa050036147a12d49004b6b9c72bd725d39d4785011fe190f0b4d"))
+            PUSH1(0x00)
+            MSTORE
+            // signature r
+            PUSH32(word!("0xa73bd4903f0ce3b639bbbf6e8e80d16931ff4bcf5993d58468e8fb19086e8cac"))
+            PUSH1(0x20)
+            MSTORE
+            // signature s
+            PUSH32(word!("0x36dbcd03009df8c59286b162af3bd7fcc0450c9aa81be5d10d312af6c66b1d60"))
+            PUSH1(0x40)
+            MSTORE
+            // public key x
+            PUSH32(word!("0x4aebd3099c618202fcfe16ae7770b0c49ab5eadf74b754204a3bb6060e44eff3"))
+            PUSH1(0x60)
+            MSTORE
+            // public key y
+            PUSH32(pk_y)
+            PUSH1(0x80)
+            MSTORE
+        }
+    }
+
+    static PK_Y: LazyLock<eth_types::Word> = LazyLock::new(|| {
+        word!("0x7618b065f9832de4ca6ca971a7a1adc826d0f7c00181a5fb2ddf79ae00b4e10e")
+    });
+
+    static TEST_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+        vec![
+            PrecompileCallArgs {
+                name: "p256verify (valid sig)",
+                setup_code: setup_code(*PK_Y),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xa0.into(),
+                ret_offset: 0xa0.into(),
+                ret_size: 0x20.into(),
+                address: PrecompileCalls::P256Verify.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "p256verify (invalid sig, public key not on the curve)",
+                setup_code: setup_code(*PK_Y + 1u64),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xa0.into(),
+                ret_offset: 0xa0.into(),
+                ret_size: 0x20.into(),
+                address: PrecompileCalls::P256Verify.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "p256verify (short input)",
+                setup_code: setup_code(*PK_Y),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0x9f.into(),
+                ret_offset: 0xa0.into(),
+                ret_size: 0x20.into(),
+                address: PrecompileCalls::P256Verify.address().to_word(),
+                ..Default::default()
+            },
+            PrecompileCallArgs {
+                name: "p256verify (extra input bytes)",
+                setup_code: setup_code(*PK_Y),
+                call_data_offset: 0x00.into(),
+                call_data_length: 0xa1.into(),
+                ret_offset: 0xa0.into(),
+                ret_size: 0x20.into(),
+                address: PrecompileCalls::P256Verify.address().to_word(),
+                ..Default::default()
+            },
+        ]
+    });
+
+    static OOG_TEST_VECTOR: LazyLock<Vec<PrecompileCallArgs>> = LazyLock::new(|| {
+        vec![PrecompileCallArgs {
+            name: "p256verify (oog)",
+            setup_code: setup_code(*PK_Y),
+            call_data_offset: 0x00.into(),
+            call_data_length: 0xa0.into(),
+            ret_offset: 0xa0.into(),
+            ret_size: 0x20.into(),
+            gas: 0.into(),
+            address: PrecompileCalls::P256Verify.address().to_word(),
+            ..Default::default()
+        }]
+    });
+
+    /// Build the witness of a tx calling the p256verify precompile once, without going through
+    /// a tracer supporting RIP-7212. l2geth traces the call as one to a cold account without
+    /// code, so the trace is patched into the one of the precompile: 0x100 is warm, its gas is
+    /// charged, and a valid signature returns 32 bytes.
+    fn synthetic_witness_block(bytecode: Bytecode, is_valid: bool) -> Block {
+        let ctx = TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap();
+        let mut trace = ctx.l2_trace().clone();
+
+        let result = &mut trace.execution_results[0];
+        let call_idx = result
+            .exec_steps
+            .iter()
+            .position(|step| step.op.is_call())
+            .unwrap();
+        let cold_access_surcharge =
+            GasCost::COLD_ACCOUNT_ACCESS.as_u64() - GasCost::WARM_ACCESS.as_u64();
+        let precompile_gas_cost = GasCost::PRECOMPILE_P256VERIFY.as_u64();
+        for step in result.exec_steps[call_idx + 1..].iter_mut() {
+            step.gas = step.gas + cold_access_surcharge - precompile_gas_cost;
+        }
+        result.gas = result.gas + precompile_gas_cost - cold_access_surcharge;
+        #[cfg(feature = "enable-stack")]
+        if is_valid {
+            let idx = result
+                .exec_steps
+                .iter()
+                .position(|step| step.op == OpcodeId::RETURNDATASIZE)
+                .unwrap();
+            if let Some(stack) = result.exec_steps[idx + 1].stack.as_mut() {
+                *stack.last_mut().unwrap() = 0x20.into();
+            }
+        }
+        #[cfg(not(feature = "enable-stack"))]
+        let _ = is_valid;
+
+        let params = CircuitsParams {
+            max_txs: 1,
+            ..Default::default()
+        };
+        let mut builder = CircuitInputBuilder::new_from_l2_trace(params, trace).unwrap();
+        builder.finalize_building().unwrap();
+        let mut block = block_convert(&builder.block, &builder.code_db).unwrap();
+        block.apply_mpt_updates(&builder.mpt_init_state.unwrap());
+        block
+    }
+
+    #[test]
+    fn precompile_p256_verify_synthetic_witness_test() {
+        let call_kinds = vec![
+            OpcodeId::CALL,
+            OpcodeId::STATICCALL,
+            OpcodeId::DELEGATECALL,
+            OpcodeId::CALLCODE,
+        ];
+        // only the first test vector holds a valid signature.
+        let is_valid = [true, false, false, false];
+
+        TEST_VECTOR
+            .par_iter()
+            .zip(is_valid.par_iter())
+            .for_each(|(test_vector, &is_valid)| {
+                for &call_kind in &call_kinds {
+                    let bytecode = test_vector.with_call_op(call_kind);
+
+                    CircuitTestBuilder::<2, 1>::new_from_block(synthetic_witness_block(
+                        bytecode, is_valid,
+                    ))
+                    .run();
+                }
+            });
+    }
+
+    #[test]
+    #[ignore = "l2geth tracer does not support RIP-7212 yet"]
+    fn precompile_p256_verify_test() {
+        let call_kinds = vec![
+            OpcodeId::CALL,
+            OpcodeId::STATICCALL,
+            OpcodeId::DELEGATECALL,
+            OpcodeId::CALLCODE,
+        ];
+
+        TEST_VECTOR.par_iter().for_each(|test_vector| {
+            for &call_kind in &call_kinds {
+                let bytecode = test_vector.with_call_op(call_kind);
+
+                CircuitTestBuilder::new_from_test_ctx(
+                    TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                )
+                .run();
+            }
+        });
+    }
+
+    #[test]
+    #[ignore = "l2geth tracer does not support RIP-7212 yet"]
+    fn precompile_p256_verify_oog_test() {
+        let call_kinds = vec![
+            OpcodeId::CALL,
+            OpcodeId::STATICCALL,
+            OpcodeId::DELEGATECALL,
+            OpcodeId::CALLCODE,
+        ];
+
+        OOG_TEST_VECTOR.par_iter().for_each(|test_vector| {
+            for &call_kind in &call_kinds {
+                let bytecode = test_vector.with_call_op(call_kind);
+
+                CircuitTestBuilder::new_from_test_ctx(
+                    TestContext::<2, 1>::simple_ctx_with_bytecode(bytecode).unwrap(),
+                )
+                .run();
+            }
+        })
+    }
+}
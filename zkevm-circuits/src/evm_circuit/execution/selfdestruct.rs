@@ -0,0 +1,661 @@
+use crate::{
+    evm_circuit::{
+        execution::ExecutionGadget,
+        param::N_BYTES_ACCOUNT_ADDRESS,
+        step::ExecutionState,
+        util::{
+            common_gadget::{BytecodeLookupGadget, RestoreContextGadget, TransferToGadget},
+            constraint_builder::{
+                ConstrainBuilderCommon, EVMConstraintBuilder, ReversionInfo, StepStateTransition,
+                Transition::{Delta, Same, To},
+            },
+            from_bytes,
+            math_gadget::{IsEqualGadget, IsZeroGadget},
+            not, select, CachedRegion, Cell, StepRws, Word,
+        },
+        witness::{Block, Call, ExecStep, Transaction},
+    },
+    table::{AccountFieldTag, CallContextFieldTag, RwTableTag},
+    util::{Expr, Field},
+};
+use either::Either;
+use eth_types::{
+    evm_types::{GasCost, OpcodeId},
+    ToAddress, ToLittleEndian, ToScalar,
+};
+use halo2_proofs::{circuit::Value, plonk::Error};
+
+/// Gadget for SELFDESTRUCT following EIP-6780: the balance is always sent to
+/// the beneficiary, while the account is only deleted when it has been created
+/// in the same transaction.
+///
+/// Deleting the account clears its storage: each non-zero slot is cleared by a
+/// step of its own in storage clear mode, which must be followed by another
+/// SELFDESTRUCT step at the same program counter.
+#[derive(Clone, Debug)]
+pub(crate) struct SelfDestructGadget<F> {
+    opcode_gadget: BytecodeLookupGadget<F>,
+    is_storage_clear: Cell<F>,
+    storage_key: Cell<F>,
+    storage_value_prev: Cell<F>,
+    storage_committed_value: Cell<F>,
+    beneficiary: Word<F>,
+    tx_id: Cell<F>,
+    reversion_info: ReversionInfo<F>,
+    callee_address: Cell<F>,
+    is_warm: Cell<F>,
+    beneficiary_code_hash: Cell<F>,
+    beneficiary_not_exists: IsZeroGadget<F>,
+    is_created: Cell<F>,
+    balance: Word<F>,
+    balance_is_zero: IsZeroGadget<F>,
+    is_self_beneficiary: IsEqualGadget<F>,
+    transfer: TransferToGadget<F>,
+    nonce_prev: Cell<F>,
+    code_hash_prev: Cell<F>,
+    keccak_code_hash_prev: Cell<F>,
+    code_size_prev: Cell<F>,
+    restore_context: RestoreContextGadget<F>,
+}
+
+impl<F: Field> ExecutionGadget<F> for SelfDestructGadget<F> {
+    const NAME: &'static str = "SELFDESTRUCT";
+
+    const EXECUTION_STATE: ExecutionState = ExecutionState::SELFDESTRUCT;
+
+    fn configure(cb: &mut EVMConstraintBuilder<F>) -> Self {
+        let opcode_gadget = BytecodeLookupGadget::construct(cb);
+        cb.require_equal(
+            "Opcode should be SELFDESTRUCT",
+            opcode_gadget.opcode.expr(),
+            OpcodeId::SELFDESTRUCT.expr(),
+        );
+
+        let is_storage_clear = cb.query_bool();
+        let is_opcode = not::expr(is_storage_clear.expr());
+
+        let beneficiary = cb.query_word_rlc();
+        let beneficiary_address = from_bytes::expr(&beneficiary.cells[..N_BYTES_ACCOUNT_ADDRESS]);
+        cb.condition(is_opcode.expr(), |cb| cb.stack_pop(beneficiary.expr()));
+
+        let tx_id = cb.call_context(None, CallContextFieldTag::TxId);
+        cb.condition(is_opcode.expr(), |cb| {
+            cb.call_context_lookup(false.expr(), None, CallContextFieldTag::IsStatic, 0.expr());
+        });
+        let mut reversion_info = cb.reversion_info_read(None);
+        let callee_address = cb.call_context(None, CallContextFieldTag::CalleeAddress);
+
+        // Clear one storage slot of an account created in this tx, then execute
+        // SELFDESTRUCT again.
+        let storage_key = cb.query_cell_phase2();
+        let storage_value_prev = cb.query_cell_phase2();
+        let storage_committed_value = cb.query_cell_phase2();
+        cb.condition(is_storage_clear.expr(), |cb| {
+            cb.tx_created_account_read(tx_id.expr(), callee_address.expr(), 1.expr());
+            cb.account_storage_write(
+                callee_address.expr(),
+                storage_key.expr(),
+                0.expr(),
+                storage_value_prev.expr(),
+                tx_id.expr(),
+                storage_committed_value.expr(),
+                Some(&mut reversion_info),
+            );
+
+            cb.require_equal(
+                "Go to SELFDESTRUCT after clearing a storage slot",
+                cb.next
+                    .execution_state_selector([ExecutionState::SELFDESTRUCT]),
+                1.expr(),
+            );
+            cb.require_step_state_transition(StepStateTransition {
+                rw_counter: Delta(cb.rw_counter_offset()),
+                reversible_write_counter: Delta(1.expr()),
+                ..Default::default()
+            });
+        });
+
+        let is_warm = cb.query_bool();
+        let beneficiary_code_hash = cb.query_cell_phase2();
+        let is_created = cb.query_bool();
+        cb.condition(is_opcode.expr(), |cb| {
+            cb.account_access_list_write(
+                tx_id.expr(),
+                beneficiary_address.expr(),
+                1.expr(),
+                is_warm.expr(),
+                Some(&mut reversion_info),
+            );
+
+            // For non-existing accounts the code_hash must be 0 in the rw_table.
+            cb.account_read(
+                beneficiary_address.expr(),
+                AccountFieldTag::CodeHash,
+                beneficiary_code_hash.expr(),
+            );
+
+            cb.tx_created_account_read(tx_id.expr(), callee_address.expr(), is_created.expr());
+        });
+        let beneficiary_not_exists = IsZeroGadget::construct(cb, beneficiary_code_hash.expr());
+
+        // Move the whole balance out of the account. It is only kept when a
+        // pre-existing account names itself as the beneficiary, and burnt when
+        // an account created in this tx does so.
+        let balance = cb.query_word_rlc();
+        let balance_is_zero = IsZeroGadget::construct(cb, balance.expr());
+        let is_self_beneficiary =
+            IsEqualGadget::construct(cb, callee_address.expr(), beneficiary_address.expr());
+        cb.condition(is_opcode.expr(), |cb| {
+            cb.account_write(
+                callee_address.expr(),
+                AccountFieldTag::Balance,
+                select::expr(
+                    is_self_beneficiary.expr() * not::expr(is_created.expr()),
+                    balance.expr(),
+                    0.expr(),
+                ),
+                balance.expr(),
+                Some(&mut reversion_info),
+            );
+        });
+        let is_transfer = is_opcode.expr() * not::expr(is_self_beneficiary.expr());
+        let transfer = cb.condition(is_transfer, |cb| {
+            TransferToGadget::construct_with_is_zero(
+                cb,
+                beneficiary_address.expr(),
+                not::expr(beneficiary_not_exists.expr()),
+                0.expr(),
+                beneficiary_code_hash.expr(),
+                // The beneficiary is only created when it does not exist.
+                #[cfg(feature = "scroll")]
+                0.expr(),
+                balance.clone(),
+                Either::Right(balance_is_zero.expr()),
+                Some(&mut reversion_info),
+            )
+        });
+
+        // Delete the account only if it has been created in this tx. Its
+        // storage has been cleared by the preceding steps.
+        let nonce_prev = cb.query_cell();
+        let code_hash_prev = cb.query_cell_phase2();
+        let keccak_code_hash_prev = cb.query_cell_phase2();
+        let code_size_prev = cb.query_cell();
+        cb.condition(is_opcode.expr() * is_created.expr(), |cb| {
+            cb.account_write(
+                callee_address.expr(),
+                AccountFieldTag::Nonce,
+                0.expr(),
+                nonce_prev.expr(),
+                Some(&mut reversion_info),
+            );
+            cb.account_write(
+                callee_address.expr(),
+                AccountFieldTag::CodeHash,
+                0.expr(),
+                code_hash_prev.expr(),
+                Some(&mut reversion_info),
+            );
+            #[cfg(feature = "scroll")]
+            {
+                cb.account_write(
+                    callee_address.expr(),
+                    AccountFieldTag::KeccakCodeHash,
+                    0.expr(),
+                    keccak_code_hash_prev.expr(),
+                    Some(&mut reversion_info),
+                );
+                cb.account_write(
+                    callee_address.expr(),
+                    AccountFieldTag::CodeSize,
+                    0.expr(),
+                    code_size_prev.expr(),
+                    Some(&mut reversion_info),
+                );
+            }
+        });
+
+        // Call ends with SELFDESTRUCT must be successful
+        cb.condition(is_opcode.expr(), |cb| {
+            cb.call_context_lookup(false.expr(), None, CallContextFieldTag::IsSuccess, 1.expr());
+        });
+
+        // Cold account access and the creation of the beneficiary when some
+        // balance is sent to a non-existing account. There is no refund since
+        // EIP-3529.
+        let gas_cost = GasCost::SELFDESTRUCT.expr()
+            + not::expr(is_warm.expr()) * GasCost::COLD_ACCOUNT_ACCESS.expr()
+            + beneficiary_not_exists.expr()
+                * not::expr(balance_is_zero.expr())
+                * GasCost::NEW_ACCOUNT.expr();
+
+        // +1 Write TxAccessListAccount (beneficiary)
+        // +1 Write Account (callee) Balance
+        // +a TransferToGadget (beneficiary)
+        // +n Write Account (callee) Nonce, CodeHash, and KeccakCodeHash and
+        //    CodeSize with scroll, if created in this tx
+        let n_deleted_fields = if cfg!(feature = "scroll") { 4 } else { 2 };
+        let reversible_write_counter_increase = 2.expr()
+            + not::expr(is_self_beneficiary.expr()) * transfer.reversible_w_delta()
+            + is_created.expr() * n_deleted_fields.expr();
+
+        let is_to_end_tx = cb.next.execution_state_selector([ExecutionState::EndTx]);
+        cb.condition(is_opcode.expr(), |cb| {
+            cb.require_equal(
+                "Go to EndTx only when is_root",
+                cb.curr.state.is_root.expr(),
+                is_to_end_tx,
+            );
+        });
+
+        // When it's a root call
+        cb.condition(is_opcode.expr() * cb.curr.state.is_root.expr(), |cb| {
+            cb.require_step_state_transition(StepStateTransition {
+                call_id: Same,
+                rw_counter: Delta(cb.rw_counter_offset()),
+                gas_left: Delta(-gas_cost.clone()),
+                reversible_write_counter: Delta(reversible_write_counter_increase.clone()),
+                end_tx: To(1.expr()),
+                ..StepStateTransition::any()
+            });
+        });
+
+        // When it's an internal call
+        let is_internal = is_opcode * not::expr(cb.curr.state.is_root.expr());
+        let restore_context = cb.condition(is_internal, |cb| {
+            RestoreContextGadget::construct2(
+                cb,
+                true.expr(),
+                gas_cost,
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                0.expr(),
+                reversible_write_counter_increase,
+            )
+        });
+
+        Self {
+            opcode_gadget,
+            is_storage_clear,
+            storage_key,
+            storage_value_prev,
+            storage_committed_value,
+            beneficiary,
+            tx_id,
+            reversion_info,
+            callee_address,
+            is_warm,
+            beneficiary_code_hash,
+            beneficiary_not_exists,
+            is_created,
+            balance,
+            balance_is_zero,
+            is_self_beneficiary,
+            transfer,
+            nonce_prev,
+            code_hash_prev,
+            keccak_code_hash_prev,
+            code_size_prev,
+            restore_context,
+        }
+    }
+
+    fn assign_exec_step(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        tx: &Transaction,
+        call: &Call,
+        step: &ExecStep,
+    ) -> Result<(), Error> {
+        self.opcode_gadget
+            .assign(region, offset, block, call, step)?;
+
+        let mut rws = StepRws::new(block, step);
+
+        // Only the step executing the opcode pops the beneficiary.
+        let is_storage_clear = block.rws[step.rw_indices[0]].tag() != RwTableTag::Stack;
+        self.is_storage_clear
+            .assign(region, offset, Value::known(F::from(is_storage_clear)))?;
+
+        self.tx_id
+            .assign(region, offset, Value::known(F::from(tx.id as u64)))?;
+        self.reversion_info.assign(
+            region,
+            offset,
+            call.rw_counter_end_of_reversion,
+            call.is_persistent,
+        )?;
+        let callee_address = call
+            .callee_address
+            .to_scalar()
+            .expect("unexpected Address -> Scalar conversion failure");
+        self.callee_address
+            .assign(region, offset, Value::known(callee_address))?;
+
+        if is_storage_clear {
+            // TxId, RwCounterEndOfReversion, IsPersistent, CalleeAddress,
+            // TxCreatedAccount
+            rws.offset_add(5);
+            let storage = rws.next();
+            let (_, value_prev, _, committed_value) = storage.storage_value_aux();
+            self.storage_key.assign(
+                region,
+                offset,
+                region.word_rlc(storage.storage_key().expect("storage key")),
+            )?;
+            self.storage_value_prev
+                .assign(region, offset, region.word_rlc(value_prev))?;
+            self.storage_committed_value.assign(
+                region,
+                offset,
+                region.word_rlc(committed_value),
+            )?;
+            return Ok(());
+        }
+
+        let beneficiary = rws.next().stack_value();
+        self.beneficiary
+            .assign(region, offset, Some(beneficiary.to_le_bytes()))?;
+        let beneficiary_address = beneficiary.to_address();
+        // TxId, IsStatic, RwCounterEndOfReversion, IsPersistent, CalleeAddress
+        rws.offset_add(5);
+
+        let is_warm = rws.next().tx_access_list_value_pair().1;
+        self.is_warm
+            .assign(region, offset, Value::known(F::from(is_warm)))?;
+
+        let beneficiary_code_hash = rws.next().account_codehash_pair().0;
+        self.beneficiary_code_hash.assign(
+            region,
+            offset,
+            region.code_hash(beneficiary_code_hash),
+        )?;
+        self.beneficiary_not_exists.assign_value(
+            region,
+            offset,
+            region.code_hash(beneficiary_code_hash),
+        )?;
+
+        let is_created = rws.next().tx_created_account_value_pair().0;
+        self.is_created
+            .assign(region, offset, Value::known(F::from(is_created)))?;
+
+        let balance = rws.next().account_balance_pair().1;
+        self.balance
+            .assign(region, offset, Some(balance.to_le_bytes()))?;
+        self.balance_is_zero
+            .assign_value(region, offset, region.word_rlc(balance))?;
+        let beneficiary_scalar = beneficiary_address
+            .to_scalar()
+            .expect("unexpected Address -> Scalar conversion failure");
+        self.is_self_beneficiary
+            .assign(region, offset, callee_address, beneficiary_scalar)?;
+        if beneficiary_address != call.callee_address {
+            self.transfer.assign_from_rws(
+                region,
+                offset,
+                !beneficiary_code_hash.is_zero(),
+                false,
+                balance,
+                &mut rws,
+            )?;
+        }
+
+        if is_created {
+            let nonce_prev = rws.next().account_nonce_pair().1;
+            self.nonce_prev.assign(
+                region,
+                offset,
+                Value::known(
+                    nonce_prev
+                        .to_scalar()
+                        .expect("unexpected U256 -> Scalar conversion failure"),
+                ),
+            )?;
+            let code_hash_prev = rws.next().account_codehash_pair().1;
+            self.code_hash_prev
+                .assign(region, offset, region.code_hash(code_hash_prev))?;
+            #[cfg(feature = "scroll")]
+            {
+                let keccak_code_hash_prev = rws.next().account_keccak_codehash_pair().1;
+                self.keccak_code_hash_prev.assign(
+                    region,
+                    offset,
+                    region.word_rlc(keccak_code_hash_prev),
+                )?;
+                let code_size_prev = rws.next().account_value_pair().1;
+                self.code_size_prev.assign(
+                    region,
+                    offset,
+                    Value::known(
+                        code_size_prev
+                            .to_scalar()
+                            .expect("unexpected U256 -> Scalar conversion failure"),
+                    ),
+                )?;
+            }
+        }
+
+        rws.next(); // IsSuccess
+        if !call.is_root {
+            self.restore_context
+                .assign(region, offset, block, call, step, rws.offset())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::test_util::CircuitTestBuilder;
+    use eth_types::{address, bytecode, Address, Bytecode, ToWord, Word};
+    use mock::{eth, TestContext, MOCK_ACCOUNTS};
+    use std::sync::LazyLock;
+
+    static BENEFICIARY: LazyLock<Address> =
+        LazyLock::new(|| address!("0x00000000000000000000000000000000000000be"));
+
+    fn selfdestruct_code(beneficiary: Address) -> Bytecode {
+        bytecode! {
+            PUSH20(beneficiary.to_word())
+            SELFDESTRUCT
+        }
+    }
+
+    fn test_root_ok(beneficiary: Address, beneficiary_exists: bool, balance: Word) {
+        let code = selfdestruct_code(beneficiary);
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(balance)
+                    .code(code);
+                accs[1].address(MOCK_ACCOUNTS[1]).balance(eth(10));
+                if beneficiary_exists {
+                    accs[2].address(*BENEFICIARY).balance(Word::from(1));
+                } else {
+                    accs[2].address(MOCK_ACCOUNTS[2]).balance(Word::from(1));
+                }
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[0].address)
+                    .from(accs[1].address)
+                    .gas(Word::from(100_000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    fn test_internal_ok(beneficiary: Address, balance: Word) {
+        let callee_code = selfdestruct_code(beneficiary);
+        let caller_code = bytecode! {
+            PUSH1(0) // retLength
+            PUSH1(0) // retOffset
+            PUSH1(0) // argsLength
+            PUSH1(0) // argsOffset
+            PUSH1(0) // value
+            PUSH20(MOCK_ACCOUNTS[0].to_word())
+            GAS
+            CALL
+            STOP
+        };
+        let ctx = TestContext::<3, 1>::new(
+            None,
+            |accs| {
+                accs[0]
+                    .address(MOCK_ACCOUNTS[0])
+                    .balance(balance)
+                    .code(callee_code);
+                accs[1].address(MOCK_ACCOUNTS[1]).code(caller_code);
+                accs[2].address(MOCK_ACCOUNTS[2]).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[1].address)
+                    .from(accs[2].address)
+                    .gas(Word::from(100_000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    fn test_created_in_same_tx_ok(beneficiary: Address, value: Word) {
+        // init code which self destructs right away
+        test_init_code_ok(selfdestruct_code(beneficiary), value);
+    }
+
+    fn test_init_code_ok(init_code: Bytecode, value: Word) {
+        let ctx = TestContext::<1, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .from(accs[0].address)
+                    .value(value)
+                    .gas(Word::from(200_000))
+                    .input(init_code.into());
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+
+    #[test]
+    fn selfdestruct_gadget_existing_beneficiary() {
+        test_root_ok(*BENEFICIARY, true, Word::from(1000));
+        test_root_ok(*BENEFICIARY, true, Word::zero());
+    }
+
+    #[test]
+    fn selfdestruct_gadget_non_existing_beneficiary() {
+        test_root_ok(*BENEFICIARY, false, Word::from(1000));
+        test_root_ok(*BENEFICIARY, false, Word::zero());
+    }
+
+    #[test]
+    fn selfdestruct_gadget_warm_beneficiary() {
+        // the caller (tx sender) is warm
+        test_root_ok(MOCK_ACCOUNTS[1], true, Word::from(1000));
+    }
+
+    #[test]
+    fn selfdestruct_gadget_self_beneficiary() {
+        test_root_ok(MOCK_ACCOUNTS[0], true, Word::from(1000));
+    }
+
+    #[test]
+    fn selfdestruct_gadget_internal_call() {
+        test_internal_ok(*BENEFICIARY, Word::from(1000));
+        test_internal_ok(MOCK_ACCOUNTS[0], Word::from(1000));
+    }
+
+    #[test]
+    fn selfdestruct_gadget_created_in_same_tx() {
+        test_created_in_same_tx_ok(*BENEFICIARY, Word::from(1000));
+        test_created_in_same_tx_ok(*BENEFICIARY, Word::zero());
+    }
+
+    #[test]
+    fn selfdestruct_gadget_created_in_same_tx_clears_storage() {
+        // init code which writes storage slots, one of them back to zero,
+        // before self destructing
+        let mut init_code = bytecode! {
+            PUSH1(0x11)
+            PUSH1(0x02)
+            SSTORE
+            PUSH1(0x22)
+            PUSH1(0x01)
+            SSTORE
+            PUSH1(0x33)
+            PUSH1(0x03)
+            SSTORE
+            PUSH1(0x00)
+            PUSH1(0x03)
+            SSTORE
+        };
+        init_code.append(&selfdestruct_code(*BENEFICIARY));
+        test_init_code_ok(init_code, Word::from(1000));
+    }
+
+    #[test]
+    fn selfdestruct_gadget_created_in_reverted_call_clears_storage() {
+        // init code which writes a storage slot before self destructing
+        let mut init_code = bytecode! {
+            PUSH1(0x11)
+            PUSH1(0x02)
+            SSTORE
+        };
+        init_code.append(&selfdestruct_code(*BENEFICIARY));
+
+        // creates the account and reverts afterwards, so that every deletion
+        // of the account is reverted too
+        let init_code = init_code.code();
+        let code = bytecode! {
+            PUSH32(Word::from_big_endian(&init_code))
+            PUSH1(0x00)
+            MSTORE
+            PUSH1(init_code.len()) // length
+            PUSH1(32 - init_code.len()) // offset
+            PUSH1(0x00) // value
+            CREATE
+            POP
+            PUSH1(0x00)
+            PUSH1(0x00)
+            REVERT
+        };
+
+        let ctx = TestContext::<2, 1>::new(
+            None,
+            |accs| {
+                accs[0].address(MOCK_ACCOUNTS[0]).code(code);
+                accs[1].address(MOCK_ACCOUNTS[1]).balance(eth(10));
+            },
+            |mut txs, accs| {
+                txs[0]
+                    .to(accs[0].address)
+                    .from(accs[1].address)
+                    .gas(Word::from(300_000));
+            },
+            |block, _tx| block.number(0xcafeu64),
+        )
+        .unwrap();
+
+        CircuitTestBuilder::new_from_test_ctx(ctx).run();
+    }
+}
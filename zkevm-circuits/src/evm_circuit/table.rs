@@ -175,6 +175,8 @@ pub(crate) enum Table {
     Copy,
     Keccak,
     Sha256,
+    Ripemd160,
+    Blake2f,
     Exp,
     Sig,
     ModExp,
@@ -355,6 +357,24 @@ pub(crate) enum Lookup<F> {
         /// the final output sha256 hash of the input.
         output_rlc: Expression<F>,
     },
+    /// Lookup to ripemd160 table.
+    Ripemd160Table {
+        /// Accumulator to the input.
+        input_rlc: Expression<F>,
+        /// Length of input that is being hashed.
+        input_len: Expression<F>,
+        /// RLC representation of the 20-byte digest of the input.
+        output_rlc: Expression<F>,
+    },
+    /// Lookup to blake2f table.
+    Blake2fTable {
+        /// Number of rounds of the compression.
+        rounds: Expression<F>,
+        /// Accumulator to the 213-byte input.
+        input_rlc: Expression<F>,
+        /// RLC of the 64-byte output state vector.
+        output_rlc: Expression<F>,
+    },
     /// Lookup to exponentiation table.
     ExpTable {
         base_limbs: [Expression<F>; 4],
@@ -369,11 +389,17 @@ pub(crate) enum Lookup<F> {
         recovered_addr: Expression<F>,
         is_valid: Expression<F>,
     },
+    /// Lookup to the modexp table, big integers being represented by the RLC of their big-endian
+    /// bytes.
     ModExpTable {
-        base_limbs: [Expression<F>; 3],
-        exp_limbs: [Expression<F>; 3],
-        modulus_limbs: [Expression<F>; 3],
-        result_limbs: [Expression<F>; 3],
+        /// Accumulator to the input following the lengths header, padded to the input limit.
+        input_rlc: Expression<F>,
+        /// Byte lengths of base, exp and modulus.
+        lens: [Expression<F>; 3],
+        /// RLC of the leading (up to) 32 bytes of exp.
+        exp_head_rlc: Expression<F>,
+        is_success: Expression<F>,
+        result_rlc: Expression<F>,
     },
     EccTable {
         op_type: Expression<F>,
@@ -411,6 +437,8 @@ impl<F: Field> Lookup<F> {
             Self::CopyTable { .. } => Table::Copy,
             Self::KeccakTable { .. } => Table::Keccak,
             Self::Sha256Table { .. } => Table::Sha256,
+            Self::Ripemd160Table { .. } => Table::Ripemd160,
+            Self::Blake2fTable { .. } => Table::Blake2f,
             Self::ExpTable { .. } => Table::Exp,
             Self::SigTable { .. } => Table::Sig,
             Self::ModExpTable { .. } => Table::ModExp,
@@ -550,6 +578,28 @@ impl<F: Field> Lookup<F> {
                 input_len.clone(),
                 output_rlc.clone(),
             ],
+            Self::Ripemd160Table {
+                input_rlc,
+                input_len,
+                output_rlc,
+            } => vec![
+                1.expr(), // q_enable
+                1.expr(), // is_final
+                input_rlc.clone(),
+                input_len.clone(),
+                output_rlc.clone(),
+            ],
+            Self::Blake2fTable {
+                rounds,
+                input_rlc,
+                output_rlc,
+            } => vec![
+                1.expr(), // q_enable
+                1.expr(), // is_final
+                rounds.clone(),
+                input_rlc.clone(),
+                output_rlc.clone(),
+            ],
             Self::ExpTable {
                 base_limbs,
                 exponent_lo_hi,
@@ -583,24 +633,20 @@ impl<F: Field> Lookup<F> {
                 is_valid.clone(),
             ],
             Self::ModExpTable {
-                base_limbs,
-                exp_limbs,
-                modulus_limbs,
-                result_limbs,
+                input_rlc,
+                lens,
+                exp_head_rlc,
+                is_success,
+                result_rlc,
             } => vec![
                 1.expr(), // q_head
-                base_limbs[0].clone(),
-                exp_limbs[0].clone(),
-                modulus_limbs[0].clone(),
-                result_limbs[0].clone(),
-                base_limbs[1].clone(),
-                exp_limbs[1].clone(),
-                modulus_limbs[1].clone(),
-                result_limbs[1].clone(),
-                base_limbs[2].clone(),
-                exp_limbs[2].clone(),
-                modulus_limbs[2].clone(),
-                result_limbs[2].clone(),
+                input_rlc.clone(),
+                lens[0].clone(),
+                lens[1].clone(),
+                lens[2].clone(),
+                exp_head_rlc.clone(),
+                is_success.clone(),
+                result_rlc.clone(),
             ],
             Self::EccTable {
                 op_type,
@@ -10,6 +10,7 @@ use crate::{
     util::{query_expression, Challenges, Expr, Field},
     witness::{Block, ExecStep, Rw, RwMap},
 };
+use bus_mapping::precompile;
 use eth_types::{state_db::CodeDB, Address, ToLittleEndian, ToWord, U256};
 use halo2_proofs::{
     circuit::{AssignedCell, Region, Value},
@@ -720,7 +721,7 @@ pub(crate) fn transpose_val_ret<F, E>(value: Value<Result<F, E>>) -> Result<Valu
 }
 
 pub(crate) fn is_precompiled(address: &Address) -> bool {
-    address.0[0..19] == [0u8; 19] && (1..=9).contains(&address.0[19])
+    precompile::is_precompiled(address)
 }
 
 /// Helper struct to read rw operations from a step sequentially.
@@ -747,6 +748,10 @@ impl<'a> StepRws<'a> {
     pub(crate) fn offset_set(&mut self, offset: usize) {
         self.offset = offset
     }
+    /// Return the current step rw operation offset.
+    pub(crate) fn offset(&self) -> usize {
+        self.offset
+    }
     /// Return the next rw operation from the step.
     pub(crate) fn next(&mut self) -> Rw {
         let rw = self.rws[self.rw_indices[self.offset]];
@@ -34,11 +34,13 @@ use halo2_proofs::{
 
 mod tx_eip1559;
 mod tx_eip2930;
+mod tx_eip7702;
 mod tx_l1_fee;
 mod tx_l1_msg;
 
 pub(crate) use tx_eip1559::TxEip1559Gadget;
 pub(crate) use tx_eip2930::TxAccessListGadget;
+pub(crate) use tx_eip7702::{DelegateAddressGadget, DelegationDesignatorGadget, TxEip7702Gadget};
 pub(crate) use tx_l1_fee::TxL1FeeGadget;
 pub(crate) use tx_l1_msg::TxL1MsgGadget;
 
@@ -985,6 +987,23 @@ impl<F: Field> TransferToGadget<F> {
             ..Default::default()
         })
     }
+
+    /// Number of reversible writes, assuming the gadget was constructed with
+    /// a `reversion_info`.
+    pub(crate) fn reversible_w_delta(&self) -> Expression<F> {
+        // +1 Write Account (receiver) CodeHash (account creation via code_hash update)
+        // feature = "scroll": +1 Write Account (receiver) KeccakCodeHash
+        or::expr([
+            not::expr(self.value_is_zero()) * not::expr(self.receiver_exists.clone()),
+            self.must_create.clone(),
+        ]) * if cfg!(feature = "scroll") {
+            2.expr()
+        } else {
+            1.expr()
+        } +
+            // +1 Write Account (receiver) Balance
+            not::expr(self.value_is_zero())
+    }
 }
 
 impl<F: Field> TransferGadgetInfo<F> for TransferToGadget<F> {
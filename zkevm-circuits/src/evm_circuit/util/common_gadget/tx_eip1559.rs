@@ -25,6 +25,7 @@ use halo2_proofs::plonk::{Error, Expression};
 #[derive(Clone, Debug)]
 pub(crate) struct TxEip1559Gadget<F> {
     is_eip1559_tx: IsEqualGadget<F>,
+    is_eip7702_tx: IsEqualGadget<F>,
     // MaxFeePerGas
     gas_fee_cap: Word<F>,
     // MaxPriorityFeePerGas
@@ -65,7 +66,9 @@ impl<F: Field> TxEip1559Gadget<F> {
         value: &Word<F>,
         sender_balance: &Word<F>,
     ) -> Self {
-        let is_eip1559_tx = IsEqualGadget::construct(cb, tx_type, (TxType::Eip1559 as u64).expr());
+        // EIP-7702 txs share the fee market of EIP-1559 txs.
+        let [is_eip1559_tx, is_eip7702_tx] = [TxType::Eip1559, TxType::Eip7702]
+            .map(|val| IsEqualGadget::construct(cb, tx_type.expr(), (val as u64).expr()));
 
         let [gas_fee_cap, gas_tip_cap] =
             [TxFieldTag::MaxFeePerGas, TxFieldTag::MaxPriorityFeePerGas]
@@ -81,7 +84,7 @@ impl<F: Field> TxEip1559Gadget<F> {
             gas_tip_cap_lt_gas_fee_cap_minus_base_fee,
             gas_sub_base_fee,
             effective_gas_price_check,
-        ) = cb.condition(is_eip1559_tx.expr(), |cb| {
+        ) = cb.condition(is_eip1559_tx.expr() + is_eip7702_tx.expr(), |cb| {
             let mul_gas_fee_cap_by_gas =
                 MulWordByU64Gadget::construct(cb, gas_fee_cap.clone(), tx_gas);
 
@@ -146,6 +149,7 @@ impl<F: Field> TxEip1559Gadget<F> {
 
         Self {
             is_eip1559_tx,
+            is_eip7702_tx,
             gas_fee_cap,
             gas_tip_cap,
             gas_tip_cap_lt_gas_fee_cap_minus_base_fee,
@@ -175,6 +179,12 @@ impl<F: Field> TxEip1559Gadget<F> {
             F::from(tx.tx_type as u64),
             F::from(TxType::Eip1559 as u64),
         )?;
+        self.is_eip7702_tx.assign(
+            region,
+            offset,
+            F::from(tx.tx_type as u64),
+            F::from(TxType::Eip7702 as u64),
+        )?;
         self.gas_fee_cap
             .assign(region, offset, Some(tx.max_fee_per_gas.to_le_bytes()))?;
         self.gas_tip_cap.assign(
@@ -19,11 +19,12 @@ use halo2_proofs::{
     plonk::{Error, Expression},
 };
 
-/// Transaction gadget to handle access-list for EIP-1559 and EIP-2930
+/// Transaction gadget to handle access-list for EIP-1559, EIP-2930 and EIP-7702
 #[derive(Clone, Debug)]
 pub(crate) struct TxAccessListGadget<F> {
     is_eip1559_tx: IsEqualGadget<F>,
     is_eip2930_tx: IsEqualGadget<F>,
+    is_eip7702_tx: IsEqualGadget<F>,
     is_address_len_zero: IsZeroGadget<F>,
     is_storage_key_len_zero: IsZeroGadget<F>,
     address_len: Cell<F>,
@@ -36,11 +37,12 @@ impl<F: Field> TxAccessListGadget<F> {
         tx_id: Expression<F>,
         tx_type: Expression<F>,
     ) -> Self {
-        let [is_eip1559_tx, is_eip2930_tx] = [TxType::Eip1559, TxType::Eip2930]
-            .map(|val| IsEqualGadget::construct(cb, tx_type.expr(), (val as u64).expr()));
+        let [is_eip1559_tx, is_eip2930_tx, is_eip7702_tx] =
+            [TxType::Eip1559, TxType::Eip2930, TxType::Eip7702]
+                .map(|val| IsEqualGadget::construct(cb, tx_type.expr(), (val as u64).expr()));
 
         let (address_len, storage_key_len, is_address_len_zero, is_storage_key_len_zero) = cb.condition(
-            or::expr([is_eip1559_tx.expr(), is_eip2930_tx.expr()]),
+            or::expr([is_eip1559_tx.expr(), is_eip2930_tx.expr(), is_eip7702_tx.expr()]),
             |cb| {
                 let [(address_len, is_address_len_zero), (storage_key_len, is_storage_key_len_zero)] = [
                     TxFieldTag::AccessListAddressesLen,
@@ -92,6 +94,7 @@ impl<F: Field> TxAccessListGadget<F> {
         Self {
             is_eip1559_tx,
             is_eip2930_tx,
+            is_eip7702_tx,
             is_address_len_zero,
             is_storage_key_len_zero,
             address_len,
@@ -117,6 +120,12 @@ impl<F: Field> TxAccessListGadget<F> {
             F::from(tx.tx_type as u64),
             F::from(TxType::Eip2930 as u64),
         )?;
+        self.is_eip7702_tx.assign(
+            region,
+            offset,
+            F::from(tx.tx_type as u64),
+            F::from(TxType::Eip7702 as u64),
+        )?;
 
         let (address_len, storage_key_len) = access_list_size(&tx.access_list);
 
@@ -135,7 +144,11 @@ impl<F: Field> TxAccessListGadget<F> {
 
     pub(crate) fn gas_cost(&self) -> Expression<F> {
         select::expr(
-            or::expr([self.is_eip1559_tx.expr(), self.is_eip2930_tx.expr()]),
+            or::expr([
+                self.is_eip1559_tx.expr(),
+                self.is_eip2930_tx.expr(),
+                self.is_eip7702_tx.expr(),
+            ]),
             self.address_len.expr() * GasCost::ACCESS_LIST_PER_ADDRESS.expr()
                 + self.storage_key_len.expr() * GasCost::ACCESS_LIST_PER_STORAGE_KEY.expr(),
             0.expr(),
@@ -144,7 +157,11 @@ impl<F: Field> TxAccessListGadget<F> {
 
     pub(crate) fn rw_delta_expr(&self) -> Expression<F> {
         select::expr(
-            or::expr([self.is_eip1559_tx.expr(), self.is_eip2930_tx.expr()]),
+            or::expr([
+                self.is_eip1559_tx.expr(),
+                self.is_eip2930_tx.expr(),
+                self.is_eip7702_tx.expr(),
+            ]),
             self.address_len.expr() + self.storage_key_len.expr(),
             0.expr(),
         )
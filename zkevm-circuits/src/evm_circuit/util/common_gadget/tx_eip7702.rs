@@ -0,0 +1,711 @@
+//! TxEip7702Gadget applies the authorization list of EIP-7702 set-code transactions.
+//! Reference the geth code as:
+//! <https://github.com/ethereum/go-ethereum/blob/master/core/state_transition.go#L585>
+
+use super::{BytecodeLengthGadget, CachedRegion, Cell};
+use crate::{
+    evm_circuit::{
+        param::N_BYTES_ACCOUNT_ADDRESS,
+        util::{
+            constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
+            from_bytes,
+            math_gadget::{IsEqualGadget, IsZeroGadget, LtGadget},
+            not, or, rlc, select, StepRws,
+        },
+        witness::{Block, Transaction},
+    },
+    table::{AccountFieldTag, TxFieldTag},
+    util::{Expr, Field},
+};
+use bus_mapping::circuit_input_builder::CopyDataType;
+use eth_types::{
+    evm_types::GasCost,
+    geth_types::{TxType, MAX_AUTHORIZATIONS_PER_TX},
+    state_db::CodeDB,
+    utils::DELEGATION_PREFIX,
+    Address, ToWord, U256,
+};
+use gadgets::{util::sum, ToScalar};
+use halo2_proofs::{
+    circuit::Value,
+    plonk::{Error, Expression},
+};
+
+/// Length of the delegation designator `0xef0100 || address`.
+const DELEGATION_DESIGNATOR_LEN: usize = DELEGATION_PREFIX.len() + 20;
+
+/// Transaction gadget to apply the authorization list of EIP-7702 txs. The
+/// authorization tuples are processed in `MAX_AUTHORIZATIONS_PER_TX` slots.
+#[derive(Clone, Debug)]
+pub(crate) struct TxEip7702Gadget<F> {
+    is_eip7702_tx: IsEqualGadget<F>,
+    authorization_list_len: Cell<F>,
+    authorization_list_len_lt_max: LtGadget<F, 1>,
+    slots: [AuthorizationSlot<F>; MAX_AUTHORIZATIONS_PER_TX],
+}
+
+/// A single authorization tuple of the authorization list.
+#[derive(Clone, Debug)]
+struct AuthorizationSlot<F> {
+    is_in_list: LtGadget<F, 1>,
+    authority: Cell<F>,
+    authority_is_zero: IsZeroGadget<F>,
+    address: Cell<F>,
+    address_is_zero: IsZeroGadget<F>,
+    nonce: Cell<F>,
+    // the authority has been recovered from a tuple of the list
+    is_active: Cell<F>,
+    is_warm_prev: Cell<F>,
+    code_hash_prev: Cell<F>,
+    code_hash_prev_is_zero: IsZeroGadget<F>,
+    code_hash_prev_is_empty: IsEqualGadget<F>,
+    is_delegated: DelegationDesignatorGadget<F>,
+    authority_nonce: Cell<F>,
+    nonce_is_equal: IsEqualGadget<F>,
+    // the authorization is applied
+    is_valid: Cell<F>,
+    code_hash: Cell<F>,
+    code_length: BytecodeLengthGadget<F>,
+    delegate_address: DelegateAddressGadget<F>,
+    #[cfg(feature = "scroll")]
+    keccak_code_hash: Cell<F>,
+    #[cfg(feature = "scroll")]
+    keccak_code_hash_prev: Cell<F>,
+    #[cfg(feature = "scroll")]
+    code_size_prev: Cell<F>,
+}
+
+impl<F: Field> TxEip7702Gadget<F> {
+    pub(crate) fn construct(
+        cb: &mut EVMConstraintBuilder<F>,
+        tx_id: Expression<F>,
+        tx_type: Expression<F>,
+    ) -> Self {
+        let is_eip7702_tx = IsEqualGadget::construct(cb, tx_type, (TxType::Eip7702 as u64).expr());
+
+        // The length is zero for other tx types.
+        let authorization_list_len =
+            cb.tx_context(tx_id.expr(), TxFieldTag::AuthorizationListLen, None);
+        let authorization_list_len_lt_max = LtGadget::construct(
+            cb,
+            authorization_list_len.expr(),
+            (MAX_AUTHORIZATIONS_PER_TX + 1).expr(),
+        );
+        cb.require_true(
+            "authorization list length <= MAX_AUTHORIZATIONS_PER_TX",
+            authorization_list_len_lt_max.expr(),
+        );
+
+        let slots = array_init::array_init(|idx| {
+            AuthorizationSlot::construct(cb, tx_id.expr(), idx, authorization_list_len.expr())
+        });
+
+        // Refund for the authorities which already exist.
+        let refund = sum::expr(slots.iter().map(|slot| {
+            slot.is_valid.expr()
+                * not::expr(slot.code_hash_prev_is_zero.expr())
+                * (GasCost::AUTHORIZATION_PER_EMPTY_ACCOUNT.0
+                    - GasCost::AUTHORIZATION_PER_AUTH_BASE.0)
+                    .expr()
+        }));
+        cb.condition(is_eip7702_tx.expr(), |cb| {
+            cb.tx_refund_write(tx_id.expr(), refund, 0.expr(), None);
+        });
+
+        Self {
+            is_eip7702_tx,
+            authorization_list_len,
+            authorization_list_len_lt_max,
+            slots,
+        }
+    }
+
+    pub(crate) fn assign(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        tx: &Transaction,
+        rws: &mut StepRws,
+    ) -> Result<(), Error> {
+        self.is_eip7702_tx.assign(
+            region,
+            offset,
+            F::from(tx.tx_type as u64),
+            F::from(TxType::Eip7702 as u64),
+        )?;
+        let len = tx.authorization_list.len() as u64;
+        self.authorization_list_len
+            .assign(region, offset, Value::known(F::from(len)))?;
+        self.authorization_list_len_lt_max.assign(
+            region,
+            offset,
+            F::from(len),
+            F::from(MAX_AUTHORIZATIONS_PER_TX as u64 + 1),
+        )?;
+
+        for (idx, slot) in self.slots.iter().enumerate() {
+            slot.assign(region, offset, block, tx, idx, rws)?;
+        }
+
+        if tx.tx_type.is_eip7702() {
+            rws.next(); // TxRefund
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn gas_cost(&self) -> Expression<F> {
+        self.authorization_list_len.expr() * GasCost::AUTHORIZATION_PER_EMPTY_ACCOUNT.expr()
+    }
+
+    pub(crate) fn rw_delta(&self) -> Expression<F> {
+        self.is_eip7702_tx.expr() + sum::expr(self.slots.iter().map(|slot| slot.rw_delta()))
+    }
+}
+
+impl<F: Field> AuthorizationSlot<F> {
+    fn construct(
+        cb: &mut EVMConstraintBuilder<F>,
+        tx_id: Expression<F>,
+        idx: usize,
+        authorization_list_len: Expression<F>,
+    ) -> Self {
+        let is_in_list = LtGadget::construct(cb, idx.expr(), authorization_list_len);
+
+        // Authorization tuple index starts from 1 in tx-table.
+        let [authority, address, nonce] = cb.condition(is_in_list.expr(), |cb| {
+            [
+                TxFieldTag::AuthorizationAuthority,
+                TxFieldTag::AuthorizationAddress,
+                TxFieldTag::AuthorizationNonce,
+            ]
+            .map(|field_tag| cb.tx_context(tx_id.expr(), field_tag, Some((idx + 1).expr())))
+        });
+        let authority_is_zero = IsZeroGadget::construct(cb, authority.expr());
+        let address_is_zero = IsZeroGadget::construct(cb, address.expr());
+
+        let is_active = cb.query_bool();
+        cb.require_equal(
+            "authorization is active if its authority is recovered",
+            is_active.expr(),
+            is_in_list.expr() * not::expr(authority_is_zero.expr()),
+        );
+
+        let is_warm_prev = cb.query_bool();
+        let code_hash_prev = cb.query_cell_phase2();
+        let code_hash_prev_is_zero = IsZeroGadget::construct(cb, code_hash_prev.expr());
+        let code_hash_prev_is_empty =
+            IsEqualGadget::construct(cb, code_hash_prev.expr(), cb.empty_code_hash_rlc());
+        let is_delegated = DelegationDesignatorGadget::construct(
+            cb,
+            code_hash_prev.clone(),
+            is_active.expr() * not::expr(code_hash_prev_is_zero.expr()),
+        );
+        let authority_nonce = cb.query_cell();
+        let nonce_is_equal = IsEqualGadget::construct(cb, authority_nonce.expr(), nonce.expr());
+
+        cb.condition(is_active.expr(), |cb| {
+            cb.account_access_list_write(
+                tx_id.expr(),
+                authority.expr(),
+                1.expr(),
+                is_warm_prev.expr(),
+                None,
+            );
+            cb.account_read(
+                authority.expr(),
+                AccountFieldTag::CodeHash,
+                code_hash_prev.expr(),
+            );
+            cb.condition(not::expr(code_hash_prev_is_zero.expr()), |cb| {
+                cb.account_read(
+                    authority.expr(),
+                    AccountFieldTag::Nonce,
+                    authority_nonce.expr(),
+                );
+            });
+        });
+        cb.condition(code_hash_prev_is_zero.expr(), |cb| {
+            cb.require_zero(
+                "nonce of non-existing authority is 0",
+                authority_nonce.expr(),
+            );
+        });
+        // The authority must not be a contract, and its nonce has to match the authorization.
+        let is_valid = cb.query_bool();
+        cb.require_equal(
+            "authorization is applied if it is valid",
+            is_valid.expr(),
+            is_active.expr()
+                * or::expr([
+                    code_hash_prev_is_zero.expr(),
+                    code_hash_prev_is_empty.expr(),
+                    is_delegated.expr(),
+                ])
+                * nonce_is_equal.expr(),
+        );
+
+        let code_hash = cb.query_cell_phase2();
+        // The new code is the designator `0xef0100 || address` unless delegating to the zero
+        // address.
+        let is_designator = is_valid.expr() * not::expr(address_is_zero.expr());
+        let code_length = cb.condition(is_designator.expr(), |cb| {
+            BytecodeLengthGadget::construct(cb, code_hash.clone())
+        });
+        cb.condition(is_designator.expr(), |cb| {
+            #[cfg(not(feature = "dual-bytecode"))]
+            cb.require_true(
+                "designator is in the bytecode table",
+                code_length.is_first_bytecode_table.expr(),
+            );
+            cb.require_equal(
+                "designator length is 23",
+                code_length.code_length.expr(),
+                DELEGATION_DESIGNATOR_LEN.expr(),
+            );
+        });
+        let delegate_address =
+            DelegateAddressGadget::construct(cb, code_hash.expr(), is_designator.expr());
+        cb.condition(is_designator, |cb| {
+            cb.require_equal(
+                "designator delegates to the authorized address",
+                delegate_address.expr(),
+                address.expr(),
+            );
+        });
+        #[cfg(feature = "scroll")]
+        let keccak_code_hash = cb.query_cell_phase2();
+        #[cfg(feature = "scroll")]
+        let keccak_code_hash_prev = cb.query_cell_phase2();
+        #[cfg(feature = "scroll")]
+        let code_size_prev = cb.query_cell();
+        cb.condition(is_valid.expr(), |cb| {
+            // Delegating to the zero address clears the delegation.
+            cb.condition(address_is_zero.expr(), |cb| {
+                cb.require_equal(
+                    "code hash is empty when delegating to the zero address",
+                    code_hash.expr(),
+                    cb.empty_code_hash_rlc(),
+                );
+                #[cfg(feature = "scroll")]
+                cb.require_equal(
+                    "keccak code hash is empty when delegating to the zero address",
+                    keccak_code_hash.expr(),
+                    cb.empty_keccak_hash_rlc(),
+                );
+            });
+            cb.account_write(
+                authority.expr(),
+                AccountFieldTag::CodeHash,
+                code_hash.expr(),
+                code_hash_prev.expr(),
+                None,
+            );
+            #[cfg(feature = "scroll")]
+            {
+                cb.account_write(
+                    authority.expr(),
+                    AccountFieldTag::KeccakCodeHash,
+                    keccak_code_hash.expr(),
+                    keccak_code_hash_prev.expr(),
+                    None,
+                );
+                cb.account_write(
+                    authority.expr(),
+                    AccountFieldTag::CodeSize,
+                    select::expr(
+                        address_is_zero.expr(),
+                        0.expr(),
+                        DELEGATION_DESIGNATOR_LEN.expr(),
+                    ),
+                    code_size_prev.expr(),
+                    None,
+                );
+            }
+            cb.account_write(
+                authority.expr(),
+                AccountFieldTag::Nonce,
+                authority_nonce.expr() + 1.expr(),
+                authority_nonce.expr(),
+                None,
+            );
+        });
+
+        Self {
+            is_in_list,
+            authority,
+            authority_is_zero,
+            address,
+            address_is_zero,
+            nonce,
+            is_active,
+            is_warm_prev,
+            code_hash_prev,
+            code_hash_prev_is_zero,
+            code_hash_prev_is_empty,
+            is_delegated,
+            authority_nonce,
+            nonce_is_equal,
+            is_valid,
+            code_hash,
+            code_length,
+            delegate_address,
+            #[cfg(feature = "scroll")]
+            keccak_code_hash,
+            #[cfg(feature = "scroll")]
+            keccak_code_hash_prev,
+            #[cfg(feature = "scroll")]
+            code_size_prev,
+        }
+    }
+
+    fn assign(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        tx: &Transaction,
+        idx: usize,
+        rws: &mut StepRws,
+    ) -> Result<(), Error> {
+        let authorization = tx.authorization_list.get(idx);
+        self.is_in_list.assign(
+            region,
+            offset,
+            F::from(idx as u64),
+            F::from(tx.authorization_list.len() as u64),
+        )?;
+
+        let authority =
+            authorization.and_then(|authorization| authorization.authority(tx.chain_id));
+        let is_active = authority.is_some();
+        let (address, nonce) = authorization.map_or((Address::zero(), 0), |authorization| {
+            (authorization.address, authorization.nonce.as_u64())
+        });
+        let [authority, address] = [authority.unwrap_or_default(), address].map(|address| {
+            address
+                .to_scalar()
+                .expect("unexpected Address -> Scalar conversion failure")
+        });
+        self.authority
+            .assign(region, offset, Value::known(authority))?;
+        self.authority_is_zero.assign(region, offset, authority)?;
+        self.address.assign(region, offset, Value::known(address))?;
+        self.address_is_zero.assign(region, offset, address)?;
+        self.nonce
+            .assign(region, offset, Value::known(F::from(nonce)))?;
+
+        self.is_active
+            .assign(region, offset, Value::known(F::from(is_active as u64)))?;
+
+        let (is_warm_prev, code_hash_prev, authority_nonce) = if is_active {
+            let is_warm_prev = rws.next().tx_access_list_value_pair().1;
+            let code_hash_prev = rws.next().account_codehash_pair().0;
+            let authority_nonce = if code_hash_prev.is_zero() {
+                U256::zero()
+            } else {
+                rws.next().account_nonce_pair().0
+            };
+            (is_warm_prev, code_hash_prev, authority_nonce)
+        } else {
+            (false, U256::zero(), U256::zero())
+        };
+        self.is_warm_prev
+            .assign(region, offset, Value::known(F::from(is_warm_prev as u64)))?;
+        self.code_hash_prev
+            .assign(region, offset, region.code_hash(code_hash_prev))?;
+        self.code_hash_prev_is_zero.assign_value(
+            region,
+            offset,
+            region.code_hash(code_hash_prev),
+        )?;
+        self.code_hash_prev_is_empty.assign_value(
+            region,
+            offset,
+            region.code_hash(code_hash_prev),
+            region.empty_code_hash_rlc(),
+        )?;
+        let is_delegated = self
+            .is_delegated
+            .assign(region, offset, block, code_hash_prev)?;
+        self.authority_nonce.assign(
+            region,
+            offset,
+            Value::known(F::from(authority_nonce.as_u64())),
+        )?;
+        self.nonce_is_equal.assign(
+            region,
+            offset,
+            F::from(authority_nonce.as_u64()),
+            F::from(nonce),
+        )?;
+
+        let is_code_empty =
+            code_hash_prev.is_zero() || code_hash_prev == CodeDB::empty_code_hash().to_word();
+        let is_valid =
+            is_active && (is_code_empty || is_delegated) && authority_nonce.as_u64() == nonce;
+        self.is_valid
+            .assign(region, offset, Value::known(F::from(is_valid as u64)))?;
+
+        let code_hash = if is_valid {
+            rws.next().account_codehash_pair().0
+        } else {
+            U256::zero()
+        };
+        self.code_hash
+            .assign(region, offset, region.code_hash(code_hash))?;
+        let delegate_address =
+            authorization.map_or(Address::zero(), |authorization| authorization.address);
+        let designator_code_hash = if is_valid && !delegate_address.is_zero() {
+            code_hash
+        } else {
+            U256::zero()
+        };
+        self.code_length
+            .assign(region, offset, block, &designator_code_hash)?;
+        self.delegate_address
+            .assign(region, offset, delegate_address)?;
+        #[cfg(feature = "scroll")]
+        {
+            let (keccak_code_hash, keccak_code_hash_prev, code_size_prev) = if is_valid {
+                let (keccak_code_hash, keccak_code_hash_prev) =
+                    rws.next().account_keccak_codehash_pair();
+                let code_size_prev = rws.next().account_value_pair().1;
+                (keccak_code_hash, keccak_code_hash_prev, code_size_prev)
+            } else {
+                (U256::zero(), U256::zero(), U256::zero())
+            };
+            self.keccak_code_hash
+                .assign(region, offset, region.word_rlc(keccak_code_hash))?;
+            self.keccak_code_hash_prev.assign(
+                region,
+                offset,
+                region.word_rlc(keccak_code_hash_prev),
+            )?;
+            self.code_size_prev.assign(
+                region,
+                offset,
+                Value::known(F::from(code_size_prev.as_u64())),
+            )?;
+        }
+        if is_valid {
+            rws.next(); // Nonce
+        }
+
+        Ok(())
+    }
+
+    fn rw_delta(&self) -> Expression<F> {
+        // access list write, code hash read and nonce read if the authority exists
+        self.is_active.expr() * (2.expr() + not::expr(self.code_hash_prev_is_zero.expr()))
+            // code hash (keccak code hash and code size in scroll mode) and nonce writes
+            + self.is_valid.expr() * if cfg!(feature = "scroll") { 4.expr() } else { 2.expr() }
+    }
+}
+
+/// Gadget to tell whether a code is an EIP-7702 delegation designator, i.e. it is 23 bytes long
+/// and starts with 0xef. Since EIP-3541 forbids to deploy code starting with 0xef, such a code
+/// can only be a designator `0xef0100 || address`, whose bytes are checked by
+/// [`DelegateAddressGadget`].
+#[derive(Clone, Debug)]
+pub(crate) struct DelegationDesignatorGadget<F> {
+    code_length: BytecodeLengthGadget<F>,
+    is_designator_length: IsEqualGadget<F>,
+    has_designator_length: Cell<F>,
+    first_byte: Cell<F>,
+    first_byte_push_rlc: Cell<F>,
+    is_designator_prefix: IsEqualGadget<F>,
+    is_delegated: Cell<F>,
+}
+
+impl<F: Field> DelegationDesignatorGadget<F> {
+    /// `has_code` is true if the account with code `code_hash` exists, so that its code is in
+    /// the bytecode table.
+    pub(crate) fn construct(
+        cb: &mut EVMConstraintBuilder<F>,
+        code_hash: Cell<F>,
+        has_code: Expression<F>,
+    ) -> Self {
+        let code_length = cb.condition(has_code.expr(), |cb| {
+            BytecodeLengthGadget::construct(cb, code_hash.clone())
+        });
+        #[cfg(not(feature = "dual-bytecode"))]
+        cb.condition(has_code.expr(), |cb| {
+            cb.require_true(
+                "code is in the bytecode table",
+                code_length.is_first_bytecode_table.expr(),
+            );
+        });
+        let is_designator_length = IsEqualGadget::construct(
+            cb,
+            code_length.code_length.expr(),
+            DELEGATION_DESIGNATOR_LEN.expr(),
+        );
+        let has_designator_length = cb.query_bool();
+        cb.require_equal(
+            "has_designator_length == has_code && code_length == 23",
+            has_designator_length.expr(),
+            has_code * is_designator_length.expr(),
+        );
+
+        // The first byte is at a code position, and is a push if the code is not a designator.
+        let first_byte = cb.query_cell();
+        let first_byte_push_rlc = cb.query_cell_phase2();
+        cb.condition(has_designator_length.expr(), |cb| {
+            cb.condition(code_length.is_first_bytecode_table.expr(), |cb| {
+                cb.bytecode_lookup(
+                    code_hash.expr(),
+                    0.expr(),
+                    1.expr(),
+                    first_byte.expr(),
+                    first_byte_push_rlc.expr(),
+                );
+            });
+            #[cfg(feature = "dual-bytecode")]
+            cb.condition(
+                not::expr(code_length.is_first_bytecode_table.expr()),
+                |cb| {
+                    cb.bytecode_lookup1(
+                        code_hash.expr(),
+                        0.expr(),
+                        1.expr(),
+                        first_byte.expr(),
+                        first_byte_push_rlc.expr(),
+                    );
+                },
+            );
+        });
+        let is_designator_prefix =
+            IsEqualGadget::construct(cb, first_byte.expr(), DELEGATION_PREFIX[0].expr());
+
+        let is_delegated = cb.query_bool();
+        cb.require_equal(
+            "is_delegated == has_designator_length && code[0] == 0xef",
+            is_delegated.expr(),
+            has_designator_length.expr() * is_designator_prefix.expr(),
+        );
+
+        Self {
+            code_length,
+            is_designator_length,
+            has_designator_length,
+            first_byte,
+            first_byte_push_rlc,
+            is_designator_prefix,
+            is_delegated,
+        }
+    }
+
+    pub(crate) fn expr(&self) -> Expression<F> {
+        self.is_delegated.expr()
+    }
+
+    /// Assign the code with hash `code_hash`, which is zero if the account does not exist, and
+    /// return whether it is a delegation designator.
+    pub(crate) fn assign(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        block: &Block,
+        code_hash: U256,
+    ) -> Result<bool, Error> {
+        let code_length = self.code_length.assign(region, offset, block, &code_hash)?;
+        self.is_designator_length.assign(
+            region,
+            offset,
+            F::from(code_length),
+            F::from(DELEGATION_DESIGNATOR_LEN as u64),
+        )?;
+        let has_designator_length = code_length == DELEGATION_DESIGNATOR_LEN as u64;
+        self.has_designator_length.assign(
+            region,
+            offset,
+            Value::known(F::from(has_designator_length as u64)),
+        )?;
+
+        let (first_byte, first_byte_push_rlc) = if has_designator_length {
+            let (byte, _, push_rlc) =
+                block.bytecodes[&code_hash].get_byte_row(0, region.challenges());
+            (byte, push_rlc)
+        } else {
+            (0, Value::known(F::zero()))
+        };
+        self.first_byte
+            .assign(region, offset, Value::known(F::from(first_byte as u64)))?;
+        self.first_byte_push_rlc
+            .assign(region, offset, first_byte_push_rlc)?;
+        self.is_designator_prefix.assign(
+            region,
+            offset,
+            F::from(first_byte as u64),
+            F::from(DELEGATION_PREFIX[0] as u64),
+        )?;
+
+        let is_delegated = has_designator_length && first_byte == DELEGATION_PREFIX[0];
+        self.is_delegated
+            .assign(region, offset, Value::known(F::from(is_delegated as u64)))?;
+
+        Ok(is_delegated)
+    }
+}
+
+/// Gadget to read the delegate address of an EIP-7702 delegation designator
+/// `0xef0100 || address` through a copy of its bytecode into an RLC.
+#[derive(Clone, Debug)]
+pub(crate) struct DelegateAddressGadget<F> {
+    // big-endian bytes of the address
+    bytes: [Cell<F>; N_BYTES_ACCOUNT_ADDRESS],
+}
+
+impl<F: Field> DelegateAddressGadget<F> {
+    /// The copy is only looked up if `is_delegated` is true.
+    pub(crate) fn construct(
+        cb: &mut EVMConstraintBuilder<F>,
+        code_hash: Expression<F>,
+        is_delegated: Expression<F>,
+    ) -> Self {
+        let bytes = cb.query_bytes();
+
+        cb.condition(is_delegated, |cb| {
+            // The RLC of the copied bytes starts with the first byte.
+            let designator_le = bytes
+                .iter()
+                .rev()
+                .map(Expr::expr)
+                .chain(DELEGATION_PREFIX.iter().rev().map(|byte| byte.expr()))
+                .collect::<Vec<_>>();
+            let designator_rlc = rlc::expr(&designator_le, cb.challenges().keccak_input());
+            cb.copy_table_lookup(
+                code_hash.expr(),
+                CopyDataType::Bytecode.expr(),
+                code_hash,
+                CopyDataType::RlcAcc.expr(),
+                0.expr(),
+                DELEGATION_DESIGNATOR_LEN.expr(),
+                0.expr(), // dst_addr for CopyDataType::RlcAcc is 0.
+                DELEGATION_DESIGNATOR_LEN.expr(),
+                designator_rlc,
+                0.expr(),
+            );
+        });
+
+        Self { bytes }
+    }
+
+    pub(crate) fn expr(&self) -> Expression<F> {
+        from_bytes::expr(&self.bytes.iter().rev().cloned().collect::<Vec<_>>())
+    }
+
+    pub(crate) fn assign(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        address: Address,
+    ) -> Result<(), Error> {
+        for (cell, byte) in self.bytes.iter().zip(address.to_fixed_bytes()) {
+            cell.assign(region, offset, Value::known(F::from(byte as u64)))?;
+        }
+        Ok(())
+    }
+}
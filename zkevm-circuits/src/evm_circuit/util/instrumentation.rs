@@ -109,6 +109,12 @@ impl Instrument {
                     CellType::Lookup(Table::Sha256) => {
                         report.sha256_table = data_entry;
                     }
+                    CellType::Lookup(Table::Ripemd160) => {
+                        report.ripemd160_table = data_entry;
+                    }
+                    CellType::Lookup(Table::Blake2f) => {
+                        report.blake2f_table = data_entry;
+                    }
                     CellType::Lookup(Table::Exp) => {
                         report.exp_table = data_entry;
                     }
@@ -152,6 +158,8 @@ pub(crate) struct ExecStateReport {
     pub(crate) copy_table: StateReportRow,
     pub(crate) keccak_table: StateReportRow,
     pub(crate) sha256_table: StateReportRow,
+    pub(crate) ripemd160_table: StateReportRow,
+    pub(crate) blake2f_table: StateReportRow,
     pub(crate) exp_table: StateReportRow,
     pub(crate) sig_table: StateReportRow,
     pub(crate) modexp_table: StateReportRow,
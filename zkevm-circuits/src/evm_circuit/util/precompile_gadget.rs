@@ -1,19 +1,70 @@
 use crate::util::Field;
 use bus_mapping::precompile::PrecompileCalls;
-use gadgets::util::{and, not, Expr};
-use halo2_proofs::plonk::Expression;
+use gadgets::util::{and, not, sum, Expr};
+use halo2_proofs::{circuit::Value, plonk::Expression};
+use strum::IntoEnumIterator;
 
 use crate::evm_circuit::step::{ExecutionState, ExecutionState::ErrorOutOfGasPrecompile};
 
 use super::{
     constraint_builder::{ConstrainBuilderCommon, EVMConstraintBuilder},
     math_gadget::BinaryNumberGadget,
-    CachedRegion,
+    CachedRegion, Cell,
 };
 
+/// Binary representation of a precompile address. All precompiles but the secp256r1 verification
+/// one (0x100) live in 0x01..=0x0f, so the address is decomposed as `0x100 * is_p256_verify +
+/// low_bits`.
+#[derive(Clone, Debug)]
+pub struct PrecompileAddressGadget<F> {
+    is_p256_verify: Cell<F>,
+    low_bits: BinaryNumberGadget<F, 4>,
+}
+
+impl<F: Field> PrecompileAddressGadget<F> {
+    pub(crate) fn construct(cb: &mut EVMConstraintBuilder<F>, address: Expression<F>) -> Self {
+        let is_p256_verify = cb.query_bool();
+        let low_bits = BinaryNumberGadget::construct(
+            cb,
+            address - is_p256_verify.expr() * PrecompileCalls::P256Verify.address().expr(),
+        );
+
+        Self {
+            is_p256_verify,
+            low_bits,
+        }
+    }
+
+    pub(crate) fn value_equals(&self, precompile: PrecompileCalls) -> Expression<F> {
+        let is_p256_verify = if precompile == PrecompileCalls::P256Verify {
+            self.is_p256_verify.expr()
+        } else {
+            not::expr(self.is_p256_verify.expr())
+        };
+        // the low bits of 0x100 are all zero.
+        and::expr([is_p256_verify, self.low_bits.value_equals(precompile)])
+    }
+
+    pub(crate) fn assign(
+        &self,
+        region: &mut CachedRegion<'_, '_, F>,
+        offset: usize,
+        precompile: PrecompileCalls,
+    ) -> Result<(), halo2_proofs::plonk::Error> {
+        let is_p256_verify = precompile == PrecompileCalls::P256Verify;
+        self.is_p256_verify.assign(
+            region,
+            offset,
+            Value::known(F::from(is_p256_verify as u64)),
+        )?;
+        self.low_bits
+            .assign(region, offset, (precompile.address() & 0x0f) as usize)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct PrecompileGadget<F> {
-    address: BinaryNumberGadget<F, 4>,
+    address: PrecompileAddressGadget<F>,
 }
 
 impl<F: Field> PrecompileGadget<F> {
@@ -25,7 +76,7 @@ impl<F: Field> PrecompileGadget<F> {
         output_bytes_rlc: Option<Expression<F>>,
         return_bytes_rlc: Option<Expression<F>>,
     ) -> Self {
-        let address = BinaryNumberGadget::construct(cb, callee_address.expr());
+        let address = PrecompileAddressGadget::construct(cb, callee_address.expr());
 
         macro_rules! constrain_next_state {
             ($cb:ident, $precompile_call_type:ident, $precompile_exec_state:ident) => {
@@ -49,6 +100,7 @@ impl<F: Field> PrecompileGadget<F> {
         constrain_next_state!(cb, Bn128Mul, PrecompileBn256ScalarMul);
         constrain_next_state!(cb, Bn128Pairing, PrecompileBn256Pairing);
         constrain_next_state!(cb, Blake2F, PrecompileBlake2f);
+        constrain_next_state!(cb, P256Verify, PrecompileP256Verify);
 
         // Without constraining the next step's state, only constrain the first two Phase2 cells,
         // i.e. RLC(input_bytes) and RLC(return_bytes)
@@ -86,6 +138,31 @@ impl<F: Field> PrecompileGadget<F> {
         Self { address }
     }
 
+    /// Returns `(has_limit, limit)`, where `has_limit` is 1 if the precompile only takes a fixed
+    /// number of input bytes (see `PrecompileCalls::input_len`) and `limit` is that number, or 0.
+    pub(crate) fn input_len_limit(&self) -> (Expression<F>, Expression<F>) {
+        let limited = PrecompileCalls::iter()
+            .filter_map(|precompile| {
+                precompile
+                    .input_len()
+                    .map(|input_len| (self.address.value_equals(precompile), input_len))
+            })
+            .collect::<Vec<_>>();
+
+        (
+            sum::expr(
+                limited
+                    .iter()
+                    .map(|(is_precompile, _)| is_precompile.clone()),
+            ),
+            sum::expr(
+                limited
+                    .iter()
+                    .map(|(is_precompile, input_len)| is_precompile.clone() * input_len.expr()),
+            ),
+        )
+    }
+
     pub(crate) fn assign(
         &self,
         region: &mut CachedRegion<'_, '_, F>,
@@ -25,6 +25,7 @@
 #[cfg(not(target_pointer_width = "64"))]
 compile_error!("This program requires a 64-bit target architecture.");
 
+pub mod blake2f_circuit;
 pub mod bytecode_circuit;
 pub mod copy_circuit;
 pub mod ecc_circuit;
@@ -39,6 +40,7 @@ pub mod sig_circuit;
 // we don't use this for aggregation
 //pub mod root_circuit;
 pub mod modexp_circuit;
+pub mod ripemd160_circuit;
 pub mod sha256_circuit;
 pub mod state_circuit;
 pub mod super_circuit;
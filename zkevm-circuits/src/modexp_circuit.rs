@@ -1,212 +1,1363 @@
 //! The Modexp circuit is responsible for modexp operations on big integer from precompiled contract
-//! calls ModExp, current the size of supported integer is up to 32 bytes (U256)
+//! calls ModExp, supporting operands of up to `MODEXP_SIZE_LIMIT` bytes.
+//!
+//! Each call takes a section of rows: first one row per byte of its input (following the lengths
+//! header), in which the operands are split off and accumulated into RLCs, then, if the call
+//! succeeded with a non-zero modulus, a chain of blocks computing the result by square and
+//! multiply. The values of the call are exposed on the last row of the section, with `q_head` set.
+//!
+//! A big integer of `n` bytes is handled as the polynomial `A(x)` whose coefficients are its
+//! bytes, such that its RLC is `A(r)` and its value `A(256)`. A block of rows checks a modular
+//! multiplication `a * b = q * m + r` by having the prover provide the digits of `q` and `r`,
+//! together with carries `c` such that
+//!
+//! `A(x) * B(x) - Q(x) * M(x) - R(x) = (x - 256) * C(x)`,
+//!
+//! i.e. the left hand side has 256 as a root, which is checked at the random point `x = r` with
+//! the RLCs evaluated by Horner's method across the rows of the block. With the digits of `q` and
+//! `r` being bytes and the carries being small, the identity holds over the integers. The last
+//! block of a chain checks the result is less than the modulus.
 
 #[cfg(any(feature = "test", test, feature = "test-circuits"))]
 mod dev;
 #[cfg(any(feature = "test", test, feature = "test-circuits"))]
 mod test;
 
+use std::marker::PhantomData;
+
+use bus_mapping::{circuit_input_builder::BigModExp, precompile::MODEXP_INPUT_LIMIT};
+use gadgets::{
+    is_equal::{IsEqualChip, IsEqualConfig, IsEqualInstruction},
+    is_zero::{IsZeroChip, IsZeroConfig, IsZeroInstruction},
+    util::{and, not, sum, Expr},
+};
 use halo2_proofs::{
     circuit::{Layouter, Region, Value},
-    plonk::{Advice, Column, ConstraintSystem, Error},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, VirtualCells},
+    poly::Rotation,
 };
+use num::{BigUint, One, Zero};
+
+#[cfg(feature = "onephase")]
+use halo2_proofs::plonk::FirstPhase as SecondPhase;
+#[cfg(not(feature = "onephase"))]
+use halo2_proofs::plonk::SecondPhase;
 
 use crate::{
-    table::ModExpTable,
-    util::{Challenges, Field, SubCircuit, SubCircuitConfig},
+    evm_circuit::util::constraint_builder::{BaseConstraintBuilder, ConstrainBuilderCommon},
+    table::{LookupTable, ModExpTable, U16Table, U8Table},
+    util::{rlc_be_bytes, Challenges, Field, SubCircuit, SubCircuitConfig},
     witness,
 };
-use bus_mapping::circuit_input_builder::BigModExp;
-use eth_types::Word;
-
-//use misc_precompiled_circuit::value_for_assign;
-use misc_precompiled_circuit::circuits::{
-    modexp::{ModExpChip, Number},
-    range::{RangeCheckChip, RangeCheckConfig},
-    CommonGateConfig as ModExpConfig, Limb,
-};
+
+/// Number of input bytes following the lengths header, i.e. the number of input rows of a call.
+const INPUT_BYTES: usize = MODEXP_INPUT_LIMIT - 96;
+
+/// Offset added to the carries of a block to make them non-negative, they are then range checked
+/// to 32 bits.
+const CARRY_OFFSET: u64 = 1 << 31;
+
+/// The kind of a block of rows in the chain of a call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum BlockKind {
+    /// `acc * acc = q * modulus + r`
+    Square,
+    /// `acc * base = q * modulus + r`
+    Multiply,
+    /// `acc * 1 = q * modulus + r`, reducing a zero exponent result of 1 modulo the modulus.
+    Reduce,
+    /// `modulus - acc = r` with `r != 0`, i.e. the result `acc` is less than the modulus.
+    Lt,
+}
 
 /// ModExp circuit config
 #[derive(Clone, Debug)]
-pub struct ModExpCircuitConfig {
-    modexp_config: ModExpConfig,
-    rangecheck_config: RangeCheckConfig,
+pub struct ModExpCircuitConfig<F> {
+    q_enable: Column<Fixed>,
+    q_first: Column<Fixed>,
+
+    // input rows
+    is_input: Column<Advice>,
+    is_base: Column<Advice>,
+    is_exp: Column<Advice>,
+    is_modulus: Column<Advice>,
+    /// Whether the byte is among the leading 32 bytes of exp.
+    is_exp_head: Column<Advice>,
+    byte: Column<Advice>,
+    index: Column<Advice>,
+    is_last_input: IsEqualConfig<F>,
+    base_count: Column<Advice>,
+    exp_count: Column<Advice>,
+    exp_head_count: Column<Advice>,
+    modulus_count: Column<Advice>,
+    base_rlc: Column<Advice>,
+    exp_rlc: Column<Advice>,
+    modulus_rlc: Column<Advice>,
+    modulus_is_zero: IsZeroConfig<F>,
+
+    // chain rows
+    is_square: Column<Advice>,
+    is_multiply: Column<Advice>,
+    is_reduce: Column<Advice>,
+    is_lt: Column<Advice>,
+    is_block_end: Column<Advice>,
+    q_digit: Column<Advice>,
+    r_digit: Column<Advice>,
+    carry_lo: Column<Advice>,
+    carry_hi: Column<Advice>,
+    q_eval: Column<Advice>,
+    r_eval: Column<Advice>,
+    c_eval: Column<Advice>,
+    /// RLC of the accumulated power the block operates on.
+    acc: Column<Advice>,
+    /// The bit of exp a square block is for.
+    bit: Column<Advice>,
+    /// The value of the bits of the current exp byte processed so far.
+    exp_byte: Column<Advice>,
+    /// The number of bits of the current exp byte processed so far.
+    bit_index: Column<Advice>,
+    is_byte_done: IsEqualConfig<F>,
+    /// RLC of the bytes of exp processed so far.
+    exp_chain_rlc: Column<Advice>,
+    lt_inv: Column<Advice>,
+
     modexp_table: ModExpTable,
 }
 
-impl<F: Field> SubCircuitConfig<F> for ModExpCircuitConfig {
-    type ConfigArgs = ModExpTable;
+/// Arguments to configure the modexp circuit.
+#[derive(Clone, Debug)]
+pub struct ModExpCircuitConfigArgs<F: Field> {
+    /// ModExpTable
+    pub modexp_table: ModExpTable,
+    /// u8 range table
+    pub u8_table: U8Table,
+    /// u16 range table
+    pub u16_table: U16Table,
+    /// Challenges
+    pub challenges: Challenges<Expression<F>>,
+}
+
+/// The cells of a row of the circuit, queried at some rotation.
+struct ModExpRow<F> {
+    q_head: Expression<F>,
+    input_rlc: Expression<F>,
+    lens: [Expression<F>; 3],
+    exp_head_rlc: Expression<F>,
+    is_success: Expression<F>,
+    result_rlc: Expression<F>,
+
+    is_input: Expression<F>,
+    is_base: Expression<F>,
+    is_exp: Expression<F>,
+    is_modulus: Expression<F>,
+    is_exp_head: Expression<F>,
+    byte: Expression<F>,
+    index: Expression<F>,
+    base_count: Expression<F>,
+    exp_count: Expression<F>,
+    exp_head_count: Expression<F>,
+    modulus_count: Expression<F>,
+    base_rlc: Expression<F>,
+    exp_rlc: Expression<F>,
+    modulus_rlc: Expression<F>,
+
+    is_square: Expression<F>,
+    is_multiply: Expression<F>,
+    is_reduce: Expression<F>,
+    is_lt: Expression<F>,
+    is_block_end: Expression<F>,
+    q_digit: Expression<F>,
+    r_digit: Expression<F>,
+    carry: Expression<F>,
+    q_eval: Expression<F>,
+    r_eval: Expression<F>,
+    c_eval: Expression<F>,
+    acc: Expression<F>,
+    bit: Expression<F>,
+    exp_byte: Expression<F>,
+    bit_index: Expression<F>,
+    exp_chain_rlc: Expression<F>,
+    lt_inv: Expression<F>,
+}
+
+impl<F: Field> ModExpRow<F> {
+    fn is_operand(&self) -> Expression<F> {
+        sum::expr([
+            self.is_base.expr(),
+            self.is_exp.expr(),
+            self.is_modulus.expr(),
+        ])
+    }
+
+    fn is_chain(&self) -> Expression<F> {
+        sum::expr([
+            self.is_square.expr(),
+            self.is_multiply.expr(),
+            self.is_reduce.expr(),
+            self.is_lt.expr(),
+        ])
+    }
+
+    /// The values of the call, which are the same across its rows.
+    fn call_values(&self) -> [Expression<F>; 5] {
+        [
+            self.lens[0].expr(),
+            self.lens[1].expr(),
+            self.lens[2].expr(),
+            self.is_success.expr(),
+            self.result_rlc.expr(),
+        ]
+    }
+
+    /// The RLCs of the input of the call, which are final once the input rows are over.
+    fn input_rlcs(&self) -> [Expression<F>; 5] {
+        [
+            self.input_rlc.expr(),
+            self.exp_head_rlc.expr(),
+            self.base_rlc.expr(),
+            self.exp_rlc.expr(),
+            self.modulus_rlc.expr(),
+        ]
+    }
+
+    /// The exp bits processed at the row.
+    fn exp_state(&self) -> [Expression<F>; 3] {
+        [
+            self.exp_byte.expr(),
+            self.bit_index.expr(),
+            self.exp_chain_rlc.expr(),
+        ]
+    }
+
+    /// Constrain the row to be the first input row of a call.
+    fn constrain_event_start(&self, cb: &mut BaseConstraintBuilder<F>) {
+        cb.require_zero("index starts at 0", self.index.expr());
+        cb.require_equal(
+            "input_rlc starts with the byte",
+            self.input_rlc.expr(),
+            self.byte.expr(),
+        );
+        for (flag, count, rlc) in [
+            (&self.is_base, &self.base_count, &self.base_rlc),
+            (&self.is_exp, &self.exp_count, &self.exp_rlc),
+            (&self.is_exp_head, &self.exp_head_count, &self.exp_head_rlc),
+            (&self.is_modulus, &self.modulus_count, &self.modulus_rlc),
+        ] {
+            cb.require_equal(
+                "operand count starts at its flag",
+                count.expr(),
+                flag.expr(),
+            );
+            cb.require_equal(
+                "operand rlc starts with the byte if flagged",
+                rlc.expr(),
+                flag.expr() * self.byte.expr(),
+            );
+        }
+        cb.require_zero(
+            "exp head starts along with exp",
+            self.is_exp.expr() * not::expr(self.is_exp_head.expr()),
+        );
+    }
+}
+
+impl<F: Field> ModExpCircuitConfig<F> {
+    fn query_row(&self, meta: &mut VirtualCells<F>, at: Rotation) -> ModExpRow<F> {
+        let table = &self.modexp_table;
+        let mut query = |column: Column<Advice>| meta.query_advice(column, at);
+        ModExpRow {
+            q_head: query(table.q_head),
+            input_rlc: query(table.input_rlc),
+            lens: [
+                query(table.base_len),
+                query(table.exp_len),
+                query(table.modulus_len),
+            ],
+            exp_head_rlc: query(table.exp_head_rlc),
+            is_success: query(table.is_success),
+            result_rlc: query(table.result_rlc),
+
+            is_input: query(self.is_input),
+            is_base: query(self.is_base),
+            is_exp: query(self.is_exp),
+            is_modulus: query(self.is_modulus),
+            is_exp_head: query(self.is_exp_head),
+            byte: query(self.byte),
+            index: query(self.index),
+            base_count: query(self.base_count),
+            exp_count: query(self.exp_count),
+            exp_head_count: query(self.exp_head_count),
+            modulus_count: query(self.modulus_count),
+            base_rlc: query(self.base_rlc),
+            exp_rlc: query(self.exp_rlc),
+            modulus_rlc: query(self.modulus_rlc),
+
+            is_square: query(self.is_square),
+            is_multiply: query(self.is_multiply),
+            is_reduce: query(self.is_reduce),
+            is_lt: query(self.is_lt),
+            is_block_end: query(self.is_block_end),
+            q_digit: query(self.q_digit),
+            r_digit: query(self.r_digit),
+            carry: query(self.carry_lo) + query(self.carry_hi) * (1u64 << 16).expr()
+                - CARRY_OFFSET.expr(),
+            q_eval: query(self.q_eval),
+            r_eval: query(self.r_eval),
+            c_eval: query(self.c_eval),
+            acc: query(self.acc),
+            bit: query(self.bit),
+            exp_byte: query(self.exp_byte),
+            bit_index: query(self.bit_index),
+            exp_chain_rlc: query(self.exp_chain_rlc),
+            lt_inv: query(self.lt_inv),
+        }
+    }
+}
+
+impl<F: Field> SubCircuitConfig<F> for ModExpCircuitConfig<F> {
+    type ConfigArgs = ModExpCircuitConfigArgs<F>;
 
     /// Return a new ModExpCircuitConfig
-    fn new(meta: &mut ConstraintSystem<F>, modexp_table: Self::ConfigArgs) -> Self {
-        let rangecheck_config = RangeCheckChip::configure(meta);
-        let modexp_config = ModExpChip::configure(meta, &rangecheck_config);
-        Self {
-            modexp_config,
-            rangecheck_config,
+    fn new(
+        meta: &mut ConstraintSystem<F>,
+        ModExpCircuitConfigArgs {
+            modexp_table,
+            u8_table,
+            u16_table,
+            challenges,
+        }: Self::ConfigArgs,
+    ) -> Self {
+        let q_enable = meta.fixed_column();
+        let q_first = meta.fixed_column();
+        let [is_input, is_base, is_exp, is_modulus, is_exp_head, byte, index, base_count, exp_count, exp_head_count, modulus_count, is_square, is_multiply, is_reduce, is_lt, is_block_end, q_digit, r_digit, carry_lo, carry_hi, bit, exp_byte, bit_index] =
+            std::array::from_fn(|_| meta.advice_column());
+        let [base_rlc, exp_rlc, modulus_rlc, q_eval, r_eval, c_eval, acc, exp_chain_rlc, lt_inv] =
+            std::array::from_fn(|_| meta.advice_column_in(SecondPhase));
+
+        let is_last_input = IsEqualChip::configure(
+            meta,
+            |meta| {
+                and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_input, Rotation::cur()),
+                ])
+            },
+            |meta| meta.query_advice(index, Rotation::cur()),
+            |_| (INPUT_BYTES - 1).expr(),
+        );
+        let modulus_is_zero = IsZeroChip::configure(
+            meta,
+            |meta| {
+                and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_input, Rotation::cur()),
+                    is_last_input.expr(),
+                ])
+            },
+            |meta| meta.query_advice(modulus_rlc, Rotation::cur()),
+            meta.advice_column_in(SecondPhase),
+        );
+        let is_byte_done = IsEqualChip::configure(
+            meta,
+            |meta| {
+                and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_square, Rotation::cur()),
+                    meta.query_advice(is_block_end, Rotation::cur()),
+                ])
+            },
+            |meta| meta.query_advice(bit_index, Rotation::cur()),
+            |_| 7.expr(),
+        );
+
+        let config = Self {
+            q_enable,
+            q_first,
+            is_input,
+            is_base,
+            is_exp,
+            is_modulus,
+            is_exp_head,
+            byte,
+            index,
+            is_last_input,
+            base_count,
+            exp_count,
+            exp_head_count,
+            modulus_count,
+            base_rlc,
+            exp_rlc,
+            modulus_rlc,
+            modulus_is_zero,
+            is_square,
+            is_multiply,
+            is_reduce,
+            is_lt,
+            is_block_end,
+            q_digit,
+            r_digit,
+            carry_lo,
+            carry_hi,
+            q_eval,
+            r_eval,
+            c_eval,
+            acc,
+            bit,
+            exp_byte,
+            bit_index,
+            is_byte_done,
+            exp_chain_rlc,
+            lt_inv,
             modexp_table,
+        };
+
+        meta.create_gate("modexp circuit: row", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+            let cur = config.query_row(meta, Rotation::cur());
+            let keccak_input = challenges.keccak_input();
+
+            for flag in [
+                &cur.q_head,
+                &cur.is_success,
+                &cur.is_input,
+                &cur.is_base,
+                &cur.is_exp,
+                &cur.is_modulus,
+                &cur.is_exp_head,
+                &cur.is_square,
+                &cur.is_multiply,
+                &cur.is_reduce,
+                &cur.is_lt,
+                &cur.is_block_end,
+                &cur.bit,
+            ] {
+                cb.require_boolean("flag is boolean", flag.expr());
+            }
+            cb.require_boolean("operand flags are exclusive", cur.is_operand());
+            cb.require_zero(
+                "operands are part of the input",
+                cur.is_operand() * not::expr(cur.is_input.expr()),
+            );
+            cb.require_zero(
+                "exp head is part of exp",
+                cur.is_exp_head.expr() * not::expr(cur.is_exp.expr()),
+            );
+            cb.require_boolean("block kinds are exclusive", cur.is_chain());
+            cb.require_zero(
+                "chain rows are not input rows",
+                cur.is_chain() * cur.is_input.expr(),
+            );
+            cb.require_zero(
+                "block ends are chain rows",
+                cur.is_block_end.expr() * not::expr(cur.is_chain()),
+            );
+
+            // the values of the call are exposed at the end of its rows.
+            let is_last_input = and::expr([cur.is_input.expr(), config.is_last_input.expr()]);
+            cb.require_zero(
+                "q_head is unset before the last input row",
+                cur.is_input.expr() * not::expr(config.is_last_input.expr()) * cur.q_head.expr(),
+            );
+            cb.condition(is_last_input.expr(), |cb| {
+                cb.require_equal(
+                    "q_head is set at the last input row if there is no chain",
+                    cur.q_head.expr(),
+                    not::expr(cur.is_success.expr() * not::expr(config.modulus_is_zero.expr())),
+                );
+                for (count, len) in [
+                    (&cur.base_count, &cur.lens[0]),
+                    (&cur.exp_count, &cur.lens[1]),
+                    (&cur.modulus_count, &cur.lens[2]),
+                ] {
+                    cb.require_equal("operand count is its len", count.expr(), len.expr());
+                }
+                cb.require_zero(
+                    "exp head is the leading 32 bytes of exp, or all of it",
+                    (cur.exp_head_count.expr() - 32.expr())
+                        * (cur.exp_head_count.expr() - cur.lens[1].expr()),
+                );
+                cb.require_zero(
+                    "result is 0 without chain",
+                    cur.q_head.expr() * cur.result_rlc.expr(),
+                );
+            });
+            cb.require_equal(
+                "q_head is set at the end of the chain",
+                cur.is_chain() * cur.q_head.expr(),
+                cur.is_lt.expr() * cur.is_block_end.expr(),
+            );
+            cb.require_zero(
+                "q_head is unset at padding rows",
+                not::expr(cur.is_input.expr() + cur.is_chain()) * cur.q_head.expr(),
+            );
+
+            // block identities, see the module docs.
+            cb.condition(cur.is_block_end.expr(), |cb| {
+                let q_m_r_c = |modulus: Expression<F>| {
+                    cur.q_eval.expr() * modulus
+                        + cur.r_eval.expr()
+                        + (keccak_input.expr() - 256.expr()) * cur.c_eval.expr()
+                };
+                for (name, flag, lhs) in [
+                    (
+                        "acc * acc = q * modulus + r",
+                        &cur.is_square,
+                        cur.acc.expr() * cur.acc.expr(),
+                    ),
+                    (
+                        "acc * base = q * modulus + r",
+                        &cur.is_multiply,
+                        cur.acc.expr() * cur.base_rlc.expr(),
+                    ),
+                    ("acc * 1 = q * modulus + r", &cur.is_reduce, cur.acc.expr()),
+                ] {
+                    cb.require_zero(name, flag.expr() * (lhs - q_m_r_c(cur.modulus_rlc.expr())));
+                }
+                cb.require_zero(
+                    "modulus - acc = r",
+                    cur.is_lt.expr()
+                        * (cur.modulus_rlc.expr() - cur.acc.expr() - q_m_r_c(0.expr())),
+                );
+                cb.require_zero(
+                    "modulus - acc != 0",
+                    cur.is_lt.expr() * (cur.r_eval.expr() * cur.lt_inv.expr() - 1.expr()),
+                );
+            });
+            cb.condition(cur.is_block_end.expr() * cur.is_reduce.expr(), |cb| {
+                cb.require_equal(
+                    "all bytes of exp are processed",
+                    cur.exp_chain_rlc.expr(),
+                    cur.exp_rlc.expr(),
+                );
+                cb.require_zero("no partial exp byte is left", cur.bit_index.expr());
+                cb.require_equal(
+                    "result is the reduced power",
+                    cur.result_rlc.expr(),
+                    cur.r_eval.expr(),
+                );
+            });
+
+            cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
+        });
+
+        meta.create_gate("modexp circuit: first row", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+            let cur = config.query_row(meta, Rotation::cur());
+
+            cb.require_zero("first row is not a chain row", cur.is_chain());
+            cb.condition(cur.is_input.expr(), |cb| cur.constrain_event_start(cb));
+
+            cb.gate(meta.query_fixed(q_first, Rotation::cur()))
+        });
+
+        meta.create_gate("modexp circuit: transition", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+            let cur = config.query_row(meta, Rotation::cur());
+            let next = config.query_row(meta, Rotation::next());
+            let keccak_input = challenges.keccak_input();
+
+            cb.require_zero(
+                "padding rows are followed by padding rows",
+                not::expr(cur.is_input.expr() + cur.is_chain())
+                    * (next.is_input.expr() + next.is_chain()),
+            );
+            cb.require_zero(
+                "a call is not followed by chain rows",
+                cur.q_head.expr() * next.is_chain(),
+            );
+            cb.condition(cur.q_head.expr() * next.is_input.expr(), |cb| {
+                next.constrain_event_start(cb)
+            });
+
+            let is_same_call =
+                (cur.is_input.expr() + cur.is_chain()) * not::expr(cur.q_head.expr());
+            cb.condition(is_same_call, |cb| {
+                cb.require_equal(
+                    "the rows of a call continue",
+                    next.is_input.expr() + next.is_chain(),
+                    1.expr(),
+                );
+                for (cur_value, next_value) in cur.call_values().into_iter().zip(next.call_values())
+                {
+                    cb.require_equal("the values of a call are kept", next_value, cur_value);
+                }
+            });
+
+            // input rows
+            cb.condition(
+                cur.is_input.expr() * not::expr(config.is_last_input.expr()),
+                |cb| {
+                    cb.require_equal("input continues", next.is_input.expr(), 1.expr());
+                    cb.require_equal(
+                        "index increases",
+                        next.index.expr(),
+                        cur.index.expr() + 1.expr(),
+                    );
+                    cb.require_equal(
+                        "input_rlc accumulates the bytes",
+                        next.input_rlc.expr(),
+                        cur.input_rlc.expr() * keccak_input.expr() + next.byte.expr(),
+                    );
+                    cb.require_zero(
+                        "base is a prefix of the input",
+                        next.is_base.expr() * not::expr(cur.is_base.expr()),
+                    );
+                    cb.require_zero(
+                        "exp follows base",
+                        next.is_exp.expr() * not::expr(cur.is_base.expr() + cur.is_exp.expr()),
+                    );
+                    cb.require_zero(
+                        "modulus follows exp",
+                        next.is_modulus.expr() * not::expr(cur.is_operand()),
+                    );
+                    cb.require_zero(
+                        "exp head starts along with exp",
+                        next.is_exp.expr()
+                            * not::expr(cur.is_exp.expr())
+                            * not::expr(next.is_exp_head.expr()),
+                    );
+                    cb.require_zero(
+                        "exp head is a prefix of exp",
+                        cur.is_exp.expr()
+                            * next.is_exp_head.expr()
+                            * not::expr(cur.is_exp_head.expr()),
+                    );
+                    for (flag, count, cur_rlc, next_rlc) in [
+                        (
+                            &next.is_base,
+                            [&cur.base_count, &next.base_count],
+                            &cur.base_rlc,
+                            &next.base_rlc,
+                        ),
+                        (
+                            &next.is_exp,
+                            [&cur.exp_count, &next.exp_count],
+                            &cur.exp_rlc,
+                            &next.exp_rlc,
+                        ),
+                        (
+                            &next.is_exp_head,
+                            [&cur.exp_head_count, &next.exp_head_count],
+                            &cur.exp_head_rlc,
+                            &next.exp_head_rlc,
+                        ),
+                        (
+                            &next.is_modulus,
+                            [&cur.modulus_count, &next.modulus_count],
+                            &cur.modulus_rlc,
+                            &next.modulus_rlc,
+                        ),
+                    ] {
+                        cb.require_equal(
+                            "operand count increases by its flag",
+                            count[1].expr(),
+                            count[0].expr() + flag.expr(),
+                        );
+                        cb.require_equal(
+                            "operand rlc accumulates the byte if flagged",
+                            next_rlc.expr(),
+                            cur_rlc.expr()
+                                + flag.expr()
+                                    * (cur_rlc.expr() * (keccak_input.expr() - 1.expr())
+                                        + next.byte.expr()),
+                        );
+                    }
+                },
+            );
+
+            // the chain starts with acc = 1 and no exp bits processed.
+            cb.condition(
+                and::expr([
+                    cur.is_input.expr(),
+                    config.is_last_input.expr(),
+                    not::expr(cur.q_head.expr()),
+                ]),
+                |cb| {
+                    cb.require_equal(
+                        "chain starts with a square or reduce block",
+                        next.is_square.expr() + next.is_reduce.expr(),
+                        1.expr(),
+                    );
+                    cb.require_equal("chain starts with acc = 1", next.acc.expr(), 1.expr());
+                    for value in next.exp_state() {
+                        cb.require_zero("chain starts with no exp bits processed", value);
+                    }
+                },
+            );
+
+            // chain rows
+            cb.condition(next.is_chain(), |cb| {
+                for (cur_value, next_value) in cur.input_rlcs().into_iter().zip(next.input_rlcs()) {
+                    cb.require_equal("input rlcs are kept along the chain", next_value, cur_value);
+                }
+            });
+            cb.condition(cur.is_chain() * not::expr(cur.is_block_end.expr()), |cb| {
+                for (cur_value, next_value) in [
+                    (&cur.is_square, &next.is_square),
+                    (&cur.is_multiply, &next.is_multiply),
+                    (&cur.is_reduce, &next.is_reduce),
+                    (&cur.is_lt, &next.is_lt),
+                    (&cur.acc, &next.acc),
+                    (&cur.bit, &next.bit),
+                ] {
+                    cb.require_equal("block values are kept", next_value.expr(), cur_value.expr());
+                }
+                for (cur_value, next_value) in cur.exp_state().into_iter().zip(next.exp_state()) {
+                    cb.require_equal("exp state is kept along a block", next_value, cur_value);
+                }
+                for (cur_eval, next_eval, next_digit) in [
+                    (&cur.q_eval, &next.q_eval, &next.q_digit),
+                    (&cur.r_eval, &next.r_eval, &next.r_digit),
+                    (&cur.c_eval, &next.c_eval, &next.carry),
+                ] {
+                    cb.require_equal(
+                        "evaluation accumulates the digits",
+                        next_eval.expr(),
+                        cur_eval.expr() * keccak_input.expr() + next_digit.expr(),
+                    );
+                }
+            });
+            cb.condition(
+                next.is_chain() * not::expr(cur.is_chain() * not::expr(cur.is_block_end.expr())),
+                |cb| {
+                    for (next_eval, next_digit) in [
+                        (&next.q_eval, &next.q_digit),
+                        (&next.r_eval, &next.r_digit),
+                        (&next.c_eval, &next.carry),
+                    ] {
+                        cb.require_equal(
+                            "evaluation starts with the digit",
+                            next_eval.expr(),
+                            next_digit.expr(),
+                        );
+                    }
+                },
+            );
+
+            // block transitions: the chain is a square (and multiply if the bit is set) per bit of
+            // exp, followed by a reduce and lt block.
+            cb.condition(cur.is_block_end.expr(), |cb| {
+                cb.require_zero(
+                    "chain continues up to the lt block",
+                    not::expr(cur.is_lt.expr()) * not::expr(next.is_chain()),
+                );
+                cb.require_zero(
+                    "acc is the result of the previous block",
+                    not::expr(cur.is_lt.expr()) * (next.acc.expr() - cur.r_eval.expr()),
+                );
+                cb.require_zero(
+                    "square for bit 1 is followed by multiply",
+                    cur.is_square.expr() * cur.bit.expr() * not::expr(next.is_multiply.expr()),
+                );
+                cb.require_zero(
+                    "square for bit 0 is followed by square or reduce",
+                    cur.is_square.expr()
+                        * not::expr(cur.bit.expr())
+                        * (next.is_multiply.expr() + next.is_lt.expr()),
+                );
+                cb.require_zero(
+                    "multiply is followed by square or reduce",
+                    cur.is_multiply.expr() * (next.is_multiply.expr() + next.is_lt.expr()),
+                );
+                cb.require_zero(
+                    "reduce is followed by lt",
+                    cur.is_reduce.expr() * not::expr(next.is_lt.expr()),
+                );
+            });
+
+            // the bits of exp are shifted into the current byte by the square blocks.
+            let exp_byte = cur.exp_byte.expr() * 2.expr() + cur.bit.expr();
+            let is_square_end = cur.is_block_end.expr() * cur.is_square.expr();
+            cb.condition(is_square_end.expr() * config.is_byte_done.expr(), |cb| {
+                cb.require_equal(
+                    "exp_chain_rlc accumulates the completed byte",
+                    next.exp_chain_rlc.expr(),
+                    cur.exp_chain_rlc.expr() * keccak_input.expr() + exp_byte.expr(),
+                );
+                cb.require_zero("exp byte resets", next.exp_byte.expr());
+                cb.require_zero("bit index resets", next.bit_index.expr());
+            });
+            cb.condition(
+                is_square_end * not::expr(config.is_byte_done.expr()),
+                |cb| {
+                    cb.require_equal(
+                        "exp_chain_rlc is kept within a byte",
+                        next.exp_chain_rlc.expr(),
+                        cur.exp_chain_rlc.expr(),
+                    );
+                    cb.require_equal("exp byte shifts in the bit", next.exp_byte.expr(), exp_byte);
+                    cb.require_equal(
+                        "bit index increases",
+                        next.bit_index.expr(),
+                        cur.bit_index.expr() + 1.expr(),
+                    );
+                },
+            );
+            cb.condition(cur.is_block_end.expr() * cur.is_multiply.expr(), |cb| {
+                for (cur_value, next_value) in cur.exp_state().into_iter().zip(next.exp_state()) {
+                    cb.require_equal("exp state is kept by multiply", next_value, cur_value);
+                }
+            });
+
+            cb.gate(and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                meta.query_fixed(q_enable, Rotation::next()),
+            ]))
+        });
+
+        for (name, column) in [
+            ("modexp circuit: byte in u8", byte),
+            ("modexp circuit: q_digit in u8", q_digit),
+            ("modexp circuit: r_digit in u8", r_digit),
+        ] {
+            meta.lookup(name, |meta| {
+                vec![(meta.query_advice(column, Rotation::cur()), u8_table.into())]
+            });
         }
+        meta.lookup("modexp circuit: exp head has at most 32 bytes", |meta| {
+            let is_input = meta.query_advice(is_input, Rotation::cur());
+            let exp_head_count = meta.query_advice(exp_head_count, Rotation::cur());
+            vec![(is_input * (32.expr() - exp_head_count), u8_table.into())]
+        });
+        for (name, column) in [
+            ("modexp circuit: carry_lo in u16", carry_lo),
+            ("modexp circuit: carry_hi in u16", carry_hi),
+        ] {
+            meta.lookup(name, |meta| {
+                vec![(meta.query_advice(column, Rotation::cur()), u16_table.into())]
+            });
+        }
+
+        config
     }
 }
 
-impl ModExpCircuitConfig {
-    pub(crate) fn assign_group<F: Field>(
+/// The witness of a block of rows.
+struct BlockWitness<F> {
+    kind: BlockKind,
+    /// The power the block operates on.
+    acc: BigUint,
+    /// Quotient, 0 for lt blocks.
+    q: BigUint,
+    /// Remainder, or difference for lt blocks.
+    r: BigUint,
+    /// Carries, in increasing order of coefficient index.
+    carries: Vec<i64>,
+    bit: bool,
+    exp_byte: u8,
+    bit_index: u8,
+    exp_chain_rlc: Value<F>,
+}
+
+/// Little-endian bytes of a big integer, which are the coefficients of its polynomial.
+fn digits(value: &BigUint) -> Vec<u8> {
+    if value.is_zero() {
+        vec![]
+    } else {
+        value.to_bytes_le()
+    }
+}
+
+/// Coefficients of the product of two polynomials, given theirs, as a polynomial of `len`
+/// coefficients.
+fn mul_coeffs(lhs: &[u8], rhs: &[u8], len: usize) -> Vec<i64> {
+    let mut product = vec![0; len];
+    for (i, &lhs) in lhs.iter().enumerate().filter(|(_, &lhs)| lhs != 0) {
+        for (j, &rhs) in rhs.iter().enumerate() {
+            product[i + j] += lhs as i64 * rhs as i64;
+        }
+    }
+    product
+}
+
+/// The carries `c` such that `P(x) = (x - 256) * C(x)`, given the coefficients of `P`, which
+/// must have 256 as a root.
+fn carries(p: &[i64]) -> Vec<i64> {
+    let mut carry = 0;
+    let carries = p
+        .iter()
+        .map(|&coeff| {
+            // coefficient k of (x - 256) * C(x) is c[k - 1] - 256 * c[k].
+            debug_assert_eq!((carry - coeff) % 256, 0);
+            carry = (carry - coeff) / 256;
+            carry
+        })
+        .collect();
+    assert_eq!(carry, 0, "256 is not a root of the block polynomial");
+    carries
+}
+
+impl<F: Field> BlockWitness<F> {
+    /// Witness of a block checking `acc * b = q * modulus + r`, given `b`.
+    fn mul(kind: BlockKind, acc: &BigUint, b: &BigUint, modulus: &BigUint, len: usize) -> Self {
+        let product = acc * b;
+        let (q, r) = (&product / modulus, &product % modulus);
+        let mut p = mul_coeffs(&digits(acc), &digits(b), len);
+        for (k, coeff) in mul_coeffs(&digits(&q), &digits(modulus), len)
+            .into_iter()
+            .enumerate()
+        {
+            p[k] -= coeff;
+        }
+        for (k, &digit) in digits(&r).iter().enumerate() {
+            p[k] -= digit as i64;
+        }
+
+        Self {
+            kind,
+            acc: acc.clone(),
+            q,
+            r,
+            carries: carries(&p),
+            bit: false,
+            exp_byte: 0,
+            bit_index: 0,
+            exp_chain_rlc: Value::known(F::zero()),
+        }
+    }
+
+    /// Witness of a block checking `modulus - result = r != 0`.
+    fn lt(result: &BigUint, modulus: &BigUint, len: usize) -> Self {
+        let r = modulus - result;
+        let mut p = vec![0; len];
+        for (k, &digit) in digits(modulus).iter().enumerate() {
+            p[k] += digit as i64;
+        }
+        for value in [result, &r] {
+            for (k, &digit) in digits(value).iter().enumerate() {
+                p[k] -= digit as i64;
+            }
+        }
+
+        Self {
+            kind: BlockKind::Lt,
+            acc: result.clone(),
+            q: BigUint::zero(),
+            r,
+            carries: carries(&p),
+            bit: false,
+            exp_byte: 0,
+            bit_index: 0,
+            exp_chain_rlc: Value::known(F::zero()),
+        }
+    }
+
+    fn with_exp_state(mut self, bit: bool, exp_byte: u8, bit_index: u8, rlc: Value<F>) -> Self {
+        self.bit = bit;
+        self.exp_byte = exp_byte;
+        self.bit_index = bit_index;
+        self.exp_chain_rlc = rlc;
+        self
+    }
+}
+
+impl<F: Field> ModExpCircuitConfig<F> {
+    fn assign_flag(
+        region: &mut Region<F>,
+        offset: usize,
+        column: Column<Advice>,
+        value: bool,
+    ) -> Result<(), Error> {
+        Self::assign_value(region, offset, column, Value::known(F::from(value)))
+    }
+
+    fn assign_value(
+        region: &mut Region<F>,
+        offset: usize,
+        column: Column<Advice>,
+        value: Value<F>,
+    ) -> Result<(), Error> {
+        region.assign_advice(|| "modexp circuit", column, offset, || value)?;
+        Ok(())
+    }
+
+    /// Assign the values of the call that are common to all of its rows.
+    fn assign_call_values(
         &self,
         region: &mut Region<F>,
-        table_offset: usize,
-        mut calc_offset: usize,
+        offset: usize,
         event: &BigModExp,
-        modexp_chip: &ModExpChip<F>,
-        range_check_chip: &mut RangeCheckChip<F>,
-    ) -> Result<usize, Error> {
-        let base = self.assign_value(region, table_offset, self.modexp_table.base, &event.base)?;
-        let exp =
-            self.assign_value(region, table_offset, self.modexp_table.exp, &event.exponent)?;
-        let modulus = self.assign_value(
-            region,
-            table_offset,
-            self.modexp_table.modulus,
-            &event.modulus,
-        )?;
-        let ret = modexp_chip.mod_exp(
-            region,
-            range_check_chip,
-            &mut calc_offset,
-            &base,
-            &exp,
-            &modulus,
+        result_rlc: Value<F>,
+        is_head: bool,
+    ) -> Result<(), Error> {
+        let table = &self.modexp_table;
+        region.assign_fixed(
+            || "modexp circuit q_enable",
+            self.q_enable,
+            offset,
+            || Value::known(F::one()),
         )?;
-        for i in 0..4 {
-            region.assign_fixed(
-                || format!("modexp table head {}", table_offset + i),
-                self.modexp_table.q_head,
-                table_offset + i,
-                || Value::known(if i == 0 { F::one() } else { F::zero() }),
+        Self::assign_flag(region, offset, table.q_head, is_head)?;
+        for (column, len) in [table.base_len, table.exp_len, table.modulus_len]
+            .into_iter()
+            .zip(event.lens)
+        {
+            Self::assign_value(region, offset, column, Value::known(F::from(len as u64)))?;
+        }
+        Self::assign_flag(region, offset, table.is_success, event.is_success)?;
+        Self::assign_value(region, offset, table.result_rlc, result_rlc)
+    }
+
+    /// Assign the rows of a call, returning the offset following them.
+    fn assign_event(
+        &self,
+        region: &mut Region<F>,
+        mut offset: usize,
+        event: &BigModExp,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<usize, Error> {
+        let keccak_input = challenges.keccak_input();
+        let table = &self.modexp_table;
+        let modulus = BigUint::from_bytes_be(event.modulus());
+        let has_chain = event.is_success && !modulus.is_zero();
+        let result_rlc = rlc_be_bytes(&event.result, keccak_input);
+
+        let [base_len, exp_len, modulus_len] = event.lens;
+        let zero = Value::known(F::zero());
+        let (mut input_rlc, mut exp_head_rlc) = (zero, zero);
+        let (mut base_rlc, mut exp_rlc, mut modulus_rlc) = (zero, zero, zero);
+        let mut counts = [0u64; 4];
+        for (index, &byte) in event.input.iter().enumerate() {
+            let is_base = index < base_len;
+            let is_exp = !is_base && index < base_len + exp_len;
+            let is_exp_head = is_exp && index < base_len + 32;
+            let is_modulus = !is_base && !is_exp && index < base_len + exp_len + modulus_len;
+            let is_last = index == INPUT_BYTES - 1;
+            let byte_value = Value::known(F::from(byte as u64));
+
+            input_rlc = input_rlc * keccak_input + byte_value;
+            for (flag, rlc, count) in [
+                (is_base, &mut base_rlc, &mut counts[0]),
+                (is_exp, &mut exp_rlc, &mut counts[1]),
+                (is_exp_head, &mut exp_head_rlc, &mut counts[2]),
+                (is_modulus, &mut modulus_rlc, &mut counts[3]),
+            ] {
+                if flag {
+                    *rlc = *rlc * keccak_input + byte_value;
+                    *count += 1;
+                }
+            }
+
+            self.assign_call_values(region, offset, event, result_rlc, is_last && !has_chain)?;
+            for (column, flag) in [
+                (self.is_input, true),
+                (self.is_base, is_base),
+                (self.is_exp, is_exp),
+                (self.is_exp_head, is_exp_head),
+                (self.is_modulus, is_modulus),
+            ] {
+                Self::assign_flag(region, offset, column, flag)?;
+            }
+            Self::assign_value(region, offset, self.byte, byte_value)?;
+            Self::assign_value(
+                region,
+                offset,
+                self.index,
+                Value::known(F::from(index as u64)),
+            )?;
+            IsEqualChip::construct(self.is_last_input.clone()).assign(
+                region,
+                offset,
+                Value::known(F::from(index as u64)),
+                Value::known(F::from((INPUT_BYTES - 1) as u64)),
             )?;
+            for (column, count) in [
+                self.base_count,
+                self.exp_count,
+                self.exp_head_count,
+                self.modulus_count,
+            ]
+            .into_iter()
+            .zip(counts)
+            {
+                Self::assign_value(region, offset, column, Value::known(F::from(count)))?;
+            }
+            for (column, rlc) in [
+                (table.input_rlc, input_rlc),
+                (table.exp_head_rlc, exp_head_rlc),
+                (self.base_rlc, base_rlc),
+                (self.exp_rlc, exp_rlc),
+                (self.modulus_rlc, modulus_rlc),
+            ] {
+                Self::assign_value(region, offset, column, rlc)?;
+            }
+            if is_last {
+                IsZeroChip::construct(self.modulus_is_zero.clone()).assign(
+                    region,
+                    offset,
+                    modulus_rlc,
+                )?;
+            }
+            offset += 1;
+        }
+
+        if !has_chain {
+            return Ok(offset);
+        }
+
+        let input_rlcs = [
+            (table.input_rlc, input_rlc),
+            (table.exp_head_rlc, exp_head_rlc),
+            (self.base_rlc, base_rlc),
+            (self.exp_rlc, exp_rlc),
+            (self.modulus_rlc, modulus_rlc),
+        ];
+        for block in Self::chain(event, &modulus, keccak_input) {
+            let len = block.carries.len();
+            let acc_rlc = rlc_be_bytes(&block.acc.to_bytes_be(), keccak_input);
+            let (q_digits, r_digits) = (digits(&block.q), digits(&block.r));
+            let (mut q_eval, mut r_eval, mut c_eval) = (zero, zero, zero);
+            for row in 0..len {
+                let k = len - 1 - row;
+                let is_block_end = k == 0;
+                let [q_digit, r_digit] = [&q_digits, &r_digits]
+                    .map(|digits| F::from(digits.get(k).copied().unwrap_or_default() as u64));
+                let carry = (block.carries[k] + CARRY_OFFSET as i64) as u64;
+                let carry_value = F::from(block.carries[k].unsigned_abs());
+                let carry_value = if block.carries[k] < 0 {
+                    -carry_value
+                } else {
+                    carry_value
+                };
+                q_eval = q_eval * keccak_input + Value::known(q_digit);
+                r_eval = r_eval * keccak_input + Value::known(r_digit);
+                c_eval = c_eval * keccak_input + Value::known(carry_value);
 
-            ret.limbs[i]
-                .cell
-                .clone()
-                .expect("should has assigned after modexp")
-                .copy_advice(
-                    || "copy to result limbs",
+                self.assign_call_values(
                     region,
-                    self.modexp_table.result,
-                    table_offset + i,
+                    offset,
+                    event,
+                    result_rlc,
+                    is_block_end && block.kind == BlockKind::Lt,
                 )?;
+                for (column, rlc) in input_rlcs {
+                    Self::assign_value(region, offset, column, rlc)?;
+                }
+                for (column, kind) in [
+                    (self.is_square, BlockKind::Square),
+                    (self.is_multiply, BlockKind::Multiply),
+                    (self.is_reduce, BlockKind::Reduce),
+                    (self.is_lt, BlockKind::Lt),
+                ] {
+                    Self::assign_flag(region, offset, column, block.kind == kind)?;
+                }
+                Self::assign_flag(region, offset, self.is_block_end, is_block_end)?;
+                Self::assign_flag(region, offset, self.bit, block.bit)?;
+                for (column, value) in [
+                    (self.q_digit, q_digit),
+                    (self.r_digit, r_digit),
+                    (self.carry_lo, F::from(carry & 0xffff)),
+                    (self.carry_hi, F::from(carry >> 16)),
+                    (self.exp_byte, F::from(block.exp_byte as u64)),
+                    (self.bit_index, F::from(block.bit_index as u64)),
+                ] {
+                    Self::assign_value(region, offset, column, Value::known(value))?;
+                }
+                for (column, value) in [
+                    (self.q_eval, q_eval),
+                    (self.r_eval, r_eval),
+                    (self.c_eval, c_eval),
+                    (self.acc, acc_rlc),
+                    (self.exp_chain_rlc, block.exp_chain_rlc),
+                ] {
+                    Self::assign_value(region, offset, column, value)?;
+                }
+                if is_block_end && block.kind == BlockKind::Square {
+                    IsEqualChip::construct(self.is_byte_done.clone()).assign(
+                        region,
+                        offset,
+                        Value::known(F::from(block.bit_index as u64)),
+                        Value::known(F::from(7)),
+                    )?;
+                }
+                if is_block_end && block.kind == BlockKind::Lt {
+                    Self::assign_value(
+                        region,
+                        offset,
+                        self.lt_inv,
+                        r_eval.map(|r| r.invert().unwrap_or(F::zero())),
+                    )?;
+                }
+                offset += 1;
+            }
         }
-        Ok(calc_offset)
+
+        Ok(offset)
     }
 
-    fn assign_value<F: Field>(
-        &self,
-        region: &mut Region<F>,
-        offset: usize,
-        col: Column<Advice>,
-        value: &Word,
-    ) -> Result<Number<F>, Error> {
-        let limbs_v = ModExpTable::split_u256_108bit_limbs(value);
-        let native_v = ModExpTable::native_u256(value);
-        let mut limbs = Vec::new();
-
-        for (i, limb) in limbs_v.into_iter().enumerate() {
-            let fv = F::from_u128(limb);
-            let c = region.assign_advice(
-                || "assign modexp limb",
-                col,
-                offset + i,
-                || Value::known(fv),
-            )?;
-            limbs.push(Limb::new(Some(c), fv));
+    /// The blocks computing the result of a call with a non-zero modulus.
+    fn chain(event: &BigModExp, modulus: &BigUint, keccak_input: Value<F>) -> Vec<BlockWitness<F>> {
+        let len = ModExpCircuit::<F>::block_rows(event);
+        let base = BigUint::from_bytes_be(event.base());
+        let mut blocks = vec![];
+        let mut acc = BigUint::one();
+        let mut exp_chain_rlc = Value::known(F::zero());
+        for &byte in event.exponent().iter().skip_while(|&&byte| byte == 0) {
+            // the bits of the byte are processed from the most significant one.
+            let mut exp_byte = 0;
+            for bit_index in 0..8 {
+                let bit = (byte >> (7 - bit_index)) & 1 == 1;
+                let square = BlockWitness::mul(BlockKind::Square, &acc, &acc, modulus, len)
+                    .with_exp_state(bit, exp_byte, bit_index, exp_chain_rlc);
+                acc = square.r.clone();
+                blocks.push(square);
+                if bit_index == 7 {
+                    exp_chain_rlc =
+                        exp_chain_rlc * keccak_input + Value::known(F::from(byte as u64));
+                    exp_byte = 0;
+                } else {
+                    exp_byte = 2 * exp_byte + bit as u8;
+                }
+                if bit {
+                    let multiply =
+                        BlockWitness::mul(BlockKind::Multiply, &acc, &base, modulus, len)
+                            .with_exp_state(false, exp_byte, (bit_index + 1) % 8, exp_chain_rlc);
+                    acc = multiply.r.clone();
+                    blocks.push(multiply);
+                }
+            }
         }
-        let c = region.assign_advice(
-            || "assign modexp native",
-            col,
-            offset + 3,
-            || Value::known(native_v),
-        )?;
-        limbs.push(Limb::new(Some(c), native_v));
-        Ok(Number {
-            limbs: limbs.try_into().expect("just 4 pushes"),
-        })
+        let reduce = BlockWitness::mul(BlockKind::Reduce, &acc, &BigUint::one(), modulus, len)
+            .with_exp_state(false, 0, 0, exp_chain_rlc);
+        let result = reduce.r.clone();
+        blocks.push(reduce);
+        blocks.push(BlockWitness::lt(&result, modulus, len));
+        blocks
     }
 }
 
-const MODEXPCONFIG_EACH_CHIP_ROWS: usize = 39962;
-
 /// ModExp circuit for precompile modexp
 #[derive(Clone, Debug, Default)]
-pub struct ModExpCircuit<F: Field>(Vec<BigModExp>, std::marker::PhantomData<F>);
+pub struct ModExpCircuit<F: Field> {
+    /// The modexp calls.
+    pub events: Vec<BigModExp>,
+    /// The number of rows to enable, at least one more than used by the calls. If 0, only the
+    /// rows used by the calls and a padding row are enabled.
+    pub max_rows: usize,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> ModExpCircuit<F> {
+    /// Return a new ModExpCircuit
+    pub fn new(events: Vec<BigModExp>, max_rows: usize) -> Self {
+        Self {
+            events,
+            max_rows,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The number of rows of a block in the chain of a call, one per coefficient of the product
+    /// of two numbers of the size of the modulus or base.
+    fn block_rows(event: &BigModExp) -> usize {
+        2 * event.lens[0].max(event.lens[2]) - 1
+    }
+
+    /// The number of rows taken by a call, which is the capacity it consumes.
+    pub fn event_rows(event: &BigModExp) -> usize {
+        let has_chain = event.is_success && event.modulus().iter().any(|&byte| byte != 0);
+        if !has_chain {
+            return INPUT_BYTES;
+        }
+        let exp_bits = event
+            .exponent()
+            .iter()
+            .skip_while(|&&byte| byte == 0)
+            .map(|byte| 8 + byte.count_ones() as usize)
+            .sum::<usize>();
+        // a square per bit and a multiply per set bit, followed by reduce and lt.
+        INPUT_BYTES + (exp_bits + 2) * Self::block_rows(event)
+    }
+
+    /// The number of rows taken by the calls, including the trailing padding row.
+    fn rows(events: &[BigModExp]) -> usize {
+        events.iter().map(Self::event_rows).sum::<usize>() + 1
+    }
+}
 
 impl<F: Field> SubCircuit<F> for ModExpCircuit<F> {
-    type Config = ModExpCircuitConfig;
+    type Config = ModExpCircuitConfig<F>;
 
     fn unusable_rows() -> usize {
-        // No column queried at more than 4 distinct rotations, so returns 8 as
+        // No column queried at more than 3 distinct rotations, so returns 6 as
         // minimum unusable rows.
-        8
+        6
     }
 
     fn new_from_block(block: &witness::Block) -> Self {
-        let event_limit = block.circuits_params.max_keccak_rows / MODEXPCONFIG_EACH_CHIP_ROWS;
-
-        let mut exp_events = block.get_big_modexp();
-        if event_limit != 0 {
+        let events = block.get_big_modexp();
+        let max_rows = block.circuits_params.max_keccak_rows;
+        if max_rows != 0 {
+            let rows = Self::rows(&events);
             assert!(
-                exp_events.len() <= event_limit,
-                "no enough rows for modexp circuit, expected {}, limit {}",
-                exp_events.len(),
-                event_limit,
+                rows <= max_rows,
+                "no enough rows for modexp circuit, expected {rows}, limit {max_rows}",
             );
-            exp_events.resize(event_limit, Default::default());
-            log::info!("modexp circuit work with maximum {} entries", event_limit);
+            log::info!("modexp circuit uses {rows} of {max_rows} rows");
         }
 
-        Self(exp_events, Default::default())
+        Self::new(events, max_rows)
     }
 
     fn min_num_rows_block(block: &witness::Block) -> (usize, usize) {
-        let exp_events = block.get_big_modexp();
-        let real_len = exp_events.len() * MODEXPCONFIG_EACH_CHIP_ROWS;
+        let rows = Self::rows(&block.get_big_modexp());
         (
-            real_len,
-            real_len
-                .max(block.circuits_params.max_keccak_rows)
-                .max(4096),
+            rows,
+            rows.max(block.circuits_params.max_keccak_rows).max(4096),
         )
     }
 
     fn synthesize_sub(
         &self,
         config: &Self::Config,
-        _challenges: &Challenges<Value<F>>,
+        challenges: &Challenges<Value<F>>,
         layouter: &mut impl Layouter<F>,
     ) -> Result<(), Error> {
-        let modexp_chip = ModExpChip::new(config.modexp_config.clone());
-        let mut range_chip = RangeCheckChip::new(config.rangecheck_config.clone());
+        let max_rows = if self.max_rows == 0 {
+            Self::rows(&self.events)
+        } else {
+            self.max_rows
+        };
 
         layouter.assign_region(
             || "modexp circuit",
             |mut region| {
-                range_chip.initialize(&mut region)?;
-                let _modexp_count = self.0.len();
-                let mut calc_offset = 0;
-                for (n, event) in self.0.iter().enumerate() {
-                    calc_offset = config.assign_group(
-                        &mut region,
-                        n * 4,
-                        calc_offset,
-                        event,
-                        &modexp_chip,
-                        &mut range_chip,
+                region.assign_fixed(
+                    || "modexp circuit q_first",
+                    config.q_first,
+                    0,
+                    || Value::known(F::one()),
+                )?;
+                let mut offset = 0;
+                for event in &self.events {
+                    offset = config.assign_event(&mut region, offset, event, challenges)?;
+                }
+                assert!(
+                    offset < max_rows,
+                    "no enough rows for modexp circuit, used {offset}, limit {max_rows}",
+                );
+
+                // padding rows, with all advice cells being zero.
+                for offset in offset..max_rows {
+                    region.assign_fixed(
+                        || "modexp circuit q_enable",
+                        config.q_enable,
+                        offset,
+                        || Value::known(F::one()),
                     )?;
+                    for column in
+                        <ModExpTable as LookupTable<F>>::advice_columns(&config.modexp_table)
+                    {
+                        region.assign_advice(
+                            || "modexp circuit padding",
+                            column,
+                            offset,
+                            || Value::known(F::zero()),
+                        )?;
+                    }
                 }
-                //assert_eq!(max(calc_offset, range_chip.offset), MODEXPCONFIG_EACH_CHIP_ROWS *
-                // modexp_count);
                 Ok(())
             },
-        )?;
-
-        config.modexp_table.fill_blank(layouter)
+        )
     }
 }
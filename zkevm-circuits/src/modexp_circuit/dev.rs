@@ -1,13 +1,12 @@
 use super::*;
-use crate::util::MockChallenges;
+use crate::table::{U16Table, U8Table};
 use halo2_proofs::{
     circuit::SimpleFloorPlanner,
-    halo2curves::bn256::Fr,
     plonk::{Circuit, ConstraintSystem},
 };
 
-impl Circuit<Fr> for ModExpCircuit<Fr> {
-    type Config = (ModExpCircuitConfig, MockChallenges);
+impl<F: Field> Circuit<F> for ModExpCircuit<F> {
+    type Config = (ModExpCircuitConfig<F>, U8Table, U16Table, Challenges);
     type FloorPlanner = SimpleFloorPlanner;
     type Params = ();
 
@@ -15,21 +14,36 @@ impl Circuit<Fr> for ModExpCircuit<Fr> {
         Self::default()
     }
 
-    fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
         let modexp_table = ModExpTable::construct(meta);
-        let challenge = MockChallenges::construct(meta);
+        let u8_table = U8Table::construct(meta);
+        let u16_table = U16Table::construct(meta);
+        let challenges = Challenges::construct(meta);
+        let challenges_expr = challenges.exprs(meta);
         (
-            <ModExpCircuitConfig as SubCircuitConfig<Fr>>::new(meta, modexp_table),
-            challenge,
+            ModExpCircuitConfig::new(
+                meta,
+                ModExpCircuitConfigArgs {
+                    modexp_table,
+                    u8_table,
+                    u16_table,
+                    challenges: challenges_expr,
+                },
+            ),
+            u8_table,
+            u16_table,
+            challenges,
         )
     }
 
     fn synthesize(
         &self,
-        (config, challenge): Self::Config,
-        mut layouter: impl Layouter<Fr>,
+        (config, u8_table, u16_table, challenges): Self::Config,
+        mut layouter: impl Layouter<F>,
     ) -> Result<(), Error> {
-        let challenges = challenge.values(&layouter);
-        <Self as SubCircuit<Fr>>::synthesize_sub(self, &config, &challenges, &mut layouter)
+        let challenges = challenges.values(&layouter);
+        u8_table.load(&mut layouter)?;
+        u16_table.load(&mut layouter)?;
+        self.synthesize_sub(&config, &challenges, &mut layouter)
     }
 }
@@ -1,57 +1,98 @@
-#![allow(unused_imports)]
 use super::*;
 
-use eth_types::U256;
-use halo2_proofs::dev::MockProver;
+use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
 
-#[test]
-fn test_modexp_circuit_00() {
-    let event1 = construct_modexp(Word::from(1u128), Word::from(3u128), Word::from(7u128));
+fn construct_modexp(base: &[u8], exp: &[u8], modulus: &[u8]) -> BigModExp {
+    let lens = [base.len(), exp.len(), modulus.len()];
+    let mut input = [base, exp, modulus].concat();
+    input.resize(INPUT_BYTES, 0);
+
+    let modulus_value = BigUint::from_bytes_be(modulus);
+    let result = if modulus_value.is_zero() {
+        BigUint::zero()
+    } else {
+        BigUint::from_bytes_be(base).modpow(&BigUint::from_bytes_be(exp), &modulus_value)
+    };
+    // left-padded to the length of the modulus.
+    let result = result.to_bytes_be();
+    let mut padded_result = vec![0; modulus.len()];
+    padded_result[modulus.len().saturating_sub(result.len())..]
+        .copy_from_slice(&result[result.len().saturating_sub(modulus.len())..]);
+
+    BigModExp {
+        input,
+        lens,
+        is_success: true,
+        result: padded_result,
+    }
+}
 
-    let test_circuit = ModExpCircuit(vec![event1], Default::default());
-    let prover = MockProver::run(16, &test_circuit, vec![]).unwrap();
+fn run(events: Vec<BigModExp>) {
+    let test_circuit = ModExpCircuit::<Fr>::new(events, 0);
+    let prover = MockProver::run(17, &test_circuit, vec![]).unwrap();
     assert_eq!(prover.verify(), Ok(()));
 }
 
 #[test]
-fn test_modexp_circuit_01() {
-    let event1 = construct_modexp(Word::from(1u128), Word::from(2u128), Word::from(7u128));
+fn test_modexp_circuit_00() {
+    run(vec![construct_modexp(&[1], &[3], &[7])]);
+}
 
-    let test_circuit = ModExpCircuit(vec![event1], Default::default());
-    let prover = MockProver::run(16, &test_circuit, vec![]).unwrap();
-    assert_eq!(prover.verify(), Ok(()));
+#[test]
+fn test_modexp_circuit_01() {
+    run(vec![
+        construct_modexp(&[2], &[2], &[7]),
+        construct_modexp(&[3], &[21], &[78]),
+    ]);
 }
+
+// test all zeros case (exp == mod == base == 0)
 #[test]
 fn test_modexp_circuit_02() {
-    let event1 = construct_modexp(Word::from(2u128), Word::from(2u128), Word::from(7u128));
-    let event2 = construct_modexp(Word::from(3u128), Word::from(21u128), Word::from(78u128));
-
-    let test_circuit = ModExpCircuit(vec![event1, event2], Default::default());
-    let prover = MockProver::run(17, &test_circuit, vec![]).unwrap();
-    assert_eq!(prover.verify(), Ok(()));
+    run(vec![construct_modexp(&[0], &[0], &[0])]);
 }
 
-// test all zeros case (exp == mod == base == 0)
+// test zero exponent, with the power 1 being reduced by the modulus
 #[test]
 fn test_modexp_circuit_03() {
-    let event1 = construct_modexp(Word::from(0u128), Word::from(0u128), Word::from(0u128));
+    run(vec![
+        construct_modexp(&[5], &[0, 0], &[7]),
+        construct_modexp(&[5], &[], &[1]),
+    ]);
+}
 
-    let test_circuit = ModExpCircuit(vec![event1], Default::default());
-    let prover = MockProver::run(16, &test_circuit, vec![]).unwrap();
-    assert_eq!(prover.verify(), Ok(()));
+// test operands larger than 32 bytes
+#[test]
+fn test_modexp_circuit_big_operands() {
+    let base = (0..64).map(|i| 0xff - i).collect::<Vec<u8>>();
+    let modulus = (0..96u32).map(|i| (i * 3 + 1) as u8).collect::<Vec<_>>();
+    run(vec![
+        construct_modexp(&base, &[0x01, 0x00, 0x01], &modulus),
+        construct_modexp(
+            &modulus,
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+                0, 0, 0, 0, 0, 3,
+            ],
+            &base,
+        ),
+    ]);
 }
 
-fn construct_modexp(base: U256, exp: U256, modulus: U256) -> BigModExp {
-    let result = if modulus == Word::zero() {
-        Word::zero()
-    } else {
-        base.pow(exp).div_mod(modulus).1
-    };
+// test calls without result: failed, or without any operands
+#[test]
+fn test_modexp_circuit_no_result() {
+    let mut failed = construct_modexp(&[3], &[5], &[11]);
+    failed.is_success = false;
+    failed.result = vec![];
+    run(vec![failed, construct_modexp(&[], &[], &[])]);
+}
 
-    BigModExp {
-        base,
-        exponent: exp,
-        modulus,
-        result,
-    }
+#[test]
+fn test_modexp_circuit_bad_result() {
+    let mut event = construct_modexp(&[3], &[5], &[11]);
+    event.result = vec![3u8.pow(5) % 11 + 11];
+    let test_circuit = ModExpCircuit::<Fr>::new(vec![event], 0);
+    let prover = MockProver::run(17, &test_circuit, vec![]).unwrap();
+    assert!(prover.verify().is_err());
 }
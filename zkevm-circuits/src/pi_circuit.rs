@@ -15,6 +15,7 @@ use crate::{
 use bus_mapping::circuit_input_builder::get_dummy_tx_hash;
 use eth_types::{
     constants::{get_coinbase_constant, get_difficulty_constant},
+    evm_types::block_utils::MIN_BASE_FEE_PER_BLOB_GAS,
     geth_types::TxType,
     Address, Hash, ToBigEndian, Word, H256,
 };
@@ -50,12 +51,14 @@ use halo2_proofs::{
 
 use crate::{
     evm_circuit::param::{N_BYTES_ACCOUNT_ADDRESS, N_BYTES_U64, N_BYTES_WORD},
-    pi_circuit::param::{COINBASE_OFFSET, DIFFICULTY_OFFSET, NUM_ALL_TXS_OFFSET},
+    pi_circuit::param::{
+        BLOB_BASE_FEE_OFFSET, COINBASE_OFFSET, DIFFICULTY_OFFSET, NUM_ALL_TXS_OFFSET,
+    },
     table::{
         BlockContextFieldTag,
         BlockContextFieldTag::{
-            BaseFee, ChainId, Coinbase, CumNumTxs, Difficulty, GasLimit, NumAllTxs, NumTxs, Number,
-            Timestamp,
+            BaseFee, BlobBaseFee, ChainId, Coinbase, CumNumTxs, Difficulty, GasLimit, NumAllTxs,
+            NumTxs, Number, Timestamp,
         },
     },
     util::rlc_be_bytes,
@@ -335,6 +338,7 @@ impl BlockContext {
             number: Default::default(),
             timestamp: Default::default(),
             base_fee: Default::default(),
+            blob_base_fee: MIN_BASE_FEE_PER_BLOB_GAS.into(),
             history_hashes: vec![],
             parent_hash: Default::default(),
             state_root: Default::default(),
@@ -1375,6 +1379,12 @@ impl<F: Field> PiCircuitConfig<F> {
                 rpi_cells[1].cell(),
                 block_value_cells[BLOCK_LEN * block_idx + DIFFICULTY_OFFSET].cell(),
             )?;
+            // No blob-carrying txs on L2, so the blob base fee stays at its minimum. Its RLC
+            // equals the value itself as it fits into a single byte.
+            region.constrain_constant(
+                block_value_cells[BLOCK_LEN * block_idx + BLOB_BASE_FEE_OFFSET].cell(),
+                F::from(MIN_BASE_FEE_PER_BLOB_GAS),
+            )?;
         }
 
         Ok(offset)
@@ -1667,8 +1677,17 @@ impl<F: Field> PiCircuitConfig<F> {
                 .cloned()
                 .unwrap_or(0);
             let tag = [
-                Coinbase, Timestamp, Number, Difficulty, GasLimit, BaseFee, ChainId, NumTxs,
-                CumNumTxs, NumAllTxs,
+                Coinbase,
+                Timestamp,
+                Number,
+                Difficulty,
+                GasLimit,
+                BaseFee,
+                ChainId,
+                NumTxs,
+                CumNumTxs,
+                NumAllTxs,
+                BlobBaseFee,
             ];
 
             // index_cells of same block are equal to block_number.
@@ -0,0 +1,1169 @@
+//! The RIPEMD-160 circuit verifies the RIPEMD-160 hashes of the precompile ripemd160 calls.
+//!
+//! The input is padded into 64-byte blocks as in MD4, with the bit length appended in
+//! little-endian. Every block is laid out in [`ROWS_PER_BLOCK`] rows, and the chaining value, the
+//! input RLC, the input length and the padding state are carried from one block to the next by
+//! copy constraints. Every block writes a row to the [`Ripemd160Table`]; only the rows with
+//! `is_final = 1` hold the digest of a whole input.
+//!
+//! All the values are 32-bit words decomposed into little-endian bytes. Additions are done with an
+//! explicit carry, the bitwise functions are done with a lookup to a `(a, b, a ^ b, a & b)` table
+//! on every pair of bytes, and the rotations are done by splitting the word at the rotation, with
+//! the high part range checked by the same table.
+
+#[cfg(any(feature = "test", test, feature = "test-circuits"))]
+mod dev;
+#[cfg(any(feature = "test", test))]
+mod test;
+#[cfg(any(feature = "test", test, feature = "test-circuits"))]
+pub use dev::Ripemd160Circuit as TestRipemd160Circuit;
+
+use crate::{
+    evm_circuit::util::{
+        constraint_builder::{BaseConstraintBuilder, ConstrainBuilderCommon},
+        pow_of_two,
+    },
+    table::{LookupTable, Ripemd160Table},
+    util::{Challenges, Field, SubCircuit, SubCircuitConfig},
+    witness,
+};
+use bus_mapping::circuit_input_builder::Ripemd160;
+use gadgets::util::{expr_from_bytes, Expr};
+#[cfg(feature = "onephase")]
+use halo2_proofs::plonk::FirstPhase as SecondPhase;
+#[cfg(not(feature = "onephase"))]
+use halo2_proofs::plonk::SecondPhase;
+use halo2_proofs::{
+    circuit::{AssignedCell, Layouter, Region, Value},
+    plonk::{
+        Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, TableColumn,
+        VirtualCells,
+    },
+    poly::Rotation,
+};
+use std::marker::PhantomData;
+
+/// RIPEMD-160 initial chaining value.
+const IV: [u64; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+/// Round constants of the left line.
+const K: [u64; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+
+/// Round constants of the right line.
+const K_PRIME: [u64; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+/// Message word selection of the left line.
+const R: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, //
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8, //
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12, //
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2, //
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13,
+];
+
+/// Message word selection of the right line.
+const R_PRIME: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12, //
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2, //
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13, //
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14, //
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11,
+];
+
+/// Rotation amounts of the left line.
+const S: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8, //
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12, //
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5, //
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12, //
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6,
+];
+
+/// Rotation amounts of the right line.
+const S_PRIME: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6, //
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11, //
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5, //
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8, //
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11,
+];
+
+/// Rows taken by the boolean functions f1 to f5: 2 or 3 bitwise operations of 3 rows.
+const F_ROWS: [usize; 5] = [6, 9, 6, 9, 6];
+
+/// Rows taken by a step of a line besides its boolean function: add4, rotl, add, rotl.
+const STEP_ROWS: usize = 5 + 2 + 3 + 2;
+
+/// Rows taken by the 80 steps of a line.
+const LINE_ROWS: usize =
+    16 * (F_ROWS[0] + F_ROWS[1] + F_ROWS[2] + F_ROWS[3] + F_ROWS[4]) + 80 * STEP_ROWS;
+
+/// Rows taken by a block, which is:
+/// - the `is_first` row,
+/// - the selections between the carried and the initial chaining value, input RLC, input length
+///   and padding state,
+/// - the head row and the 16 message words,
+/// - the left and right lines,
+/// - the 5 additions of the new chaining value and its RLC.
+pub const ROWS_PER_BLOCK: usize = 1 + 3 * (5 + 3) + 1 + 16 + 2 * LINE_ROWS + 4 * 5 + 5;
+
+/// Number of rows of the bitwise table.
+const BITWISE_TABLE_ROWS: usize = 1 << 16;
+
+/// Bitwise operations done with a lookup, in the order of their selectors.
+#[derive(Clone, Copy, Debug)]
+enum Bitwise {
+    /// x ^ y
+    Xor,
+    /// !(x ^ y)
+    Xnor,
+    /// x & y
+    And,
+    /// !x & y
+    AndN,
+}
+
+impl Bitwise {
+    fn apply(&self, x: u64, y: u64) -> u64 {
+        let mask = u32::MAX as u64;
+        match self {
+            Self::Xor => x ^ y,
+            Self::Xnor => !(x ^ y) & mask,
+            Self::And => x & y,
+            Self::AndN => !x & y & mask,
+        }
+    }
+}
+
+/// Layout for the RIPEMD-160 circuit.
+#[derive(Clone, Debug)]
+pub struct Ripemd160CircuitConfig<F> {
+    /// Enabled on every assigned row, decomposes the value into bytes.
+    q_word: Selector,
+    /// Bitwise operation of the current and next rows, one selector per [`Bitwise`].
+    q_bitwise: [Selector; 4],
+    /// Left rotation of the current row by the amount set in `rotl_shift`.
+    q_rotl: Selector,
+    /// Additions of 2, 3 and 4 rows.
+    q_add: [Selector; 3],
+    /// Selection between 2 rows.
+    q_select: Selector,
+    /// Row before the message words, holding the carried input RLC, length and padding state.
+    q_head: Selector,
+    /// Message word, accumulating the input RLC and length of its non-padding bytes.
+    q_msg: Selector,
+    /// Message word whose padding bytes are zero, except for the first one.
+    q_msg_body: Selector,
+    /// Last message words, holding the bit length in the final block.
+    q_msg_length: Selector,
+    /// RLC row of a little-endian 32-bit word.
+    q_rlc_word: Selector,
+    /// First RLC row of the output.
+    q_rlc_word_first: Selector,
+    /// `2^(16 - s)` for the left rotation by `s` bits.
+    rotl_shift: Column<Fixed>,
+    /// 32-bit word
+    value: Column<Advice>,
+    /// Little-endian bytes of the word
+    bytes: [Column<Advice>; 4],
+    /// Whether the bytes of a message word are padding
+    is_pad: [Column<Advice>; 4],
+    /// Whether the block of a message word is the final one
+    is_final: Column<Advice>,
+    /// Auxiliary witness of the operations
+    aux: Column<Advice>,
+    /// RLC accumulator
+    rlc: Column<Advice>,
+    /// Bitwise table, with columns `a`, `b`, `a ^ b`, `a & b` and `a + 256 * b` for all pairs of
+    /// bytes.
+    bitwise_table: [TableColumn; 5],
+    /// RIPEMD-160 table
+    pub ripemd160_table: Ripemd160Table,
+    _marker: PhantomData<F>,
+}
+
+/// Arguments to configure the RIPEMD-160 circuit
+#[derive(Clone, Debug)]
+pub struct Ripemd160CircuitConfigArgs<F: Field> {
+    /// RIPEMD-160 table
+    pub ripemd160_table: Ripemd160Table,
+    /// Challenges randomness
+    pub challenges: Challenges<Expression<F>>,
+}
+
+impl<F: Field> SubCircuitConfig<F> for Ripemd160CircuitConfig<F> {
+    type ConfigArgs = Ripemd160CircuitConfigArgs<F>;
+
+    /// Return a new Ripemd160CircuitConfig
+    fn new(
+        meta: &mut ConstraintSystem<F>,
+        Self::ConfigArgs {
+            ripemd160_table,
+            challenges,
+        }: Self::ConfigArgs,
+    ) -> Self {
+        let q_word = meta.complex_selector();
+        let q_bitwise = [(); 4].map(|_| meta.complex_selector());
+        let q_rotl = meta.complex_selector();
+        let q_add = [(); 3].map(|_| meta.selector());
+        let q_select = meta.selector();
+        let q_head = meta.selector();
+        let q_msg = meta.selector();
+        let q_msg_body = meta.selector();
+        let q_msg_length = meta.selector();
+        let q_rlc_word = meta.selector();
+        let q_rlc_word_first = meta.selector();
+        let rotl_shift = meta.fixed_column();
+
+        let value = meta.advice_column();
+        let bytes = [(); 4].map(|_| meta.advice_column());
+        let is_pad = [(); 4].map(|_| meta.advice_column());
+        let is_final = meta.advice_column();
+        let aux = meta.advice_column();
+        let rlc = meta.advice_column_in(SecondPhase);
+        for column in [value, is_pad[3], is_final, aux, rlc] {
+            meta.enable_equality(column);
+        }
+        let constants: Column<Fixed> = meta.fixed_column();
+        meta.enable_constant(constants);
+        let bitwise_table = [(); 5].map(|_| meta.lookup_table_column());
+
+        let two_pow_16 = Expression::Constant(pow_of_two::<F>(16));
+        let two_pow_32 = Expression::Constant(pow_of_two::<F>(32));
+        let r = challenges.keccak_input();
+        let r_pows = std::iter::successors(Some(1.expr()), |pow: &Expression<F>| {
+            Some(pow.clone() * r.clone())
+        })
+        .take(5)
+        .collect::<Vec<_>>();
+
+        meta.create_gate("ripemd160 word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let bytes = bytes.map(|column| meta.query_advice(column, Rotation::cur()));
+            cb.require_equal(
+                "value is composed of its little-endian bytes",
+                meta.query_advice(value, Rotation::cur()),
+                expr_from_bytes(&bytes),
+            );
+
+            cb.gate(meta.query_selector(q_word))
+        });
+        for column in bytes {
+            meta.lookup("ripemd160 byte range", |meta| {
+                let byte = meta.query_selector(q_word) * meta.query_advice(column, Rotation::cur());
+                vec![(byte, bitwise_table[0])]
+            });
+        }
+
+        // z = op(x, y) with x, y and z in 3 consecutive rows, byte by byte. The negations are
+        // applied to the bytes of x for AndN and to the bytes of z for Xnor.
+        let [q_xor, q_xnor, q_and, q_andn] = q_bitwise;
+        for j in 0..4 {
+            meta.lookup("ripemd160 xor", |meta| {
+                let q_xor = meta.query_selector(q_xor);
+                let q_xnor = meta.query_selector(q_xnor);
+                let x = meta.query_advice(bytes[j], Rotation::cur());
+                let y = meta.query_advice(bytes[j], Rotation::next());
+                let z = meta.query_advice(bytes[j], Rotation(2));
+                let q = q_xor.clone() + q_xnor.clone();
+                vec![
+                    (q.clone() * x, bitwise_table[0]),
+                    (q * y, bitwise_table[1]),
+                    (
+                        q_xor * z.clone() + q_xnor * (255.expr() - z),
+                        bitwise_table[2],
+                    ),
+                ]
+            });
+            meta.lookup("ripemd160 and", |meta| {
+                let q_and = meta.query_selector(q_and);
+                let q_andn = meta.query_selector(q_andn);
+                let x = meta.query_advice(bytes[j], Rotation::cur());
+                let y = meta.query_advice(bytes[j], Rotation::next());
+                let z = meta.query_advice(bytes[j], Rotation(2));
+                let q = q_and.clone() + q_andn.clone();
+                vec![
+                    (
+                        q_and * x.clone() + q_andn * (255.expr() - x),
+                        bitwise_table[0],
+                    ),
+                    (q.clone() * y, bitwise_table[1]),
+                    (q * z, bitwise_table[3]),
+                ]
+            });
+        }
+
+        // rotl(x, s) == x * 2^s - hi * (2^32 - 1), with hi the highest s bits of x, which is
+        // unique for 32-bit x and rotl(x, s) when hi < 2^s. Both sides are multiplied by
+        // 2^(16 - s) so that hi < 2^s is checked as hi * 2^(16 - s) < 2^16.
+        meta.create_gate("ripemd160 rotl", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let x = meta.query_advice(value, Rotation::cur());
+            let hi = meta.query_advice(aux, Rotation::cur());
+            let shift = meta.query_fixed(rotl_shift, Rotation::cur());
+            cb.require_equal(
+                "rotl(x, s) * 2^(16 - s) == x * 2^16 - hi * 2^(16 - s) * (2^32 - 1)",
+                meta.query_advice(value, Rotation::next()) * shift.clone(),
+                x * two_pow_16.clone() - hi * shift * (two_pow_32.clone() - 1.expr()),
+            );
+
+            cb.gate(meta.query_selector(q_rotl))
+        });
+        meta.lookup("ripemd160 rotl high part", |meta| {
+            let hi = meta.query_selector(q_rotl)
+                * meta.query_advice(aux, Rotation::cur())
+                * meta.query_fixed(rotl_shift, Rotation::cur());
+            vec![(hi, bitwise_table[4])]
+        });
+
+        for (n, &q_add) in (2..).zip(q_add.iter()) {
+            meta.create_gate("ripemd160 add", |meta| {
+                let mut cb = BaseConstraintBuilder::default();
+
+                let sum = (0..n).fold(0.expr(), |acc, i| {
+                    acc + meta.query_advice(value, Rotation(i))
+                });
+                let carry = meta.query_advice(aux, Rotation(n));
+                cb.require_in_set(
+                    "carry is less than the number of terms",
+                    carry.clone(),
+                    (0..n as u64).map(|c| c.expr()).collect(),
+                );
+                cb.require_equal(
+                    "sum(terms) == out + carry * 2^32",
+                    sum,
+                    meta.query_advice(value, Rotation(n)) + carry * two_pow_32.clone(),
+                );
+
+                cb.gate(meta.query_selector(q_add))
+            });
+        }
+
+        meta.create_gate("ripemd160 select", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let cond = meta.query_advice(aux, Rotation(2));
+            cb.require_boolean("condition is boolean", cond.clone());
+            for column in [value, rlc] {
+                let [a, b, out] = [0, 1, 2].map(|i| meta.query_advice(column, Rotation(i)));
+                cb.require_equal(
+                    "out == cond ? b : a",
+                    out,
+                    a.clone() + cond.clone() * (b - a),
+                );
+            }
+
+            cb.gate(meta.query_selector(q_select))
+        });
+
+        meta.create_gate("ripemd160 head", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let is_final = meta.query_advice(is_final, Rotation::cur());
+            cb.require_boolean("is_final is boolean", is_final.clone());
+            // a block after a block ending in padding can only hold the bit length
+            cb.require_zero(
+                "is_pad::prev ==> is_final",
+                meta.query_advice(is_pad[3], Rotation::cur()) * (1.expr() - is_final),
+            );
+
+            cb.gate(meta.query_selector(q_head))
+        });
+
+        meta.create_gate("ripemd160 message", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            cb.require_equal(
+                "is_final == is_final::prev",
+                meta.query_advice(is_final, Rotation::cur()),
+                meta.query_advice(is_final, Rotation::prev()),
+            );
+            let mut pad_prev = meta.query_advice(is_pad[3], Rotation::prev());
+            let mut len = meta.query_advice(aux, Rotation::prev());
+            let mut acc = meta.query_advice(rlc, Rotation::prev());
+            for j in 0..4 {
+                let pad = meta.query_advice(is_pad[j], Rotation::cur());
+                let byte = meta.query_advice(bytes[j], Rotation::cur());
+                cb.require_boolean("is_pad is boolean", pad.clone());
+                cb.require_zero(
+                    "is_pad::prev ==> is_pad",
+                    pad_prev.clone() * (1.expr() - pad.clone()),
+                );
+                cb.require_zero(
+                    "the first padding byte is 0x80",
+                    (pad.clone() - pad_prev) * (byte.clone() - 0x80.expr()),
+                );
+                len = len + 1.expr() - pad.clone();
+                acc = acc * (pad.clone() + (1.expr() - pad.clone()) * r.clone())
+                    + (1.expr() - pad.clone()) * byte;
+                pad_prev = pad;
+            }
+            cb.require_equal(
+                "len == len::prev + non-padding bytes",
+                meta.query_advice(aux, Rotation::cur()),
+                len,
+            );
+            cb.require_equal(
+                "rlc == rlc::prev accumulated with the non-padding bytes",
+                meta.query_advice(rlc, Rotation::cur()),
+                acc,
+            );
+
+            cb.gate(meta.query_selector(q_msg))
+        });
+
+        meta.create_gate("ripemd160 message body", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            for (pad_prev, byte) in rest_padding_bytes(meta, &bytes, &is_pad, 0) {
+                cb.require_zero("padding byte is 0", pad_prev * byte);
+            }
+
+            cb.gate(meta.query_selector(q_msg_body))
+        });
+
+        // Enabled at the 14th message word, so that the 15th and 16th ones hold the bit length
+        // in the final block.
+        meta.create_gate("ripemd160 message length", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let is_final = meta.query_advice(is_final, Rotation::cur());
+            let not_final = 1.expr() - is_final.clone();
+            cb.require_zero(
+                "is_final ==> the padding starts before the bit length",
+                is_final.clone() * (1.expr() - meta.query_advice(is_pad[3], Rotation::cur())),
+            );
+            for i in [1, 2] {
+                for (pad_prev, byte) in rest_padding_bytes(meta, &bytes, &is_pad, i) {
+                    cb.require_zero(
+                        "!is_final ==> padding byte is 0",
+                        not_final.clone() * pad_prev * byte,
+                    );
+                }
+            }
+            cb.require_equal(
+                "is_final ==> low word of the bit length == 8 * len",
+                is_final.clone() * meta.query_advice(value, Rotation::next()),
+                is_final.clone() * 8.expr() * meta.query_advice(aux, Rotation(2)),
+            );
+            cb.require_zero(
+                "is_final ==> high word of the bit length == 0",
+                is_final * meta.query_advice(value, Rotation(2)),
+            );
+
+            cb.gate(meta.query_selector(q_msg_length))
+        });
+
+        meta.create_gate("ripemd160 rlc word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let rlc_prev = meta.query_advice(rlc, Rotation::prev());
+            let word_rlc = word_rlc(meta, &bytes, &r_pows);
+            cb.require_equal(
+                "rlc == rlc::prev * r^4 + rlc(word)",
+                meta.query_advice(rlc, Rotation::cur()),
+                rlc_prev * r_pows[4].clone() + word_rlc,
+            );
+
+            cb.gate(meta.query_selector(q_rlc_word))
+        });
+
+        meta.create_gate("ripemd160 rlc first word", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let word_rlc = word_rlc(meta, &bytes, &r_pows);
+            cb.require_equal(
+                "rlc == rlc(word)",
+                meta.query_advice(rlc, Rotation::cur()),
+                word_rlc,
+            );
+
+            cb.gate(meta.query_selector(q_rlc_word_first))
+        });
+
+        meta.annotate_lookup_column(bitwise_table[0], || "RIPEMD160_bitwise_a");
+        meta.annotate_lookup_column(bitwise_table[1], || "RIPEMD160_bitwise_b");
+        meta.annotate_lookup_column(bitwise_table[2], || "RIPEMD160_bitwise_a^b");
+        meta.annotate_lookup_column(bitwise_table[3], || "RIPEMD160_bitwise_a&b");
+        meta.annotate_lookup_column(bitwise_table[4], || "RIPEMD160_bitwise_a+256b");
+
+        Self {
+            q_word,
+            q_bitwise,
+            q_rotl,
+            q_add,
+            q_select,
+            q_head,
+            q_msg,
+            q_msg_body,
+            q_msg_length,
+            q_rlc_word,
+            q_rlc_word_first,
+            rotl_shift,
+            value,
+            bytes,
+            is_pad,
+            is_final,
+            aux,
+            rlc,
+            bitwise_table,
+            ripemd160_table,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: Field> Ripemd160CircuitConfig<F> {
+    fn load_bitwise_table(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "ripemd160 bitwise table",
+            |mut table| {
+                for (offset, (a, b)) in (0..256u64)
+                    .flat_map(|b| (0..256u64).map(move |a| (a, b)))
+                    .enumerate()
+                {
+                    for (&column, value) in
+                        self.bitwise_table
+                            .iter()
+                            .zip([a, b, a ^ b, a & b, a + 256 * b])
+                    {
+                        table.assign_cell(
+                            || format!("bitwise table row {offset}"),
+                            column,
+                            offset,
+                            || Value::known(F::from(value)),
+                        )?;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+
+    /// Assign the hashes, padded with hashes of the empty input up to
+    /// `max_blocks` blocks when it is not 0.
+    pub(crate) fn assign(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        events: &[Ripemd160],
+        max_blocks: usize,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<(), Error> {
+        self.load_bitwise_table(layouter)?;
+
+        let total_blocks = events.iter().map(num_blocks).sum::<usize>();
+        if max_blocks != 0 && total_blocks > max_blocks {
+            log::error!("ripemd160 blocks ({total_blocks}) exceed limit ({max_blocks})");
+            return Err(Error::Synthesis);
+        }
+        let padding = Ripemd160::default();
+        let num_padding = max_blocks.saturating_sub(total_blocks);
+
+        layouter.assign_region(
+            || "ripemd160 circuit",
+            |mut region| {
+                let mut assigner = Assigner {
+                    config: self,
+                    region: &mut region,
+                    offset: 0,
+                    challenge: challenges.keccak_input(),
+                };
+                let mut carry = None;
+                let events = events
+                    .iter()
+                    .map(|event| (event, true))
+                    .chain(std::iter::repeat((&padding, false)).take(num_padding));
+                for (event, is_real) in events {
+                    let (padded, is_pad) = pad(&event.input);
+                    let num_blocks = padded.len() / 64;
+                    for (i, (block, is_pad)) in padded.chunks(64).zip(is_pad.chunks(64)).enumerate()
+                    {
+                        carry = Some(assigner.assign_block(
+                            block,
+                            is_pad,
+                            i + 1 == num_blocks,
+                            carry.as_ref(),
+                        )?);
+                    }
+                    let last = carry.as_ref().expect("at least one block per hash");
+                    let digest = last
+                        .output
+                        .iter()
+                        .flat_map(|word| (*word as u32).to_le_bytes())
+                        .collect::<Vec<_>>();
+                    if is_real && digest != event.digest {
+                        log::error!("ripemd160 digest mismatch for {event:?}");
+                        return Err(Error::Synthesis);
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Pairs of the padding flag of the previous byte and the byte, for the bytes of the message
+/// word at `rotation`.
+fn rest_padding_bytes<F: Field>(
+    meta: &mut VirtualCells<'_, F>,
+    bytes: &[Column<Advice>; 4],
+    is_pad: &[Column<Advice>; 4],
+    rotation: i32,
+) -> Vec<(Expression<F>, Expression<F>)> {
+    (0..4)
+        .map(|j| {
+            let pad_prev = if j == 0 {
+                meta.query_advice(is_pad[3], Rotation(rotation - 1))
+            } else {
+                meta.query_advice(is_pad[j - 1], Rotation(rotation))
+            };
+            (pad_prev, meta.query_advice(bytes[j], Rotation(rotation)))
+        })
+        .collect()
+}
+
+/// RLC of the little-endian bytes of the current row, in that order.
+fn word_rlc<F: Field>(
+    meta: &mut VirtualCells<'_, F>,
+    bytes: &[Column<Advice>; 4],
+    r_pows: &[Expression<F>],
+) -> Expression<F> {
+    bytes
+        .iter()
+        .enumerate()
+        .fold(0.expr(), |acc, (j, &column)| {
+            acc + meta.query_advice(column, Rotation::cur()) * r_pows[3 - j].clone()
+        })
+}
+
+/// Padded input, with the padding flags of its bytes.
+fn pad(input: &[u8]) -> (Vec<u8>, Vec<bool>) {
+    let len = num_blocks_of_len(input.len()) * 64;
+    let mut padded = input.to_vec();
+    padded.push(0x80);
+    padded.resize(len - 8, 0);
+    padded.extend_from_slice(&(8 * input.len() as u64).to_le_bytes());
+    let is_pad = (0..len).map(|i| i >= input.len()).collect();
+    (padded, is_pad)
+}
+
+/// Number of blocks of an input of `len` bytes, padded with at least 9 bytes.
+fn num_blocks_of_len(len: usize) -> usize {
+    (len + 9).div_ceil(64)
+}
+
+/// Number of blocks of a hash.
+fn num_blocks(event: &Ripemd160) -> usize {
+    num_blocks_of_len(event.input.len())
+}
+
+/// A 32-bit word assigned in the value column.
+#[derive(Clone, Debug)]
+struct Word<F: Field> {
+    cell: AssignedCell<F, F>,
+    value: u64,
+}
+
+/// A row with its word, auxiliary witness, RLC and message state.
+struct Row<F: Field> {
+    word: Word<F>,
+    aux: Word<F>,
+    rlc: AssignedCell<F, F>,
+    is_pad: Word<F>,
+    is_final: Word<F>,
+}
+
+/// Source of the value of a cell.
+enum Src<'w, F: Field> {
+    /// Copy of an assigned word
+    Word(&'w Word<F>),
+    /// Constant
+    Const(u64),
+    /// Free witness
+    Free(u64),
+}
+
+/// Padding flags and final block flag of a message word.
+struct Msg<'w, F: Field> {
+    is_pad: [Src<'w, F>; 4],
+    is_final: Src<'w, F>,
+}
+
+impl<F: Field> Default for Msg<'_, F> {
+    fn default() -> Self {
+        Self {
+            is_pad: [(); 4].map(|_| Src::Free(0)),
+            is_final: Src::Free(0),
+        }
+    }
+}
+
+/// State carried from a block to the next one.
+struct Carry<F: Field> {
+    /// chaining value
+    h: Vec<Word<F>>,
+    input_rlc: AssignedCell<F, F>,
+    input_len: Word<F>,
+    /// whether the last byte of the block is padding
+    is_pad: Word<F>,
+    is_final: Word<F>,
+    output: [u64; 5],
+}
+
+struct Assigner<'r, 'a, F: Field> {
+    config: &'r Ripemd160CircuitConfig<F>,
+    region: &'r mut Region<'a, F>,
+    offset: usize,
+    challenge: Value<F>,
+}
+
+impl<F: Field> Assigner<'_, '_, F> {
+    fn cell(&mut self, column: Column<Advice>, src: Src<'_, F>) -> Result<Word<F>, Error> {
+        let offset = self.offset;
+        Ok(match src {
+            Src::Word(word) => Word {
+                cell: word
+                    .cell
+                    .copy_advice(|| "ripemd160 cell", self.region, column, offset)?,
+                value: word.value,
+            },
+            Src::Const(value) => Word {
+                cell: self.region.assign_advice_from_constant(
+                    || "ripemd160 cell",
+                    column,
+                    offset,
+                    F::from(value),
+                )?,
+                value,
+            },
+            Src::Free(value) => Word {
+                cell: self.region.assign_advice(
+                    || "ripemd160 cell",
+                    column,
+                    offset,
+                    || Value::known(F::from(value)),
+                )?,
+                value,
+            },
+        })
+    }
+
+    fn row(
+        &mut self,
+        value: Src<'_, F>,
+        aux: Src<'_, F>,
+        rlc: Value<F>,
+        msg: Msg<'_, F>,
+    ) -> Result<Row<F>, Error> {
+        let config = self.config;
+        let offset = self.offset;
+
+        let word = self.cell(config.value, value)?;
+        for (&column, byte) in config.bytes.iter().zip((word.value as u32).to_le_bytes()) {
+            self.region.assign_advice(
+                || "ripemd160 byte",
+                column,
+                offset,
+                || Value::known(F::from(byte as u64)),
+            )?;
+        }
+        let aux = self.cell(config.aux, aux)?;
+        let rlc = self
+            .region
+            .assign_advice(|| "ripemd160 rlc", config.rlc, offset, || rlc)?;
+        let mut is_pad = None;
+        for (&column, src) in config.is_pad.iter().zip(msg.is_pad) {
+            is_pad = Some(self.cell(column, src)?);
+        }
+        let is_final = self.cell(config.is_final, msg.is_final)?;
+        config.q_word.enable(self.region, offset)?;
+
+        self.offset += 1;
+        Ok(Row {
+            word,
+            aux,
+            rlc,
+            is_pad: is_pad.expect("4 padding flags"),
+            is_final,
+        })
+    }
+
+    fn word(&mut self, value: Src<'_, F>) -> Result<Word<F>, Error> {
+        Ok(self
+            .row(value, Src::Free(0), Value::known(F::zero()), Msg::default())?
+            .word)
+    }
+
+    fn word_with_aux(&mut self, value: Src<'_, F>, aux: Src<'_, F>) -> Result<Word<F>, Error> {
+        Ok(self
+            .row(value, aux, Value::known(F::zero()), Msg::default())?
+            .word)
+    }
+
+    /// Row in the RLC of the little-endian `word`, accumulated over `rlc_prev`.
+    fn rlc_row(
+        &mut self,
+        selector: Selector,
+        word: Src<'_, F>,
+        value: u64,
+        rlc_prev: Value<F>,
+    ) -> Result<Row<F>, Error> {
+        let rlc = rlc_prev.zip(self.challenge).map(|(rlc_prev, r)| {
+            (value as u32)
+                .to_le_bytes()
+                .iter()
+                .fold(rlc_prev, |acc, &byte| acc * r + F::from(byte as u64))
+        });
+        selector.enable(self.region, self.offset)?;
+        self.row(word, Src::Free(0), rlc, Msg::default())
+    }
+
+    /// op(x, y)
+    fn bitwise(&mut self, op: Bitwise, x: &Word<F>, y: &Word<F>) -> Result<Word<F>, Error> {
+        self.config.q_bitwise[op as usize].enable(self.region, self.offset)?;
+        self.word(Src::Word(x))?;
+        self.word(Src::Word(y))?;
+        self.word(Src::Free(op.apply(x.value, y.value)))
+    }
+
+    /// rotl(x, s)
+    fn rotl(&mut self, x: &Word<F>, s: u32) -> Result<Word<F>, Error> {
+        self.config.q_rotl.enable(self.region, self.offset)?;
+        self.region.assign_fixed(
+            || "ripemd160 rotl shift",
+            self.config.rotl_shift,
+            self.offset,
+            || Value::known(F::from(1 << (16 - s))),
+        )?;
+        self.word_with_aux(Src::Word(x), Src::Free(x.value >> (32 - s)))?;
+        self.word(Src::Free((x.value as u32).rotate_left(s) as u64))
+    }
+
+    /// Wrapping addition of 2 to 4 words.
+    fn add<const N: usize>(&mut self, terms: [Src<'_, F>; N]) -> Result<Word<F>, Error> {
+        self.config.q_add[N - 2].enable(self.region, self.offset)?;
+        let mut sum = 0;
+        for term in terms {
+            sum += self.word(term)?.value;
+        }
+        self.word_with_aux(Src::Free(sum & u32::MAX as u64), Src::Free(sum >> 32))
+    }
+
+    /// cond ? b : a
+    fn select(&mut self, cond: &Word<F>, a: Src<'_, F>, b: Src<'_, F>) -> Result<Word<F>, Error> {
+        self.config.q_select.enable(self.region, self.offset)?;
+        let a = self.word(a)?;
+        let b = self.word(b)?;
+        let out = if cond.value == 1 { b.value } else { a.value };
+        self.word_with_aux(Src::Free(out), Src::Word(cond))
+    }
+
+    /// cond ? 0 : a, on the RLC column. `a` is zero when it is `None`.
+    fn select_rlc(
+        &mut self,
+        cond: &Word<F>,
+        a: Option<&AssignedCell<F, F>>,
+    ) -> Result<AssignedCell<F, F>, Error> {
+        let config = self.config;
+        config.q_select.enable(self.region, self.offset)?;
+        let a_value = a.map_or(Value::known(F::zero()), |a| a.value().copied());
+        let a_row = self.row(Src::Free(0), Src::Free(0), a_value, Msg::default())?;
+        if let Some(a) = a {
+            self.region.constrain_equal(a.cell(), a_row.rlc.cell())?;
+        }
+        // the RLC of the zero word is zero
+        let b_row = self.rlc_row(
+            config.q_rlc_word_first,
+            Src::Const(0),
+            0,
+            Value::known(F::zero()),
+        )?;
+        let out = if cond.value == 1 {
+            b_row.rlc
+        } else {
+            a_row.rlc
+        };
+        Ok(self
+            .row(
+                Src::Free(0),
+                Src::Word(cond),
+                out.value().copied(),
+                Msg::default(),
+            )?
+            .rlc)
+    }
+
+    /// The boolean function of the given round on x, y and z.
+    fn f(&mut self, round: usize, x: &Word<F>, y: &Word<F>, z: &Word<F>) -> Result<Word<F>, Error> {
+        match round {
+            // x ^ y ^ z
+            0 => {
+                let t = self.bitwise(Bitwise::Xor, x, y)?;
+                self.bitwise(Bitwise::Xor, &t, z)
+            }
+            // (x & y) | (!x & z) == z ^ (x & (y ^ z))
+            1 => {
+                let t = self.bitwise(Bitwise::Xor, y, z)?;
+                let t = self.bitwise(Bitwise::And, x, &t)?;
+                self.bitwise(Bitwise::Xor, z, &t)
+            }
+            // (x | !y) ^ z == !((!x & y) ^ z)
+            2 => {
+                let t = self.bitwise(Bitwise::AndN, x, y)?;
+                self.bitwise(Bitwise::Xnor, &t, z)
+            }
+            // (x & z) | (y & !z) == y ^ (z & (x ^ y))
+            3 => {
+                let t = self.bitwise(Bitwise::Xor, x, y)?;
+                let t = self.bitwise(Bitwise::And, z, &t)?;
+                self.bitwise(Bitwise::Xor, y, &t)
+            }
+            // x ^ (y | !z) == !(x ^ (!y & z))
+            4 => {
+                let t = self.bitwise(Bitwise::AndN, y, z)?;
+                self.bitwise(Bitwise::Xnor, x, &t)
+            }
+            _ => unreachable!("RIPEMD-160 has 5 rounds"),
+        }
+    }
+
+    /// The 80 steps of a line on the chaining value `h` and the message words `x`, returning the
+    /// final `[a, b, c, d, e]`.
+    fn line(&mut self, h: &[Word<F>], x: &[Word<F>], is_left: bool) -> Result<Vec<Word<F>>, Error> {
+        let mut v = h.to_vec();
+        for j in 0..80 {
+            let round = j / 16;
+            let (f_round, r, s, k) = if is_left {
+                (round, R[j], S[j], K[round])
+            } else {
+                (4 - round, R_PRIME[j], S_PRIME[j], K_PRIME[round])
+            };
+            let [a, b, c, d, e] = [0, 1, 2, 3, 4].map(|i| &v[i]);
+            let f = self.f(f_round, b, c, d)?;
+            let sum = self.add([Src::Word(a), Src::Word(&f), Src::Word(&x[r]), Src::Const(k)])?;
+            let rotated = self.rotl(&sum, s)?;
+            let t = self.add([Src::Word(&rotated), Src::Word(e)])?;
+            let c_rotated = self.rotl(c, 10)?;
+            v = vec![e.clone(), t, b.clone(), c_rotated, d.clone()];
+        }
+        Ok(v)
+    }
+
+    /// Assign a block of the padded input with the padding flags of its bytes, which starts a
+    /// hash when the carried block is final and otherwise continues from `carry`.
+    fn assign_block(
+        &mut self,
+        block: &[u8],
+        is_pad: &[bool],
+        is_final: bool,
+        carry: Option<&Carry<F>>,
+    ) -> Result<Carry<F>, Error> {
+        let config = self.config;
+        let start = self.offset;
+        let is_first =
+            self.word(carry.map_or(Src::Const(1), |carry| Src::Word(&carry.is_final)))?;
+
+        // start from the initial state or continue from the carried one
+        // the carried words are dummy zeros in the first block of the circuit
+        let mut h = Vec::with_capacity(5);
+        for (i, &iv) in IV.iter().enumerate() {
+            let a = carry.map_or(Src::Free(0), |carry| Src::Word(&carry.h[i]));
+            h.push(self.select(&is_first, a, Src::Const(iv))?);
+        }
+        let input_rlc = self.select_rlc(&is_first, carry.map(|carry| &carry.input_rlc))?;
+        let input_len = self.select(
+            &is_first,
+            carry.map_or(Src::Free(0), |carry| Src::Word(&carry.input_len)),
+            Src::Const(0),
+        )?;
+        let is_pad_prev = self.select(
+            &is_first,
+            carry.map_or(Src::Free(0), |carry| Src::Word(&carry.is_pad)),
+            Src::Const(0),
+        )?;
+
+        // head row and message words
+        config.q_head.enable(self.region, self.offset)?;
+        let head = self.row(
+            Src::Free(0),
+            Src::Word(&input_len),
+            input_rlc.value().copied(),
+            Msg {
+                is_pad: [
+                    Src::Free(0),
+                    Src::Free(0),
+                    Src::Free(0),
+                    Src::Word(&is_pad_prev),
+                ],
+                is_final: Src::Free(is_final as u64),
+            },
+        )?;
+        self.region
+            .constrain_equal(input_rlc.cell(), head.rlc.cell())?;
+        let mut len = input_len.value;
+        let mut rlc = input_rlc.value().copied();
+        let mut x = Vec::with_capacity(16);
+        let mut last = None;
+        for (i, (bytes, is_pad)) in block.chunks(4).zip(is_pad.chunks(4)).enumerate() {
+            config.q_msg.enable(self.region, self.offset)?;
+            if i < 14 {
+                config.q_msg_body.enable(self.region, self.offset)?;
+            }
+            if i == 13 {
+                config.q_msg_length.enable(self.region, self.offset)?;
+            }
+            for (&byte, &is_pad) in bytes.iter().zip(is_pad) {
+                if !is_pad {
+                    len += 1;
+                    rlc = rlc
+                        .zip(self.challenge)
+                        .map(|(rlc, r)| rlc * r + F::from(byte as u64));
+                }
+            }
+            let row = self.row(
+                Src::Free(u32::from_le_bytes(bytes.try_into().unwrap()) as u64),
+                Src::Free(len),
+                rlc,
+                Msg {
+                    is_pad: [0, 1, 2, 3].map(|j| Src::Free(is_pad[j] as u64)),
+                    is_final: Src::Free(is_final as u64),
+                },
+            )?;
+            x.push(row.word.clone());
+            last = Some(row);
+        }
+        let last = last.expect("16 message words");
+
+        // compression
+        let left = self.line(&h, &x, true)?;
+        let right = self.line(&h, &x, false)?;
+        let mut h_next = Vec::with_capacity(5);
+        for i in 0..5 {
+            h_next.push(self.add([
+                Src::Word(&h[(i + 1) % 5]),
+                Src::Word(&left[(i + 2) % 5]),
+                Src::Word(&right[(i + 3) % 5]),
+            ])?);
+        }
+
+        // output and its RLC
+        let mut rlc = Value::known(F::zero());
+        let mut output_rlc = None;
+        for (i, word) in h_next.iter().enumerate() {
+            let selector = if i == 0 {
+                config.q_rlc_word_first
+            } else {
+                config.q_rlc_word
+            };
+            let row = self.rlc_row(selector, Src::Word(word), word.value, rlc)?;
+            rlc = row.rlc.value().copied();
+            output_rlc = Some(row.rlc);
+        }
+        let output_rlc = output_rlc.expect("output has 5 words");
+        debug_assert_eq!(self.offset - start, ROWS_PER_BLOCK);
+
+        // ripemd160 table row
+        let table = &config.ripemd160_table;
+        self.region.assign_fixed(
+            || "ripemd160 table q_enable",
+            table.q_enable,
+            start,
+            || Value::known(F::one()),
+        )?;
+        for (cell, column) in [
+            (&head.is_final.cell, table.is_final),
+            (&last.rlc, table.input_rlc),
+            (&last.aux.cell, table.input_len),
+            (&output_rlc, table.output_rlc),
+        ] {
+            cell.copy_advice(|| "ripemd160 table", self.region, column, start)?;
+        }
+
+        Ok(Carry {
+            output: h_next
+                .iter()
+                .map(|word| word.value)
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+            h: h_next,
+            input_rlc: last.rlc,
+            input_len: last.aux,
+            is_pad: last.is_pad,
+            is_final: head.is_final,
+        })
+    }
+}
+
+/// RIPEMD-160 circuit for precompile ripemd160
+#[derive(Clone, Debug, Default)]
+pub struct Ripemd160Circuit<F: Field> {
+    /// RIPEMD-160 hashes
+    pub events: Vec<Ripemd160>,
+    /// Maximum number of blocks, or 0 to assign only the hashes
+    pub max_blocks: usize,
+    _marker: PhantomData<F>,
+}
+
+impl<F: Field> Ripemd160Circuit<F> {
+    /// Return a new Ripemd160Circuit working within `row_limit` rows, or with
+    /// exactly the rows of the hashes if it is 0.
+    pub fn new(events: Vec<Ripemd160>, row_limit: usize) -> Self {
+        if row_limit != 0 {
+            let expected_rows = Self::expected_rows(&events);
+            log::info!(
+                "ripemd160 circuit work with {} hashes, set with maximum {} rows",
+                events.len(),
+                row_limit
+            );
+            assert!(
+                expected_rows <= row_limit,
+                "no enough rows for ripemd160 circuit, expected {expected_rows}, limit {row_limit}",
+            );
+        }
+        Self {
+            events,
+            max_blocks: row_limit / ROWS_PER_BLOCK,
+            _marker: PhantomData,
+        }
+    }
+
+    fn expected_rows(events: &[Ripemd160]) -> usize {
+        events.iter().map(num_blocks).sum::<usize>() * ROWS_PER_BLOCK
+    }
+}
+
+impl<F: Field> SubCircuit<F> for Ripemd160Circuit<F> {
+    type Config = Ripemd160CircuitConfig<F>;
+
+    fn unusable_rows() -> usize {
+        // The value and aux columns are queried at 5 distinct rotations, so
+        // returns 8 unusable rows.
+        8
+    }
+
+    fn new_from_block(block: &witness::Block) -> Self {
+        Self::new(block.get_ripemd160(), block.circuits_params.max_keccak_rows)
+    }
+
+    fn min_num_rows_block(block: &witness::Block) -> (usize, usize) {
+        let real_rows = Self::expected_rows(&block.get_ripemd160());
+        (
+            real_rows,
+            real_rows
+                .max(block.circuits_params.max_keccak_rows)
+                .max(BITWISE_TABLE_ROWS),
+        )
+    }
+
+    fn synthesize_sub(
+        &self,
+        config: &Self::Config,
+        challenges: &Challenges<Value<F>>,
+        layouter: &mut impl Layouter<F>,
+    ) -> Result<(), Error> {
+        config.assign(layouter, &self.events, self.max_blocks, challenges)
+    }
+}
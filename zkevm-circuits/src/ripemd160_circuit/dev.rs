@@ -0,0 +1,46 @@
+pub use super::Ripemd160Circuit;
+
+use crate::{
+    ripemd160_circuit::{Ripemd160CircuitConfig, Ripemd160CircuitConfigArgs},
+    table::Ripemd160Table,
+    util::{Challenges, Field, SubCircuit, SubCircuitConfig},
+};
+use halo2_proofs::{
+    circuit::{Layouter, SimpleFloorPlanner},
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+
+impl<F: Field> Circuit<F> for Ripemd160Circuit<F> {
+    type Config = (Ripemd160CircuitConfig<F>, Challenges);
+    type FloorPlanner = SimpleFloorPlanner;
+    type Params = ();
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let ripemd160_table = Ripemd160Table::construct(meta);
+        let challenges = Challenges::construct(meta);
+        let challenges_expr = challenges.exprs(meta);
+        (
+            Ripemd160CircuitConfig::new(
+                meta,
+                Ripemd160CircuitConfigArgs {
+                    ripemd160_table,
+                    challenges: challenges_expr,
+                },
+            ),
+            challenges,
+        )
+    }
+
+    fn synthesize(
+        &self,
+        (config, challenges): Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let challenges = challenges.values(&layouter);
+        self.synthesize_sub(&config, &challenges, &mut layouter)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    ripemd160_circuit::{Ripemd160Circuit, ROWS_PER_BLOCK},
+    util::{unusable_rows, SubCircuit},
+};
+use bus_mapping::circuit_input_builder::Ripemd160;
+use halo2_proofs::{dev::MockProver, halo2curves::bn256::Fr};
+
+/// Inputs and digests of the RIPEMD-160 test vectors, covering 1 and 2 blocks and the padding
+/// overflowing into an extra block.
+const VECTORS: [(&str, &str); 6] = [
+    ("", "9c1185a5c5e9fc54612808977ee8f548b2258d31"),
+    ("abc", "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc"),
+    ("message digest", "5d0689ef49d2fae572b881b123a85ffa21595f36"),
+    (
+        "abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        "12a053384a9c0c88e405a06c27dcf49ada62eb2b",
+    ),
+    (
+        "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        "b0e20b6e3116640286ed3a87a5713079b21f5189",
+    ),
+    (
+        "12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        "9b752e45573d4b39f4dbd3323cab82bf63326bfb",
+    ),
+];
+
+fn event(input: &str, digest: &str) -> Ripemd160 {
+    Ripemd160 {
+        input: input.as_bytes().to_vec(),
+        digest: hex::decode(digest).unwrap().try_into().unwrap(),
+    }
+}
+
+fn run(
+    events: Vec<Ripemd160>,
+    row_limit: usize,
+) -> Result<(), Vec<halo2_proofs::dev::VerifyFailure>> {
+    let circuit = Ripemd160Circuit::<Fr>::new(events, row_limit);
+    let prover = MockProver::<Fr>::run(17, &circuit, vec![]).unwrap();
+    prover.verify_par()
+}
+
+#[test]
+fn ripemd160_circuit_unusable_rows() {
+    assert_eq!(
+        Ripemd160Circuit::<Fr>::unusable_rows(),
+        unusable_rows::<Fr, Ripemd160Circuit::<Fr>>(),
+    )
+}
+
+#[test]
+fn ripemd160_circuit_vectors() {
+    for (input, digest) in VECTORS {
+        assert_eq!(run(vec![event(input, digest)], 0), Ok(()));
+    }
+}
+
+#[test]
+fn ripemd160_circuit_multiple_hashes_with_padding() {
+    let events = VECTORS
+        .iter()
+        .map(|&(input, digest)| event(input, digest))
+        .collect();
+    assert_eq!(run(events, 20 * ROWS_PER_BLOCK), Ok(()));
+}
+
+#[test]
+fn ripemd160_circuit_wrong_digest() {
+    let mut event = event(VECTORS[1].0, VECTORS[1].1);
+    event.digest[0] ^= 1;
+    let circuit = Ripemd160Circuit::<Fr>::new(vec![event], 0);
+    assert!(MockProver::<Fr>::run(17, &circuit, vec![]).is_err());
+}
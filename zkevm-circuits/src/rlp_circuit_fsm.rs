@@ -17,7 +17,10 @@ use crate::{
         Block, DataTable, Format, RlpFsmWitnessGen, RlpFsmWitnessRow, RlpTag, RomTableRow, State,
         State::{DecodeTagStart, End},
         Tag,
-        Tag::{AccessListAddress, AccessListStorageKey, BeginObject, EndObject, EndVector, TxType},
+        Tag::{
+            AccessListAddress, AccessListStorageKey, AuthChainId, BeginObject, BeginVector,
+            EndObject, EndVector, TxType,
+        },
         Transaction,
     },
 };
@@ -317,6 +320,9 @@ pub struct RlpCircuitConfig<F> {
     /// Boolean to reduce the circuit's degree
     /// Indicates the end of storage key list in a particular access list item
     is_storage_key_list_end: Column<Advice>,
+    /// Boolean to reduce the circuit's degree
+    /// Indicates the start of the authorization list (EIP-7702) right after an access list
+    is_authorization_list_begin: Column<Advice>,
     /// Decoding table id change
     /// id = (tx_id, format, depth, al_idx, sk_idx)
     stack_op_id_diff: Column<Advice>,
@@ -422,6 +428,7 @@ impl<F: Field> RlpCircuitConfig<F> {
             is_new_access_list_storage_key,
             is_access_list_end,
             is_storage_key_list_end,
+            is_authorization_list_begin,
             is_push_op_lookup,
             is_pop_op_lookup,
             is_update_op_lookup,
@@ -451,6 +458,7 @@ impl<F: Field> RlpCircuitConfig<F> {
             meta.advice_column(),
             meta.advice_column(),
             meta.advice_column(),
+            meta.advice_column(),
         );
 
         let tag_value_acc = meta.advice_column_in(SecondPhase);
@@ -500,6 +508,7 @@ impl<F: Field> RlpCircuitConfig<F> {
         is_tag!(is_tag_end_vector, EndVector);
         is_tag!(is_access_list_address, AccessListAddress);
         is_tag!(is_access_list_storage_key, AccessListStorageKey);
+        is_tag!(is_auth_chain_id, AuthChainId);
 
         //////////////////////////////////////////////////////////
         //////////// data table checks. //////////////////////////
@@ -1540,7 +1549,11 @@ impl<F: Field> RlpCircuitConfig<F> {
             cb.require_equal(
                 "is_new_access_list_address",
                 meta.query_advice(is_new_access_list_address, Rotation::cur()),
-                and::expr([is_access_list_address(meta), is_decode_tag_start(meta)]),
+                and::expr([
+                    // the tuples of an authorization list are numbered like access list items
+                    is_access_list_address(meta) + is_auth_chain_id(meta),
+                    is_decode_tag_start(meta),
+                ]),
             );
             cb.require_equal(
                 "is_new_access_list_storage_key",
@@ -1563,6 +1576,14 @@ impl<F: Field> RlpCircuitConfig<F> {
                     is_tag_end_vector(meta),
                 ]),
             );
+            cb.require_equal(
+                "is_authorization_list_begin",
+                meta.query_advice(is_authorization_list_begin, Rotation::cur()),
+                and::expr([
+                    meta.query_advice(is_access_list_end, Rotation::prev()),
+                    is_tag_begin_vector(meta),
+                ]),
+            );
 
             cb.gate(meta.query_fixed(q_enabled, Rotation::cur()))
         });
@@ -1613,6 +1634,19 @@ impl<F: Field> RlpCircuitConfig<F> {
                 },
             );
 
+            // The authorization tuples are numbered after the items of the access list, which
+            // has been cleared on the previous row, so that their stack keys never collide.
+            cb.condition(
+                meta.query_advice(is_authorization_list_begin, Rotation::cur()),
+                |cb| {
+                    cb.require_equal(
+                        "al_idx = al_idx before the end of access list",
+                        meta.query_advice(rlp_table.access_list_idx, Rotation(-2)),
+                        meta.query_advice(rlp_table.access_list_idx, Rotation::cur()),
+                    );
+                },
+            );
+
             cb.gate(meta.query_fixed(q_enabled, Rotation::cur()))
         });
 
@@ -1662,6 +1696,7 @@ impl<F: Field> RlpCircuitConfig<F> {
                     and::expr([
                         not::expr(meta.query_advice(is_new_access_list_address, Rotation::cur())),
                         not::expr(meta.query_advice(is_access_list_end, Rotation::cur())),
+                        not::expr(meta.query_advice(is_authorization_list_begin, Rotation::cur())),
                     ]),
                     |cb| {
                     cb.require_equal(
@@ -2488,6 +2523,7 @@ impl<F: Field> RlpCircuitConfig<F> {
             is_new_access_list_storage_key,
             is_access_list_end,
             is_storage_key_list_end,
+            is_authorization_list_begin,
             stack_op_id_diff,
             is_stack_depth_zero,
             is_stack_depth_one,
@@ -2746,7 +2782,7 @@ impl<F: Field> RlpCircuitConfig<F> {
         )?;
 
         let is_new_access_list_address = witness.state_machine.state == DecodeTagStart
-            && witness.state_machine.tag == AccessListAddress;
+            && matches!(witness.state_machine.tag, AccessListAddress | AuthChainId);
         region.assign_advice(
             || "is_new_access_list_address",
             self.is_new_access_list_address,
@@ -2777,6 +2813,16 @@ impl<F: Field> RlpCircuitConfig<F> {
             row,
             || Value::known(F::from(is_storage_key_list_end as u64)),
         )?;
+        let is_authorization_list_begin = witness.state_machine.tag == BeginVector
+            && witness_prev.map_or(false, |prev| {
+                prev.state_machine.tag == EndVector && prev.state_machine.depth == 2
+            });
+        region.assign_advice(
+            || "is_authorization_list_begin",
+            self.is_authorization_list_begin,
+            row,
+            || Value::known(F::from(is_authorization_list_begin as u64)),
+        )?;
         for (idx, col) in [
             self.is_stack_depth_zero,
             self.is_stack_depth_one,
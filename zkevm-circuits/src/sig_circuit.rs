@@ -21,7 +21,7 @@ use crate::{
     },
     keccak_circuit::KeccakCircuit,
     sig_circuit::{ecdsa::ecdsa_verify_no_pubkey_check, utils::*},
-    table::{KeccakTable, SigTable},
+    table::{KeccakTable, SigTable, SIG_V_SECP256R1},
     util::{Challenges, Expr, Field, SubCircuit, SubCircuitConfig},
 };
 use eth_types::{
@@ -31,13 +31,13 @@ use eth_types::{
 use ff::PrimeField;
 use halo2_base::{
     gates::{range::RangeConfig, GateInstructions, RangeInstructions},
-    utils::{modulus, CurveAffineExt},
+    utils::{fe_to_biguint, modulus, CurveAffineExt},
     AssignedValue, Context, QuantumCell, SKIP_FIRST_PASS,
 };
 
 use halo2_ecc::{
     bigint::CRTInteger,
-    ecc::EccChip,
+    ecc::{EcPoint, EccChip},
     fields::{fp::FpConfig, FieldChip},
 };
 use halo2_proofs::arithmetic::CurveAffine;
@@ -301,6 +301,11 @@ impl<F: Field> SubCircuit<F> for SigCircuit<F> {
             .iter()
             .filter(|tx| !tx.tx_type.is_l1_msg())
             .count()
+            + block
+                .txs
+                .iter()
+                .map(|tx| tx.authorization_sign_datas().len())
+                .sum::<usize>()
             + block.precompile_events.get_ecrecover_events().len();
 
         let ecdsa_verif_r1_count = block.precompile_events.get_p256_verify_events().len();
@@ -530,7 +535,21 @@ impl<F: Field> SigCircuit<F> {
         let (x, y) = pk.into_coordinates();
         let pk_assigned = ecc_chip.load_private(ctx, (Value::known(x), Value::known(y)));
         let pk_is_valid = ecc_chip.is_on_curve_or_infinity::<Affine>(ctx, &pk_assigned);
-        gate.assert_is_const(ctx, &pk_is_valid, F::one());
+        // secp256k1 public keys are recovered from the signature and always on the curve, while
+        // the p256Verify precompile takes an arbitrary public key. Such a signature is reported as
+        // invalid, the verification then runs against the generator so that it stays satisfiable.
+        let is_k1_sig = self.is_k1_sig::<Fp, Affine>();
+        let pk_verified = if is_k1_sig {
+            gate.assert_is_const(ctx, &pk_is_valid, F::one());
+            pk_assigned.clone()
+        } else {
+            let (gx, gy) = Affine::generator().into_coordinates();
+            let generator = EcPoint::construct(
+                ecc_chip.field_chip().load_constant(ctx, fe_to_biguint(&gx)),
+                ecc_chip.field_chip().load_constant(ctx, fe_to_biguint(&gy)),
+            );
+            ecc_chip.select(ctx, &pk_assigned, &generator, &pk_is_valid)
+        };
 
         // build Fq chip from Fp chip
         let fq_chip = FpConfig::<F, Fq>::construct(
@@ -557,7 +576,7 @@ impl<F: Field> SigCircuit<F> {
             ecdsa_verify_no_pubkey_check::<F, Fp, Fq, Affine>(
                 &ecc_chip.field_chip,
                 ctx,
-                &pk_assigned,
+                &pk_verified,
                 &integer_r,
                 &integer_s,
                 &msg_hash,
@@ -575,7 +594,7 @@ impl<F: Field> SigCircuit<F> {
         // check if p256 curve, for precompile p256Verify, there is no need of v in the input data
         // and just use public key (x, y) provided instead.
         // so only secp256k1 signature data need to check v oddness
-        let (sig_is_valid, assigned_y_is_odd) = if self.is_k1_sig::<Fp, Affine>() {
+        let (sig_is_valid, assigned_y_is_odd) = if is_k1_sig {
             let (y_is_ok, assigned_y_is_odd) =
                 self.check_y_oddness(ctx, ecdsa_chip, v, y_coord, pk_is_zero);
             let sig_is_valid = gate.and_many(
@@ -594,11 +613,16 @@ impl<F: Field> SigCircuit<F> {
                 vec![
                     QuantumCell::Existing(sig_is_valid),
                     QuantumCell::Existing(pk_not_zero),
+                    QuantumCell::Existing(pk_is_valid),
                 ],
             );
 
-            // for p256, don't use `assigned_y_is_odd` field, zero as placeholder.
-            (sig_is_valid, gate.load_zero(ctx)) // cache zero ?
+            // for p256, there is no `assigned_y_is_odd`, tag the row with SIG_V_SECP256R1
+            // instead so that it never matches a secp256k1 lookup.
+            (
+                sig_is_valid,
+                gate.load_constant(ctx, F::from(SIG_V_SECP256R1)),
+            )
         };
 
         Ok(AssignedECDSA {
@@ -754,9 +778,6 @@ impl<F: Field> SigCircuit<F> {
     where
         Affine::Base: ff::PrimeField,
     {
-        // build ecc chip from Fp chip
-        let ecc_chip = EccChip::<F, FpConfig<F, Fp>>::construct(ecdsa_chip.clone());
-
         let zero = ecdsa_chip.range.gate.load_zero(ctx);
 
         // ================================================
@@ -846,19 +867,19 @@ impl<F: Field> SigCircuit<F> {
             .iter()
             .map(|&y| QuantumCell::Witness(Value::known(F::from_u128(y as u128))))
             .collect_vec();
-        let pk_assigned = ecc_chip.load_private(ctx, (Value::known(x), Value::known(y)));
 
+        // decompose the public key that was verified, so that the address is bound to it.
         self.assert_crt_int_byte_repr(
             ctx,
             &ecdsa_chip.range,
-            &pk_assigned.x,
+            &assigned_data.pk.x,
             &pk_x_le,
             &powers_of_256_cells,
         )?;
         self.assert_crt_int_byte_repr(
             ctx,
             &ecdsa_chip.range,
-            &pk_assigned.y,
+            &assigned_data.pk.y,
             &pk_y_le,
             &powers_of_256_cells,
         )?;
@@ -326,6 +326,59 @@ fn p256_sign_verify() {
     }
 }
 
+// invalid secp256r1 signatures, as accepted by the p256verify precompile, must not make the
+// circuit unsatisfiable.
+#[test]
+fn p256_invalid_sign_verify() {
+    use super::utils::LOG_TOTAL_NUM_ROWS;
+    use halo2_proofs::halo2curves::{bn256::Fr, CurveAffine};
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    let mut rng = XorShiftRng::seed_from_u64(10000);
+    let mut signatures = Vec::new();
+
+    // the signature does not match the message hash
+    {
+        let (sk, pk) = gen_key_pair_r1(&mut rng);
+        let msg = gen_msg(&mut rng);
+        let msg_hash = secp256r1::Fq::random(&mut rng);
+        let (r, s, v) = sign_r1_with_rng(&mut rng, sk, msg_hash);
+        let sign_data = SignData {
+            signature: (r, s, v),
+            pk,
+            msg: msg.into(),
+            msg_hash: msg_hash + secp256r1::Fq::one(),
+        };
+        assert!(!sign_data.is_valid());
+        signatures.push(sign_data);
+    }
+
+    // the public key is not on the curve
+    {
+        let (sk, pk) = gen_key_pair_r1(&mut rng);
+        let msg = gen_msg(&mut rng);
+        let msg_hash = secp256r1::Fq::random(&mut rng);
+        let (r, s, v) = sign_r1_with_rng(&mut rng, sk, msg_hash);
+        let pk = Secp256r1Affine {
+            x: *pk.coordinates().unwrap().x(),
+            y: pk.coordinates().unwrap().y() + secp256r1::Fp::one(),
+        };
+        assert!(!bool::from(pk.is_on_curve()));
+        let sign_data = SignData {
+            signature: (r, s, v),
+            pk,
+            msg: msg.into(),
+            msg_hash,
+        };
+        assert!(!sign_data.is_valid());
+        signatures.push(sign_data);
+    }
+
+    let k = LOG_TOTAL_NUM_ROWS as u32;
+    run::<Fr>(k, 0, 2, vec![], signatures);
+}
+
 // TODO: this test fail due to signature/pubkey representation(sec1 encoding/compress etc)
 // need to decode correct bytes and re-construct signature/pubkey for testing
 // test rust p256 crate apis: sign & verify
@@ -20,7 +20,7 @@ use self::{
 };
 use crate::{
     evm_circuit::{param::N_BYTES_WORD, util::rlc},
-    table::{AccountFieldTag, LookupTable, MptTable, RwTable, RwTableTag},
+    table::{AccountFieldTag, LookupTable, MptTable, RwTable, RwTableTag, TxReceiptFieldTag},
     util::{Challenges, Expr, Field, SubCircuit, SubCircuitConfig},
     witness::{self, MptUpdates, Rw, RwMap},
 };
@@ -72,6 +72,12 @@ pub struct StateCircuitConfig<F> {
     state_root: Column<Advice>,
     lexicographic_ordering: LexicographicOrderingConfig,
     not_first_access: Column<Advice>,
+    // For a Rw::TxReceipt read of the CumulativeGasUsed, done by the next tx, the gas used by the
+    // next tx. Its limbs are range checked, so the cumulative gas used never decreases.
+    tx_receipt_gas_used: Column<Advice>,
+    tx_receipt_gas_used_limbs: MpiConfig<u64, N_LIMBS_GAS>,
+    // For a Rw::TxReceipt LogLength, 1 if the tx has logs, so the last one is looked up.
+    tx_receipt_has_logs: Column<Advice>,
     lookups: LookupsConfig,
     power_of_randomness: [Expression<F>; N_BYTES_WORD - 1],
     // External tables
@@ -167,6 +173,10 @@ impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
             power_of_randomness.clone(),
         );
 
+        let tx_receipt_gas_used = meta.advice_column();
+        let tx_receipt_gas_used_limbs =
+            MpiChip::configure(meta, selector, tx_receipt_gas_used, lookups);
+
         // annotate columns
         rw_table.annotate_columns(meta);
         mpt_table.annotate_columns(meta);
@@ -180,6 +190,9 @@ impl<F: Field> SubCircuitConfig<F> for StateCircuitConfig<F> {
             state_root,
             lexicographic_ordering,
             not_first_access: meta.advice_column(),
+            tx_receipt_gas_used,
+            tx_receipt_gas_used_limbs,
+            tx_receipt_has_logs: meta.advice_column(),
             lookups,
             power_of_randomness,
             rw_table,
@@ -386,6 +399,9 @@ impl<F: Field> StateCircuitConfig<F> {
                 || mpt_proof_type,
             )?;
 
+            self.assign_tx_receipt_gas_used(region, offset, &rows, offset)?;
+            self.assign_tx_receipt_has_logs(region, offset, row)?;
+
             // TODO: Switch from Rw::Start -> Rw::Padding to simplify this logic.
             // State root assignment is at previous row (offset - 1) because the state root
             // changes on the last access row.
@@ -554,10 +570,69 @@ impl<F: Field> StateCircuitConfig<F> {
                 offset,
                 || mpt_proof_type,
             )?;
+
+            self.assign_tx_receipt_gas_used(region, offset, rows, idx)?;
+            self.assign_tx_receipt_has_logs(region, offset, row)?;
         }
         Ok(is_first_access_vec)
     }
 
+    fn assign_tx_receipt_gas_used(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        rows: &[Rw],
+        idx: usize,
+    ) -> Result<(), Error> {
+        // The CumulativeGasUsed of the next tx is 3 rows below its read, after the LogLength of
+        // this tx and the PostStateOrStatus of the next tx.
+        let gas_used = match (&rows[idx], rows.get(idx + 3)) {
+            (
+                Rw::TxReceipt {
+                    is_write: false,
+                    field_tag: TxReceiptFieldTag::CumulativeGasUsed,
+                    value,
+                    ..
+                },
+                Some(Rw::TxReceipt {
+                    value: next_value, ..
+                }),
+            ) => next_value.saturating_sub(*value),
+            _ => 0,
+        };
+        region.assign_advice(
+            || "tx_receipt_gas_used",
+            self.tx_receipt_gas_used,
+            offset,
+            || Value::known(F::from(gas_used)),
+        )?;
+        self.tx_receipt_gas_used_limbs
+            .assign(region, offset, gas_used)
+    }
+
+    fn assign_tx_receipt_has_logs(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        row: &Rw,
+    ) -> Result<(), Error> {
+        let has_logs = matches!(
+            row,
+            Rw::TxReceipt {
+                field_tag: TxReceiptFieldTag::LogLength,
+                value,
+                ..
+            } if *value != 0
+        );
+        region.assign_advice(
+            || "tx_receipt_has_logs",
+            self.tx_receipt_has_logs,
+            offset,
+            || Value::known(if has_logs { F::one() } else { F::zero() }),
+        )?;
+        Ok(())
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn assign_state_roots(
         &self,
@@ -829,6 +904,10 @@ impl<F: Field> StateCircuitConfig<F> {
         self.sort_keys.annotate_columns_in_region(region, "STATE");
         region.name_column(|| "STATE_selector", self.selector);
         region.name_column(|| "STATE_not_first_access", self.not_first_access);
+        region.name_column(|| "STATE_tx_receipt_gas_used", self.tx_receipt_gas_used);
+        self.tx_receipt_gas_used_limbs
+            .annotate_columns_in_region(region, "STATE_tx_receipt_gas_used");
+        region.name_column(|| "STATE_tx_receipt_has_logs", self.tx_receipt_has_logs);
         region.name_column(|| "STATE_phase2_initial_value", self.initial_value);
         region.name_column(|| "STATE_phase2_mpt_proof_type", self.mpt_proof_type);
         region.name_column(|| "STATE_phase2_state_root", self.state_root);
@@ -1047,6 +1126,7 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
             address: meta.query_advice(c.rw_table.address, Rotation::cur()),
             prev_address: meta.query_advice(c.rw_table.address, Rotation::prev()),
             field_tag: meta.query_advice(c.rw_table.field_tag, Rotation::cur()),
+            prev_field_tag: meta.query_advice(c.rw_table.field_tag, Rotation::prev()),
             storage_key: meta.query_advice(c.rw_table.storage_key, Rotation::cur()),
             value: meta.query_advice(c.rw_table.value, Rotation::cur()),
             value_prev: meta.query_advice(c.rw_table.value, Rotation::prev()),
@@ -1080,6 +1160,10 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
                 + meta.query_advice(first_different_limb.bits[1], Rotation::cur())
                 + meta.query_advice(first_different_limb.bits[2], Rotation::cur()))
             + final_bits_sum.clone() * (1.expr() - final_bits_sum),
+        // this isn't binary! only 0 if the tag changed, i.e. first_different_limb is 0.
+        is_tag_unchanged: (0..5)
+            .map(|idx| meta.query_advice(first_different_limb.bits[idx], Rotation::cur()))
+            .fold(0.expr(), |acc, bit| acc + bit),
         address: MpiQueries::new(meta, c.sort_keys.address),
         storage_key: RlcQueries::new(meta, c.sort_keys.storage_key),
         initial_value: meta.query_advice(c.initial_value, Rotation::cur()),
@@ -1094,6 +1178,9 @@ fn queries<F: Field>(meta: &mut VirtualCells<'_, F>, c: &StateCircuitConfig<F>)
         last_access: 1.expr() - meta.query_advice(c.not_first_access, Rotation::next()),
         state_root: meta.query_advice(c.state_root, Rotation::cur()),
         state_root_prev: meta.query_advice(c.state_root, Rotation::prev()),
+        tx_receipt_gas_used: meta.query_advice(c.tx_receipt_gas_used, Rotation::cur()),
+        next_tx_cumulative_gas_used: meta.query_advice(c.rw_table.value, Rotation(3)),
+        tx_receipt_has_logs: meta.query_advice(c.tx_receipt_has_logs, Rotation::cur()),
     }
 }
 
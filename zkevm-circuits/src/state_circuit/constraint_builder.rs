@@ -4,12 +4,14 @@ use super::{
 };
 use crate::{
     evm_circuit::{param::N_BYTES_WORD, util::not},
-    table::{AccountFieldTag, MPTProofType as ProofType, RwTableTag},
-    util::{Expr, Field},
+    table::{
+        AccountFieldTag, MPTProofType as ProofType, RwTableTag, TxLogFieldTag, TxReceiptFieldTag,
+    },
+    util::{build_tx_log_expression, Expr, Field},
 };
 use gadgets::binary_number::BinaryNumberConfig;
 use halo2_proofs::plonk::Expression;
-use strum::IntoEnumIterator;
+use strum::{EnumCount, IntoEnumIterator};
 
 #[derive(Clone)]
 pub struct RwTableQueries<F: Field> {
@@ -22,6 +24,7 @@ pub struct RwTableQueries<F: Field> {
     pub address: Expression<F>,
     pub prev_address: Expression<F>,
     pub field_tag: Expression<F>,
+    pub prev_field_tag: Expression<F>,
     pub storage_key: Expression<F>,
     pub value: Expression<F>,
     pub value_prev: Expression<F>, // meta.query(value, Rotation::prev())
@@ -51,6 +54,7 @@ pub struct Queries<F: Field> {
     pub tag_bits: [Expression<F>; 4],
     pub id: MpiQueries<F, N_LIMBS_ID>,
     pub is_tag_and_id_unchanged: Expression<F>,
+    pub is_tag_unchanged: Expression<F>,
     pub address: MpiQueries<F, N_LIMBS_ACCOUNT_ADDRESS>,
     pub storage_key: RlcQueries<F, N_BYTES_WORD>,
     pub initial_value: Expression<F>,
@@ -64,6 +68,9 @@ pub struct Queries<F: Field> {
     pub last_access: Expression<F>,
     pub state_root: Expression<F>,
     pub state_root_prev: Expression<F>,
+    pub tx_receipt_gas_used: Expression<F>,
+    pub next_tx_cumulative_gas_used: Expression<F>, // meta.query(value, Rotation(3))
+    pub tx_receipt_has_logs: Expression<F>,
 }
 
 type Constraint<F> = (&'static str, Expression<F>);
@@ -120,6 +127,7 @@ impl<F: Field> ConstraintBuilder<F> {
                 RwTableTag::AccountTransientStorage => {
                     Self::build_account_transient_storage_constraints
                 }
+                RwTableTag::TxCreatedAccount => Self::build_tx_created_account_constraints,
             };
             self.condition(q.tag_matches(tag), |cb| build(cb, q));
         }
@@ -338,6 +346,24 @@ impl<F: Field> ConstraintBuilder<F> {
             "initial TxAccessListAccount value is false",
             q.initial_value(),
         );
+        // Every entry is cold when the tx starts. The EIP-2930 access list, the precompiles and
+        // the coinbase are warmed by the writes of BeginTx, checked by the EVM circuit, so the
+        // first write to an entry warms it.
+        self.condition(q.first_access(), |cb| {
+            cb.require_zero(
+                "TxAccessListAccount first write warms the entry",
+                q.is_write() * (1.expr() - q.value()) * (1.expr() - q.initial_value()),
+            );
+        });
+        // A later write either warms the entry or reverts a write that warmed it.
+        // TODO: only allow warm to cold writes when a failed call is reverted, which the rw rows
+        // alone do not tell.
+        self.condition(q.not_first_access(), |cb| {
+            cb.require_zero(
+                "TxAccessListAccount write does not leave the entry cold",
+                q.is_write() * (1.expr() - q.value()) * (1.expr() - q.value_prev_column()),
+            );
+        });
 
         self.require_equal(
             "state_root is unchanged for TxAccessListAccount",
@@ -364,6 +390,21 @@ impl<F: Field> ConstraintBuilder<F> {
             "initial TxAccessListAccountStorage value is false",
             q.initial_value(),
         );
+        // As for TxAccessListAccount, the storage keys of the EIP-2930 access list are warmed by
+        // BeginTx.
+        self.condition(q.first_access(), |cb| {
+            cb.require_zero(
+                "TxAccessListAccountStorage first write warms the slot",
+                q.is_write() * (1.expr() - q.value()) * (1.expr() - q.initial_value()),
+            );
+        });
+        // TODO: same as for TxAccessListAccount.
+        self.condition(q.not_first_access(), |cb| {
+            cb.require_zero(
+                "TxAccessListAccountStorage write does not leave the slot cold",
+                q.is_write() * (1.expr() - q.value()) * (1.expr() - q.value_prev_column()),
+            );
+        });
 
         self.require_equal(
             "state_root is unchanged for TxAccessListAccountStorage",
@@ -503,16 +544,119 @@ impl<F: Field> ConstraintBuilder<F> {
     }
 
     fn build_tx_receipt_constraints(&mut self, q: &Queries<F>) {
-        // TODO: finish TxReceipt constraints
+        // 8.0. Unused keys are 0
+        self.require_zero("address is 0 for TxReceipt", q.rw_table.address.clone());
+        self.require_zero(
+            "storage_key is 0 for TxReceipt",
+            q.rw_table.storage_key.clone(),
+        );
+        // 8.1. field_tag is a TxReceiptFieldTag
+        self.require_in_set(
+            "field_tag in TxReceiptFieldTag range",
+            q.field_tag(),
+            TxReceiptFieldTag::iter().map(|tag| tag.expr()).collect(),
+        );
+        // 8.2. state root does not change
         self.require_equal(
             "state_root is unchanged for TxReceipt",
             q.state_root(),
             q.state_root_prev(),
         );
-        self.require_zero(
-            "value_prev_column is 0 for TxReceipt",
+        // 8.3. initial value is 0
+        self.require_zero("initial TxReceipt value is 0", q.initial_value());
+        self.require_equal(
+            "value_prev column equals initial_value for TxReceipt",
             q.value_prev_column(),
+            q.initial_value(),
+        );
+        // 8.4. PostStateOrStatus is boolean
+        self.condition(
+            q.tx_receipt_field_tag_matches(TxReceiptFieldTag::PostStateOrStatus),
+            |cb| cb.require_boolean("PostStateOrStatus is boolean", q.value()),
         );
+        // 8.5. PostStateOrStatus and LogLength are only written by the EndTx of their tx, the
+        // CumulativeGasUsed is then read by the EndTx of the next tx.
+        self.condition(q.not_first_access(), |cb| {
+            cb.require_equal(
+                "only CumulativeGasUsed is accessed more than once",
+                q.field_tag(),
+                TxReceiptFieldTag::CumulativeGasUsed.expr(),
+            );
+            cb.require_zero(
+                "CumulativeGasUsed is only written by the first access",
+                q.is_write(),
+            );
+        });
+        // 8.6. Receipts are contiguous: PostStateOrStatus, CumulativeGasUsed and LogLength of tx
+        // `id` are followed by the ones of tx `id + 1`.
+        self.condition(q.first_access() * q.is_tag_unchanged.clone(), |cb| {
+            cb.require_equal(
+                "TxReceipt (id, field_tag) follows the one of the previous row",
+                TxReceiptFieldTag::COUNT.expr() * q.id_change() + q.field_tag()
+                    - q.rw_table.prev_field_tag.clone(),
+                1.expr(),
+            );
+        });
+        // 8.7. Receipts start with the PostStateOrStatus of the first tx. For the first access of
+        // a TxReceipt row, first_different_limb is Tag, Id1, Id0 or FieldTag, so is_tag_unchanged
+        // is 0, 1, 1 or 3.
+        let is_tag_changed = (1.expr() - q.is_tag_unchanged.clone())
+            * (3.expr() - q.is_tag_unchanged.clone())
+            * Expression::Constant(F::from(3).invert().unwrap());
+        self.condition(q.first_access() * is_tag_changed, |cb| {
+            cb.require_equal("first TxReceipt is of tx 1", q.id(), 1.expr());
+            cb.require_equal(
+                "first TxReceipt is the PostStateOrStatus",
+                q.field_tag(),
+                TxReceiptFieldTag::PostStateOrStatus.expr(),
+            );
+        });
+        // 8.8. CumulativeGasUsed never decreases. Its read by the next tx is followed by the
+        // LogLength of this tx, then the PostStateOrStatus and the CumulativeGasUsed of the next
+        // tx, which exceeds it by the gas used of the next tx, fitting into 64 bits.
+        self.condition(q.not_first_access(), |cb| {
+            cb.require_equal(
+                "CumulativeGasUsed of the next tx increases by its gas used",
+                q.next_tx_cumulative_gas_used.clone(),
+                q.value() + q.tx_receipt_gas_used.clone(),
+            );
+        });
+        // 8.9. LogLength is the log_id of the last log of the tx, whose address is written at
+        // index 0. The EVM circuit writes the logs of a tx with consecutive log_ids from 1.
+        self.require_boolean(
+            "tx_receipt_has_logs is boolean",
+            q.tx_receipt_has_logs.clone(),
+        );
+        self.condition(q.tx_receipt_has_logs.clone(), |cb| {
+            cb.require_equal(
+                "only LogLength has logs",
+                q.field_tag(),
+                TxReceiptFieldTag::LogLength.expr(),
+            );
+        });
+        self.condition(
+            q.tx_receipt_field_tag_matches(TxReceiptFieldTag::LogLength),
+            |cb| {
+                cb.require_zero(
+                    "tx_receipt_has_logs is 1 if LogLength is not 0",
+                    (1.expr() - q.tx_receipt_has_logs.clone()) * q.value(),
+                );
+            },
+        );
+        self.condition(q.tx_receipt_has_logs.clone(), |cb| {
+            cb.add_lookup(
+                "last log of the tx exists for TxReceipt LogLength",
+                vec![
+                    (1.expr(), q.selector()),
+                    (RwTableTag::TxLog.expr(), q.tag()),
+                    (q.id(), q.id()),
+                    (
+                        build_tx_log_expression(0.expr(), TxLogFieldTag::Address.expr(), q.value()),
+                        q.rw_table.address.clone(),
+                    ),
+                ],
+            );
+        });
     }
 
     fn build_account_transient_storage_constraints(&mut self, q: &Queries<F>) {
@@ -542,6 +686,30 @@ impl<F: Field> ConstraintBuilder<F> {
         );
     }
 
+    fn build_tx_created_account_constraints(&mut self, q: &Queries<F>) {
+        self.require_zero("field_tag is 0 for TxCreatedAccount", q.field_tag());
+        self.require_zero(
+            "storage_key is 0 for TxCreatedAccount",
+            q.rw_table.storage_key.clone(),
+        );
+        self.require_boolean("TxCreatedAccount value is boolean", q.value());
+        self.require_zero("initial TxCreatedAccount value is false", q.initial_value());
+
+        self.require_equal(
+            "state_root is unchanged for TxCreatedAccount",
+            q.state_root(),
+            q.state_root_prev(),
+        );
+
+        self.condition(q.not_first_access.clone(), |cb| {
+            cb.require_equal(
+                "value column at Rotation::prev() equals value_prev at Rotation::cur()",
+                q.rw_table.value_prev.clone(),
+                q.value_prev_column(),
+            );
+        });
+    }
+
     fn require_zero(&mut self, name: &'static str, e: Expression<F>) {
         self.constraints.push((name, self.condition.clone() * e));
     }
@@ -636,6 +804,18 @@ impl<F: Field> Queries<F> {
         BinaryNumberConfig::<RwTableTag, 4>::value_equals_expr(tag, self.tag_bits.clone())
     }
 
+    // only boolean for TxReceipt rows, where field_tag is a TxReceiptFieldTag.
+    fn tx_receipt_field_tag_matches(&self, field_tag: TxReceiptFieldTag) -> Expression<F> {
+        TxReceiptFieldTag::iter()
+            .filter(|&other| other != field_tag)
+            .fold(1.expr(), |acc, other| {
+                let inv = (F::from(field_tag as u64) - F::from(other as u64))
+                    .invert()
+                    .unwrap();
+                acc * (self.field_tag() - other.expr()) * Expression::Constant(inv)
+            })
+    }
+
     // be careful! not boolean!!
     fn first_access(&self) -> Expression<F> {
         not::expr(self.not_first_access.clone())
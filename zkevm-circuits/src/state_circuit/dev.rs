@@ -89,6 +89,7 @@ pub enum AdviceColumn {
     NonEmptyWitness,
     StateRoot,
     FieldTag,
+    TxReceiptHasLogs,
 }
 
 impl AdviceColumn {
@@ -121,6 +122,7 @@ impl AdviceColumn {
             Self::NonEmptyWitness => config.is_non_exist.nonempty_witness,
             Self::StateRoot => config.state_root,
             Self::FieldTag => config.rw_table.field_tag,
+            Self::TxReceiptHasLogs => config.tx_receipt_has_logs,
         }
     }
 }
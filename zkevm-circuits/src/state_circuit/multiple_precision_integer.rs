@@ -29,6 +29,12 @@ impl ToLimbs<N_LIMBS_RW_COUNTER> for u32 {
     }
 }
 
+impl ToLimbs<N_LIMBS_GAS> for u64 {
+    fn to_limbs(&self) -> [u16; 4] {
+        le_bytes_to_limbs(&self.to_le_bytes()).try_into().unwrap()
+    }
+}
+
 #[derive(Clone, Copy)]
 pub struct Config<T, const N: usize>
 where
@@ -90,6 +96,22 @@ impl Config<u32, N_LIMBS_RW_COUNTER> {
     }
 }
 
+impl Config<u64, N_LIMBS_GAS> {
+    pub fn assign<F: Field>(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: usize,
+        value: u64,
+    ) -> Result<(), Error> {
+        assign_to_config(region, self, value, offset, "u64")
+    }
+
+    /// Annotates columns of this gadget embedded within a circuit region.
+    pub fn annotate_columns_in_region<F: Field>(&self, region: &mut Region<F>, prefix: &str) {
+        annotate_columns_in_config(region, self, prefix, "u64")
+    }
+}
+
 fn assign_to_config<T: ToLimbs<N>, const N: usize, F: Field>(
     region: &mut Region<'_, F>,
     config: &Config<T, N>,
@@ -199,6 +221,9 @@ mod test {
         let val = 10u32;
         assert_eq!(val.to_limbs(), [10, 0]);
 
+        let val = 0x1_0000_000au64;
+        assert_eq!(val.to_limbs(), [10, 0, 1, 0]);
+
         let val = Address::from_low_u64_be(10);
         assert_eq!(val.to_limbs(), [10, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
     }
@@ -918,6 +918,107 @@ fn bad_initial_tx_access_list_account_value() {
     );
 }
 
+#[test]
+fn tx_access_list_account_revert_ok() {
+    let account_address = address!("0x0000000000000000000000000000000004356002");
+    let access = |rw_counter, is_warm, is_warm_prev| Rw::TxAccessListAccount {
+        rw_counter,
+        is_write: true,
+        tx_id: 1,
+        account_address,
+        is_warm,
+        is_warm_prev,
+    };
+    let rows = vec![
+        access(3, true, false),
+        access(5, true, true),
+        // reversions of the two writes above, in reverse order
+        access(9, true, true),
+        access(10, false, true),
+    ];
+
+    assert_eq!(verify(rows), Ok(()));
+}
+
+#[test]
+fn tx_access_list_account_write_stays_cold() {
+    let rows = vec![Rw::TxAccessListAccount {
+        rw_counter: 1,
+        is_write: true,
+        tx_id: 1,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        is_warm: false,
+        is_warm_prev: false,
+    }];
+
+    assert_error_matches(
+        verify(rows),
+        "TxAccessListAccount first write warms the entry",
+    );
+}
+
+#[test]
+fn tx_access_list_account_write_stays_cold_after_revert() {
+    let access = |rw_counter, is_warm, is_warm_prev| Rw::TxAccessListAccount {
+        rw_counter,
+        is_write: true,
+        tx_id: 1,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        is_warm,
+        is_warm_prev,
+    };
+    let rows = vec![
+        access(3, true, false),
+        access(9, false, true),
+        access(12, false, false),
+    ];
+
+    assert_error_matches(
+        verify(rows),
+        "TxAccessListAccount write does not leave the entry cold",
+    );
+}
+
+#[test]
+fn tx_access_list_account_storage_write_stays_cold() {
+    let rows = vec![Rw::TxAccessListAccountStorage {
+        rw_counter: 1,
+        is_write: true,
+        tx_id: 1,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        storage_key: U256::from(7u64),
+        is_warm: false,
+        is_warm_prev: false,
+    }];
+
+    assert_error_matches(
+        verify(rows),
+        "TxAccessListAccountStorage first write warms the slot",
+    );
+}
+
+#[test]
+fn bad_initial_tx_created_account_value() {
+    let rows = vec![Rw::TxCreatedAccount {
+        rw_counter: 1,
+        is_write: true,
+        tx_id: 1,
+        account_address: address!("0x0000000000000000000000000000000004356002"),
+        is_created: true,
+        is_created_prev: false,
+    }];
+
+    let overrides = HashMap::from([
+        ((AdviceColumn::InitialValue, 0), Fr::from(1)),
+        ((AdviceColumn::ValuePrev, 0), Fr::from(1)),
+    ]);
+
+    assert_error_matches(
+        verify_with_overrides(rows, overrides),
+        "initial TxCreatedAccount value is false",
+    );
+}
+
 #[test]
 fn bad_initial_tx_refund_value() {
     let rows = vec![Rw::TxRefund {
@@ -1154,19 +1255,19 @@ fn variadic_size_check() {
 }
 
 #[test]
-#[ignore = "TxReceipt constraints not yet implemented"]
 fn bad_initial_tx_receipt_value() {
     let rows = vec![Rw::TxReceipt {
         rw_counter: 1,
-        is_write: false,
-        tx_id: 3421,
-        field_tag: TxReceiptFieldTag::CumulativeGasUsed,
+        is_write: true,
+        tx_id: 1,
+        field_tag: TxReceiptFieldTag::PostStateOrStatus,
         value: 0,
     }];
 
     let overrides = HashMap::from([
-        ((AdviceColumn::Value, 0), Fr::from(1900)),
-        ((AdviceColumn::InitialValue, 0), Fr::from(1900)),
+        ((AdviceColumn::Value, 0), Fr::one()),
+        ((AdviceColumn::ValuePrev, 0), Fr::one()),
+        ((AdviceColumn::InitialValue, 0), Fr::one()),
     ]);
 
     assert_error_matches(
@@ -1175,6 +1276,148 @@ fn bad_initial_tx_receipt_value() {
     );
 }
 
+fn tx_receipt_rows() -> Vec<Rw> {
+    let receipt = |rw_counter, is_write, tx_id, field_tag, value| Rw::TxReceipt {
+        rw_counter,
+        is_write,
+        tx_id,
+        field_tag,
+        value,
+    };
+    vec![
+        receipt(10, true, 1, TxReceiptFieldTag::PostStateOrStatus, 1),
+        receipt(11, true, 1, TxReceiptFieldTag::CumulativeGasUsed, 21000),
+        // read by the EndTx of tx 2
+        receipt(20, false, 1, TxReceiptFieldTag::CumulativeGasUsed, 21000),
+        receipt(12, true, 1, TxReceiptFieldTag::LogLength, 0),
+        receipt(21, true, 2, TxReceiptFieldTag::PostStateOrStatus, 0),
+        receipt(22, true, 2, TxReceiptFieldTag::CumulativeGasUsed, 42000),
+        receipt(23, true, 2, TxReceiptFieldTag::LogLength, 0),
+    ]
+}
+
+#[test]
+fn tx_receipt_ok() {
+    assert_eq!(verify(tx_receipt_rows()), Ok(()));
+}
+
+#[test]
+fn tx_receipt_bad_status() {
+    let overrides = HashMap::from([((AdviceColumn::Value, 0), Fr::from(2))]);
+
+    assert_error_matches(
+        verify_with_overrides(tx_receipt_rows(), overrides),
+        "PostStateOrStatus is boolean",
+    );
+}
+
+#[test]
+fn tx_receipt_decreasing_cumulative_gas_used() {
+    let overrides = HashMap::from([((AdviceColumn::Value, 5), Fr::from(20000))]);
+
+    assert_error_matches(
+        verify_with_overrides(tx_receipt_rows(), overrides),
+        "CumulativeGasUsed of the next tx increases by its gas used",
+    );
+}
+
+#[test]
+fn tx_receipt_missing_log_length() {
+    let mut rows = tx_receipt_rows();
+    rows.remove(3);
+
+    assert_error_matches(
+        verify(rows),
+        "TxReceipt (id, field_tag) follows the one of the previous row",
+    );
+}
+
+#[test]
+fn tx_receipt_not_starting_at_first_tx() {
+    let rows = tx_receipt_rows()
+        .into_iter()
+        .map(|row| match row {
+            Rw::TxReceipt {
+                rw_counter,
+                is_write,
+                tx_id,
+                field_tag,
+                value,
+            } => Rw::TxReceipt {
+                rw_counter,
+                is_write,
+                tx_id: tx_id + 1,
+                field_tag,
+                value,
+            },
+            _ => unreachable!(),
+        })
+        .collect();
+
+    assert_error_matches(verify(rows), "first TxReceipt is of tx 1");
+}
+
+#[test]
+fn tx_receipt_missing_post_state_or_status() {
+    let mut rows = tx_receipt_rows();
+    rows.remove(0);
+
+    assert_error_matches(verify(rows), "first TxReceipt is the PostStateOrStatus");
+}
+
+fn tx_receipt_rows_with_log(log_length: u64) -> Vec<Rw> {
+    let mut rows = vec![Rw::TxLog {
+        rw_counter: 5,
+        is_write: true,
+        tx_id: 1,
+        log_id: 1,
+        field_tag: TxLogFieldTag::Address,
+        index: 0usize,
+        value: U256::one(),
+    }];
+    rows.extend(tx_receipt_rows().into_iter().map(|row| match row {
+        Rw::TxReceipt {
+            rw_counter,
+            is_write,
+            tx_id: 1,
+            field_tag: TxReceiptFieldTag::LogLength,
+            ..
+        } => Rw::TxReceipt {
+            rw_counter,
+            is_write,
+            tx_id: 1,
+            field_tag: TxReceiptFieldTag::LogLength,
+            value: log_length,
+        },
+        row => row,
+    }));
+    rows
+}
+
+#[test]
+fn tx_receipt_log_length_ok() {
+    assert_eq!(verify(tx_receipt_rows_with_log(1)), Ok(()));
+}
+
+#[test]
+fn tx_receipt_log_length_missing_log() {
+    assert_error_matches(
+        verify(tx_receipt_rows_with_log(2)),
+        "last log of the tx exists for TxReceipt LogLength",
+    );
+}
+
+#[test]
+fn tx_receipt_log_length_without_logs() {
+    // the LogLength of tx 1 is below its TxLog and its first 3 receipts.
+    let overrides = HashMap::from([((AdviceColumn::TxReceiptHasLogs, 4), Fr::zero())]);
+
+    assert_error_matches(
+        verify_with_overrides(tx_receipt_rows_with_log(1), overrides),
+        "tx_receipt_has_logs is 1 if LogLength is not 0",
+    );
+}
+
 fn prover(rows: Vec<Rw>, overrides: HashMap<(AdviceColumn, isize), Fr>) -> MockProver<'static, Fr> {
     let updates = MptUpdates::mock_from(&rows);
     let circuit = StateCircuit::<Fr> {
@@ -65,6 +65,7 @@ use crate::bytecode_circuit::circuit::to_poseidon_hash::{
 #[cfg(not(feature = "poseidon-codehash"))]
 use crate::bytecode_circuit::circuit::BytecodeCircuitConfig;
 use crate::{
+    blake2f_circuit::{Blake2fCircuit, Blake2fCircuitConfig, Blake2fCircuitConfigArgs},
     bytecode_circuit::circuit::{BytecodeCircuit, BytecodeCircuitConfigArgs},
     copy_circuit::{CopyCircuit, CopyCircuitConfig, CopyCircuitConfigArgs},
     ecc_circuit::{EccCircuit, EccCircuitConfig, EccCircuitConfigArgs},
@@ -74,9 +75,10 @@ use crate::{
         keccak_packed_multi::get_num_rows_per_round, KeccakCircuit, KeccakCircuitConfig,
         KeccakCircuitConfigArgs,
     },
-    modexp_circuit::{ModExpCircuit, ModExpCircuitConfig},
+    modexp_circuit::{ModExpCircuit, ModExpCircuitConfig, ModExpCircuitConfigArgs},
     pi_circuit::{PiCircuit, PiCircuitConfig, PiCircuitConfigArgs},
     poseidon_circuit::{PoseidonCircuit, PoseidonCircuitConfig, PoseidonCircuitConfigArgs},
+    ripemd160_circuit::{Ripemd160Circuit, Ripemd160CircuitConfig, Ripemd160CircuitConfigArgs},
     rlp_circuit_fsm::{RlpCircuit, RlpCircuitConfig, RlpCircuitConfigArgs},
     sha256_circuit::{
         CircuitConfig as SHA256CircuitConfig, CircuitConfigArgs as SHA256CircuitConfigArgs,
@@ -85,9 +87,9 @@ use crate::{
     sig_circuit::{SigCircuit, SigCircuitConfig, SigCircuitConfigArgs},
     state_circuit::{StateCircuit, StateCircuitConfig, StateCircuitConfigArgs},
     table::{
-        BlockTable, BytecodeTable, CopyTable, EccTable, ExpTable, KeccakTable, ModExpTable,
-        MptTable, PoseidonTable, PowOfRandTable, RlpFsmRlpTable as RlpTable, RwTable, SHA256Table,
-        SigTable, TxTable, U16Table, U8Table,
+        Blake2fTable, BlockTable, BytecodeTable, CopyTable, EccTable, ExpTable, KeccakTable,
+        ModExpTable, MptTable, PoseidonTable, PowOfRandTable, Ripemd160Table,
+        RlpFsmRlpTable as RlpTable, RwTable, SHA256Table, SigTable, TxTable, U16Table, U8Table,
     },
     tx_circuit::{TxCircuit, TxCircuitConfig, TxCircuitConfigArgs},
     util::{circuit_stats, log2_ceil, Challenges, SubCircuit, SubCircuitConfig},
@@ -98,7 +100,7 @@ use crate::{
 use crate::mpt_circuit::{MptCircuit, MptCircuitConfig, MptCircuitConfigArgs};
 
 use crate::util::Field;
-use bus_mapping::circuit_input_builder::{CircuitInputBuilder, CircuitsParams};
+use bus_mapping::circuit_input_builder::{CircuitInputBuilder, CircuitsParams, PrecompileEcParams};
 use eth_types::geth_types::GethData;
 use halo2_proofs::{
     circuit::{Layouter, SimpleFloorPlanner, Value},
@@ -122,9 +124,11 @@ pub struct SuperCircuitConfig<F: Field> {
     state_circuit: StateCircuitConfig<F>,
     tx_circuit: TxCircuitConfig<F>,
     sig_circuit: SigCircuitConfig<F>,
-    modexp_circuit: ModExpCircuitConfig,
+    modexp_circuit: ModExpCircuitConfig<F>,
     ecc_circuit: EccCircuitConfig<F>,
     sha256_circuit: SHA256CircuitConfig,
+    ripemd160_circuit: Ripemd160CircuitConfig<F>,
+    blake2f_circuit: Blake2fCircuitConfig<F>,
     #[cfg(not(feature = "poseidon-codehash"))]
     bytecode_circuit: BytecodeCircuitConfig<F>,
     #[cfg(all(feature = "dual-bytecode", not(feature = "poseidon-codehash")))]
@@ -155,6 +159,8 @@ pub struct SuperCircuitConfigArgs {
     pub max_inner_blocks: usize,
     /// Mock randomness
     pub mock_randomness: u64,
+    /// Max batched EcPairing ops, which the ECC circuit is sized for
+    pub max_pairing_batched_ops: usize,
     /// Challenges
     pub challenges: crate::util::Challenges,
 }
@@ -170,6 +176,7 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
             max_calldata: _,
             max_inner_blocks: _,
             mock_randomness: _mock_randomness,
+            max_pairing_batched_ops,
             challenges,
         }: Self::ConfigArgs,
     ) -> Self {
@@ -207,6 +214,10 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
         log_circuit_info(meta, "keccak table");
         let sha256_table = SHA256Table::construct(meta);
         log_circuit_info(meta, "sha256 table");
+        let ripemd160_table = Ripemd160Table::construct(meta);
+        log_circuit_info(meta, "ripemd160 table");
+        let blake2f_table = Blake2fTable::construct(meta);
+        log_circuit_info(meta, "blake2f table");
         let sig_table = SigTable::construct(meta);
         log_circuit_info(meta, "sig table");
         let modexp_table = ModExpTable::construct(meta);
@@ -240,6 +251,24 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
         );
         log_circuit_info(meta, "sha256 circuit");
 
+        let ripemd160_circuit = Ripemd160CircuitConfig::new(
+            meta,
+            Ripemd160CircuitConfigArgs {
+                ripemd160_table: ripemd160_table.clone(),
+                challenges: challenges_expr.clone(),
+            },
+        );
+        log_circuit_info(meta, "ripemd160 circuit");
+
+        let blake2f_circuit = Blake2fCircuitConfig::new(
+            meta,
+            Blake2fCircuitConfigArgs {
+                blake2f_table: blake2f_table.clone(),
+                challenges: challenges_expr.clone(),
+            },
+        );
+        log_circuit_info(meta, "blake2f circuit");
+
         let poseidon_circuit =
             PoseidonCircuitConfig::new(meta, PoseidonCircuitConfigArgs { poseidon_table });
         log_circuit_info(meta, "poseidon circuit");
@@ -355,7 +384,15 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
         #[cfg(feature = "zktrie")]
         log_circuit_info(meta, "zktrie circuit");
 
-        let modexp_circuit = ModExpCircuitConfig::new(meta, modexp_table);
+        let modexp_circuit = ModExpCircuitConfig::new(
+            meta,
+            ModExpCircuitConfigArgs {
+                modexp_table,
+                u8_table,
+                u16_table,
+                challenges: challenges_expr.clone(),
+            },
+        );
         log_circuit_info(meta, "modexp circuit");
         let state_circuit = StateCircuitConfig::new(
             meta,
@@ -389,6 +426,8 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
                 copy_table,
                 keccak_table: keccak_table.clone(),
                 sha256_table,
+                ripemd160_table,
+                blake2f_table,
                 exp_table,
                 sig_table,
                 modexp_table,
@@ -416,6 +455,7 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
             EccCircuitConfigArgs {
                 ecc_table,
                 challenges: challenges_expr,
+                max_pairing_batched_ops,
             },
         );
         log_circuit_info(meta, "ecc circuit");
@@ -440,6 +480,8 @@ impl SubCircuitConfig<Fr> for SuperCircuitConfig<Fr> {
             modexp_circuit,
             ecc_circuit,
             sha256_circuit,
+            ripemd160_circuit,
+            blake2f_circuit,
             bytecode_circuit,
             #[cfg(feature = "dual-bytecode")]
             bytecode_circuit1,
@@ -497,6 +539,10 @@ pub struct SuperCircuit<
     pub keccak_circuit: KeccakCircuit<F>,
     /// SHA256 Circuit
     pub sha256_circuit: SHA256Circuit<F>,
+    /// RIPEMD-160 Circuit
+    pub ripemd160_circuit: Ripemd160Circuit<F>,
+    /// BLAKE2F Circuit
+    pub blake2f_circuit: Blake2fCircuit<F>,
     /// Poseidon hash Circuit
     pub poseidon_circuit: PoseidonCircuit<F>,
     /// Sig Circuit
@@ -563,6 +609,10 @@ impl<
         push("keccak", keccak);
         let sha256 = SHA256Circuit::<Fr>::min_num_rows_block(block);
         push("sha256", sha256);
+        let ripemd160 = Ripemd160Circuit::<Fr>::min_num_rows_block(block);
+        push("ripemd160", ripemd160);
+        let blake2f = Blake2fCircuit::<Fr>::min_num_rows_block(block);
+        push("blake2f", blake2f);
         let tx = TxCircuit::<Fr>::min_num_rows_block(block);
         push("tx", tx);
         let rlp = RlpCircuit::<Fr, _>::min_num_rows_block(block);
@@ -630,6 +680,8 @@ impl<
             CopyCircuit::<Fr>::unusable_rows(),
             ExpCircuit::<Fr>::unusable_rows(),
             KeccakCircuit::<Fr>::unusable_rows(),
+            Ripemd160Circuit::<Fr>::unusable_rows(),
+            Blake2fCircuit::<Fr>::unusable_rows(),
         ])
         .unwrap()
     }
@@ -655,6 +707,8 @@ impl<
         let modexp_circuit = ModExpCircuit::new_from_block(block);
         let keccak_circuit = KeccakCircuit::new_from_block(block);
         let sha256_circuit = SHA256Circuit::new_from_block(block);
+        let ripemd160_circuit = Ripemd160Circuit::new_from_block(block);
+        let blake2f_circuit = Blake2fCircuit::new_from_block(block);
         let poseidon_circuit = PoseidonCircuit::new_from_block(block);
         let rlp_circuit = RlpCircuit::new_from_block(block);
         let sig_circuit = SigCircuit::new_from_block(block);
@@ -673,6 +727,8 @@ impl<
             exp_circuit,
             keccak_circuit,
             sha256_circuit,
+            ripemd160_circuit,
+            blake2f_circuit,
             poseidon_circuit,
             rlp_circuit,
             sig_circuit,
@@ -740,6 +796,12 @@ impl<
         log::debug!("assigning sha256_circuit");
         self.sha256_circuit
             .synthesize_sub(&config.sha256_circuit, challenges, layouter)?;
+        log::debug!("assigning ripemd160_circuit");
+        self.ripemd160_circuit
+            .synthesize_sub(&config.ripemd160_circuit, challenges, layouter)?;
+        log::debug!("assigning blake2f_circuit");
+        self.blake2f_circuit
+            .synthesize_sub(&config.blake2f_circuit, challenges, layouter)?;
         log::debug!("assigning poseidon_circuit");
         self.poseidon_circuit
             .synthesize_sub(&config.poseidon_circuit, challenges, layouter)?;
@@ -815,7 +877,7 @@ impl<
 {
     type Config = (SuperCircuitConfig<Fr>, Challenges);
     type FloorPlanner = SimpleFloorPlanner;
-    type Params = ();
+    type Params = CircuitsParams;
 
     fn without_witnesses(&self) -> Self {
         let dummy_block = Block {
@@ -825,7 +887,47 @@ impl<
         Self::new_from_block(&dummy_block)
     }
 
+    fn params(&self) -> Self::Params {
+        self.circuit_params
+    }
+
+    fn configure_with_params(
+        meta: &mut ConstraintSystem<Fr>,
+        params: Self::Params,
+    ) -> Self::Config {
+        Self::configure_with_batched_ops(meta, params.max_ec_ops.ec_pairing_batched)
+    }
+
+    /// Without params, the ECC circuit is sized for the most batched EcPairing ops.
     fn configure(meta: &mut ConstraintSystem<Fr>) -> Self::Config {
+        Self::configure_with_batched_ops(meta, PrecompileEcParams::MAX_EC_PAIRING_BATCHED)
+    }
+
+    fn synthesize(
+        &self,
+        (config, challenges): Self::Config,
+        mut layouter: impl Layouter<Fr>,
+    ) -> Result<(), Error> {
+        let challenges = challenges.values(&layouter);
+
+        config.u8_table.load(&mut layouter)?;
+        config.u16_table.load(&mut layouter)?;
+
+        self.synthesize_sub(&config, &challenges, &mut layouter)
+    }
+}
+
+impl<
+        const MAX_TXS: usize,
+        const MAX_CALLDATA: usize,
+        const MAX_INNER_BLOCKS: usize,
+        const MOCK_RANDOMNESS: u64,
+    > SuperCircuit<Fr, MAX_TXS, MAX_CALLDATA, MAX_INNER_BLOCKS, MOCK_RANDOMNESS>
+{
+    fn configure_with_batched_ops(
+        meta: &mut ConstraintSystem<Fr>,
+        max_pairing_batched_ops: usize,
+    ) -> (SuperCircuitConfig<Fr>, Challenges) {
         let challenges = Challenges::construct(meta);
         (
             SuperCircuitConfig::new(
@@ -835,25 +937,13 @@ impl<
                     max_calldata: MAX_CALLDATA,
                     max_inner_blocks: MAX_INNER_BLOCKS,
                     mock_randomness: MOCK_RANDOMNESS,
+                    max_pairing_batched_ops,
                     challenges,
                 },
             ),
             challenges,
         )
     }
-
-    fn synthesize(
-        &self,
-        (config, challenges): Self::Config,
-        mut layouter: impl Layouter<Fr>,
-    ) -> Result<(), Error> {
-        let challenges = challenges.values(&layouter);
-
-        config.u8_table.load(&mut layouter)?;
-        config.u16_table.load(&mut layouter)?;
-
-        self.synthesize_sub(&config, &challenges, &mut layouter)
-    }
 }
 
 impl<
@@ -21,6 +21,7 @@ pub const MAX_RWS: usize = 1_000_000;
 pub const MAX_PRECOMPILE_EC_ADD: usize = 50;
 pub const MAX_PRECOMPILE_EC_MUL: usize = 50;
 pub const MAX_PRECOMPILE_EC_PAIRING: usize = 2;
+pub const MAX_PRECOMPILE_EC_PAIRING_BATCHED: usize = PrecompileEcParams::MAX_EC_PAIRING_BATCHED;
 
 /// default params for super circuit
 pub fn get_super_circuit_params() -> CircuitsParams {
@@ -42,6 +43,7 @@ pub fn get_super_circuit_params() -> CircuitsParams {
             ec_add: MAX_PRECOMPILE_EC_ADD,
             ec_mul: MAX_PRECOMPILE_EC_MUL,
             ec_pairing: MAX_PRECOMPILE_EC_PAIRING,
+            ec_pairing_batched: MAX_PRECOMPILE_EC_PAIRING_BATCHED,
         },
     }
 }
@@ -60,6 +62,8 @@ pub fn get_sub_circuit_limit_and_confidence() -> Vec<(usize, f64)> {
         (MAX_RWS, default_confidence),                          // copy
         (MAX_KECCAK_ROWS, default_confidence),                  // keccak
         (MAX_KECCAK_ROWS, default_confidence),                  // sha256
+        (MAX_KECCAK_ROWS, default_confidence),                  // ripemd160
+        (MAX_KECCAK_ROWS, default_confidence),                  // blake2f
         (MAX_VERTICAL_ROWS, default_confidence),                // tx
         (MAX_CALLDATA, default_confidence),                     // rlp
         (OFFSET_INCREMENT * MAX_EXP_STEPS, default_confidence), // exp
@@ -725,12 +725,12 @@ pub(crate) fn block_invalid_precompile() -> BlockTrace {
         .with_call_op(OpcodeId::DELEGATECALL),
     );
 
-    // Tx 3: 2 invalid ModExp cases
+    // Tx 3: 2 ModExp cases with operands larger than 32 bytes
     let mut contract_code_modexp = Bytecode::default();
 
     contract_code_modexp.append(
         &PrecompileCallArgs {
-            name: "modexp length too large invalid",
+            name: "modexp length over 32 bytes",
             setup_code: bytecode! {
                 // Base size
                 PUSH1(0x1)
@@ -761,7 +761,7 @@ pub(crate) fn block_invalid_precompile() -> BlockTrace {
     );
     contract_code_modexp.append(
         &PrecompileCallArgs {
-            name: "modexp length too large invalid",
+            name: "modexp length over 32 bytes",
             setup_code: bytecode! {
                 // Base size
                 PUSH1(0x21)
@@ -495,8 +495,8 @@ fn precomiple_super_circuits_params(max_txs: usize, max_calldata: usize) -> Circ
         max_mpt_rows: 2049,
         max_poseidon_rows: 8192,
         max_evm_rows: 0,
-        // modexp ref this to decide its ability, we
-        // need at least one (~25000 rows)
+        // modexp ref this to decide its ability, each
+        // call takes at least 1536 rows
         max_keccak_rows: 40000,
         max_inner_blocks: 1,
         max_exp_steps: 256,
@@ -16,8 +16,8 @@ use crate::{
 };
 use bus_mapping::{
     circuit_input_builder::{
-        BigModExp, CopyDataType, CopyEvent, CopyStep, EcAddOp, EcMulOp, EcPairingOp, ExpEvent,
-        PrecompileEcParams,
+        BigModExp, Blake2F, CopyDataType, CopyEvent, CopyStep, EcAddOp, EcMulOp, EcPairingOp,
+        ExpEvent, PrecompileEcParams, Ripemd160,
     },
     precompile::PrecompileCalls,
 };
@@ -195,6 +195,26 @@ pub enum TxFieldTag {
     MaxPriorityFeePerGas,
     /// Max Fee Per Gas (EIP1559)
     MaxFeePerGas,
+    /// Blob versioned hash count (EIP-4844)
+    BlobVersionedHashesLen,
+    /// Blob versioned hash (EIP-4844)
+    BlobVersionedHash,
+    /// Authorization tuple count (EIP-7702)
+    AuthorizationListLen,
+    /// Chain id of an authorization, zero means any chain (EIP-7702)
+    AuthorizationChainId,
+    /// Address an authorization delegates to (EIP-7702)
+    AuthorizationAddress,
+    /// Nonce of an authorization (EIP-7702)
+    AuthorizationNonce,
+    /// "y_parity" value of the signature of an authorization (EIP-7702)
+    AuthorizationYParity,
+    /// "r" value of the signature of an authorization (EIP-7702)
+    AuthorizationR,
+    /// "s" value of the signature of an authorization (EIP-7702)
+    AuthorizationS,
+    /// Recovered authority of an authorization, zero if it is invalid (EIP-7702)
+    AuthorizationAuthority,
 }
 impl_expr!(TxFieldTag);
 
@@ -405,6 +425,36 @@ impl TxTable {
                         )?;
                         offset += 1;
                     }
+                    for row in tx
+                        .table_assignments_blob_hashes_dyn(*challenges)
+                        .into_iter()
+                    {
+                        assign_row(
+                            &mut region,
+                            offset,
+                            self.q_enable,
+                            &advice_columns,
+                            &self.tag,
+                            &row,
+                            "",
+                        )?;
+                        offset += 1;
+                    }
+                    for row in tx
+                        .table_assignments_authorization_list_dyn(*challenges)
+                        .into_iter()
+                    {
+                        assign_row(
+                            &mut region,
+                            offset,
+                            self.q_enable,
+                            &advice_columns,
+                            &self.tag,
+                            &row,
+                            "",
+                        )?;
+                        offset += 1;
+                    }
                 }
 
                 Ok(tx_value_cells)
@@ -484,6 +534,8 @@ pub enum RwTableTag {
     TxLog,
     /// Tx Receipt operation
     TxReceipt,
+    /// Tx Created Account operation
+    TxCreatedAccount,
 }
 impl_expr!(RwTableTag);
 
@@ -498,6 +550,7 @@ impl RwTableTag {
                 | RwTableTag::Account
                 | RwTableTag::AccountStorage
                 | RwTableTag::AccountTransientStorage
+                | RwTableTag::TxCreatedAccount
         )
     }
 }
@@ -1335,6 +1388,8 @@ pub enum BlockContextFieldTag {
     /// included in this block which also taking skipped l1 msgs into account.
     /// This could possibly be larger than NumTxs.
     NumAllTxs,
+    /// Blob Base Fee field (EIP-4844)
+    BlobBaseFee,
 }
 impl_expr!(BlockContextFieldTag);
 
@@ -1722,6 +1777,259 @@ impl SHA256Table {
     }
 }
 
+/// RIPEMD-160 Table, used to verify RIPEMD-160 hashing from RLC'ed input in precompile.
+#[derive(Clone, Debug)]
+pub struct Ripemd160Table {
+    /// True when the row is enabled
+    pub q_enable: Column<Fixed>,
+    /// True when the row holds the hash of the whole input
+    pub is_final: Column<Advice>,
+    /// Byte array input as `RLC(reversed(input))`
+    pub input_rlc: Column<Advice>,
+    /// Byte array input length
+    pub input_len: Column<Advice>,
+    /// 20-byte digest as `RLC(reversed(digest))`
+    pub output_rlc: Column<Advice>,
+}
+
+impl<F: Field> LookupTable<F> for Ripemd160Table {
+    fn columns(&self) -> Vec<Column<Any>> {
+        vec![
+            self.q_enable.into(),
+            self.is_final.into(),
+            self.input_rlc.into(),
+            self.input_len.into(),
+            self.output_rlc.into(),
+        ]
+    }
+
+    fn annotations(&self) -> Vec<String> {
+        vec![
+            String::from("q_enable"),
+            String::from("is_final"),
+            String::from("input_rlc"),
+            String::from("input_len"),
+            String::from("output_rlc"),
+        ]
+    }
+}
+
+impl Ripemd160Table {
+    /// Construct a new Ripemd160Table
+    pub fn construct<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        let ret = Self {
+            q_enable: meta.fixed_column(),
+            is_final: meta.advice_column(),
+            input_rlc: meta.advice_column_in(SecondPhase),
+            input_len: meta.advice_column(),
+            output_rlc: meta.advice_column_in(SecondPhase),
+        };
+        for column in <Self as LookupTable<F>>::advice_columns(&ret) {
+            meta.enable_equality(column);
+        }
+        ret
+    }
+
+    /// Generate the ripemd160 table assignments from a hash event.
+    /// Used only for dev_load
+    pub fn assignments<F: Field>(
+        event: &Ripemd160,
+        challenges: &Challenges<Value<F>>,
+    ) -> Vec<[Value<F>; 4]> {
+        let input_len = Value::known(F::from(event.input.len() as u64));
+        let input_rlc = challenges
+            .keccak_input()
+            .map(|challenge| rlc::value(event.input.iter().rev(), challenge));
+        let output_rlc = challenges
+            .keccak_input()
+            .map(|challenge| rlc::value(event.digest.iter().rev(), challenge));
+
+        vec![[Value::known(F::one()), input_rlc, input_len, output_rlc]]
+    }
+
+    /// Provide this function for the case that we want to consume a ripemd160
+    /// table but without running the full ripemd160 circuit
+    pub fn dev_load<'a, F: Field>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        events: impl IntoIterator<Item = &'a Ripemd160> + Clone,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "ripemd160 table dev",
+            |mut region| {
+                let mut offset = 0;
+                for column in <Self as LookupTable<F>>::advice_columns(self) {
+                    region.assign_fixed(
+                        || "ripemd160 table all-zero row",
+                        self.q_enable,
+                        offset,
+                        || Value::known(F::one()),
+                    )?;
+                    region.assign_advice(
+                        || "ripemd160 table all-zero row",
+                        column,
+                        offset,
+                        || Value::known(F::zero()),
+                    )?;
+                }
+                offset += 1;
+
+                let table_columns = <Self as LookupTable<F>>::advice_columns(self);
+                for event in events.clone() {
+                    for row in Self::assignments(event, challenges) {
+                        region.assign_fixed(
+                            || format!("table row {offset}"),
+                            self.q_enable,
+                            offset,
+                            || Value::known(F::one()),
+                        )?;
+                        for (&column, value) in table_columns.iter().zip_eq(row) {
+                            region.assign_advice(
+                                || format!("table row {offset}"),
+                                column,
+                                offset,
+                                || value,
+                            )?;
+                        }
+                        offset += 1;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// BLAKE2F Table, used to verify the BLAKE2b compression function F (EIP-152) from RLC'ed
+/// input in precompile.
+#[derive(Clone, Debug)]
+pub struct Blake2fTable {
+    /// True when the row is enabled
+    pub q_enable: Column<Fixed>,
+    /// True when the row holds the result of all the rounds of a compression
+    pub is_final: Column<Advice>,
+    /// Number of rounds
+    pub rounds: Column<Advice>,
+    /// 213-byte input as `RLC(reversed(input))`
+    pub input_rlc: Column<Advice>,
+    /// 64-byte output as `RLC(reversed(output))`
+    pub output_rlc: Column<Advice>,
+}
+
+impl<F: Field> LookupTable<F> for Blake2fTable {
+    fn columns(&self) -> Vec<Column<Any>> {
+        vec![
+            self.q_enable.into(),
+            self.is_final.into(),
+            self.rounds.into(),
+            self.input_rlc.into(),
+            self.output_rlc.into(),
+        ]
+    }
+
+    fn annotations(&self) -> Vec<String> {
+        vec![
+            String::from("q_enable"),
+            String::from("is_final"),
+            String::from("rounds"),
+            String::from("input_rlc"),
+            String::from("output_rlc"),
+        ]
+    }
+}
+
+impl Blake2fTable {
+    /// Construct a new Blake2fTable
+    pub fn construct<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
+        let ret = Self {
+            q_enable: meta.fixed_column(),
+            is_final: meta.advice_column(),
+            rounds: meta.advice_column(),
+            input_rlc: meta.advice_column_in(SecondPhase),
+            output_rlc: meta.advice_column_in(SecondPhase),
+        };
+        for column in <Self as LookupTable<F>>::advice_columns(&ret) {
+            meta.enable_equality(column);
+        }
+        ret
+    }
+
+    /// Generate the blake2f table assignments from a compression event.
+    /// Used only for dev_load
+    pub fn assignments<F: Field>(
+        event: &Blake2F,
+        challenges: &Challenges<Value<F>>,
+    ) -> Vec<[Value<F>; 4]> {
+        let input_rlc = challenges
+            .keccak_input()
+            .map(|challenge| rlc::value(event.input_bytes().iter().rev(), challenge));
+        let output_rlc = challenges
+            .keccak_input()
+            .map(|challenge| rlc::value(event.output_bytes().iter().rev(), challenge));
+
+        vec![[
+            Value::known(F::one()),
+            Value::known(F::from(event.rounds as u64)),
+            input_rlc,
+            output_rlc,
+        ]]
+    }
+
+    /// Provide this function for the case that we want to consume a blake2f
+    /// table but without running the full blake2f circuit
+    pub fn dev_load<'a, F: Field>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        events: impl IntoIterator<Item = &'a Blake2F> + Clone,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "blake2f table dev",
+            |mut region| {
+                let mut offset = 0;
+                for column in <Self as LookupTable<F>>::advice_columns(self) {
+                    region.assign_fixed(
+                        || "blake2f table all-zero row",
+                        self.q_enable,
+                        offset,
+                        || Value::known(F::one()),
+                    )?;
+                    region.assign_advice(
+                        || "blake2f table all-zero row",
+                        column,
+                        offset,
+                        || Value::known(F::zero()),
+                    )?;
+                }
+                offset += 1;
+
+                let table_columns = <Self as LookupTable<F>>::advice_columns(self);
+                for event in events.clone() {
+                    for row in Self::assignments(event, challenges) {
+                        region.assign_fixed(
+                            || format!("table row {offset}"),
+                            self.q_enable,
+                            offset,
+                            || Value::known(F::one()),
+                        )?;
+                        for (&column, value) in table_columns.iter().zip_eq(row) {
+                            region.assign_advice(
+                                || format!("table row {offset}"),
+                                column,
+                                offset,
+                                || value,
+                            )?;
+                        }
+                        offset += 1;
+                    }
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
 /// Copy Table, used to verify copies of byte chunks between Memory, Bytecode,
 /// TxLogs and TxCallData.
 #[derive(Clone, Copy, Debug)]
@@ -2545,6 +2853,11 @@ impl RlpFsmRlpTable {
     }
 }
 
+/// The `sig_v` of the secp256r1 rows in the sig table. The p256_verify precompile has no recovery
+/// id, this value tags the rows so that they never match a secp256k1 lookup, where `sig_v` is 0 or
+/// 1.
+pub const SIG_V_SECP256R1: u64 = 2;
+
 /// The sig table is used to verify signatures, used in tx circuit and ecrecover & p256_verify precompiles.
 #[derive(Clone, Copy, Debug)]
 pub struct SigTable {
@@ -2552,7 +2865,7 @@ pub struct SigTable {
     pub q_enable: Column<Fixed>,
     /// Random-linear combination of the Keccak256 hash of the message that's signed.
     pub msg_hash_rlc: Column<Advice>,
-    /// should be in range [0, 1]
+    /// should be in range [0, 1] for secp256k1 signatures, [`SIG_V_SECP256R1`] for secp256r1 ones.
     pub sig_v: Column<Advice>,
     /// Random-linear combination of the signature's `r` component.
     pub sig_r_rlc: Column<Advice>,
@@ -2700,9 +3013,9 @@ impl SigTable {
                     challenge,
                 )
             });
-            let sig_v = Value::known(F::from(sign_data.signature.2 as u64));
+            let sig_v = Value::known(F::from(SIG_V_SECP256R1));
             let recovered_addr = Value::known(sign_data.get_addr().to_scalar().unwrap());
-            let is_valid = Value::known(F::from(!sign_data.get_addr().is_zero()));
+            let is_valid = Value::known(F::from(sign_data.is_valid()));
             sig_table_items.push(SigTableRow {
                 msg_hash_rlc,
                 sig_r_rlc,
@@ -2853,7 +3166,8 @@ impl EccTable {
         pairing_ops: &[EcPairingOp],
         challenges: &Challenges<Value<F>>,
     ) -> Result<(), Error> {
-        let mut assignments = Vec::with_capacity(params.ec_add + params.ec_mul + params.ec_pairing);
+        let max_pairing_ops = params.ec_pairing + params.ec_pairing_batched;
+        let mut assignments = Vec::with_capacity(params.ec_add + params.ec_mul + max_pairing_ops);
         let u256_to_value = |u256: U256, randomness: Value<F>| -> Value<F> {
             randomness.map(|r| rlc::value(u256.to_le_bytes().iter(), r))
         };
@@ -2909,7 +3223,7 @@ impl EccTable {
             .iter()
             .filter(|pairing_op| !pairing_op.skip_by_ecc_circuit())
             .chain(std::iter::repeat(&EcPairingOp::default()))
-            .take(params.ec_pairing)
+            .take(max_pairing_ops)
         {
             assignments.push([
                 Value::known(F::from(u64::from(PrecompileCalls::Bn128Pairing))),
@@ -2959,81 +3273,63 @@ impl EccTable {
 }
 
 /// Lookup table embedded in the modexp circuit for precompile.
+///
+/// A call to the precompile spans a variable number of rows in the modexp circuit, its values
+/// being exposed on its last row, the one with `q_head` set. Big integers are represented by the
+/// RLC of their big-endian bytes with the keccak randomness.
 #[derive(Clone, Copy, Debug)]
 pub struct ModExpTable {
-    /// Use for indicate beginning of a limbs group
-    pub q_head: Column<Fixed>,
-    /// base represented by limbs
-    pub base: Column<Advice>,
-    /// exp represented by limbs
-    pub exp: Column<Advice>,
-    /// modulus represented by limbs
-    pub modulus: Column<Advice>,
-    /// result represented by limbs
-    pub result: Column<Advice>,
+    /// Whether the row holds the values of a call
+    pub q_head: Column<Advice>,
+    /// RLC of the call input following the 96-byte lengths header, right-padded with zeros to
+    /// `MODEXP_INPUT_LIMIT` bytes in total
+    pub input_rlc: Column<Advice>,
+    /// byte length of base, 0 if the input is invalid
+    pub base_len: Column<Advice>,
+    /// byte length of exp, 0 if the input is invalid
+    pub exp_len: Column<Advice>,
+    /// byte length of modulus, 0 if the input is invalid
+    pub modulus_len: Column<Advice>,
+    /// RLC of the leading (up to) 32 bytes of exp, which determine the gas cost
+    pub exp_head_rlc: Column<Advice>,
+    /// Whether the call succeeded, in which case the result is computed
+    pub is_success: Column<Advice>,
+    /// RLC of the result, 0 if the call failed
+    pub result_rlc: Column<Advice>,
 }
 
 impl ModExpTable {
     /// Construct the modexp table.
     pub fn construct<F: Field>(meta: &mut ConstraintSystem<F>) -> Self {
-        let ret = Self {
-            q_head: meta.fixed_column(),
-            base: meta.advice_column(),
-            exp: meta.advice_column(),
-            modulus: meta.advice_column(),
-            result: meta.advice_column(),
-        };
-        meta.enable_equality(ret.base);
-        meta.enable_equality(ret.exp);
-        meta.enable_equality(ret.modulus);
-        meta.enable_equality(ret.result);
-        ret
-    }
-
-    /// helper for divide a U256 into 3 108bit limbs
-    pub fn split_u256_108bit_limbs(word: &Word) -> [u128; 3] {
-        let bit108 = 1u128 << 108;
-        let (next, limb0) = word.div_mod(U256::from(bit108));
-        let (limb2, limb1) = next.div_mod(U256::from(bit108));
-        [limb0.as_u128(), limb1.as_u128(), limb2.as_u128()]
-    }
-
-    /// helper for obtain the modulus of a U256 in Fr
-    pub fn native_u256<F: Field>(word: &Word) -> F {
-        let minus1 = -F::one();
-        let div = Word::from_little_endian(minus1.to_repr().as_ref()) + Word::from(1u64);
-        let (_, remainder) = word.div_mod(div);
-
-        let mut bytes = [0u8; 64];
-        remainder.to_little_endian(&mut bytes[..32]);
-        F::from_uniform_bytes(&bytes)
+        Self {
+            q_head: meta.advice_column(),
+            input_rlc: meta.advice_column_in(SecondPhase),
+            base_len: meta.advice_column(),
+            exp_len: meta.advice_column(),
+            modulus_len: meta.advice_column(),
+            exp_head_rlc: meta.advice_column_in(SecondPhase),
+            is_success: meta.advice_column(),
+            result_rlc: meta.advice_column_in(SecondPhase),
+        }
     }
 
-    /// fill a blank 4-row region start from offset for empty lookup
-    pub fn fill_blank<F: Field>(&self, layouter: &mut impl Layouter<F>) -> Result<(), Error> {
-        layouter.assign_region(
-            || "modexp table blank region",
-            |mut region| {
-                for i in 0..4 {
-                    // fill last totally 0 row
-                    region.assign_fixed(
-                        || "modexp table blank head row",
-                        self.q_head,
-                        i,
-                        || Value::known(F::zero()),
-                    )?;
-                    for &col in [&self.base, &self.exp, &self.modulus, &self.result] {
-                        region.assign_advice(
-                            || "modexp table blank limb row",
-                            col,
-                            i,
-                            || Value::known(F::zero()),
-                        )?;
-                    }
-                }
-                Ok(())
-            },
-        )
+    /// The values of the table row holding a call, in the order of [`LookupTable::columns`].
+    pub fn row_values<F: Field>(
+        event: &BigModExp,
+        challenges: &Challenges<Value<F>>,
+    ) -> [Value<F>; 8] {
+        let keccak_input = challenges.keccak_input();
+        let exp_head = &event.exponent()[..event.lens[1].min(32)];
+        [
+            Value::known(F::one()),
+            rlc_be_bytes(&event.input, keccak_input),
+            Value::known(F::from(event.lens[0] as u64)),
+            Value::known(F::from(event.lens[1] as u64)),
+            Value::known(F::from(event.lens[2] as u64)),
+            rlc_be_bytes(exp_head, keccak_input),
+            Value::known(F::from(event.is_success)),
+            rlc_be_bytes(&event.result, keccak_input),
+        ]
     }
 
     /// Get assignments to the modexp table. Meant to be used for dev purposes.
@@ -3041,62 +3337,33 @@ impl ModExpTable {
         &self,
         layouter: &mut impl Layouter<F>,
         events: &[BigModExp],
+        challenges: &Challenges<Value<F>>,
     ) -> Result<(), Error> {
         layouter.assign_region(
             || "modexp table",
             |mut region| {
-                let mut offset = 0usize;
-
-                for event in events {
-                    for i in 0..4 {
-                        region.assign_fixed(
-                            || format!("modexp table head {}", offset + i),
-                            self.q_head,
-                            offset + i,
-                            || Value::known(if i == 0 { F::one() } else { F::zero() }),
-                        )?;
-                    }
-
-                    let base_limbs = Self::split_u256_108bit_limbs(&event.base);
-                    let exp_limbs = Self::split_u256_108bit_limbs(&event.exponent);
-                    let modulus_limbs = Self::split_u256_108bit_limbs(&event.modulus);
-                    let result_limbs = Self::split_u256_108bit_limbs(&event.result);
-
-                    for i in 0..3 {
-                        for (limbs, &col) in [base_limbs, exp_limbs, modulus_limbs, result_limbs]
-                            .iter()
-                            .zip([&self.base, &self.exp, &self.modulus, &self.result])
-                        {
-                            region.assign_advice(
-                                || format!("modexp table limb row {}", offset + i),
-                                col,
-                                offset + i,
-                                || Value::known(F::from_u128(limbs[i])),
-                            )?;
-                        }
-                    }
-
-                    // native is not used by lookup (and in fact it can be omitted in dev)
-                    for (word, &col) in
-                        [&event.base, &event.exponent, &event.modulus, &event.result]
-                            .iter()
-                            .zip([&self.base, &self.exp, &self.modulus, &self.result])
+                // the last row is left blank for disabled lookups.
+                let rows = events
+                    .iter()
+                    .map(|event| Self::row_values(event, challenges))
+                    .chain(once([Value::known(F::zero()); 8]));
+                for (offset, row) in rows.enumerate() {
+                    for (column, value) in <Self as LookupTable<F>>::advice_columns(self)
+                        .into_iter()
+                        .zip(row)
                     {
                         region.assign_advice(
-                            || format!("modexp table native row {}", offset + 3),
-                            col,
-                            offset + 3,
-                            || Value::<F>::known(Self::native_u256(word)),
+                            || format!("modexp table row {offset}"),
+                            column,
+                            offset,
+                            || value,
                         )?;
                     }
-
-                    offset += 4;
                 }
 
                 Ok(())
             },
-        )?;
-        self.fill_blank(layouter)
+        )
     }
 }
 
@@ -3104,41 +3371,26 @@ impl<F: Field> LookupTable<F> for ModExpTable {
     fn columns(&self) -> Vec<Column<Any>> {
         vec![
             self.q_head.into(),
-            self.base.into(),
-            self.exp.into(),
-            self.modulus.into(),
-            self.result.into(),
+            self.input_rlc.into(),
+            self.base_len.into(),
+            self.exp_len.into(),
+            self.modulus_len.into(),
+            self.exp_head_rlc.into(),
+            self.is_success.into(),
+            self.result_rlc.into(),
         ]
     }
 
     fn annotations(&self) -> Vec<String> {
         vec![
-            String::from("is_head"),
-            String::from("base"),
-            String::from("exp"),
-            String::from("modulus"),
-            String::from("result"),
-        ]
-    }
-
-    fn table_exprs(&self, meta: &mut VirtualCells<F>) -> Vec<Expression<F>> {
-        vec![
-            // ignore the is_valid field as the EVM circuit's use-case (Ecrecover precompile) does
-            // not care whether the signature is valid or not. It only cares about the recovered
-            // address.
-            meta.query_fixed(self.q_head, Rotation::cur()),
-            meta.query_advice(self.base, Rotation::cur()),
-            meta.query_advice(self.exp, Rotation::cur()),
-            meta.query_advice(self.modulus, Rotation::cur()),
-            meta.query_advice(self.result, Rotation::cur()),
-            meta.query_advice(self.base, Rotation::next()),
-            meta.query_advice(self.exp, Rotation::next()),
-            meta.query_advice(self.modulus, Rotation::next()),
-            meta.query_advice(self.result, Rotation::next()),
-            meta.query_advice(self.base, Rotation(2)),
-            meta.query_advice(self.exp, Rotation(2)),
-            meta.query_advice(self.modulus, Rotation(2)),
-            meta.query_advice(self.result, Rotation(2)),
+            String::from("q_head"),
+            String::from("input_rlc"),
+            String::from("base_len"),
+            String::from("exp_len"),
+            String::from("modulus_len"),
+            String::from("exp_head_rlc"),
+            String::from("is_success"),
+            String::from("result_rlc"),
         ]
     }
 }
@@ -20,10 +20,13 @@ use crate::{
         BlockTable, KeccakTable, LookupTable, PowOfRandTable, RlpFsmRlpTable as RlpTable, SigTable,
         TxFieldTag,
         TxFieldTag::{
-            AccessListAddressesLen, AccessListRLC, AccessListStorageKeysLen, BlockNumber, CallData,
-            CallDataGasCost, CallDataLength, CallDataRLC, CalleeAddress, CallerAddress, ChainID,
-            Gas, GasPrice, IsCreate, MaxFeePerGas, MaxPriorityFeePerGas, Nonce, SigR, SigS, SigV,
-            TxDataGasCost, TxHashLength, TxHashRLC, TxSignHash, TxSignLength, TxSignRLC,
+            AccessListAddressesLen, AccessListRLC, AccessListStorageKeysLen, AuthorizationAddress,
+            AuthorizationAuthority, AuthorizationChainId, AuthorizationListLen, AuthorizationNonce,
+            AuthorizationR, AuthorizationS, AuthorizationYParity, BlobVersionedHashesLen,
+            BlockNumber, CallData, CallDataGasCost, CallDataLength, CallDataRLC, CalleeAddress,
+            CallerAddress, ChainID, Gas, GasPrice, IsCreate, MaxFeePerGas, MaxPriorityFeePerGas,
+            Nonce, SigR, SigS, SigV, TxDataGasCost, TxHashLength, TxHashRLC, TxSignHash,
+            TxSignLength, TxSignRLC,
         },
         TxTable, U16Table, U8Table,
     },
@@ -35,8 +38,8 @@ use crate::{
     witness::{
         rlp_fsm::{Tag, ValueTagLength},
         Format::{
-            L1MsgHash, TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashPreEip155, TxSignEip155,
-            TxSignEip1559, TxSignEip2930, TxSignPreEip155,
+            L1MsgHash, TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashEip7702, TxHashPreEip155,
+            TxSignEip155, TxSignEip1559, TxSignEip2930, TxSignEip7702, TxSignPreEip155,
         },
         RlpTag,
         RlpTag::{GasCost, Len, Null, RLC},
@@ -48,7 +51,8 @@ use crate::{util::Field, witness::keccak::keccak_inputs_sign_verify};
 use eth_types::{
     geth_types::{
         access_list_size, TxType,
-        TxType::{Eip155, Eip1559, Eip2930, L1Msg, PreEip155},
+        TxType::{Eip155, Eip1559, Eip2930, Eip7702, L1Msg, PreEip155},
+        AUTHORIZATION_MAGIC,
     },
     sign_types::SignData,
     AccessList, Address, ToAddress, ToBigEndian,
@@ -86,7 +90,7 @@ use halo2_proofs::plonk::{Any, Fixed};
 use itertools::Itertools;
 
 /// Number of rows of one tx occupies in the fixed part of tx table
-pub const TX_LEN: usize = 28;
+pub const TX_LEN: usize = 30;
 /// Offset of TxHash tag in the tx table
 pub const TX_HASH_OFFSET: usize = 21;
 /// Offset of CallerAddress in the tx table
@@ -119,6 +123,8 @@ enum LookupCondition {
     Keccak,
     // lookup into dynamic access list section of tx table
     TxAccessList,
+    // lookup into dynamic authorization list section of tx table
+    TxAuthorizationList,
 }
 
 #[derive(Clone, Debug)]
@@ -248,13 +254,17 @@ impl TxRomTable {
                     (AccessListStorageKeysLen, AccessListRLC, 1, 1, 0),
                     (AccessListRLC, MaxFeePerGas, 1, 1, 0),
                     (MaxFeePerGas, MaxPriorityFeePerGas, 1, 1, 0),
-                    (MaxPriorityFeePerGas, BlockNumber, 1, 1, 0),
+                    (MaxPriorityFeePerGas, BlobVersionedHashesLen, 1, 1, 0),
+                    (BlobVersionedHashesLen, AuthorizationListLen, 1, 1, 0),
+                    (AuthorizationListLen, BlockNumber, 1, 1, 0),
                     // Transition into dynamic section of tx_table
                     (BlockNumber, Nonce, 0, 1, 0),
                     (BlockNumber, CallData, 1, 1, 1),
                     (BlockNumber, CallData, 0, 1, 1),
                     (BlockNumber, TxFieldTag::AccessListAddress, 1, 1, 1),
                     (BlockNumber, TxFieldTag::AccessListAddress, 0, 1, 1),
+                    (BlockNumber, AuthorizationChainId, 1, 1, 1),
+                    (BlockNumber, AuthorizationChainId, 0, 1, 1),
                     // Transition between dynamic tags of tx_table
                     (CallData, CallData, 1, 0, 0),
                     (CallData, CallData, 0, 1, 0),
@@ -304,6 +314,42 @@ impl TxRomTable {
                     ),
                     (TxFieldTag::AccessListAddress, CallData, 0, 1, 0),
                     (TxFieldTag::AccessListStorageKey, CallData, 0, 1, 0),
+                    // The authorization list is the last dynamic section of a tx
+                    (CallData, AuthorizationChainId, 1, 1, 0),
+                    (CallData, AuthorizationChainId, 0, 1, 0),
+                    (TxFieldTag::AccessListAddress, AuthorizationChainId, 1, 1, 0),
+                    (TxFieldTag::AccessListAddress, AuthorizationChainId, 0, 1, 0),
+                    (
+                        TxFieldTag::AccessListStorageKey,
+                        AuthorizationChainId,
+                        1,
+                        1,
+                        0,
+                    ),
+                    (
+                        TxFieldTag::AccessListStorageKey,
+                        AuthorizationChainId,
+                        0,
+                        1,
+                        0,
+                    ),
+                    // Fields of an authorization tuple are in RLP order, followed by its authority
+                    (AuthorizationChainId, AuthorizationAddress, 1, 0, 0),
+                    (AuthorizationAddress, AuthorizationNonce, 1, 0, 0),
+                    (AuthorizationNonce, AuthorizationYParity, 1, 0, 0),
+                    (AuthorizationYParity, AuthorizationR, 1, 0, 0),
+                    (AuthorizationR, AuthorizationS, 1, 0, 0),
+                    (AuthorizationS, AuthorizationAuthority, 1, 0, 0),
+                    (AuthorizationAuthority, AuthorizationChainId, 1, 0, 0),
+                    (AuthorizationAuthority, AuthorizationChainId, 0, 1, 0),
+                    (
+                        AuthorizationAuthority,
+                        TxFieldTag::AccessListAddress,
+                        0,
+                        1,
+                        0,
+                    ),
+                    (AuthorizationAuthority, CallData, 0, 1, 0),
                     // Continue padding. Padding has the Calldata tag
                     (CallData, CallData, 1, 1, 0),
                 ];
@@ -339,7 +385,7 @@ pub struct TxCircuitConfig<F: Field> {
     // A selector which is enabled at 1st row
     q_first: Column<Fixed>,
     tx_table: TxTable,
-    tx_tag_bits: BinaryNumberConfig<TxFieldTag, 5>,
+    tx_tag_bits: BinaryNumberConfig<TxFieldTag, 6>,
 
     tx_type: Column<Advice>,
     tx_type_bits: BinaryNumberConfig<TxType, 3>,
@@ -370,6 +416,7 @@ pub struct TxCircuitConfig<F: Field> {
     is_l1_msg: Column<Advice>,
     is_eip2930: Column<Advice>,
     is_eip1559: Column<Advice>,
+    is_eip7702: Column<Advice>,
     is_chain_id: Column<Advice>,
     is_tx_id_zero: Column<Advice>,
     lookup_conditions: HashMap<LookupCondition, Column<Advice>>,
@@ -429,6 +476,19 @@ pub struct TxCircuitConfig<F: Field> {
     // works together with section_rlc to ensure
     // no ommittance in access list dynamic section
     field_rlc: Column<Advice>,
+
+    // Authorization list columns
+    // section denoter for authorization list, reduces degree
+    is_authorization: Column<Advice>,
+    // authorization tag denoter, reduces degree
+    is_authorization_authority: Column<Advice>,
+    // whether the chain id or the nonce of an authorization is RLP-encoded as a single byte
+    is_single_byte: Column<Advice>,
+    tx_value_length_is_one: IsEqualConfig<F>,
+    // RLC and hash RLC of the message signed by an authority, magic || rlp([chain_id, address,
+    // nonce])
+    auth_msg_rlc: Column<Advice>,
+    auth_msg_hash_rlc: Column<Advice>,
     // column for reducing degree. Excludes L1Msg and padding tx
     is_chunk_bytes: Column<Advice>,
     // A tx's len for the chunk's hash is different from HashLen
@@ -537,6 +597,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
         let is_l1_msg = meta.advice_column();
         let is_eip2930 = meta.advice_column();
         let is_eip1559 = meta.advice_column();
+        let is_eip7702 = meta.advice_column();
         let is_calldata = meta.advice_column();
         let is_tx_id_zero = meta.advice_column();
         let is_caller_address = meta.advice_column();
@@ -550,6 +611,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             LookupCondition::RlpHashTag,
             LookupCondition::Keccak,
             LookupCondition::TxAccessList,
+            LookupCondition::TxAuthorizationList,
         ]
         .into_iter()
         .map(|condition| (condition, meta.advice_column()))
@@ -564,6 +626,13 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
         let is_access_list_storage_key = meta.advice_column();
         let field_rlc = meta.advice_column_in(SecondPhase);
 
+        // authorization list columns
+        let is_authorization = meta.advice_column();
+        let is_authorization_authority = meta.advice_column();
+        let is_single_byte = meta.advice_column();
+        let auth_msg_rlc = meta.advice_column_in(SecondPhase);
+        let auth_msg_hash_rlc = meta.advice_column_in(SecondPhase);
+
         // Chunk bytes accumulator
         let is_chunk_bytes = meta.advice_column();
         let chunk_bytes_len = meta.advice_column();
@@ -636,6 +705,15 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
         is_tx_tag!(is_tag_access_list_storage_key, AccessListStorageKey);
         is_tx_tag!(is_max_fee_per_gas, MaxFeePerGas);
         is_tx_tag!(is_max_priority_fee_per_gas, MaxPriorityFeePerGas);
+        is_tx_tag!(is_blob_versioned_hashes_len, BlobVersionedHashesLen);
+        is_tx_tag!(is_authorization_list_len, AuthorizationListLen);
+        is_tx_tag!(is_auth_chain_id, AuthorizationChainId);
+        is_tx_tag!(is_auth_address, AuthorizationAddress);
+        is_tx_tag!(is_auth_nonce, AuthorizationNonce);
+        is_tx_tag!(is_auth_y_parity, AuthorizationYParity);
+        is_tx_tag!(is_auth_r, AuthorizationR);
+        is_tx_tag!(is_auth_s, AuthorizationS);
+        is_tx_tag!(is_auth_authority, AuthorizationAuthority);
 
         // testing if value is zero for tags
         let value_is_zero = IsZeroChip::configure(
@@ -653,6 +731,11 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                         // if access_list_addresses_len is zero, then access_list_storage_keys_len
                         // = 0 and access_list_rlc = 0
                         is_access_list_addresses_len(meta),
+                        // if authorization_list_len is zero, then skip lookup to the authorization
+                        // list
+                        is_authorization_list_len(meta),
+                        // if the authority of an authorization is zero, then skip the sig verify
+                        meta.query_advice(is_authorization_authority, Rotation::cur()),
                     ]),
                 ])
             },
@@ -725,10 +808,12 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             let is_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::cur()),
                 meta.query_advice(is_access_list, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
             ]);
             let is_next_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::next()),
                 meta.query_advice(is_access_list, Rotation::next()),
+                meta.query_advice(is_authorization, Rotation::next()),
             ]);
             cb.gate(and::expr([
                 meta.query_fixed(q_enable, Rotation::cur()),
@@ -761,6 +846,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                     sum::expr([
                         meta.query_advice(is_calldata, Rotation::cur()),
                         meta.query_advice(is_access_list, Rotation::cur()),
+                        meta.query_advice(is_authorization, Rotation::cur()),
                     ]),
                     meta.query_advice(is_final, Rotation::cur()),
                     1.expr(),
@@ -806,6 +892,12 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                     is_max_priority_fee_per_gas(meta),
                     Tag::MaxPriorityFeePerGas.into(),
                 ),
+                (is_auth_chain_id(meta), Tag::AuthChainId.into()),
+                (is_auth_address(meta), Tag::AuthAddress.into()),
+                (is_auth_nonce(meta), Tag::AuthNonce.into()),
+                (is_auth_y_parity(meta), Tag::AuthYParity.into()),
+                (is_auth_r(meta), Tag::AuthR.into()),
+                (is_auth_s(meta), Tag::AuthS.into()),
                 // tx tags which correspond to Null
                 (is_null(meta), Null),
                 (is_create(meta), Null),
@@ -820,6 +912,9 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 (is_access_list_addresses_len(meta), Null),
                 (is_access_list_storage_keys_len(meta), Null),
                 (is_access_list_rlc(meta), RLC),
+                (is_blob_versioned_hashes_len(meta), Null),
+                (is_authorization_list_len(meta), Null),
+                (is_auth_authority(meta), Null),
             ];
 
             cb.require_boolean(
@@ -836,6 +931,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                     usize::from(L1Msg).expr(),
                     usize::from(Eip2930).expr(),
                     usize::from(Eip1559).expr(),
+                    usize::from(Eip7702).expr(),
                 ],
             );
 
@@ -847,6 +943,42 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 );
             });
 
+            // blob-carrying txs (EIP-4844) are not supported, so none of the supported tx types
+            // has blob versioned hashes.
+            cb.condition(is_blob_versioned_hashes_len(meta), |cb| {
+                cb.require_zero(
+                    "BlobVersionedHashesLen == 0",
+                    meta.query_advice(tx_table.value, Rotation::cur()),
+                );
+            });
+
+            // only set-code txs (EIP-7702) have an authorization list, and they cannot be used
+            // to create contracts.
+            cb.condition(
+                and::expr([
+                    is_authorization_list_len(meta),
+                    not::expr(meta.query_advice(is_eip7702, Rotation::cur())),
+                ]),
+                |cb| {
+                    cb.require_zero(
+                        "AuthorizationListLen == 0",
+                        meta.query_advice(tx_table.value, Rotation::cur()),
+                    );
+                },
+            );
+            cb.condition(
+                and::expr([
+                    is_create(meta),
+                    meta.query_advice(is_eip7702, Rotation::cur()),
+                ]),
+                |cb| {
+                    cb.require_zero(
+                        "set-code tx is not a creation",
+                        meta.query_advice(tx_table.value, Rotation::cur()),
+                    );
+                },
+            );
+
             cb.require_equal(
                 "associated rlp_tag",
                 meta.query_advice(rlp_tag, Rotation::cur()),
@@ -1019,6 +1151,52 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             },
         );
 
+        meta.create_gate("is_authorization", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            cb.require_equal(
+                "is_authorization",
+                sum::expr([
+                    is_auth_chain_id(meta),
+                    is_auth_address(meta),
+                    is_auth_nonce(meta),
+                    is_auth_y_parity(meta),
+                    is_auth_r(meta),
+                    is_auth_s(meta),
+                    is_auth_authority(meta),
+                ]),
+                meta.query_advice(is_authorization, Rotation::cur()),
+            );
+
+            // Ensure continuity of is_authorization when is_final is false
+            cb.condition(
+                and::expr([
+                    meta.query_advice(is_authorization, Rotation::cur()),
+                    not::expr(meta.query_advice(is_final, Rotation::cur())),
+                ]),
+                |cb| {
+                    cb.require_zero(
+                        "is_authorization is continuous when is_final is false",
+                        meta.query_advice(is_authorization, Rotation::next()) - 1.expr(),
+                    )
+                },
+            );
+
+            cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
+        });
+
+        meta.create_gate("is_authorization_authority", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            cb.require_equal(
+                "is_authorization_authority",
+                is_auth_authority(meta),
+                meta.query_advice(is_authorization_authority, Rotation::cur()),
+            );
+
+            cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
+        });
+
         meta.create_gate("is_caller_address", |meta| {
             let mut cb = BaseConstraintBuilder::default();
 
@@ -1068,7 +1246,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
         });
 
         meta.create_gate(
-            "distinguish tx type: is_l1_msg, is_eip2930, is_eip1559",
+            "distinguish tx type: is_l1_msg, is_eip2930, is_eip1559, is_eip7702",
             |meta| {
                 let mut cb = BaseConstraintBuilder::default();
 
@@ -1090,6 +1268,12 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                     tx_type_bits.value_equals(Eip1559, Rotation::cur())(meta),
                 );
 
+                cb.require_equal(
+                    "is_eip7702 = (tx_type == Eip7702)",
+                    meta.query_advice(is_eip7702, Rotation::cur()),
+                    tx_type_bits.value_equals(Eip7702, Rotation::cur())(meta),
+                );
+
                 cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
             },
         );
@@ -1130,13 +1314,40 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
         });
 
+        meta.create_gate(
+            "lookup to authorization list dynamic section condition",
+            |meta| {
+                let mut cb = BaseConstraintBuilder::default();
+
+                cb.require_equal(
+                    "condition",
+                    and::expr([
+                        is_authorization_list_len(meta),
+                        not::expr(value_is_zero.expr(Rotation::cur())(meta)),
+                    ]),
+                    meta.query_advice(
+                        lookup_conditions[&LookupCondition::TxAuthorizationList],
+                        Rotation::cur(),
+                    ),
+                );
+
+                cb.gate(meta.query_fixed(q_enable, Rotation::cur()))
+            },
+        );
+
+        // EIP-1559 and EIP-7702 txs have a fee market instead of a gas price
+        let is_fee_market = |meta: &mut VirtualCells<F>| {
+            meta.query_advice(is_eip1559, Rotation::cur())
+                + meta.query_advice(is_eip7702, Rotation::cur())
+        };
+
         meta.create_gate("sign tag lookup into RLP table condition", |meta| {
             let mut cb = BaseConstraintBuilder::default();
 
             let is_tag_in_tx_sign = sum::expr([
                 is_nonce(meta),
                 and::expr([
-                    not::expr(meta.query_advice(is_eip1559, Rotation::cur())),
+                    not::expr(is_fee_market(meta)),
                     is_gas_price(meta),
                 ]),
                 is_gas(meta),
@@ -1149,14 +1360,15 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                         tx_type_bits.value_equals(Eip155, Rotation::cur())(meta),
                         meta.query_advice(is_eip2930, Rotation::cur()),
                         meta.query_advice(is_eip1559, Rotation::cur()),
+                        meta.query_advice(is_eip7702, Rotation::cur()),
                     ]),
                 ]),
                 and::expr([
-                    meta.query_advice(is_eip1559, Rotation::cur()),
+                    is_fee_market(meta),
                     is_max_fee_per_gas(meta),
                 ]),
                 and::expr([
-                    meta.query_advice(is_eip1559, Rotation::cur()),
+                    is_fee_market(meta),
                     is_max_priority_fee_per_gas(meta),
                 ]),
                 is_sign_length(meta),
@@ -1184,7 +1396,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             let is_tag_in_tx_hash = sum::expr([
                 is_nonce(meta),
                 and::expr([
-                    not::expr(meta.query_advice(is_eip1559, Rotation::cur())),
+                    not::expr(is_fee_market(meta)),
                     is_gas_price(meta),
                 ]),
                 is_gas(meta),
@@ -1198,11 +1410,11 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 is_hash_length(meta),
                 is_hash_rlc(meta),
                 and::expr([
-                    meta.query_advice(is_eip1559, Rotation::cur()),
+                    is_fee_market(meta),
                     is_max_fee_per_gas(meta),
                 ]),
                 and::expr([
-                    meta.query_advice(is_eip1559, Rotation::cur()),
+                    is_fee_market(meta),
                     is_max_priority_fee_per_gas(meta),
                 ]),
             ]);
@@ -1302,6 +1514,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             is_l1_msg,
             is_eip2930,
             is_eip1559,
+            is_eip7702,
             sv_address,
             calldata_gas_cost_acc,
             section_rlc,
@@ -1389,6 +1602,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             let is_next_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::next()),
                 meta.query_advice(is_access_list, Rotation::next()),
+                meta.query_advice(is_authorization, Rotation::next()),
             ]);
 
             let lookup_condition = and::expr([
@@ -1412,10 +1626,12 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             let is_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::cur()),
                 meta.query_advice(is_access_list, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
             ]);
             let is_next_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::next()),
                 meta.query_advice(is_access_list, Rotation::next()),
+                meta.query_advice(is_authorization, Rotation::next()),
             ]);
 
             // first tx in tx table
@@ -1571,6 +1787,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                     not::expr(sum::expr([
                         meta.query_advice(is_calldata, Rotation::next()),
                         meta.query_advice(is_access_list, Rotation::next()),
+                        meta.query_advice(is_authorization, Rotation::next()),
                     ])),
                 ]),
                 |cb| {
@@ -1762,11 +1979,13 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 );
             });
 
-            // on the final call data byte, if there's no access list, tx_id must change.
+            // on the final call data byte, if there's no access list or authorization list, tx_id
+            // must change.
             cb.condition(
                 and::expr([
                     is_final_cur.expr(),
                     not::expr(meta.query_advice(is_access_list, Rotation::next())),
+                    not::expr(meta.query_advice(is_authorization, Rotation::next())),
                 ]),
                 |cb| {
                     cb.require_zero(
@@ -1840,6 +2059,22 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             ]))
         });
 
+        meta.create_gate("Dynamic section init with authorization list", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            cb.require_equal(
+                "index starts with 1",
+                meta.query_advice(tx_table.index, Rotation::cur()),
+                1.expr(),
+            );
+
+            cb.gate(and::expr([
+                meta.query_fixed(q_dynamic_first, Rotation::cur()),
+                not::expr(tx_id_is_zero.expr(Rotation::cur())(meta)),
+                meta.query_advice(is_authorization, Rotation::cur()),
+            ]))
+        });
+
         meta.create_gate("Dynamic section transitions", |meta| {
             let mut cb = BaseConstraintBuilder::default();
             let is_final_cur = meta.query_advice(is_final, Rotation::cur());
@@ -1887,11 +2122,24 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 );
             });
 
+            // Dynamic section transition #3: into authorization list
+            cb.condition(
+                meta.query_advice(is_authorization, Rotation::next()),
+                |cb| {
+                    cb.require_equal(
+                        "index' starts with 1",
+                        meta.query_advice(tx_table.index, Rotation::next()),
+                        1.expr(),
+                    );
+                },
+            );
+
             cb.gate(and::expr([
                 meta.query_fixed(q_enable, Rotation::cur()),
                 sum::expr([
                     meta.query_advice(is_access_list, Rotation::cur()),
                     meta.query_advice(is_calldata, Rotation::cur()),
+                    meta.query_advice(is_authorization, Rotation::cur()),
                 ]),
                 not::expr(meta.query_advice(is_tx_id_zero, Rotation::cur())),
                 not::expr(meta.query_advice(is_tx_id_zero, Rotation::next())),
@@ -2050,11 +2298,13 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 },
             );
 
-            // When is_final_cur is true, the tx_id must change for the next dynamic section
+            // When is_final_cur is true, the tx_id must change for the next dynamic section unless
+            // the authorization list of the tx follows
             cb.condition(
                 and::expr([
                     is_final_cur.clone(),
-                    not::expr(tx_id_is_zero.expr(Rotation::next())(meta)),
+                    not::expr(meta.query_advice(is_tx_id_zero, Rotation::next())),
+                    not::expr(meta.query_advice(is_authorization, Rotation::next())),
                 ]),
                 |cb| {
                     cb.require_zero(
@@ -2071,6 +2321,417 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             ]))
         });
 
+        ////////////////////////////////////////////////////////////////////////
+        ////////  Authorization List Constraints (if available on tx)  /////////
+        ////////////////////////////////////////////////////////////////////////
+        // Each authorization tuple takes 7 rows: its fields in RLP order (chain_id, address, nonce,
+        // y_parity, r, s) and its authority. The fields are looked up in the RLP table with the
+        // access list index that the RLP circuit assigns to the tuple, which counts on from the
+        // last access list address.
+        let tx_value_length_is_one = IsEqualChip::configure(
+            meta,
+            |meta| {
+                and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization, Rotation::cur()),
+                ])
+            },
+            |meta| meta.query_advice(tx_value_length, Rotation::cur()),
+            |_| 1.expr(),
+        );
+
+        // Rows of the chain id and nonce of a tuple, which are 6 and 4 rows before its authority
+        let is_auth_int_field = |meta: &mut VirtualCells<F>| {
+            sum::expr([
+                meta.query_advice(is_authorization_authority, Rotation(6)),
+                meta.query_advice(is_authorization_authority, Rotation(4)),
+            ])
+        };
+        // RLC, length and r^length of the RLP encoding of the chain id or nonce at `rotation`
+        let rlp_encoded_int = |meta: &mut VirtualCells<F>, rotation: i32| {
+            let is_single_byte = meta.query_advice(is_single_byte, Rotation(rotation));
+            let len = meta.query_advice(tx_value_length, Rotation(rotation));
+            let bytes_rlc = meta.query_advice(tx_value_rlc, Rotation(rotation));
+            // r^len
+            let pow_of_rand = meta.query_advice(pow_of_rand, Rotation(rotation));
+
+            (
+                select::expr(
+                    is_single_byte.expr(),
+                    bytes_rlc.expr(),
+                    (0x80.expr() + len.expr()) * pow_of_rand.expr() + bytes_rlc,
+                ),
+                len + 1.expr() - is_single_byte.expr(),
+                challenges.keccak_input() * select::expr(is_single_byte, 1.expr(), pow_of_rand),
+            )
+        };
+        // payload length of rlp([chain_id, address, nonce]), queried at the authority row
+        let auth_payload_len = |meta: &mut VirtualCells<F>| {
+            let (_, chain_id_len, _) = rlp_encoded_int(meta, -6);
+            let (_, nonce_len, _) = rlp_encoded_int(meta, -4);
+
+            chain_id_len + 21.expr() + nonce_len
+        };
+
+        meta.create_gate("tx authorization list", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let is_final_cur = meta.query_advice(is_final, Rotation::cur());
+            let is_authority = meta.query_advice(is_authorization_authority, Rotation::cur());
+            cb.require_boolean("is_final is boolean", is_final_cur.clone());
+            cb.require_boolean(
+                "is_single_byte is boolean",
+                meta.query_advice(is_single_byte, Rotation::cur()),
+            );
+
+            // the authorization list of a tx ends with the authority of its last tuple
+            cb.condition(is_final_cur.clone(), |cb| {
+                cb.require_equal(
+                    "is_final => is_authorization_authority",
+                    is_authority.clone(),
+                    1.expr(),
+                );
+            });
+
+            // the tuple index and its access list index only advance after the authority
+            cb.condition(not::expr(is_final_cur), |cb| {
+                cb.require_equal(
+                    "tx_id::next == tx_id::cur",
+                    tx_id_unchanged.is_equal_expression.clone(),
+                    1.expr(),
+                );
+                cb.require_equal(
+                    "index' = index + is_authorization_authority",
+                    meta.query_advice(tx_table.index, Rotation::next()),
+                    meta.query_advice(tx_table.index, Rotation::cur()) + is_authority.expr(),
+                );
+                cb.require_equal(
+                    "al_idx' = al_idx + is_authorization_authority",
+                    meta.query_advice(al_idx, Rotation::next()),
+                    meta.query_advice(al_idx, Rotation::cur()) + is_authority,
+                );
+            });
+
+            // a single byte in [0x00, 0x7f] is its own RLP encoding
+            cb.condition(meta.query_advice(is_single_byte, Rotation::cur()), |cb| {
+                cb.require_equal(
+                    "is_single_byte => tx_value_length == 1",
+                    tx_value_length_is_one.is_equal_expression.clone(),
+                    1.expr(),
+                );
+            });
+
+            cb.gate(and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
+            ]))
+        });
+
+        meta.lookup("is_single_byte of an authorization field", |meta| {
+            let enable = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                is_auth_int_field(meta),
+                tx_value_length_is_one.is_equal_expression.clone(),
+            ]);
+            let byte = meta.query_advice(tx_value_rlc, Rotation::cur());
+
+            vec![(
+                enable
+                    * select::expr(
+                        meta.query_advice(is_single_byte, Rotation::cur()),
+                        0x7f.expr() - byte.expr(),
+                        byte - 0x80.expr(),
+                    ),
+                u8_table.into(),
+            )]
+        });
+
+        meta.lookup_any(
+            "Correct pow_of_rand for the chain id and nonce of an authorization",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    is_auth_int_field(meta),
+                ]);
+
+                vec![
+                    1.expr(),                                            // q_enable
+                    meta.query_advice(tx_value_length, Rotation::cur()), // exponent
+                    meta.query_advice(pow_of_rand, Rotation::cur()),     // pow_of_rand
+                ]
+                .into_iter()
+                .zip(pow_of_rand_table.table_exprs(meta))
+                .map(|(arg, table)| (enable.clone() * arg, table))
+                .collect()
+            },
+        );
+
+        meta.lookup_any(
+            "Correct pow_of_rand for the payload length of an authorization message",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization_authority, Rotation::cur()),
+                ]);
+
+                vec![
+                    1.expr(),                                        // q_enable
+                    auth_payload_len(meta),                          // exponent
+                    meta.query_advice(pow_of_rand, Rotation::cur()), // pow_of_rand
+                ]
+                .into_iter()
+                .zip(pow_of_rand_table.table_exprs(meta))
+                .map(|(arg, table)| (enable.clone() * arg, table))
+                .collect()
+            },
+        );
+
+        meta.create_gate("tx authorization message", |meta| {
+            let mut cb = BaseConstraintBuilder::default();
+
+            let r = challenges.keccak_input();
+            let r20 = (0..20).fold(1.expr(), |acc: Expression<F>, _| acc * r.clone());
+            let (chain_id_rlc, _, _) = rlp_encoded_int(meta, -6);
+            // an address is always encoded as 0x94 || address
+            let address_rlc =
+                0x94.expr() * r20.clone() + meta.query_advice(tx_value_rlc, Rotation(-5));
+            let (nonce_rlc, _, nonce_pow) = rlp_encoded_int(meta, -4);
+            let payload_rlc =
+                (chain_id_rlc * r20 * r.clone() + address_rlc) * nonce_pow + nonce_rlc;
+
+            // the payload of a valid authorization is shorter than 56 bytes as its chain id
+            // is zero or the chain id of the tx, so the list has a single byte header.
+            cb.require_equal(
+                "auth_msg_rlc == rlc(magic || 0xc0 + payload_len || payload)",
+                meta.query_advice(auth_msg_rlc, Rotation::cur()),
+                (AUTHORIZATION_MAGIC.expr() * r + 0xc0.expr() + auth_payload_len(meta))
+                    * meta.query_advice(pow_of_rand, Rotation::cur())
+                    + payload_rlc,
+            );
+
+            cb.gate(and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                meta.query_advice(is_authorization_authority, Rotation::cur()),
+            ]))
+        });
+
+        // TODO: a zero authority is not proven to come from an invalid tuple, and a nonzero
+        // authority is not checked against a nonce of 2^64 - 1.
+        meta.lookup_any(
+            "Keccak table lookup for the message of an authorization",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization_authority, Rotation::cur()),
+                    not::expr(value_is_zero.expr(Rotation::cur())(meta)),
+                ]);
+
+                vec![
+                    1.expr(),                                              // q_enable
+                    1.expr(),                                              // is_final
+                    meta.query_advice(auth_msg_rlc, Rotation::cur()),      // input_rlc
+                    auth_payload_len(meta) + 2.expr(),                     // input_len
+                    meta.query_advice(auth_msg_hash_rlc, Rotation::cur()), // output_rlc
+                ]
+                .into_iter()
+                .zip(keccak_table.table_exprs(meta))
+                .map(|(arg, table)| (enable.clone() * arg, table))
+                .collect()
+            },
+        );
+
+        meta.lookup_any(
+            "Sig table lookup for the authority of an authorization",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization_authority, Rotation::cur()),
+                    not::expr(value_is_zero.expr(Rotation::cur())(meta)),
+                ]);
+
+                let input_exprs = vec![
+                    1.expr(),                                              // q_enable = true
+                    meta.query_advice(auth_msg_hash_rlc, Rotation::cur()), // msg_hash_rlc
+                    meta.query_advice(tx_table.value, Rotation(-3)),       // y_parity
+                    meta.query_advice(tx_table.value, Rotation(-2)),       // r
+                    meta.query_advice(tx_table.value, Rotation(-1)),       // s
+                    meta.query_advice(tx_table.value, Rotation::cur()),    // authority
+                    1.expr(),                                              // is_valid
+                ];
+                let table_exprs = vec![
+                    meta.query_fixed(sig_table.q_enable, Rotation::cur()),
+                    meta.query_advice(sig_table.msg_hash_rlc, Rotation::cur()),
+                    meta.query_advice(sig_table.sig_v, Rotation::cur()),
+                    meta.query_advice(sig_table.sig_r_rlc, Rotation::cur()),
+                    meta.query_advice(sig_table.sig_s_rlc, Rotation::cur()),
+                    meta.query_advice(sig_table.recovered_addr, Rotation::cur()),
+                    meta.query_advice(sig_table.is_valid, Rotation::cur()),
+                ];
+
+                input_exprs
+                    .into_iter()
+                    .zip(table_exprs)
+                    .map(|(input, table)| (input * enable.expr(), table))
+                    .collect()
+            },
+        );
+
+        // an authorization with an authority is valid on any chain (chain id 0) or this one
+        meta.lookup_any(
+            "Chain id of an authorization is the chain id of the tx",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization_authority, Rotation(6)),
+                    not::expr(value_is_zero.expr(Rotation(6))(meta)),
+                    not::expr(meta.query_advice(is_none, Rotation::cur())),
+                ]);
+
+                let input_exprs = vec![
+                    meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                    ChainID.expr(),
+                    meta.query_advice(tx_value_rlc, Rotation::cur()),
+                    meta.query_advice(tx_value_length, Rotation::cur()),
+                ];
+                let table_exprs = vec![
+                    meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                    meta.query_advice(tx_table.tag, Rotation::cur()),
+                    meta.query_advice(tx_value_rlc, Rotation::cur()),
+                    meta.query_advice(tx_value_length, Rotation::cur()),
+                ];
+
+                input_exprs
+                    .into_iter()
+                    .zip(table_exprs)
+                    .map(|(input, table)| (input * enable.expr(), table))
+                    .collect()
+            },
+        );
+
+        meta.lookup_any("lookup AuthorizationListLen in the TxTable", |meta| {
+            let enable = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
+                meta.query_advice(is_final, Rotation::cur()),
+            ]);
+
+            let input_exprs = vec![
+                meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                AuthorizationListLen.expr(),
+                meta.query_advice(tx_table.index, Rotation::cur()),
+            ];
+            let table_exprs = vec![
+                meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                meta.query_advice(tx_table.tag, Rotation::cur()),
+                meta.query_advice(tx_table.value, Rotation::cur()),
+            ];
+
+            input_exprs
+                .into_iter()
+                .zip(table_exprs)
+                .map(|(input, table)| (input * enable.expr(), table))
+                .collect()
+        });
+
+        // the RLP circuit numbers authorization tuples after the access list addresses
+        meta.lookup_any(
+            "lookup AccessListAddressesLen in the TxTable from the authorization list",
+            |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization, Rotation::cur()),
+                    meta.query_advice(is_final, Rotation::cur()),
+                ]);
+
+                let input_exprs = vec![
+                    meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                    AccessListAddressesLen.expr(),
+                    meta.query_advice(al_idx, Rotation::cur())
+                        - meta.query_advice(tx_table.index, Rotation::cur()),
+                ];
+                let table_exprs = vec![
+                    meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                    meta.query_advice(tx_table.tag, Rotation::cur()),
+                    meta.query_advice(tx_table.value, Rotation::cur()),
+                ];
+
+                input_exprs
+                    .into_iter()
+                    .zip(table_exprs)
+                    .map(|(input, table)| (input * enable.expr(), table))
+                    .collect()
+            },
+        );
+
+        meta.lookup_any("is_final authorization row should be present", |meta| {
+            let enable = and::expr([
+                meta.query_fixed(q_enable, Rotation::cur()),
+                meta.query_advice(
+                    lookup_conditions[&LookupCondition::TxAuthorizationList],
+                    Rotation::cur(),
+                ),
+            ]);
+            let input_exprs = vec![
+                meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                1.expr(),
+                1.expr(),
+                meta.query_advice(tx_table.value, Rotation::cur()), // authorization_list_len
+            ];
+            let table_exprs = vec![
+                meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
+                meta.query_advice(is_final, Rotation::cur()),
+                meta.query_advice(tx_table.index, Rotation::cur()),
+            ];
+
+            input_exprs
+                .into_iter()
+                .zip(table_exprs)
+                .map(|(input, table)| (input * enable.expr(), table))
+                .collect()
+        });
+
+        // TODO: the number of tuples is not bound to the RLP table yet, as for the access list.
+        for (name, format) in [
+            (
+                "Lookup authorization field in RLP Table from tx circuit dynamic section (Signing)",
+                TxSignEip7702,
+            ),
+            (
+                "Lookup authorization field in RLP Table from tx circuit dynamic section (Hashing)",
+                TxHashEip7702,
+            ),
+        ] {
+            meta.lookup_any(name, |meta| {
+                let enable = and::expr([
+                    meta.query_fixed(q_enable, Rotation::cur()),
+                    meta.query_advice(is_authorization, Rotation::cur()),
+                    not::expr(meta.query_advice(is_authorization_authority, Rotation::cur())),
+                ]);
+
+                let input_exprs = vec![
+                    1.expr(), // q_enable = true
+                    meta.query_advice(tx_table.tx_id, Rotation::cur()),
+                    format.expr(),
+                    meta.query_advice(rlp_tag, Rotation::cur()),
+                    meta.query_advice(tx_table.value, Rotation::cur()),
+                    meta.query_advice(tx_value_rlc, Rotation::cur()),
+                    meta.query_advice(tx_value_length, Rotation::cur()),
+                    1.expr(), // is_output = true
+                    meta.query_advice(is_none, Rotation::cur()),
+                    meta.query_advice(al_idx, Rotation::cur()), // access_list_idx
+                    0.expr(),                                   // storage_key_idx
+                ];
+                assert_eq!(input_exprs.len(), rlp_table.table_exprs(meta).len());
+
+                input_exprs
+                    .into_iter()
+                    .zip(rlp_table.table_exprs(meta))
+                    .map(|(input, table)| (enable.expr() * input, table))
+                    .collect()
+            });
+        }
+
         ////////////////////////////////////////////////////////////////////////
         ///////////   SignVerify recover CallerAddress    //////////////////////
         ////////////////////////////////////////////////////////////////////////
@@ -2120,13 +2781,14 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 },
             );
 
-            // 4. EPI1559/2930: v Є {0, 1}
+            // 4. EPI1559/2930/7702: v Є {0, 1}
             cb.condition(
                 and::expr([
                     is_chain_id.expr(),
                     sum::expr([
                         tx_type_bits.value_equals(Eip1559, Rotation::cur())(meta),
                         tx_type_bits.value_equals(Eip2930, Rotation::cur())(meta),
+                        tx_type_bits.value_equals(Eip7702, Rotation::cur())(meta),
                     ]),
                 ]),
                 |cb| {
@@ -2179,6 +2841,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
                 not::expr(meta.query_fixed(q_first, Rotation::cur())),
                 not::expr(meta.query_advice(is_calldata, Rotation::cur())),
                 not::expr(meta.query_advice(is_access_list, Rotation::cur())),
+                not::expr(meta.query_advice(is_authorization, Rotation::cur())),
             ]))
         });
 
@@ -2290,6 +2953,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             let is_tag_dynamic = sum::expr([
                 meta.query_advice(is_calldata, Rotation::cur()),
                 meta.query_advice(is_access_list, Rotation::cur()),
+                meta.query_advice(is_authorization, Rotation::cur()),
             ]);
 
             // chunk_txbytes_len_acc, chunk_txbytes_rlc and pow_of_rand stay the same for the same tx
@@ -2352,6 +3016,7 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             is_l1_msg,
             is_eip2930,
             is_eip1559,
+            is_eip7702,
             is_row_hash_rlc,
             is_chain_id,
             is_final,
@@ -2373,6 +3038,12 @@ impl<F: Field> SubCircuitConfig<F> for TxCircuitConfig<F> {
             is_access_list_address,
             is_access_list_storage_key,
             field_rlc,
+            is_authorization,
+            is_authorization_authority,
+            is_single_byte,
+            tx_value_length_is_one,
+            auth_msg_rlc,
+            auth_msg_hash_rlc,
             is_chunk_bytes,
             chunk_bytes_len,
             chunk_txbytes_rlc,
@@ -2405,6 +3076,7 @@ impl<F: Field> TxCircuitConfig<F> {
         is_l1_msg_col: Column<Advice>,
         is_eip2930: Column<Advice>,
         is_eip1559: Column<Advice>,
+        is_eip7702: Column<Advice>,
         sv_address: Column<Advice>,
         calldata_gas_cost_acc: Column<Advice>,
         section_rlc: Column<Advice>,
@@ -2680,7 +3352,8 @@ impl<F: Field> TxCircuitConfig<F> {
             let sign_format = is_pre_eip155(meta) * TxSignPreEip155.expr()
                 + is_eip155(meta) * TxSignEip155.expr()
                 + meta.query_advice(is_eip2930, Rotation::cur()) * TxSignEip2930.expr()
-                + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr();
+                + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr()
+                + meta.query_advice(is_eip7702, Rotation::cur()) * TxSignEip7702.expr();
 
             // q_enable, tx_id, format, rlp_tag, tag_value, is_output, is_none
             vec![
@@ -2723,7 +3396,8 @@ impl<F: Field> TxCircuitConfig<F> {
                 + is_eip155(meta) * TxHashEip155.expr()
                 + is_l1_msg(meta) * L1MsgHash.expr()
                 + meta.query_advice(is_eip2930, Rotation::cur()) * TxHashEip2930.expr()
-                + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr();
+                + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr()
+                + meta.query_advice(is_eip7702, Rotation::cur()) * TxHashEip7702.expr();
 
             vec![
                 1.expr(), // q_enable = true
@@ -2755,10 +3429,11 @@ impl<F: Field> TxCircuitConfig<F> {
                     meta.query_advice(is_access_list_address, Rotation::cur()),
                 ]);
 
-                // only eip2930 and eip1559 contains an access list
+                // only eip2930, eip1559 and eip7702 contain an access list
                 let sign_format = meta.query_advice(is_eip2930, Rotation::cur())
                     * TxSignEip2930.expr()
-                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr();
+                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr()
+                    + meta.query_advice(is_eip7702, Rotation::cur()) * TxSignEip7702.expr();
 
                 vec![
                     1.expr(), // q_enable = true
@@ -2788,10 +3463,11 @@ impl<F: Field> TxCircuitConfig<F> {
                     meta.query_advice(is_access_list_address, Rotation::cur()),
                 ]);
 
-                // only eip2930 and eip1559 contains an access list
+                // only eip2930, eip1559 and eip7702 contain an access list
                 let hash_format = meta.query_advice(is_eip2930, Rotation::cur())
                     * TxHashEip2930.expr()
-                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr();
+                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr()
+                    + meta.query_advice(is_eip7702, Rotation::cur()) * TxHashEip7702.expr();
 
                 vec![
                     1.expr(), // q_enable = true
@@ -2824,10 +3500,11 @@ impl<F: Field> TxCircuitConfig<F> {
                     meta.query_advice(is_access_list_storage_key, Rotation::cur()),
                 ]);
 
-                // only eip2930 and eip1559 contains an access list
+                // only eip2930, eip1559 and eip7702 contain an access list
                 let sign_format = meta.query_advice(is_eip2930, Rotation::cur())
                     * TxSignEip2930.expr()
-                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr();
+                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxSignEip1559.expr()
+                    + meta.query_advice(is_eip7702, Rotation::cur()) * TxSignEip7702.expr();
 
                 vec![
                     1.expr(), // q_enable = true
@@ -2857,10 +3534,11 @@ impl<F: Field> TxCircuitConfig<F> {
                     meta.query_advice(is_access_list_storage_key, Rotation::cur()),
                 ]);
 
-                // only eip2930 and eip1559 contains an access list
+                // only eip2930, eip1559 and eip7702 contain an access list
                 let hash_format = meta.query_advice(is_eip2930, Rotation::cur())
                     * TxHashEip2930.expr()
-                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr();
+                    + meta.query_advice(is_eip1559, Rotation::cur()) * TxHashEip1559.expr()
+                    + meta.query_advice(is_eip7702, Rotation::cur()) * TxHashEip7702.expr();
 
                 vec![
                     1.expr(), // q_enable = true
@@ -2902,12 +3580,13 @@ impl<F: Field> TxCircuitConfig<F> {
             let sig_s = meta.query_advice(tx_table.value, Rotation(3));
             let sv_address = meta.query_advice(sv_address, Rotation::cur());
 
-            // include eip1559 and eip2930 type tx, sig_v is 0 or 1.
+            // include eip1559, eip2930 and eip7702 type tx, sig_v is 0 or 1.
 
             let v = is_eip155(meta) * (sig_v.expr() - 2.expr() * chain_id - 35.expr())
                 + is_pre_eip155(meta) * (sig_v.expr() - 27.expr())
                 + meta.query_advice(is_eip1559, Rotation::cur()) * sig_v.expr()
-                + meta.query_advice(is_eip2930, Rotation::cur()) * sig_v.expr();
+                + meta.query_advice(is_eip2930, Rotation::cur()) * sig_v.expr()
+                + meta.query_advice(is_eip7702, Rotation::cur()) * sig_v.expr();
 
             let input_exprs = vec![
                 1.expr(),     // q_enable = true
@@ -3316,6 +3995,16 @@ impl<F: Field> TxCircuitConfig<F> {
                 }),
                 rlc_be_bytes(&tx.max_priority_fee_per_gas.to_be_bytes(), evm_word),
             ),
+            (
+                BlobVersionedHashesLen,
+                None,
+                Value::known(F::from(tx.blob_versioned_hashes.len() as u64)),
+            ),
+            (
+                AuthorizationListLen,
+                None,
+                Value::known(F::from(tx.authorization_list.len() as u64)),
+            ),
             (BlockNumber, None, Value::known(F::from(tx.block_number))),
         ];
         for (tx_tag, rlp_input, tx_value) in fixed_rows {
@@ -3326,6 +4015,7 @@ impl<F: Field> TxCircuitConfig<F> {
                 .clone()
                 .map_or(zero_rlc, |input| input.be_bytes_rlc);
             let is_l1_msg = tx.tx_type.is_l1_msg();
+            let is_fee_market = tx.tx_type.is_eip1559() || tx.tx_type.is_eip7702();
             // it's the tx_id of next row
             let tx_id_next = if tx_tag == BlockNumber {
                 next_tx.map_or(0, |tx| tx.id)
@@ -3486,9 +4176,9 @@ impl<F: Field> TxCircuitConfig<F> {
                 let is_tag_in_set = sign_set.into_iter().filter(|tag| tx_tag == *tag).count() == 1;
                 let case1 = is_tag_in_set && !is_l1_msg;
                 let case2 = !tx.tx_type.is_pre_eip155() && !is_l1_msg && (tx_tag == ChainID);
-                let case3 = !tx.tx_type.is_eip1559() && !is_l1_msg && (tx_tag == GasPrice);
-                let case4 = tx.tx_type.is_eip1559()
-                    && (tx_tag == MaxFeePerGas || tx_tag == MaxPriorityFeePerGas);
+                let case3 = !is_fee_market && !is_l1_msg && (tx_tag == GasPrice);
+                let case4 =
+                    is_fee_market && (tx_tag == MaxFeePerGas || tx_tag == MaxPriorityFeePerGas);
                 F::from((case1 || case2 || case3 || case4) as u64)
             });
             // 4. lookup to RLP table for hashing (non L1 msg)
@@ -3508,9 +4198,9 @@ impl<F: Field> TxCircuitConfig<F> {
                 ];
                 let is_tag_in_set = hash_set.into_iter().filter(|tag| tx_tag == *tag).count() == 1;
                 let case1 = is_tag_in_set && !is_l1_msg;
-                let case2 = !tx.tx_type.is_eip1559() && !is_l1_msg && (tx_tag == GasPrice);
-                let case3 = tx.tx_type.is_eip1559()
-                    && (tx_tag == MaxFeePerGas || tx_tag == MaxPriorityFeePerGas);
+                let case2 = !is_fee_market && !is_l1_msg && (tx_tag == GasPrice);
+                let case3 =
+                    is_fee_market && (tx_tag == MaxFeePerGas || tx_tag == MaxPriorityFeePerGas);
                 F::from((case1 || case2 || case3) as u64)
             });
             // 5. lookup to RLP table for hashing (L1 msg)
@@ -3782,6 +4472,221 @@ impl<F: Field> TxCircuitConfig<F> {
         Ok(())
     }
 
+    /// Assign authorization list rows of each tx
+    fn assign_authorization_list_rows(
+        &self,
+        region: &mut Region<'_, F>,
+        offset: &mut usize,
+        tx: &Transaction,
+        next_tx: Option<&Transaction>,
+        challenges: &Challenges<Value<F>>,
+    ) -> Result<(), Error> {
+        let keccak_input = challenges.keccak_input();
+        let evm_word = challenges.evm_word();
+        let zero_rlc = keccak_input.map(|_| F::zero());
+        let (access_list_address_size, _) = access_list_size(&tx.access_list);
+
+        for (idx, authorization) in tx.authorization_list.iter().enumerate() {
+            let authority = authorization.authority(tx.chain_id).unwrap_or_default();
+            let nonce = authorization.nonce.as_u64();
+            let y_parity = authorization.y_parity.as_u64();
+
+            // the chain id and the nonce are encoded as a single byte if they are in [1, 0x80)
+            let chain_id_len = authorization.chain_id.tag_length();
+            let is_chain_id_single_byte =
+                chain_id_len == 1 && authorization.chain_id.as_u64() < 0x80;
+            let nonce_len = nonce.tag_length();
+            let is_nonce_single_byte = nonce_len == 1 && nonce < 0x80;
+            let payload_len = (chain_id_len + 1 - is_chain_id_single_byte as u32)
+                + 21
+                + (nonce_len + 1 - is_nonce_single_byte as u32);
+
+            let msg = authorization.rlp_unsigned();
+            let msg_rlc = rlc_be_bytes(&msg, keccak_input);
+            let msg_hash_rlc = rlc_be_bytes(&keccak256(&msg), evm_word);
+
+            let rows = [
+                (
+                    AuthorizationChainId,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthChainId.into(),
+                        is_none: authorization.chain_id.is_zero(),
+                        be_bytes_len: chain_id_len,
+                        be_bytes_rlc: rlc_be_bytes(
+                            &authorization.chain_id.to_be_bytes(),
+                            keccak_input,
+                        ),
+                    }),
+                    rlc_be_bytes(&authorization.chain_id.to_be_bytes(), evm_word),
+                    is_chain_id_single_byte,
+                    keccak_input.map(|r| r.pow([chain_id_len as u64, 0, 0, 0])),
+                ),
+                (
+                    AuthorizationAddress,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthAddress.into(),
+                        is_none: false,
+                        be_bytes_len: authorization.address.tag_length(),
+                        be_bytes_rlc: rlc_be_bytes(
+                            &authorization.address.to_fixed_bytes(),
+                            keccak_input,
+                        ),
+                    }),
+                    Value::known(authorization.address.to_scalar().unwrap()),
+                    false,
+                    zero_rlc,
+                ),
+                (
+                    AuthorizationNonce,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthNonce.into(),
+                        is_none: nonce == 0,
+                        be_bytes_len: nonce_len,
+                        be_bytes_rlc: rlc_be_bytes(&nonce.to_be_bytes(), keccak_input),
+                    }),
+                    Value::known(F::from(nonce)),
+                    is_nonce_single_byte,
+                    keccak_input.map(|r| r.pow([nonce_len as u64, 0, 0, 0])),
+                ),
+                (
+                    AuthorizationYParity,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthYParity.into(),
+                        is_none: y_parity == 0,
+                        be_bytes_len: y_parity.tag_length(),
+                        be_bytes_rlc: rlc_be_bytes(&y_parity.to_be_bytes(), keccak_input),
+                    }),
+                    Value::known(F::from(y_parity)),
+                    false,
+                    zero_rlc,
+                ),
+                (
+                    AuthorizationR,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthR.into(),
+                        is_none: authorization.r.is_zero(),
+                        be_bytes_len: authorization.r.tag_length(),
+                        be_bytes_rlc: rlc_be_bytes(&authorization.r.to_be_bytes(), keccak_input),
+                    }),
+                    rlc_be_bytes(&authorization.r.to_be_bytes(), evm_word),
+                    false,
+                    zero_rlc,
+                ),
+                (
+                    AuthorizationS,
+                    Some(RlpTableInputValue {
+                        tag: Tag::AuthS.into(),
+                        is_none: authorization.s.is_zero(),
+                        be_bytes_len: authorization.s.tag_length(),
+                        be_bytes_rlc: rlc_be_bytes(&authorization.s.to_be_bytes(), keccak_input),
+                    }),
+                    rlc_be_bytes(&authorization.s.to_be_bytes(), evm_word),
+                    false,
+                    zero_rlc,
+                ),
+                (
+                    AuthorizationAuthority,
+                    None,
+                    Value::known(authority.to_scalar().unwrap()),
+                    false,
+                    keccak_input.map(|r| r.pow([payload_len as u64, 0, 0, 0])),
+                ),
+            ];
+
+            for (tx_tag, rlp_input, tx_value, is_single_byte, pow_of_rand) in rows {
+                let rlp_tag = rlp_input.clone().map_or(Null, |input| input.tag);
+                let rlp_is_none = rlp_input.clone().map_or(false, |input| input.is_none);
+                let rlp_be_bytes_len = rlp_input.clone().map_or(0, |input| input.be_bytes_len);
+                let rlp_be_bytes_rlc = rlp_input.map_or(zero_rlc, |input| input.be_bytes_rlc);
+                let is_authority = tx_tag == AuthorizationAuthority;
+                let is_final = is_authority && idx == tx.authorization_list.len() - 1;
+                let tx_id_next = if is_final {
+                    next_tx.map_or(0, |tx| tx.id)
+                } else {
+                    tx.id
+                };
+
+                self.assign_common_part(
+                    region,
+                    *offset,
+                    Some(tx),
+                    tx_id_next,
+                    tx_tag,
+                    (idx + 1) as u64,
+                    tx_value,
+                    Value::known(F::zero()),
+                )?;
+
+                // 1st phase columns
+                for (col_anno, col, col_val) in [
+                    ("block_num", self.block_num, F::from(tx.block_number)),
+                    (
+                        "al_idx",
+                        self.al_idx,
+                        F::from(access_list_address_size + (idx + 1) as u64),
+                    ),
+                    ("sk_idx", self.sk_idx, F::zero()),
+                    (
+                        "rlp_tag",
+                        self.rlp_tag,
+                        F::from(usize::from(rlp_tag) as u64),
+                    ),
+                    ("is_none", self.is_none, F::from(rlp_is_none as u64)),
+                    (
+                        "tx_value_length",
+                        self.tx_value_length,
+                        F::from(rlp_be_bytes_len as u64),
+                    ),
+                    ("is_final", self.is_final, F::from(is_final as u64)),
+                    ("is_authorization", self.is_authorization, F::one()),
+                    (
+                        "is_authorization_authority",
+                        self.is_authorization_authority,
+                        F::from(is_authority as u64),
+                    ),
+                    (
+                        "is_single_byte",
+                        self.is_single_byte,
+                        F::from(is_single_byte as u64),
+                    ),
+                ] {
+                    region.assign_advice(|| col_anno, col, *offset, || Value::known(col_val))?;
+                }
+
+                let tx_value_length_is_one_chip =
+                    IsEqualChip::construct(self.tx_value_length_is_one.clone());
+                tx_value_length_is_one_chip.assign(
+                    region,
+                    *offset,
+                    Value::known(F::from(rlp_be_bytes_len as u64)),
+                    Value::known(F::one()),
+                )?;
+
+                // 2nd phase columns
+                for (col_anno, col, col_val) in [
+                    ("tx_value_rlc", self.tx_value_rlc, rlp_be_bytes_rlc),
+                    ("pow_of_rand", self.pow_of_rand, pow_of_rand),
+                    (
+                        "auth_msg_rlc",
+                        self.auth_msg_rlc,
+                        if is_authority { msg_rlc } else { zero_rlc },
+                    ),
+                    (
+                        "auth_msg_hash_rlc",
+                        self.auth_msg_hash_rlc,
+                        if is_authority { msg_hash_rlc } else { zero_rlc },
+                    ),
+                ] {
+                    region.assign_advice(|| col_anno, col, *offset, || col_val)?;
+                }
+
+                *offset += 1;
+            }
+        }
+
+        Ok(())
+    }
+
     // Assigns to common columns in different parts of tx circuit
     // 1. 1st all zero row
     // 2. fixed rows of each tx
@@ -3859,6 +4764,11 @@ impl<F: Field> TxCircuitConfig<F> {
                 self.is_eip1559,
                 F::from(tx_type.is_eip1559() as u64),
             ),
+            (
+                "is_eip7702",
+                self.is_eip7702,
+                F::from(tx_type.is_eip7702() as u64),
+            ),
             (
                 "is_tx_id_zero",
                 self.is_tx_id_zero,
@@ -4049,6 +4959,8 @@ impl<F: Field> TxCircuit<F> {
             })
             .collect::<Result<Vec<SignData<secp256k1::Fq, Secp256k1Affine>>, Error>>()?;
         sign_datas.push(SignData::default());
+        // authorization tuples are verified by the same SignVerify Chip
+        sign_datas.extend(self.txs.iter().flat_map(|tx| tx.authorization_sign_datas()));
         // Keccak inputs from SignVerify Chip
         let sign_verify_inputs = keccak_inputs_sign_verify(&sign_datas);
         inputs.extend_from_slice(&sign_verify_inputs);
@@ -4263,7 +5175,7 @@ impl<F: Field> TxCircuit<F> {
                     }
                     let is_last_tx = i == (sigs.len() - 1);
                     let next_tx = if is_last_tx {
-                        self.txs.iter().find(|tx| !tx.call_data.is_empty() || (tx.access_list.as_ref().map_or(false, |al| !al.0.is_empty())))
+                        self.txs.iter().find(|tx| !tx.call_data.is_empty() || (tx.access_list.as_ref().map_or(false, |al| !al.0.is_empty())) || !tx.authorization_list.is_empty())
                     } else {
                         Some(get_tx(i+1))
                     };
@@ -4314,7 +5226,7 @@ impl<F: Field> TxCircuit<F> {
                         .txs
                         .iter()
                         .skip(i + 1)
-                        .find(|tx| !tx.call_data.is_empty() || (tx.access_list.as_ref().map_or(false, |al| !al.0.is_empty())));
+                        .find(|tx| !tx.call_data.is_empty() || (tx.access_list.as_ref().map_or(false, |al| !al.0.is_empty())) || !tx.authorization_list.is_empty());
                     config.assign_calldata_rows(
                         &mut region,
                         &mut offset,
@@ -4329,6 +5241,13 @@ impl<F: Field> TxCircuit<F> {
                         next_tx,
                         challenges,
                     )?;
+                    config.assign_authorization_list_rows(
+                        &mut region,
+                        &mut offset,
+                        tx,
+                        next_tx,
+                        challenges,
+                    )?;
                 }
                 assert!(offset <= calldata_last_row, "{offset}, {calldata_last_row}");
                 // 3.2 pad calldata with zeros
@@ -4414,18 +5333,25 @@ impl<F: Field> SubCircuit<F> for TxCircuit<F> {
                 }
             })
             .sum::<usize>();
+        // each authorization tuple takes one row per field and one for its authority
+        let sum_authorization_list_len = block
+            .txs
+            .iter()
+            .map(|tx| tx.authorization_list.len() * 7)
+            .sum::<usize>();
 
         // With the introduction of access list, the max_calldata circuit parameter now has to share
         // capacity between calldata and access list rows TODO: The max_calldata parameter
         // should be renamed later to max_dynamic
         let max_dynamic_data = if block.circuits_params.max_calldata == 0 {
             // input-specific max_dynamic
-            sum_calldata_len + sum_access_list_len
+            sum_calldata_len + sum_access_list_len + sum_authorization_list_len
         } else {
             block.circuits_params.max_calldata
         };
-        let dynamic_usage =
-            (sum_calldata_len + sum_access_list_len) as f32 / max_dynamic_data as f32;
+        let dynamic_usage = (sum_calldata_len + sum_access_list_len + sum_authorization_list_len)
+            as f32
+            / max_dynamic_data as f32;
 
         // Get the highest usage fraction out of all capacities
         let highest_usage = ([blob_usage, dynamic_usage])
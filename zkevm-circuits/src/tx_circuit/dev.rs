@@ -117,12 +117,19 @@ impl<F: Field> TxCircuitTester<F> {
         start_l1_queue_index: u64,
         txs: Vec<Transaction>,
     ) -> Self {
+        // authorities of EIP-7702 txs are recovered by the sig circuit as well
+        let authorization_sign_datas = txs
+            .iter()
+            .flat_map(|tx| tx.authorization_sign_datas())
+            .collect::<Vec<_>>();
+        let mut signatures_k1 = get_sign_data(&txs, max_txs, chain_id as usize).unwrap();
+        signatures_k1.extend(authorization_sign_datas.iter().cloned());
         TxCircuitTester::<F> {
             sig_circuit: SigCircuit {
-                max_verify_k1: max_txs,
+                max_verify_k1: max_txs + authorization_sign_datas.len(),
                 // tx circuit don't need r1 sigs, set max_verify_r1 = 0 here.
                 max_verify_r1: 0usize,
-                signatures_k1: get_sign_data(&txs, max_txs, chain_id as usize).unwrap(),
+                signatures_k1,
                 signatures_r1: vec![],
                 _marker: PhantomData,
             },
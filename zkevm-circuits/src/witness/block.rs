@@ -14,8 +14,8 @@ use crate::{
 };
 use bus_mapping::{
     circuit_input_builder::{
-        self, BigModExp, CircuitInputBuilder, CircuitsParams, CopyEvent, EcAddOp, EcMulOp,
-        EcPairingOp, ExpEvent, PrecompileEvents, SHA256,
+        self, BigModExp, Blake2F, CircuitInputBuilder, CircuitsParams, CopyEvent, EcAddOp, EcMulOp,
+        EcPairingOp, ExpEvent, PrecompileEvents, Ripemd160, SHA256,
     },
     Error,
 };
@@ -153,7 +153,8 @@ impl Block {
         }
     }
 
-    /// Get signature (witness) from the block for tx signatures and ecRecover calls.
+    /// Get signature (witness) from the block for tx signatures, EIP-7702 authorizations and
+    /// ecRecover calls.
     pub(crate) fn get_sign_data(
         &self,
         padding: bool,
@@ -166,6 +167,8 @@ impl Block {
             .map(|tx| tx.sign_data())
             .filter_map(|res| res.ok())
             .collect::<Vec<SignData<secp256k1::Fq, Secp256k1Affine>>>();
+        // EIP-7702 authorities are recovered from the authorization tuples
+        signatures.extend(self.txs.iter().flat_map(|tx| tx.authorization_sign_datas()));
         signatures.extend_from_slice(&self.precompile_events.get_ecrecover_events());
         if padding && self.txs.len() < self.circuits_params.max_txs {
             // padding tx's sign data
@@ -216,6 +219,16 @@ impl Block {
         self.precompile_events.get_sha256_events()
     }
 
+    /// Get ripemd160 operations from all precompiled contract calls in this block.
+    pub(crate) fn get_ripemd160(&self) -> Vec<Ripemd160> {
+        self.precompile_events.get_ripemd160_events()
+    }
+
+    /// Get BLAKE2F compressions from all precompiled contract calls in this block.
+    pub(crate) fn get_blake2f(&self) -> Vec<Blake2F> {
+        self.precompile_events.get_blake2f_events()
+    }
+
     pub(crate) fn print_evm_circuit_row_usage(&self) {
         let mut num_rows = 0;
         let mut counter = HashMap::new();
@@ -308,6 +321,10 @@ impl Block {
         );
         log::debug!("tx_receipt num: {}", self.rws.rw_num(RwTableTag::TxReceipt));
         log::debug!("tx_log num: {}", self.rws.rw_num(RwTableTag::TxLog));
+        log::debug!(
+            "tx_created_account num: {}",
+            self.rws.rw_num(RwTableTag::TxCreatedAccount)
+        );
         log::debug!("start num: {}", self.rws.rw_num(RwTableTag::Start));
     }
 
@@ -458,6 +475,8 @@ pub struct BlockContext {
     pub difficulty: Word,
     /// The base fee, the minimum amount of gas fee for a transaction
     pub base_fee: Word,
+    /// The blob base fee, the price of blob gas (EIP-4844)
+    pub blob_base_fee: Word,
     /// The hash of previous blocks
     pub history_hashes: Vec<Word>,
     /// The chain id
@@ -532,6 +551,13 @@ impl BlockContext {
                     Value::known(current_block_number),
                     Value::known(F::from(num_all_txs)),
                 ],
+                [
+                    Value::known(F::from(BlockContextFieldTag::BlobBaseFee as u64)),
+                    Value::known(current_block_number),
+                    randomness.map(|randomness| {
+                        rlc::value(&self.blob_base_fee.to_le_bytes(), randomness)
+                    }),
+                ],
             ],
             self.block_hash_assignments(randomness),
         ]
@@ -586,6 +612,7 @@ impl From<&circuit_input_builder::Blocks> for BlockContexts {
                             timestamp: block.timestamp,
                             difficulty: block.difficulty,
                             base_fee: block.base_fee,
+                            blob_base_fee: block.blob_base_fee,
                             history_hashes: block.history_hashes.clone(),
                             chain_id: block.chain_id,
                             parent_hash: block.parent_hash,
@@ -23,6 +23,13 @@ pub fn keccak_inputs(block: &Block) -> Result<Vec<Vec<u8>>, Error> {
         "keccak total len after txs: {}",
         keccak_inputs.iter().map(|i| i.len()).sum::<usize>()
     );
+    // EIP-7702 authorizations
+    let authorization_sigs = block
+        .txs
+        .iter()
+        .flat_map(|tx| tx.authorization_sign_datas())
+        .collect::<Vec<_>>();
+    keccak_inputs.extend_from_slice(&keccak_inputs_sign_verify(&authorization_sigs));
     // Ecrecover
     let mut ecrecover_sigs = block.precompile_events.get_ecrecover_events();
     ecrecover_sigs.push(SignData::default());
@@ -136,6 +136,20 @@ pub enum Tag {
     // L1MsgHash
     /// Sender
     Sender,
+
+    // EIP-7702
+    /// Chain ID of authorization tuple
+    AuthChainId,
+    /// Delegated address of authorization tuple
+    AuthAddress,
+    /// Nonce of authorization tuple
+    AuthNonce,
+    /// Signature y_parity of authorization tuple
+    AuthYParity,
+    /// Signature r of authorization tuple
+    AuthR,
+    /// Signature s of authorization tuple
+    AuthS,
 }
 
 impl From<Tag> for usize {
@@ -178,6 +192,11 @@ impl Tag {
     pub fn is_access_list_storage_key(&self) -> bool {
         matches!(self, Self::AccessListStorageKey)
     }
+
+    /// If the tag is AuthChainId, i.e. the first field of an authorization tuple
+    pub fn is_auth_chain_id(&self) -> bool {
+        matches!(self, Self::AuthChainId)
+    }
 }
 
 /// RLP tags
@@ -219,13 +238,14 @@ use crate::{
     witness::{
         l1_msg,
         Format::{
-            TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashPreEip155, TxSignEip155,
-            TxSignEip1559, TxSignEip2930, TxSignPreEip155,
+            TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashEip7702, TxHashPreEip155,
+            TxSignEip155, TxSignEip1559, TxSignEip2930, TxSignEip7702, TxSignPreEip155,
         },
         Tag::{
-            AccessListAddress, AccessListStorageKey, BeginObject, BeginVector, ChainId, Data,
-            EndObject, EndVector, Gas, GasPrice, MaxFeePerGas, MaxPriorityFeePerGas, Nonce, SigR,
-            SigS, SigV, To, TxType, Value as TxValue, Zero1, Zero2,
+            AccessListAddress, AccessListStorageKey, AuthAddress, AuthChainId, AuthNonce, AuthR,
+            AuthS, AuthYParity, BeginObject, BeginVector, ChainId, Data, EndObject, EndVector, Gas,
+            GasPrice, MaxFeePerGas, MaxPriorityFeePerGas, Nonce, SigR, SigS, SigV, To, TxType,
+            Value as TxValue, Zero1, Zero2,
         },
     },
 };
@@ -545,6 +565,143 @@ pub fn eip1559_tx_sign_rom_table_rows() -> Vec<RomTableRow> {
         .collect()
 }
 
+pub fn eip7702_tx_sign_rom_table_rows() -> Vec<RomTableRow> {
+    let rows = vec![
+        (TxType, BeginObject, 1, vec![1]),
+        (BeginObject, ChainId, MAX_TAG_LENGTH_OF_LIST, vec![2]),
+        (ChainId, Nonce, N_BYTES_U64, vec![3]),
+        (Nonce, MaxPriorityFeePerGas, N_BYTES_U64, vec![4]),
+        (MaxPriorityFeePerGas, MaxFeePerGas, N_BYTES_WORD, vec![5]),
+        (MaxFeePerGas, Gas, N_BYTES_WORD, vec![6]),
+        (Gas, To, N_BYTES_U64, vec![7]),
+        (To, TxValue, N_BYTES_ACCOUNT_ADDRESS, vec![8]),
+        (TxValue, Data, N_BYTES_WORD, vec![9]),
+        (Data, BeginVector, N_BYTES_CALLDATA, vec![10, 11]),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![21]), // access_list is none
+        (BeginVector, BeginObject, MAX_TAG_LENGTH_OF_LIST, vec![12]),
+        (
+            BeginObject,
+            AccessListAddress,
+            MAX_TAG_LENGTH_OF_LIST,
+            vec![13],
+        ),
+        (
+            AccessListAddress,
+            BeginVector,
+            N_BYTES_ACCOUNT_ADDRESS,
+            vec![14, 15],
+        ),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![18]), /* access_list.storage_keys
+                                                                     * is none */
+        (
+            BeginVector,
+            AccessListStorageKey,
+            MAX_TAG_LENGTH_OF_LIST,
+            vec![16, 17],
+        ),
+        (AccessListStorageKey, EndVector, N_BYTES_WORD, vec![18]), // finished parsing storage keys
+        (
+            AccessListStorageKey,
+            AccessListStorageKey,
+            N_BYTES_WORD,
+            vec![16, 17],
+        ), // keep parsing storage_keys
+        (EndVector, EndObject, 0, vec![19, 20]),
+        (EndObject, EndVector, 0, vec![21]), // finished parsing access_list
+        (EndObject, BeginObject, 0, vec![12]), // parse another access_list entry
+        (EndVector, BeginVector, 0, vec![22, 23]),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![33]), // authorization_list is none
+        (BeginVector, BeginObject, MAX_TAG_LENGTH_OF_LIST, vec![24]),
+        (BeginObject, AuthChainId, MAX_TAG_LENGTH_OF_LIST, vec![25]),
+        (AuthChainId, AuthAddress, N_BYTES_WORD, vec![26]),
+        (AuthAddress, AuthNonce, N_BYTES_ACCOUNT_ADDRESS, vec![27]),
+        (AuthNonce, AuthYParity, N_BYTES_U64, vec![28]),
+        (AuthYParity, AuthR, 1, vec![29]),
+        (AuthR, AuthS, N_BYTES_WORD, vec![30]),
+        (AuthS, EndObject, N_BYTES_WORD, vec![31, 32]),
+        (EndObject, EndVector, 0, vec![33]), // finished parsing authorization_list
+        (EndObject, BeginObject, 0, vec![24]), // parse another authorization tuple
+        (EndVector, EndObject, 0, vec![34]),
+        (EndObject, EndObject, 0, vec![35]),
+        // used to emit TxGasCostInL1
+        (EndObject, BeginObject, 0, vec![]),
+    ];
+
+    rows.into_iter()
+        .map(|row| (row.0, row.1, row.2, TxSignEip7702, row.3).into())
+        .collect()
+}
+
+pub fn eip7702_tx_hash_rom_table_rows() -> Vec<RomTableRow> {
+    let rows = vec![
+        (TxType, BeginObject, 1, vec![1]),
+        (BeginObject, ChainId, MAX_TAG_LENGTH_OF_LIST, vec![2]),
+        (ChainId, Nonce, N_BYTES_U64, vec![3]),
+        (Nonce, MaxPriorityFeePerGas, N_BYTES_U64, vec![4]),
+        (MaxPriorityFeePerGas, MaxFeePerGas, N_BYTES_WORD, vec![5]),
+        (MaxFeePerGas, Gas, N_BYTES_WORD, vec![6]),
+        (Gas, To, N_BYTES_U64, vec![7]),
+        (To, TxValue, N_BYTES_ACCOUNT_ADDRESS, vec![8]),
+        (TxValue, Data, N_BYTES_WORD, vec![9]),
+        (Data, BeginVector, N_BYTES_CALLDATA, vec![10, 11]),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![21]), // access_list is none
+        (BeginVector, BeginObject, MAX_TAG_LENGTH_OF_LIST, vec![12]),
+        (
+            BeginObject,
+            AccessListAddress,
+            MAX_TAG_LENGTH_OF_LIST,
+            vec![13],
+        ),
+        (
+            AccessListAddress,
+            BeginVector,
+            N_BYTES_ACCOUNT_ADDRESS,
+            vec![14, 15],
+        ),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![18]), /* access_list.storage_keys
+                                                                     * is none */
+        (
+            BeginVector,
+            AccessListStorageKey,
+            MAX_TAG_LENGTH_OF_LIST,
+            vec![16, 17],
+        ),
+        (AccessListStorageKey, EndVector, N_BYTES_WORD, vec![18]), // finished parsing storage keys
+        (
+            AccessListStorageKey,
+            AccessListStorageKey,
+            N_BYTES_WORD,
+            vec![16, 17],
+        ), // keep parsing storage_keys
+        (EndVector, EndObject, 0, vec![19, 20]),
+        (EndObject, EndVector, 0, vec![21]), // finished parsing access_list
+        (EndObject, BeginObject, 0, vec![12]), // parse another access_list entry
+        (EndVector, BeginVector, 0, vec![22, 23]),
+        (BeginVector, EndVector, MAX_TAG_LENGTH_OF_LIST, vec![33]), // authorization_list is none
+        (BeginVector, BeginObject, MAX_TAG_LENGTH_OF_LIST, vec![24]),
+        (BeginObject, AuthChainId, MAX_TAG_LENGTH_OF_LIST, vec![25]),
+        (AuthChainId, AuthAddress, N_BYTES_WORD, vec![26]),
+        (AuthAddress, AuthNonce, N_BYTES_ACCOUNT_ADDRESS, vec![27]),
+        (AuthNonce, AuthYParity, N_BYTES_U64, vec![28]),
+        (AuthYParity, AuthR, 1, vec![29]),
+        (AuthR, AuthS, N_BYTES_WORD, vec![30]),
+        (AuthS, EndObject, N_BYTES_WORD, vec![31, 32]),
+        (EndObject, EndVector, 0, vec![33]), // finished parsing authorization_list
+        (EndObject, BeginObject, 0, vec![24]), // parse another authorization tuple
+        (EndVector, SigV, 0, vec![34]),
+        (SigV, SigR, N_BYTES_U64, vec![35]),
+        (SigR, SigS, N_BYTES_WORD, vec![36]),
+        (SigS, EndObject, N_BYTES_WORD, vec![37]),
+        (EndObject, EndObject, 0, vec![38]),
+        // used to emit TxGasCostInL1
+        (EndObject, BeginObject, 0, vec![]),
+    ];
+
+    rows.into_iter()
+        .map(|row| (row.0, row.1, row.2, TxHashEip7702, row.3).into())
+        .collect()
+}
+
 /// Read-only Memory table row.
 #[derive(Debug, Clone)]
 pub struct RomTableRow {
@@ -603,6 +760,10 @@ pub enum Format {
     TxHashEip2930,
     /// L1 Msg
     L1MsgHash,
+    /// Sign for EIP7702 tx
+    TxSignEip7702,
+    /// Hash for EIP7702 tx
+    TxHashEip7702,
 }
 
 impl From<Format> for usize {
@@ -629,6 +790,8 @@ impl Format {
             TxSignEip2930 => eip2930_tx_sign_rom_table_rows(),
             TxHashEip2930 => eip2930_tx_hash_rom_table_rows(),
             Self::L1MsgHash => l1_msg::rom_table_rows(),
+            TxSignEip7702 => eip7702_tx_sign_rom_table_rows(),
+            TxHashEip7702 => eip7702_tx_hash_rom_table_rows(),
         }
     }
 }
@@ -723,7 +886,9 @@ pub struct RlpTable<F: Field> {
     /// If current tag's value is None.
     pub is_none: bool,
     /// The index of access list address
-    /// Corresponding tag is AccessListAddress
+    /// Corresponding tag is AccessListAddress. For EIP-7702 txs the index keeps counting through
+    /// the authorization list, i.e. the i-th authorization tuple has index
+    /// `access_list_addresses_len + i`.
     pub access_list_idx: u64,
     /// The index of the storage key
     /// The combination (access_list_idx, storage_key_idx)
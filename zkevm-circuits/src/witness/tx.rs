@@ -6,19 +6,19 @@ use crate::{
         rlp_fsm::{RlpStackOp, SmState},
         DataTable, Format,
         Format::{
-            L1MsgHash, TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashPreEip155, TxSignEip155,
-            TxSignEip1559, TxSignEip2930, TxSignPreEip155,
+            L1MsgHash, TxHashEip155, TxHashEip1559, TxHashEip2930, TxHashEip7702, TxHashPreEip155,
+            TxSignEip155, TxSignEip1559, TxSignEip2930, TxSignEip7702, TxSignPreEip155,
         },
         RlpFsmWitnessGen, RlpFsmWitnessRow, RlpTable, RlpTag, State,
         State::DecodeTagStart,
         StateMachine,
-        Tag::{EndObject, EndVector},
+        Tag::{BeginVector, EndObject, EndVector},
     },
 };
 use bus_mapping::circuit_input_builder::{self, get_dummy_tx_hash, TxL1Fee};
 use eth_types::{
     evm_types::gas_utils::{tx_access_list_gas_cost, tx_data_gas_cost},
-    geth_types::{access_list_size, TxType, TxType::PreEip155},
+    geth_types::{access_list_size, Authorization, TxType, TxType::PreEip155},
     sign_types::{
         biguint_to_32bytes_le, ct_option_ok_or, get_dummy_tx, recover_pk2, SignData, SECP256K1_Q,
     },
@@ -96,6 +96,10 @@ pub struct Transaction {
     pub l1_fee_committed: TxL1Fee,
     /// Optional access list for EIP-2930
     pub access_list: Option<AccessList>,
+    /// Blob versioned hashes for EIP-4844
+    pub blob_versioned_hashes: Vec<H256>,
+    /// Authorization list for EIP-7702
+    pub authorization_list: Vec<Authorization>,
     /// The calls made in the transaction
     pub calls: Vec<Call>,
     /// The steps executioned in the transaction
@@ -384,6 +388,20 @@ impl Transaction {
                 }),
                 Value::known(F::zero()),
             ],
+            [
+                Value::known(F::from(self.id as u64)),
+                Value::known(F::from(TxContextFieldTag::BlobVersionedHashesLen as u64)),
+                Value::known(F::zero()),
+                Value::known(F::from(self.blob_versioned_hashes.len() as u64)),
+                Value::known(F::zero()),
+            ],
+            [
+                Value::known(F::from(self.id as u64)),
+                Value::known(F::from(TxContextFieldTag::AuthorizationListLen as u64)),
+                Value::known(F::zero()),
+                Value::known(F::from(self.authorization_list.len() as u64)),
+                Value::known(F::zero()),
+            ],
             [
                 Value::known(F::from(self.id as u64)),
                 Value::known(F::from(TxContextFieldTag::BlockNumber as u64)),
@@ -450,6 +468,96 @@ impl Transaction {
         assignments
     }
 
+    /// Assignments for tx table authorization list. Each authorization tuple is represented by
+    /// its fields in RLP order followed by its authority, which is zero if the tuple is invalid
+    /// on this chain.
+    pub fn table_assignments_authorization_list_dyn<F: Field>(
+        &self,
+        challenges: Challenges<Value<F>>,
+    ) -> Vec<[Value<F>; 5]> {
+        self.authorization_list
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, authorization)| {
+                let authority = authorization.authority(self.chain_id).unwrap_or_default();
+                [
+                    (
+                        TxContextFieldTag::AuthorizationChainId,
+                        rlc_be_bytes(&authorization.chain_id.to_be_bytes(), challenges.evm_word()),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationAddress,
+                        Value::known(authorization.address.to_scalar().unwrap()),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationNonce,
+                        Value::known(F::from(authorization.nonce.as_u64())),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationYParity,
+                        Value::known(F::from(authorization.y_parity.as_u64())),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationR,
+                        rlc_be_bytes(&authorization.r.to_be_bytes(), challenges.evm_word()),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationS,
+                        rlc_be_bytes(&authorization.s.to_be_bytes(), challenges.evm_word()),
+                    ),
+                    (
+                        TxContextFieldTag::AuthorizationAuthority,
+                        Value::known(authority.to_scalar().unwrap()),
+                    ),
+                ]
+                .map(|(tag, value)| {
+                    [
+                        Value::known(F::from(self.id as u64)),
+                        Value::known(F::from(tag as u64)),
+                        Value::known(F::from((idx + 1) as u64)),
+                        value,
+                        Value::known(F::zero()),
+                    ]
+                })
+            })
+            .collect()
+    }
+
+    /// Sign data of the authorization tuples whose signatures recover an authority.
+    pub fn authorization_sign_datas(
+        &self,
+    ) -> Vec<SignData<secp256k1::Fq, secp256k1::Secp256k1Affine>> {
+        self.authorization_list
+            .iter()
+            .filter(|authorization| authorization.authority(self.chain_id).is_some())
+            .map(|authorization| {
+                authorization
+                    .sign_data()
+                    .expect("authority has been recovered")
+            })
+            .collect()
+    }
+
+    /// Assignments for tx table blob versioned hashes
+    pub fn table_assignments_blob_hashes_dyn<F: Field>(
+        &self,
+        challenges: Challenges<Value<F>>,
+    ) -> Vec<[Value<F>; 5]> {
+        self.blob_versioned_hashes
+            .iter()
+            .enumerate()
+            .map(|(idx, hash)| {
+                [
+                    Value::known(F::from(self.id as u64)),
+                    Value::known(F::from(TxContextFieldTag::BlobVersionedHash as u64)),
+                    Value::known(F::from(idx as u64)),
+                    rlc_be_bytes(&hash.to_fixed_bytes(), challenges.evm_word()),
+                    Value::known(F::zero()),
+                ]
+            })
+            .collect()
+    }
+
     pub(crate) fn gen_rlp_witness<F: Field>(
         &self,
         is_hash: bool,
@@ -464,6 +572,7 @@ impl Transaction {
                     TxType::Eip1559 => TxHashEip1559,
                     TxType::L1Msg => L1MsgHash,
                     TxType::Eip2930 => TxHashEip2930,
+                    TxType::Eip7702 => TxHashEip7702,
                 },
             )
         } else {
@@ -474,6 +583,7 @@ impl Transaction {
                     TxType::PreEip155 => TxSignPreEip155,
                     TxType::Eip1559 => TxSignEip1559,
                     TxType::Eip2930 => TxSignEip2930,
+                    TxType::Eip7702 => TxSignEip7702,
                     _ => unreachable!("tx type {:?} not supported", self.tx_type),
                 },
             )
@@ -548,6 +658,10 @@ impl Transaction {
         // unique identifier of addresses and storage keys included in access list
         let mut access_list_idx: u64 = 0;
         let mut storage_key_idx: u64 = 0;
+        // The number of items of the last decoded list on depth 2, and whether the previous
+        // row ended such a list. They are used to number the authorization tuples (EIP-7702).
+        let mut access_list_len: u64 = 0;
+        let mut is_prev_access_list_end = false;
 
         loop {
             // default behavior
@@ -580,10 +694,11 @@ impl Transaction {
                             // structure at depth 4 specified in EIP standards
                             storage_key_idx = 0;
                         } else if cur.depth == 2 {
-                            // end of access list
+                            // end of access list or authorization list
                             // note: depth alone currently is sufficient to ascertain
-                            // the end of an access list as there's no other nested
+                            // the end of these lists as there's no other nested
                             // structure at depth 2 specified in EIP standards
+                            access_list_len = access_list_idx;
                             access_list_idx = 0;
                         } else if cur.depth == 0 {
                             // emit GasCost
@@ -626,6 +741,15 @@ impl Transaction {
                                 storage_key_idx += 1;
                             }
                         }
+                        // detect start of authorization list, whose tuples are numbered after
+                        // the access list items so that their stack keys never collide.
+                        if cur.tag == BeginVector && is_prev_access_list_end {
+                            access_list_idx = access_list_len;
+                        }
+                        // detect start of authorization tuple
+                        if cur.tag.is_auth_chain_id() {
+                            access_list_idx += 1;
+                        }
 
                         if let Some(rem) = remaining_bytes.last_mut() {
                             // read one more byte
@@ -981,6 +1105,8 @@ impl Transaction {
             });
 
             witness_table_idx += 1;
+            is_prev_access_list_end =
+                cur.state == DecodeTagStart && cur.tag == EndVector && cur.depth == 2;
 
             if cur.tag == EndObject && cur.depth == 0 {
                 break;
@@ -1104,6 +1230,7 @@ impl<F: Field> RlpFsmWitnessGen<F> for Transaction {
             TxType::PreEip155 => (TxHashPreEip155, Some(TxSignPreEip155)),
             TxType::Eip1559 => (TxHashEip1559, Some(TxSignEip1559)),
             TxType::Eip2930 => (TxHashEip2930, Some(TxSignEip2930)),
+            TxType::Eip7702 => (TxHashEip7702, Some(TxSignEip7702)),
             TxType::L1Msg => (L1MsgHash, None),
         };
 
@@ -1200,6 +1327,8 @@ impl From<mock::MockTransaction> for Transaction {
             l1_fee: Default::default(),
             l1_fee_committed: Default::default(),
             access_list,
+            blob_versioned_hashes: vec![],
+            authorization_list: vec![],
             calls: vec![],
             steps: vec![],
         }
@@ -1235,12 +1364,12 @@ pub(super) fn tx_convert(
         nonce: tx.nonce,
         gas: tx.gas,
         gas_price: tx.gas_price,
-        max_fee_per_gas: if tx.tx_type.is_eip1559() {
+        max_fee_per_gas: if tx.tx_type.is_eip1559() || tx.tx_type.is_eip7702() {
             tx.gas_fee_cap
         } else {
             tx.gas_price
         },
-        max_priority_fee_per_gas: if tx.tx_type.is_eip1559() {
+        max_priority_fee_per_gas: if tx.tx_type.is_eip1559() || tx.tx_type.is_eip7702() {
             tx.gas_tip_cap
         } else {
             tx.gas_price
@@ -1263,6 +1392,8 @@ pub(super) fn tx_convert(
         l1_fee: tx.l1_fee,
         l1_fee_committed: tx.l1_fee_committed,
         access_list: tx.access_list.clone(),
+        blob_versioned_hashes: tx.blob_versioned_hashes.clone(),
+        authorization_list: tx.authorization_list.clone(),
         calls: tx
             .calls()
             .iter()